@@ -0,0 +1,121 @@
+use integer::{u128_wide_mul, u256, U64IntoU128, U128TryIntoU64};
+use option::OptionTrait;
+use traits::{Into, TryInto};
+
+/// The raw value of `1` in the `UFixed64x64` representation, i.e. `2**64`.
+const UFIXED64X64_ONE: u128 = 0x10000000000000000;
+
+/// An unsigned fixed-point number with 64 integer bits and 64 fractional bits, represented by its
+/// value multiplied by `2**64`.
+#[derive(Copy, Drop, PartialEq, Serde, storage_access::StorageAccess)]
+struct UFixed64x64 {
+    raw: u128, 
+}
+
+/// Returns the fixed-point number with the given raw representation.
+#[inline(always)]
+fn ufixed64x64_from_raw(raw: u128) -> UFixed64x64 nopanic {
+    UFixed64x64 { raw }
+}
+
+/// Returns the fixed-point number equal to the given integer.
+fn ufixed64x64_from_int(value: u64) -> UFixed64x64 {
+    UFixed64x64 { raw: value.into() * UFIXED64X64_ONE }
+}
+
+/// Returns the integer part of the fixed-point number, rounding down.
+fn ufixed64x64_floor(value: UFixed64x64) -> u64 {
+    (value.raw / UFIXED64X64_ONE).try_into().unwrap()
+}
+
+impl U64IntoUFixed64x64 of Into<u64, UFixed64x64> {
+    fn into(self: u64) -> UFixed64x64 {
+        ufixed64x64_from_int(self)
+    }
+}
+
+impl UFixed64x64Add of Add<UFixed64x64> {
+    fn add(lhs: UFixed64x64, rhs: UFixed64x64) -> UFixed64x64 {
+        UFixed64x64 { raw: lhs.raw + rhs.raw }
+    }
+}
+impl UFixed64x64AddEq of AddEq<UFixed64x64> {
+    #[inline(always)]
+    fn add_eq(ref self: UFixed64x64, other: UFixed64x64) {
+        self = Add::add(self, other);
+    }
+}
+
+impl UFixed64x64Sub of Sub<UFixed64x64> {
+    fn sub(lhs: UFixed64x64, rhs: UFixed64x64) -> UFixed64x64 {
+        UFixed64x64 { raw: lhs.raw - rhs.raw }
+    }
+}
+impl UFixed64x64SubEq of SubEq<UFixed64x64> {
+    #[inline(always)]
+    fn sub_eq(ref self: UFixed64x64, other: UFixed64x64) {
+        self = Sub::sub(self, other);
+    }
+}
+
+/// Multiplies two fixed-point numbers, rounding the result down.
+impl UFixed64x64Mul of Mul<UFixed64x64> {
+    fn mul(lhs: UFixed64x64, rhs: UFixed64x64) -> UFixed64x64 {
+        // The product of the raw values is the result multiplied by `2**128`, so the result is
+        // the middle 128 bits of the product.
+        let (high, low) = u128_wide_mul(lhs.raw, rhs.raw);
+        let high: u64 = high.try_into().expect('UFixed64x64_mul Overflow');
+        UFixed64x64 { raw: high.into() * UFIXED64X64_ONE + low / UFIXED64X64_ONE }
+    }
+}
+impl UFixed64x64MulEq of MulEq<UFixed64x64> {
+    #[inline(always)]
+    fn mul_eq(ref self: UFixed64x64, other: UFixed64x64) {
+        self = Mul::mul(self, other);
+    }
+}
+
+/// Divides two fixed-point numbers, rounding the result down.
+impl UFixed64x64Div of Div<UFixed64x64> {
+    fn div(lhs: UFixed64x64, rhs: UFixed64x64) -> UFixed64x64 {
+        // The raw value of `lhs` multiplied by `2**64`, so that the quotient is the raw result.
+        let shifted = u256 {
+            low: (lhs.raw % UFIXED64X64_ONE) * UFIXED64X64_ONE, high: lhs.raw / UFIXED64X64_ONE
+        };
+        let quotient = shifted / u256 { low: rhs.raw, high: 0 };
+        assert(quotient.high == 0, 'UFixed64x64_div Overflow');
+        UFixed64x64 { raw: quotient.low }
+    }
+}
+impl UFixed64x64DivEq of DivEq<UFixed64x64> {
+    #[inline(always)]
+    fn div_eq(ref self: UFixed64x64, other: UFixed64x64) {
+        self = Div::div(self, other);
+    }
+}
+
+impl UFixed64x64PartialOrd of PartialOrd<UFixed64x64> {
+    #[inline(always)]
+    fn le(lhs: UFixed64x64, rhs: UFixed64x64) -> bool {
+        lhs.raw <= rhs.raw
+    }
+    #[inline(always)]
+    fn ge(lhs: UFixed64x64, rhs: UFixed64x64) -> bool {
+        lhs.raw >= rhs.raw
+    }
+    #[inline(always)]
+    fn lt(lhs: UFixed64x64, rhs: UFixed64x64) -> bool {
+        lhs.raw < rhs.raw
+    }
+    #[inline(always)]
+    fn gt(lhs: UFixed64x64, rhs: UFixed64x64) -> bool {
+        lhs.raw > rhs.raw
+    }
+}
+
+impl UFixed64x64Default of Default<UFixed64x64> {
+    #[inline(always)]
+    fn default() -> UFixed64x64 nopanic {
+        UFixed64x64 { raw: 0 }
+    }
+}
@@ -55,6 +55,36 @@ impl LegacyHashU128 of LegacyHash<u128> {
     }
 }
 
+impl LegacyHashI8 of LegacyHash<i8> {
+    fn hash(state: felt252, value: i8) -> felt252 {
+        LegacyHash::<felt252>::hash(state, value.into())
+    }
+}
+
+impl LegacyHashI16 of LegacyHash<i16> {
+    fn hash(state: felt252, value: i16) -> felt252 {
+        LegacyHash::<felt252>::hash(state, value.into())
+    }
+}
+
+impl LegacyHashI32 of LegacyHash<i32> {
+    fn hash(state: felt252, value: i32) -> felt252 {
+        LegacyHash::<felt252>::hash(state, value.into())
+    }
+}
+
+impl LegacyHashI64 of LegacyHash<i64> {
+    fn hash(state: felt252, value: i64) -> felt252 {
+        LegacyHash::<felt252>::hash(state, value.into())
+    }
+}
+
+impl LegacyHashI128 of LegacyHash<i128> {
+    fn hash(state: felt252, value: i128) -> felt252 {
+        LegacyHash::<felt252>::hash(state, value.into())
+    }
+}
+
 impl LegacyHashU256 of LegacyHash<u256> {
     fn hash(state: felt252, value: u256) -> felt252 {
         let state = LegacyHash::<u128>::hash(state, value.low);
@@ -62,6 +92,21 @@ impl LegacyHashU256 of LegacyHash<u256> {
     }
 }
 
+impl LegacyHashU512 of LegacyHash<integer::u512> {
+    fn hash(state: felt252, value: integer::u512) -> felt252 {
+        let state = LegacyHash::<u128>::hash(state, value.limb0);
+        let state = LegacyHash::<u128>::hash(state, value.limb1);
+        let state = LegacyHash::<u128>::hash(state, value.limb2);
+        LegacyHash::<u128>::hash(state, value.limb3)
+    }
+}
+
+impl LegacyHashUFixed64x64 of LegacyHash<fixed::UFixed64x64> {
+    fn hash(state: felt252, value: fixed::UFixed64x64) -> felt252 {
+        LegacyHash::<u128>::hash(state, value.raw)
+    }
+}
+
 impl LegacyHashContractAddress of LegacyHash<starknet::ContractAddress> {
     fn hash(state: felt252, value: starknet::ContractAddress) -> felt252 {
         LegacyHash::<felt252>::hash(state, value.into())
@@ -1157,7 +1157,7 @@ impl U256BitNot of BitNot<u256> {
     }
 }
 
-#[derive(Copy, Drop, PartialEq, Serde)]
+#[derive(Copy, Drop, PartialEq, Serde, storage_access::StorageAccess)]
 struct u512 {
     limb0: u128,
     limb1: u128,
@@ -1173,27 +1173,21 @@ fn u128_add_with_carry(a: u128, b: u128) -> (u128, u128) nopanic {
     }
 }
 
-fn u256_wide_mul(a: u256, b: u256) -> u512 nopanic {
-    let (limb1, limb0) = u128_wide_mul(a.low, b.low);
-    let (limb2, limb1_part) = u128_wide_mul(a.low, b.high);
-    let (limb1, limb1_overflow0) = u128_add_with_carry(limb1, limb1_part);
-    let (limb2_part, limb1_part) = u128_wide_mul(a.high, b.low);
-    let (limb1, limb1_overflow1) = u128_add_with_carry(limb1, limb1_part);
-    let (limb2, limb2_overflow) = u128_add_with_carry(limb2, limb2_part);
-    let (limb3, limb2_part) = u128_wide_mul(a.high, b.high);
-    // No overflow since no limb4.
-    let limb3 = u128_wrapping_add(limb3, limb2_overflow);
-    let (limb2, limb2_overflow) = u128_add_with_carry(limb2, limb2_part);
-    // No overflow since no limb4.
-    let limb3 = u128_wrapping_add(limb3, limb2_overflow);
-    // No overflow possible in this addition since both operands are 0/1.
-    let limb1_overflow = u128_wrapping_add(limb1_overflow0, limb1_overflow1);
-    let (limb2, limb2_overflow) = u128_add_with_carry(limb2, limb1_overflow);
-    // No overflow since no limb4.
-    let limb3 = u128_wrapping_add(limb3, limb2_overflow);
-    u512 { limb0, limb1, limb2, limb3 }
+/// Multiplies two u256s and returns the full 512-bit result.
+#[inline(always)]
+fn u256_wide_mul(a: u256, b: u256) -> u512 implicits(RangeCheck) nopanic {
+    let (result, _, _, _, _) = u256_guarantee_wide_mul(a, b);
+    result
 }
 
+/// Multiplies two u256s and returns the full 512-bit result.
+/// Additionally returns several `U128MulGuarantee`s that are required for validating the calculation.
+extern fn u256_guarantee_wide_mul(
+    a: u256, b: u256
+) -> (
+    u512, U128MulGuarantee, U128MulGuarantee, U128MulGuarantee, U128MulGuarantee
+) implicits(RangeCheck) nopanic;
+
 /// Calculates division with remainder of a u512 by a non-zero u256.
 #[inline(always)]
 fn u512_safe_div_rem_by_u256(
@@ -1217,6 +1211,377 @@ extern fn u512_safe_divmod_by_u256(
     U128MulGuarantee
 ) implicits(RangeCheck) nopanic;
 
+#[derive(Copy, Drop)]
+extern type i8;
+impl NumericLiterali8 of NumericLiteral<i8>;
+extern fn i8_const<value>() -> i8 nopanic;
+extern fn i8_to_felt252(a: i8) -> felt252 nopanic;
+
+#[panic_with('i8_from Overflow', i8_from_felt252)]
+extern fn i8_try_from_felt252(a: felt252) -> Option<i8> implicits(RangeCheck) nopanic;
+
+extern fn i8_eq(lhs: i8, rhs: i8) -> bool implicits() nopanic;
+
+impl I8PartialEq of PartialEq<i8> {
+    #[inline(always)]
+    fn eq(lhs: i8, rhs: i8) -> bool {
+        i8_eq(lhs, rhs)
+    }
+    #[inline(always)]
+    fn ne(lhs: i8, rhs: i8) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+impl I8PartialOrd of PartialOrd<i8> {
+    #[inline(always)]
+    fn le(lhs: i8, rhs: i8) -> bool {
+        !(rhs < lhs)
+    }
+    #[inline(always)]
+    fn ge(lhs: i8, rhs: i8) -> bool {
+        !(lhs < rhs)
+    }
+    fn lt(lhs: i8, rhs: i8) -> bool {
+        // Shifting by 0x80 maps the range of i8 onto the range of u8, keeping the order.
+        let lhs: u128 = (i8_to_felt252(lhs) + 0x80).try_into().unwrap();
+        let rhs: u128 = (i8_to_felt252(rhs) + 0x80).try_into().unwrap();
+        lhs < rhs
+    }
+    #[inline(always)]
+    fn gt(lhs: i8, rhs: i8) -> bool {
+        rhs < lhs
+    }
+}
+
+impl I8Add of Add<i8> {
+    fn add(lhs: i8, rhs: i8) -> i8 {
+        i8_try_from_felt252(i8_to_felt252(lhs) + i8_to_felt252(rhs)).expect('i8_add Overflow')
+    }
+}
+impl I8AddEq of AddEq<i8> {
+    #[inline(always)]
+    fn add_eq(ref self: i8, other: i8) {
+        self = Add::add(self, other);
+    }
+}
+
+impl I8Sub of Sub<i8> {
+    fn sub(lhs: i8, rhs: i8) -> i8 {
+        i8_try_from_felt252(i8_to_felt252(lhs) - i8_to_felt252(rhs)).expect('i8_sub Overflow')
+    }
+}
+impl I8SubEq of SubEq<i8> {
+    #[inline(always)]
+    fn sub_eq(ref self: i8, other: i8) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I8Neg of Neg<i8> {
+    fn neg(a: i8) -> i8 {
+        i8_try_from_felt252(-i8_to_felt252(a)).expect('i8_neg Overflow')
+    }
+}
+
+#[derive(Copy, Drop)]
+extern type i16;
+impl NumericLiterali16 of NumericLiteral<i16>;
+extern fn i16_const<value>() -> i16 nopanic;
+extern fn i16_to_felt252(a: i16) -> felt252 nopanic;
+
+#[panic_with('i16_from Overflow', i16_from_felt252)]
+extern fn i16_try_from_felt252(a: felt252) -> Option<i16> implicits(RangeCheck) nopanic;
+
+extern fn i16_eq(lhs: i16, rhs: i16) -> bool implicits() nopanic;
+
+impl I16PartialEq of PartialEq<i16> {
+    #[inline(always)]
+    fn eq(lhs: i16, rhs: i16) -> bool {
+        i16_eq(lhs, rhs)
+    }
+    #[inline(always)]
+    fn ne(lhs: i16, rhs: i16) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+impl I16PartialOrd of PartialOrd<i16> {
+    #[inline(always)]
+    fn le(lhs: i16, rhs: i16) -> bool {
+        !(rhs < lhs)
+    }
+    #[inline(always)]
+    fn ge(lhs: i16, rhs: i16) -> bool {
+        !(lhs < rhs)
+    }
+    fn lt(lhs: i16, rhs: i16) -> bool {
+        // Shifting by 0x8000 maps the range of i16 onto the range of u16, keeping the order.
+        let lhs: u128 = (i16_to_felt252(lhs) + 0x8000).try_into().unwrap();
+        let rhs: u128 = (i16_to_felt252(rhs) + 0x8000).try_into().unwrap();
+        lhs < rhs
+    }
+    #[inline(always)]
+    fn gt(lhs: i16, rhs: i16) -> bool {
+        rhs < lhs
+    }
+}
+
+impl I16Add of Add<i16> {
+    fn add(lhs: i16, rhs: i16) -> i16 {
+        i16_try_from_felt252(i16_to_felt252(lhs) + i16_to_felt252(rhs)).expect('i16_add Overflow')
+    }
+}
+impl I16AddEq of AddEq<i16> {
+    #[inline(always)]
+    fn add_eq(ref self: i16, other: i16) {
+        self = Add::add(self, other);
+    }
+}
+
+impl I16Sub of Sub<i16> {
+    fn sub(lhs: i16, rhs: i16) -> i16 {
+        i16_try_from_felt252(i16_to_felt252(lhs) - i16_to_felt252(rhs)).expect('i16_sub Overflow')
+    }
+}
+impl I16SubEq of SubEq<i16> {
+    #[inline(always)]
+    fn sub_eq(ref self: i16, other: i16) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I16Neg of Neg<i16> {
+    fn neg(a: i16) -> i16 {
+        i16_try_from_felt252(-i16_to_felt252(a)).expect('i16_neg Overflow')
+    }
+}
+
+#[derive(Copy, Drop)]
+extern type i32;
+impl NumericLiterali32 of NumericLiteral<i32>;
+extern fn i32_const<value>() -> i32 nopanic;
+extern fn i32_to_felt252(a: i32) -> felt252 nopanic;
+
+#[panic_with('i32_from Overflow', i32_from_felt252)]
+extern fn i32_try_from_felt252(a: felt252) -> Option<i32> implicits(RangeCheck) nopanic;
+
+extern fn i32_eq(lhs: i32, rhs: i32) -> bool implicits() nopanic;
+
+impl I32PartialEq of PartialEq<i32> {
+    #[inline(always)]
+    fn eq(lhs: i32, rhs: i32) -> bool {
+        i32_eq(lhs, rhs)
+    }
+    #[inline(always)]
+    fn ne(lhs: i32, rhs: i32) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+impl I32PartialOrd of PartialOrd<i32> {
+    #[inline(always)]
+    fn le(lhs: i32, rhs: i32) -> bool {
+        !(rhs < lhs)
+    }
+    #[inline(always)]
+    fn ge(lhs: i32, rhs: i32) -> bool {
+        !(lhs < rhs)
+    }
+    fn lt(lhs: i32, rhs: i32) -> bool {
+        // Shifting by 0x80000000 maps the range of i32 onto the range of u32, keeping the order.
+        let lhs: u128 = (i32_to_felt252(lhs) + 0x80000000).try_into().unwrap();
+        let rhs: u128 = (i32_to_felt252(rhs) + 0x80000000).try_into().unwrap();
+        lhs < rhs
+    }
+    #[inline(always)]
+    fn gt(lhs: i32, rhs: i32) -> bool {
+        rhs < lhs
+    }
+}
+
+impl I32Add of Add<i32> {
+    fn add(lhs: i32, rhs: i32) -> i32 {
+        i32_try_from_felt252(i32_to_felt252(lhs) + i32_to_felt252(rhs)).expect('i32_add Overflow')
+    }
+}
+impl I32AddEq of AddEq<i32> {
+    #[inline(always)]
+    fn add_eq(ref self: i32, other: i32) {
+        self = Add::add(self, other);
+    }
+}
+
+impl I32Sub of Sub<i32> {
+    fn sub(lhs: i32, rhs: i32) -> i32 {
+        i32_try_from_felt252(i32_to_felt252(lhs) - i32_to_felt252(rhs)).expect('i32_sub Overflow')
+    }
+}
+impl I32SubEq of SubEq<i32> {
+    #[inline(always)]
+    fn sub_eq(ref self: i32, other: i32) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I32Neg of Neg<i32> {
+    fn neg(a: i32) -> i32 {
+        i32_try_from_felt252(-i32_to_felt252(a)).expect('i32_neg Overflow')
+    }
+}
+
+#[derive(Copy, Drop)]
+extern type i64;
+impl NumericLiterali64 of NumericLiteral<i64>;
+extern fn i64_const<value>() -> i64 nopanic;
+extern fn i64_to_felt252(a: i64) -> felt252 nopanic;
+
+#[panic_with('i64_from Overflow', i64_from_felt252)]
+extern fn i64_try_from_felt252(a: felt252) -> Option<i64> implicits(RangeCheck) nopanic;
+
+extern fn i64_eq(lhs: i64, rhs: i64) -> bool implicits() nopanic;
+
+impl I64PartialEq of PartialEq<i64> {
+    #[inline(always)]
+    fn eq(lhs: i64, rhs: i64) -> bool {
+        i64_eq(lhs, rhs)
+    }
+    #[inline(always)]
+    fn ne(lhs: i64, rhs: i64) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+impl I64PartialOrd of PartialOrd<i64> {
+    #[inline(always)]
+    fn le(lhs: i64, rhs: i64) -> bool {
+        !(rhs < lhs)
+    }
+    #[inline(always)]
+    fn ge(lhs: i64, rhs: i64) -> bool {
+        !(lhs < rhs)
+    }
+    fn lt(lhs: i64, rhs: i64) -> bool {
+        // Shifting by 0x8000000000000000 maps the range of i64 onto the range of u64, keeping the order.
+        let lhs: u128 = (i64_to_felt252(lhs) + 0x8000000000000000).try_into().unwrap();
+        let rhs: u128 = (i64_to_felt252(rhs) + 0x8000000000000000).try_into().unwrap();
+        lhs < rhs
+    }
+    #[inline(always)]
+    fn gt(lhs: i64, rhs: i64) -> bool {
+        rhs < lhs
+    }
+}
+
+impl I64Add of Add<i64> {
+    fn add(lhs: i64, rhs: i64) -> i64 {
+        i64_try_from_felt252(i64_to_felt252(lhs) + i64_to_felt252(rhs)).expect('i64_add Overflow')
+    }
+}
+impl I64AddEq of AddEq<i64> {
+    #[inline(always)]
+    fn add_eq(ref self: i64, other: i64) {
+        self = Add::add(self, other);
+    }
+}
+
+impl I64Sub of Sub<i64> {
+    fn sub(lhs: i64, rhs: i64) -> i64 {
+        i64_try_from_felt252(i64_to_felt252(lhs) - i64_to_felt252(rhs)).expect('i64_sub Overflow')
+    }
+}
+impl I64SubEq of SubEq<i64> {
+    #[inline(always)]
+    fn sub_eq(ref self: i64, other: i64) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I64Neg of Neg<i64> {
+    fn neg(a: i64) -> i64 {
+        i64_try_from_felt252(-i64_to_felt252(a)).expect('i64_neg Overflow')
+    }
+}
+
+#[derive(Copy, Drop)]
+extern type i128;
+impl NumericLiterali128 of NumericLiteral<i128>;
+extern fn i128_const<value>() -> i128 nopanic;
+extern fn i128_to_felt252(a: i128) -> felt252 nopanic;
+
+#[panic_with('i128_from Overflow', i128_from_felt252)]
+extern fn i128_try_from_felt252(a: felt252) -> Option<i128> implicits(RangeCheck) nopanic;
+
+extern fn i128_eq(lhs: i128, rhs: i128) -> bool implicits() nopanic;
+
+impl I128PartialEq of PartialEq<i128> {
+    #[inline(always)]
+    fn eq(lhs: i128, rhs: i128) -> bool {
+        i128_eq(lhs, rhs)
+    }
+    #[inline(always)]
+    fn ne(lhs: i128, rhs: i128) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+impl I128PartialOrd of PartialOrd<i128> {
+    #[inline(always)]
+    fn le(lhs: i128, rhs: i128) -> bool {
+        !(rhs < lhs)
+    }
+    #[inline(always)]
+    fn ge(lhs: i128, rhs: i128) -> bool {
+        !(lhs < rhs)
+    }
+    fn lt(lhs: i128, rhs: i128) -> bool {
+        // Shifting by 0x80000000000000000000000000000000 maps the range of i128 onto the range of u128, keeping the order.
+        let lhs: u128 = (i128_to_felt252(lhs) + 0x80000000000000000000000000000000)
+            .try_into()
+            .unwrap();
+        let rhs: u128 = (i128_to_felt252(rhs) + 0x80000000000000000000000000000000)
+            .try_into()
+            .unwrap();
+        lhs < rhs
+    }
+    #[inline(always)]
+    fn gt(lhs: i128, rhs: i128) -> bool {
+        rhs < lhs
+    }
+}
+
+impl I128Add of Add<i128> {
+    fn add(lhs: i128, rhs: i128) -> i128 {
+        i128_try_from_felt252(i128_to_felt252(lhs) + i128_to_felt252(rhs))
+            .expect('i128_add Overflow')
+    }
+}
+impl I128AddEq of AddEq<i128> {
+    #[inline(always)]
+    fn add_eq(ref self: i128, other: i128) {
+        self = Add::add(self, other);
+    }
+}
+
+impl I128Sub of Sub<i128> {
+    fn sub(lhs: i128, rhs: i128) -> i128 {
+        i128_try_from_felt252(i128_to_felt252(lhs) - i128_to_felt252(rhs))
+            .expect('i128_sub Overflow')
+    }
+}
+impl I128SubEq of SubEq<i128> {
+    #[inline(always)]
+    fn sub_eq(ref self: i128, other: i128) {
+        self = Sub::sub(self, other);
+    }
+}
+
+impl I128Neg of Neg<i128> {
+    fn neg(a: i128) -> i128 {
+        i128_try_from_felt252(-i128_to_felt252(a)).expect('i128_neg Overflow')
+    }
+}
+
 /// Bounded
 trait BoundedInt<T> {
     fn min() -> T nopanic;
@@ -1340,6 +1705,56 @@ impl U128IntoFelt252 of Into<u128, felt252> {
         u128_to_felt252(self)
     }
 }
+impl Felt252TryIntoI8 of TryInto<felt252, i8> {
+    fn try_into(self: felt252) -> Option<i8> {
+        i8_try_from_felt252(self)
+    }
+}
+impl I8IntoFelt252 of Into<i8, felt252> {
+    fn into(self: i8) -> felt252 {
+        i8_to_felt252(self)
+    }
+}
+impl Felt252TryIntoI16 of TryInto<felt252, i16> {
+    fn try_into(self: felt252) -> Option<i16> {
+        i16_try_from_felt252(self)
+    }
+}
+impl I16IntoFelt252 of Into<i16, felt252> {
+    fn into(self: i16) -> felt252 {
+        i16_to_felt252(self)
+    }
+}
+impl Felt252TryIntoI32 of TryInto<felt252, i32> {
+    fn try_into(self: felt252) -> Option<i32> {
+        i32_try_from_felt252(self)
+    }
+}
+impl I32IntoFelt252 of Into<i32, felt252> {
+    fn into(self: i32) -> felt252 {
+        i32_to_felt252(self)
+    }
+}
+impl Felt252TryIntoI64 of TryInto<felt252, i64> {
+    fn try_into(self: felt252) -> Option<i64> {
+        i64_try_from_felt252(self)
+    }
+}
+impl I64IntoFelt252 of Into<i64, felt252> {
+    fn into(self: i64) -> felt252 {
+        i64_to_felt252(self)
+    }
+}
+impl Felt252TryIntoI128 of TryInto<felt252, i128> {
+    fn try_into(self: felt252) -> Option<i128> {
+        i128_try_from_felt252(self)
+    }
+}
+impl I128IntoFelt252 of Into<i128, felt252> {
+    fn into(self: i128) -> felt252 {
+        i128_to_felt252(self)
+    }
+}
 impl Felt252IntoU256 of Into<felt252, u256> {
     fn into(self: felt252) -> u256 {
         u256_from_felt252(self)
@@ -1414,6 +1829,41 @@ impl U256Default of Default<u256> {
     }
 }
 
+impl I8Default of Default<i8> {
+    #[inline(always)]
+    fn default() -> i8 nopanic {
+        0_i8
+    }
+}
+
+impl I16Default of Default<i16> {
+    #[inline(always)]
+    fn default() -> i16 nopanic {
+        0_i16
+    }
+}
+
+impl I32Default of Default<i32> {
+    #[inline(always)]
+    fn default() -> i32 nopanic {
+        0_i32
+    }
+}
+
+impl I64Default of Default<i64> {
+    #[inline(always)]
+    fn default() -> i64 nopanic {
+        0_i64
+    }
+}
+
+impl I128Default of Default<i128> {
+    #[inline(always)]
+    fn default() -> i128 nopanic {
+        0_i128
+    }
+}
+
 
 /// Default values for felt252_dict values.
 impl U8Felt252DictValue of Felt252DictValue<u8> {
@@ -224,9 +224,16 @@ use integer::{
     u32_const, u64, u64_const, u256, u256_sqrt, Felt252TryIntoU8, U8IntoFelt252, Felt252TryIntoU16,
     U16IntoFelt252, Felt252TryIntoU32, U32IntoFelt252, Felt252TryIntoU64, U64IntoFelt252,
     Felt252TryIntoU128, U128IntoFelt252, U16TryIntoU8, U32TryIntoU16, U64TryIntoU32, U128TryIntoU64,
-    Felt252IntoU256, Bitwise
+    Felt252IntoU256, Bitwise, i8, i8_const, i16, i16_const, i32, i32_const, i64, i64_const, i128,
+    i128_const, Felt252TryIntoI8, I8IntoFelt252, Felt252TryIntoI16, I16IntoFelt252,
+    Felt252TryIntoI32, I32IntoFelt252, Felt252TryIntoI64, I64IntoFelt252, Felt252TryIntoI128,
+    I128IntoFelt252
 };
 
+// Fixed point.
+mod fixed;
+use fixed::{UFixed64x64, U64IntoUFixed64x64};
+
 // Math.
 mod math;
 
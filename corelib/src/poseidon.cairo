@@ -17,6 +17,56 @@ struct PoseidonBuiltinState {
     s2: felt252,
 }
 
+// Represents the state of a Poseidon hash computed incrementally.
+// `odd` is true if an element was absorbed into `s0` and awaits its pair.
+#[derive(Copy, Drop)]
+struct HashState {
+    s0: felt252,
+    s1: felt252,
+    s2: felt252,
+    odd: bool,
+}
+
+trait PoseidonTrait {
+    fn new() -> HashState;
+}
+
+impl PoseidonImpl of PoseidonTrait {
+    #[inline(always)]
+    fn new() -> HashState {
+        HashState { s0: 0, s1: 0, s2: 0, odd: false }
+    }
+}
+
+trait HashStateTrait<S> {
+    fn update(self: S, value: felt252) -> S;
+    fn finalize(self: S) -> felt252;
+}
+
+/// Computes the same hash as `poseidon_hash_span` on the elements passed to `update`, in order.
+impl HashStateImpl of HashStateTrait<HashState> {
+    #[inline(always)]
+    fn update(self: HashState, value: felt252) -> HashState {
+        if self.odd {
+            let (s0, s1, s2) = hades_permutation(self.s0, self.s1 + value, self.s2);
+            HashState { s0, s1, s2, odd: false }
+        } else {
+            HashState { s0: self.s0 + value, s1: self.s1, s2: self.s2, odd: true }
+        }
+    }
+
+    #[inline(always)]
+    fn finalize(self: HashState) -> felt252 {
+        // Pad with [0, 1] if an element awaits its pair, and with [1, 0] otherwise.
+        let (r, _, _) = if self.odd {
+            hades_permutation(self.s0, self.s1 + 1, self.s2)
+        } else {
+            hades_permutation(self.s0 + 1, self.s1, self.s2)
+        };
+        r
+    }
+}
+
 
 /// Computes the Poseidon hash on the given input.
 ///
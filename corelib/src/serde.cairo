@@ -75,6 +75,51 @@ impl U128Serde of Serde<u128> {
     }
 }
 
+impl I8Serde of Serde<i8> {
+    fn serialize(self: @i8, ref output: Array<felt252>) {
+        Into::<i8, felt252>::into(*self).serialize(ref output);
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<i8> {
+        Option::Some(((*serialized.pop_front()?).try_into())?)
+    }
+}
+
+impl I16Serde of Serde<i16> {
+    fn serialize(self: @i16, ref output: Array<felt252>) {
+        Into::<i16, felt252>::into(*self).serialize(ref output);
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<i16> {
+        Option::Some(((*serialized.pop_front()?).try_into())?)
+    }
+}
+
+impl I32Serde of Serde<i32> {
+    fn serialize(self: @i32, ref output: Array<felt252>) {
+        Into::<i32, felt252>::into(*self).serialize(ref output);
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<i32> {
+        Option::Some(((*serialized.pop_front()?).try_into())?)
+    }
+}
+
+impl I64Serde of Serde<i64> {
+    fn serialize(self: @i64, ref output: Array<felt252>) {
+        Into::<i64, felt252>::into(*self).serialize(ref output);
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<i64> {
+        Option::Some(((*serialized.pop_front()?).try_into())?)
+    }
+}
+
+impl I128Serde of Serde<i128> {
+    fn serialize(self: @i128, ref output: Array<felt252>) {
+        Into::<i128, felt252>::into(*self).serialize(ref output);
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<i128> {
+        Option::Some(((*serialized.pop_front()?).try_into())?)
+    }
+}
+
 impl OptionSerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Option<T>> {
     fn serialize(self: @Option<T>, ref output: Array<felt252>) {
         match self {
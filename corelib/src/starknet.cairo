@@ -26,6 +26,9 @@ use syscalls::{
 // secp256k1
 mod secp256k1;
 
+// secp256r1
+mod secp256r1;
+
 // ContractAddress
 mod contract_address;
 use contract_address::{
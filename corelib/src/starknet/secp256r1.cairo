@@ -0,0 +1,112 @@
+//! This module contains functions and constructs related to elliptic curve operations on the
+//! secp256r1 curve.
+
+use math::u256_div_mod_n;
+use option::OptionTrait;
+use starknet::{SyscallResult, SyscallResultTrait};
+use traits::TryInto;
+use integer::U256TryIntoNonZero;
+
+#[derive(Copy, Drop)]
+extern type Secp256R1EcPoint;
+
+/// Creates a secp256r1 EC point from the given x and y coordinates.
+/// Returns None if the given coordinates do not correspond to a point on the curve.
+extern fn secp256r1_ec_new_syscall(
+    x: u256, y: u256
+) -> SyscallResult<Option<Secp256R1EcPoint>> implicits(GasBuiltin, System) nopanic;
+
+fn get_N() -> u256 {
+    0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551
+}
+
+/// Computes the addition of secp256r1 EC points `p0 + p1`.
+extern fn secp256r1_ec_add_syscall(
+    p0: Secp256R1EcPoint, p1: Secp256R1EcPoint
+) -> SyscallResult<Secp256R1EcPoint> implicits(GasBuiltin, System) nopanic;
+/// Computes the product of a secp256r1 EC point `p` by the given scalar `m`.
+extern fn secp256r1_ec_mul_syscall(
+    p: Secp256R1EcPoint, m: u256
+) -> SyscallResult<Secp256R1EcPoint> implicits(GasBuiltin, System) nopanic;
+
+/// Computes the point on the secp256r1 curve that matches the given `x` coordinate, if such exists.
+/// Out of the two possible y's, chooses according to `y_parity`.
+extern fn secp256r1_ec_get_point_from_x_syscall(
+    x: u256, y_parity: bool
+) -> SyscallResult<Option<Secp256R1EcPoint>> implicits(GasBuiltin, System) nopanic;
+
+/// Returns the coordinates of a point on the secp256r1 curve.
+extern fn secp256r1_ec_get_coordinates_syscall(
+    p: Secp256R1EcPoint
+) -> SyscallResult<(u256, u256)> implicits(GasBuiltin, System) nopanic;
+
+/// Creates the generator point of the secp256r1 curve.
+fn get_generator_point() -> Secp256R1EcPoint {
+    secp256r1_ec_new_syscall(
+        u256 { high: 0x6b17d1f2e12c4247f8bce6e563a440f2, low: 0x77037d812deb33a0f4a13945d898c296 },
+        u256 { high: 0x4fe342e2fe1a7f9b8ee7eb4a7c0f9e16, low: 0x2bce33576b315ececbb6406837bf51f5 }
+    )
+        .unwrap_syscall()
+        .unwrap()
+}
+
+/// Receives a signature and the signed message hash.
+/// Returns the public key associated with the signer, represented as a point on the curve.
+fn recover_public_key(
+    msg_hash: u256, r: u256, s: u256, y_parity: bool
+) -> Option<Secp256R1EcPoint> {
+    let r_point = secp256r1_ec_get_point_from_x_syscall(x: r, :y_parity).unwrap_syscall()?;
+    let generator_point = get_generator_point();
+
+    // The result is given by
+    //   -(msg_hash / r) * gen + (s / r) * r_point
+    // where the divisions by `r` are modulo `N`.
+
+    let n_nz = get_N().try_into().unwrap();
+    let r_nz = r.try_into().unwrap();
+    let u1 = u256_div_mod_n(msg_hash, r_nz, n_nz).unwrap();
+    let minus_u1 = secp256r1_ec_negate_scalar(u1);
+    let u2 = u256_div_mod_n(s, r_nz, n_nz).unwrap();
+
+    let minus_point1 = secp256r1_ec_mul_syscall(generator_point, minus_u1).unwrap_syscall();
+
+    let point2 = secp256r1_ec_mul_syscall(r_point, u2).unwrap_syscall();
+
+    Option::Some(secp256r1_ec_add_syscall(minus_point1, point2).unwrap_syscall())
+}
+
+/// Computes the negation of a scalar modulo N (the size of the curve).
+fn secp256r1_ec_negate_scalar(c: u256) -> u256 {
+    get_N() - c
+}
+
+/// Returns whether the given Secp256r1 ECDSA signature of `msg_hash` is valid for `public_key`.
+/// Also verifies that r and s are in the range (0, N).
+fn is_valid_signature(msg_hash: u256, r: u256, s: u256, public_key: Secp256R1EcPoint) -> bool {
+    if !is_signature_entry_valid(r) | !is_signature_entry_valid(s) {
+        return false;
+    }
+
+    // The signature is valid if the x coordinate of
+    //   (msg_hash / s) * gen + (r / s) * public_key
+    // is equal to `r` modulo `N`, where the divisions by `s` are modulo `N`.
+    let n_nz = get_N().try_into().unwrap();
+    let s_nz = s.try_into().unwrap();
+    let u1 = u256_div_mod_n(msg_hash, s_nz, n_nz).unwrap();
+    let u2 = u256_div_mod_n(r, s_nz, n_nz).unwrap();
+
+    let point1 = secp256r1_ec_mul_syscall(get_generator_point(), u1).unwrap_syscall();
+    let point2 = secp256r1_ec_mul_syscall(public_key, u2).unwrap_syscall();
+    let sum = secp256r1_ec_add_syscall(point1, point2).unwrap_syscall();
+    let (x, _) = secp256r1_ec_get_coordinates_syscall(sum).unwrap_syscall();
+    if x < get_N() {
+        x == r
+    } else {
+        x - get_N() == r
+    }
+}
+
+/// Checks whether `value` is in the range [1, N).
+fn is_signature_entry_valid(value: u256) -> bool {
+    value != 0_u256 & value < get_N()
+}
@@ -311,6 +311,186 @@ impl StorageAccessU128 of StorageAccess<u128> {
     }
 }
 
+impl StorageAccessI8 of StorageAccess<i8> {
+    fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<i8> {
+        Result::Ok(
+            StorageAccess::<felt252>::read(address_domain, base)?
+                .try_into()
+                .expect('StorageAccessI8 - non i8')
+        )
+    }
+    #[inline(always)]
+    fn write(address_domain: u32, base: StorageBaseAddress, value: i8) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write(address_domain, base, value.into())
+    }
+    #[inline(always)]
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<i8> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessI8 - non i8')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: i8
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal(value: i8) -> u8 {
+        1_u8
+    }
+}
+
+impl StorageAccessI16 of StorageAccess<i16> {
+    fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<i16> {
+        Result::Ok(
+            StorageAccess::<felt252>::read(address_domain, base)?
+                .try_into()
+                .expect('StorageAccessI16 - non i16')
+        )
+    }
+    #[inline(always)]
+    fn write(address_domain: u32, base: StorageBaseAddress, value: i16) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write(address_domain, base, value.into())
+    }
+    #[inline(always)]
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<i16> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessI16 - non i16')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: i16
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal(value: i16) -> u8 {
+        1_u8
+    }
+}
+
+impl StorageAccessI32 of StorageAccess<i32> {
+    fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<i32> {
+        Result::Ok(
+            StorageAccess::<felt252>::read(address_domain, base)?
+                .try_into()
+                .expect('StorageAccessI32 - non i32')
+        )
+    }
+    #[inline(always)]
+    fn write(address_domain: u32, base: StorageBaseAddress, value: i32) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write(address_domain, base, value.into())
+    }
+    #[inline(always)]
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<i32> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessI32 - non i32')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: i32
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal(value: i32) -> u8 {
+        1_u8
+    }
+}
+
+impl StorageAccessI64 of StorageAccess<i64> {
+    fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<i64> {
+        Result::Ok(
+            StorageAccess::<felt252>::read(address_domain, base)?
+                .try_into()
+                .expect('StorageAccessI64 - non i64')
+        )
+    }
+    #[inline(always)]
+    fn write(address_domain: u32, base: StorageBaseAddress, value: i64) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write(address_domain, base, value.into())
+    }
+    #[inline(always)]
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<i64> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessI64 - non i64')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: i64
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal(value: i64) -> u8 {
+        1_u8
+    }
+}
+
+impl StorageAccessI128 of StorageAccess<i128> {
+    fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<i128> {
+        Result::Ok(
+            StorageAccess::<felt252>::read(address_domain, base)?
+                .try_into()
+                .expect('StorageAccessI128 - non i128')
+        )
+    }
+    #[inline(always)]
+    fn write(address_domain: u32, base: StorageBaseAddress, value: i128) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write(address_domain, base, value.into())
+    }
+    #[inline(always)]
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<i128> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessI128 - non i128')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: i128
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal(value: i128) -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessStorageAddress of StorageAccess<StorageAddress> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<StorageAddress> {
         Result::Ok(
@@ -1,4 +1,10 @@
+use array::ArrayTrait;
+use array::SpanTrait;
+use option::OptionTrait;
+use serde::Serde;
+use traits::Into;
 use starknet::ContractAddress;
+use starknet::contract_address::ContractAddressIntoFelt252;
 
 extern fn set_caller_address(address: ContractAddress) implicits() nopanic;
 extern fn set_contract_address(address: ContractAddress) implicits() nopanic;
@@ -12,3 +18,132 @@ extern fn set_transaction_hash(hash: felt252) implicits() nopanic;
 extern fn set_chain_id(chain_id: felt252) implicits() nopanic;
 extern fn set_nonce(nonce: felt252) implicits() nopanic;
 extern fn set_signature(signature: Span<felt252>) implicits() nopanic;
+
+/// Calls a cheatcode of the test runner, given by its selector, with the given input.
+extern fn cheatcode(selector: felt252, input: Span<felt252>) -> Span<felt252> implicits() nopanic;
+
+/// Overrides the caller address seen by the calls of the target contract, until
+/// `stop_cheat_caller_address` is called for it.
+fn start_cheat_caller_address(target: ContractAddress, caller_address: ContractAddress) {
+    start_cheat('caller_address', target, caller_address.into());
+}
+fn stop_cheat_caller_address(target: ContractAddress) {
+    stop_cheat('caller_address', target);
+}
+
+/// Overrides the contract address seen by the calls of the target contract, until
+/// `stop_cheat_contract_address` is called for it.
+fn start_cheat_contract_address(target: ContractAddress, contract_address: ContractAddress) {
+    start_cheat('contract_address', target, contract_address.into());
+}
+fn stop_cheat_contract_address(target: ContractAddress) {
+    stop_cheat('contract_address', target);
+}
+
+/// Overrides the block number seen by the calls of the target contract, until
+/// `stop_cheat_block_number` is called for it.
+fn start_cheat_block_number(target: ContractAddress, block_number: u64) {
+    start_cheat('block_number', target, block_number.into());
+}
+fn stop_cheat_block_number(target: ContractAddress) {
+    stop_cheat('block_number', target);
+}
+
+/// Overrides the block timestamp seen by the calls of the target contract, until
+/// `stop_cheat_block_timestamp` is called for it.
+fn start_cheat_block_timestamp(target: ContractAddress, block_timestamp: u64) {
+    start_cheat('block_timestamp', target, block_timestamp.into());
+}
+fn stop_cheat_block_timestamp(target: ContractAddress) {
+    stop_cheat('block_timestamp', target);
+}
+
+/// Overrides the sequencer address seen by the calls of the target contract, until
+/// `stop_cheat_sequencer_address` is called for it.
+fn start_cheat_sequencer_address(target: ContractAddress, sequencer_address: ContractAddress) {
+    start_cheat('sequencer_address', target, sequencer_address.into());
+}
+fn stop_cheat_sequencer_address(target: ContractAddress) {
+    stop_cheat('sequencer_address', target);
+}
+
+/// Overrides the chain id seen by the calls of the target contract, until `stop_cheat_chain_id`
+/// is called for it.
+fn start_cheat_chain_id(target: ContractAddress, chain_id: felt252) {
+    start_cheat('chain_id', target, chain_id);
+}
+fn stop_cheat_chain_id(target: ContractAddress) {
+    stop_cheat('chain_id', target);
+}
+
+/// Pops the earliest event emitted by the given contract that was not popped yet, returning its
+/// keys and data, or `None` if all the events emitted by the contract were popped.
+fn pop_log(address: ContractAddress) -> Option<(Span<felt252>, Span<felt252>)> {
+    let mut input = array::array_new();
+    input.append(address.into());
+    let mut output = cheatcode('pop_log', input.span());
+    let log: Option<(Array<felt252>, Array<felt252>)> = Serde::deserialize(ref output)
+        .expect('invalid pop_log output');
+    match log {
+        Option::Some((keys, data)) => Option::Some((keys.span(), data.span())),
+        Option::None(()) => Option::None(()),
+    }
+}
+
+/// Mocks the calls of the entry point with the given selector of the target contract, returning
+/// `ret_data` without calling the contract, until `stop_mock_call` is called for it. The target
+/// contract doesn't have to be deployed.
+fn start_mock_call(target: ContractAddress, selector: felt252, ret_data: Span<felt252>) {
+    mock_call(target, selector, false, ret_data);
+}
+
+/// Mocks the calls of the entry point with the given selector of the target contract, failing
+/// with `panic_data` as if the contract panicked, until `stop_mock_call` is called for it.
+fn start_mock_call_panic(target: ContractAddress, selector: felt252, panic_data: Span<felt252>) {
+    mock_call(target, selector, true, panic_data);
+}
+
+/// Stops mocking the calls of the entry point with the given selector of the target contract.
+fn stop_mock_call(target: ContractAddress, selector: felt252) {
+    let mut input = array::array_new();
+    input.append(target.into());
+    input.append(selector);
+    cheatcode('stop_mock_call', input.span());
+}
+
+/// Mocks the calls of an entry point of the target contract to return or fail with `data`.
+fn mock_call(target: ContractAddress, selector: felt252, failed: bool, mut data: Span<felt252>) {
+    let mut input = array::array_new();
+    input.append(target.into());
+    input.append(selector);
+    failed.serialize(ref input);
+    input.append(data.len().into());
+    loop {
+        match data.pop_front() {
+            Option::Some(value) => {
+                input.append(*value);
+            },
+            Option::None(_) => {
+                break ();
+            },
+        };
+    };
+    cheatcode('start_mock_call', input.span());
+}
+
+/// Overrides the execution info field named `field` for the calls of the target contract.
+fn start_cheat(field: felt252, target: ContractAddress, value: felt252) {
+    let mut input = array::array_new();
+    input.append(field);
+    input.append(target.into());
+    input.append(value);
+    cheatcode('start_cheat', input.span());
+}
+
+/// Stops overriding the execution info field named `field` for the calls of the target contract.
+fn stop_cheat(field: felt252, target: ContractAddress) {
+    let mut input = array::array_new();
+    input.append(field);
+    input.append(target.into());
+    cheatcode('stop_cheat', input.span());
+}
@@ -4,11 +4,13 @@ mod box_test;
 mod dict_test;
 mod ec_test;
 mod felt_test;
+mod fixed_test;
 mod cmp_test;
 mod hash_test;
 mod integer_test;
 mod keccak_test;
 mod secp256k1_test;
+mod secp256r1_test;
 mod plugins_test;
 mod testing_test;
 mod test_utils;
@@ -0,0 +1,41 @@
+use fixed::{
+    UFixed64x64, U64IntoUFixed64x64, ufixed64x64_from_int, ufixed64x64_from_raw, ufixed64x64_floor
+};
+use test::test_utils::{assert_eq, assert_ne, assert_le, assert_lt, assert_gt, assert_ge};
+use traits::Into;
+
+#[test]
+fn test_ufixed64x64_operators() {
+    let half = ufixed64x64_from_raw(0x8000000000000000);
+    let one = ufixed64x64_from_int(1);
+    let three: UFixed64x64 = 3_u64.into();
+    assert_eq(half + half, one, '0.5 + 0.5 == 1');
+    assert_eq(three - one, 2_u64.into(), '3 - 1 == 2');
+    assert_eq(three * half, ufixed64x64_from_raw(0x18000000000000000), '3 * 0.5 == 1.5');
+    assert_eq(one / three, ufixed64x64_from_raw(0x5555555555555555), '1 / 3 == 0.333..');
+    assert_eq(three / half, 6_u64.into(), '3 / 0.5 == 6');
+    assert_eq(ufixed64x64_floor(three * half), 1, 'floor(1.5) == 1');
+    assert_ne(half, one, '0.5 != 1');
+    assert_lt(half, one, '0.5 < 1');
+    assert_le(one, one, '1 <= 1');
+    assert_gt(three, one, '3 > 1');
+    assert_ge(three, three, '3 >= 3');
+}
+
+#[test]
+#[should_panic]
+fn test_ufixed64x64_mul_overflow() {
+    ufixed64x64_from_int(0x100000000) * ufixed64x64_from_int(0x100000000);
+}
+
+#[test]
+#[should_panic]
+fn test_ufixed64x64_div_overflow() {
+    ufixed64x64_from_int(0x100000000) / ufixed64x64_from_raw(1);
+}
+
+#[test]
+#[should_panic]
+fn test_ufixed64x64_div_by_zero() {
+    ufixed64x64_from_int(1) / ufixed64x64_from_raw(0);
+}
@@ -1,4 +1,5 @@
 use array::ArrayTrait;
+use poseidon::{HashStateTrait, PoseidonTrait};
 use test::test_utils::{assert_eq, assert_ne};
 
 #[test]
@@ -47,3 +48,21 @@ fn test_poseidon_hash_span() {
         'wrong result'
     );
 }
+
+#[test]
+fn test_poseidon_hash_state() {
+    // Test odd number of inputs.
+    let state = PoseidonTrait::new().update(1).update(2).update(3);
+    assert_eq(
+        state.finalize(),
+        0x2f0d8840bcf3bc629598d8a6cc80cb7c0d9e52d93dab244bbf9cd0dca0ad082,
+        'wrong result'
+    );
+
+    // Test even number of inputs.
+    assert_eq(
+        state.update(4).finalize(),
+        0x26e3ad8b876e02bc8a4fc43dad40a8f81a6384083cabffa190bcf40d512ae1d,
+        'wrong result'
+    );
+}
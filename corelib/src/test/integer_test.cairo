@@ -3,7 +3,7 @@ use core::traits::Default;
 use option::OptionTrait;
 use integer::{
     BoundedInt, u128_wrapping_sub, u16_sqrt, u32_sqrt, u64_sqrt, u8_sqrt, u512, u256_wide_mul,
-    u256_as_non_zero, u512_safe_div_rem_by_u256, u128_as_non_zero
+    u256_as_non_zero, u512_safe_div_rem_by_u256, u128_as_non_zero, i8_to_felt252
 };
 use test::test_utils::{assert_eq, assert_ne, assert_le, assert_lt, assert_gt, assert_ge};
 
@@ -715,6 +715,17 @@ fn test_u256_wide_mul() {
         },
         'long calculation failed'
     );
+    let max_u256 = 0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff;
+    assert_eq(
+        u256_wide_mul(max_u256, max_u256),
+        u512 {
+            limb0: 1,
+            limb1: 0,
+            limb2: 0xfffffffffffffffffffffffffffffffe,
+            limb3: 0xffffffffffffffffffffffffffffffff
+        },
+        'max calculation failed'
+    );
 }
 
 #[test]
@@ -1074,3 +1085,65 @@ fn test_u128_byte_reverse() {
         'Wrong byte reverse'
     );
 }
+
+#[test]
+fn test_i8_operators() {
+    assert_eq(1_i8, 1_i8, '1 == 1');
+    assert_ne(1_i8, 2_i8, '1 != 2');
+    assert_eq(1_i8 + 3_i8, 4_i8, '1 + 3 == 4');
+    assert_eq(-3_i8 + 6_i8, 3_i8, '-3 + 6 == 3');
+    assert_eq(3_i8 - 5_i8, -2_i8, '3 - 5 == -2');
+    assert_eq(-127_i8 - 1_i8 + 1_i8, -127_i8, '-127 - 1 + 1 == -127');
+    assert_eq(-(-5_i8), 5_i8, '-(-5) == 5');
+    assert_lt(-1_i8, 4_i8, '-1 < 4');
+    assert_le(-4_i8, -4_i8, '-4 <= -4');
+    assert(!(4_i8 < -4_i8), '!(4 < -4)');
+    assert_gt(5_i8, -2_i8, '5 > -2');
+    assert_ge(-2_i8, -3_i8, '-2 >= -3');
+    assert_eq(i8_to_felt252(-1_i8), -1, 'i8 -1 into felt252');
+}
+
+#[test]
+#[should_panic]
+fn test_i8_add_overflow() {
+    100_i8 + 28_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_sub_overflow() {
+    -100_i8 - 29_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_neg_overflow() {
+    -(-127_i8 - 1_i8);
+}
+
+#[test]
+fn test_i128_operators() {
+    assert_eq(
+        0x7fffffffffffffffffffffffffffffff_i128 - 0x7fffffffffffffffffffffffffffffff_i128,
+        0_i128,
+        'max - max == 0'
+    );
+    assert_eq(-3_i128 + -6_i128, -9_i128, '-3 + -6 == -9');
+    assert_lt(
+        -0x7fffffffffffffffffffffffffffffff_i128,
+        0x7fffffffffffffffffffffffffffffff_i128,
+        '-max < max'
+    );
+}
+
+#[test]
+fn test_felt252_try_into_signed() {
+    assert_eq(127.try_into().unwrap(), 127_i8, '127 fits i8');
+    assert_eq((-128).try_into().unwrap(), -127_i8 - 1_i8, '-128 fits i8');
+    let out_of_range: Option<i8> = 128.try_into();
+    assert(out_of_range.is_none(), '128 does not fit i8');
+    let out_of_range: Option<i8> = (-129).try_into();
+    assert(out_of_range.is_none(), '-129 does not fit i8');
+    let out_of_range: Option<i64> = 0x8000000000000000.try_into();
+    assert(out_of_range.is_none(), '2^63 does not fit i64');
+}
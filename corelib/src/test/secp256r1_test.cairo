@@ -0,0 +1,68 @@
+use starknet::{
+    secp256r1::{
+        get_generator_point, get_N, is_valid_signature, recover_public_key,
+        secp256r1_ec_add_syscall, secp256r1_ec_get_coordinates_syscall,
+        secp256r1_ec_get_point_from_x_syscall, secp256r1_ec_mul_syscall, secp256r1_ec_new_syscall
+    },
+    SyscallResultTrait
+};
+use option::OptionTrait;
+
+#[test]
+#[available_gas(100000000)]
+fn test_secp256r1_point_operations() {
+    let generator = get_generator_point();
+    let (x, y) = secp256r1_ec_get_coordinates_syscall(generator).unwrap_syscall();
+
+    // The generator point has an odd y coordinate.
+    let point = secp256r1_ec_get_point_from_x_syscall(x, y_parity: true).unwrap_syscall().unwrap();
+    let (point_x, point_y) = secp256r1_ec_get_coordinates_syscall(point).unwrap_syscall();
+    assert(point_x == x, 'wrong point x');
+    assert(point_y == y, 'wrong point y');
+
+    let expected_x = 0x7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978;
+    let expected_y = 0x07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1;
+    let sum = secp256r1_ec_add_syscall(generator, generator).unwrap_syscall();
+    let (sum_x, sum_y) = secp256r1_ec_get_coordinates_syscall(sum).unwrap_syscall();
+    assert(sum_x == expected_x, 'wrong sum x');
+    assert(sum_y == expected_y, 'wrong sum y');
+    let product = secp256r1_ec_mul_syscall(generator, 2).unwrap_syscall();
+    let (product_x, product_y) = secp256r1_ec_get_coordinates_syscall(product).unwrap_syscall();
+    assert(product_x == expected_x, 'wrong product x');
+    assert(product_y == expected_y, 'wrong product y');
+
+    assert(secp256r1_ec_new_syscall(x, y + 1).unwrap_syscall().is_none(), 'point not on curve');
+}
+
+#[test]
+#[available_gas(100000000)]
+fn test_secp256r1_recover_public_key() {
+    let (msg_hash, r, s, y_parity, expected_public_key_x, expected_public_key_y) =
+        get_message_and_signature();
+    let public_key = recover_public_key(msg_hash, r, s, y_parity).unwrap();
+    let (x, y) = secp256r1_ec_get_coordinates_syscall(public_key).unwrap_syscall();
+    assert(expected_public_key_x == x, 'recover failed 1');
+    assert(expected_public_key_y == y, 'recover failed 2');
+}
+
+#[test]
+#[available_gas(100000000)]
+fn test_secp256r1_is_valid_signature() {
+    let (msg_hash, r, s, _, public_key_x, public_key_y) = get_message_and_signature();
+    let public_key = secp256r1_ec_new_syscall(public_key_x, public_key_y).unwrap_syscall().unwrap();
+    assert(is_valid_signature(msg_hash, r, s, public_key), 'valid signature rejected');
+    assert(!is_valid_signature(msg_hash + 1, r, s, public_key), 'invalid signature accepted');
+    assert(!is_valid_signature(msg_hash, r, get_N(), public_key), 'out of range s accepted');
+}
+
+/// Returns a golden valid message hash, its signature, the parity of the y coordinate of the
+/// signature point, and the public key of the signer, for testing.
+fn get_message_and_signature() -> (u256, u256, u256, bool, u256, u256) {
+    let msg_hash = 0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855;
+    let r = 0x5f697b6fa1e70a2ebba3788c38c5256b1602e2197c55b6a44601b3fc66a57ea9;
+    let s = 0x3771a50f2b6b5e843cb5f4875e6c54b628439ddf87b5b4fe70111e07a03358e6;
+    let public_key_x = 0x817c32bffcf0b7e5fa000e728800cb3b8ad199d1c3e1c3db9a278f0d2d05ebcb;
+    let public_key_y = 0xc89e3d09bf31a8f5e8357797064dc7c664926f26654dad757d6d52df92f367bd;
+
+    (msg_hash, r, s, false, public_key_x, public_key_y)
+}
@@ -1,3 +1,4 @@
+use traits::Into;
 use test::test_utils::{assert_eq, assert_ne, assert_gt};
 
 #[test]
@@ -13,6 +14,20 @@ fn test_assert_false() {
     assert(false, 'assert(false)');
 }
 
+#[test]
+#[should_panic(expected: 'single value')]
+fn test_should_panic_single_value() {
+    assert(false, 'single value');
+}
+
+#[test]
+#[fuzz(runs: 16, seed: 42)]
+fn test_fuzz_add_sub(a: u128, b: u128) {
+    let a: felt252 = a.into();
+    let b: felt252 = b.into();
+    assert_eq(a + b - b, a, 'add sub mismatch');
+}
+
 #[test]
 fn test_assert_true() {
     assert(true, 'assert(true)');
@@ -28,3 +43,9 @@ fn test_get_available_gas_no_gas_supply() {
 fn test_get_available_gas_with_gas_supply() {
     assert_gt(testing::get_available_gas(), 5000, 'high amount of gas used')
 }
+
+#[test]
+#[snapshot]
+fn test_snapshot_output() -> felt252 {
+    'snapshot'
+}
@@ -1,8 +1,20 @@
 use std::fs;
+use std::io::Read;
 use std::path::PathBuf;
 
 use anyhow::Context;
-use cairo_lang_compiler::{compile_cairo_project_at_path, CompilerConfig};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::{DiagnosticsReporter, ErrorFormat, LintLevel};
+use cairo_lang_compiler::fixes::apply_fixes;
+use cairo_lang_compiler::project::{setup_in_memory_project, setup_project};
+use cairo_lang_compiler::timings::{Phase, Timings};
+use cairo_lang_compiler::watch::watch_project;
+use cairo_lang_compiler::{compile_prepared_db, CompilerConfig, SierraProgram};
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_filesystem::db::FilesGroupEx;
+use cairo_lang_sierra_to_casm::compiler::{compile_with_config, CairoProgram, CompilationConfig};
+use cairo_lang_sierra_to_casm::function_sizes::function_sizes;
+use cairo_lang_sierra_to_casm::metadata::calc_metadata;
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 
@@ -11,32 +23,198 @@ use clap::Parser;
 #[derive(Parser, Debug)]
 #[clap(version, verbatim_doc_comment)]
 struct Args {
-    /// The file to compile
+    /// The file to compile, or `-` to compile the code read from stdin, as the `lib.cairo` file of
+    /// a `stdin` crate.
     path: PathBuf,
     /// The output file name (default: stdout).
     output: Option<String>,
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// Keeps the unreachable functions and statements in the Sierra program, instead of removing
+    /// them.
+    #[arg(long, default_value_t = false)]
+    keep_dead_code: bool,
+    /// Folds the constant arithmetic and prunes the statically decided branches of the Sierra
+    /// program.
+    #[arg(short = 'O', long, default_value_t = false)]
+    optimize: bool,
+    /// Inlines the functions without an inline attribute whose lowering has at most this number of
+    /// statements.
+    #[arg(long)]
+    inlining_size_threshold: Option<usize>,
+    /// Reports the compilation progress, including the inlining decision for each function.
+    #[arg(short, long, default_value_t = false)]
+    verbose: bool,
+    /// Prints the number of Sierra statements, CASM instructions, bytecode felts and estimated
+    /// steps of each function, from the largest, instead of the Sierra program.
+    #[arg(long, default_value_t = false, conflicts_with = "output")]
+    size_report: bool,
+    /// Caches the compiled Sierra program in the given directory, and reuses it when compiling
+    /// the same inputs again.
+    #[arg(long)]
+    incremental: Option<PathBuf>,
+    /// A `cfg` option to enable in the compilation, as `name` or `key=value`. May be given
+    /// multiple times.
+    #[arg(long)]
+    cfg: Vec<Cfg>,
+    /// The format of the reported diagnostics: `human`, `json` for a JSON object per diagnostic
+    /// with its code, severity, message, spans and notes, in a line of its own, or `short` for a
+    /// line per diagnostic (default: `human`, or `short` with `--watch`).
+    #[arg(long)]
+    error_format: Option<ErrorFormat>,
+    /// Does not report the warnings of the given code, unless set otherwise by an attribute. May
+    /// be given multiple times.
+    #[arg(long)]
+    allow: Vec<String>,
+    /// Reports the warnings of the given code as warnings, unless set otherwise by an attribute.
+    /// May be given multiple times.
+    #[arg(long)]
+    warn: Vec<String>,
+    /// Reports the warnings of the given code as errors, unless set otherwise by an attribute.
+    /// May be given multiple times.
+    #[arg(long)]
+    deny: Vec<String>,
+    /// Reports all the warnings as errors, failing the compilation.
+    #[arg(long, default_value_t = false)]
+    deny_warnings: bool,
+    /// Reports the diagnostics in code generated by plugins along with the generated code they
+    /// originate in, in the `human` error format.
+    #[arg(long, default_value_t = false)]
+    show_expanded_code: bool,
+    /// Applies the suggested fixes of the diagnostics to the source files, instead of compiling
+    /// them.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["output", "size_report"])]
+    apply_fixes: bool,
+    /// Compiles the project again whenever its files change, with the results of the previous
+    /// compilations that are not affected by the changes.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["size_report", "apply_fixes"])]
+    watch: bool,
+    /// The number of threads to check, lower and Sierra-generate the modules and functions of the
+    /// project on, and to compile its Sierra program to CASM on for `--size-report`.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: u16,
+    /// Reports the wall time and the peak memory of each phase of the compilation of each crate,
+    /// including the compilation of the Sierra program to CASM, to stderr.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["apply_fixes", "watch"])]
+    timings: bool,
+    /// Also writes the timings to the given file in the Chrome trace event format, to view them
+    /// in `chrome://tracing` or Perfetto.
+    #[arg(long, requires = "timings")]
+    timings_trace: Option<PathBuf>,
 }
 
-fn main() -> anyhow::Result<()> {
-    init_logging(log::LevelFilter::Off);
-    log::info!("Starting Cairo compilation.");
+/// The path argument for compiling the code read from stdin.
+const STDIN_PATH: &str = "-";
 
+fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
-    let sierra_program = compile_cairo_project_at_path(
-        &args.path,
-        CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
+    init_logging(if args.verbose { log::LevelFilter::Info } else { log::LevelFilter::Off });
+    log::info!("Starting Cairo compilation.");
+
+    let mut db_builder = RootDatabase::builder();
+    db_builder.detect_corelib();
+    if let Some(threshold) = args.inlining_size_threshold {
+        db_builder.with_inlining_size_threshold(threshold);
+    }
+    let mut db = db_builder.build()?;
+    let lint_levels = args
+        .allow
+        .iter()
+        .map(|code| (code.clone(), LintLevel::Allow))
+        .chain(args.warn.iter().map(|code| (code.clone(), LintLevel::Warn)))
+        .chain(args.deny.iter().map(|code| (code.clone(), LintLevel::Deny)))
+        .collect::<Vec<_>>();
+    let error_format = args.error_format.unwrap_or(if args.watch {
+        ErrorFormat::Short
+    } else {
+        ErrorFormat::Human
+    });
+    let compiler_config = || CompilerConfig {
+        // The functions in the size report are named by their debug names.
+        replace_ids: args.replace_ids || args.size_report,
+        eliminate_dead_code: !args.keep_dead_code,
+        fold_constants: args.optimize,
+        incremental_dir: args.incremental.clone(),
+        diagnostics_reporter: DiagnosticsReporter::stderr()
+            .with_error_format(error_format)
+            .with_lint_levels(lint_levels.clone())
+            .with_deny_warnings(args.deny_warnings)
+            .with_expanded_code(args.show_expanded_code),
+        cfg_set: CfgSet::from_iter(args.cfg.clone()),
+        jobs: args.jobs.into(),
+        ..CompilerConfig::default()
+    };
+    let from_stdin = args.path.as_os_str() == STDIN_PATH;
+    if from_stdin && (args.watch || args.apply_fixes) {
+        anyhow::bail!("The code read from stdin cannot be watched or fixed.");
+    }
+    if args.watch {
+        return watch_project(&mut db, &args.path, |db, main_crate_ids| {
+            let sierra_program = compile_prepared_db(db, main_crate_ids, compiler_config())?;
+            write_output(args.output.as_deref(), &sierra_program)
+        });
+    }
+    let main_crate_ids = if from_stdin {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content).context("Failed to read stdin.")?;
+        vec![setup_in_memory_project(&mut db, "stdin", [("lib.cairo".into(), content)])?]
+    } else {
+        setup_project(&mut db, &args.path)?
+    };
+    if args.apply_fixes {
+        db.use_cfg(&CfgSet::from_iter(args.cfg.clone()));
+        let n_applied = apply_fixes(&db)?;
+        eprintln!("Applied {n_applied} fixes.");
+        return Ok(());
+    }
+    let mut timings = Timings::default();
+    let sierra_program = compile_prepared_db(
+        &mut db,
+        main_crate_ids,
+        CompilerConfig { timings: args.timings.then_some(&mut timings), ..compiler_config() },
     )?;
 
-    match args.output {
+    if args.timings {
+        let jobs = args.jobs.into();
+        timings.measure(Phase::SierraToCasm, None, || compile_to_casm(&sierra_program, jobs))?;
+        timings.print_report(args.timings_trace.as_deref())?;
+    }
+
+    if args.size_report {
+        let cairo_program = compile_to_casm(&sierra_program, args.jobs.into())?;
+        for size in function_sizes(&sierra_program, &cairo_program) {
+            println!(
+                "{}: {} Sierra statements, {} CASM instructions, {} bytecode felts, ~{} steps",
+                size.id,
+                size.sierra_statements,
+                size.casm_instructions,
+                size.bytecode_size,
+                size.estimated_steps
+            );
+        }
+        return Ok(());
+    }
+
+    write_output(args.output.as_deref(), &sierra_program)
+}
+
+/// Compiles the Sierra program to CASM, on `jobs` threads.
+fn compile_to_casm(sierra_program: &SierraProgram, jobs: usize) -> anyhow::Result<CairoProgram> {
+    let metadata = calc_metadata(sierra_program, Default::default())
+        .context("Failed calculating Sierra variables.")?;
+    let config = CompilationConfig { jobs, ..CompilationConfig::default() };
+    compile_with_config(sierra_program, &metadata, config).context("Compilation to CASM failed.")
+}
+
+/// Writes the compiled Sierra program to the output file, or to stdout if none is given.
+fn write_output(output: Option<&str>, sierra_program: &SierraProgram) -> anyhow::Result<()> {
+    match output {
         Some(path) => {
             fs::write(path, format!("{sierra_program}")).context("Failed to write output.")?
         }
         None => println!("{sierra_program}"),
     }
-
     Ok(())
 }
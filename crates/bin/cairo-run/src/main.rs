@@ -1,14 +1,18 @@
 //! Compiles and runs a Cairo program.
 
 use std::path::Path;
+use std::time::Duration;
 
 use anyhow::{Context, Ok};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::setup_project;
 use cairo_lang_diagnostics::ToOption;
-use cairo_lang_runner::short_string::as_cairo_short_string;
-use cairo_lang_runner::{SierraCasmRunner, StarknetState};
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_runner::{
+    Breakpoint, PanicDataDecoder, Repl, ResourceLimits, SierraCasmRunner, StarknetState,
+    SyscallCosts,
+};
 use cairo_lang_sierra::extensions::gas::{
     BuiltinCostWithdrawGasLibfunc, RedepositGasLibfunc, WithdrawGasLibfunc,
 };
@@ -31,12 +35,55 @@ struct Args {
     /// Whether to print the memory.
     #[arg(long, default_value_t = false)]
     print_full_memory: bool,
+    /// A JSON file with the gas costs of the syscalls, overriding the default costs.
+    #[arg(long)]
+    syscall_costs: Option<String>,
+    /// Whether to print the steps and libfunc invocations of the run per function and libfunc.
+    #[arg(long, default_value_t = false)]
+    profile: bool,
+    /// A path to write the steps of the run per call stack to, in the folded stacks format of
+    /// flamegraph tools.
+    #[arg(long)]
+    profile_output: Option<String>,
+    /// A path to write the trace of the run to, in the binary format consumed by the prover.
+    #[arg(long, requires_all = ["memory_file", "public_input_file"])]
+    trace_file: Option<String>,
+    /// A path to write the memory of the run to, in the binary format consumed by the prover.
+    #[arg(long, requires = "trace_file")]
+    memory_file: Option<String>,
+    /// A path to write the public input of the run to, as JSON.
+    #[arg(long, requires = "trace_file")]
+    public_input_file: Option<String>,
+    /// The maximal number of steps of the run.
+    #[arg(long)]
+    max_steps: Option<usize>,
+    /// The maximal number of memory cells of the stack of the run.
+    #[arg(long)]
+    max_memory: Option<usize>,
+    /// The maximal wall time of the run, in seconds.
+    #[arg(long)]
+    timeout: Option<u64>,
+    /// Whether to debug the run, pausing it at its start and reading debugger commands from the
+    /// standard input.
+    #[arg(long, default_value_t = false)]
+    debug: bool,
+    /// A function name suffix or a Sierra statement index to pause the debugged run at. May be
+    /// given multiple times.
+    #[arg(long = "break", requires = "debug")]
+    breakpoints: Vec<Breakpoint>,
+    /// A `cfg` option to enable in the compilation, as `name` or `key=value`. May be given
+    /// multiple times.
+    #[arg(long)]
+    cfg: Vec<Cfg>,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
-    let db = &mut RootDatabase::builder().detect_corelib().build()?;
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_cfg(CfgSet::from_iter(args.cfg))
+        .build()?;
 
     let main_crate_ids = setup_project(db, Path::new(&args.path))?;
 
@@ -69,33 +116,81 @@ fn main() -> anyhow::Result<()> {
         if args.available_gas.is_some() { Some(Default::default()) } else { None },
         contracts_info,
     )
-    .with_context(|| "Failed setting up runner.")?;
-    let result = runner
-        .run_function(
-            runner.find_function("::main")?,
-            &[],
-            args.available_gas,
-            StarknetState::default(),
-        )
-        .with_context(|| "Failed to run the function.")?;
+    .with_context(|| "Failed setting up runner.")?
+    .with_profiling(args.profile || args.profile_output.is_some())
+    .with_trace_export(args.trace_file.is_some())
+    .with_resource_limits(ResourceLimits {
+        max_steps: args.max_steps,
+        max_memory: args.max_memory,
+        timeout: args.timeout.map(Duration::from_secs),
+    });
+    let syscall_costs = match &args.syscall_costs {
+        Some(path) => SyscallCosts::from_file(Path::new(path))
+            .with_context(|| format!("Failed to read syscall costs from {path}."))?,
+        None => SyscallCosts::default(),
+    };
+    let func = runner.find_function("::main")?;
+    let starknet_state = StarknetState::default().with_syscall_costs(syscall_costs);
+    let result = if args.debug {
+        let mut repl = Repl::new(std::io::stdin().lock(), std::io::stdout());
+        for breakpoint in &args.breakpoints {
+            repl.add_breakpoint(breakpoint.statement_idx(&runner)?);
+        }
+        runner.debug_function(func, &[], args.available_gas, starknet_state, &mut repl)
+    } else {
+        runner.run_function(func, &[], args.available_gas, starknet_state)
+    }
+    .with_context(|| "Failed to run the function.")?;
     match result.value {
         cairo_lang_runner::RunResultValue::Success(values) => {
             println!("Run completed successfully, returning {values:?}")
         }
         cairo_lang_runner::RunResultValue::Panic(values) => {
-            print!("Run panicked with [");
-            for value in &values {
-                match as_cairo_short_string(value) {
-                    Some(as_string) => print!("{value} ('{as_string}'), "),
-                    None => print!("{value}, "),
-                }
-            }
-            println!("].")
+            println!("Run panicked with {}.", PanicDataDecoder::default().format(&values))
         }
     }
     if let Some(gas) = result.gas_counter {
         println!("Remaining gas: {gas}");
     }
+    for (syscall, count) in result.starknet_state.syscall_counter() {
+        println!("Syscall {syscall}: {count}");
+    }
+    for event in result.starknet_state.events() {
+        println!("{event}");
+    }
+    if let Some(profiling_info) = &result.profiling_info {
+        if args.profile {
+            println!(
+                "Steps: {}, memory holes: {}",
+                result.used_resources.n_steps, result.used_resources.n_memory_holes
+            );
+            println!("Steps per function:");
+            for (function, steps) in profiling_info.function_steps() {
+                println!("   {function}: {steps}");
+            }
+            println!("Steps per libfunc:");
+            for (libfunc, steps) in profiling_info.libfunc_steps() {
+                let invocations = profiling_info.libfunc_invocations.get(libfunc).unwrap_or(&0);
+                println!("   {libfunc}: {steps} ({invocations} invocations)");
+            }
+        }
+        if let Some(path) = &args.profile_output {
+            std::fs::write(path, profiling_info.folded_stacks())
+                .with_context(|| format!("Failed to write the profile to {path}."))?;
+        }
+    }
+    if let (Some(prover_input), Some(trace_file), Some(memory_file), Some(public_input_file)) =
+        (&result.prover_input, &args.trace_file, &args.memory_file, &args.public_input_file)
+    {
+        prover_input
+            .write_files(
+                &result.memory,
+                Path::new(trace_file),
+                Path::new(memory_file),
+                Path::new(public_input_file),
+            )
+            .with_context(|| "Failed to write the trace of the run.")?;
+    }
     if args.print_full_memory {
         print!("Full memory: [");
         for cell in &result.memory {
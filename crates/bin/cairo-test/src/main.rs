@@ -1,7 +1,12 @@
 //! Compiles and runs a Cairo program.
 
+use std::path::PathBuf;
+use std::time::Duration;
+
 use anyhow::Ok;
-use cairo_lang_test_runner::TestRunner;
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_runner::{ForkConfig, ResourceLimits};
+use cairo_lang_test_runner::{TestOutputFormat, TestRunner, TestRunnerConfig};
 use clap::Parser;
 
 /// Command line args parser.
@@ -14,6 +19,9 @@ struct Args {
     /// The filter for the tests, running only tests containing the filter string.
     #[arg(short, long, default_value_t = String::default())]
     filter: String,
+    /// Should we run only the tests whose full name exactly matches the filter.
+    #[arg(long, default_value_t = false)]
+    exact: bool,
     /// Should we run ignored tests as well.
     #[arg(long, default_value_t = false)]
     include_ignored: bool,
@@ -23,6 +31,63 @@ struct Args {
     /// Should we add the starknet plugin to run the tests.
     #[arg(long, default_value_t = false)]
     starknet: bool,
+    /// Should we report the external functions of the contracts that take the storage by `ref`
+    /// but never modify it.
+    #[arg(long, default_value_t = false, requires = "starknet")]
+    lint_storage_mutability: bool,
+    /// Should we print the gas used by each test.
+    #[arg(long, default_value_t = false)]
+    gas_report: bool,
+    /// A path to write the gas used by each test to, as JSON.
+    #[arg(long)]
+    gas_report_json: Option<String>,
+    /// Should we write the line and branch coverage of the tests, in lcov format.
+    #[arg(long, default_value_t = false)]
+    coverage: bool,
+    /// The path to write the coverage of the tests to.
+    #[arg(long, default_value = "lcov.info")]
+    coverage_path: String,
+    /// Should we update the snapshots of the tests to their output instead of comparing them.
+    #[arg(long, default_value_t = false)]
+    update_snapshots: bool,
+    /// The URL of the JSON-RPC endpoint of a network to fork, running the tests against its state.
+    #[arg(long, requires = "fork_block")]
+    fork_url: Option<String>,
+    /// The number of the block of the forked network whose state the tests run against.
+    #[arg(long, requires = "fork_url")]
+    fork_block: Option<u64>,
+    /// The directory to cache the responses of the forked network in.
+    #[arg(long, default_value = "fork_cache")]
+    fork_cache_dir: PathBuf,
+    /// Should we print the output of the tests as they run, instead of showing the output of the
+    /// failed tests.
+    #[arg(long, default_value_t = false)]
+    nocapture: bool,
+    /// Should we run the benchmarks instead of the tests.
+    #[arg(long, default_value_t = false)]
+    bench: bool,
+    /// A path to a baseline to compare the benchmarks with, as JSON.
+    #[arg(long, requires = "bench")]
+    bench_baseline: Option<String>,
+    /// Should we store the benchmarks as the baseline instead of comparing them.
+    #[arg(long, default_value_t = false, requires = "bench_baseline")]
+    save_bench_baseline: bool,
+    /// The format to print the results of the tests in: `pretty`, `json` or `junit`.
+    #[arg(long, default_value = "pretty")]
+    format: TestOutputFormat,
+    /// The maximal number of steps of each run, failing the runs exceeding it.
+    #[arg(long)]
+    max_steps: Option<usize>,
+    /// The maximal number of memory cells of the stack of each run, failing the runs exceeding it.
+    #[arg(long)]
+    max_memory: Option<usize>,
+    /// The maximal wall time of each run, in seconds, failing the runs exceeding it.
+    #[arg(long)]
+    timeout: Option<u64>,
+    /// A `cfg` option to enable in the compilation of the tests, in addition to `test`, as `name`
+    /// or `key=value`. May be given multiple times.
+    #[arg(long)]
+    cfg: Vec<Cfg>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -30,10 +95,34 @@ fn main() -> anyhow::Result<()> {
 
     let runner = TestRunner::new(
         &args.path,
-        &args.filter,
-        args.include_ignored,
-        args.ignored,
-        args.starknet,
+        TestRunnerConfig {
+            filter: args.filter,
+            exact: args.exact,
+            include_ignored: args.include_ignored,
+            ignored: args.ignored,
+            starknet: args.starknet,
+            lint_storage_mutability: args.lint_storage_mutability,
+            cfg_set: CfgSet::from_iter(args.cfg),
+            gas_report: args.gas_report,
+            gas_report_json: args.gas_report_json,
+            coverage: args.coverage.then_some(args.coverage_path),
+            update_snapshots: args.update_snapshots,
+            fork: args.fork_url.zip(args.fork_block).map(|(url, block_number)| ForkConfig {
+                url,
+                block_number,
+                cache_dir: Some(args.fork_cache_dir),
+            }),
+            nocapture: args.nocapture,
+            bench: args.bench,
+            bench_baseline: args.bench_baseline,
+            save_bench_baseline: args.save_bench_baseline,
+            format: args.format,
+            resource_limits: ResourceLimits {
+                max_steps: args.max_steps,
+                max_memory: args.max_memory,
+                timeout: args.timeout.map(Duration::from_secs),
+            },
+        },
     )?;
     runner.run()?;
 
@@ -2,7 +2,11 @@ use std::fs;
 
 use anyhow::Context;
 use cairo_lang_sierra::ProgramParser;
+use cairo_lang_sierra_to_casm::compiler::{
+    compile_with_config, CompilationConfig, OptimizationLevel,
+};
 use cairo_lang_sierra_to_casm::metadata::calc_metadata;
+use cairo_lang_sierra_to_casm::stack_usage::stack_usage;
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 use indoc::indoc;
@@ -15,6 +19,21 @@ struct Args {
     /// The file to compile
     file: String,
     output: String,
+    /// The optimization level of the generated casm: 0 emits the casm of each statement as is, 1
+    /// applies peephole optimizations to it.
+    #[arg(
+        short = 'O',
+        long,
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u8).range(0..=1)
+    )]
+    opt_level: u8,
+    /// The number of threads to compile the functions of the program on.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: u16,
+    /// Prints the worst-case ap change and call depth of each function, flagging recursion.
+    #[arg(long, default_value_t = false)]
+    stack_report: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -30,12 +49,19 @@ fn main() -> anyhow::Result<()> {
             Note: StarkNet contracts should be compiled with `starknet-sierra-compile`."
     })};
 
-    let gas_usage_check = true;
-    let cairo_program = cairo_lang_sierra_to_casm::compiler::compile(
+    let optimization_level =
+        if args.opt_level == 0 { OptimizationLevel::O0 } else { OptimizationLevel::O1 };
+    let metadata = calc_metadata(&program, Default::default())
+        .with_context(|| "Failed calculating Sierra variables.")?;
+    if args.stack_report {
+        for function in stack_usage(&program, &metadata)? {
+            println!("{function}");
+        }
+    }
+    let cairo_program = compile_with_config(
         &program,
-        &calc_metadata(&program, Default::default())
-            .with_context(|| "Failed calculating Sierra variables.")?,
-        gas_usage_check,
+        &metadata,
+        CompilationConfig { gas_usage_check: true, optimization_level, jobs: args.jobs.into() },
     )
     .with_context(|| "Compilation failed.")?;
 
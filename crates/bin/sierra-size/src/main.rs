@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use anyhow::Context;
+use cairo_lang_starknet::contract_class::ContractClass;
+use cairo_lang_starknet::contract_size::ContractSizeReport;
+use clap::Parser;
+
+/// Command line args parser.
+/// Prints the breakdown of the size of a contract class, and of the bytecode compiled from it, by
+/// function, libfunc category and origin of the code.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The contract class file.
+    file: String,
+    /// Fails if the bytecode compiled from the contract class is larger than this number of felts.
+    #[arg(long)]
+    max_bytecode_size: Option<usize>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let contract_class = ContractClass::from_file(Path::new(&args.file))?;
+    let report = ContractSizeReport::new(&contract_class).with_context(|| "Compilation failed.")?;
+    print!("{report}");
+    if let Some(max_bytecode_size) = args.max_bytecode_size {
+        report.check_bytecode_size(max_bytecode_size)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::fs;
+
+use anyhow::Context;
+use cairo_lang_starknet::abi::diff::abi_diff;
+use cairo_lang_starknet::abi::validation::parse_abi;
+use cairo_lang_starknet::abi::Contract;
+use clap::Parser;
+
+/// Command line args parser.
+/// Prints the changes between two versions of a contract ABI, each classified as breaking or
+/// compatible. Exits with 0/1 if the new version is compatible/incompatible with the old one.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The file of the old version of the ABI.
+    old: String,
+    /// The file of the new version of the ABI.
+    new: String,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let old = read_abi(&args.old)?;
+    let new = read_abi(&args.new)?;
+    let changes = abi_diff(&old, &new);
+    for change in &changes {
+        println!("{change}");
+    }
+    if changes.iter().any(|change| change.is_breaking()) {
+        anyhow::bail!("The new ABI is not compatible with the old one.");
+    }
+
+    Ok(())
+}
+
+/// Reads the ABI in the given file.
+fn read_abi(path: &str) -> anyhow::Result<Contract> {
+    let abi = fs::read_to_string(path).with_context(|| format!("Failed to read {path}."))?;
+    parse_abi(&abi).with_context(|| format!("Invalid ABI in {path}."))
+}
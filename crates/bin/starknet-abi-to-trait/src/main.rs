@@ -0,0 +1,34 @@
+use std::fs;
+
+use anyhow::Context;
+use cairo_lang_starknet::abi::validation::parse_abi;
+use cairo_lang_starknet::abi_to_trait::abi_to_trait;
+use clap::Parser;
+
+/// Command line args parser.
+/// Generates the Cairo interface trait of a contract, from which its dispatchers are generated,
+/// given the ABI of the contract.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The file of the ABI of the contract.
+    file: String,
+    /// The name of the generated trait.
+    #[arg(long)]
+    trait_name: String,
+    /// The output file name (default: stdout).
+    output: Option<String>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let abi = fs::read_to_string(&args.file).with_context(|| "Failed to read ABI file.")?;
+    let abi = parse_abi(&abi).with_context(|| "Invalid ABI.")?;
+    let res = abi_to_trait(&abi, &args.trait_name).with_context(|| "Trait generation failed.")?;
+    match args.output {
+        Some(path) => fs::write(path, res).with_context(|| "Failed to write output.")?,
+        None => print!("{res}"),
+    }
+
+    Ok(())
+}
@@ -1,10 +1,23 @@
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use anyhow::Context;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::{DiagnosticsReporter, ErrorFormat, LintLevel};
+use cairo_lang_compiler::timings::Timings;
+use cairo_lang_compiler::watch::watch_project;
 use cairo_lang_compiler::CompilerConfig;
-use cairo_lang_starknet::allowed_libfuncs::ListSelector;
-use cairo_lang_starknet::contract_class::starknet_compile;
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_starknet::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
+use cairo_lang_starknet::contract_class::{
+    apply_fixes_in_path, compile_contract_in_prepared_db, compile_path, profile_artifacts_dir,
+    starknet_compile_contracts,
+};
+use cairo_lang_starknet::plugin::StarkNetPlugin;
+use cairo_lang_starknet::reproducibility::find_nondeterminism;
+use cairo_lang_starknet::source_map::CasmSourceMap;
+use cairo_lang_starknet::storage_layout::storage_layout_in_path;
 use clap::Parser;
 
 /// Command line args parser.
@@ -14,37 +27,286 @@ use clap::Parser;
 struct Args {
     /// The crate to compile.
     path: PathBuf,
-    // The contract fully qualified path.
+    /// The contract fully qualified path. May be given multiple times together with
+    /// `--output-dir` or `--target-dir`.
     #[arg(short, long)]
-    contract_path: Option<String>,
+    contract_path: Vec<String>,
     /// The output file name (default: stdout).
     output: Option<String>,
+    /// Writes a separate `<name>.contract_class.json` artifact, in the format in which the class
+    /// is declared on the network, for each contract in the crate (or for each of the
+    /// contracts given by `--contract-path`) into this directory. The name is given by the
+    /// `name` argument of the `#[contract]` attribute, or else derived from the path of the
+    /// contract. Also writes a `starknet_artifacts.json` manifest listing the contracts, with
+    /// the file names of their artifacts, their class hashes and their ABIs.
+    #[arg(long, conflicts_with = "output")]
+    output_dir: Option<PathBuf>,
+    /// Writes the artifacts as `--output-dir` does, into the `<target-dir>/<profile>` directory.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "output", "output_dir", "print_abi_signatures", "storage_layout", "debug_info",
+            "apply_fixes", "watch"
+        ]
+    )]
+    target_dir: Option<PathBuf>,
+    /// The build profile whose directory in `--target-dir` the artifacts are written into.
+    #[arg(long, default_value = "dev")]
+    profile: String,
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// Keeps the unreachable functions and statements in the Sierra program, instead of removing
+    /// them.
+    #[arg(long, default_value_t = false)]
+    keep_dead_code: bool,
+    /// Folds the constant arithmetic and prunes the statically decided branches of the Sierra
+    /// program.
+    #[arg(short = 'O', long, default_value_t = false)]
+    optimize: bool,
     /// The allowed libfuncs list to use (default: most recent audited list).
     #[arg(long)]
     allowed_libfuncs_list_name: Option<String>,
     /// A file of the allowed libfuncs list to use.
     #[arg(long)]
     allowed_libfuncs_list_file: Option<String>,
+    /// Prints a one-line signature of each entry point and event of the contract, along with its
+    /// selector, instead of the contract class.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["output", "output_dir"])]
+    print_abi_signatures: bool,
+    /// Writes the storage layout of the contract, with the base address of each of its storage
+    /// variables, into this file, e.g. to decode storage dumps of the runner.
+    #[arg(long, conflicts_with = "output_dir")]
+    storage_layout: Option<PathBuf>,
+    /// Writes the source map of the contract into this file: the range of the CASM bytecode
+    /// compiled from each Sierra statement, along with the span of the Cairo code it was generated
+    /// from, e.g. for profilers, debuggers and coverage tools.
+    #[arg(long, conflicts_with = "output_dir")]
+    debug_info: Option<PathBuf>,
+    /// Writes the Sierra program of the contract into this file, in the compact binary encoding
+    /// of Sierra programs, which is smaller and faster to decode than its felt252s.
+    #[arg(long, conflicts_with = "output_dir")]
+    sierra_binary: Option<PathBuf>,
+    /// A `cfg` option to enable in the compilation, as `name` or `key=value`. May be given
+    /// multiple times.
+    #[arg(long)]
+    cfg: Vec<Cfg>,
+    /// The format of the reported diagnostics: `human`, `json` for a JSON object per diagnostic
+    /// with its code, severity, message, spans and notes, in a line of its own, or `short` for a
+    /// line per diagnostic (default: `human`, or `short` with `--watch`).
+    #[arg(long)]
+    error_format: Option<ErrorFormat>,
+    /// Does not report the warnings of the given code, unless set otherwise by an attribute. May
+    /// be given multiple times.
+    #[arg(long)]
+    allow: Vec<String>,
+    /// Reports the warnings of the given code as warnings, unless set otherwise by an attribute.
+    /// May be given multiple times.
+    #[arg(long)]
+    warn: Vec<String>,
+    /// Reports the warnings of the given code as errors, unless set otherwise by an attribute.
+    /// May be given multiple times.
+    #[arg(long)]
+    deny: Vec<String>,
+    /// Reports all the warnings as errors, failing the compilation.
+    #[arg(long, default_value_t = false)]
+    deny_warnings: bool,
+    /// Reports the external functions that take the storage by `ref` but never modify it.
+    #[arg(long, default_value_t = false)]
+    lint_storage_mutability: bool,
+    /// Reports the diagnostics in code generated by plugins along with the generated code they
+    /// originate in, in the `human` error format.
+    #[arg(long, default_value_t = false)]
+    show_expanded_code: bool,
+    /// Applies the suggested fixes of the diagnostics to the source files, instead of compiling
+    /// the contract.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["output", "output_dir"])]
+    apply_fixes: bool,
+    /// Compiles the contract again whenever the files of its crate change, with the results of the
+    /// previous compilations that are not affected by the changes.
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with_all = [
+            "output_dir", "print_abi_signatures", "storage_layout", "debug_info", "apply_fixes"
+        ]
+    )]
+    watch: bool,
+    /// The number of threads to check, lower and Sierra-generate the modules and the contracts of
+    /// the project on.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: u16,
+    /// Compiles the contract twice, and fails if the contract classes differ, or hold absolute
+    /// paths or timestamps, listing each such source of nondeterminism.
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with_all = ["output_dir", "target_dir", "print_abi_signatures", "watch"]
+    )]
+    reproducible: bool,
+    /// Reports the wall time and the peak memory of each phase of the compilation of each crate,
+    /// and of the Sierra generation of each contract, to stderr.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["print_abi_signatures", "watch"])]
+    timings: bool,
+    /// Also writes the timings to the given file in the Chrome trace event format, to view them
+    /// in `chrome://tracing` or Perfetto.
+    #[arg(long, requires = "timings")]
+    timings_trace: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    if args.apply_fixes {
+        let n_applied = apply_fixes_in_path(&args.path, CfgSet::from_iter(args.cfg))?;
+        eprintln!("Applied {n_applied} fixes.");
+        return Ok(());
+    }
     let list_selector =
         ListSelector::new(args.allowed_libfuncs_list_name, args.allowed_libfuncs_list_file)
             .expect("Both allowed libfunc list name and file were supplied.");
-    let res = starknet_compile(
-        args.path,
-        args.contract_path,
-        Some(CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() }),
-        Some(list_selector),
-    )?;
-    match args.output {
+    let lint_levels = args
+        .allow
+        .iter()
+        .map(|code| (code.clone(), LintLevel::Allow))
+        .chain(args.warn.iter().map(|code| (code.clone(), LintLevel::Warn)))
+        .chain(args.deny.iter().map(|code| (code.clone(), LintLevel::Deny)))
+        .collect::<Vec<_>>();
+    let plugin = if args.lint_storage_mutability {
+        StarkNetPlugin::default().with_storage_mutability_lint()
+    } else {
+        StarkNetPlugin::default()
+    };
+    let error_format = args.error_format.unwrap_or(if args.watch {
+        ErrorFormat::Short
+    } else {
+        ErrorFormat::Human
+    });
+    let compiler_config = || CompilerConfig {
+        replace_ids: args.replace_ids,
+        eliminate_dead_code: !args.keep_dead_code,
+        fold_constants: args.optimize,
+        diagnostics_reporter: DiagnosticsReporter::stderr()
+            .with_error_format(error_format)
+            .with_lint_levels(lint_levels.clone())
+            .with_deny_warnings(args.deny_warnings)
+            .with_expanded_code(args.show_expanded_code),
+        cfg_set: CfgSet::from_iter(args.cfg.clone()),
+        jobs: args.jobs.into(),
+        ..CompilerConfig::default()
+    };
+    let output_dir = args.output_dir.clone().or_else(|| {
+        args.target_dir.as_ref().map(|target_dir| profile_artifacts_dir(target_dir, &args.profile))
+    });
+    if args.contract_path.len() > 1 && output_dir.is_none() {
+        anyhow::bail!("Use --output-dir or --target-dir to compile more than one contract.");
+    }
+    if args.watch {
+        let mut db = RootDatabase::builder()
+            .detect_corelib()
+            .with_cfg(CfgSet::from_iter(args.cfg.clone()))
+            .with_semantic_plugin(Arc::new(plugin.clone()))
+            .build()?;
+        let contract_path = args.contract_path.first().map(String::as_str);
+        return watch_project(&mut db, &args.path, |db, main_crate_ids| {
+            let contract = compile_contract_in_prepared_db(
+                db,
+                contract_path,
+                main_crate_ids,
+                compiler_config(),
+            )?;
+            validate_compatible_sierra_version(&contract, list_selector.clone())?;
+            let res =
+                serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")?;
+            write_output(args.output.as_deref(), res)
+        });
+    }
+    let mut timings = Timings::default();
+    let config = CompilerConfig {
+        add_source_map: args.debug_info.is_some(),
+        timings: args.timings.then_some(&mut timings),
+        ..compiler_config()
+    };
+    if let Some(output_dir) = output_dir {
+        starknet_compile_contracts(
+            args.path,
+            &args.contract_path,
+            Some(config),
+            Some(list_selector),
+            &output_dir,
+            plugin,
+        )?;
+        if args.timings {
+            timings.print_report(args.timings_trace.as_deref())?;
+        }
+        return Ok(());
+    }
+    if let Some(storage_layout_path) = &args.storage_layout {
+        let layout =
+            storage_layout_in_path(&args.path, args.contract_path.first().map(String::as_str))?;
+        fs::write(storage_layout_path, layout.json())
+            .with_context(|| "Failed to write the storage layout.")?;
+    }
+    let contract_path = args.contract_path.first().map(String::as_str);
+    if args.print_abi_signatures {
+        let contract = compile_path(&args.path, contract_path, config, plugin)?;
+        for signature in contract.abi.unwrap_or_default().signatures() {
+            println!("{signature}");
+        }
+        return Ok(());
+    }
+    let mut contract = compile_path(&args.path, contract_path, config, plugin.clone())?;
+    if args.reproducible {
+        // The second compilation uses a database of its own, and its diagnostics were already
+        // reported by the first one.
+        let second_contract = compile_path(
+            &args.path,
+            contract_path,
+            CompilerConfig {
+                diagnostics_reporter: DiagnosticsReporter::ignoring(),
+                add_source_map: args.debug_info.is_some(),
+                ..compiler_config()
+            },
+            plugin,
+        )?;
+        let found = find_nondeterminism(
+            &serde_json::to_value(&contract)?,
+            &serde_json::to_value(&second_contract)?,
+        );
+        for nondeterminism in &found {
+            eprintln!("{nondeterminism}");
+        }
+        if !found.is_empty() {
+            anyhow::bail!(
+                "The compilation is not reproducible: found {} sources of nondeterminism.",
+                found.len()
+            );
+        }
+        eprintln!("The compilation is reproducible.");
+    }
+    validate_compatible_sierra_version(&contract, list_selector)?;
+    if let Some(debug_info_path) = &args.debug_info {
+        let source_map = CasmSourceMap::from_contract_class(&contract)?;
+        fs::write(debug_info_path, serde_json::to_string_pretty(&source_map)?)
+            .with_context(|| "Failed to write the debug info.")?;
+        // The source map is written as a separate artifact, rather than as part of the class.
+        contract.sierra_program_source_map = None;
+    }
+    if let Some(sierra_binary_path) = &args.sierra_binary {
+        fs::write(sierra_binary_path, contract.sierra_program_binary()?)
+            .with_context(|| "Failed to write the Sierra program.")?;
+    }
+    let res = serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")?;
+    if args.timings {
+        timings.print_report(args.timings_trace.as_deref())?;
+    }
+    write_output(args.output.as_deref(), res)
+}
+
+/// Writes the compiled contract class to the output file, or to stdout if none is given.
+fn write_output(output: Option<&str>, res: String) -> anyhow::Result<()> {
+    match output {
         Some(path) => fs::write(path, res).with_context(|| "Failed to write output.")?,
         None => println!("{res}"),
     }
-
     Ok(())
 }
@@ -0,0 +1,52 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use cairo_lang_starknet::casm_contract_class::{bytecode_annotations, CasmContractClass};
+use cairo_lang_starknet::contract_class::ContractClass;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use clap::Parser;
+
+/// Command line args parser.
+/// Disassembles the bytecode of a compiled contract class into readable CASM.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The compiled contract class file.
+    file: String,
+    /// The output file name (default: stdout).
+    output: Option<String>,
+    /// The contract class the compiled contract class was compiled from. Annotates the
+    /// instructions by the functions and Sierra statements compiled into them, named by the debug
+    /// information of the contract class.
+    #[arg(long)]
+    contract_class: Option<String>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let casm_contract = CasmContractClass::from_file(Path::new(&args.file))?;
+    let annotations = match &args.contract_class {
+        Some(path) => {
+            let contract_class = ContractClass::from_file(Path::new(path))?;
+            let annotations = bytecode_annotations(&contract_class)
+                .with_context(|| "Compilation of the contract class failed.")?;
+            let add_pythonic_hints = false;
+            let compiled_contract =
+                CasmContractClass::from_contract_class(contract_class, add_pythonic_hints)
+                    .with_context(|| "Compilation of the contract class failed.")?;
+            if compiled_contract.bytecode != casm_contract.bytecode {
+                anyhow::bail!("The compiled contract class was not compiled from {path}.");
+            }
+            annotations
+        }
+        None => OrderedHashMap::default(),
+    };
+    let res = casm_contract.disassemble(&annotations).with_context(|| "Disassembly failed.")?;
+
+    match args.output {
+        Some(path) => fs::write(path, res).with_context(|| "Failed to write the disassembly.")?,
+        None => print!("{res}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use cairo_lang_starknet::contract::{entry_point_selector, event_selector};
+use clap::Parser;
+
+/// Command line args parser.
+/// Prints the selector of an entry point, or of an event if `--event` is given.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The name of the entry point or event.
+    name: String,
+    /// Prints the selector of the event with the given name.
+    #[arg(long, default_value_t = false)]
+    event: bool,
+    /// The namespace of the selector of the event, for contracts with namespaced event selectors.
+    #[arg(long, requires = "event")]
+    namespace: Option<String>,
+}
+
+fn main() {
+    let args = Args::parse();
+    let selector = if args.event {
+        event_selector(&args.name, args.namespace.as_deref())
+    } else {
+        entry_point_selector(&args.name)
+    };
+    println!("0x{selector:x}");
+}
@@ -1,9 +1,13 @@
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use cairo_lang_starknet::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
-use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use cairo_lang_starknet::casm_cache::CasmCache;
+use cairo_lang_starknet::casm_contract_class::{CasmContractClass, HintsFormat};
+use cairo_lang_starknet::class_hash::compute_compiled_class_hash;
 use cairo_lang_starknet::contract_class::ContractClass;
+use cairo_lang_starknet::contract_size::ContractSizeReport;
 use clap::Parser;
 
 /// Command line args parser.
@@ -24,6 +28,25 @@ struct Args {
     /// Add pythonic hints.
     #[arg(long, default_value_t = false)]
     add_pythonic_hints: bool,
+    /// Serializes the hints in the legacy pythonic representation, in place of the structured
+    /// hints, for older sequencers and tooling.
+    #[arg(long, default_value_t = false, conflicts_with = "add_pythonic_hints")]
+    legacy_pythonic_hints: bool,
+    /// Prints the compiled class hash of the contract instead of the compiled contract. The
+    /// compiled contract is still written if an output file name is given.
+    #[arg(long, default_value_t = false)]
+    print_compiled_class_hash: bool,
+    /// Reuses the compiled contract if the same contract was compiled before, caching the compiled
+    /// contracts by their Sierra class hash.
+    #[arg(long, default_value_t = false)]
+    cache: bool,
+    /// The directory to cache the compiled contracts in (default: the user cache directory).
+    #[arg(long, requires = "cache")]
+    cache_dir: Option<PathBuf>,
+    /// Fails the compilation if the bytecode of the compiled contract is larger than this number
+    /// of felts, reporting its largest functions.
+    #[arg(long)]
+    max_bytecode_size: Option<usize>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -31,22 +54,48 @@ fn main() -> anyhow::Result<()> {
     let list_selector =
         ListSelector::new(args.allowed_libfuncs_list_name, args.allowed_libfuncs_list_file)
             .expect("Both allowed libfunc list name and file were supplied.");
-    let contract_class: ContractClass = serde_json::from_str(
-        &fs::read_to_string(&args.file)
-            .with_context(|| format!("Failed to read {}.", &args.file))?,
-    )
-    .with_context(|| "deserialization Failed.")?;
+    let contract_class = ContractClass::from_file(Path::new(&args.file))?;
     validate_compatible_sierra_version(&contract_class, list_selector)?;
-    let casm_contract =
-        CasmContractClass::from_contract_class(contract_class, args.add_pythonic_hints)
-            .with_context(|| "Compilation failed.")?;
+    let cache = match args.cache_dir {
+        Some(cache_dir) => Some(CasmCache::new(cache_dir)),
+        None if args.cache => Some(
+            CasmCache::in_user_cache_dir()
+                .with_context(|| "Could not find the user cache directory.")?,
+        ),
+        None => None,
+    };
+    let casm_contract = match cache {
+        Some(cache) => cache.compile(contract_class.clone(), args.add_pythonic_hints),
+        None => {
+            CasmContractClass::from_contract_class(contract_class.clone(), args.add_pythonic_hints)
+                .map_err(anyhow::Error::from)
+        }
+    }
+    .with_context(|| "Compilation failed.")?;
+    if let Some(max_bytecode_size) = args.max_bytecode_size {
+        // The size of the contract is only analyzed to attribute the excess size.
+        if casm_contract.bytecode.len() > max_bytecode_size {
+            ContractSizeReport::new(&contract_class)
+                .with_context(|| "Compilation failed.")?
+                .check_bytecode_size(max_bytecode_size)?;
+        }
+    }
 
-    let res = serde_json::to_string_pretty(&casm_contract)
+    let hints_format =
+        if args.legacy_pythonic_hints { HintsFormat::Pythonic } else { HintsFormat::Structured };
+    let res = casm_contract
+        .to_json(hints_format)
         .with_context(|| "Casm contract Serialization failed.")?;
 
     match args.output {
         Some(path) => fs::write(path, res).with_context(|| "Failed to write casm contract.")?,
+        None if args.print_compiled_class_hash => {}
         None => println!("{res}"),
     }
+    if args.print_compiled_class_hash {
+        let compiled_class_hash = compute_compiled_class_hash(&casm_contract)
+            .with_context(|| "Failed to compute the compiled class hash.")?;
+        println!("0x{compiled_class_hash:x}");
+    }
     Ok(())
 }
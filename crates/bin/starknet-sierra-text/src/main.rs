@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use cairo_lang_starknet::contract_class::ContractClass;
+use cairo_lang_starknet::sierra_text::{
+    contract_class_from_sierra_text, contract_class_to_sierra_text,
+};
+use clap::{Parser, Subcommand};
+
+/// Command line args parser.
+/// Converts between a contract class and its Sierra program in text form, with a manifest of its
+/// entry points.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+#[allow(clippy::enum_variant_names)]
+enum Command {
+    /// Writes the Sierra program of a contract class and the manifest of its entry points.
+    ToText {
+        /// The contract class file.
+        contract_class: String,
+        /// The output Sierra program file.
+        sierra: String,
+        /// The output entry points manifest file.
+        manifest: String,
+    },
+    /// Writes the contract class of a Sierra program and the manifest of its entry points.
+    FromText {
+        /// The Sierra program file.
+        sierra: String,
+        /// The entry points manifest file.
+        manifest: String,
+        /// The output contract class file.
+        contract_class: String,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    match Args::parse().command {
+        Command::ToText { contract_class, sierra, manifest } => {
+            let contract_class = ContractClass::from_file(Path::new(&contract_class))?;
+            let (sierra_text, entry_points_manifest) =
+                contract_class_to_sierra_text(&contract_class)?;
+            fs::write(sierra, sierra_text)
+                .with_context(|| "Failed to write the Sierra program.")?;
+            fs::write(
+                manifest,
+                serde_json::to_string_pretty(&entry_points_manifest)
+                    .with_context(|| "Manifest serialization failed.")?,
+            )
+            .with_context(|| "Failed to write the manifest.")
+        }
+        Command::FromText { sierra, manifest, contract_class } => {
+            let sierra_text =
+                fs::read_to_string(&sierra).with_context(|| format!("Failed to read {sierra}."))?;
+            let entry_points_manifest = serde_json::from_str(
+                &fs::read_to_string(&manifest)
+                    .with_context(|| format!("Failed to read {manifest}."))?,
+            )
+            .with_context(|| "Failed to deserialize the manifest.")?;
+            contract_class_from_sierra_text(&sierra_text, &entry_points_manifest)?
+                .to_file(Path::new(&contract_class))
+        }
+    }
+}
@@ -834,6 +834,20 @@ macro_rules! casm_build_extend {
         );
         $crate::casm_build_extend!($builder, $($tok)*)
     };
+    ($builder:ident, hint $hint_lead:ident::$hint_name:ident {
+            $($input_name:ident : $input_value:ident),*
+        } into {
+            $($output_name:ident : $output_value:ident),*
+        }; $($tok:tt)*) => {
+        $builder.add_hint(
+            |[$($input_name),*], [$($output_name),*]| $hint_lead::$hint_name {
+                $($input_name,)* $($output_name,)*
+            },
+            [$($input_value,)*],
+            [$($output_value,)*],
+        );
+        $crate::casm_build_extend!($builder, $($tok)*)
+    };
     ($builder:ident, hint $hint_lead:ident::$hint_name:ident {
         $($arg_name:ident : $arg_value:ident),*
     }; $($tok:tt)*) => {
@@ -0,0 +1,229 @@
+use std::fmt::Write;
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use thiserror::Error;
+
+use crate::assembler::{ApUpdate, FpUpdate, InstructionRepr, Op1Addr, Opcode, PcUpdate, Res};
+use crate::encoder::{
+    AP_ADD1_BIT, AP_ADD_BIT, DST_REG_BIT, OFFSET_BITS, OP0_REG_BIT, OP1_AP_BIT, OP1_FP_BIT,
+    OP1_IMM_BIT, OPCODE_ASSERT_EQ_BIT, OPCODE_CALL_BIT, OPCODE_RET_BIT, PC_JNZ_BIT,
+    PC_JUMP_ABS_BIT, PC_JUMP_REL_BIT, RES_ADD_BIT, RES_MUL_BIT,
+};
+use crate::hints::Hint;
+use crate::instructions::{
+    AddApInstruction, AssertEqInstruction, CallInstruction, Instruction, InstructionBody,
+    JnzInstruction, JumpInstruction, RetInstruction,
+};
+use crate::operand::{BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand};
+
+#[cfg(test)]
+#[path = "decoder_test.rs"]
+mod test;
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum DecodingError {
+    #[error("Invalid instruction encoding at offset {0}.")]
+    InvalidEncoding(usize),
+    #[error("Missing the immediate value of the instruction at offset {0}.")]
+    MissingImmediate(usize),
+    #[error("The instruction at offset {0} has no CASM representation.")]
+    UnsupportedInstruction(usize),
+}
+
+/// Decodes bytecode into its instructions, along with the offset of each instruction.
+/// Negative immediate values are expected to be given as negative numbers, rather than as field
+/// elements.
+pub fn decode_instructions(
+    bytecode: &[BigInt],
+) -> Result<Vec<(usize, Instruction)>, DecodingError> {
+    let mut instructions = vec![];
+    let mut offset = 0;
+    while offset < bytecode.len() {
+        let repr = InstructionRepr::decode(&bytecode[offset..], offset)?;
+        let instruction = repr.disassemble(offset)?;
+        let size = instruction.body.op_size();
+        instructions.push((offset, instruction));
+        offset += size;
+    }
+    Ok(instructions)
+}
+
+/// Disassembles bytecode into readable CASM, with an instruction per line, prefixed by its offset.
+/// The hints of each instruction are listed before it, preceded by the annotations of its offset
+/// as comments.
+pub fn disassemble(
+    bytecode: &[BigInt],
+    hints: &[(usize, Vec<Hint>)],
+    annotations: &OrderedHashMap<usize, Vec<String>>,
+) -> Result<String, DecodingError> {
+    let hints: OrderedHashMap<_, _> =
+        hints.iter().map(|(offset, hints)| (*offset, hints)).collect();
+    let mut res = String::new();
+    for (offset, mut instruction) in decode_instructions(bytecode)? {
+        for annotation in annotations.get(&offset).into_iter().flatten() {
+            writeln!(res, "// {annotation}").unwrap();
+        }
+        if let Some(hints) = hints.get(&offset) {
+            instruction.hints = hints.to_vec();
+        }
+        // The hints are written before the body of the instruction.
+        let text = instruction.to_string();
+        let (hints_text, body) = text.rsplit_once('\n').unwrap_or(("", text.as_str()));
+        if !hints_text.is_empty() {
+            writeln!(res, "{hints_text}").unwrap();
+        }
+        writeln!(res, "{offset}: {body};").unwrap();
+    }
+    Ok(res)
+}
+
+impl InstructionRepr {
+    /// Decodes the instruction at the start of the given bytecode, found at `offset`.
+    fn decode(bytecode: &[BigInt], offset: usize) -> Result<Self, DecodingError> {
+        let encoding = bytecode[0].to_u64().ok_or(DecodingError::InvalidEncoding(offset))?;
+        let flags = encoding >> (3 * OFFSET_BITS);
+        if flags >> (OPCODE_ASSERT_EQ_BIT + 1) != 0 {
+            return Err(DecodingError::InvalidEncoding(offset));
+        }
+        let flag = |bit: i32| flags & (1 << bit) != 0;
+        // Convert the offsets from positive numbers in the range [0, 2^16) centered around 2^15,
+        // back to numbers in the range [-2^15, 2^15).
+        let offset_at = |index: u32| {
+            (((encoding >> (index * OFFSET_BITS)) & 0xffff) as i32 - (1 << (OFFSET_BITS - 1)))
+                as i16
+        };
+        let register = |is_fp: bool| if is_fp { Register::FP } else { Register::AP };
+
+        let op1_addr = match (flag(OP1_IMM_BIT), flag(OP1_FP_BIT), flag(OP1_AP_BIT)) {
+            (false, false, false) => Op1Addr::Op0,
+            (true, false, false) => Op1Addr::Imm,
+            (false, true, false) => Op1Addr::FP,
+            (false, false, true) => Op1Addr::AP,
+            _ => return Err(DecodingError::InvalidEncoding(offset)),
+        };
+        let pc_update = match (flag(PC_JUMP_ABS_BIT), flag(PC_JUMP_REL_BIT), flag(PC_JNZ_BIT)) {
+            (false, false, false) => PcUpdate::Regular,
+            (true, false, false) => PcUpdate::Jump,
+            (false, true, false) => PcUpdate::JumpRel,
+            (false, false, true) => PcUpdate::Jnz,
+            _ => return Err(DecodingError::InvalidEncoding(offset)),
+        };
+        let res = match (flag(RES_ADD_BIT), flag(RES_MUL_BIT), pc_update == PcUpdate::Jnz) {
+            (false, false, false) => Res::Op1,
+            (true, false, false) => Res::Add,
+            (false, true, false) => Res::Mul,
+            (false, false, true) => Res::Unconstrained,
+            _ => return Err(DecodingError::InvalidEncoding(offset)),
+        };
+        let opcode = match (flag(OPCODE_CALL_BIT), flag(OPCODE_RET_BIT), flag(OPCODE_ASSERT_EQ_BIT))
+        {
+            (false, false, false) => Opcode::Nop,
+            (true, false, false) => Opcode::Call,
+            (false, true, false) => Opcode::Ret,
+            (false, false, true) => Opcode::AssertEq,
+            _ => return Err(DecodingError::InvalidEncoding(offset)),
+        };
+        let ap_update = match (flag(AP_ADD_BIT), flag(AP_ADD1_BIT), opcode == Opcode::Call) {
+            (false, false, false) => ApUpdate::Regular,
+            (true, false, false) => ApUpdate::Add,
+            (false, true, false) => ApUpdate::Add1,
+            (false, false, true) => ApUpdate::Add2,
+            _ => return Err(DecodingError::InvalidEncoding(offset)),
+        };
+        let fp_update = match opcode {
+            Opcode::Nop | Opcode::AssertEq => FpUpdate::Regular,
+            Opcode::Call => FpUpdate::ApPlus2,
+            Opcode::Ret => FpUpdate::Dst,
+        };
+        let imm = match op1_addr {
+            Op1Addr::Imm => {
+                Some(bytecode.get(1).cloned().ok_or(DecodingError::MissingImmediate(offset))?)
+            }
+            Op1Addr::AP | Op1Addr::FP | Op1Addr::Op0 => None,
+        };
+
+        Ok(Self {
+            off0: offset_at(0),
+            off1: offset_at(1),
+            off2: offset_at(2),
+            imm,
+            dst_register: register(flag(DST_REG_BIT)),
+            op0_register: register(flag(OP0_REG_BIT)),
+            op1_addr,
+            res,
+            pc_update,
+            ap_update,
+            fp_update,
+            opcode,
+        })
+    }
+
+    /// Returns the instruction represented by this representation, found at `offset`.
+    fn disassemble(&self, offset: usize) -> Result<Instruction, DecodingError> {
+        let unsupported = || DecodingError::UnsupportedInstruction(offset);
+        let inc_ap = self.ap_update == ApUpdate::Add1;
+        let body = match (&self.opcode, &self.pc_update, &self.ap_update) {
+            (Opcode::Call, PcUpdate::Jump | PcUpdate::JumpRel, _) => {
+                InstructionBody::Call(CallInstruction {
+                    target: self.op1().ok_or_else(unsupported)?,
+                    relative: self.pc_update == PcUpdate::JumpRel,
+                })
+            }
+            (Opcode::Ret, PcUpdate::Jump, ApUpdate::Regular) => {
+                InstructionBody::Ret(RetInstruction {})
+            }
+            (Opcode::AssertEq, PcUpdate::Regular, ApUpdate::Regular | ApUpdate::Add1) => {
+                InstructionBody::AssertEq(AssertEqInstruction {
+                    a: CellRef { register: self.dst_register, offset: self.off0 },
+                    b: self.res_operand().ok_or_else(unsupported)?,
+                })
+            }
+            (Opcode::Nop, PcUpdate::Regular, ApUpdate::Add) => {
+                InstructionBody::AddAp(AddApInstruction {
+                    operand: self.res_operand().ok_or_else(unsupported)?,
+                })
+            }
+            (
+                Opcode::Nop,
+                PcUpdate::Jump | PcUpdate::JumpRel,
+                ApUpdate::Regular | ApUpdate::Add1,
+            ) if self.res == Res::Op1 => InstructionBody::Jump(JumpInstruction {
+                target: self.op1().ok_or_else(unsupported)?,
+                relative: self.pc_update == PcUpdate::JumpRel,
+            }),
+            (Opcode::Nop, PcUpdate::Jnz, ApUpdate::Regular | ApUpdate::Add1) => {
+                InstructionBody::Jnz(JnzInstruction {
+                    jump_offset: self.op1().ok_or_else(unsupported)?,
+                    condition: CellRef { register: self.dst_register, offset: self.off0 },
+                })
+            }
+            _ => return Err(unsupported()),
+        };
+        Ok(Instruction::new(body, inc_ap))
+    }
+
+    /// Returns the op1 operand, if it is not addressed through op0.
+    fn op1(&self) -> Option<DerefOrImmediate> {
+        match self.op1_addr {
+            Op1Addr::Imm => Some(DerefOrImmediate::Immediate(self.imm.clone()?.into())),
+            Op1Addr::AP => Some(CellRef { register: Register::AP, offset: self.off2 }.into()),
+            Op1Addr::FP => Some(CellRef { register: Register::FP, offset: self.off2 }.into()),
+            Op1Addr::Op0 => None,
+        }
+    }
+
+    /// Returns the res operand, if it is representable.
+    fn res_operand(&self) -> Option<ResOperand> {
+        let op0 = CellRef { register: self.op0_register, offset: self.off1 };
+        let bin_op = |op| Some(ResOperand::BinOp(BinOpOperand { op, a: op0, b: self.op1()? }));
+        match (&self.res, &self.op1_addr) {
+            (Res::Op1, Op1Addr::Op0) => Some(ResOperand::DoubleDeref(op0, self.off2)),
+            (Res::Op1, _) => Some(self.op1()?.into()),
+            (Res::Add, _) => bin_op(Operation::Add),
+            (Res::Mul, _) => bin_op(Operation::Mul),
+            (Res::Unconstrained, _) => None,
+        }
+    }
+}
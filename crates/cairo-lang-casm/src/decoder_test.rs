@@ -0,0 +1,88 @@
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use indoc::indoc;
+use num_bigint::BigInt;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{decode_instructions, disassemble, DecodingError};
+use crate::casm;
+
+#[test]
+fn test_decode_instructions() {
+    let casm = casm! {
+        [ap + 0] = 1, ap++;
+        [fp - 5] = [ap + 1] + [fp - 5], ap++;
+        [fp + 5] = [ap + 1] * 2;
+        [ap + 5] = [[ap + 1] + 2];
+        [ap + 5] = [[fp - 1]];
+        ap += 205;
+        call rel 3;
+        call abs 3;
+        jmp rel 5 if [fp - 3] != 0;
+        jmp rel 2 if [ap - 1] != 0, ap++;
+        jmp abs 3;
+        jmp rel -5, ap++;
+        ret;
+    };
+    let bytecode = casm
+        .instructions
+        .iter()
+        .flat_map(|instruction| instruction.assemble().encode())
+        .collect::<Vec<_>>();
+    let mut offset = 0;
+    let expected = casm
+        .instructions
+        .into_iter()
+        .map(|instruction| {
+            let instruction_offset = offset;
+            offset += instruction.body.op_size();
+            (instruction_offset, instruction)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(decode_instructions(&bytecode), Ok(expected));
+}
+
+#[test]
+fn test_disassemble() {
+    let casm = casm! {
+        %{ memory[ap + 0] = segments.add() %}
+        [ap + 0] = 1, ap++;
+        call rel 3;
+        ret;
+    };
+    let bytecode = casm
+        .instructions
+        .iter()
+        .flat_map(|instruction| instruction.assemble().encode())
+        .collect::<Vec<_>>();
+    let hints = vec![(0, casm.instructions[0].hints.clone())];
+    let annotations = OrderedHashMap::from_iter([
+        (0, vec!["foo".to_string()]),
+        (4, vec!["bar".to_string(), "baz".to_string()]),
+    ]);
+    assert_eq!(
+        disassemble(&bytecode, &hints, &annotations).unwrap(),
+        indoc! {"
+            // foo
+            %{ memory[ap + 0] = segments.add() %}
+            0: [ap + 0] = 1, ap++;
+            2: call rel 3;
+            // bar
+            // baz
+            4: ret;
+        "}
+    );
+}
+
+#[test_case(&[-1], DecodingError::InvalidEncoding(0); "negative encoding")]
+#[test_case(&[0x208b7fff7fff7ffe, 1 << 63], DecodingError::InvalidEncoding(1); "unknown flag")]
+#[test_case(
+    &[0x208b7fff7fff7ffe, 0x480680017fff8000],
+    DecodingError::MissingImmediate(1);
+    "missing immediate"
+)]
+#[test_case(&[0x7fff7fff7fff], DecodingError::UnsupportedInstruction(0); "nop")]
+fn test_decoding_errors(bytecode: &[i128], error: DecodingError) {
+    let bytecode = bytecode.iter().copied().map(BigInt::from).collect::<Vec<_>>();
+    assert_eq!(decode_instructions(&bytecode), Err(error));
+}
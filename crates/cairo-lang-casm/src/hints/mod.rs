@@ -39,19 +39,53 @@ impl Display for Hint {
 /// Represents a hint that triggers a system call.
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub enum StarknetHint {
-    SystemCall { system: ResOperand },
-    SetBlockNumber { value: ResOperand },
-    SetBlockTimestamp { value: ResOperand },
-    SetCallerAddress { value: ResOperand },
-    SetContractAddress { value: ResOperand },
-    SetSequencerAddress { value: ResOperand },
-    SetVersion { value: ResOperand },
-    SetAccountContractAddress { value: ResOperand },
-    SetMaxFee { value: ResOperand },
-    SetTransactionHash { value: ResOperand },
-    SetChainId { value: ResOperand },
-    SetNonce { value: ResOperand },
-    SetSignature { start: ResOperand, end: ResOperand },
+    SystemCall {
+        system: ResOperand,
+    },
+    SetBlockNumber {
+        value: ResOperand,
+    },
+    SetBlockTimestamp {
+        value: ResOperand,
+    },
+    SetCallerAddress {
+        value: ResOperand,
+    },
+    SetContractAddress {
+        value: ResOperand,
+    },
+    SetSequencerAddress {
+        value: ResOperand,
+    },
+    SetVersion {
+        value: ResOperand,
+    },
+    SetAccountContractAddress {
+        value: ResOperand,
+    },
+    SetMaxFee {
+        value: ResOperand,
+    },
+    SetTransactionHash {
+        value: ResOperand,
+    },
+    SetChainId {
+        value: ResOperand,
+    },
+    SetNonce {
+        value: ResOperand,
+    },
+    SetSignature {
+        start: ResOperand,
+        end: ResOperand,
+    },
+    Cheatcode {
+        selector: ResOperand,
+        input_start: ResOperand,
+        input_end: ResOperand,
+        output_start: CellRef,
+        output_end: CellRef,
+    },
 }
 
 // Represents a cairo core hint.
@@ -775,6 +809,31 @@ impl Display for StarknetHint {
                     ResOperandFormatter(end)
                 )
             }
+            StarknetHint::Cheatcode {
+                selector,
+                input_start,
+                input_end,
+                output_start,
+                output_end,
+            } => {
+                let (selector, input_start, input_end) = (
+                    ResOperandFormatter(selector),
+                    ResOperandFormatter(input_start),
+                    ResOperandFormatter(input_end),
+                );
+                writedoc!(
+                    f,
+                    "
+
+                    r = syscall_handler.cheatcode(
+                        selector={selector},
+                        input=[memory[i] for i in range({input_start}, {input_end})],
+                    )
+                    memory{output_start} = r.start
+                    memory{output_end} = r.end
+                    "
+                )
+            }
         }
     }
 }
@@ -97,6 +97,29 @@ fn test_syscall_hint_format() {
     );
 }
 
+#[test]
+fn test_cheatcode_hint_format() {
+    assert_eq!(
+        StarknetHint::Cheatcode {
+            selector: res!([fp - 5]),
+            input_start: res!([fp - 4]),
+            input_end: res!([fp - 3]),
+            output_start: CellRef { register: Register::AP, offset: 0 },
+            output_end: CellRef { register: Register::AP, offset: 1 },
+        }
+        .to_string(),
+        indoc! {"
+
+            r = syscall_handler.cheatcode(
+                selector=memory[fp + -5],
+                input=[memory[i] for i in range(memory[fp + -4], memory[fp + -3])],
+            )
+            memory[ap + 0] = r.start
+            memory[ap + 1] = r.end
+        "}
+    );
+}
+
 #[test]
 fn test_debug_hint_format() {
     assert_eq!(
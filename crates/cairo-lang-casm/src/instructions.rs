@@ -9,7 +9,7 @@ use crate::operand::{CellRef, DerefOrImmediate, ResOperand};
 mod test;
 
 // An enum of Cairo instructions.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub enum InstructionBody {
     AddAp(AddApInstruction),
     AssertEq(AssertEqInstruction),
@@ -45,7 +45,7 @@ impl Display for InstructionBody {
 }
 
 /// Represents an instruction, including the ap++ flag (inc_ap).
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Instruction {
     pub body: InstructionBody,
     pub inc_ap: bool,
@@ -78,7 +78,7 @@ impl Display for Instruction {
 }
 
 /// Represents a call instruction "call rel/abs target".
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct CallInstruction {
     pub target: DerefOrImmediate,
     pub relative: bool,
@@ -98,7 +98,7 @@ impl CallInstruction {
 }
 
 /// Represents the InstructionBody "jmp rel/abs target".
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct JumpInstruction {
     pub target: DerefOrImmediate,
     pub relative: bool,
@@ -118,7 +118,7 @@ impl Display for JumpInstruction {
 }
 
 /// Represents the InstructionBody "jmp rel <jump_offset> if condition != 0".
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct JnzInstruction {
     pub jump_offset: DerefOrImmediate,
     pub condition: CellRef,
@@ -151,7 +151,7 @@ pub fn op_size_based_on_res_operands(operand: &ResOperand) -> usize {
 }
 
 /// Represents the InstructionBody "a = b" for two operands a, b.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct AssertEqInstruction {
     pub a: CellRef,
     pub b: ResOperand,
@@ -168,7 +168,7 @@ impl Display for AssertEqInstruction {
 }
 
 /// Represents a return instruction, "ret".
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct RetInstruction {}
 impl Display for RetInstruction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -183,7 +183,7 @@ impl RetInstruction {
 }
 
 /// Represents the InstructionBody "ap += op" for a given operand op.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct AddApInstruction {
     pub operand: ResOperand,
 }
@@ -4,6 +4,7 @@ pub mod ap_change;
 pub mod assembler;
 pub mod builder;
 pub mod cell_expression;
+pub mod decoder;
 pub mod encoder;
 pub mod hints;
 pub mod inline;
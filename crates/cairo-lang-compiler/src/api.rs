@@ -0,0 +1,201 @@
+//! A high-level interface of the compiler, for tools embedding it, such as build systems.
+//!
+//! The interface consists of the compiled artifacts and the diagnostics of a compilation only, so
+//! it does not change with the database of the compiler and its queries.
+
+use std::path::{Path, PathBuf};
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::TopLevelLanguageElementId;
+pub use cairo_lang_diagnostics::{LintLevel, Severity, StructuredDiagnostic};
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::db::SemanticGroup;
+pub use cairo_lang_sierra::program::Program;
+use cairo_lang_sierra_to_casm::metadata::calc_metadata;
+use num_bigint::BigInt;
+use thiserror::Error;
+
+use crate::db::RootDatabase;
+use crate::diagnostics::DiagnosticsReporter;
+use crate::project::{setup_in_memory_project, setup_project};
+use crate::{compile_prepared_db, CompilerConfig};
+
+#[cfg(test)]
+#[path = "api_test.rs"]
+mod test;
+
+/// The options of a compilation.
+#[derive(Clone, Debug, Default)]
+pub struct CompileOptions {
+    /// The `cfg` options to enable in the compilation.
+    pub cfg: Vec<Cfg>,
+    /// Replaces the Sierra ids with human-readable ones.
+    pub replace_ids: bool,
+    /// Keeps the unreachable functions and statements, and the unused declarations, in the Sierra
+    /// program.
+    pub keep_dead_code: bool,
+    /// Folds the constant arithmetic and prunes the statically decided branches of the Sierra
+    /// program.
+    pub fold_constants: bool,
+    /// The levels of the warnings of the given codes, unless set by an attribute.
+    pub lint_levels: Vec<(String, LintLevel)>,
+    /// Fails the compilation on any warning.
+    pub deny_warnings: bool,
+    /// Also compiles the Sierra program to CASM.
+    pub build_casm: bool,
+}
+
+/// The artifacts of a successful compilation.
+#[derive(Clone, Debug)]
+pub struct Artifacts {
+    /// The compiled Sierra program.
+    pub sierra: Program,
+    /// The CASM compiled from the Sierra program, if requested.
+    pub casm: Option<CasmArtifact>,
+    /// The signatures of the free functions of the compiled crates.
+    pub abi: Vec<FunctionAbi>,
+    /// The warnings reported by the compilation.
+    pub diagnostics: Vec<StructuredDiagnostic>,
+}
+
+/// A Sierra program compiled to CASM.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CasmArtifact {
+    /// The CASM code, an instruction per line.
+    pub assembly: String,
+    /// The encoded instructions.
+    pub bytecode: Vec<BigInt>,
+}
+
+/// The signature of a function, with its types formatted as in Cairo code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FunctionAbi {
+    /// The full path of the function, e.g. `my_crate::my_module::my_function`.
+    pub name: String,
+    pub inputs: Vec<FunctionAbiParam>,
+    pub output: String,
+}
+
+/// A parameter of a function, with its type formatted as in Cairo code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FunctionAbiParam {
+    pub name: String,
+    pub ty: String,
+}
+
+/// An error of a compilation.
+#[derive(Debug, Error)]
+pub enum CompileError {
+    /// The project could not be set up, e.g. because its project file is invalid.
+    #[error("{0}")]
+    Setup(String),
+    /// The code of the project has errors, reported along with its warnings.
+    #[error("Compilation failed.")]
+    Diagnostics(Vec<StructuredDiagnostic>),
+    /// The Sierra program could not be compiled to CASM.
+    #[error("Failed to compile the Sierra program to CASM: {0}")]
+    Casm(String),
+}
+
+/// Compiles the Cairo project at the given path: either a standalone `.cairo` file, or a directory
+/// with a `cairo_project.toml` file.
+pub fn compile_project(path: &Path, options: &CompileOptions) -> Result<Artifacts, CompileError> {
+    let mut db = build_db()?;
+    let main_crate_ids =
+        setup_project(&mut db, path).map_err(|error| CompileError::Setup(error.to_string()))?;
+    compile_prepared_project(&mut db, main_crate_ids, options)
+}
+
+/// Compiles a project held in memory, without reading or writing any file other than the corelib:
+/// a single crate with the given name, whose files are given by their paths relative to the crate
+/// root, including its `lib.cairo` file. The paths of the files in the diagnostics are under
+/// [crate::project::IN_MEMORY_ROOT].
+pub fn compile_in_memory_project(
+    crate_name: &str,
+    files: impl IntoIterator<Item = (PathBuf, String)>,
+    options: &CompileOptions,
+) -> Result<Artifacts, CompileError> {
+    let mut db = build_db()?;
+    let crate_id = setup_in_memory_project(&mut db, crate_name, files)
+        .map_err(|error| CompileError::Setup(error.to_string()))?;
+    compile_prepared_project(&mut db, vec![crate_id], options)
+}
+
+/// Builds a database with the detected corelib.
+fn build_db() -> Result<RootDatabase, CompileError> {
+    RootDatabase::builder()
+        .detect_corelib()
+        .build()
+        .map_err(|error| CompileError::Setup(error.to_string()))
+}
+
+/// Compiles the given crates of a database that is already set up.
+fn compile_prepared_project(
+    db: &mut RootDatabase,
+    main_crate_ids: Vec<CrateId>,
+    options: &CompileOptions,
+) -> Result<Artifacts, CompileError> {
+    let mut diagnostics = vec![];
+    let compiler_config = CompilerConfig {
+        diagnostics_reporter: DiagnosticsReporter::collect_structured(&mut diagnostics)
+            .with_lint_levels(options.lint_levels.clone())
+            .with_deny_warnings(options.deny_warnings),
+        replace_ids: options.replace_ids,
+        eliminate_dead_code: !options.keep_dead_code,
+        fold_constants: options.fold_constants,
+        cfg_set: CfgSet::from_iter(options.cfg.clone()),
+        ..CompilerConfig::default()
+    };
+    let sierra = match compile_prepared_db(db, main_crate_ids.clone(), compiler_config) {
+        Ok(sierra) => sierra.as_ref().clone(),
+        Err(error) => {
+            if !diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error) {
+                diagnostics.push(StructuredDiagnostic::error(format!("{error:#}")));
+            }
+            return Err(CompileError::Diagnostics(diagnostics));
+        }
+    };
+    let casm = if options.build_casm { Some(compile_casm(&sierra)?) } else { None };
+    let abi = functions_abi(db, &main_crate_ids);
+    Ok(Artifacts { sierra, casm, abi, diagnostics })
+}
+
+/// Compiles a Sierra program to CASM.
+fn compile_casm(program: &Program) -> Result<CasmArtifact, CompileError> {
+    let metadata = calc_metadata(program, Default::default())
+        .map_err(|error| CompileError::Casm(error.to_string()))?;
+    let cairo_program = cairo_lang_sierra_to_casm::compiler::compile(program, &metadata, true)
+        .map_err(|error| CompileError::Casm(error.to_string()))?;
+    let bytecode = cairo_program
+        .instructions
+        .iter()
+        .flat_map(|instruction| instruction.assemble().encode())
+        .collect();
+    Ok(CasmArtifact { assembly: cairo_program.to_string(), bytecode })
+}
+
+/// Returns the signatures of the free functions of the given crates.
+fn functions_abi(db: &RootDatabase, crate_ids: &[CrateId]) -> Vec<FunctionAbi> {
+    let mut abi = vec![];
+    for crate_id in crate_ids {
+        for module_id in db.crate_modules(*crate_id).iter() {
+            for function_id in db.module_free_functions_ids(*module_id).unwrap_or_default() {
+                let Ok(signature) = db.free_function_signature(function_id) else { continue };
+                abi.push(FunctionAbi {
+                    name: function_id.full_path(db),
+                    inputs: signature
+                        .params
+                        .iter()
+                        .map(|param| FunctionAbiParam {
+                            name: param.name.to_string(),
+                            ty: param.ty.format(db),
+                        })
+                        .collect(),
+                    output: signature.return_type.format(db),
+                });
+            }
+        }
+    }
+    abi
+}
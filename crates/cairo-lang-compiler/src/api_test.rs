@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+
+use cairo_lang_diagnostics::{DiagnosticSpan, ErrorCode, SpanPosition};
+use indoc::indoc;
+
+use super::{
+    compile_in_memory_project, compile_project, CompileError, CompileOptions, FunctionAbi,
+    FunctionAbiParam,
+};
+use crate::api::{Severity, StructuredDiagnostic};
+
+/// Writes a project of a `test` crate with the given code to a temporary directory, and returns
+/// the directory and the path of the code.
+fn write_project(name: &str, content: &str) -> (PathBuf, PathBuf) {
+    let directory = std::env::temp_dir()
+        .join(format!("cairo_lang_compiler_api_test_{name}_{}", std::process::id()));
+    std::fs::create_dir_all(directory.join("src")).unwrap();
+    std::fs::write(directory.join("cairo_project.toml"), "[crate_roots]\ntest = \"src\"\n")
+        .unwrap();
+    let path = directory.join("src").join("lib.cairo");
+    std::fs::write(&path, content).unwrap();
+    (directory, path)
+}
+
+#[test]
+fn test_compile_project() {
+    let (directory, _) = write_project(
+        "ok",
+        indoc! {"
+            fn add(a: felt252, b: felt252) -> felt252 {
+                let unused = 1;
+                a + b
+            }
+        "},
+    );
+    let artifacts = compile_project(
+        &directory,
+        &CompileOptions { replace_ids: true, build_casm: true, ..CompileOptions::default() },
+    )
+    .unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(artifacts.sierra.funcs.len(), 1);
+    assert!(artifacts.sierra.to_string().contains("test::add"));
+    let casm = artifacts.casm.unwrap();
+    assert_eq!(casm.assembly, "[ap + 0] = [fp + -4] + [fp + -3], ap++;\nret;\n");
+    assert_eq!(casm.bytecode.len(), 2);
+    assert_eq!(
+        artifacts.abi,
+        [FunctionAbi {
+            name: "test::add".into(),
+            inputs: vec![
+                FunctionAbiParam { name: "a".into(), ty: "core::felt252".into() },
+                FunctionAbiParam { name: "b".into(), ty: "core::felt252".into() },
+            ],
+            output: "core::felt252".into(),
+        }]
+    );
+    assert_eq!(artifacts.diagnostics.len(), 1);
+    assert_eq!(artifacts.diagnostics[0].severity, Severity::Warning);
+}
+
+#[test]
+fn test_compile_project_errors() {
+    let (directory, path) = write_project("errors", "fn foo() {\n    bar();\n}\n");
+    let error = compile_project(&directory, &CompileOptions::default()).unwrap_err();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    let CompileError::Diagnostics(diagnostics) = error else { panic!("Unexpected error: {error}") };
+    assert_eq!(
+        diagnostics,
+        [StructuredDiagnostic {
+            code: Some(ErrorCode::new("E0168")),
+            severity: Severity::Error,
+            message: "Function not found.".into(),
+            spans: vec![DiagnosticSpan {
+                file: path.display().to_string(),
+                start: SpanPosition { line: 2, col: 5 },
+                end: SpanPosition { line: 2, col: 8 },
+            }],
+            notes: vec![],
+            fixes: vec![],
+        }]
+    );
+
+    // The project was removed.
+    assert!(matches!(
+        compile_project(&directory, &CompileOptions::default()),
+        Err(CompileError::Setup(_))
+    ));
+}
+
+#[test]
+fn test_compile_in_memory_project() {
+    let files = [
+        ("lib.cairo".into(), "mod utils;\nfn main() -> felt252 {\n    utils::one()\n}\n".into()),
+        ("utils.cairo".into(), "fn one() -> felt252 {\n    1\n}\n".into()),
+    ];
+    let artifacts =
+        compile_in_memory_project("playground", files.clone(), &CompileOptions::default()).unwrap();
+    assert_eq!(
+        artifacts.abi.iter().map(|function| function.name.as_str()).collect::<Vec<_>>(),
+        ["playground::main", "playground::utils::one"]
+    );
+
+    // The diagnostics are reported in the in-memory files.
+    let mut files = files;
+    files[1].1 = "fn one() -> felt252 {\n    two()\n}\n".into();
+    let error =
+        compile_in_memory_project("playground", files, &CompileOptions::default()).unwrap_err();
+    let CompileError::Diagnostics(diagnostics) = error else { panic!("Unexpected error: {error}") };
+    assert_eq!(diagnostics[0].message, "Function not found.");
+    assert_eq!(diagnostics[0].spans[0].file, "<memory>/utils.cairo");
+
+    // A project without a `lib.cairo` file cannot be set up.
+    assert!(matches!(
+        compile_in_memory_project(
+            "playground",
+            [(PathBuf::from("main.cairo"), "fn main() {}".into())],
+            &CompileOptions::default()
+        ),
+        Err(CompileError::Setup(_))
+    ));
+}
@@ -9,19 +9,25 @@ use cairo_lang_filesystem::db::{
     CORELIB_CRATE_NAME,
 };
 use cairo_lang_filesystem::detect::detect_corelib;
-use cairo_lang_filesystem::ids::CrateLongId;
+use cairo_lang_filesystem::flag::Flag;
+use cairo_lang_filesystem::ids::{CrateLongId, FlagId};
 use cairo_lang_lowering::db::{LoweringDatabase, LoweringGroup};
 use cairo_lang_parser::db::ParserDatabase;
 use cairo_lang_plugins::get_default_plugins;
+use cairo_lang_plugins::registry::PluginRegistry;
 use cairo_lang_project::ProjectConfig;
 use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup, SemanticGroupEx};
-use cairo_lang_semantic::plugin::SemanticPlugin;
+use cairo_lang_semantic::plugin::{DynMacroPlugin, SemanticPlugin};
 use cairo_lang_sierra_generator::db::SierraGenDatabase;
 use cairo_lang_syntax::node::db::{SyntaxDatabase, SyntaxGroup};
 use cairo_lang_utils::Upcast;
 
 use crate::project::update_crate_roots_from_project_config;
 
+#[cfg(test)]
+#[path = "db_test.rs"]
+mod test;
+
 #[salsa::database(
     DefsDatabase,
     FilesDatabase,
@@ -40,6 +46,7 @@ impl RootDatabase {
         let mut res = Self { storage: Default::default() };
         init_files_group(&mut res);
         res.set_semantic_plugins(plugins);
+        res.set_lint_levels(Default::default());
         res
     }
 
@@ -69,6 +76,7 @@ pub struct RootDatabaseBuilder {
     detect_corelib: bool,
     project_config: Option<Box<ProjectConfig>>,
     cfg_set: Option<CfgSet>,
+    inlining_size_threshold: Option<usize>,
 }
 
 impl RootDatabaseBuilder {
@@ -78,6 +86,7 @@ impl RootDatabaseBuilder {
             detect_corelib: false,
             project_config: None,
             cfg_set: None,
+            inlining_size_threshold: None,
         }
     }
 
@@ -86,6 +95,18 @@ impl RootDatabaseBuilder {
         self
     }
 
+    /// Adds a plugin that only generates code, without extending the semantic model.
+    pub fn with_macro_plugin(&mut self, plugin: Arc<dyn MacroPlugin>) -> &mut Self {
+        self.with_semantic_plugin(Arc::new(DynMacroPlugin(plugin)))
+    }
+
+    /// Uses the plugins of the registry instead of the plugins added so far, including the default
+    /// ones.
+    pub fn with_plugin_registry(&mut self, registry: &PluginRegistry) -> &mut Self {
+        self.plugins = registry.plugins();
+        self
+    }
+
     pub fn clear_plugins(&mut self) -> &mut Self {
         self.plugins.clear();
         self
@@ -106,6 +127,13 @@ impl RootDatabaseBuilder {
         self
     }
 
+    /// Inlines the functions without an inline attribute whose lowering has at most `threshold`
+    /// statements.
+    pub fn with_inlining_size_threshold(&mut self, threshold: usize) -> &mut Self {
+        self.inlining_size_threshold = Some(threshold);
+        self
+    }
+
     pub fn build(&mut self) -> Result<RootDatabase> {
         // NOTE: Order of operations matters here!
         //   Errors if something is not OK are very subtle, mostly this results in missing
@@ -123,6 +151,11 @@ impl RootDatabaseBuilder {
             init_dev_corelib(&mut db, path);
         }
 
+        if let Some(threshold) = self.inlining_size_threshold {
+            let flag_id = FlagId::new(&db, "inlining_size_threshold");
+            db.set_flag(flag_id, Some(Arc::new(Flag::InliningSizeThreshold(threshold))));
+        }
+
         if let Some(config) = self.project_config.clone() {
             update_crate_roots_from_project_config(&mut db, *config.clone());
 
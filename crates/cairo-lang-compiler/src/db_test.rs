@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::plugin::{
+    DynGeneratedFileAuxData, MacroPlugin, PluginGeneratedFile, PluginResult,
+};
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use cairo_lang_plugins::registry::PluginRegistry;
+use cairo_lang_semantic::plugin::TrivialPluginAuxData;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{ast, Terminal};
+use indoc::indoc;
+
+use crate::db::RootDatabase;
+use crate::diagnostics::get_diagnostics_as_string;
+
+/// A plugin generating a `<name>_twice` function for every free function annotated with
+/// `#[twice]`, calling it twice.
+#[derive(Debug)]
+struct TwicePlugin;
+impl MacroPlugin for TwicePlugin {
+    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        let ast::Item::FreeFunction(function) = item_ast else { return PluginResult::default() };
+        if !function.has_attr(db, "twice") {
+            return PluginResult::default();
+        }
+        let name = function.declaration(db).name(db).text(db);
+        PluginResult {
+            code: Some(PluginGeneratedFile {
+                name: "twice".into(),
+                content: format!("fn {name}_twice() {{\n    {name}();\n    {name}();\n}}\n"),
+                aux_data: DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {})),
+            }),
+            diagnostics: vec![],
+            remove_original_item: false,
+        }
+    }
+}
+
+/// Returns the diagnostics of a crate with the given code, in a database built with the plugins of
+/// the given registry.
+fn check_with_registry(registry: &PluginRegistry, content: &str) -> String {
+    let mut db =
+        RootDatabase::builder().detect_corelib().with_plugin_registry(registry).build().unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    db.override_file_content(root.file(&db, "lib.cairo".into()), Some(Arc::new(content.into())));
+    get_diagnostics_as_string(&mut db)
+}
+
+#[test]
+fn test_plugin_registry() {
+    let content = indoc! {"
+        #[twice]
+        fn foo() {}
+
+        #[derive(Copy, Drop)]
+        struct A {}
+
+        fn bar() {
+            foo_twice();
+            let _a = A {};
+        }
+    "};
+
+    let mut registry = PluginRegistry::with_default_plugins();
+    assert!(registry.register_macro_plugin("twice", Arc::new(TwicePlugin)).is_none());
+    assert_eq!(
+        registry.names().collect::<Vec<_>>(),
+        ["derive", "generate_trait", "panicable", "config", "twice"]
+    );
+    assert_eq!(check_with_registry(&registry, content), "");
+
+    // Without the plugin, the generated function is missing.
+    assert!(registry.unregister("twice").is_some());
+    assert_eq!(
+        check_with_registry(&registry, content),
+        indoc! {"
+            error: Function not found.
+             --> lib.cairo:8:5
+                foo_twice();
+                ^*******^
+
+        "}
+    );
+}
@@ -1,10 +1,23 @@
+use std::str::FromStr;
+
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
+pub use cairo_lang_diagnostics::LintLevel;
+use cairo_lang_diagnostics::{
+    format_diagnostics, format_expansion, DiagnosticEntry, DiagnosticLocation, DiagnosticSpan,
+    Diagnostics, ErrorCode, Severity, StructuredDiagnostic,
+};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileLongId;
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeListStructurize};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::Upcast;
 use thiserror::Error;
 
 use crate::db::RootDatabase;
@@ -17,6 +30,32 @@ mod test;
 #[error("Compilation failed.")]
 pub struct DiagnosticsError;
 
+/// The format in which diagnostics are reported.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ErrorFormat {
+    /// The diagnostics are formatted for humans, with the marked code they refer to.
+    #[default]
+    Human,
+    /// Each diagnostic is reported as a JSON object in a line of its own - see
+    /// [`StructuredDiagnostic`].
+    Json,
+    /// Each diagnostic is reported in a line of its own, without the code it refers to, as
+    /// `file:line:col: severity[code]: message`.
+    Short,
+}
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            "short" => Ok(Self::Short),
+            _ => Err(format!("Unknown error format `{s}`, expected `human`, `json` or `short`.")),
+        }
+    }
+}
+
 trait DiagnosticCallback {
     fn on_diagnostic(&mut self, diagnostic: String);
 }
@@ -32,12 +71,29 @@ impl<'a> DiagnosticCallback for Option<Box<dyn DiagnosticCallback + 'a>> {
 /// Collects compilation diagnostics and presents them in preconfigured way.
 pub struct DiagnosticsReporter<'a> {
     callback: Option<Box<dyn DiagnosticCallback + 'a>>,
+    /// Collects the diagnostics in a structured form, instead of reporting them to the callback.
+    structured: Option<&'a mut Vec<StructuredDiagnostic>>,
+    error_format: ErrorFormat,
+    /// The levels of the warnings of specific codes, unless set by an attribute.
+    lint_levels: OrderedHashMap<String, LintLevel>,
+    /// Whether the reported warnings fail the compilation, as errors.
+    deny_warnings: bool,
+    /// Whether the diagnostics mapped from code generated by plugins are reported with the
+    /// generated code they originate in.
+    expanded_code: bool,
 }
 
 impl DiagnosticsReporter<'static> {
     /// Create a reporter which does not print or collect diagnostics at all.
     pub fn ignoring() -> Self {
-        Self { callback: None }
+        Self {
+            callback: None,
+            structured: None,
+            error_format: ErrorFormat::Human,
+            lint_levels: Default::default(),
+            deny_warnings: false,
+            expanded_code: false,
+        }
     }
 
     /// Create a reporter which prints all diagnostics to [`std::io::Stderr`].
@@ -75,57 +131,194 @@ impl<'a> DiagnosticsReporter<'a> {
         })
     }
 
+    /// Create a reporter which collects all diagnostics in a structured form, with the severities
+    /// they are reported with, into the provided vector.
+    pub fn collect_structured(diagnostics: &'a mut Vec<StructuredDiagnostic>) -> Self {
+        Self { structured: Some(diagnostics), ..DiagnosticsReporter::ignoring() }
+    }
+
     /// Create a reporter which calls [`DiagnosticCallback::on_diagnostic`].
     fn new(callback: impl DiagnosticCallback + 'a) -> Self {
-        Self { callback: Some(Box::new(callback)) }
+        Self {
+            callback: Some(Box::new(callback)),
+            structured: None,
+            error_format: ErrorFormat::Human,
+            lint_levels: Default::default(),
+            deny_warnings: false,
+            expanded_code: false,
+        }
+    }
+
+    /// Sets the format in which the diagnostics are reported.
+    pub fn with_error_format(mut self, error_format: ErrorFormat) -> Self {
+        self.error_format = error_format;
+        self
+    }
+
+    /// Sets the levels of the warnings of the given codes, unless set by an attribute.
+    pub fn with_lint_levels(
+        mut self,
+        lint_levels: impl IntoIterator<Item = (String, LintLevel)>,
+    ) -> Self {
+        self.lint_levels.extend(lint_levels);
+        self
+    }
+
+    /// Sets whether the reported warnings are reported as errors, failing the compilation.
+    pub fn with_deny_warnings(mut self, deny_warnings: bool) -> Self {
+        self.deny_warnings = deny_warnings;
+        self
+    }
+
+    /// Sets whether the diagnostics mapped from code generated by plugins are reported, in the
+    /// human format, with the generated code they originate in, from the outermost expansion to
+    /// the innermost one.
+    pub fn with_expanded_code(mut self, expanded_code: bool) -> Self {
+        self.expanded_code = expanded_code;
+        self
+    }
+
+    /// Returns a description of the levels the warnings are reported at, which decide whether a
+    /// compilation fails.
+    pub(crate) fn levels_description(&self) -> String {
+        let lint_levels = self
+            .lint_levels
+            .iter()
+            .map(|(code, level)| format!("{code}={level:?}"))
+            .collect::<Vec<_>>();
+        format!("deny_warnings={} lint_levels=[{}]", self.deny_warnings, lint_levels.join(","))
     }
 
     /// Checks if there are diagnostics and reports them to the provided callback as strings.
-    /// Returns `true` if diagnostics were found.
+    /// Returns `true` if errors were found. Warnings are reported, but are not counted unless
+    /// denied.
     pub fn check(&mut self, db: &RootDatabase) -> bool {
-        let mut found_diagnostics = false;
+        let mut found_errors = false;
         for crate_id in db.crates() {
             let Ok(module_file) = db.module_main_file(ModuleId::CrateRoot(crate_id)) else {
-                found_diagnostics = true;
-                self.callback.on_diagnostic("Failed to get main module file".to_string());
+                found_errors = true;
+                self.report_error("Failed to get main module file".to_string());
                 continue;
             };
 
             if db.file_content(module_file).is_none() {
                 match db.lookup_intern_file(module_file) {
                     FileLongId::OnDisk(path) => {
-                        self.callback.on_diagnostic(format!("{} not found\n", path.display()))
+                        self.report_error(format!("{} not found\n", path.display()))
                     }
                     FileLongId::Virtual(_) => panic!("Missing virtual file."),
                 }
-                found_diagnostics = true;
+                found_errors = true;
             }
 
             for module_id in &*db.crate_modules(crate_id) {
                 for file_id in db.module_files(*module_id).unwrap_or_default() {
-                    let diag = db.file_syntax_diagnostics(file_id);
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_errors |= self.report(db, &db.file_syntax_diagnostics(file_id));
                 }
 
                 if let Ok(diag) = db.module_semantic_diagnostics(*module_id) {
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_errors |= self.report(db, &diag);
+                }
+
+                if let Ok(diag) = db.module_lint_diagnostics(*module_id) {
+                    found_errors |= self.report(db, &diag);
                 }
 
                 if let Ok(diag) = db.module_lowering_diagnostics(*module_id) {
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
+                    found_errors |= self.report(db, &diag);
+                }
+            }
+        }
+        found_errors
+    }
+
+    /// Reports a collection of diagnostics in the configured format, at their levels. Returns
+    /// `true` if any of them was reported as an error.
+    fn report<TEntry: DiagnosticEntry>(
+        &mut self,
+        db: &RootDatabase,
+        diagnostics: &Diagnostics<TEntry>,
+    ) -> bool
+    where
+        RootDatabase: Upcast<TEntry::DbType>,
+    {
+        let entries_db: &TEntry::DbType = db.upcast();
+        let mut found_errors = false;
+        let mut formatted = String::new();
+        for entry in diagnostics.get_all() {
+            let location = entry.location(entries_db);
+            let Some(severity) = self.reported_severity(db, &entry, &location) else {
+                continue;
+            };
+            found_errors |= severity == Severity::Error;
+            if let Some(structured) = &mut self.structured {
+                let diagnostic = StructuredDiagnostic::from_entry(entries_db, &entry);
+                structured.push(StructuredDiagnostic { severity, ..diagnostic });
+                continue;
+            }
+            match self.error_format {
+                ErrorFormat::Human => {
+                    let message = entry.format(entries_db);
+                    formatted += &format_diagnostics(db, severity, &message, location);
+                    if self.expanded_code {
+                        for expansion_location in entry.expansion(entries_db) {
+                            formatted += &format_expansion(db, expansion_location);
+                        }
                     }
+                    formatted += "\n";
+                }
+                ErrorFormat::Json => {
+                    let diagnostic = StructuredDiagnostic::from_entry(entries_db, &entry);
+                    formatted += &to_json_line(&StructuredDiagnostic { severity, ..diagnostic });
+                }
+                ErrorFormat::Short => {
+                    formatted += &to_short_line(db, &entry, severity, location);
                 }
             }
         }
-        found_diagnostics
+        if !formatted.is_empty() {
+            self.callback.on_diagnostic(formatted);
+        }
+        found_errors
+    }
+
+    /// Returns the severity a diagnostic is reported with, or `None` if it is allowed.
+    /// The level of a warning is set by an attribute, else by the reporter, else by the project.
+    fn reported_severity<TEntry: DiagnosticEntry>(
+        &self,
+        db: &RootDatabase,
+        entry: &TEntry,
+        location: &DiagnosticLocation,
+    ) -> Option<Severity> {
+        if entry.severity() == Severity::Error {
+            return Some(Severity::Error);
+        }
+        let level = entry
+            .error_code()
+            .and_then(|code| {
+                attribute_lint_level(db, code, location)
+                    .or_else(|| self.lint_levels.get(code.as_str()).copied())
+                    .or_else(|| db.lint_levels().get(code.as_str()).copied())
+            })
+            .unwrap_or(LintLevel::Warn);
+        match level {
+            LintLevel::Allow => None,
+            LintLevel::Warn if !self.deny_warnings => Some(Severity::Warning),
+            LintLevel::Warn | LintLevel::Deny => Some(Severity::Error),
+        }
+    }
+
+    /// Reports an error that is not attached to a location in the code.
+    fn report_error(&mut self, message: String) {
+        if let Some(structured) = &mut self.structured {
+            structured.push(StructuredDiagnostic::error(message.trim_end()));
+            return;
+        }
+        let formatted = match self.error_format {
+            ErrorFormat::Human | ErrorFormat::Short => message,
+            ErrorFormat::Json => to_json_line(&StructuredDiagnostic::error(message.trim_end())),
+        };
+        self.callback.on_diagnostic(formatted);
     }
 
     /// Checks if there are diagnostics and reports them to the provided callback as strings.
@@ -141,6 +334,69 @@ impl Default for DiagnosticsReporter<'static> {
     }
 }
 
+/// Returns the level set for the warnings of a code by the innermost `#[allow(..)]`, `#[warn(..)]`
+/// or `#[deny(..)]` attribute listing the code, of an item enclosing the location.
+fn attribute_lint_level(
+    db: &RootDatabase,
+    code: ErrorCode,
+    location: &DiagnosticLocation,
+) -> Option<LintLevel> {
+    let syntax_db: &dyn SyntaxGroup = db;
+    let root = db.file_syntax(location.file_id).ok()?.as_syntax_node();
+    let mut node = Some(root.lookup_offset(syntax_db, location.span.start));
+    while let Some(current) = node {
+        for child in current.children(syntax_db) {
+            if child.kind(syntax_db) != SyntaxKind::AttributeList {
+                continue;
+            }
+            let attributes = ast::AttributeList::from_syntax_node(syntax_db, child);
+            for attribute in attributes.structurize(syntax_db) {
+                let Some(level) = LintLevel::from_attribute(&attribute.id) else { continue };
+                let lists_code = attribute.args.iter().any(|arg| match &arg.variant {
+                    AttributeArgVariant::Unnamed { value, .. } => {
+                        value.as_syntax_node().get_text_without_trivia(syntax_db) == code.as_str()
+                    }
+                    _ => false,
+                });
+                if lists_code {
+                    return Some(level);
+                }
+            }
+        }
+        node = current.parent();
+    }
+    None
+}
+
+/// Returns a diagnostic serialized as JSON, in a line of its own.
+fn to_json_line(diagnostic: &StructuredDiagnostic) -> String {
+    let json = serde_json::to_string(diagnostic).expect("Diagnostics are serializable.");
+    format!("{json}\n")
+}
+
+/// Returns a diagnostic in the short format, in a line of its own.
+fn to_short_line<TEntry: DiagnosticEntry>(
+    db: &RootDatabase,
+    entry: &TEntry,
+    severity: Severity,
+    location: DiagnosticLocation,
+) -> String
+where
+    RootDatabase: Upcast<TEntry::DbType>,
+{
+    let message = entry.format(db.upcast());
+    let severity = match entry.error_code() {
+        Some(code) => format!("{severity}[{code}]"),
+        None => severity.to_string(),
+    };
+    match DiagnosticSpan::from_location(db, location) {
+        Some(span) => {
+            format!("{}:{}:{}: {severity}: {message}\n", span.file, span.start.line, span.start.col)
+        }
+        None => format!("{severity}: {message}\n"),
+    }
+}
+
 /// Returns a string with all the diagnostics in the db.
 ///
 /// This is a shortcut for `DiagnosticsReporter::write_to_string(&mut string).check(db)`.
@@ -1,8 +1,19 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::plugin::{
+    DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
+};
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use indoc::indoc;
 
 use crate::db::RootDatabase;
-use crate::diagnostics::get_diagnostics_as_string;
+use crate::diagnostics::{get_diagnostics_as_string, DiagnosticsReporter, ErrorFormat, LintLevel};
 
 #[test]
 fn test_diagnostics() {
@@ -13,3 +24,384 @@ fn test_diagnostics() {
 
     assert_eq!(get_diagnostics_as_string(&mut db), "no/such/path/lib.cairo not found\n");
 }
+
+#[test]
+fn test_diagnostics_json() {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    db.override_file_content(
+        root.file(&db, "lib.cairo".into()),
+        Some(Arc::new("fn foo() {\n    bar();\n}\n".into())),
+    );
+
+    let mut diagnostics = String::default();
+    DiagnosticsReporter::write_to_string(&mut diagnostics)
+        .with_error_format(ErrorFormat::Json)
+        .check(&db);
+    assert_eq!(
+        diagnostics,
+        concat!(
+            r#"{"code":"E0168","severity":"error","message":"Function not found.","#,
+            r#""spans":[{"file":"src/lib.cairo","start":{"line":2,"col":5},"#,
+            r#""end":{"line":2,"col":8}}],"notes":[]}"#,
+            "\n"
+        )
+    );
+}
+
+#[test]
+fn test_diagnostics_short() {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    db.override_file_content(
+        root.file(&db, "lib.cairo".into()),
+        Some(Arc::new("fn foo() {\n    bar();\n    let x = 1;\n}\n".into())),
+    );
+
+    let mut diagnostics = String::default();
+    DiagnosticsReporter::write_to_string(&mut diagnostics)
+        .with_error_format(ErrorFormat::Short)
+        .check(&db);
+    assert_eq!(
+        diagnostics,
+        indoc! {"
+            src/lib.cairo:2:5: error[E0168]: Function not found.
+            src/lib.cairo:3:9: warning[W0101]: Unused variable `x`. Consider ignoring it by prefixing it with `_`.
+        "}
+    );
+}
+
+/// A plugin warning about every free function whose name starts with `linted_`.
+#[derive(Debug)]
+struct FunctionLintPlugin;
+impl MacroPlugin for FunctionLintPlugin {
+    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        let ast::Item::FreeFunction(function) = item_ast else { return PluginResult::default() };
+        let name = function.declaration(db).name(db);
+        if !name.text(db).starts_with("linted_") {
+            return PluginResult::default();
+        }
+        PluginResult {
+            code: None,
+            diagnostics: vec![PluginDiagnostic {
+                stable_ptr: name.stable_ptr().untyped(),
+                message: "A function.".into(),
+                code: Some(ErrorCode::new("T001")),
+                severity: Severity::Warning,
+                fixes: vec![],
+            }],
+            remove_original_item: false,
+        }
+    }
+}
+impl AsDynMacroPlugin for FunctionLintPlugin {
+    fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
+    where
+        Self: 'a,
+    {
+        self
+    }
+}
+impl SemanticPlugin for FunctionLintPlugin {}
+
+/// Returns the diagnostics of a crate with the given code, linted by [FunctionLintPlugin], as
+/// reported by the given reporter, and whether errors were found.
+fn check_lints(
+    content: &str,
+    reporter: impl FnOnce(DiagnosticsReporter<'_>) -> DiagnosticsReporter<'_>,
+) -> (String, bool) {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(FunctionLintPlugin))
+        .build()
+        .unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    db.override_file_content(root.file(&db, "lib.cairo".into()), Some(Arc::new(content.into())));
+
+    let mut diagnostics = String::default();
+    let found_errors = reporter(DiagnosticsReporter::write_to_string(&mut diagnostics)).check(&db);
+    (diagnostics, found_errors)
+}
+
+#[test]
+fn test_lint_levels() {
+    let content = indoc! {"
+        fn linted_foo() {}
+
+        #[allow(T001)]
+        fn linted_bar() {}
+
+        #[deny(T001)]
+        fn linted_baz() {}
+    "};
+    assert_eq!(
+        check_lints(content, |reporter| reporter),
+        (
+            indoc! {"
+                warning: Plugin diagnostic: A function.
+                 --> lib.cairo:1:4
+                fn linted_foo() {}
+                   ^********^
+
+                error: Plugin diagnostic: A function.
+                 --> lib.cairo:7:4
+                fn linted_baz() {}
+                   ^********^
+
+            "}
+            .into(),
+            true
+        )
+    );
+    // The attributes take precedence over the levels of the reporter.
+    assert_eq!(
+        check_lints(content, |reporter| {
+            reporter.with_lint_levels([("T001".into(), LintLevel::Allow)])
+        }),
+        (
+            indoc! {"
+                error: Plugin diagnostic: A function.
+                 --> lib.cairo:7:4
+                fn linted_baz() {}
+                   ^********^
+
+            "}
+            .into(),
+            true
+        )
+    );
+}
+
+#[test]
+fn test_deny_warnings() {
+    let content = "fn linted_foo() {}\n";
+    assert_eq!(
+        check_lints(content, |reporter| reporter),
+        (
+            indoc! {"
+                warning: Plugin diagnostic: A function.
+                 --> lib.cairo:1:4
+                fn linted_foo() {}
+                   ^********^
+
+            "}
+            .into(),
+            false
+        )
+    );
+    assert_eq!(
+        check_lints(content, |reporter| reporter.with_deny_warnings(true)),
+        (
+            indoc! {"
+                error: Plugin diagnostic: A function.
+                 --> lib.cairo:1:4
+                fn linted_foo() {}
+                   ^********^
+
+            "}
+            .into(),
+            true
+        )
+    );
+}
+
+#[test]
+fn test_semantic_lints() {
+    let content = indoc! {"
+        use array::ArrayTrait;
+        use array::SpanTrait;
+        use clone::Clone;
+        use option::OptionTrait;
+
+        fn foo(x: felt252) -> Span<felt252> {
+            let y = x + 1;
+            let _z = x;
+            let mut arr = array::array_new();
+            arr.append(x);
+            let span = arr.span();
+            let len = span.len();
+            span.clone()
+        }
+
+        #[allow(W0101)]
+        fn bar() {
+            let y = 1;
+        }
+
+        fn baz() -> felt252 {
+            panic_with_felt252('a');
+            5
+        }
+    "};
+    // `ArrayTrait` and `Clone` are only used by calling their methods.
+    assert_eq!(
+        check_lints(content, |reporter| reporter),
+        (
+            indoc! {"
+                warning: Unused variable `y`. Consider ignoring it by prefixing it with `_`.
+                 --> lib.cairo:7:9
+                    let y = x + 1;
+                        ^
+
+                warning: Unused variable `len`. Consider ignoring it by prefixing it with `_`.
+                 --> lib.cairo:12:9
+                    let len = span.len();
+                        ^*^
+
+                warning: Needless clone of a span. Spans are copyable, so the span can be used directly.
+                 --> lib.cairo:13:5
+                    span.clone()
+                    ^**********^
+
+                warning: Unused import `OptionTrait`.
+                 --> lib.cairo:4:13
+                use option::OptionTrait;
+                            ^*********^
+
+                warning: Unreachable code
+                 --> lib.cairo:23:5
+                    5
+                    ^
+
+            "}
+            .into(),
+            false
+        )
+    );
+}
+
+#[test]
+fn test_project_lint_levels() {
+    let content = indoc! {"
+        use option::OptionTrait;
+
+        #[warn(W0102)]
+        mod inner {
+            use option::OptionTrait;
+        }
+    "};
+    // The levels of the project apply unless set by an attribute or by the reporter.
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    db.set_lint_levels(Arc::new([("W0102".into(), LintLevel::Deny)].into_iter().collect()));
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    db.override_file_content(root.file(&db, "lib.cairo".into()), Some(Arc::new(content.into())));
+    assert_eq!(
+        get_diagnostics_as_string(&mut db),
+        indoc! {"
+            error: Unused import `OptionTrait`.
+             --> lib.cairo:1:13
+            use option::OptionTrait;
+                        ^*********^
+
+            warning: Unused import `OptionTrait`.
+             --> lib.cairo:5:17
+                use option::OptionTrait;
+                            ^*********^
+
+        "}
+    );
+    let mut diagnostics = String::default();
+    let found_errors = DiagnosticsReporter::write_to_string(&mut diagnostics)
+        .with_lint_levels([("W0102".into(), LintLevel::Allow)])
+        .check(&db);
+    assert!(!found_errors);
+    assert_eq!(
+        diagnostics,
+        indoc! {"
+            warning: Unused import `OptionTrait`.
+             --> lib.cairo:5:17
+                use option::OptionTrait;
+                            ^*********^
+
+        "}
+    );
+}
+
+/// A plugin adding a function calling a missing function for every free function whose name starts
+/// with `expanded_`.
+#[derive(Debug)]
+struct BadExpansionPlugin;
+impl MacroPlugin for BadExpansionPlugin {
+    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        let ast::Item::FreeFunction(function) = item_ast else { return PluginResult::default() };
+        let name = function.declaration(db).name(db).text(db);
+        if !name.starts_with("expanded_") {
+            return PluginResult::default();
+        }
+        PluginResult {
+            code: Some(PluginGeneratedFile {
+                name: "bad_expansion".into(),
+                content: format!("fn generated_{name}() {{\n    bar();\n}}\n"),
+                aux_data: DynGeneratedFileAuxData::new(TrivialPluginAuxData {}),
+            }),
+            diagnostics: vec![],
+            remove_original_item: false,
+        }
+    }
+}
+
+#[test]
+fn test_generated_code_diagnostics() {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_macro_plugin(Arc::new(BadExpansionPlugin))
+        .build()
+        .unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = Directory("src".into());
+    db.set_crate_root(crate_id, Some(root.clone()));
+    db.override_file_content(
+        root.file(&db, "lib.cairo".into()),
+        Some(Arc::new("fn expanded_foo() {}\n".into())),
+    );
+
+    // The error in the generated code is reported at the function it was generated from.
+    assert_eq!(
+        get_diagnostics_as_string(&mut db),
+        indoc! {"
+            error: Function not found.
+             --> lib.cairo:1:1
+            fn expanded_foo() {}
+            ^******************^
+
+        "}
+    );
+    let mut diagnostics = String::default();
+    DiagnosticsReporter::write_to_string(&mut diagnostics).with_expanded_code(true).check(&db);
+    assert_eq!(
+        diagnostics,
+        indoc! {"
+            error: Function not found.
+             --> lib.cairo:1:1
+            fn expanded_foo() {}
+            ^******************^
+            note: In the code generated by a plugin:
+             --> bad_expansion:2:5
+                bar();
+                ^*^
+
+        "}
+    );
+    let mut diagnostics = String::default();
+    DiagnosticsReporter::write_to_string(&mut diagnostics)
+        .with_error_format(ErrorFormat::Json)
+        .check(&db);
+    assert_eq!(
+        diagnostics,
+        concat!(
+            r#"{"code":"E0168","severity":"error","message":"Function not found.","#,
+            r#""spans":[{"file":"src/lib.cairo","start":{"line":1,"col":1},"#,
+            r#""end":{"line":1,"col":21}}],"#,
+            r#""notes":["Originates in the code generated by a plugin, at `bad_expansion:2:5`."]}"#,
+            "\n"
+        )
+    );
+}
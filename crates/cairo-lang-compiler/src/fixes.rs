@@ -0,0 +1,111 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::Upcast;
+
+use crate::db::RootDatabase;
+
+#[cfg(test)]
+#[path = "fixes_test.rs"]
+mod test;
+
+/// A replacement of the code at a span of a file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TextEdit {
+    pub span: TextSpan,
+    pub replacement: String,
+}
+
+/// Returns the suggested fixes of the diagnostics of all the crates in the database, by the files
+/// they apply to.
+pub fn collect_fixes(db: &RootDatabase) -> OrderedHashMap<FileId, Vec<TextEdit>> {
+    let mut fixes = OrderedHashMap::default();
+    for crate_id in db.crates() {
+        for module_id in &*db.crate_modules(crate_id) {
+            for file_id in db.module_files(*module_id).unwrap_or_default() {
+                add_fixes(db, &db.file_syntax_diagnostics(file_id), &mut fixes);
+            }
+            if let Ok(diagnostics) = db.module_semantic_diagnostics(*module_id) {
+                add_fixes(db, &diagnostics, &mut fixes);
+            }
+            if let Ok(diagnostics) = db.module_lint_diagnostics(*module_id) {
+                add_fixes(db, &diagnostics, &mut fixes);
+            }
+            if let Ok(diagnostics) = db.module_lowering_diagnostics(*module_id) {
+                add_fixes(db, &diagnostics, &mut fixes);
+            }
+        }
+    }
+    fixes
+}
+
+/// Adds the fixes of a collection of diagnostics to `fixes`.
+fn add_fixes<TEntry: DiagnosticEntry>(
+    db: &RootDatabase,
+    diagnostics: &Diagnostics<TEntry>,
+    fixes: &mut OrderedHashMap<FileId, Vec<TextEdit>>,
+) where
+    RootDatabase: Upcast<TEntry::DbType>,
+{
+    for entry in diagnostics.get_all() {
+        for fix in entry.fixes(db.upcast()) {
+            fixes
+                .entry(fix.location.file_id)
+                .or_default()
+                .push(TextEdit { span: fix.location.span, replacement: fix.replacement });
+        }
+    }
+}
+
+/// Returns the content with the given edits applied, and the number of edits applied. An edit
+/// overlapping an edit preceding it in the content is not applied, and identical edits are applied
+/// once.
+pub fn apply_edits(content: &str, edits: Vec<TextEdit>) -> (String, usize) {
+    let offset = |text_offset: TextOffset| content.len() - text_offset.take_from(content).len();
+    let mut byte_edits = edits
+        .into_iter()
+        .map(|edit| (offset(edit.span.start), offset(edit.span.end), edit.replacement))
+        .collect::<Vec<_>>();
+    byte_edits.sort();
+    byte_edits.dedup();
+
+    let mut fixed = String::with_capacity(content.len());
+    let mut position = 0;
+    let mut n_applied = 0;
+    for (start, end, replacement) in byte_edits {
+        if start < position {
+            continue;
+        }
+        fixed.push_str(&content[position..start]);
+        fixed.push_str(&replacement);
+        position = end;
+        n_applied += 1;
+    }
+    fixed.push_str(&content[position..]);
+    (fixed, n_applied)
+}
+
+/// Applies the suggested fixes of the diagnostics of all the crates in the database to the files
+/// on disk. Returns the number of fixes applied.
+pub fn apply_fixes(db: &RootDatabase) -> Result<usize> {
+    let mut n_applied = 0;
+    for (file_id, edits) in collect_fixes(db) {
+        // Fixes of code generated by plugins cannot be applied.
+        let FileLongId::OnDisk(path) = db.lookup_intern_file(file_id) else { continue };
+        let Some(content) = db.file_content(file_id) else { continue };
+        let (fixed, n_file_applied) = apply_edits(&content, edits);
+        fs::write(&path, fixed)
+            .with_context(|| format!("Failed to write the fixed file `{}`.", path.display()))?;
+        n_applied += n_file_applied;
+    }
+    Ok(n_applied)
+}
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::plugin::{MacroPlugin, PluginDiagnostic, PluginFix, PluginResult};
+use cairo_lang_diagnostics::{ErrorCode, Severity};
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
+use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use indoc::indoc;
+
+use super::{apply_edits, apply_fixes, TextEdit};
+use crate::db::RootDatabase;
+
+/// Returns an edit replacing the given byte range.
+fn edit(start: u32, end: u32, replacement: &str) -> TextEdit {
+    let offset = |value| TextOffset::default().add_width(TextWidth::new_for_testing(value));
+    TextEdit {
+        span: TextSpan { start: offset(start), end: offset(end) },
+        replacement: replacement.into(),
+    }
+}
+
+#[test]
+fn test_apply_edits() {
+    assert_eq!(
+        apply_edits(
+            "let a = b;",
+            vec![edit(8, 9, "c"), edit(4, 5, "x"), edit(0, 0, "// Fixed.\n"), edit(4, 5, "x")]
+        ),
+        ("// Fixed.\nlet x = c;".into(), 3)
+    );
+    // An edit overlapping a preceding edit is not applied.
+    assert_eq!(
+        apply_edits("let a = b;", vec![edit(4, 9, "x = y"), edit(8, 9, "c")]),
+        ("let x = y;".into(), 1)
+    );
+}
+
+/// A plugin reporting every free function whose name starts with `bad_`, with a fix removing the
+/// prefix.
+#[derive(Debug)]
+struct FunctionRenamePlugin;
+impl MacroPlugin for FunctionRenamePlugin {
+    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        let ast::Item::FreeFunction(function) = item_ast else { return PluginResult::default() };
+        let name = function.declaration(db).name(db);
+        let Some(fixed_name) = name.text(db).strip_prefix("bad_").map(String::from) else {
+            return PluginResult::default();
+        };
+        PluginResult {
+            code: None,
+            diagnostics: vec![PluginDiagnostic {
+                stable_ptr: name.stable_ptr().untyped(),
+                message: "A badly named function.".into(),
+                code: Some(ErrorCode::new("T001")),
+                severity: Severity::Error,
+                fixes: vec![PluginFix {
+                    stable_ptr: name.stable_ptr().untyped(),
+                    replacement: fixed_name,
+                }],
+            }],
+            remove_original_item: false,
+        }
+    }
+}
+impl AsDynMacroPlugin for FunctionRenamePlugin {
+    fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
+    where
+        Self: 'a,
+    {
+        self
+    }
+}
+impl SemanticPlugin for FunctionRenamePlugin {}
+
+#[test]
+fn test_apply_fixes() {
+    let directory =
+        std::env::temp_dir().join(format!("cairo_lang_compiler_fixes_test_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let lib_path = directory.join("lib.cairo");
+    std::fs::write(
+        &lib_path,
+        indoc! {"
+            fn bad_foo() {}
+
+            // A comment.
+            fn bad_bar() {
+                bad_foo();
+            }
+        "},
+    )
+    .unwrap();
+
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(FunctionRenamePlugin))
+        .build()
+        .unwrap();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    db.set_crate_root(crate_id, Some(Directory(directory.clone())));
+
+    assert_eq!(apply_fixes(&db).unwrap(), 2);
+    assert_eq!(
+        std::fs::read_to_string(&lib_path).unwrap(),
+        indoc! {"
+            fn foo() {}
+
+            // A comment.
+            fn bar() {
+                bad_foo();
+            }
+        "}
+    );
+    std::fs::remove_dir_all(directory).unwrap();
+}
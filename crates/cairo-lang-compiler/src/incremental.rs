@@ -0,0 +1,134 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::{CrateId, FileLongId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra::program::Program;
+use cairo_lang_sierra::ProgramParser;
+use sha3::{Digest, Keccak256};
+
+use crate::db::RootDatabase;
+use crate::CompilerConfig;
+
+#[cfg(test)]
+#[path = "incremental_test.rs"]
+mod test;
+
+/// A directory of Sierra programs compiled by previous compiler invocations, each stored by the
+/// fingerprint of the inputs it was compiled from - see [`compilation_fingerprint`].
+pub struct CompilationCache {
+    directory: PathBuf,
+}
+impl CompilationCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into() }
+    }
+
+    /// Returns the program compiled from the inputs with the given fingerprint, if it was stored.
+    pub fn load(&self, fingerprint: &str) -> Option<Program> {
+        let text = fs::read_to_string(self.program_path(fingerprint)).ok()?;
+        // A program that cannot be parsed back is compiled again, and overwritten.
+        ProgramParser::new().parse(&text).ok()
+    }
+
+    /// Stores the program compiled from the inputs with the given fingerprint.
+    pub fn store(&self, fingerprint: &str, program: &Program) -> Result<()> {
+        fs::create_dir_all(&self.directory).with_context(|| {
+            format!("Failed to create the cache directory `{}`.", self.directory.display())
+        })?;
+        // The program is written to a temporary file first, so concurrent compilations never read
+        // a partially written program.
+        let path = self.program_path(fingerprint);
+        let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&temp_path, program.to_string())
+            .and_then(|()| fs::rename(&temp_path, &path))
+            .with_context(|| format!("Failed to write the cached program `{}`.", path.display()))
+    }
+
+    /// Returns the path of the program compiled from the inputs with the given fingerprint.
+    fn program_path(&self, fingerprint: &str) -> PathBuf {
+        self.directory.join(format!("{fingerprint}.sierra"))
+    }
+}
+
+/// Returns a fingerprint of all the inputs of compiling the given crates: the compiler version,
+/// the configuration options affecting the compiled program, the levels the warnings are reported
+/// at, the plugins, the compilation flags and `cfg` options, and the content of the files of the
+/// modules of all the crates in the database.
+pub fn compilation_fingerprint(
+    db: &RootDatabase,
+    main_crate_ids: &[CrateId],
+    compiler_config: &CompilerConfig<'_>,
+) -> String {
+    let mut hasher = FingerprintHasher::default();
+    hasher.add(env!("CARGO_PKG_VERSION"));
+    hasher.add(&format!(
+        "replace_ids={} eliminate_dead_code={} fold_constants={}",
+        compiler_config.replace_ids,
+        compiler_config.eliminate_dead_code,
+        compiler_config.fold_constants
+    ));
+    hasher.add(&compiler_config.diagnostics_reporter.levels_description());
+    for (code, level) in db.lint_levels().iter() {
+        hasher.add(&format!("{code}={level:?}"));
+    }
+    for plugin in db.semantic_plugins() {
+        hasher.add(&format!("{plugin:?}"));
+    }
+    for crate_id in main_crate_ids {
+        hasher.add(&db.lookup_intern_crate(*crate_id).0);
+    }
+    for (flag_id, flag) in db.flags().iter() {
+        hasher.add(&format!("{}={flag:?}", db.lookup_intern_flag(*flag_id).0));
+    }
+    for cfg in db.cfg_set().iter() {
+        hasher.add(&cfg.to_string());
+    }
+
+    for (crate_id, root) in db.crate_roots().iter() {
+        hasher.add(&db.lookup_intern_crate(*crate_id).0);
+        hasher.add(&root.0.to_string_lossy());
+        for path in crate_module_paths(db, *crate_id) {
+            hasher.add(&path.to_string_lossy());
+            let content = db.file_content(db.intern_file(FileLongId::OnDisk(path)));
+            hasher.add(content.as_deref().map_or("", String::as_str));
+        }
+    }
+    hasher.finish()
+}
+
+/// Hasher of a sequence of strings, where the boundaries between the strings are part of the hash.
+#[derive(Default)]
+struct FingerprintHasher {
+    hasher: Keccak256,
+}
+impl FingerprintHasher {
+    fn add(&mut self, value: &str) {
+        self.hasher.update((value.len() as u64).to_le_bytes());
+        self.hasher.update(value.as_bytes());
+    }
+
+    fn finish(self) -> String {
+        self.hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
+
+/// Returns the sorted paths of the files of the modules of a crate, which are the files the crate
+/// is compiled from, unlike other files in its directory. The files generated by plugins are not
+/// included, as they are generated from these.
+pub(crate) fn crate_module_paths(db: &RootDatabase, crate_id: CrateId) -> Vec<PathBuf> {
+    let mut paths = vec![];
+    for module_id in db.crate_modules(crate_id).iter() {
+        for file_id in db.module_files(*module_id).unwrap_or_default() {
+            if let FileLongId::OnDisk(path) = db.lookup_intern_file(file_id) {
+                paths.push(path);
+            }
+        }
+    }
+    paths.sort();
+    paths.dedup();
+    paths
+}
@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra::ProgramParser;
+use indoc::indoc;
+
+use super::{compilation_fingerprint, CompilationCache};
+use crate::db::RootDatabase;
+use crate::diagnostics::DiagnosticsReporter;
+use crate::project::setup_in_memory_project;
+use crate::{compile_prepared_db, CompilerConfig};
+
+#[test]
+fn test_compilation_fingerprint() {
+    let mut db = RootDatabase::default();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    let root = PathBuf::from("no/such/path");
+    db.set_crate_root(crate_id, Some(Directory(root.clone())));
+    let config = CompilerConfig::default();
+    let fingerprint = compilation_fingerprint(&db, &[crate_id], &config);
+    assert_eq!(compilation_fingerprint(&db, &[crate_id], &config), fingerprint);
+
+    let optimizing_config = CompilerConfig { fold_constants: true, ..CompilerConfig::default() };
+    assert_ne!(compilation_fingerprint(&db, &[crate_id], &optimizing_config), fingerprint);
+
+    let file_id = db.intern_file(FileLongId::OnDisk(root.join("lib.cairo")));
+    db.override_file_content(file_id, Some(Arc::new("fn main() {}".into())));
+    let lib_fingerprint = compilation_fingerprint(&db, &[crate_id], &config);
+    assert_ne!(lib_fingerprint, fingerprint);
+    // Only the files of the modules of the crate are compiled.
+    let other_file_id = db.intern_file(FileLongId::OnDisk(root.join("other.cairo")));
+    db.override_file_content(other_file_id, Some(Arc::new("fn other() {}".into())));
+    assert_eq!(compilation_fingerprint(&db, &[crate_id], &config), lib_fingerprint);
+    db.override_file_content(file_id, Some(Arc::new("fn main() { 1; }".into())));
+    assert_ne!(compilation_fingerprint(&db, &[crate_id], &config), lib_fingerprint);
+
+    let denying_config = CompilerConfig {
+        diagnostics_reporter: DiagnosticsReporter::ignoring().with_deny_warnings(true),
+        ..CompilerConfig::default()
+    };
+    let denying_fingerprint = compilation_fingerprint(&db, &[crate_id], &denying_config);
+    assert_ne!(compilation_fingerprint(&db, &[crate_id], &config), denying_fingerprint);
+
+    let plugins_fingerprint = compilation_fingerprint(&db, &[crate_id], &config);
+    db.set_semantic_plugins(vec![]);
+    assert_ne!(compilation_fingerprint(&db, &[crate_id], &config), plugins_fingerprint);
+}
+
+#[test]
+fn test_cached_compilation_diagnostics() {
+    let directory = std::env::temp_dir()
+        .join(format!("cairo_lang_compiler_incremental_diagnostics_test_{}", std::process::id()));
+    let compile = |deny_warnings| {
+        let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+        let crate_id = setup_in_memory_project(
+            &mut db,
+            "test",
+            [("lib.cairo".into(), "fn main() { let x = 5; }".into())],
+        )
+        .unwrap();
+        let config = CompilerConfig {
+            diagnostics_reporter: DiagnosticsReporter::ignoring().with_deny_warnings(deny_warnings),
+            incremental_dir: Some(directory.clone()),
+            ..CompilerConfig::default()
+        };
+        compile_prepared_db(&mut db, vec![crate_id], config)
+    };
+    // The program compiled with a warning is cached, yet the warning still fails the compilation
+    // once denied.
+    assert!(compile(false).is_ok());
+    assert!(compile(false).is_ok());
+    assert!(compile(true).is_err());
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn test_compilation_cache() {
+    let directory = std::env::temp_dir()
+        .join(format!("cairo_lang_compiler_incremental_test_{}", std::process::id()));
+    let cache = CompilationCache::new(&directory);
+    assert_eq!(cache.load("fingerprint"), None);
+
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+            libfunc felt252_add = felt252_add;
+            felt252_add([0], [1]) -> ([2]);
+            return([2]);
+            add@0([0]: felt252, [1]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    cache.store("fingerprint", &program).unwrap();
+    assert_eq!(cache.load("fingerprint"), Some(program));
+    assert_eq!(cache.load("other_fingerprint"), None);
+    std::fs::remove_dir_all(directory).unwrap();
+}
@@ -2,34 +2,78 @@
 //!
 //! This crate is responsible for compiling a Cairo project into a Sierra program.
 //! It is the main entry point for the compiler.
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use ::cairo_lang_diagnostics::ToOption;
 use anyhow::{Context, Result};
+use cairo_lang_filesystem::cfg::CfgSet;
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_sierra::program::Program;
+use cairo_lang_sierra_generator::canonical_id_replacer::CanonicalReplacer;
+use cairo_lang_sierra_generator::constant_folding::fold_constants;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
-use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_sierra_generator::dead_code_elimination::eliminate_dead_code;
+use cairo_lang_sierra_generator::replace_ids::{replace_sierra_ids_in_program, SierraIdReplacer};
 
 use crate::db::RootDatabase;
 use crate::diagnostics::DiagnosticsReporter;
+use crate::incremental::{compilation_fingerprint, CompilationCache};
+use crate::parallel::{check_modules_in_parallel, generate_sierra_in_parallel};
 use crate::project::{get_main_crate_ids_from_project, setup_project, ProjectConfig};
+use crate::timings::Timings;
 
+pub mod api;
 pub mod db;
 pub mod diagnostics;
+pub mod fixes;
+pub mod incremental;
+pub mod parallel;
 pub mod project;
+pub mod timings;
+pub mod watch;
 
 /// Configuration for the compiler.
 pub struct CompilerConfig<'c> {
     pub diagnostics_reporter: DiagnosticsReporter<'c>,
 
-    /// Replaces sierra ids with human-readable ones.
+    /// Replaces sierra ids with human-readable ones. Otherwise, the ids are made canonical -
+    /// numbered by the order of their declarations.
     pub replace_ids: bool,
 
+    /// Removes the unreachable functions and statements, and the unused declarations, from the
+    /// Sierra program. Off by default, so that the program is not changed unless requested; the
+    /// command line tools turn it on unless given `--keep-dead-code`.
+    pub eliminate_dead_code: bool,
+
+    /// Folds the constant arithmetic and prunes the statically decided branches of the Sierra
+    /// program.
+    pub fold_constants: bool,
+
+    /// Adds the span of the Cairo code each Sierra statement was generated from to the compiled
+    /// contract classes.
+    pub add_source_map: bool,
+
     /// The name of the allowed libfuncs list to use in compilation.
     /// If None the default list of audited libfuncs will be used.
     pub allowed_libfuncs_list_name: Option<String>,
+
+    /// A directory for caching the compiled Sierra program across compilations. A program
+    /// compiled from the exact same inputs is reused instead of generated again, once the
+    /// diagnostics of the compilation are checked.
+    pub incremental_dir: Option<PathBuf>,
+
+    /// The `cfg` options to enable in the compilation, in addition to those of the project.
+    pub cfg_set: CfgSet,
+
+    /// Measures the phases of the compilation of each crate. The Sierra generation of a
+    /// compilation reusing a cached program is not measured.
+    pub timings: Option<&'c mut Timings>,
+
+    /// The number of threads the modules are checked and lowered, and the functions are
+    /// Sierra-generated on. With a single thread, the program is compiled on the calling thread.
+    pub jobs: usize,
 }
 
 /// The default compiler configuration.
@@ -38,7 +82,14 @@ impl Default for CompilerConfig<'static> {
         CompilerConfig {
             diagnostics_reporter: DiagnosticsReporter::default(),
             replace_ids: false,
+            eliminate_dead_code: false,
+            fold_constants: false,
+            add_source_map: false,
             allowed_libfuncs_list_name: None,
+            incremental_dir: None,
+            cfg_set: CfgSet::new(),
+            timings: None,
+            jobs: 1,
         }
     }
 }
@@ -99,15 +150,57 @@ pub fn compile_prepared_db(
     main_crate_ids: Vec<CrateId>,
     mut compiler_config: CompilerConfig<'_>,
 ) -> Result<SierraProgram> {
+    db.use_cfg(&compiler_config.cfg_set);
+    if let Some(timings) = compiler_config.timings.as_deref_mut() {
+        timings.measure_crate_phases(db, &db.crates());
+    }
+    if compiler_config.jobs > 1 {
+        check_modules_in_parallel(db, compiler_config.jobs);
+    }
     compiler_config.diagnostics_reporter.ensure(db)?;
 
+    let cache = compiler_config.incremental_dir.as_ref().map(|directory| {
+        let fingerprint = compilation_fingerprint(db, &main_crate_ids, &compiler_config);
+        (CompilationCache::new(directory), fingerprint)
+    });
+    if let Some((cache, fingerprint)) = &cache {
+        if let Some(sierra_program) = cache.load(fingerprint) {
+            log::info!("Reusing the Sierra program cached in `{fingerprint}`.");
+            return Ok(Arc::new(sierra_program));
+        }
+    }
+
+    if let Some(timings) = compiler_config.timings.as_deref_mut() {
+        timings.measure_sierra_gen(db, &main_crate_ids);
+    }
+    if compiler_config.jobs > 1 {
+        generate_sierra_in_parallel(db, &main_crate_ids, compiler_config.jobs);
+    }
     let mut sierra_program = db
         .get_sierra_program(main_crate_ids)
         .to_option()
         .context("Compilation failed without any diagnostics")?;
 
+    if compiler_config.fold_constants {
+        sierra_program = Arc::new(fold_constants(db, &sierra_program));
+    }
+    if compiler_config.eliminate_dead_code {
+        // The program only holds the functions of the main crates and the functions they call, so
+        // all of its functions are kept.
+        let roots =
+            sierra_program.funcs.iter().map(|function| function.id.clone()).collect::<Vec<_>>();
+        sierra_program = Arc::new(eliminate_dead_code(db, &sierra_program, &roots));
+    }
     if compiler_config.replace_ids {
         sierra_program = Arc::new(replace_sierra_ids_in_program(db, &sierra_program));
+    } else {
+        // The ids are interned in the order in which the functions were generated, which depends
+        // on the number of threads, so they are replaced by ids in the order of the program.
+        let replacer = CanonicalReplacer::from_program(&sierra_program);
+        sierra_program = Arc::new(replacer.apply(&sierra_program));
+    }
+    if let Some((cache, fingerprint)) = &cache {
+        cache.store(fingerprint, &sierra_program)?;
     }
 
     Ok(sierra_program)
@@ -0,0 +1,147 @@
+//! Computing the queries of a compilation on worker threads, before the compilation uses their
+//! results on the calling thread.
+//!
+//! Each thread computes the queries of the items it takes from a shared queue on a snapshot of the
+//! database, so their results are cached in the database itself. A query needed by several
+//! threads is computed by one of them, while the others wait for its result.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::thread;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::program_generator::{
+    crates_function_ids, try_get_function_with_body_id,
+};
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+
+use crate::db::RootDatabase;
+
+#[cfg(test)]
+#[path = "parallel_test.rs"]
+mod test;
+
+/// Computes the diagnostics of all the modules of the crates in the database, up to their
+/// lowering, on `jobs` threads.
+pub fn check_modules_in_parallel(db: &RootDatabase, jobs: usize) {
+    let module_ids = db
+        .crates()
+        .into_iter()
+        .flat_map(|crate_id| db.crate_modules(crate_id).iter().copied().collect::<Vec<_>>())
+        .collect();
+    run_in_parallel(db, jobs, module_ids, |db, module_id| {
+        let _ = db.module_semantic_diagnostics(module_id);
+        let _ = db.module_lint_diagnostics(module_id);
+        let _ = db.module_lowering_diagnostics(module_id);
+        vec![]
+    });
+}
+
+/// Generates the Sierra code of the functions of the Sierra program of the given crates, and of the
+/// functions they call, on `jobs` threads.
+pub fn generate_sierra_in_parallel(db: &RootDatabase, crate_ids: &[CrateId], jobs: usize) {
+    let Ok(function_ids) = crates_function_ids(db, crate_ids) else { return };
+    generate_functions_sierra_in_parallel(db, function_ids, jobs);
+}
+
+/// Generates the Sierra code of the given functions, and of the functions they call, on `jobs`
+/// threads.
+pub fn generate_functions_sierra_in_parallel(
+    db: &RootDatabase,
+    function_ids: Vec<ConcreteFunctionWithBodyId>,
+    jobs: usize,
+) {
+    run_in_parallel(db, jobs, function_ids, |db, function_id| {
+        let Ok(function) = db.function_with_body_sierra(function_id) else { return vec![] };
+        function
+            .body
+            .iter()
+            .filter_map(|statement| try_get_function_with_body_id(db, statement))
+            .collect()
+    });
+}
+
+/// The items waiting to be processed by the worker threads.
+struct WorkQueue<T: Eq + Hash> {
+    pending: VecDeque<T>,
+    /// The items that were queued so far, so each item is processed once.
+    queued: UnorderedHashSet<T>,
+    /// The number of items being processed, whose processing may queue more items.
+    in_progress: usize,
+}
+
+/// Marks the item a thread took from the queue as processed when dropped, after the items its
+/// processing returned were queued. As it is also dropped when the processing panics, the other
+/// threads do not wait for the item forever, and the panic is propagated once they are done.
+struct InProgressGuard<'a, T: Eq + Hash> {
+    queue: &'a Mutex<WorkQueue<T>>,
+    changed: &'a Condvar,
+}
+impl<T: Eq + Hash> Drop for InProgressGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+        queue.in_progress -= 1;
+        self.changed.notify_all();
+    }
+}
+
+/// Runs `process` on each of the given items, and on each of the items it returns, on `jobs`
+/// threads, each with its own snapshot of the database. Each item is processed once.
+fn run_in_parallel<T: Copy + Eq + Hash + Send>(
+    db: &RootDatabase,
+    jobs: usize,
+    items: Vec<T>,
+    process: impl Fn(&RootDatabase, T) -> Vec<T> + Sync,
+) {
+    let mut queue =
+        WorkQueue { pending: VecDeque::new(), queued: Default::default(), in_progress: 0 };
+    for item in items {
+        if queue.queued.insert(item) {
+            queue.pending.push_back(item);
+        }
+    }
+    let queue = Mutex::new(queue);
+    let changed = Condvar::new();
+    let snapshots = (0..jobs.max(1)).map(|_| db.snapshot()).collect::<Vec<_>>();
+    thread::scope(|scope| {
+        for snapshot in snapshots {
+            let (queue, changed, process) = (&queue, &changed, &process);
+            scope.spawn(move || {
+                loop {
+                    let item = {
+                        let mut queue = queue.lock().unwrap();
+                        loop {
+                            if let Some(item) = queue.pending.pop_front() {
+                                queue.in_progress += 1;
+                                break Some(item);
+                            }
+                            if queue.in_progress == 0 {
+                                break None;
+                            }
+                            queue = changed.wait(queue).unwrap();
+                        }
+                    };
+                    let Some(item) = item else {
+                    changed.notify_all();
+                    return;
+                };
+                    let _guard = InProgressGuard { queue, changed };
+                    let new_items = process(&snapshot, item);
+                    let mut queue = queue.lock().unwrap();
+                    for new_item in new_items {
+                        if queue.queued.insert(new_item) {
+                            queue.pending.push_back(new_item);
+                        }
+                    }
+                }
+            });
+        }
+    });
+}
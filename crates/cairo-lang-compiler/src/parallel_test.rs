@@ -0,0 +1,84 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use indoc::indoc;
+
+use super::run_in_parallel;
+use crate::db::RootDatabase;
+use crate::project::setup_in_memory_project;
+use crate::{compile_prepared_db, CompilerConfig, SierraProgram};
+
+/// Compiles a crate with a few modules on the given number of threads.
+fn compile_on_threads(jobs: usize, replace_ids: bool) -> SierraProgram {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = setup_in_memory_project(
+        &mut db,
+        "test",
+        [
+            (
+                "lib.cairo".into(),
+                indoc! {"
+                    mod a;
+                    mod b;
+
+                    fn main() -> u128 {
+                        a::sum(3) + b::product(4)
+                    }
+                "}
+                .into(),
+            ),
+            (
+                "a.cairo".into(),
+                indoc! {"
+                    fn sum(n: u128) -> u128 {
+                        if n == 0 { 0 } else { n + sum(n - 1) }
+                    }
+                "}
+                .into(),
+            ),
+            (
+                "b.cairo".into(),
+                indoc! {"
+                    use array::ArrayTrait;
+
+                    fn product(n: u128) -> u128 {
+                        let mut arr: Array<u128> = Default::default();
+                        arr.append(n);
+                        *arr.at(0) * 2
+                    }
+                "}
+                .into(),
+            ),
+        ],
+    )
+    .unwrap();
+    compile_prepared_db(
+        &mut db,
+        vec![crate_id],
+        CompilerConfig { replace_ids, jobs, ..CompilerConfig::default() },
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_compile_on_threads() {
+    for replace_ids in [false, true] {
+        pretty_assertions::assert_eq!(
+            compile_on_threads(4, replace_ids).to_string(),
+            compile_on_threads(1, replace_ids).to_string()
+        );
+    }
+}
+
+#[test]
+fn test_panic_on_threads() {
+    let db = RootDatabase::builder().build().unwrap();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_in_parallel(&db, 4, vec![0, 1, 2, 3], |_, item: usize| {
+            if item == 5 {
+                panic!("Failed processing an item.");
+            }
+            if item < 12 { vec![item + 4] } else { vec![] }
+        })
+    }));
+    assert!(result.is_err());
+}
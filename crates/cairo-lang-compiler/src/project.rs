@@ -18,8 +18,14 @@ pub enum ProjectError {
     BadPath { path: String },
     #[error("Failed to load project config.")]
     LoadProjectError,
+    #[error("The in-memory project has no lib.cairo file.")]
+    NoInMemoryLibFile,
 }
 
+/// The root directory of the crate of an in-memory project. It does not exist on the disk, so the
+/// files of the crate are only the ones held in memory.
+pub const IN_MEMORY_ROOT: &str = "<memory>";
+
 /// Setup to 'db' to compile the file at the given path.
 /// Returns the id of the generated crate.
 fn setup_single_file_project(
@@ -57,16 +63,34 @@ fn setup_single_file_project(
     }
 }
 
-/// Updates the crate roots from a ProjectConfig object.
+/// Setup the 'db' to compile a project held in memory: a single crate with the given name, whose
+/// files are given by their paths relative to the crate root, e.g. `lib.cairo` and `a/b.cairo`.
+/// Returns the id of the crate.
+pub fn setup_in_memory_project(
+    db: &mut dyn SemanticGroup,
+    crate_name: &str,
+    files: impl IntoIterator<Item = (PathBuf, String)>,
+) -> Result<CrateId, ProjectError> {
+    let files =
+        files.into_iter().map(|(path, content)| (path, Arc::new(content))).collect::<Vec<_>>();
+    if !files.iter().any(|(path, _)| path == Path::new("lib.cairo")) {
+        return Err(ProjectError::NoInMemoryLibFile);
+    }
+    let root = Directory(IN_MEMORY_ROOT.into());
+    db.as_files_group_mut().override_directory_content(&root, files);
+    let crate_id = db.intern_crate(CrateLongId(crate_name.into()));
+    db.set_crate_root(crate_id, Some(root));
+    Ok(crate_id)
+}
+
+/// Updates the crate roots from a ProjectConfig object, including the crates of its workspace
+/// members and dependencies, enables the `cfg` options of the project and sets its lint levels.
 pub fn update_crate_roots_from_project_config(db: &mut dyn SemanticGroup, config: ProjectConfig) {
-    for (crate_name, directory_path) in config.content.crate_roots {
+    db.use_cfg(&config.content.cfg);
+    db.set_lint_levels(Arc::new(config.content.lints.clone()));
+    for (crate_name, crate_config) in config.crates {
         let crate_id = db.intern_crate(CrateLongId(crate_name));
-        let mut path = PathBuf::from(&directory_path);
-        if path.is_relative() {
-            path = PathBuf::from(&config.base_path).join(path);
-        }
-        let root = Directory(path);
-        db.set_crate_root(crate_id, Some(root));
+        db.set_crate_root(crate_id, Some(Directory(crate_config.root)));
     }
 }
 
@@ -91,14 +115,16 @@ pub fn setup_project(
     }
 }
 
+/// Returns the ids of the main crates of the project - the crates to compile, as opposed to the
+/// crates of its dependencies.
 pub fn get_main_crate_ids_from_project(
     db: &mut dyn SemanticGroup,
     config: &ProjectConfig,
 ) -> Vec<CrateId> {
     config
-        .content
-        .crate_roots
-        .keys()
-        .map(|crate_id| db.intern_crate(CrateLongId(crate_id.clone())))
+        .crates
+        .iter()
+        .filter(|(_, crate_config)| crate_config.main)
+        .map(|(crate_name, _)| db.intern_crate(CrateLongId(crate_name.clone())))
         .collect()
 }
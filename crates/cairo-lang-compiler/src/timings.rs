@@ -0,0 +1,222 @@
+//! Measuring the wall time and the peak memory of the phases of a compilation, to find where the
+//! compilation of a project spends its time.
+//!
+//! The queries of the compiler are computed lazily, so a phase is measured by computing all its
+//! queries for a crate, before the later phases need them. The queries of other crates needed by a
+//! phase of a crate, and not computed yet, are measured as part of it.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::{CrateId, FileId};
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use serde_json::json;
+
+use crate::db::RootDatabase;
+use crate::incremental::crate_module_paths;
+
+#[cfg(test)]
+#[path = "timings_test.rs"]
+mod test;
+
+/// A phase of a compilation. Each phase uses the results of the previous ones.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Phase {
+    /// Parsing the files of the modules of a crate. Finding the modules of the crate runs the
+    /// plugins on the items of its files as well.
+    Parse,
+    /// Parsing the code generated by the plugins for the items of the modules of a crate.
+    PluginExpansion,
+    /// The semantic analysis of the modules of a crate, including their lints.
+    Semantic,
+    /// Lowering the functions of a crate.
+    Lowering,
+    /// Generating the Sierra program of a crate.
+    SierraGen,
+    /// Compiling the Sierra program to CASM.
+    SierraToCasm,
+}
+impl Phase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::PluginExpansion => "plugin-expansion",
+            Phase::Semantic => "semantic",
+            Phase::Lowering => "lowering",
+            Phase::SierraGen => "sierra-gen",
+            Phase::SierraToCasm => "sierra-to-casm",
+        }
+    }
+}
+
+/// The measurement of a phase of a compilation.
+#[derive(Clone, Debug)]
+pub struct PhaseTiming {
+    pub phase: Phase,
+    /// The crate the phase was run for, or `None` for a phase of the whole program.
+    pub crate_name: Option<String>,
+    /// The time from the start of the measurements to the start of the phase.
+    pub start: Duration,
+    pub duration: Duration,
+    /// The peak resident memory of the process, in bytes, by the end of the phase. Only known on
+    /// Linux.
+    pub peak_memory: Option<u64>,
+}
+
+/// The measurements of the phases of a compilation.
+#[derive(Debug)]
+pub struct Timings {
+    start: Instant,
+    pub phases: Vec<PhaseTiming>,
+}
+impl Default for Timings {
+    fn default() -> Self {
+        Self { start: Instant::now(), phases: vec![] }
+    }
+}
+impl Timings {
+    /// Runs `f` as the given phase, for the given crate or for the whole program, and records its
+    /// measurement.
+    pub fn measure<T>(
+        &mut self,
+        phase: Phase,
+        crate_name: Option<&str>,
+        f: impl FnOnce() -> T,
+    ) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.phases.push(PhaseTiming {
+            phase,
+            crate_name: crate_name.map(String::from),
+            start: start - self.start,
+            duration: start.elapsed(),
+            peak_memory: peak_memory(),
+        });
+        result
+    }
+
+    /// Computes the phases up to the lowering of the given crates, in order, measuring each phase
+    /// of each crate.
+    pub fn measure_crate_phases(&mut self, db: &RootDatabase, crate_ids: &[CrateId]) {
+        let crate_names = crate_ids
+            .iter()
+            .map(|crate_id| db.lookup_intern_crate(*crate_id).0)
+            .collect::<Vec<_>>();
+        for (crate_id, crate_name) in crate_ids.iter().zip(&crate_names) {
+            self.measure(Phase::Parse, Some(crate_name.as_str()), || {
+                for path in crate_module_paths(db, *crate_id) {
+                    let _ = db.file_syntax(FileId::new(db, path));
+                }
+            });
+        }
+        for (crate_id, crate_name) in crate_ids.iter().zip(&crate_names) {
+            self.measure(Phase::PluginExpansion, Some(crate_name.as_str()), || {
+                for module_id in db.crate_modules(*crate_id).iter() {
+                    for file_id in db.module_files(*module_id).unwrap_or_default() {
+                        let _ = db.file_syntax(file_id);
+                    }
+                }
+            });
+        }
+        for (crate_id, crate_name) in crate_ids.iter().zip(&crate_names) {
+            self.measure(Phase::Semantic, Some(crate_name.as_str()), || {
+                for module_id in db.crate_modules(*crate_id).iter() {
+                    let _ = db.module_semantic_diagnostics(*module_id);
+                    let _ = db.module_lint_diagnostics(*module_id);
+                }
+            });
+        }
+        for (crate_id, crate_name) in crate_ids.iter().zip(&crate_names) {
+            self.measure(Phase::Lowering, Some(crate_name.as_str()), || {
+                for module_id in db.crate_modules(*crate_id).iter() {
+                    let _ = db.module_lowering_diagnostics(*module_id);
+                }
+            });
+        }
+    }
+
+    /// Generates the Sierra program of each of the given crates, measuring each.
+    pub fn measure_sierra_gen(&mut self, db: &RootDatabase, crate_ids: &[CrateId]) {
+        for crate_id in crate_ids {
+            let crate_name = db.lookup_intern_crate(*crate_id).0;
+            self.measure(Phase::SierraGen, Some(crate_name.as_str()), || {
+                let _ = db.get_sierra_program(vec![*crate_id]);
+            });
+        }
+    }
+
+    /// Returns a table of the measured phases, a phase per line, followed by their total time.
+    pub fn report(&self) -> String {
+        let mut report =
+            format!("{:<18}{:<24}{:>12}{:>16}\n", "Phase", "Crate", "Time", "Peak memory");
+        for timing in &self.phases {
+            let peak_memory = timing
+                .peak_memory
+                .map_or("-".into(), |bytes| format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0)));
+            writeln!(
+                report,
+                "{:<18}{:<24}{:>12}{:>16}",
+                timing.phase.name(),
+                timing.crate_name.as_deref().unwrap_or("-"),
+                format!("{:.2?}", timing.duration),
+                peak_memory
+            )
+            .unwrap();
+        }
+        let total = self.phases.iter().map(|timing| timing.duration).sum::<Duration>();
+        writeln!(report, "Total: {total:.2?}").unwrap();
+        report
+    }
+
+    /// Returns the measured phases in the Chrome trace event format, to view them on a timeline,
+    /// e.g. in `chrome://tracing` or Perfetto.
+    pub fn chrome_trace(&self) -> serde_json::Value {
+        let events = self
+            .phases
+            .iter()
+            .map(|timing| {
+                json!({
+                    "name": timing.phase.name(),
+                    "cat": timing.crate_name.as_deref().unwrap_or("program"),
+                    "ph": "X",
+                    "ts": timing.start.as_micros() as u64,
+                    "dur": timing.duration.as_micros() as u64,
+                    "pid": 0,
+                    "tid": 0,
+                    "args": {
+                        "crate": timing.crate_name,
+                        "peak_memory_bytes": timing.peak_memory,
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({ "traceEvents": events, "displayTimeUnit": "ms" })
+    }
+
+    /// Prints the report of the measured phases to stderr, and writes them to the given file in the
+    /// Chrome trace event format, if any.
+    pub fn print_report(&self, chrome_trace_path: Option<&Path>) -> anyhow::Result<()> {
+        eprint!("{}", self.report());
+        if let Some(path) = chrome_trace_path {
+            fs::write(path, serde_json::to_string_pretty(&self.chrome_trace())?)
+                .with_context(|| format!("Failed to write the trace to `{}`.", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the peak resident memory of the process, in bytes, or `None` if it is unknown.
+fn peak_memory() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes = line["VmHWM:".len()..].trim().strip_suffix("kB")?.trim().parse::<u64>().ok()?;
+    Some(kilobytes * 1024)
+}
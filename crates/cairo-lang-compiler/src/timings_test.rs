@@ -0,0 +1,56 @@
+use super::{Phase, Timings};
+use crate::db::RootDatabase;
+use crate::project::setup_in_memory_project;
+use crate::{compile_prepared_db, CompilerConfig};
+
+#[test]
+fn test_timings() {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = setup_in_memory_project(
+        &mut db,
+        "test",
+        [("lib.cairo".into(), "fn foo() -> felt252 {\n    1\n}\n".into())],
+    )
+    .unwrap();
+    let mut timings = Timings::default();
+    compile_prepared_db(
+        &mut db,
+        vec![crate_id],
+        CompilerConfig { timings: Some(&mut timings), ..CompilerConfig::default() },
+    )
+    .unwrap();
+    timings.measure(Phase::SierraToCasm, None, || {});
+
+    let phases = timings
+        .phases
+        .iter()
+        .map(|timing| (timing.phase.name(), timing.crate_name.as_deref()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        phases,
+        [
+            ("parse", Some("core")),
+            ("parse", Some("test")),
+            ("plugin-expansion", Some("core")),
+            ("plugin-expansion", Some("test")),
+            ("semantic", Some("core")),
+            ("semantic", Some("test")),
+            ("lowering", Some("core")),
+            ("lowering", Some("test")),
+            ("sierra-gen", Some("test")),
+            ("sierra-to-casm", None),
+        ]
+    );
+    // The phases follow each other.
+    for (timing, next) in timings.phases.iter().zip(timings.phases.iter().skip(1)) {
+        assert!(timing.start + timing.duration <= next.start);
+    }
+
+    let report = timings.report();
+    assert!(report.starts_with("Phase"));
+    assert_eq!(report.lines().count(), phases.len() + 2);
+    let trace = timings.chrome_trace();
+    assert_eq!(trace["traceEvents"].as_array().unwrap().len(), phases.len());
+    assert_eq!(trace["traceEvents"][9]["name"], "sierra-to-casm");
+    assert_eq!(trace["traceEvents"][9]["cat"], "program");
+}
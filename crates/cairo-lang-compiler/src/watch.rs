@@ -0,0 +1,117 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+use std::{fs, thread};
+
+use anyhow::Result;
+use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup, PrivRawFileContentQuery};
+use cairo_lang_filesystem::ids::{CrateId, FileLongId};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+use crate::db::RootDatabase;
+use crate::incremental::crate_module_paths;
+use crate::project::{setup_project, PROJECT_FILE_NAME};
+
+#[cfg(test)]
+#[path = "watch_test.rs"]
+mod test;
+
+/// The interval at which the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// The modification time and size of a file, which change when the file is modified.
+type FileStamp = (Option<SystemTime>, u64);
+
+/// Watcher of the files of a project: the files of the modules of all the crates in the database,
+/// and the project file of the project, if the project is a directory.
+pub struct ProjectWatcher {
+    /// The path of the project, either a directory with a project file or a Cairo file.
+    path: PathBuf,
+    /// The stamps of the watched files, when they were last checked.
+    stamps: OrderedHashMap<PathBuf, FileStamp>,
+}
+impl ProjectWatcher {
+    pub fn new(db: &RootDatabase, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let stamps = watched_stamps(db, &path);
+        Self { path, stamps }
+    }
+
+    /// Returns the files that were modified, added or removed since the last check, and
+    /// invalidates their content in the database, so it is read again from the disk.
+    pub fn changed_files(&mut self, db: &mut RootDatabase) -> Vec<PathBuf> {
+        let stamps = watched_stamps(db, &self.path);
+        let mut changed = stamps
+            .iter()
+            .filter(|(file, stamp)| self.stamps.get(*file) != Some(*stamp))
+            .map(|(file, _)| file.clone())
+            .collect::<Vec<_>>();
+        changed.extend(self.stamps.keys().filter(|file| !stamps.contains_key(*file)).cloned());
+        for file in &changed {
+            let file_id = db.intern_file(FileLongId::OnDisk(file.clone()));
+            PrivRawFileContentQuery.in_db_mut(db.as_files_group_mut()).invalidate(&file_id);
+        }
+        // The changed files may declare other modules, whose files are watched from now on.
+        self.stamps = if changed.is_empty() { stamps } else { watched_stamps(db, &self.path) };
+        changed
+    }
+}
+
+/// Returns the stamps of the existing files watched for a project at the given path.
+fn watched_stamps(db: &RootDatabase, path: &Path) -> OrderedHashMap<PathBuf, FileStamp> {
+    let mut files = vec![];
+    for crate_id in db.crates() {
+        files.extend(crate_module_paths(db, crate_id));
+    }
+    if path.is_dir() {
+        files.push(path.join(PROJECT_FILE_NAME));
+    }
+    files.sort();
+    files.dedup();
+    files.into_iter().filter_map(|file| Some((file.clone(), file_stamp(&file)?))).collect()
+}
+
+/// Returns the stamp of a file, or `None` if it does not exist.
+fn file_stamp(path: &Path) -> Option<FileStamp> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok(), metadata.len()))
+}
+
+/// Compiles the project at the given path with `compile`, and again whenever its files change.
+/// The same database is used for all the compilations, so only the queries depending on the
+/// changed files are computed again. The project is set up again when its project file changes.
+///
+/// A line reporting the result of each compilation is printed to stderr, along with the changed
+/// files triggering it. Returns only if the project cannot be set up initially.
+pub fn watch_project(
+    db: &mut RootDatabase,
+    path: &Path,
+    mut compile: impl FnMut(&mut RootDatabase, Vec<CrateId>) -> Result<()>,
+) -> Result<()> {
+    let mut main_crate_ids = setup_project(db, path)?;
+    let mut watcher = ProjectWatcher::new(db, path);
+    loop {
+        let start = Instant::now();
+        match compile(db, main_crate_ids.clone()) {
+            Ok(()) => eprintln!("Compiled in {:.2?}. Watching for changes.", start.elapsed()),
+            Err(error) => eprintln!("{error:#} Watching for changes."),
+        }
+        let changed = loop {
+            thread::sleep(POLL_INTERVAL);
+            let changed = watcher.changed_files(db);
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        let changed_names =
+            changed.iter().map(|file| format!("`{}`", file.display())).collect::<Vec<_>>();
+        eprintln!("Recompiling after changes in {}.", changed_names.join(", "));
+        if changed.iter().any(|file| file.ends_with(PROJECT_FILE_NAME)) {
+            match setup_project(db, path) {
+                Ok(crate_ids) => main_crate_ids = crate_ids,
+                Err(error) => eprintln!("{error} Keeping the previous project configuration."),
+            }
+            // The files of the crates added to the project are watched from now on.
+            watcher = ProjectWatcher::new(db, path);
+        }
+    }
+}
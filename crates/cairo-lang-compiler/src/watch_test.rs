@@ -0,0 +1,51 @@
+use std::fs;
+
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+
+use super::ProjectWatcher;
+use crate::db::RootDatabase;
+
+#[test]
+fn test_changed_files() {
+    let directory =
+        std::env::temp_dir().join(format!("cairo_lang_compiler_watch_test_{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let lib_path = directory.join("lib.cairo");
+    fs::write(&lib_path, "fn foo() {}\n").unwrap();
+
+    let mut db = RootDatabase::default();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    db.set_crate_root(crate_id, Some(Directory(directory.clone())));
+    let lib_file = db.intern_file(FileLongId::OnDisk(lib_path.clone()));
+    let syntax_text =
+        |db: &RootDatabase| db.file_syntax(lib_file).unwrap().as_syntax_node().get_text(db);
+    assert_eq!(syntax_text(&db), "fn foo() {}\n");
+
+    let mut watcher = ProjectWatcher::new(&db, &lib_path);
+    assert!(watcher.changed_files(&mut db).is_empty());
+
+    // A modified file is read again.
+    fs::write(&lib_path, "fn foo() {}\nfn bar() {}\n").unwrap();
+    assert_eq!(watcher.changed_files(&mut db), vec![lib_path.clone()]);
+    assert_eq!(syntax_text(&db), "fn foo() {}\nfn bar() {}\n");
+    assert!(watcher.changed_files(&mut db).is_empty());
+
+    // Only the files of the modules of the crate are watched.
+    let module_path = directory.join("module.cairo");
+    fs::write(&module_path, "fn baz() {}\n").unwrap();
+    assert!(watcher.changed_files(&mut db).is_empty());
+    fs::write(&lib_path, "mod module;\nfn foo() {}\nfn bar() {}\n").unwrap();
+    assert_eq!(watcher.changed_files(&mut db), vec![lib_path]);
+    assert!(watcher.changed_files(&mut db).is_empty());
+    fs::write(&module_path, "fn baz() {}\nfn qux() {}\n").unwrap();
+    assert_eq!(watcher.changed_files(&mut db), vec![module_path.clone()]);
+
+    // Removed files are changes as well.
+    fs::remove_file(&module_path).unwrap();
+    assert_eq!(watcher.changed_files(&mut db), vec![module_path]);
+
+    fs::remove_dir_all(directory).unwrap();
+}
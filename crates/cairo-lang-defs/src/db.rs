@@ -240,6 +240,8 @@ pub struct GeneratedFileInfo {
     pub aux_data: DynGeneratedFileAuxData,
     /// The module and file index from which the current file was generated.
     pub origin: ModuleFileId,
+    /// The item in the origin file from which the current file was generated.
+    pub origin_item: SyntaxStablePtrId,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -328,6 +330,7 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
                     res.generated_file_infos.push(Some(GeneratedFileInfo {
                         aux_data: generated.aux_data,
                         origin: module_file_id,
+                        origin_item: item_ast.stable_ptr().untyped(),
                     }));
                     module_queue.push_back((new_file, db.file_syntax(new_file)?.items(syntax_db)));
                 }
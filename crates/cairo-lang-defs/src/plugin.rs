@@ -2,6 +2,7 @@ use std::any::Any;
 use std::ops::Deref;
 use std::sync::Arc;
 
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
@@ -60,6 +61,20 @@ pub struct PluginResult {
 pub struct PluginDiagnostic {
     pub stable_ptr: SyntaxStablePtrId,
     pub message: String,
+    /// The stable code of the kind of the diagnostic, e.g. `SN001` for the Starknet plugin.
+    pub code: Option<ErrorCode>,
+    /// Lints are reported as warnings, which do not fail the compilation.
+    pub severity: Severity,
+    /// Replacements of code that fix the diagnostic, and can be applied without a review.
+    pub fixes: Vec<PluginFix>,
+}
+
+/// A suggested fix of a [PluginDiagnostic]: replacing the code of a node, without its trivia, with
+/// a new text.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PluginFix {
+    pub stable_ptr: SyntaxStablePtrId,
+    pub replacement: String,
 }
 
 // TOD(spapini): Move to another place.
@@ -2,6 +2,7 @@ use std::fmt::Write as _;
 use std::sync::Arc;
 
 use cairo_lang_debug::debug::DebugWithDb;
+use cairo_lang_diagnostics::Severity;
 use cairo_lang_filesystem::db::{
     init_files_group, AsFilesGroupMut, FilesDatabase, FilesGroup, FilesGroupEx,
 };
@@ -245,6 +246,9 @@ impl MacroPlugin for DummyPlugin {
                 diagnostics: vec![PluginDiagnostic {
                     stable_ptr: free_function_ast.stable_ptr().untyped(),
                     message: "bla".into(),
+                    code: None,
+                    severity: Severity::Error,
+                    fixes: vec![],
                 }],
                 remove_original_item: false,
             },
@@ -2,6 +2,7 @@
 #[path = "diagnostics_test.rs"]
 mod test;
 
+use std::fmt::Display;
 use std::sync::Arc;
 
 use cairo_lang_filesystem::db::FilesGroup;
@@ -9,8 +10,10 @@ use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_utils::Upcast;
 use itertools::Itertools;
+use serde::{Deserialize, Serialize, Serializer};
 
 use crate::location_marks::get_location_marks;
+use crate::structured::StructuredDiagnostic;
 
 /// A trait for diagnostics (i.e., errors and warnings) across the compiler.
 /// Meant to be implemented by each module that may produce diagnostics.
@@ -18,8 +21,96 @@ pub trait DiagnosticEntry: Clone + std::fmt::Debug + Eq + std::hash::Hash {
     type DbType: Upcast<dyn FilesGroup> + ?Sized;
     fn format(&self, db: &Self::DbType) -> String;
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation;
+    /// The stable code of the kind of the diagnostic, if it has one.
+    fn error_code(&self) -> Option<ErrorCode> {
+        None
+    }
+    /// The default severity of the diagnostic. Only errors fail the compilation, and the level of
+    /// warnings may be overridden per code.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    /// Additional information about the diagnostic, e.g. the diagnostic it was caused by.
+    fn notes(&self, _db: &Self::DbType) -> Vec<String> {
+        vec![]
+    }
+    /// Replacements of code that fix the diagnostic, and can be applied without a review.
+    fn fixes(&self, _db: &Self::DbType) -> Vec<DiagnosticFix> {
+        vec![]
+    }
+    /// The locations in code generated by plugins that the diagnostic was mapped from, its
+    /// expansion backtrace: from the code generated from its location, to the code it originates
+    /// in.
+    fn expansion(&self, _db: &Self::DbType) -> Vec<DiagnosticLocation> {
+        vec![]
+    }
     // TODO(spapini): Add a way to inspect the diagnostic programmatically, e.g, downcast.
 }
+
+/// A stable code identifying the kind of a diagnostic, e.g. `E0001`, for looking it up and for
+/// matching it in tools.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ErrorCode(&'static str);
+impl ErrorCode {
+    pub const fn new(code: &'static str) -> Self {
+        Self(code)
+    }
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// The level at which the warnings of a code are reported, set by the `--allow`, `--warn` and
+/// `--deny` flags, by the `[lints]` section of the project file, or by an `#[allow(CODE)]`,
+/// `#[warn(CODE)]` or `#[deny(CODE)]` attribute of an item enclosing the warning. Errors are always
+/// reported as errors.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// The warnings are not reported.
+    Allow,
+    /// The warnings are reported, and do not fail the compilation.
+    Warn,
+    /// The warnings are reported as errors.
+    Deny,
+}
+impl LintLevel {
+    /// Returns the level set by an attribute with the given name, if it is a level attribute.
+    pub fn from_attribute(attribute: &str) -> Option<Self> {
+        match attribute {
+            "allow" => Some(Self::Allow),
+            "warn" => Some(Self::Warn),
+            "deny" => Some(Self::Deny),
+            _ => None,
+        }
+    }
+}
 pub struct DiagnosticLocation {
     pub file_id: FileId,
     pub span: TextSpan,
@@ -31,6 +122,13 @@ impl DiagnosticLocation {
     }
 }
 
+/// A suggested fix of a diagnostic: replacing the code at a location with a new text. An empty
+/// span inserts the text.
+pub struct DiagnosticFix {
+    pub location: DiagnosticLocation,
+    pub replacement: String,
+}
+
 /// This struct is used to ensure that when an error occurs, a diagnostic is properly reported.
 ///
 /// It must not be constructed directly. Instead it is returned by [DiagnosticsBuilder::add]
@@ -107,16 +205,27 @@ impl<TEntry: DiagnosticEntry> Default for DiagnosticsBuilder<TEntry> {
 
 pub fn format_diagnostics(
     db: &dyn FilesGroup,
+    severity: Severity,
     message: &str,
     location: DiagnosticLocation,
 ) -> String {
+    format!("{severity}: {message}\n{}", format_location(db, location))
+}
+
+/// Formats a location in code generated by a plugin, from the expansion backtrace of a diagnostic.
+pub fn format_expansion(db: &dyn FilesGroup, location: DiagnosticLocation) -> String {
+    format!("note: In the code generated by a plugin:\n{}", format_location(db, location))
+}
+
+/// Formats a location, followed by the line it starts in, with the code it refers to marked.
+fn format_location(db: &dyn FilesGroup, location: DiagnosticLocation) -> String {
     let file_name = location.file_id.file_name(db);
     let marks = get_location_marks(db, &location);
     let pos = match location.span.start.position_in_file(db, location.file_id) {
         Some(pos) => format!("{}:{}", pos.line + 1, pos.col + 1),
         None => "?".into(),
     };
-    format!("error: {message}\n --> {file_name}:{pos}\n{marks}\n")
+    format!(" --> {file_name}:{pos}\n{marks}\n")
 }
 
 /// A set of diagnostic entries that arose during a computation.
@@ -135,8 +244,15 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         self.0.count == 0
     }
 
+    /// Returns `Err` if there are errors among the diagnostics. Warnings are ignored.
     pub fn is_diagnostic_free(&self) -> Maybe<()> {
-        if self.is_empty() { Ok(()) } else { Err(DiagnosticAdded) }
+        if self.has_errors() { Err(DiagnosticAdded) } else { Ok(()) }
+    }
+
+    /// Returns whether any of the diagnostics is an error.
+    pub fn has_errors(&self) -> bool {
+        self.0.leaves.iter().any(|entry| entry.severity() == Severity::Error)
+            || self.0.subtrees.iter().any(|subtree| subtree.has_errors())
     }
 
     pub fn format(&self, db: &TEntry::DbType) -> String {
@@ -144,7 +260,7 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         // Format leaves.
         for entry in &self.0.leaves {
             let message = entry.format(db);
-            res += &format_diagnostics(db.upcast(), &message, entry.location(db));
+            res += &format_diagnostics(db.upcast(), entry.severity(), &message, entry.location(db));
             res += "\n";
         }
         // Format subtrees.
@@ -168,6 +284,21 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         }
     }
 
+    /// Returns the diagnostics in a structured form, in the order in which [Self::format] formats
+    /// them.
+    pub fn structured(&self, db: &TEntry::DbType) -> Vec<StructuredDiagnostic> {
+        let mut res = self
+            .0
+            .leaves
+            .iter()
+            .map(|entry| StructuredDiagnostic::from_entry(db, entry))
+            .collect_vec();
+        for subtree in &self.0.subtrees {
+            res.extend(subtree.structured(db));
+        }
+        res
+    }
+
     // TODO(spapini): This is temporary. Remove once the logic in language server doesn't use this.
     pub fn get_all(&self) -> Vec<TEntry> {
         let mut res = self.0.leaves.clone();
@@ -7,12 +7,15 @@ use cairo_lang_filesystem::test_utils::FilesDatabaseForTesting;
 use indoc::indoc;
 use test_log::test;
 
-use super::{DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder};
+use super::{
+    format_expansion, DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder, ErrorCode, Severity,
+};
 
 // Test diagnostic.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 struct SimpleDiag {
     file_id: FileId,
+    severity: Severity,
 }
 impl DiagnosticEntry for SimpleDiag {
     type DbType = dyn FilesGroup;
@@ -30,6 +33,14 @@ impl DiagnosticEntry for SimpleDiag {
             },
         }
     }
+
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(ErrorCode::new("E0000"))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
 }
 
 fn setup() -> (FilesDatabaseForTesting, FileId) {
@@ -47,7 +58,7 @@ fn test_diagnostics() {
     let (db_val, file_id) = setup();
 
     let mut diagnostics: DiagnosticsBuilder<SimpleDiag> = DiagnosticsBuilder::default();
-    let diagnostic = SimpleDiag { file_id };
+    let diagnostic = SimpleDiag { file_id, severity: Severity::Error };
     diagnostics.add(diagnostic);
 
     assert_eq!(
@@ -61,3 +72,64 @@ fn test_diagnostics() {
         " }
     );
 }
+
+#[test]
+fn test_warnings() {
+    let (db_val, file_id) = setup();
+
+    let mut diagnostics: DiagnosticsBuilder<SimpleDiag> = DiagnosticsBuilder::default();
+    diagnostics.add(SimpleDiag { file_id, severity: Severity::Warning });
+    let diagnostics = diagnostics.build();
+
+    assert!(!diagnostics.has_errors());
+    assert_eq!(diagnostics.is_diagnostic_free(), Ok(()));
+    assert_eq!(
+        diagnostics.format(&db_val),
+        indoc! { "
+            warning: Simple diagnostic.
+             --> dummy_file.sierra:1:1
+            abcd
+            ^**^
+
+        " }
+    );
+}
+
+#[test]
+fn test_format_expansion() {
+    let (db_val, file_id) = setup();
+
+    let location = SimpleDiag { file_id, severity: Severity::Error }.location(&db_val);
+    assert_eq!(
+        format_expansion(&db_val, location),
+        indoc! { "
+            note: In the code generated by a plugin:
+             --> dummy_file.sierra:1:1
+            abcd
+            ^**^
+        " }
+    );
+}
+
+#[test]
+fn test_structured_diagnostics() {
+    let (db_val, file_id) = setup();
+
+    let mut diagnostics: DiagnosticsBuilder<SimpleDiag> = DiagnosticsBuilder::default();
+    diagnostics.add(SimpleDiag { file_id, severity: Severity::Error });
+
+    assert_eq!(
+        serde_json::to_value(diagnostics.build().structured(&db_val)).unwrap(),
+        serde_json::json!([{
+            "code": "E0000",
+            "severity": "error",
+            "message": "Simple diagnostic.",
+            "spans": [{
+                "file": "dummy_file.sierra",
+                "start": { "line": 1, "col": 1 },
+                "end": { "line": 2, "col": 2 },
+            }],
+            "notes": [],
+        }])
+    );
+}
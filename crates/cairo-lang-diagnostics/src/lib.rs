@@ -3,8 +3,11 @@
 
 mod diagnostics;
 mod location_marks;
+mod structured;
 
 pub use self::diagnostics::{
-    format_diagnostics, skip_diagnostic, DiagnosticAdded, DiagnosticEntry, DiagnosticLocation,
-    Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe, ToOption,
+    format_diagnostics, format_expansion, skip_diagnostic, DiagnosticAdded, DiagnosticEntry,
+    DiagnosticFix, DiagnosticLocation, Diagnostics, DiagnosticsBuilder, ErrorCode, LintLevel,
+    Maybe, Severity, ToMaybe, ToOption,
 };
+pub use self::structured::{DiagnosticSpan, SpanPosition, StructuredDiagnostic, StructuredFix};
@@ -0,0 +1,103 @@
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileLongId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_utils::Upcast;
+use serde::Serialize;
+
+use crate::{DiagnosticEntry, DiagnosticFix, DiagnosticLocation, ErrorCode, Severity};
+
+/// A diagnostic in a structured form, e.g. for emitting it as JSON to IDEs and CI tools.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct StructuredDiagnostic {
+    pub code: Option<ErrorCode>,
+    pub severity: Severity,
+    pub message: String,
+    /// The spans of the code the diagnostic refers to, the primary one first.
+    pub spans: Vec<DiagnosticSpan>,
+    pub notes: Vec<String>,
+    /// The suggested fixes of the diagnostic, that can be applied without a review.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<StructuredFix>,
+}
+impl StructuredDiagnostic {
+    /// Returns a diagnostic entry in a structured form, with its default severity.
+    pub fn from_entry<TEntry: DiagnosticEntry>(db: &TEntry::DbType, entry: &TEntry) -> Self {
+        Self {
+            code: entry.error_code(),
+            severity: entry.severity(),
+            message: entry.format(db),
+            spans: DiagnosticSpan::from_location(db.upcast(), entry.location(db))
+                .into_iter()
+                .collect(),
+            notes: entry.notes(db),
+            fixes: entry
+                .fixes(db)
+                .into_iter()
+                .filter_map(|fix| StructuredFix::from_fix(db.upcast(), fix))
+                .collect(),
+        }
+    }
+
+    /// Creates an error with a message and no location.
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            code: None,
+            severity: Severity::Error,
+            message: message.into(),
+            spans: vec![],
+            notes: vec![],
+            fixes: vec![],
+        }
+    }
+}
+
+/// A suggested fix of a diagnostic, in a structured form.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct StructuredFix {
+    /// The span of the replaced code. An empty span inserts the replacement.
+    pub span: DiagnosticSpan,
+    pub replacement: String,
+}
+impl StructuredFix {
+    /// Returns a fix in a structured form, or `None` if the position of its span is not known.
+    pub fn from_fix(db: &dyn FilesGroup, fix: DiagnosticFix) -> Option<Self> {
+        Some(Self {
+            span: DiagnosticSpan::from_location(db, fix.location)?,
+            replacement: fix.replacement,
+        })
+    }
+}
+
+/// A span of code in a file, in the positions an editor shows - 1 based lines and columns.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct DiagnosticSpan {
+    /// The path of the file, or the name of a virtual file - e.g. one generated by a plugin.
+    pub file: String,
+    pub start: SpanPosition,
+    pub end: SpanPosition,
+}
+impl DiagnosticSpan {
+    /// Returns the span of a diagnostic location, or `None` if its position in the file is not
+    /// known.
+    pub fn from_location(db: &dyn FilesGroup, location: DiagnosticLocation) -> Option<Self> {
+        let position = |offset: TextOffset| {
+            let position = offset.position_in_file(db, location.file_id)?;
+            Some(SpanPosition { line: position.line + 1, col: position.col + 1 })
+        };
+        let file = match db.lookup_intern_file(location.file_id) {
+            FileLongId::OnDisk(path) => path.to_string_lossy().into_owned(),
+            FileLongId::Virtual(_) => location.file_id.file_name(db),
+        };
+        Some(Self {
+            file,
+            start: position(location.span.start)?,
+            end: position(location.span.end)?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct SpanPosition {
+    pub line: usize,
+    pub col: usize,
+}
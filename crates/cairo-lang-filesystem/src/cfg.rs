@@ -1,4 +1,5 @@
 use std::fmt;
+use std::str::FromStr;
 
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -41,6 +42,46 @@ impl fmt::Debug for Cfg {
     }
 }
 
+/// Error of parsing a `cfg` option from a string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CfgParseError(String);
+impl fmt::Display for CfgParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid cfg option `{}`, expected `name` or `key=value`.", self.0)
+    }
+}
+impl std::error::Error for CfgParseError {}
+
+/// Parses a `cfg` option given as `name`, or as `key=value` - where the value may be quoted, e.g.
+/// in command line arguments and project files.
+impl FromStr for Cfg {
+    type Err = CfgParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = match s.split_once('=') {
+            Some((key, value)) => {
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|value| value.strip_suffix('"'))
+                    .unwrap_or(value);
+                (key.trim(), Some(value))
+            }
+            None => (s.trim(), None),
+        };
+        let mut chars = key.chars();
+        let is_identifier = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !is_identifier {
+            return Err(CfgParseError(s.into()));
+        }
+        Ok(match value {
+            Some(value) => Cfg::kv(key, value),
+            None => Cfg::name(key),
+        })
+    }
+}
+
 mod serde_ext {
     use serde::{Deserialize, Serialize};
     use smol_str::SmolStr;
@@ -213,4 +254,15 @@ mod tests {
 
         assert_eq!(serde_cfg, cfg);
     }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("name".parse(), Ok(Cfg::name("name")));
+        assert_eq!("k=v".parse(), Ok(Cfg::kv("k", "v")));
+        assert_eq!("k = \"a b\"".parse(), Ok(Cfg::kv("k", "a b")));
+        assert_eq!("k=".parse(), Ok(Cfg::kv("k", "")));
+        assert!("".parse::<Cfg>().is_err());
+        assert!("1k=v".parse::<Cfg>().is_err());
+        assert!("a.b".parse::<Cfg>().is_err());
+    }
 }
@@ -91,6 +91,21 @@ pub trait FilesGroupEx: Upcast<dyn FilesGroup> + AsFilesGroupMut {
         };
         self.as_files_group_mut().set_file_overrides(Arc::new(overrides));
     }
+    /// Overrides the content of the files inside a directory, given by their paths relative to it.
+    /// Used to compile code that is held in memory: with a directory that does not exist on the
+    /// disk, the files of a crate rooted in it are only the given ones.
+    fn override_directory_content(
+        &mut self,
+        directory: &Directory,
+        files: impl IntoIterator<Item = (PathBuf, Arc<String>)>,
+    ) {
+        let mut overrides = Upcast::upcast(self).file_overrides().as_ref().clone();
+        for (path, content) in files {
+            let file = FileId::new(Upcast::upcast(self), directory.0.join(path));
+            overrides.insert(file, content);
+        }
+        self.as_files_group_mut().set_file_overrides(Arc::new(overrides));
+    }
     /// Sets the root directory of the crate. None value removes the crate.
     fn set_crate_root(&mut self, crt: CrateId, root: Option<Directory>) {
         let mut crate_roots = Upcast::upcast(self).crate_roots().as_ref().clone();
@@ -132,10 +147,15 @@ fn crate_root_dir(db: &dyn FilesGroup, crt: CrateId) -> Option<Directory> {
 
 fn priv_raw_file_content(db: &dyn FilesGroup, file: FileId) -> Option<Arc<String>> {
     match db.lookup_intern_file(file) {
-        FileLongId::OnDisk(path) => match fs::read_to_string(path) {
-            Ok(content) => Some(Arc::new(content)),
-            Err(_) => None,
-        },
+        FileLongId::OnDisk(path) => {
+            // The file may change on disk, so its content is read again in every revision. An
+            // unchanged content does not invalidate the queries depending on it.
+            db.salsa_runtime().report_untracked_read();
+            match fs::read_to_string(path) {
+                Ok(content) => Some(Arc::new(content)),
+                Err(_) => None,
+            }
+        }
         FileLongId::Virtual(virt) => Some(virt.content),
     }
 }
@@ -27,6 +27,26 @@ fn test_filesystem() {
     assert_eq!(*db.file_content(file_id).unwrap(), "content\n");
 }
 
+#[test]
+fn test_override_directory_content() {
+    let mut db = FilesDatabaseForTesting::default();
+
+    let directory = Directory("<memory>".into());
+    db.override_directory_content(
+        &directory,
+        [
+            ("lib.cairo".into(), Arc::new("mod a;\n".into())),
+            ("a/b.cairo".into(), Arc::new("fn b() {}\n".into())),
+        ],
+    );
+
+    let lib_file = directory.file(&db, "lib.cairo".into());
+    assert_eq!(*db.file_content(lib_file).unwrap(), "mod a;\n");
+    let b_file = directory.subdir("a".into()).file(&db, "b.cairo".into());
+    assert_eq!(*db.file_content(b_file).unwrap(), "fn b() {}\n");
+    assert!(db.file_content(directory.file(&db, "a.cairo".into())).is_none());
+}
+
 #[test]
 fn test_flags() {
     let mut db = FilesDatabaseForTesting::default();
@@ -4,4 +4,9 @@ pub enum Flag {
     /// Whether automatically add `withdraw_gas` calls in code cycles.
     /// Default is true - automatically add.
     AddWithdrawGas(bool),
+    /// The maximal number of lowered statements of a function without an inline attribute for it
+    /// to be inlined by the heuristic.
+    /// Default is unset - only functions calling another function or returning a literal are
+    /// inlined.
+    InliningSizeThreshold(usize),
 }
@@ -16,7 +16,7 @@ use cairo_lang_defs::ids::{
     LanguageElementId, LookupItemId, ModuleFileId, ModuleId, ModuleItemId, StructLongId,
     TraitLongId, UseLongId,
 };
-use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, ToOption};
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, Severity, ToOption};
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::db::{
     init_dev_corelib, AsFilesGroupMut, FilesGroup, FilesGroupEx, PrivRawFileContentQuery,
@@ -92,6 +92,7 @@ pub struct FileDiagnostics {
     pub parser: Diagnostics<ParserDiagnostic>,
     pub semantic: Diagnostics<SemanticDiagnostic>,
     pub lowering: Diagnostics<LoweringDiagnostic>,
+    pub lint: Diagnostics<SemanticDiagnostic>,
 }
 #[derive(Default)]
 pub struct State {
@@ -197,6 +198,7 @@ impl Backend {
                 parser: db.file_syntax_diagnostics(file_id),
                 semantic: db.file_semantic_diagnostics(file_id).unwrap_or_default(),
                 lowering: db.file_lowering_diagnostics(file_id).unwrap_or_default(),
+                lint: db.file_lint_diagnostics(file_id).unwrap_or_default(),
             };
             // Since we are using Arcs, this comparison should be efficient.
             if let Some(old_file_diagnostics) = state.file_diagnostics.get(&file_id) {
@@ -208,6 +210,7 @@ impl Backend {
             self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.parser);
             self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.semantic);
             self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.lowering);
+            self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.lint);
             state.file_diagnostics.insert(file_id, new_file_diagnostics);
 
             self.client.publish_diagnostics(uri, diags, None).await
@@ -241,9 +244,15 @@ impl Backend {
             let end = from_pos(
                 location.span.start.position_in_file(db.upcast(), location.file_id).unwrap(),
             );
+            let severity = match diagnostic.severity() {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+            };
             diags.push(Diagnostic {
                 range: Range { start, end },
                 message,
+                severity: Some(severity),
+                code: diagnostic.error_code().map(|code| NumberOrString::String(code.to_string())),
                 ..Diagnostic::default()
             });
         }
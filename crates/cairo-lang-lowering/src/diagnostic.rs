@@ -2,6 +2,7 @@ use cairo_lang_defs::diagnostic_utils::{StableLocation, StableLocationOption};
 use cairo_lang_defs::ids::ModuleFileId;
 use cairo_lang_diagnostics::{
     DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder,
+    ErrorCode, Severity,
 };
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::expr::inference::InferenceError;
@@ -88,6 +89,31 @@ impl DiagnosticEntry for LoweringDiagnostic {
         }
     }
 
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(ErrorCode::new(match &self.kind {
+            LoweringDiagnosticKind::Unreachable { .. } => "E0301",
+            LoweringDiagnosticKind::NonZeroValueInMatch => "E0302",
+            LoweringDiagnosticKind::OnlyMatchZeroIsSupported => "E0303",
+            LoweringDiagnosticKind::VariableMoved { .. } => "E0304",
+            LoweringDiagnosticKind::VariableNotDropped { .. } => "E0305",
+            LoweringDiagnosticKind::DesnappingANonCopyableType { .. } => "E0306",
+            LoweringDiagnosticKind::UnsupportedMatchedValue => "E0307",
+            LoweringDiagnosticKind::UnsupportedMatchArms => "E0308",
+            LoweringDiagnosticKind::UnsupportedMatchArmNotAVariant => "E0309",
+            LoweringDiagnosticKind::UnsupportedMatchArmOutOfOrder => "E0310",
+            LoweringDiagnosticKind::CannotInlineFunctionThatMightCallItself => "E0311",
+            LoweringDiagnosticKind::MemberPathLoop => "E0312",
+        }))
+    }
+
+    fn severity(&self) -> Severity {
+        match &self.kind {
+            // Code after a statement that never returns, e.g. a panic, compiles, but is a lint.
+            LoweringDiagnosticKind::Unreachable { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
     #[allow(unreachable_patterns, clippy::single_match)]
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation {
         match &self.kind {
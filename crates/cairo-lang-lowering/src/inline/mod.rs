@@ -4,9 +4,12 @@ mod test;
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::LanguageElementId;
+use cairo_lang_defs::ids::{LanguageElementId, TopLevelLanguageElementId};
 use cairo_lang_diagnostics::{Diagnostics, Maybe};
+use cairo_lang_filesystem::flag::Flag;
+use cairo_lang_filesystem::ids::FlagId;
 use cairo_lang_semantic::items::functions::InlineConfiguration;
+use cairo_lang_utils::extract_matches;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{izip, Itertools};
 
@@ -55,6 +58,11 @@ pub fn priv_inline_data(
             gather_inlining_info(db, &mut diagnostics, false, function_id)?
         }
     };
+    log::info!(
+        "Inlining decision for `{}`: {}.",
+        semantic_function_id.full_path(db.upcast()),
+        inlining_decision(&config, &info)
+    );
     Ok(Arc::new(PrivInlineData { diagnostics: diagnostics.build(), config, info }))
 }
 
@@ -85,10 +93,30 @@ fn gather_inlining_info(
     Ok(InlineInfo { is_inlinable: true, should_inline: should_inline(db, &lowered)? })
 }
 
+/// Describes the inlining decision for a function, for reporting.
+fn inlining_decision(config: &InlineConfiguration, info: &InlineInfo) -> &'static str {
+    match (config, info.is_inlinable, info.should_inline) {
+        (InlineConfiguration::Never(_), ..) => "not inlined, marked `#[inline(never)]`",
+        (InlineConfiguration::Should(_), ..) => "inlined when not recursive, marked `#[inline]`",
+        (InlineConfiguration::Always(_), true, _) => "inlined, marked `#[inline(always)]`",
+        (_, false, _) => "not inlined, might call itself",
+        (InlineConfiguration::None, true, true) => "inlined by the heuristic",
+        (InlineConfiguration::None, true, false) => "not inlined by the heuristic",
+    }
+}
+
 // A heuristic to decide if a function should be inlined.
-fn should_inline(_db: &dyn LoweringGroup, lowered: &FlatLowered) -> Maybe<bool> {
+fn should_inline(db: &dyn LoweringGroup, lowered: &FlatLowered) -> Maybe<bool> {
     let root_block = lowered.blocks.root_block()?;
 
+    if let Some(flag) = db.get_flag(FlagId::new(db.upcast(), "inlining_size_threshold")) {
+        // Inline a function that is small enough, regardless of its control flow.
+        let size: usize = lowered.blocks.iter().map(|(_, block)| block.statements.len()).sum();
+        if size <= extract_matches!(*flag, Flag::InliningSizeThreshold) {
+            return Ok(true);
+        }
+    }
+
     Ok(match &root_block.end {
         FlatBlockEnd::Return(_) => {
             // Inline a function that only calls another function or returns a literal.
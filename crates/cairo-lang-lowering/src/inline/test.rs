@@ -1,6 +1,10 @@
 use std::ops::Deref;
+use std::sync::Arc;
 
 use cairo_lang_debug::DebugWithDb;
+use cairo_lang_filesystem::db::FilesGroupEx;
+use cairo_lang_filesystem::flag::Flag;
+use cairo_lang_filesystem::ids::FlagId;
 use cairo_lang_plugins::get_default_plugins;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::test_utils::setup_test_function;
@@ -18,6 +22,7 @@ cairo_lang_test_utils::test_file_test!(
     {
         inline :"inline",
         inline_diagnostics :"inline_diagnostics",
+        inline_size_threshold :"inline_size_threshold",
     },
     test_function_inlining
 );
@@ -27,6 +32,13 @@ fn test_function_inlining(
 ) -> OrderedHashMap<String, String> {
     let db = &mut LoweringDatabaseForTesting::default();
     db.set_semantic_plugins(get_default_plugins());
+    if let Some(threshold) = inputs.get("inlining_size_threshold") {
+        let inlining_size_threshold_flag_id = FlagId::new(&*db, "inlining_size_threshold");
+        db.set_flag(
+            inlining_size_threshold_flag_id,
+            Some(Arc::new(Flag::InliningSizeThreshold(threshold.parse().unwrap()))),
+        );
+    }
     let (test_function, semantic_diagnostics) = setup_test_function(
         db,
         inputs["function"].as_str(),
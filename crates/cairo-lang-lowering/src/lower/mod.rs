@@ -246,15 +246,19 @@ fn lower_expr_block(
             // If flow is not reachable anymore, no need to continue emitting statements.
             // TODO(spapini): We might want to report unreachable for expr that abruptly
             // ends, e.g. `5 + {return; 6}`.
-            if i + 1 < expr_block.statements.len() {
-                let start_stmt = &ctx.function_body.statements[expr_block.statements[i + 1]];
-                let end_stmt =
-                    &ctx.function_body.statements[*expr_block.statements.last().unwrap()];
-                // Emit diagnostic fo the rest of the statements with unreachable.
-                ctx.diagnostics.report(
-                    start_stmt.stable_ptr().untyped(),
-                    Unreachable { last_statement_ptr: end_stmt.stable_ptr().untyped() },
-                );
+            let statements = &ctx.function_body.statements;
+            let exprs = &ctx.function_body.exprs;
+            let tail_ptr = expr_block.tail.map(|expr| exprs[expr].stable_ptr().untyped());
+            let start_ptr = match expr_block.statements.get(i + 1) {
+                Some(stmt_id) => Some(statements[*stmt_id].stable_ptr().untyped()),
+                None => tail_ptr,
+            };
+            if let Some(start_ptr) = start_ptr {
+                let end_ptr = tail_ptr.unwrap_or_else(|| {
+                    statements[*expr_block.statements.last().unwrap()].stable_ptr().untyped()
+                });
+                // Emit diagnostic for the rest of the statements and the tail with unreachable.
+                ctx.diagnostics.report(start_ptr, Unreachable { last_statement_ptr: end_ptr });
             }
         }
         return Err(err);
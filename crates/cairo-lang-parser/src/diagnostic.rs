@@ -1,4 +1,4 @@
-use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_diagnostics::{DiagnosticEntry, ErrorCode};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::TextSpan;
@@ -81,6 +81,27 @@ impl DiagnosticEntry for ParserDiagnostic {
         }
     }
 
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(ErrorCode::new(match &self.kind {
+            ParserDiagnosticKind::SkippedElement { .. } => "E0001",
+            ParserDiagnosticKind::MissingToken(..) => "E0002",
+            ParserDiagnosticKind::MissingExpression => "E0003",
+            ParserDiagnosticKind::MissingPathSegment => "E0004",
+            ParserDiagnosticKind::MissingTypeClause => "E0005",
+            ParserDiagnosticKind::MissingTypeExpression => "E0006",
+            ParserDiagnosticKind::ReservedIdentifier { .. } => "E0007",
+            ParserDiagnosticKind::UnderscoreNotAllowedAsIdentifier => "E0008",
+            ParserDiagnosticKind::MissingLiteralSuffix => "E0009",
+            ParserDiagnosticKind::InvalidNumericLiteralValue => "E0010",
+            ParserDiagnosticKind::IllegalStringEscaping => "E0011",
+            ParserDiagnosticKind::ShortStringMustBeAscii => "E0012",
+            ParserDiagnosticKind::UnterminatedString => "E0013",
+            ParserDiagnosticKind::AttributesWithoutItem => "E0014",
+            ParserDiagnosticKind::AttributesWithoutTraitItem => "E0015",
+            ParserDiagnosticKind::AttributesWithoutImplItem => "E0016",
+        }))
+    }
+
     fn location(&self, _db: &dyn FilesGroup) -> cairo_lang_diagnostics::DiagnosticLocation {
         cairo_lang_diagnostics::DiagnosticLocation { file_id: self.file_id, span: self.span }
     }
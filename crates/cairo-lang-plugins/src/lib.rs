@@ -3,19 +3,15 @@ use std::sync::Arc;
 
 use cairo_lang_semantic::plugin::SemanticPlugin;
 
-use crate::plugins::{ConfigPlugin, DerivePlugin, GenerateTraitPlugin, PanicablePlugin};
+use crate::registry::PluginRegistry;
 
 pub mod plugins;
+pub mod registry;
 
 #[cfg(test)]
 mod test;
 
 /// Gets the list of default plugins to load into the Cairo compiler.
 pub fn get_default_plugins() -> Vec<Arc<dyn SemanticPlugin>> {
-    vec![
-        Arc::new(DerivePlugin::default()),
-        Arc::new(GenerateTraitPlugin::default()),
-        Arc::new(PanicablePlugin::default()),
-        Arc::new(ConfigPlugin::default()),
-    ]
+    PluginRegistry::with_default_plugins().plugins()
 }
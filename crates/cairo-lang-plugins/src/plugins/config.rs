@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use cairo_lang_defs::plugin::{MacroPlugin, PluginDiagnostic, PluginResult};
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin};
 use cairo_lang_syntax::attribute::structured::{
@@ -42,6 +43,18 @@ impl AsDynMacroPlugin for ConfigPlugin {
 }
 impl SemanticPlugin for ConfigPlugin {}
 
+/// Returns whether the item is enabled in the current config, according to its `#[cfg(...)]`
+/// attributes. Meant for plugins that generate code from the items of a module, as the items
+/// removed by [ConfigPlugin] are still visible to them.
+/// Malformed predicates are ignored here, as they are reported by [ConfigPlugin].
+pub fn is_enabled_by_cfg(db: &dyn SyntaxGroup, item: &impl QueryAttrs) -> bool {
+    let cfg_set = db.cfg_set();
+    item.query_attr(db, "cfg").into_iter().all(|attr| {
+        parse_predicate(db, attr.structurize(db), &mut vec![])
+            .map_or(true, |pattern| cfg_set.is_superset(&pattern))
+    })
+}
+
 /// Parse `#[cfg(...)]` attribute arguments as a predicate matching [`Cfg`] items.
 fn parse_predicate(
     db: &dyn SyntaxGroup,
@@ -72,6 +85,9 @@ fn parse_predicate_item(
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: arg.arg_stable_ptr.untyped(),
                 message: "This attribute does not support field initialization shorthands.".into(),
+                code: Some(ErrorCode::new("E0401")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
             None
         }
@@ -80,6 +96,9 @@ fn parse_predicate_item(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: value_stable_ptr.untyped(),
                     message: "Expected short string.".into(),
+                    code: Some(ErrorCode::new("E0402")),
+                    severity: Severity::Error,
+                    fixes: vec![],
                 });
                 return None;
             };
@@ -93,6 +112,9 @@ fn parse_predicate_item(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: value_stable_ptr.untyped(),
                     message: "Expected identifier.".into(),
+                    code: Some(ErrorCode::new("E0403")),
+                    severity: Severity::Error,
+                    fixes: vec![],
                 });
                 return None;
             };
@@ -100,6 +122,9 @@ fn parse_predicate_item(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: value_stable_ptr.untyped(),
                     message: "Expected simple path.".into(),
+                    code: Some(ErrorCode::new("E0404")),
+                    severity: Severity::Error,
+                    fixes: vec![],
                 });
                 return None;
             };
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use cairo_lang_defs::plugin::{
     DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
 use cairo_lang_syntax::attribute::structured::{
     AttributeArg, AttributeArgVariant, AttributeStructurize,
@@ -129,6 +130,9 @@ fn generate_derive_code_for_type(
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.args_stable_ptr.untyped(),
                 message: "Expected args.".into(),
+                code: Some(ErrorCode::new("E0405")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
             continue;
         }
@@ -145,6 +149,9 @@ fn generate_derive_code_for_type(
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: arg.arg_stable_ptr.untyped(),
                     message: "Expected path.".into(),
+                    code: Some(ErrorCode::new("E0406")),
+                    severity: Severity::Error,
+                    fixes: vec![],
                 });
                 continue;
             };
@@ -173,6 +180,9 @@ fn generate_derive_code_for_type(
                     diagnostics.push(PluginDiagnostic {
                         stable_ptr: value_stable_ptr.untyped(),
                         message: "Unsupported trait for derive for extern types.".into(),
+                        code: Some(ErrorCode::new("E0407")),
+                        severity: Severity::Error,
+                        fixes: vec![],
                     })
                 }
                 _ => {
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use cairo_lang_defs::plugin::{
     DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
 use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeStructurize};
 use cairo_lang_syntax::node::ast::{
@@ -49,6 +50,9 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
                 PluginDiagnostic {
                     stable_ptr: trait_ast.stable_ptr().untyped(),
                     message: "Generated trait must have a single element path.".to_string(),
+                    code: Some(ErrorCode::new("E0408")),
+                    severity: Severity::Error,
+                    fixes: vec![],
                 }],
             remove_original_item: false,
         };
@@ -76,6 +80,9 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: attr_arg.arg_stable_ptr.untyped(),
                     message: "Expected an argument with the name `trait_attrs`.".to_string(),
+                    code: Some(ErrorCode::new("E0409")),
+                    severity: Severity::Error,
+                    fixes: vec![],
                 });
                 vec![]
             }
@@ -124,6 +131,9 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
             stable_ptr: trait_ast.stable_ptr().untyped(),
             message: "Generated trait must have generic args matching the impl's generic params."
                 .to_string(),
+            code: Some(ErrorCode::new("E0410")),
+            severity: Severity::Error,
+            fixes: vec![],
         });
     }
     let signatures = match impl_ast.body(db) {
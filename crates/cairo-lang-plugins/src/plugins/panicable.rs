@@ -3,6 +3,7 @@ use std::sync::Arc;
 use cairo_lang_defs::plugin::{
     DynGeneratedFileAuxData, MacroPlugin, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
 use cairo_lang_syntax::attribute::structured::{
     Attribute, AttributeArg, AttributeArgVariant, AttributeStructurize,
@@ -61,6 +62,9 @@ fn generate_panicable_code(
                 stable_ptr: extra_attr.stable_ptr().untyped(),
                 message: "`#[panic_with]` cannot be applied multiple times to the same item."
                     .into(),
+                code: Some(ErrorCode::new("E0411")),
+                severity: Severity::Error,
+                fixes: vec![],
             }],
             remove_original_item: false,
         };
@@ -76,6 +80,9 @@ fn generate_panicable_code(
                 stable_ptr: signature.ret_ty(db).stable_ptr().untyped(),
                 message: "Currently only wrapping functions returning an Option<T> or \
                     Result<T, E>".into(),
+                code: Some(ErrorCode::new("E0412")),
+                severity: Severity::Error,
+                fixes: vec![],
             }],
             remove_original_item: false,
         };
@@ -89,6 +96,9 @@ fn generate_panicable_code(
             diagnostics: vec![PluginDiagnostic {
                 stable_ptr: attr.stable_ptr.untyped(),
                 message: "Failed to extract panic data attribute".into(),
+                code: Some(ErrorCode::new("E0413")),
+                severity: Severity::Error,
+                fixes: vec![],
             }],
             remove_original_item: false,
         };
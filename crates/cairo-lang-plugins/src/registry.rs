@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::plugin::MacroPlugin;
+use cairo_lang_semantic::plugin::{DynMacroPlugin, SemanticPlugin};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+use crate::plugins::{ConfigPlugin, DerivePlugin, GenerateTraitPlugin, PanicablePlugin};
+
+/// A collection of named plugins to load into the Cairo compiler.
+///
+/// Binaries built on the compiler register their own plugins - e.g. for additional derives or
+/// attributes - on top of the default ones, and pass the registry to the database builder.
+#[derive(Clone, Debug, Default)]
+pub struct PluginRegistry {
+    plugins: OrderedHashMap<String, Arc<dyn SemanticPlugin>>,
+}
+impl PluginRegistry {
+    /// Returns a registry of the default plugins of the compiler: `derive`, `generate_trait`,
+    /// `panicable` and `config`.
+    pub fn with_default_plugins() -> Self {
+        let mut registry = Self::default();
+        registry.register("derive", Arc::new(DerivePlugin::default()));
+        registry.register("generate_trait", Arc::new(GenerateTraitPlugin::default()));
+        registry.register("panicable", Arc::new(PanicablePlugin::default()));
+        registry.register("config", Arc::new(ConfigPlugin::default()));
+        registry
+    }
+
+    /// Registers a plugin under the given name. Returns the plugin previously registered under the
+    /// name, if any, which the new plugin replaces.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        plugin: Arc<dyn SemanticPlugin>,
+    ) -> Option<Arc<dyn SemanticPlugin>> {
+        self.plugins.insert(name.into(), plugin)
+    }
+
+    /// Registers a plugin that only generates code, see [Self::register].
+    pub fn register_macro_plugin(
+        &mut self,
+        name: impl Into<String>,
+        plugin: Arc<dyn MacroPlugin>,
+    ) -> Option<Arc<dyn SemanticPlugin>> {
+        self.register(name, Arc::new(DynMacroPlugin(plugin)))
+    }
+
+    /// Removes the plugin registered under the given name, and returns it.
+    pub fn unregister(&mut self, name: &str) -> Option<Arc<dyn SemanticPlugin>> {
+        self.plugins.shift_remove(name)
+    }
+
+    /// Returns the plugin registered under the given name.
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn SemanticPlugin>> {
+        self.plugins.get(name)
+    }
+
+    /// Returns the names of the registered plugins, in their registration order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.plugins.keys().map(String::as_str)
+    }
+
+    /// Returns the registered plugins, in their registration order.
+    pub fn plugins(&self) -> Vec<Arc<dyn SemanticPlugin>> {
+        self.plugins.values().cloned().collect()
+    }
+}
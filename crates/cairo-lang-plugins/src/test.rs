@@ -54,7 +54,7 @@ pub fn test_expand_plugin(
 
                 let location =
                     DiagnosticLocation { file_id, span: syntax_node.span_without_trivia(db) };
-                format_diagnostics(db, &diag.message, location)
+                format_diagnostics(db, diag.severity, &diag.message, location)
             }));
 
             if result.remove_original_item {
@@ -2,11 +2,15 @@
 #[cfg(test)]
 mod test;
 
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
+use cairo_lang_diagnostics::LintLevel;
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::ids::Directory;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
-use serde::{Deserialize, Serialize};
+use path_clean::PathClean;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use smol_str::SmolStr;
 
 #[derive(thiserror::Error, Debug)]
@@ -17,8 +21,13 @@ pub enum DeserializationError {
     IoError(#[from] std::io::Error),
     #[error("PathError")]
     PathError,
+    #[error("Crate `{crate_name}` has conflicting roots: `{first}` and `{second}`.")]
+    ConflictingCrateRoots { crate_name: SmolStr, first: PathBuf, second: PathBuf },
+    #[error("Dependency `{crate_name}` is not a crate of the project at `{path}`.")]
+    MissingDependencyCrate { crate_name: SmolStr, path: PathBuf },
 }
-const PROJECT_FILE_NAME: &str = "cairo_project.toml";
+/// The name of the file configuring a Cairo project, at the root of the project.
+pub const PROJECT_FILE_NAME: &str = "cairo_project.toml";
 
 /// Cairo project config, including its file content and metadata about the file.
 /// This file is expected to be at a root of a crate and specify the crate name and location and
@@ -28,11 +37,66 @@ pub struct ProjectConfig {
     pub base_path: PathBuf,
     pub corelib: Option<Directory>,
     pub content: ProjectConfigContent,
+    /// All the crates of the project, including the crates of its workspace members and
+    /// dependencies, by name.
+    pub crates: OrderedHashMap<SmolStr, CrateConfig>,
 }
 /// Contents of a Cairo project config file.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProjectConfigContent {
+    /// The `cfg` options the project is compiled with by default, each given as `name` or
+    /// `key=value`. Declared first, as TOML requires plain values to precede tables.
+    #[serde(
+        default,
+        skip_serializing_if = "CfgSet::is_empty",
+        serialize_with = "serialize_cfg_set",
+        deserialize_with = "deserialize_cfg_set"
+    )]
+    pub cfg: CfgSet,
+    #[serde(default, skip_serializing_if = "OrderedHashMap::is_empty")]
     pub crate_roots: OrderedHashMap<SmolStr, PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<WorkspaceConfig>,
+    /// Projects whose crates the crates of this project depend on, by the name of the crate they
+    /// provide.
+    #[serde(default, skip_serializing_if = "OrderedHashMap::is_empty")]
+    pub dependencies: OrderedHashMap<SmolStr, DependencyConfig>,
+    #[serde(default, skip_serializing_if = "OrderedHashMap::is_empty")]
+    pub crate_settings: OrderedHashMap<SmolStr, CrateSettings>,
+    /// The levels of the warnings of specific codes, e.g. `W0101 = "allow"`, unless set by the
+    /// command line or by an attribute.
+    #[serde(default, skip_serializing_if = "OrderedHashMap::is_empty")]
+    pub lints: OrderedHashMap<SmolStr, LintLevel>,
+}
+/// The workspace section of a Cairo project config file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceConfig {
+    /// The directories of the projects that are members of the workspace, relative to the
+    /// workspace directory.
+    pub members: Vec<PathBuf>,
+}
+/// A dependency on the crate of another Cairo project.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyConfig {
+    /// The directory of the project providing the crate, relative to the depending project.
+    pub path: PathBuf,
+}
+/// Settings of a single crate of a Cairo project.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrateSettings {
+    /// Whether the crate is compiled as a main crate of the project. Defaults to `true` for the
+    /// crates of the project and its workspace members, and to `false` for the crates of its
+    /// dependencies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main: Option<bool>,
+}
+/// A crate of a Cairo project, as resolved from the config files of the project.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrateConfig {
+    /// The root directory of the crate.
+    pub root: PathBuf,
+    /// Whether the crate is a main crate of the project.
+    pub main: bool,
 }
 
 impl ProjectConfig {
@@ -40,12 +104,103 @@ impl ProjectConfig {
         Self::from_file(&directory.join(PROJECT_FILE_NAME))
     }
     pub fn from_file(filename: &Path) -> Result<Self, DeserializationError> {
-        let base_path = filename
+        let base_path: PathBuf = filename
             .parent()
             .and_then(|p| p.to_str())
             .ok_or(DeserializationError::PathError)?
             .into();
-        let content = toml::from_str(&std::fs::read_to_string(filename)?)?;
-        Ok(ProjectConfig { base_path, content, corelib: None })
+        let content: ProjectConfigContent = toml::from_str(&std::fs::read_to_string(filename)?)?;
+        let mut resolver = CratesResolver::default();
+        resolver.add_project(&base_path, &content, true)?;
+        Ok(ProjectConfig { base_path, content, corelib: None, crates: resolver.crates })
+    }
+}
+
+fn serialize_cfg_set<S: Serializer>(cfg_set: &CfgSet, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(cfg_set.iter().map(|cfg| match &cfg.value {
+        Some(value) => format!("{}={value}", cfg.key),
+        None => cfg.key.to_string(),
+    }))
+}
+
+fn deserialize_cfg_set<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CfgSet, D::Error> {
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|cfg| cfg.parse::<Cfg>().map_err(serde::de::Error::custom))
+        .collect()
+}
+
+/// Helper for collecting the crates of a project, its workspace members and its dependencies.
+#[derive(Default)]
+struct CratesResolver {
+    crates: OrderedHashMap<SmolStr, CrateConfig>,
+    /// The projects already added, by their directory and whether their crates are main crates.
+    visited: HashSet<(PathBuf, bool)>,
+}
+impl CratesResolver {
+    /// Adds the crates of the project at the given directory, with the given content.
+    fn add_project(
+        &mut self,
+        base_path: &Path,
+        content: &ProjectConfigContent,
+        main: bool,
+    ) -> Result<(), DeserializationError> {
+        if !self.visited.insert((base_path.to_path_buf().clean(), main)) {
+            return Ok(());
+        }
+        for (crate_name, root) in content.crate_roots.iter() {
+            let main = content
+                .crate_settings
+                .get(crate_name)
+                .and_then(|settings| settings.main)
+                .unwrap_or(main);
+            self.add_crate(crate_name, base_path.join(root).clean(), main)?;
+        }
+        for member in content.workspace.iter().flat_map(|workspace| &workspace.members) {
+            self.add_project_at(&base_path.join(member), main)?;
+        }
+        for (crate_name, dependency) in content.dependencies.iter() {
+            let path = base_path.join(&dependency.path);
+            self.add_project_at(&path, false)?;
+            if !self.crates.contains_key(crate_name) {
+                return Err(DeserializationError::MissingDependencyCrate {
+                    crate_name: crate_name.clone(),
+                    path,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the crates of the project whose config file is at the given directory.
+    fn add_project_at(&mut self, directory: &Path, main: bool) -> Result<(), DeserializationError> {
+        let content = toml::from_str(&std::fs::read_to_string(directory.join(PROJECT_FILE_NAME))?)?;
+        self.add_project(directory, &content, main)
+    }
+
+    /// Adds a crate, which is a main crate if any of the projects it is added by says so.
+    fn add_crate(
+        &mut self,
+        crate_name: &SmolStr,
+        root: PathBuf,
+        main: bool,
+    ) -> Result<(), DeserializationError> {
+        match self.crates.get_mut(crate_name) {
+            Some(existing) if existing.root != root => {
+                Err(DeserializationError::ConflictingCrateRoots {
+                    crate_name: crate_name.clone(),
+                    first: existing.root.clone(),
+                    second: root,
+                })
+            }
+            Some(existing) => {
+                existing.main |= main;
+                Ok(())
+            }
+            None => {
+                self.crates.insert(crate_name.clone(), CrateConfig { root, main });
+                Ok(())
+            }
+        }
     }
 }
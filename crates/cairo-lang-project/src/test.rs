@@ -1,11 +1,23 @@
+use std::path::Path;
+
+use cairo_lang_diagnostics::LintLevel;
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use indoc::indoc;
 
-use crate::ProjectConfigContent;
+use crate::{
+    CrateConfig, CrateSettings, CratesResolver, DependencyConfig, DeserializationError,
+    ProjectConfig, ProjectConfigContent, WorkspaceConfig,
+};
 
 #[test]
 fn test_serde() {
     let config = ProjectConfigContent {
         crate_roots: [("crate".into(), "dir".into())].into_iter().collect(),
+        workspace: None,
+        dependencies: Default::default(),
+        crate_settings: Default::default(),
+        cfg: CfgSet::new(),
+        lints: Default::default(),
     };
     let serialized = toml::to_string(&config).unwrap();
     assert_eq!(
@@ -17,3 +29,80 @@ fn test_serde() {
     );
     assert_eq!(config, toml::from_str(&serialized).unwrap());
 }
+
+#[test]
+fn test_serde_workspace() {
+    let config = ProjectConfigContent {
+        crate_roots: [("crate".into(), "dir".into())].into_iter().collect(),
+        workspace: Some(WorkspaceConfig { members: vec!["member".into()] }),
+        dependencies: [("dep".into(), DependencyConfig { path: "../dep".into() })]
+            .into_iter()
+            .collect(),
+        crate_settings: [("crate".into(), CrateSettings { main: Some(false) })]
+            .into_iter()
+            .collect(),
+        cfg: CfgSet::from_iter([Cfg::name("feature"), Cfg::kv("network", "testnet")]),
+        lints: [("W0101".into(), LintLevel::Allow), ("E0301".into(), LintLevel::Deny)]
+            .into_iter()
+            .collect(),
+    };
+    let serialized = toml::to_string(&config).unwrap();
+    assert_eq!(
+        serialized,
+        indoc! { r#"
+            cfg = ["feature", "network=testnet"]
+
+            [crate_roots]
+            crate = "dir"
+
+            [workspace]
+            members = ["member"]
+            [dependencies.dep]
+            path = "../dep"
+            [crate_settings.crate]
+            main = false
+
+            [lints]
+            W0101 = "allow"
+            E0301 = "deny"
+        "# }
+    );
+    assert_eq!(config, toml::from_str(&serialized).unwrap());
+}
+
+#[test]
+fn test_workspace_crates() {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data/workspace");
+    let config = ProjectConfig::from_directory(&workspace).unwrap();
+    let crate_config = |root: &str, main: bool| CrateConfig { root: workspace.join(root), main };
+    assert_eq!(
+        config.crates,
+        [
+            ("app".into(), crate_config("app/src", true)),
+            ("app_tests".into(), crate_config("app/tests", false)),
+            ("math".into(), crate_config("math/src", true)),
+            ("utils".into(), crate_config("utils/src", false)),
+        ]
+        .into_iter()
+        .collect()
+    );
+}
+
+#[test]
+fn test_missing_dependency_crate() {
+    let app = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data/workspace/app");
+    let mut content: ProjectConfigContent =
+        toml::from_str(&std::fs::read_to_string(app.join("cairo_project.toml")).unwrap()).unwrap();
+    content.dependencies.insert("other".into(), DependencyConfig { path: "../utils".into() });
+    let result = CratesResolver::default().add_project(&app, &content, true);
+    let Err(DeserializationError::MissingDependencyCrate { crate_name, .. }) = result else {
+        panic!("Expected a missing dependency crate error, got: {result:?}");
+    };
+    assert_eq!(crate_name, "other");
+}
+
+#[test]
+fn test_invalid_cfg() {
+    let error = toml::from_str::<ProjectConfigContent>(r#"cfg = ["a.b"]"#).unwrap_err();
+    assert!(error.to_string().contains("Invalid cfg option `a.b`"), "{error}");
+}
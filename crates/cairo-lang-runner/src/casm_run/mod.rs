@@ -1,19 +1,24 @@
 use std::any::Any;
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
 use std::ops::{Deref, Shl};
+use std::path::Path;
+use std::sync::Arc;
 
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
 use ark_ff::fields::{Fp256, MontBackend, MontConfig};
 use ark_ff::{BigInteger, Field, PrimeField};
-use ark_secp256k1 as secp256k1;
 use ark_std::UniformRand;
 use cairo_felt::{felt_str as felt252_str, Felt252, PRIME_STR};
-use cairo_lang_casm::hints::{CoreHint, DeprecatedHint, Hint, StarknetHint};
+use cairo_lang_casm::hints::{CoreHint, CoreHintBase, DeprecatedHint, Hint, StarknetHint};
 use cairo_lang_casm::instructions::Instruction;
 use cairo_lang_casm::operand::{
     BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand,
 };
 use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra::program::StatementIdx;
+use cairo_lang_starknet::storage_layout::StorageLayout;
 use cairo_lang_utils::extract_matches;
 use cairo_vm::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
 use cairo_vm::serde::deserialize_program::{
@@ -25,16 +30,22 @@ use cairo_vm::types::relocatable::{MaybeRelocatable, Relocatable};
 use cairo_vm::vm::errors::hint_errors::HintError;
 use cairo_vm::vm::errors::memory_errors::MemoryError;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
-use cairo_vm::vm::runners::cairo_runner::CairoRunner;
+use cairo_vm::vm::runners::cairo_runner::{CairoRunner, ExecutionResources};
 use cairo_vm::vm::vm_core::VirtualMachine;
 use dict_manager::DictManagerExecScope;
+use itertools::{chain, Itertools};
 use num_bigint::BigUint;
 use num_integer::Integer;
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
+use serde::{Deserialize, Serialize};
+use {ark_secp256k1 as secp256k1, ark_secp256r1 as secp256r1};
 
 use self::dict_manager::DictSquashExecScope;
+use crate::debugger::{self, DebugContext};
+use crate::prover_input::ProverInput;
+use crate::resource_limits::{self, ExhaustedResource, ResourceLimits};
 use crate::short_string::as_cairo_short_string;
-use crate::{Arg, RunResultValue, SierraCasmRunner};
+use crate::{Arg, ForkError, ForkedState, RunResultValue, RunnerError, SierraCasmRunner};
 
 #[cfg(test)]
 mod test;
@@ -65,18 +76,45 @@ pub fn hint_to_hint_params(hint: &Hint) -> HintParams {
     }
 }
 
-/// Helper object to allocate and track Secp256K1 elliptic curve points.
-#[derive(Default)]
-struct Secp256K1ExecutionScope {
-    /// All elliptic curve points provided by the secp256k1 syscalls.
+/// A secp256 elliptic curve supported by the secp256 syscalls.
+trait Secp256Curve: SWCurveConfig {
+    /// The name of the execution scope tracking the points of the curve.
+    const EXEC_SCOPE_NAME: &'static str;
+
+    /// Returns whether the given y coordinate is considered even when choosing a point by the
+    /// parity of its y coordinate.
+    fn is_y_even(y: &Self::BaseField) -> bool;
+}
+impl Secp256Curve for secp256k1::Config {
+    const EXEC_SCOPE_NAME: &'static str = "secp256k1_exec_scope";
+
+    fn is_y_even(y: &Self::BaseField) -> bool {
+        y.0.is_even()
+    }
+}
+impl Secp256Curve for secp256r1::Config {
+    const EXEC_SCOPE_NAME: &'static str = "secp256r1_exec_scope";
+
+    fn is_y_even(y: &Self::BaseField) -> bool {
+        y.into_bigint().is_even()
+    }
+}
+
+/// Helper object to allocate and track the points of a secp256 elliptic curve.
+struct Secp256ExecutionScope<Curve: Secp256Curve> {
+    /// All elliptic curve points provided by the syscalls of the curve.
     /// The id of a point is the index in the vector.
-    ec_points: Vec<secp256k1::Affine>,
+    ec_points: Vec<Affine<Curve>>,
+}
+impl<Curve: Secp256Curve> Default for Secp256ExecutionScope<Curve> {
+    fn default() -> Self {
+        Self { ec_points: vec![] }
+    }
 }
 
 /// HintProcessor for Cairo compiler hints.
 pub struct CairoHintProcessor<'a> {
-    /// The Cairo runner.
-    #[allow(dead_code)]
+    /// The runner of the program whose contracts can be called.
     pub runner: Option<&'a SierraCasmRunner>,
     // A dict from instruction offset to hint vector.
     pub hints_dict: HashMap<usize, Vec<HintParams>>,
@@ -84,6 +122,10 @@ pub struct CairoHintProcessor<'a> {
     pub string_to_hint: HashMap<String, Hint>,
     // The starknet state.
     pub starknet_state: StarknetState,
+    // The resources used by the contract calls made during the run.
+    pub nested_resources: ExecutionResources,
+    // The resource exhausted by a contract call made during the run, failing the run.
+    pub nested_exhausted_resource: Option<ExhaustedResource>,
 }
 
 impl<'a> CairoHintProcessor<'a> {
@@ -111,11 +153,18 @@ impl<'a> CairoHintProcessor<'a> {
             }
             hint_offset += instruction.body.op_size();
         }
-        CairoHintProcessor { runner, hints_dict, string_to_hint, starknet_state }
+        CairoHintProcessor {
+            runner,
+            hints_dict,
+            string_to_hint,
+            starknet_state,
+            nested_resources: ExecutionResources::default(),
+            nested_exhausted_resource: None,
+        }
     }
 }
 
-fn cell_ref_to_relocatable(cell_ref: &CellRef, vm: &VirtualMachine) -> Relocatable {
+pub(crate) fn cell_ref_to_relocatable(cell_ref: &CellRef, vm: &VirtualMachine) -> Relocatable {
     let base = match cell_ref.register {
         Register::AP => vm.get_ap(),
         Register::FP => vm.get_fp(),
@@ -132,6 +181,9 @@ macro_rules! insert_value_to_cellref {
 
 /// Execution scope for starknet related data.
 /// All values will be 0 and by default if not setup by the test.
+///
+/// The state of the contracts is discarded when a call to them is reverted, while the records of
+/// the run - the syscall counts, the statement counts and the captured output - are kept.
 #[derive(Clone, Default)]
 pub struct StarknetState {
     /// The values of addresses in the simulated storage per contract.
@@ -142,12 +194,337 @@ pub struct StarknetState {
     /// The simulated execution info.
     exec_info: ExecutionInfo,
     next_id: Felt252,
+    /// The gas costs of the syscalls.
+    syscall_costs: SyscallCosts,
+    /// The execution info values overridden by the cheatcodes, per target contract address.
+    cheats: HashMap<Felt252, ExecutionInfoCheats>,
+    /// The results of the mocked calls, by contract address and entry point selector: the return
+    /// data of the calls, or their panic data if they fail.
+    mocked_calls: HashMap<(Felt252, Felt252), Result<Vec<Felt252>, Vec<Felt252>>>,
+    /// The events emitted by the contracts, in emission order.
+    events: Vec<ContractEvent>,
+    /// The number of events popped by the `pop_log` cheatcode, per emitting contract address.
+    popped_logs: HashMap<Felt252, usize>,
+    /// The gas used by each of the contract calls, in call order.
+    contract_calls_gas: Vec<ContractCallGas>,
+    /// The forked network state, used for the contracts, classes and storage values that are not
+    /// part of the simulated state.
+    fork: Option<Arc<ForkedState>>,
+    /// The extension handling the syscalls and cheatcodes the runner does not support.
+    extension: Option<Arc<dyn HintProcessorExtension>>,
+    /// The records of the run, kept even if the calls they were made in are reverted.
+    bookkeeping: RunBookkeeping,
+}
+
+/// The records of a run that are kept when a contract call is reverted, unlike the rest of the
+/// [StarknetState].
+#[derive(Clone, Default)]
+struct RunBookkeeping {
+    /// The number of executions of each syscall, by syscall name.
+    syscall_counter: HashMap<String, usize>,
+    /// The number of executions of each Sierra statement, including the statements of called
+    /// contracts.
+    sierra_statement_counts: HashMap<StatementIdx, usize>,
+    /// The output of the `print` hints, including those of called contracts, if captured instead
+    /// of printed.
+    captured_output: Option<String>,
 }
 impl StarknetState {
+    /// Sets the gas costs of the syscalls.
+    pub fn with_syscall_costs(mut self, syscall_costs: SyscallCosts) -> Self {
+        self.syscall_costs = syscall_costs;
+        self
+    }
+
+    /// Sets whether to capture the output of the `print` hints instead of printing it (see
+    /// [Self::captured_output]).
+    pub fn with_output_capture(mut self, capture: bool) -> Self {
+        self.bookkeeping.captured_output = capture.then(String::new);
+        self
+    }
+
+    /// Returns the captured output of the `print` hints, including those of called contracts, even
+    /// if the calls were reverted. `None` if the output is not captured.
+    pub fn captured_output(&self) -> Option<&str> {
+        self.bookkeeping.captured_output.as_deref()
+    }
+
+    /// Sets the forked network state, and the block number to the number of the forked block.
+    pub fn with_fork(mut self, fork: Arc<ForkedState>) -> Self {
+        self.exec_info.block_info.block_number = Felt252::from(fork.block_number());
+        self.fork = Some(fork);
+        self
+    }
+
+    /// Sets the extension handling the syscalls and cheatcodes the runner does not support,
+    /// including those of called contracts (see [HintProcessorExtension]).
+    pub fn with_extension(mut self, extension: Arc<dyn HintProcessorExtension>) -> Self {
+        self.extension = Some(extension);
+        self
+    }
+
+    /// Returns the number of executions of each syscall, including the syscalls of called
+    /// contracts, sorted by syscall name.
+    pub fn syscall_counter(&self) -> Vec<(&str, usize)> {
+        self.bookkeeping
+            .syscall_counter
+            .iter()
+            .map(|(name, count)| (name.as_str(), *count))
+            .sorted()
+            .collect()
+    }
+
+    /// Returns the events emitted by the contracts, in emission order. The events of reverted
+    /// calls are not included.
+    pub fn events(&self) -> &[ContractEvent] {
+        &self.events
+    }
+
+    /// Returns the gas used by each of the contract calls, in call order. The calls nested in
+    /// reverted calls are not included.
+    pub fn contract_calls_gas(&self) -> &[ContractCallGas] {
+        &self.contract_calls_gas
+    }
+
+    /// Returns the number of executions of each executed Sierra statement, including the
+    /// statements of called contracts, even if the calls were reverted.
+    pub fn sierra_statement_counts(&self) -> &HashMap<StatementIdx, usize> {
+        &self.bookkeeping.sierra_statement_counts
+    }
+
+    /// Returns the state for starting other runs from the end of the run that produced it.
+    ///
+    /// The storage, the deployed contracts, the cheats, the mocked calls, the fork, whether the
+    /// output is captured and the extension are kept, while the records of the run - the emitted
+    /// events, the syscall counts, the gas of the contract calls, the statement counts and the
+    /// captured output - are cleared.
+    pub fn into_fixture(self) -> Self {
+        Self {
+            storage: self.storage,
+            deployed_contracts: self.deployed_contracts,
+            exec_info: self.exec_info,
+            next_id: self.next_id,
+            syscall_costs: self.syscall_costs,
+            cheats: self.cheats,
+            mocked_calls: self.mocked_calls,
+            fork: self.fork,
+            extension: self.extension,
+            bookkeeping: RunBookkeeping {
+                captured_output: self.bookkeeping.captured_output.map(|_| String::new()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Adds executions of Sierra statements to the counts.
+    pub(crate) fn add_sierra_statement_counts(
+        &mut self,
+        counts: impl IntoIterator<Item = (StatementIdx, usize)>,
+    ) {
+        for (statement_idx, count) in counts {
+            *self.bookkeeping.sierra_statement_counts.entry(statement_idx).or_default() += count;
+        }
+    }
+
+    /// Pops the earliest event emitted by the given contract that was not popped yet.
+    fn pop_log(&mut self, from_address: &Felt252) -> Option<ContractEvent> {
+        let popped = self.popped_logs.entry(from_address.clone()).or_default();
+        let event = self
+            .events
+            .iter()
+            .filter(|event| &event.from_address == from_address)
+            .nth(*popped)?
+            .clone();
+        *popped += 1;
+        Some(event)
+    }
+
     pub fn get_next_id(&mut self) -> Felt252 {
         self.next_id += Felt252::from(1);
         self.next_id.clone()
     }
+
+    /// Returns the values in the simulated storage of each contract, sorted by contract address,
+    /// along with the storage variables they belong to according to the given storage layouts by
+    /// contract address (see [`StorageLayout::variable_at`]).
+    pub fn dump_storage(
+        &self,
+        layouts: &HashMap<Felt252, StorageLayout>,
+    ) -> Vec<ContractStorageDump> {
+        let mut dumps = self
+            .storage
+            .iter()
+            .map(|(contract_address, contract_storage)| {
+                let layout = layouts.get(contract_address);
+                let mut entries = contract_storage
+                    .iter()
+                    .map(|(address, value)| StorageEntry {
+                        address: address.clone(),
+                        value: value.clone(),
+                        variable: layout
+                            .and_then(|layout| layout.variable_at(&address.to_biguint()))
+                            .map(|(variable, offset)| (variable.name.clone(), offset)),
+                    })
+                    .collect_vec();
+                entries.sort_by(|a, b| a.address.cmp(&b.address));
+                ContractStorageDump { contract_address: contract_address.clone(), entries }
+            })
+            .collect_vec();
+        dumps.sort_by(|a, b| a.contract_address.cmp(&b.contract_address));
+        dumps
+    }
+}
+
+/// An event emitted by a contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractEvent {
+    pub from_address: Felt252,
+    pub keys: Vec<Felt252>,
+    pub data: Vec<Felt252>,
+}
+impl fmt::Display for ContractEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format_felts = |felts: &[Felt252]| {
+            felts.iter().map(|felt| format!("{:#x}", felt.to_biguint())).join(", ")
+        };
+        write!(
+            f,
+            "Event from {:#x}: keys [{}], data [{}]",
+            self.from_address.to_biguint(),
+            format_felts(&self.keys),
+            format_felts(&self.data)
+        )
+    }
+}
+
+/// The gas used by a call to an entry point of a contract, including the calls nested in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractCallGas {
+    pub contract_address: Felt252,
+    pub selector: Felt252,
+    pub gas: usize,
+}
+
+/// The values in the simulated storage of a contract, sorted by address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractStorageDump {
+    pub contract_address: Felt252,
+    pub entries: Vec<StorageEntry>,
+}
+impl fmt::Display for ContractStorageDump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Contract {:#x}:", self.contract_address.to_biguint())?;
+        for entry in &self.entries {
+            match &entry.variable {
+                Some((name, 0)) => write!(f, "  {name}")?,
+                Some((name, offset)) => write!(f, "  {name}+{offset}")?,
+                None => write!(f, "  {:#x}", entry.address.to_biguint())?,
+            }
+            writeln!(f, " = {:#x}", entry.value.to_biguint())?;
+        }
+        Ok(())
+    }
+}
+
+/// A value in the simulated storage of a contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageEntry {
+    pub address: Felt252,
+    pub value: Felt252,
+    /// The name of the storage variable the address belongs to, and the offset of the address
+    /// from the base address of the variable, if known.
+    pub variable: Option<(String, u8)>,
+}
+
+/// The gas costs of the syscalls, charged on top of the gas of the entry points they run.
+/// Missing fields in the JSON representation are set to the costs of the network.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyscallCosts {
+    pub storage_read: usize,
+    pub storage_write: usize,
+    pub get_block_hash: usize,
+    pub get_execution_info: usize,
+    pub emit_event: usize,
+    pub deploy: usize,
+    pub call_contract: usize,
+    pub library_call: usize,
+    /// The cost of each permutation of the keccak syscall, absorbing 17 words of the input.
+    pub keccak_round: usize,
+    /// The cost of each of the secp256k1 syscalls.
+    pub secp256k1: usize,
+    /// The cost of each of the secp256r1 syscalls.
+    pub secp256r1: usize,
+}
+impl Default for SyscallCosts {
+    fn default() -> Self {
+        Self {
+            storage_read: 100,
+            storage_write: 1000,
+            get_block_hash: 100,
+            get_execution_info: 50,
+            emit_event: 50,
+            deploy: 50,
+            call_contract: 50,
+            library_call: 50,
+            keccak_round: 5000,
+            secp256k1: 500,
+            secp256r1: 500,
+        }
+    }
+}
+impl SyscallCosts {
+    /// Reads the syscall costs from a JSON file.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+/// The return values of a syscall, or its revert reason if it failed.
+pub type SyscallOutput = Result<Vec<Felt252>, Vec<Felt252>>;
+
+/// An extension of the runner, handling the syscalls and cheatcodes it does not support - e.g. to
+/// emulate custom precompiles, or to provide oracles for tests.
+///
+/// The extension is only consulted for unknown selectors, so the supported syscalls and cheatcodes
+/// always behave as on the network, and runs that only use them are unaffected by it. State kept by
+/// the extension is shared by all the runs and contract calls it is set for, and is not reverted
+/// along with a failed call.
+pub trait HintProcessorExtension: Send + Sync {
+    /// Executes a syscall with the given selector, reading its input and deducting its gas cost
+    /// from the gas counter. Returns `None` if the syscall is not supported by the extension.
+    fn execute_syscall(
+        &self,
+        _selector: &str,
+        _gas_counter: &mut usize,
+        _input: &mut SyscallInput<'_, '_>,
+    ) -> Result<Option<SyscallOutput>, HintError> {
+        Ok(None)
+    }
+
+    /// Executes a cheatcode with the given selector and input, returning its output. Returns `None`
+    /// if the cheatcode is not supported by the extension.
+    fn execute_cheatcode(&self, _selector: &str, _input: &[Felt252]) -> Option<Vec<Felt252>> {
+        None
+    }
+}
+
+/// The input of a syscall executed by a [HintProcessorExtension], read value by value.
+pub struct SyscallInput<'a, 'b> {
+    buffer: &'a mut MemBuffer<'b>,
+}
+impl SyscallInput<'_, '_> {
+    /// Reads the next felt252 value of the input.
+    pub fn next_felt252(&mut self) -> Result<Felt252, MemoryError> {
+        Ok(self.buffer.next_felt252()?.into_owned())
+    }
+
+    /// Reads the next array of the input, passed as its start and end pointers.
+    pub fn next_arr(&mut self) -> Result<Vec<Felt252>, HintError> {
+        self.buffer.next_arr()
+    }
 }
 
 /// Copy of the cairo `ExecutionInfo` struct.
@@ -159,6 +536,52 @@ struct ExecutionInfo {
     contract_address: Felt252,
 }
 
+/// The values of the execution info overridden for the calls of a contract by the cheatcodes.
+#[derive(Clone, Default)]
+struct ExecutionInfoCheats {
+    caller_address: Option<Felt252>,
+    contract_address: Option<Felt252>,
+    block_number: Option<Felt252>,
+    block_timestamp: Option<Felt252>,
+    sequencer_address: Option<Felt252>,
+    chain_id: Option<Felt252>,
+}
+impl ExecutionInfoCheats {
+    /// Returns the overridden value of the execution info field with the given name.
+    fn field_mut(&mut self, name: &str) -> Result<&mut Option<Felt252>, HintError> {
+        Ok(match name {
+            "caller_address" => &mut self.caller_address,
+            "contract_address" => &mut self.contract_address,
+            "block_number" => &mut self.block_number,
+            "block_timestamp" => &mut self.block_timestamp,
+            "sequencer_address" => &mut self.sequencer_address,
+            "chain_id" => &mut self.chain_id,
+            _ => {
+                return Err(HintError::CustomHint(format!(
+                    "Unknown execution info field `{name}`."
+                )));
+            }
+        })
+    }
+
+    /// Overrides the values of the execution info.
+    fn apply(&self, exec_info: &mut ExecutionInfo) {
+        let overrides = [
+            (&self.caller_address, &mut exec_info.caller_address),
+            (&self.contract_address, &mut exec_info.contract_address),
+            (&self.block_number, &mut exec_info.block_info.block_number),
+            (&self.block_timestamp, &mut exec_info.block_info.block_timestamp),
+            (&self.sequencer_address, &mut exec_info.block_info.sequencer_address),
+            (&self.chain_id, &mut exec_info.tx_info.chain_id),
+        ];
+        for (value, field) in overrides {
+            if let Some(value) = value {
+                *field = value.clone();
+            }
+        }
+    }
+}
+
 /// Copy of the cairo `BlockInfo` struct.
 #[derive(Clone, Default)]
 struct BlockInfo {
@@ -322,6 +745,13 @@ impl HintProcessor for CairoHintProcessor<'_> {
     ) -> Result<(), HintError> {
         let hint = hint_data.downcast_ref::<Hint>().unwrap();
         let hint = match hint {
+            Hint::Core(CoreHintBase::Core(CoreHint::DebugPrint { start, end }))
+                if self.starknet_state.bookkeeping.captured_output.is_some() =>
+            {
+                let output = debug_print_output(vm, start, end)?;
+                self.starknet_state.bookkeeping.captured_output.as_mut().unwrap().push_str(&output);
+                return Ok(());
+            }
             Hint::Core(core_hint_base) => {
                 return execute_core_hint_base(vm, exec_scopes, core_hint_base);
             }
@@ -372,6 +802,20 @@ impl HintProcessor for CairoHintProcessor<'_> {
                 let end = get_ptr(vm, cell, &offset)?;
                 self.starknet_state.exec_info.tx_info.signature = vm_get_range(vm, start, end)?;
             }
+            StarknetHint::Cheatcode {
+                selector,
+                input_start,
+                input_end,
+                output_start,
+                output_end,
+            } => {
+                self.execute_cheatcode(
+                    selector,
+                    [input_start, input_end],
+                    [output_start, output_end],
+                    vm,
+                )?;
+            }
         };
         Ok(())
     }
@@ -538,6 +982,9 @@ impl<'a> CairoHintProcessor<'a> {
         let mut system_buffer = MemBuffer::new(vm, system_ptr);
         let selector = system_buffer.next_felt252()?.to_bytes_be();
         let mut gas_counter = system_buffer.next_usize()?;
+        let selector = std::str::from_utf8(&selector).unwrap();
+        *self.starknet_state.bookkeeping.syscall_counter.entry(selector.into()).or_default() += 1;
+        let costs = self.starknet_state.syscall_costs.clone();
         let mut execute_handle_helper =
             |handler: &mut dyn FnMut(
                 // The syscall buffer.
@@ -559,7 +1006,7 @@ impl<'a> CairoHintProcessor<'a> {
                 }
                 Ok(())
             };
-        match std::str::from_utf8(&selector).unwrap() {
+        match selector {
             "StorageWrite" => execute_handle_helper(&mut |system_buffer, gas_counter| {
                 self.storage_write(
                     gas_counter,
@@ -582,33 +1029,33 @@ impl<'a> CairoHintProcessor<'a> {
                 self.get_execution_info(gas_counter, system_buffer)
             }),
             "EmitEvent" => execute_handle_helper(&mut |system_buffer, gas_counter| {
-                system_buffer.next_arr()?;
-                system_buffer.next_arr()?;
-                deduct_gas!(gas_counter, 50);
-                Ok(SyscallResult::Success(vec![]))
+                self.emit_event(gas_counter, system_buffer.next_arr()?, system_buffer.next_arr()?)
             }),
             "Keccak" => execute_handle_helper(&mut |system_buffer, gas_counter| {
-                keccak(gas_counter, system_buffer.next_arr()?)
+                keccak(gas_counter, costs.keccak_round, system_buffer.next_arr()?)
             }),
             "Secp256k1EcNew" => execute_handle_helper(&mut |system_buffer, gas_counter| {
-                secp256k1_ec_new(
+                secp256_ec_new::<secp256k1::Config>(
                     gas_counter,
+                    costs.secp256k1,
                     system_buffer.next_u256()?,
                     system_buffer.next_u256()?,
                     exec_scopes,
                 )
             }),
             "Secp256k1EcAdd" => execute_handle_helper(&mut |system_buffer, gas_counter| {
-                secp256k1_ec_add(
+                secp256_ec_add::<secp256k1::Config>(
                     gas_counter,
+                    costs.secp256k1,
                     exec_scopes,
                     system_buffer.next_usize()?,
                     system_buffer.next_usize()?,
                 )
             }),
             "Secp256k1EcMul" => execute_handle_helper(&mut |system_buffer, gas_counter| {
-                secp256k1_ec_mul(
+                secp256_ec_mul::<secp256k1::Config>(
                     gas_counter,
+                    costs.secp256k1,
                     system_buffer.next_usize()?,
                     system_buffer.next_u256()?,
                     exec_scopes,
@@ -616,8 +1063,9 @@ impl<'a> CairoHintProcessor<'a> {
             }),
             "Secp256k1EcGetPointFromX" => {
                 execute_handle_helper(&mut |system_buffer, gas_counter| {
-                    secp256k1_ec_get_point_from_x(
+                    secp256_ec_get_point_from_x::<secp256k1::Config>(
                         gas_counter,
+                        costs.secp256k1,
                         system_buffer.next_u256()?,
                         system_buffer.next_felt252()?.is_zero(),
                         exec_scopes,
@@ -626,8 +1074,57 @@ impl<'a> CairoHintProcessor<'a> {
             }
             "Secp256k1EcGetCoordinates" => {
                 execute_handle_helper(&mut |system_buffer, gas_counter| {
-                    secp256k1_ec_get_coordinates(
+                    secp256_ec_get_coordinates::<secp256k1::Config>(
+                        gas_counter,
+                        costs.secp256k1,
+                        system_buffer.next_usize()?,
+                        exec_scopes,
+                    )
+                })
+            }
+            "Secp256r1EcNew" => execute_handle_helper(&mut |system_buffer, gas_counter| {
+                secp256_ec_new::<secp256r1::Config>(
+                    gas_counter,
+                    costs.secp256r1,
+                    system_buffer.next_u256()?,
+                    system_buffer.next_u256()?,
+                    exec_scopes,
+                )
+            }),
+            "Secp256r1EcAdd" => execute_handle_helper(&mut |system_buffer, gas_counter| {
+                secp256_ec_add::<secp256r1::Config>(
+                    gas_counter,
+                    costs.secp256r1,
+                    exec_scopes,
+                    system_buffer.next_usize()?,
+                    system_buffer.next_usize()?,
+                )
+            }),
+            "Secp256r1EcMul" => execute_handle_helper(&mut |system_buffer, gas_counter| {
+                secp256_ec_mul::<secp256r1::Config>(
+                    gas_counter,
+                    costs.secp256r1,
+                    system_buffer.next_usize()?,
+                    system_buffer.next_u256()?,
+                    exec_scopes,
+                )
+            }),
+            "Secp256r1EcGetPointFromX" => {
+                execute_handle_helper(&mut |system_buffer, gas_counter| {
+                    secp256_ec_get_point_from_x::<secp256r1::Config>(
                         gas_counter,
+                        costs.secp256r1,
+                        system_buffer.next_u256()?,
+                        system_buffer.next_felt252()?.is_zero(),
+                        exec_scopes,
+                    )
+                })
+            }
+            "Secp256r1EcGetCoordinates" => {
+                execute_handle_helper(&mut |system_buffer, gas_counter| {
+                    secp256_ec_get_coordinates::<secp256r1::Config>(
+                        gas_counter,
+                        costs.secp256r1,
                         system_buffer.next_usize()?,
                         exec_scopes,
                     )
@@ -661,10 +1158,108 @@ impl<'a> CairoHintProcessor<'a> {
                     system_buffer,
                 )
             }),
-            _ => panic!("Unknown selector for system call!"),
+            _ => execute_handle_helper(&mut |system_buffer, gas_counter| {
+                let output = match &self.starknet_state.extension {
+                    Some(extension) => extension.execute_syscall(
+                        selector,
+                        gas_counter,
+                        &mut SyscallInput { buffer: system_buffer },
+                    )?,
+                    None => None,
+                };
+                Ok(match output.expect("Unknown selector for system call!") {
+                    Ok(values) => {
+                        SyscallResult::Success(values.into_iter().map(Into::into).collect())
+                    }
+                    Err(revert_reason) => SyscallResult::Failure(revert_reason),
+                })
+            }),
         }
     }
 
+    /// Executes a cheatcode, writing the start and end of its output to the given cells.
+    fn execute_cheatcode(
+        &mut self,
+        selector: &ResOperand,
+        [input_start, input_end]: [&ResOperand; 2],
+        [output_start, output_end]: [&CellRef; 2],
+        vm: &mut VirtualMachine,
+    ) -> Result<(), HintError> {
+        let selector = get_val(vm, selector)?.to_bytes_be();
+        let (cell, offset) = extract_buffer(input_start);
+        let input_start = get_ptr(vm, cell, &offset)?;
+        let (cell, offset) = extract_buffer(input_end);
+        let input_end = get_ptr(vm, cell, &offset)?;
+        let input = vm_get_range(vm, input_start, input_end)?;
+        let selector = std::str::from_utf8(&selector).map_err(|_| {
+            HintError::CustomHint("Cheatcode selector is not a valid short string.".into())
+        })?;
+        let invalid_input =
+            |expected: &str| HintError::CustomHint(format!("`{selector}` expects {expected}."));
+        let output = match selector {
+            "start_cheat" => {
+                let [field, target, value] = <[Felt252; 3]>::try_from(input)
+                    .map_err(|_| invalid_input("a field, a target and a value"))?;
+                let field = as_cairo_short_string(&field).unwrap_or_default();
+                let cheats = self.starknet_state.cheats.entry(target).or_default();
+                *cheats.field_mut(&field)? = Some(value);
+                vec![]
+            }
+            "stop_cheat" => {
+                let [field, target] = <[Felt252; 2]>::try_from(input)
+                    .map_err(|_| invalid_input("a field and a target"))?;
+                let field = as_cairo_short_string(&field).unwrap_or_default();
+                if let Some(cheats) = self.starknet_state.cheats.get_mut(&target) {
+                    *cheats.field_mut(&field)? = None;
+                }
+                vec![]
+            }
+            "start_mock_call" => {
+                let [contract_address, selector, failed, _len, data @ ..] = &input[..] else {
+                    return Err(invalid_input("an address, a selector, a flag and data"));
+                };
+                let data = data.to_vec();
+                let result = if failed.is_zero() { Ok(data) } else { Err(data) };
+                self.starknet_state
+                    .mocked_calls
+                    .insert((contract_address.clone(), selector.clone()), result);
+                vec![]
+            }
+            "stop_mock_call" => {
+                let [contract_address, selector] = <[Felt252; 2]>::try_from(input)
+                    .map_err(|_| invalid_input("an address and a selector"))?;
+                self.starknet_state.mocked_calls.remove(&(contract_address, selector));
+                vec![]
+            }
+            "pop_log" => {
+                let [from_address] =
+                    <[Felt252; 1]>::try_from(input).map_err(|_| invalid_input("an address"))?;
+                match self.starknet_state.pop_log(&from_address) {
+                    Some(ContractEvent { keys, data, .. }) => chain!(
+                        [Felt252::from(0), Felt252::from(keys.len())],
+                        keys,
+                        [Felt252::from(data.len())],
+                        data
+                    )
+                    .collect(),
+                    None => vec![Felt252::from(1)],
+                }
+            }
+            selector => self
+                .starknet_state
+                .extension
+                .as_ref()
+                .and_then(|extension| extension.execute_cheatcode(selector, &input))
+                .ok_or_else(|| {
+                    HintError::CustomHint(format!("Unknown selector for cheatcode `{selector}`."))
+                })?,
+        };
+        let (output_start_ptr, output_end_ptr) = segment_with_data(vm, output.into_iter())?;
+        insert_value_to_cellref!(vm, output_start, output_start_ptr)?;
+        insert_value_to_cellref!(vm, output_end, output_end_ptr)?;
+        Ok(())
+    }
+
     /// Executes the `storage_write_syscall` syscall.
     fn storage_write(
         &mut self,
@@ -673,7 +1268,7 @@ impl<'a> CairoHintProcessor<'a> {
         addr: Felt252,
         value: Felt252,
     ) -> Result<SyscallResult, HintError> {
-        deduct_gas!(gas_counter, 1000);
+        deduct_gas!(gas_counter, self.starknet_state.syscall_costs.storage_write);
         if !addr_domain.is_zero() {
             // Only address_domain 0 is currently supported.
             fail_syscall!(b"Unsupported address domain");
@@ -690,18 +1285,23 @@ impl<'a> CairoHintProcessor<'a> {
         addr_domain: Felt252,
         addr: Felt252,
     ) -> Result<SyscallResult, HintError> {
-        deduct_gas!(gas_counter, 100);
+        deduct_gas!(gas_counter, self.starknet_state.syscall_costs.storage_read);
         if !addr_domain.is_zero() {
             // Only address_domain 0 is currently supported.
             fail_syscall!(b"Unsupported address domain");
         }
+        let contract = &self.starknet_state.exec_info.contract_address;
         let value = self
             .starknet_state
             .storage
-            .get(&self.starknet_state.exec_info.contract_address)
+            .get(contract)
             .and_then(|contract_storage| contract_storage.get(&addr))
-            .cloned()
-            .unwrap_or_else(|| Felt252::from(0));
+            .cloned();
+        let value = match (value, &self.starknet_state.fork) {
+            (Some(value), _) => value,
+            (None, Some(fork)) => fork.storage_at(contract, &addr).map_err(fork_hint_error)?,
+            (None, None) => Felt252::from(0),
+        };
         Ok(SyscallResult::Success(vec![value.into()]))
     }
 
@@ -711,7 +1311,7 @@ impl<'a> CairoHintProcessor<'a> {
         gas_counter: &mut usize,
         _block_number: u64,
     ) -> Result<SyscallResult, HintError> {
-        deduct_gas!(gas_counter, 100);
+        deduct_gas!(gas_counter, self.starknet_state.syscall_costs.get_block_hash);
         // TODO(Arni, 28/5/2023): Replace the temporary return value with the required value.
         //      One design suggestion - to preform a storage read. Have an arbitrary, hardcoded
         //      (For example, addr=1) contain the mapping from block number to block hash.
@@ -724,8 +1324,17 @@ impl<'a> CairoHintProcessor<'a> {
         gas_counter: &mut usize,
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
-        deduct_gas!(gas_counter, 50);
-        let exec_info = &self.starknet_state.exec_info;
+        deduct_gas!(gas_counter, self.starknet_state.syscall_costs.get_execution_info);
+        let mut exec_info = self.starknet_state.exec_info.clone();
+        if let Some(fork) = &self.starknet_state.fork {
+            exec_info.tx_info.nonce = fork
+                .nonce_at(&exec_info.tx_info.account_contract_address)
+                .map_err(fork_hint_error)?;
+        }
+        if let Some(cheats) = self.starknet_state.cheats.get(&exec_info.contract_address) {
+            cheats.apply(&mut exec_info);
+        }
+        let exec_info = &exec_info;
         let block_info = &exec_info.block_info;
         let tx_info = &exec_info.tx_info;
         let mut res_segment = MemBuffer::new_segment(vm);
@@ -753,6 +1362,19 @@ impl<'a> CairoHintProcessor<'a> {
         Ok(SyscallResult::Success(vec![exec_info_ptr.into()]))
     }
 
+    /// Executes the `emit_event_syscall` syscall.
+    fn emit_event(
+        &mut self,
+        gas_counter: &mut usize,
+        keys: Vec<Felt252>,
+        data: Vec<Felt252>,
+    ) -> Result<SyscallResult, HintError> {
+        deduct_gas!(gas_counter, self.starknet_state.syscall_costs.emit_event);
+        let from_address = self.starknet_state.exec_info.contract_address.clone();
+        self.starknet_state.events.push(ContractEvent { from_address, keys, data });
+        Ok(SyscallResult::Success(vec![]))
+    }
+
     /// Executes the `deploy_syscall` syscall.
     fn deploy(
         &mut self,
@@ -763,16 +1385,16 @@ impl<'a> CairoHintProcessor<'a> {
         _deploy_from_zero: Felt252,
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
-        deduct_gas!(gas_counter, 50);
+        deduct_gas!(gas_counter, self.starknet_state.syscall_costs.deploy);
 
         // Assign an arbitrary address to the contract.
         let deployed_contract_address = self.starknet_state.get_next_id();
 
         // Prepare runner for running the constructor.
-        let runner = self.runner.expect("Runner is needed for starknet.");
-        let Some(contract_info) = runner.starknet_contracts_info.get(&class_hash) else {
+        let Some(runner) = self.class_runner(&class_hash)? else {
             fail_syscall!(b"CLASS_HASH_NOT_FOUND");
         };
+        let contract_info = &runner.starknet_contracts_info[class_hash.clone()];
 
         // Call constructor if it exists.
         let (res_data_start, res_data_end) = if let Some(constructor) = &contract_info.constructor {
@@ -783,11 +1405,11 @@ impl<'a> CairoHintProcessor<'a> {
             );
 
             // Run the constructor.
-            let res = self.call_entry_point(gas_counter, runner, constructor, calldata, vm);
+            let res = self.call_entry_point(gas_counter, &runner, constructor, calldata, vm);
 
             // Restore the contract address in the context.
             self.starknet_state.exec_info.contract_address = old_contract_address;
-            match res {
+            match res? {
                 Ok(value) => value,
                 Err(mut revert_reason) => {
                     fail_syscall!(revert_reason, b"CONSTRUCTOR_FAILED");
@@ -817,19 +1439,37 @@ impl<'a> CairoHintProcessor<'a> {
         calldata: Vec<Felt252>,
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
-        deduct_gas!(gas_counter, 50);
+        deduct_gas!(gas_counter, self.starknet_state.syscall_costs.call_contract);
+
+        // Mocked calls return their mocked result, without calling the contract.
+        let mock_key = (contract_address.clone(), selector.clone());
+        if let Some(result) = self.starknet_state.mocked_calls.get(&mock_key) {
+            match result.clone() {
+                Ok(ret_data) => {
+                    let (res_data_start, res_data_end) =
+                        segment_with_data(vm, ret_data.into_iter())
+                            .expect("failed to allocate segment");
+                    return Ok(SyscallResult::Success(vec![
+                        res_data_start.into(),
+                        res_data_end.into(),
+                    ]));
+                }
+                Err(mut panic_data) => {
+                    fail_syscall!(panic_data, b"ENTRYPOINT_FAILED");
+                }
+            }
+        }
 
         // Get the class hash of the contract.
-        let Some(class_hash) = self.starknet_state.deployed_contracts.get(&contract_address) else {
+        let Some(class_hash) = self.deployed_class_hash(&contract_address)? else {
             fail_syscall!(b"CONTRACT_NOT_DEPLOYED");
         };
 
-        // Prepare runner for running the ctor.
-        let runner = self.runner.expect("Runner is needed for starknet.");
-        let contract_info = runner
-            .starknet_contracts_info
-            .get(class_hash)
-            .expect("Deployed contract not found in registry.");
+        // Prepare runner for running the call.
+        let Some(runner) = self.class_runner(&class_hash)? else {
+            fail_syscall!(b"CLASS_HASH_NOT_FOUND");
+        };
+        let contract_info = &runner.starknet_contracts_info[class_hash.clone()];
 
         // Call the function.
         let Some(entry_point) = contract_info.externals.get(&selector) else {
@@ -846,13 +1486,19 @@ impl<'a> CairoHintProcessor<'a> {
             old_contract_address.clone(),
         );
 
-        let res = self.call_entry_point(gas_counter, runner, entry_point, calldata, vm);
+        let gas_before_call = *gas_counter;
+        let res = self.call_entry_point(gas_counter, &runner, entry_point, calldata, vm);
+        self.starknet_state.contract_calls_gas.push(ContractCallGas {
+            contract_address: contract_address.clone(),
+            selector,
+            gas: gas_before_call - *gas_counter,
+        });
 
         // Restore the contract address in the context.
         self.starknet_state.exec_info.caller_address = old_caller_address;
         self.starknet_state.exec_info.contract_address = old_contract_address;
 
-        match res {
+        match res? {
             Ok((res_data_start, res_data_end)) => {
                 Ok(SyscallResult::Success(vec![res_data_start.into(), res_data_end.into()]))
             }
@@ -871,19 +1517,18 @@ impl<'a> CairoHintProcessor<'a> {
         calldata: Vec<Felt252>,
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
-        deduct_gas!(gas_counter, 50);
+        deduct_gas!(gas_counter, self.starknet_state.syscall_costs.library_call);
         // Prepare runner for running the call.
-        let runner = self.runner.expect("Runner is needed for starknet.");
-        let contract_info = runner
-            .starknet_contracts_info
-            .get(&class_hash)
-            .expect("Deployed contract not found in registry.");
+        let Some(runner) = self.class_runner(&class_hash)? else {
+            fail_syscall!(b"CLASS_HASH_NOT_FOUND");
+        };
+        let contract_info = &runner.starknet_contracts_info[class_hash.clone()];
 
         // Call the function.
         let Some(entry_point) = contract_info.externals.get(&selector) else {
             fail_syscall!(b"ENTRYPOINT_NOT_FOUND");
         };
-        match self.call_entry_point(gas_counter, runner, entry_point, calldata, vm) {
+        match self.call_entry_point(gas_counter, &runner, entry_point, calldata, vm)? {
             Ok((res_data_start, res_data_end)) => {
                 Ok(SyscallResult::Success(vec![res_data_start.into(), res_data_end.into()]))
             }
@@ -893,7 +1538,38 @@ impl<'a> CairoHintProcessor<'a> {
         }
     }
 
-    /// Executes the entry point with the given calldata.
+    /// Returns the class hash of the contract deployed at the given address, looking it up in the
+    /// fork if not deployed in the simulated state.
+    fn deployed_class_hash(
+        &self,
+        contract_address: &Felt252,
+    ) -> Result<Option<Felt252>, HintError> {
+        if let Some(class_hash) = self.starknet_state.deployed_contracts.get(contract_address) {
+            return Ok(Some(class_hash.clone()));
+        }
+        match &self.starknet_state.fork {
+            Some(fork) => fork.class_hash_at(contract_address).map_err(fork_hint_error),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the runner of the class with the given hash, whose contract infos include the info
+    /// of the class, looking the class up in the fork if it is not one of the runner's classes.
+    fn class_runner(&self, class_hash: &Felt252) -> Result<Option<ClassRunner<'a>>, HintError> {
+        let runner = self.runner.expect("Runner is needed for starknet.");
+        if runner.starknet_contracts_info.contains_key(class_hash) {
+            return Ok(Some(ClassRunner::Program(runner)));
+        }
+        match &self.starknet_state.fork {
+            Some(fork) => {
+                Ok(fork.class_runner(class_hash).map_err(fork_hint_error)?.map(ClassRunner::Forked))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Executes the entry point with the given calldata, returning its result data, or its panic
+    /// data if it failed. Fails the hint if the call exhausted its resources.
     fn call_entry_point(
         &mut self,
         gas_counter: &mut usize,
@@ -901,40 +1577,85 @@ impl<'a> CairoHintProcessor<'a> {
         entry_point: &FunctionId,
         calldata: Vec<Felt252>,
         vm: &mut dyn VMWrapper,
-    ) -> Result<(Relocatable, Relocatable), Vec<Felt252>> {
+    ) -> Result<Result<(Relocatable, Relocatable), Vec<Felt252>>, HintError> {
         let function = runner
             .sierra_program_registry
             .get_function(entry_point)
             .expect("Entrypoint exists, but not found.");
-        let mut res = runner
-            .run_function(
-                function,
-                &[Arg::Array(calldata)],
-                Some(*gas_counter),
-                self.starknet_state.clone(),
-            )
-            .expect("Internal runner error.");
+        // The contracts of the program remain callable from the classes fetched from the fork.
+        let res = runner.run_contract_function(
+            function,
+            &[Arg::Array(calldata)],
+            Some(*gas_counter),
+            self.starknet_state.clone(),
+            self.runner.expect("Runner is needed for starknet."),
+        );
+        let mut res = match res {
+            Ok(res) => res,
+            Err(RunnerError::ResourcesExhausted(resource)) => {
+                self.nested_exhausted_resource = Some(resource);
+                return Err(HintError::CustomHint(format!("Contract call {resource}.")));
+            }
+            Err(err) => panic!("Internal runner error: {err:?}"),
+        };
 
         *gas_counter = res.gas_counter.unwrap().to_usize().unwrap();
-        self.starknet_state = std::mem::take(&mut res.starknet_state);
+        self.nested_resources += &res.used_resources;
         match res.value {
             RunResultValue::Success(value) => {
-                Ok(segment_with_data(vm, read_array_result_as_vec(&res.memory, &value).into_iter())
-                    .expect("failed to allocate segment"))
+                self.starknet_state = std::mem::take(&mut res.starknet_state);
+                Ok(Ok(segment_with_data(
+                    vm,
+                    read_array_result_as_vec(&res.memory, &value).into_iter(),
+                )
+                .expect("failed to allocate segment")))
+            }
+            RunResultValue::Panic(panic_data) => {
+                // The state changes of a reverted call are discarded, including those of the calls
+                // nested in it, but the records of the run are kept.
+                self.starknet_state.bookkeeping =
+                    std::mem::take(&mut res.starknet_state.bookkeeping);
+                Ok(Err(panic_data))
             }
-            RunResultValue::Panic(panic_data) => Err(panic_data),
         }
     }
 }
 
+/// The runner of a contract class.
+enum ClassRunner<'a> {
+    /// The runner of the program, running one of its contracts.
+    Program(&'a SierraCasmRunner),
+    /// The runner of a class fetched from the fork.
+    Forked(Arc<SierraCasmRunner>),
+}
+impl Deref for ClassRunner<'_> {
+    type Target = SierraCasmRunner;
+
+    fn deref(&self) -> &SierraCasmRunner {
+        match self {
+            Self::Program(runner) => runner,
+            Self::Forked(runner) => runner,
+        }
+    }
+}
+
+/// Converts a failure to access the fork into an error of the hint accessing it.
+fn fork_hint_error(err: ForkError) -> HintError {
+    HintError::CustomHint(err.to_string())
+}
+
 /// Executes the `keccak_syscall` syscall.
-fn keccak(gas_counter: &mut usize, data: Vec<Felt252>) -> Result<SyscallResult, HintError> {
+fn keccak(
+    gas_counter: &mut usize,
+    round_gas_cost: usize,
+    data: Vec<Felt252>,
+) -> Result<SyscallResult, HintError> {
     if data.len() % 17 != 0 {
         fail_syscall!(b"Invalid keccak input size");
     }
     let mut state = [0u64; 25];
     for chunk in data.chunks(17) {
-        deduct_gas!(gas_counter, 5000);
+        deduct_gas!(gas_counter, round_gas_cost);
         for (i, val) in chunk.iter().enumerate() {
             state[i] ^= val.to_u64().unwrap();
         }
@@ -946,28 +1667,32 @@ fn keccak(gas_counter: &mut usize, data: Vec<Felt252>) -> Result<SyscallResult,
     ]))
 }
 
-/// Executes the `secp256k1_ec_new_syscall` syscall.
-fn secp256k1_ec_new(
+/// Executes the `secp256k1_ec_new_syscall` and `secp256r1_ec_new_syscall` syscalls.
+fn secp256_ec_new<Curve: Secp256Curve>(
     gas_counter: &mut usize,
+    gas_cost: usize,
     x: BigUint,
     y: BigUint,
     exec_scopes: &mut ExecutionScopes,
-) -> Result<SyscallResult, HintError> {
-    deduct_gas!(gas_counter, 500);
-    let modulos = <secp256k1::Fq as PrimeField>::MODULUS.into();
+) -> Result<SyscallResult, HintError>
+where
+    Curve::BaseField: PrimeField,
+{
+    deduct_gas!(gas_counter, gas_cost);
+    let modulos = <Curve::BaseField as PrimeField>::MODULUS.into();
     if x >= modulos || y >= modulos {
         fail_syscall!(b"Coordinates out of range");
     }
     let p = if x.is_zero() && y.is_zero() {
-        secp256k1::Affine::identity()
+        Affine::<Curve>::identity()
     } else {
-        secp256k1::Affine::new_unchecked(x.into(), y.into())
+        Affine::<Curve>::new_unchecked(x.into(), y.into())
     };
     Ok(SyscallResult::Success(
         if !(p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve()) {
             vec![1.into(), 0.into()]
         } else {
-            let ec = get_secp256k1_exec_scope(exec_scopes)?;
+            let ec = get_secp256_exec_scope::<Curve>(exec_scopes)?;
             let id = ec.ec_points.len();
             ec.ec_points.push(p);
             vec![0.into(), id.into()]
@@ -975,15 +1700,16 @@ fn secp256k1_ec_new(
     ))
 }
 
-/// Executes the `secp256k1_ec_add_syscall` syscall.
-fn secp256k1_ec_add(
+/// Executes the `secp256k1_ec_add_syscall` and `secp256r1_ec_add_syscall` syscalls.
+fn secp256_ec_add<Curve: Secp256Curve>(
     gas_counter: &mut usize,
+    gas_cost: usize,
     exec_scopes: &mut ExecutionScopes,
     p0_id: usize,
     p1_id: usize,
 ) -> Result<SyscallResult, HintError> {
-    deduct_gas!(gas_counter, 500);
-    let ec = get_secp256k1_exec_scope(exec_scopes)?;
+    deduct_gas!(gas_counter, gas_cost);
+    let ec = get_secp256_exec_scope::<Curve>(exec_scopes)?;
     let p0 = &ec.ec_points[p0_id];
     let p1 = &ec.ec_points[p1_id];
     let sum = *p0 + *p1;
@@ -992,65 +1718,76 @@ fn secp256k1_ec_add(
     Ok(SyscallResult::Success(vec![id.into()]))
 }
 
-/// Executes the `secp256k1_ec_mul_syscall` syscall.
-fn secp256k1_ec_mul(
+/// Executes the `secp256k1_ec_mul_syscall` and `secp256r1_ec_mul_syscall` syscalls.
+fn secp256_ec_mul<Curve: Secp256Curve>(
     gas_counter: &mut usize,
+    gas_cost: usize,
     p_id: usize,
     m: BigUint,
     exec_scopes: &mut ExecutionScopes,
 ) -> Result<SyscallResult, HintError> {
-    deduct_gas!(gas_counter, 500);
-    if m >= <secp256k1::Fr as PrimeField>::MODULUS.into() {
+    deduct_gas!(gas_counter, gas_cost);
+    if m >= <Curve::ScalarField as PrimeField>::MODULUS.into() {
         fail_syscall!(b"Scalar out of range");
     }
-    let ec = get_secp256k1_exec_scope(exec_scopes)?;
+    let ec = get_secp256_exec_scope::<Curve>(exec_scopes)?;
     let p = &ec.ec_points[p_id];
-    let product = *p * secp256k1::Fr::from(m);
+    let product = *p * Curve::ScalarField::from(m);
     let id = ec.ec_points.len();
     ec.ec_points.push(product.into());
     Ok(SyscallResult::Success(vec![id.into()]))
 }
 
-/// Executes the `secp256k1_ec_get_point_from_x_syscall` syscall.
-fn secp256k1_ec_get_point_from_x(
+/// Executes the `secp256k1_ec_get_point_from_x_syscall` and
+/// `secp256r1_ec_get_point_from_x_syscall` syscalls.
+fn secp256_ec_get_point_from_x<Curve: Secp256Curve>(
     gas_counter: &mut usize,
+    gas_cost: usize,
     x: BigUint,
     y_parity: bool,
     exec_scopes: &mut ExecutionScopes,
-) -> Result<SyscallResult, HintError> {
-    deduct_gas!(gas_counter, 500);
-    if x >= <secp256k1::Fq as PrimeField>::MODULUS.into() {
+) -> Result<SyscallResult, HintError>
+where
+    Curve::BaseField: PrimeField,
+{
+    deduct_gas!(gas_counter, gas_cost);
+    if x >= <Curve::BaseField as PrimeField>::MODULUS.into() {
         fail_syscall!(b"Coordinates out of range");
     }
     let x = x.into();
-    let maybe_p = secp256k1::Affine::get_ys_from_x_unchecked(x)
-        .map(|(smaller, greater)| match (smaller.0.is_even(), y_parity) {
+    let maybe_p = Affine::<Curve>::get_ys_from_x_unchecked(x)
+        .map(|(smaller, greater)| match (Curve::is_y_even(&smaller), y_parity) {
             (true, true) | (false, false) => smaller,
             (true, false) | (false, true) => greater,
         })
-        .map(|y| secp256k1::Affine::new_unchecked(x, y))
+        .map(|y| Affine::<Curve>::new_unchecked(x, y))
         .filter(|p| p.is_in_correct_subgroup_assuming_on_curve());
     let Some(p) = maybe_p else {
         return Ok(SyscallResult::Success(vec![1.into(), 0.into()]));
     };
-    let ec = get_secp256k1_exec_scope(exec_scopes)?;
+    let ec = get_secp256_exec_scope::<Curve>(exec_scopes)?;
     let id = ec.ec_points.len();
     ec.ec_points.push(p);
     Ok(SyscallResult::Success(vec![0.into(), id.into()]))
 }
 
-/// Executes the `secp256k1_ec_get_coordinates_syscall` syscall.
-fn secp256k1_ec_get_coordinates(
+/// Executes the `secp256k1_ec_get_coordinates_syscall` and
+/// `secp256r1_ec_get_coordinates_syscall` syscalls.
+fn secp256_ec_get_coordinates<Curve: Secp256Curve>(
     gas_counter: &mut usize,
+    gas_cost: usize,
     p_id: usize,
     exec_scopes: &mut ExecutionScopes,
-) -> Result<SyscallResult, HintError> {
-    deduct_gas!(gas_counter, 500);
-    let ec = get_secp256k1_exec_scope(exec_scopes)?;
+) -> Result<SyscallResult, HintError>
+where
+    Curve::BaseField: PrimeField,
+{
+    deduct_gas!(gas_counter, gas_cost);
+    let ec = get_secp256_exec_scope::<Curve>(exec_scopes)?;
     let p = &ec.ec_points[p_id];
     let pow_2_128 = BigUint::from(u128::MAX) + 1u32;
-    let (x1, x0) = BigUint::from(p.x).div_rem(&pow_2_128);
-    let (y1, y0) = BigUint::from(p.y).div_rem(&pow_2_128);
+    let (x1, x0) = Into::<BigUint>::into(p.x).div_rem(&pow_2_128);
+    let (y1, y0) = Into::<BigUint>::into(p.y).div_rem(&pow_2_128);
     Ok(SyscallResult::Success(vec![
         Felt252::from(x0).into(),
         Felt252::from(x1).into(),
@@ -1059,17 +1796,19 @@ fn secp256k1_ec_get_coordinates(
     ]))
 }
 
-/// Returns the `Secp256K1ExecScope` managing the different active points.
+/// Returns the `Secp256ExecutionScope` managing the different active points of the curve.
 /// The first call to this function will create the scope, and subsequent calls will return it.
 /// The first call would happen from some point creation syscall.
-fn get_secp256k1_exec_scope(
+fn get_secp256_exec_scope<Curve: Secp256Curve>(
     exec_scopes: &mut ExecutionScopes,
-) -> Result<&mut Secp256K1ExecutionScope, HintError> {
-    const NAME: &str = "secp256k1_exec_scope";
-    if exec_scopes.get_ref::<Secp256K1ExecutionScope>(NAME).is_err() {
-        exec_scopes.assign_or_update_variable(NAME, Box::<Secp256K1ExecutionScope>::default());
+) -> Result<&mut Secp256ExecutionScope<Curve>, HintError> {
+    if exec_scopes.get_ref::<Secp256ExecutionScope<Curve>>(Curve::EXEC_SCOPE_NAME).is_err() {
+        exec_scopes.assign_or_update_variable(
+            Curve::EXEC_SCOPE_NAME,
+            Box::<Secp256ExecutionScope<Curve>>::default(),
+        );
     }
-    exec_scopes.get_mut_ref::<Secp256K1ExecutionScope>(NAME)
+    exec_scopes.get_mut_ref::<Secp256ExecutionScope<Curve>>(Curve::EXEC_SCOPE_NAME)
 }
 
 pub fn execute_core_hint_base(
@@ -1547,22 +2286,7 @@ pub fn execute_core_hint(
             )?;
         }
         CoreHint::DebugPrint { start, end } => {
-            let as_relocatable = |vm, value| {
-                let (base, offset) = extract_buffer(value);
-                get_ptr(vm, base, &offset)
-            };
-            let mut curr = as_relocatable(vm, start)?;
-            let end = as_relocatable(vm, end)?;
-            while curr != end {
-                let value = vm.get_integer(curr)?;
-                if let Some(shortstring) = as_cairo_short_string(&value) {
-                    println!("[DEBUG]\t{shortstring: <31}\t(raw: {value: <31})");
-                } else {
-                    println!("[DEBUG]\t{0: <31}\t(raw: {value: <31}) ", ' ');
-                }
-                curr += 1;
-            }
-            println!();
+            print!("{}", debug_print_output(vm, start, end)?);
         }
         CoreHint::AllocConstantSize { size, dst } => {
             let object_size = get_val(vm, size)?.to_usize().expect("Object size too large.");
@@ -1610,6 +2334,31 @@ fn vm_get_range(
     Ok(values)
 }
 
+/// Returns the output of a `DebugPrint` hint: a line per printed value, along with its short
+/// string representation, followed by an empty line.
+fn debug_print_output(
+    vm: &mut VirtualMachine,
+    start: &ResOperand,
+    end: &ResOperand,
+) -> Result<String, HintError> {
+    let as_relocatable = |vm, value| {
+        let (base, offset) = extract_buffer(value);
+        get_ptr(vm, base, &offset)
+    };
+    let start = as_relocatable(vm, start)?;
+    let end = as_relocatable(vm, end)?;
+    let mut output = String::new();
+    for value in vm_get_range(vm, start, end)? {
+        if let Some(shortstring) = as_cairo_short_string(&value) {
+            output.push_str(&format!("[DEBUG]\t{shortstring: <31}\t(raw: {value: <31})\n"));
+        } else {
+            output.push_str(&format!("[DEBUG]\t{0: <31}\t(raw: {value: <31}) \n", ' '));
+        }
+    }
+    output.push('\n');
+    Ok(output)
+}
+
 /// Extracts a parameter assumed to be a buffer.
 fn extract_buffer(buffer: &ResOperand) -> (&CellRef, Felt252) {
     let (cell, base_offset) = match buffer {
@@ -1628,9 +2377,20 @@ pub struct RunFunctionContext<'a> {
     pub data_len: usize,
 }
 
-type RunFunctionRes = (Vec<Option<Felt252>>, usize, StarknetState);
-
-/// Runs `program` on layout with prime, and returns the memory layout and ap value.
+type RunFunctionRes = (
+    Vec<Option<Felt252>>,
+    usize,
+    StarknetState,
+    ExecutionResources,
+    Vec<usize>,
+    Option<ProverInput>,
+);
+
+/// Runs `program` on layout with prime, and returns the memory layout and ap value, along with the
+/// offsets in the program of the executed instructions, and the input for proving the run if
+/// `export_trace` is set. Fails if the run exceeds the given resource limits, which are not
+/// enforced if the run is debugged.
+#[allow(clippy::too_many_arguments)]
 pub fn run_function<'a, 'b: 'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
     runner: Option<&'b SierraCasmRunner>,
     instructions: Instructions,
@@ -1639,7 +2399,10 @@ pub fn run_function<'a, 'b: 'a, Instructions: Iterator<Item = &'a Instruction> +
         context: RunFunctionContext<'_>,
     ) -> Result<(), Box<VirtualMachineError>>,
     starknet_state: StarknetState,
-) -> Result<RunFunctionRes, Box<VirtualMachineError>> {
+    export_trace: bool,
+    limits: &ResourceLimits,
+    debug_context: Option<DebugContext<'_>>,
+) -> Result<RunFunctionRes, RunnerError> {
     let data: Vec<MaybeRelocatable> = instructions
         .clone()
         .flat_map(|inst| inst.assemble().encode())
@@ -1673,12 +2436,44 @@ pub fn run_function<'a, 'b: 'a, Instructions: Iterator<Item = &'a Instruction> +
 
     additional_initialization(RunFunctionContext { vm: &mut vm, data_len })?;
 
-    runner.run_until_pc(end, &mut vm, &mut hint_processor)?;
+    match debug_context {
+        Some(context) => {
+            debugger::run_until_pc(&mut runner, end, &mut vm, &mut hint_processor, context)
+        }
+        None => {
+            resource_limits::run_until_pc(&mut runner, end, &mut vm, &mut hint_processor, limits)
+        }
+    }
+    .map_err(|err| match hint_processor.nested_exhausted_resource.take() {
+        Some(resource) => RunnerError::ResourcesExhausted(resource),
+        None => err,
+    })?;
     runner.end_run(true, false, &mut vm, &mut hint_processor).map_err(Box::new)?;
+    // The limits are computed from the trace before its relocation.
+    let rc_limits = if export_trace {
+        Some(runner.get_perm_range_check_limits(&vm).map_err(Box::new)?)
+    } else {
+        None
+    };
     runner.relocate(&mut vm, true).map_err(VirtualMachineError::from).map_err(Box::new)?;
+    let mut used_resources =
+        runner.get_execution_resources(&vm).map_err(VirtualMachineError::from).map_err(Box::new)?;
+    used_resources += &hint_processor.nested_resources;
+    let trace = vm.get_relocated_trace().unwrap();
+    // The program segment is relocated to start at address 1.
+    let pc_offsets = trace.iter().map(|entry| entry.pc - 1).collect();
+    let ap = trace.last().unwrap().ap;
+    let prover_input = rc_limits
+        .map(|rc_limits| ProverInput::new(&vm, &runner.relocated_memory, rc_limits))
+        .transpose()
+        .map_err(VirtualMachineError::from)
+        .map_err(Box::new)?;
     Ok((
         runner.relocated_memory,
-        vm.get_relocated_trace().unwrap().last().unwrap().ap,
+        ap,
         hint_processor.starknet_state,
+        used_resources,
+        pc_offsets,
+        prover_input,
     ))
 }
@@ -1,12 +1,23 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
 use cairo_felt::Felt252;
+use cairo_lang_casm::hints::{Hint, StarknetHint};
 use cairo_lang_casm::inline::CasmContext;
+use cairo_lang_casm::operand::ResOperand;
 use cairo_lang_casm::{casm, deref};
+use cairo_lang_starknet::storage_layout::{StorageLayout, StorageVariable};
+use cairo_vm::vm::errors::hint_errors::HintError;
 use itertools::Itertools;
 use num_traits::ToPrimitive;
 use test_case::test_case;
 
 use crate::casm_run::run_function;
-use crate::StarknetState;
+use crate::{
+    ContractEvent, ExhaustedResource, HintProcessorExtension, ResourceLimits, RunnerError,
+    StarknetState, SyscallCosts, SyscallInput, SyscallOutput,
+};
 
 #[test_case(
     casm! {
@@ -102,12 +113,15 @@ use crate::StarknetState;
     "simple_division"
 )]
 fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
-    let (cells, ap, _) = run_function(
+    let (cells, ap, _, _, _, _) = run_function(
         None,
         function.instructions.iter(),
         vec![],
         |_| Ok(()),
         StarknetState::default(),
+        false,
+        &ResourceLimits::default(),
+        None,
     )
     .expect("Running code failed.");
     let cells = cells.into_iter().skip(ap - n_returns);
@@ -117,9 +131,48 @@ fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
     );
 }
 
+#[test_case(
+    casm! { jmp rel 0; },
+    ResourceLimits { max_steps: Some(100), ..Default::default() },
+    ExhaustedResource::Steps(100);
+    "infinite_loop"
+)]
+#[test_case(
+    casm! { jmp rel 0, ap++; },
+    ResourceLimits { max_memory: Some(50), ..Default::default() },
+    ExhaustedResource::Memory(50);
+    "infinite_stack_growth"
+)]
+#[test_case(
+    casm! { jmp rel 0; },
+    ResourceLimits { timeout: Some(Duration::ZERO), ..Default::default() },
+    ExhaustedResource::Time(Duration::ZERO);
+    "timeout"
+)]
+fn test_resource_limits(
+    function: CasmContext,
+    limits: ResourceLimits,
+    expected_resource: ExhaustedResource,
+) {
+    let result = run_function(
+        None,
+        function.instructions.iter(),
+        vec![],
+        |_| Ok(()),
+        StarknetState::default(),
+        false,
+        &limits,
+        None,
+    );
+    assert!(matches!(
+        result,
+        Err(RunnerError::ResourcesExhausted(resource)) if resource == expected_resource
+    ));
+}
+
 #[test]
 fn test_allocate_segment() {
-    let (memory, ap, _) = run_function(
+    let (memory, ap, _, _, _, _) = run_function(
         None,
         casm! {
             [ap] = 1337, ap++;
@@ -132,6 +185,9 @@ fn test_allocate_segment() {
         vec![],
         |_| Ok(()),
         StarknetState::default(),
+        false,
+        &ResourceLimits::default(),
+        None,
     )
     .expect("Running code failed.");
     let ptr = memory[ap]
@@ -141,3 +197,101 @@ fn test_allocate_segment() {
         .expect("Number not in index range.");
     assert_eq!(memory[ptr], Some(Felt252::from(1337)));
 }
+
+/// An extension with a `Double` syscall, doubling its input at a gas cost of 10.
+struct DoublingExtension;
+impl HintProcessorExtension for DoublingExtension {
+    fn execute_syscall(
+        &self,
+        selector: &str,
+        gas_counter: &mut usize,
+        input: &mut SyscallInput<'_, '_>,
+    ) -> Result<Option<SyscallOutput>, HintError> {
+        if selector != "Double" {
+            return Ok(None);
+        }
+        *gas_counter -= 10;
+        Ok(Some(Ok(vec![input.next_felt252()? * Felt252::from(2)])))
+    }
+}
+
+#[test]
+fn test_extension_syscall() {
+    let mut function = casm! {
+        %{ memory[ap] = segments.add() %}
+        ap += 1;
+        // The `Double` selector.
+        [ap] = 0x446f75626c65_u64, ap++;
+        [ap - 1] = [[ap - 2]];
+        [ap] = 1000, ap++;
+        [ap - 1] = [[ap - 3] + 1];
+        [ap] = 21, ap++;
+        [ap - 1] = [[ap - 4] + 2];
+        // Returns the gas counter, the failure flag and the output of the syscall.
+        [ap] = [[ap - 4] + 3], ap++;
+        [ap] = [[ap - 5] + 4], ap++;
+        [ap] = [[ap - 6] + 5], ap++;
+        ret;
+    };
+    function.instructions[7].hints.push(Hint::Starknet(StarknetHint::SystemCall {
+        system: ResOperand::Deref(deref!([ap - 4])),
+    }));
+    let (cells, ap, starknet_state, _, _, _) = run_function(
+        None,
+        function.instructions.iter(),
+        vec![],
+        |_| Ok(()),
+        StarknetState::default().with_extension(Arc::new(DoublingExtension)),
+        false,
+        &ResourceLimits::default(),
+        None,
+    )
+    .expect("Running code failed.");
+    assert_eq!(
+        cells[ap - 3..ap].iter().map(|cell| cell.clone().unwrap()).collect_vec(),
+        [990, 0, 42].map(Felt252::from)
+    );
+    assert_eq!(starknet_state.syscall_counter(), vec![("Double", 1)]);
+}
+
+#[test]
+fn test_dump_storage() {
+    let mut starknet_state = StarknetState::default();
+    starknet_state.storage.entry(Felt252::from(7)).or_default().extend([
+        (Felt252::from(1001), Felt252::from(5)),
+        (Felt252::from(1000), Felt252::from(4)),
+        (Felt252::from(3), Felt252::from(6)),
+    ]);
+    let layout = StorageLayout {
+        variables: vec![StorageVariable {
+            name: "total_supply".into(),
+            ty: "u256".into(),
+            address: 1000_u32.into(),
+        }],
+    };
+    let dumps = starknet_state.dump_storage(&HashMap::from([(Felt252::from(7), layout)]));
+    assert_eq!(
+        dumps.iter().map(|dump| dump.to_string()).join(""),
+        "Contract 0x7:\n  0x3 = 0x6\n  total_supply = 0x4\n  total_supply+1 = 0x5\n"
+    );
+}
+
+#[test]
+fn test_contract_event_display() {
+    let event = ContractEvent {
+        from_address: Felt252::from(7),
+        keys: vec![Felt252::from(1)],
+        data: vec![Felt252::from(2), Felt252::from(3)],
+    };
+    assert_eq!(event.to_string(), "Event from 0x7: keys [0x1], data [0x2, 0x3]");
+}
+
+#[test]
+fn test_syscall_costs_from_json() {
+    let costs: SyscallCosts =
+        serde_json::from_str(r#"{"storage_write": 2000, "call_contract": 70}"#).unwrap();
+    assert_eq!(
+        costs,
+        SyscallCosts { storage_write: 2000, call_contract: 70, ..SyscallCosts::default() }
+    );
+}
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::str::FromStr;
+
+use cairo_felt::Felt252;
+use cairo_lang_casm::cell_expression::{CellExpression, CellOperator};
+use cairo_lang_casm::operand::{CellRef, DerefOrImmediate};
+use cairo_lang_sierra::ids::{ConcreteTypeId, VarId};
+use cairo_lang_sierra::program::{Function, Statement, StatementIdx};
+use cairo_vm::types::relocatable::{MaybeRelocatable, Relocatable};
+use cairo_vm::vm::runners::cairo_runner::CairoRunner;
+use cairo_vm::vm::vm_core::VirtualMachine;
+use num_traits::Zero;
+
+use crate::casm_run::{cell_ref_to_relocatable, CairoHintProcessor};
+use crate::{RunnerError, SierraCasmRunner};
+
+mod repl;
+
+pub use repl::Repl;
+
+/// A point in the program to pause a debugged run at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Breakpoint {
+    /// The start of the function whose full name ends with the given suffix.
+    Function(String),
+    /// The start of a Sierra statement.
+    Statement(StatementIdx),
+}
+impl Breakpoint {
+    /// Returns the Sierra statement the breakpoint pauses at, in the program of the given runner.
+    pub fn statement_idx(&self, runner: &SierraCasmRunner) -> Result<StatementIdx, RunnerError> {
+        match self {
+            Self::Function(suffix) => Ok(runner.find_function(suffix)?.entry_point),
+            Self::Statement(statement_idx) => Ok(*statement_idx),
+        }
+    }
+}
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    /// Parses a breakpoint from a statement index or a function name suffix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("Expected a function name or a statement index.".into());
+        }
+        Ok(match s.parse::<usize>() {
+            Ok(statement_idx) => Self::Statement(StatementIdx(statement_idx)),
+            Err(_) => Self::Function(s.into()),
+        })
+    }
+}
+
+/// How to resume a paused run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Resume {
+    /// Executes the next CASM instruction and pauses.
+    Instruction,
+    /// Runs until the start of the next Sierra statement.
+    Statement,
+    /// Runs until the start of a Sierra statement that is a breakpoint.
+    Continue,
+    /// Stops the run, failing it with [RunnerError::RunAborted].
+    Abort,
+}
+
+/// Controls a debugged run: pauses the run at its breakpoints, and inspects it when paused.
+pub trait Debugger {
+    /// Returns whether a continued run pauses at the start of the given Sierra statement.
+    fn is_breakpoint(&self, statement_idx: StatementIdx) -> bool;
+
+    /// Inspects the paused run, and returns how to resume it.
+    fn pause(&mut self, run: &PausedRun<'_>) -> Resume;
+}
+
+/// A variable available at the start of a Sierra statement, along with the values of its cells.
+#[derive(Debug)]
+pub struct DebugVariable<'a> {
+    pub id: &'a VarId,
+    pub ty: &'a ConcreteTypeId,
+    /// The values of the cells of the variable, `None` for uninitialized cells.
+    pub values: Vec<Option<MaybeRelocatable>>,
+}
+
+/// The state of a paused run.
+pub struct PausedRun<'a> {
+    runner: &'a SierraCasmRunner,
+    vm: &'a VirtualMachine,
+    /// The Sierra statement starting at the current instruction, if any.
+    statement_idx: Option<StatementIdx>,
+    /// The number of steps executed before the pause.
+    steps: usize,
+}
+impl<'a> PausedRun<'a> {
+    /// Returns the runner of the run.
+    pub fn runner(&self) -> &'a SierraCasmRunner {
+        self.runner
+    }
+
+    /// Returns the number of steps executed before the pause.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn pc(&self) -> Relocatable {
+        self.vm.get_pc()
+    }
+
+    pub fn ap(&self) -> Relocatable {
+        self.vm.get_ap()
+    }
+
+    pub fn fp(&self) -> Relocatable {
+        self.vm.get_fp()
+    }
+
+    /// Returns the index of the Sierra statement starting at the current instruction, if any.
+    pub fn statement_idx(&self) -> Option<StatementIdx> {
+        self.statement_idx
+    }
+
+    /// Returns the Sierra statement starting at the current instruction, if any.
+    pub fn statement(&self) -> Option<&'a Statement> {
+        Some(&self.runner.sierra_program.statements[self.statement_idx?.0])
+    }
+
+    /// Returns the Sierra function of the statement starting at the current instruction, if any.
+    pub fn function(&self) -> Option<&'a Function> {
+        let statement_idx = self.statement_idx?;
+        self.runner
+            .sierra_program
+            .funcs
+            .iter()
+            .filter(|function| function.entry_point.0 <= statement_idx.0)
+            .max_by_key(|function| function.entry_point.0)
+    }
+
+    /// Returns the value of the memory cell at the given address, if initialized.
+    pub fn memory(&self, address: Relocatable) -> Option<MaybeRelocatable> {
+        self.vm.get_maybe(&address)
+    }
+
+    /// Returns the variables available at the start of the Sierra statement starting at the
+    /// current instruction, sorted by id. Empty if no statement starts at the current instruction.
+    pub fn variables(&self) -> Vec<DebugVariable<'a>> {
+        let Some(statement_idx) = self.statement_idx else {
+            return vec![];
+        };
+        self.runner.casm_program.debug_info.sierra_statement_info[statement_idx.0]
+            .variables
+            .iter()
+            .map(|variable| DebugVariable {
+                id: &variable.id,
+                ty: &variable.ty,
+                values: variable.expression.cells.iter().map(|cell| self.eval(cell)).collect(),
+            })
+            .collect()
+    }
+
+    /// Evaluates a cell expression at the current registers, if its cells are initialized.
+    fn eval(&self, expression: &CellExpression) -> Option<MaybeRelocatable> {
+        let cell =
+            |cell_ref: &CellRef| self.vm.get_maybe(&cell_ref_to_relocatable(cell_ref, self.vm));
+        match expression {
+            CellExpression::Deref(cell_ref) => cell(cell_ref),
+            CellExpression::DoubleDeref(cell_ref, offset) => {
+                let base = cell(cell_ref)?.get_relocatable()?;
+                self.vm.get_maybe(&(base + *offset as i32).ok()?)
+            }
+            CellExpression::Immediate(value) => Some(Felt252::from(value.clone()).into()),
+            CellExpression::BinOp { op, a, b } => {
+                let a = cell(a)?;
+                let b = match b {
+                    DerefOrImmediate::Deref(cell_ref) => cell(cell_ref)?,
+                    DerefOrImmediate::Immediate(value) => Felt252::from(value.value.clone()).into(),
+                };
+                match op {
+                    CellOperator::Add => a.add(&b).ok(),
+                    CellOperator::Sub => a.sub(&b).ok(),
+                    CellOperator::Mul => Some((a.get_int_ref()? * b.get_int_ref()?).into()),
+                    CellOperator::Div => {
+                        let divisor = b.get_int_ref().filter(|divisor| !divisor.is_zero())?;
+                        Some((a.get_int_ref()? / divisor).into())
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The debugging of a run: the runner of the run, the debugger controlling it, and the size of
+/// the entry code preceding the program.
+pub struct DebugContext<'a> {
+    pub(crate) runner: &'a SierraCasmRunner,
+    pub(crate) debugger: &'a mut dyn Debugger,
+    pub(crate) entry_code_size: usize,
+}
+
+/// Runs the vm until it reaches `end`, pausing it at the start of its first Sierra statement and
+/// then as requested by the debugger.
+pub(crate) fn run_until_pc(
+    runner: &mut CairoRunner,
+    end: Relocatable,
+    vm: &mut VirtualMachine,
+    hint_processor: &mut CairoHintProcessor<'_>,
+    context: DebugContext<'_>,
+) -> Result<(), RunnerError> {
+    let references = runner.get_reference_list();
+    let hint_data =
+        runner.get_hint_data_dictionary(&references, hint_processor).map_err(Box::new)?;
+    let constants = HashMap::new();
+    let mut resume = Resume::Statement;
+    let mut steps = 0;
+    while vm.get_pc() != end {
+        let statements = statements_at(context.runner, vm.get_pc(), context.entry_code_size);
+        let pause = match resume {
+            Resume::Instruction => true,
+            Resume::Statement => !statements.is_empty(),
+            Resume::Continue => statements
+                .clone()
+                .any(|statement_idx| context.debugger.is_breakpoint(StatementIdx(statement_idx))),
+            Resume::Abort => unreachable!("Aborted runs are not resumed."),
+        };
+        if pause {
+            let run = PausedRun {
+                runner: context.runner,
+                vm,
+                statement_idx: (!statements.is_empty()).then(|| StatementIdx(statements.end - 1)),
+                steps,
+            };
+            resume = context.debugger.pause(&run);
+            if resume == Resume::Abort {
+                return Err(RunnerError::RunAborted);
+            }
+        }
+        vm.step(hint_processor, &mut runner.exec_scopes, &hint_data, &constants)
+            .map_err(Box::new)?;
+        steps += 1;
+    }
+    Ok(())
+}
+
+/// Returns the range of the Sierra statements starting at the instruction at `pc`, given the size
+/// of the entry code preceding the program. Statements that compile to no instructions start along
+/// with the statement that follows them, which is the last in the range.
+fn statements_at(
+    runner: &SierraCasmRunner,
+    pc: Relocatable,
+    entry_code_size: usize,
+) -> Range<usize> {
+    // The last entry holds the end of the program, rather than the start of a statement.
+    let statements_info = &runner.casm_program.debug_info.sierra_statement_info;
+    let statements_info = &statements_info[..statements_info.len().saturating_sub(1)];
+    let Some(offset) = pc.offset.checked_sub(entry_code_size) else {
+        return 0..0;
+    };
+    statements_info.partition_point(|info| info.code_offset < offset)
+        ..statements_info.partition_point(|info| info.code_offset <= offset)
+}
@@ -0,0 +1,233 @@
+use std::collections::HashSet;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use cairo_lang_sierra::program::StatementIdx;
+use cairo_vm::types::relocatable::Relocatable;
+use itertools::Itertools;
+
+use super::{Breakpoint, Debugger, PausedRun, Resume};
+
+#[cfg(test)]
+#[path = "repl_test.rs"]
+mod test;
+
+/// The prompt printed before reading a command.
+const PROMPT: &str = "(cairo-debug) ";
+
+/// The help text describing the commands.
+const HELP: &str = "\
+commands:
+  break <function | statement>  pause at the start of a function, by name suffix, or of a Sierra
+                                statement, by index (b)
+  delete <statement>            remove the breakpoint at a Sierra statement (d)
+  breakpoints                   list the breakpoints (bl)
+  continue                      run until a breakpoint (c)
+  step                          run until the next Sierra statement (s)
+  stepi                         execute the next CASM instruction (si)
+  where                         show the current statement and function (w)
+  registers                     show the registers and the number of executed steps (r)
+  memory <segment>:<offset> [n] show n memory cells, 1 by default (m)
+  variables                     show the Sierra variables of the current statement (v)
+  quit                          abort the run (q)
+  help                          show this help (h)";
+
+/// A command of the REPL.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Command {
+    Break(Breakpoint),
+    Delete(StatementIdx),
+    Breakpoints,
+    Continue,
+    Step,
+    StepInstruction,
+    Where,
+    Registers,
+    Memory { address: Relocatable, count: usize },
+    Variables,
+    Quit,
+    Help,
+}
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, args) = s.trim().split_once(' ').unwrap_or((s.trim(), ""));
+        let args = args.split_whitespace().collect_vec();
+        let no_args = |command: Command| {
+            if args.is_empty() { Ok(command) } else { Err(format!("`{name}` takes no arguments.")) }
+        };
+        match name {
+            "break" | "b" => match args[..] {
+                [breakpoint] => Ok(Command::Break(breakpoint.parse()?)),
+                _ => Err("Usage: break <function | statement>.".into()),
+            },
+            "delete" | "d" => match args[..] {
+                [statement] => statement
+                    .parse()
+                    .map(|statement_idx| Command::Delete(StatementIdx(statement_idx)))
+                    .map_err(|_| format!("Invalid statement index `{statement}`.")),
+                _ => Err("Usage: delete <statement>.".into()),
+            },
+            "breakpoints" | "bl" => no_args(Command::Breakpoints),
+            "continue" | "c" => no_args(Command::Continue),
+            "step" | "s" => no_args(Command::Step),
+            "stepi" | "si" => no_args(Command::StepInstruction),
+            "where" | "w" => no_args(Command::Where),
+            "registers" | "r" => no_args(Command::Registers),
+            "memory" | "m" => {
+                let (address, count) = match args[..] {
+                    [address] => (address, "1"),
+                    [address, count] => (address, count),
+                    _ => return Err("Usage: memory <segment>:<offset> [n].".into()),
+                };
+                Ok(Command::Memory {
+                    address: parse_address(address)?,
+                    count: count.parse().map_err(|_| format!("Invalid count `{count}`."))?,
+                })
+            }
+            "variables" | "v" => no_args(Command::Variables),
+            "quit" | "q" => no_args(Command::Quit),
+            "help" | "h" => no_args(Command::Help),
+            _ => Err(format!("Unknown command `{name}`, try `help`.")),
+        }
+    }
+}
+
+/// Parses a memory address of the form `<segment>:<offset>`.
+fn parse_address(address: &str) -> Result<Relocatable, String> {
+    let invalid = || format!("Invalid address `{address}`, expected `<segment>:<offset>`.");
+    let (segment, offset) = address.split_once(':').ok_or_else(invalid)?;
+    Ok(Relocatable::from((
+        segment.parse::<isize>().map_err(|_| invalid())?,
+        offset.parse::<usize>().map_err(|_| invalid())?,
+    )))
+}
+
+/// A debugger reading commands from an input, and writing the state of the paused run to an
+/// output. When the input ends, the run continues to its end.
+pub struct Repl<R: BufRead, W: Write> {
+    input: R,
+    output: W,
+    /// The Sierra statements the run pauses at when continued.
+    breakpoints: HashSet<StatementIdx>,
+}
+impl<R: BufRead, W: Write> Repl<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Self { input, output, breakpoints: HashSet::new() }
+    }
+
+    /// Adds a breakpoint at the start of a Sierra statement.
+    pub fn add_breakpoint(&mut self, statement_idx: StatementIdx) {
+        self.breakpoints.insert(statement_idx);
+    }
+
+    /// Reads and executes commands until the run is resumed.
+    fn run_commands(&mut self, run: &PausedRun<'_>) -> io::Result<Resume> {
+        self.write_location(run)?;
+        loop {
+            write!(self.output, "{PROMPT}")?;
+            self.output.flush()?;
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                writeln!(self.output)?;
+                return Ok(Resume::Continue);
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            let command = match line.parse::<Command>() {
+                Ok(command) => command,
+                Err(err) => {
+                    writeln!(self.output, "{err}")?;
+                    continue;
+                }
+            };
+            match command {
+                Command::Break(breakpoint) => match breakpoint.statement_idx(run.runner()) {
+                    Ok(statement_idx) => {
+                        self.add_breakpoint(statement_idx);
+                        writeln!(self.output, "Breakpoint at statement #{statement_idx}.")?;
+                    }
+                    Err(err) => writeln!(self.output, "{err}")?,
+                },
+                Command::Delete(statement_idx) => {
+                    if !self.breakpoints.remove(&statement_idx) {
+                        writeln!(self.output, "No breakpoint at statement #{statement_idx}.")?;
+                    }
+                }
+                Command::Breakpoints => {
+                    for statement_idx in self.breakpoints.iter().sorted_by_key(|idx| idx.0) {
+                        writeln!(self.output, "#{statement_idx}")?;
+                    }
+                }
+                Command::Continue => return Ok(Resume::Continue),
+                Command::Step => return Ok(Resume::Statement),
+                Command::StepInstruction => return Ok(Resume::Instruction),
+                Command::Where => self.write_location(run)?,
+                Command::Registers => writeln!(
+                    self.output,
+                    "pc: {}, ap: {}, fp: {}, steps: {}",
+                    run.pc(),
+                    run.ap(),
+                    run.fp(),
+                    run.steps()
+                )?,
+                Command::Memory { address, count } => {
+                    for offset in 0..count {
+                        let address = (address + offset).unwrap();
+                        match run.memory(address) {
+                            Some(value) => writeln!(self.output, "{address}: {value}")?,
+                            None => writeln!(self.output, "{address}: <uninitialized>")?,
+                        }
+                    }
+                }
+                Command::Variables => {
+                    if run.statement_idx().is_none() {
+                        writeln!(self.output, "Not at the start of a Sierra statement.")?;
+                    }
+                    for variable in run.variables() {
+                        let values = variable
+                            .values
+                            .iter()
+                            .map(|value| match value {
+                                Some(value) => value.to_string(),
+                                None => "<uninitialized>".into(),
+                            })
+                            .join(", ");
+                        writeln!(self.output, "{}: {} = {values}", variable.id, variable.ty)?;
+                    }
+                }
+                Command::Quit => return Ok(Resume::Abort),
+                Command::Help => writeln!(self.output, "{HELP}")?,
+            }
+        }
+    }
+
+    /// Writes the current instruction, and the Sierra statement starting at it and its function.
+    fn write_location(&mut self, run: &PausedRun<'_>) -> io::Result<()> {
+        match (run.statement_idx(), run.statement(), run.function()) {
+            (Some(statement_idx), Some(statement), function) => {
+                let function =
+                    function.map_or_else(|| "<unknown>".into(), |function| function.id.to_string());
+                writeln!(
+                    self.output,
+                    "pc {}: statement #{statement_idx} in {function}: {statement}",
+                    run.pc()
+                )
+            }
+            _ => writeln!(self.output, "pc {}: not at the start of a Sierra statement", run.pc()),
+        }
+    }
+}
+impl<R: BufRead, W: Write> Debugger for Repl<R, W> {
+    fn is_breakpoint(&self, statement_idx: StatementIdx) -> bool {
+        self.breakpoints.contains(&statement_idx)
+    }
+
+    /// Reads and executes commands until the run is resumed, aborting the run if the input or
+    /// output fails.
+    fn pause(&mut self, run: &PausedRun<'_>) -> Resume {
+        self.run_commands(run).unwrap_or(Resume::Abort)
+    }
+}
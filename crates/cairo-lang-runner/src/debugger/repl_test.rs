@@ -0,0 +1,29 @@
+use cairo_lang_sierra::program::StatementIdx;
+use cairo_vm::types::relocatable::Relocatable;
+use test_case::test_case;
+
+use super::Command;
+use crate::Breakpoint;
+
+#[test_case("break fib", Command::Break(Breakpoint::Function("fib".into())); "break_function")]
+#[test_case("b 12", Command::Break(Breakpoint::Statement(StatementIdx(12))); "break_statement")]
+#[test_case("delete 12", Command::Delete(StatementIdx(12)); "delete")]
+#[test_case("  continue ", Command::Continue; "continue_with_spaces")]
+#[test_case("si", Command::StepInstruction; "step_instruction")]
+#[test_case(
+    "memory 1:20 3",
+    Command::Memory { address: Relocatable::from((1, 20)), count: 3 };
+    "memory_range"
+)]
+#[test_case("m 1:20", Command::Memory { address: Relocatable::from((1, 20)), count: 1 }; "memory")]
+fn test_parse_command(line: &str, expected: Command) {
+    assert_eq!(line.parse::<Command>(), Ok(expected));
+}
+
+#[test_case("jump", "Unknown command `jump`, try `help`."; "unknown")]
+#[test_case("break", "Usage: break <function | statement>."; "break_without_breakpoint")]
+#[test_case("continue 3", "`continue` takes no arguments."; "continue_with_arguments")]
+#[test_case("memory 20", "Invalid address `20`, expected `<segment>:<offset>`."; "invalid_address")]
+fn test_parse_command_failure(line: &str, expected_error: &str) {
+    assert_eq!(line.parse::<Command>(), Err(expected_error.into()));
+}
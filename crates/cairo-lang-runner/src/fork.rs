@@ -0,0 +1,297 @@
+#[cfg(test)]
+#[path = "fork_test.rs"]
+mod test;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use cairo_felt::Felt252;
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_sierra::ids::{FunctionId, UserTypeId};
+use cairo_lang_sierra::program::{GenericArg, Program};
+use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
+use cairo_lang_starknet::casm_contract_class::ENTRY_POINT_COST;
+use cairo_lang_starknet::contract::ContractInfo;
+use cairo_lang_starknet::contract_class::{ContractEntryPoint, DeclaredContractClass};
+use cairo_lang_starknet::felt252_serde::sierra_from_felt252s;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::{chain, Itertools};
+use num_bigint::BigUint;
+use num_traits::Num;
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::SierraCasmRunner;
+
+/// The JSON-RPC error code of a request for the state of a contract that is not deployed.
+const CONTRACT_NOT_FOUND: i64 = 20;
+/// The JSON-RPC error code of a request for a class that is not declared.
+const CLASS_HASH_NOT_FOUND: i64 = 28;
+/// The name of the type the entry points of a contract return.
+const ENTRY_POINT_RESULT_TYPE: &str = "core::PanicResult::<(core::array::Span::<core::felt252>,)>";
+
+#[derive(Debug, Error)]
+pub enum ForkError {
+    #[error("Fork request `{method}` failed: {reason}")]
+    Request { method: String, reason: String },
+    #[error("Fork request `{method}` failed with error {code}: {message}")]
+    Rpc { method: String, code: i64, message: String },
+    #[error("Fork request `{method}` returned an invalid result: {result}")]
+    InvalidResult { method: String, result: Value },
+    #[error("Forked class {class_hash} can't be run: {reason}")]
+    UnsupportedClass { class_hash: String, reason: String },
+    #[error("Failed to access the fork cache: {0}")]
+    Cache(#[from] std::io::Error),
+}
+
+/// The configuration of a fork of a live Starknet network.
+#[derive(Clone, Debug)]
+pub struct ForkConfig {
+    /// The URL of the JSON-RPC endpoint of the network.
+    pub url: String,
+    /// The number of the block whose state is forked.
+    pub block_number: u64,
+    /// The directory the responses of the endpoint are cached in, if cached on disk. The
+    /// directory should only be used for caching the responses of a single network.
+    pub cache_dir: Option<PathBuf>,
+}
+
+/// A Starknet state backend that lazily fetches the state of a live network at a pinned block:
+/// the class hashes of the deployed contracts, the declared classes, the storage values and the
+/// nonces.
+///
+/// The responses are cached in memory, and on disk if configured, so a state is fetched at most
+/// once per block. The fetched classes can call other fetched classes, as well as the contracts of
+/// the program of the runner.
+pub struct ForkedState {
+    config: ForkConfig,
+    /// The cached results of the requests, by cache key. `None` for results that were not found.
+    responses: Mutex<HashMap<String, Option<Value>>>,
+    /// The runners of the fetched classes, by class hash.
+    class_runners: Mutex<HashMap<Felt252, Arc<SierraCasmRunner>>>,
+}
+impl ForkedState {
+    pub fn new(config: ForkConfig) -> Self {
+        Self { config, responses: Default::default(), class_runners: Default::default() }
+    }
+
+    /// Returns the number of the forked block.
+    pub fn block_number(&self) -> u64 {
+        self.config.block_number
+    }
+
+    /// Returns the value at the given storage address of a contract, zero if not set.
+    pub fn storage_at(
+        &self,
+        contract_address: &Felt252,
+        key: &Felt252,
+    ) -> Result<Felt252, ForkError> {
+        const METHOD: &str = "starknet_getStorageAt";
+        let result =
+            self.request(METHOD, &[("contract_address", contract_address), ("key", key)])?;
+        Ok(match result {
+            Some(result) => parse_felt(METHOD, &result)?,
+            None => Felt252::from(0),
+        })
+    }
+
+    /// Returns the nonce of a contract, zero if not deployed.
+    pub fn nonce_at(&self, contract_address: &Felt252) -> Result<Felt252, ForkError> {
+        const METHOD: &str = "starknet_getNonce";
+        Ok(match self.request(METHOD, &[("contract_address", contract_address)])? {
+            Some(result) => parse_felt(METHOD, &result)?,
+            None => Felt252::from(0),
+        })
+    }
+
+    /// Returns the class hash of a contract, if deployed.
+    pub fn class_hash_at(&self, contract_address: &Felt252) -> Result<Option<Felt252>, ForkError> {
+        const METHOD: &str = "starknet_getClassHashAt";
+        self.request(METHOD, &[("contract_address", contract_address)])?
+            .map(|result| parse_felt(METHOD, &result))
+            .transpose()
+    }
+
+    /// Returns the class with the given hash, if declared.
+    pub fn class(&self, class_hash: &Felt252) -> Result<Option<DeclaredContractClass>, ForkError> {
+        const METHOD: &str = "starknet_getClass";
+        let Some(result) = self.request(METHOD, &[("class_hash", class_hash)])? else {
+            return Ok(None);
+        };
+        serde_json::from_value(result).map(Some).map_err(|err| ForkError::UnsupportedClass {
+            class_hash: format_felt(class_hash),
+            reason: err.to_string(),
+        })
+    }
+
+    /// Returns the runner of the class with the given hash, if declared. The info of the class is
+    /// the only contract info of the runner.
+    pub(crate) fn class_runner(
+        &self,
+        class_hash: &Felt252,
+    ) -> Result<Option<Arc<SierraCasmRunner>>, ForkError> {
+        if let Some(runner) = self.class_runners.lock().unwrap().get(class_hash) {
+            return Ok(Some(runner.clone()));
+        }
+        let Some(class) = self.class(class_hash)? else {
+            return Ok(None);
+        };
+        let runner = Arc::new(create_class_runner(class_hash, class)?);
+        self.class_runners.lock().unwrap().insert(class_hash.clone(), runner.clone());
+        Ok(Some(runner))
+    }
+
+    /// Sends a request for the state at the forked block, with the given felt252 parameters.
+    /// Returns `None` if the requested contract or class was not found.
+    fn request(
+        &self,
+        method: &str,
+        params: &[(&str, &Felt252)],
+    ) -> Result<Option<Value>, ForkError> {
+        let key = chain!([method.to_string()], params.iter().map(|(_, value)| format_felt(value)))
+            .join("_");
+        if let Some(result) = self.responses.lock().unwrap().get(&key) {
+            return Ok(result.clone());
+        }
+        let cache_path = self.config.cache_dir.as_ref().map(|cache_dir| {
+            cache_dir.join(self.config.block_number.to_string()).join(format!("{key}.json"))
+        });
+        let result = match &cache_path {
+            Some(path) if path.exists() => {
+                let content = std::fs::read_to_string(path)?;
+                serde_json::from_str(&content).map_err(|err| ForkError::Request {
+                    method: method.into(),
+                    reason: format!("invalid cached response {}: {err}", path.display()),
+                })?
+            }
+            _ => {
+                let result = self.send(method, params)?;
+                if let Some(path) = &cache_path {
+                    std::fs::create_dir_all(path.parent().unwrap())?;
+                    std::fs::write(path, serde_json::to_string(&result).unwrap())?;
+                }
+                result
+            }
+        };
+        self.responses.lock().unwrap().insert(key, result.clone());
+        Ok(result)
+    }
+
+    /// Sends a request to the JSON-RPC endpoint.
+    fn send(&self, method: &str, params: &[(&str, &Felt252)]) -> Result<Option<Value>, ForkError> {
+        let mut request_params = serde_json::Map::new();
+        request_params
+            .insert("block_id".into(), json!({ "block_number": self.config.block_number }));
+        for (name, value) in params {
+            request_params.insert(name.to_string(), format_felt(value).into());
+        }
+        let request_error = |reason: String| ForkError::Request { method: method.into(), reason };
+        let mut response: Value = ureq::post(&self.config.url)
+            .send_json(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "method": method,
+                "params": request_params,
+            }))
+            .map_err(|err| request_error(err.to_string()))?
+            .into_json()
+            .map_err(|err| request_error(err.to_string()))?;
+        if let Some(error) = response.get("error") {
+            let code = error["code"].as_i64().unwrap_or_default();
+            if code == CONTRACT_NOT_FOUND || code == CLASS_HASH_NOT_FOUND {
+                return Ok(None);
+            }
+            return Err(ForkError::Rpc {
+                method: method.into(),
+                code,
+                message: error["message"].as_str().unwrap_or_default().into(),
+            });
+        }
+        match response.get_mut("result").map(Value::take) {
+            Some(result) => Ok(Some(result)),
+            None => Err(ForkError::InvalidResult { method: method.into(), result: response }),
+        }
+    }
+}
+
+/// Creates a runner for a class fetched from the fork.
+fn create_class_runner(
+    class_hash: &Felt252,
+    class: DeclaredContractClass,
+) -> Result<SierraCasmRunner, ForkError> {
+    let unsupported = |reason: String| ForkError::UnsupportedClass {
+        class_hash: format_felt(class_hash),
+        reason,
+    };
+    let (_, _, mut program) =
+        sierra_from_felt252s(&class.sierra_program).map_err(|err| unsupported(err.to_string()))?;
+    // The fetched program has no debug names, while the runner identifies the panics of the entry
+    // points by the name of the type they return.
+    let entry_point_result = UserTypeId::from_string(ENTRY_POINT_RESULT_TYPE);
+    for declaration in &mut program.type_declarations {
+        for arg in &mut declaration.long_id.generic_args {
+            match arg {
+                GenericArg::UserType(user_type) if *user_type == entry_point_result => {
+                    *user_type = entry_point_result.clone();
+                }
+                _ => {}
+            }
+        }
+    }
+    let entry_points = &class.entry_points_by_type;
+    let entry_point_functions = |entry_points: &[ContractEntryPoint]| {
+        entry_points
+            .iter()
+            .map(|entry_point| {
+                Ok((
+                    Felt252::from(entry_point.selector.clone()),
+                    entry_point_function(&program, entry_point).ok_or_else(|| {
+                        unsupported(format!("missing function {}", entry_point.function_idx))
+                    })?,
+                ))
+            })
+            .collect::<Result<OrderedHashMap<_, _>, ForkError>>()
+    };
+    let contract_info = ContractInfo {
+        constructor: entry_point_functions(&entry_points.constructor)?
+            .into_iter()
+            .map(|(_, function_id)| function_id)
+            .next(),
+        externals: entry_point_functions(&entry_points.external)?,
+        l1_handlers: entry_point_functions(&entry_points.l1_handler)?,
+    };
+    let function_set_costs = chain!(
+        &contract_info.constructor,
+        contract_info.externals.values(),
+        contract_info.l1_handlers.values()
+    )
+    .map(|function_id| (function_id.clone(), [(CostTokenType::Const, ENTRY_POINT_COST)].into()))
+    .collect();
+    SierraCasmRunner::new(
+        program,
+        Some(MetadataComputationConfig { function_set_costs }),
+        [(class_hash.clone(), contract_info)].into_iter().collect(),
+    )
+    .map_err(|err| unsupported(err.to_string()))
+}
+
+/// Returns the Sierra function of an entry point of a class.
+fn entry_point_function(program: &Program, entry_point: &ContractEntryPoint) -> Option<FunctionId> {
+    Some(program.funcs.get(entry_point.function_idx)?.id.clone())
+}
+
+/// Formats a felt252 as a hex string, as expected by the JSON-RPC endpoints.
+fn format_felt(value: &Felt252) -> String {
+    format!("{:#x}", value.to_biguint())
+}
+
+/// Parses a felt252 from the hex string result of a request.
+fn parse_felt(method: &str, result: &Value) -> Result<Felt252, ForkError> {
+    result
+        .as_str()
+        .and_then(|value| value.strip_prefix("0x"))
+        .and_then(|value| BigUint::from_str_radix(value, 16).ok())
+        .map(Felt252::from)
+        .ok_or_else(|| ForkError::InvalidResult { method: method.into(), result: result.clone() })
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use cairo_felt::Felt252;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::project::setup_in_memory_project;
+use cairo_lang_compiler::CompilerConfig;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
+use cairo_lang_starknet::contract::get_contracts_info;
+use cairo_lang_starknet::contract_class::{compile_contract_in_prepared_db, DeclaredContractClass};
+use cairo_lang_starknet::plugin::StarkNetPlugin;
+
+use super::{ForkConfig, ForkedState};
+use crate::{Arg, RunResultValue, SierraCasmRunner, StarknetState};
+
+#[test]
+fn test_cached_responses() {
+    let cache_dir = std::env::temp_dir().join(format!("cairo-fork-cache-{}", std::process::id()));
+    let block_dir = cache_dir.join("100");
+    std::fs::create_dir_all(&block_dir).unwrap();
+    std::fs::write(block_dir.join("starknet_getStorageAt_0x5_0x7.json"), "\"0x2a\"").unwrap();
+    std::fs::write(block_dir.join("starknet_getNonce_0x5.json"), "\"0x3\"").unwrap();
+    std::fs::write(block_dir.join("starknet_getClassHashAt_0x6.json"), "null").unwrap();
+    // The endpoint is unreachable, so only the cached responses can be used.
+    let fork = ForkedState::new(ForkConfig {
+        url: "http://127.0.0.1:0".into(),
+        block_number: 100,
+        cache_dir: Some(cache_dir.clone()),
+    });
+    let (contract, missing_contract) = (Felt252::from(5), Felt252::from(6));
+    assert_eq!(fork.storage_at(&contract, &Felt252::from(7)).unwrap(), Felt252::from(42));
+    assert_eq!(fork.nonce_at(&contract).unwrap(), Felt252::from(3));
+    assert_eq!(fork.class_hash_at(&missing_contract).unwrap(), None);
+    assert!(fork.storage_at(&contract, &Felt252::from(8)).is_err());
+    std::fs::remove_dir_all(cache_dir).unwrap();
+}
+
+/// A program deploying the `Callee` contract and calling a `Caller` class fetched from the fork,
+/// which calls back into the deployed contract.
+const CALL_BACK_CODE: &str = r#"
+use array::ArrayTrait;
+use option::OptionTrait;
+use traits::TryInto;
+use starknet::ContractAddress;
+use starknet::SyscallResultTrait;
+use starknet::class_hash::Felt252TryIntoClassHash;
+use starknet::contract_address::Felt252TryIntoContractAddress;
+use starknet::syscalls::deploy_syscall;
+
+#[starknet::interface]
+trait ICallee<T> {
+    fn value(self: @T) -> felt252;
+}
+
+#[starknet::interface]
+trait ICaller<T> {
+    fn call_back(self: @T, callee: ContractAddress) -> felt252;
+}
+
+#[contract]
+mod Callee {
+    #[starknet::storage]
+    struct Storage {}
+
+    #[external]
+    fn value(self: @Storage) -> felt252 {
+        42
+    }
+}
+
+#[contract]
+mod Caller {
+    use starknet::ContractAddress;
+    use super::{ICalleeDispatcher, ICalleeDispatcherTrait};
+
+    #[starknet::storage]
+    struct Storage {}
+
+    #[external]
+    fn call_back(self: @Storage, callee: ContractAddress) -> felt252 {
+        ICalleeDispatcher { contract_address: callee }.value()
+    }
+}
+
+fn run(caller: felt252) -> felt252 {
+    let calldata: Array<felt252> = Default::default();
+    let (callee, _) = deploy_syscall(
+        Callee::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap_syscall();
+    ICallerDispatcher { contract_address: caller.try_into().unwrap() }.call_back(callee)
+}
+"#;
+
+#[test]
+fn test_forked_class_calling_back_into_program() {
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let crate_id =
+        setup_in_memory_project(db, "fork_test", [("lib.cairo".into(), CALL_BACK_CODE.into())])
+            .unwrap();
+    let db = &*db;
+
+    // The `Caller` class is only available in the fork, under a class hash of its own.
+    let (caller_address, caller_class_hash) = (Felt252::from(0x100), Felt252::from(0x200));
+    let caller_class = compile_contract_in_prepared_db(
+        db,
+        Some("fork_test::Caller"),
+        vec![crate_id],
+        CompilerConfig::default(),
+    )
+    .unwrap();
+    let cache_dir =
+        std::env::temp_dir().join(format!("cairo-fork-call-back-{}", std::process::id()));
+    let block_dir = cache_dir.join("100");
+    std::fs::create_dir_all(&block_dir).unwrap();
+    std::fs::write(block_dir.join("starknet_getClassHashAt_0x100.json"), "\"0x200\"").unwrap();
+    std::fs::write(
+        block_dir.join("starknet_getClass_0x200.json"),
+        serde_json::to_string(&DeclaredContractClass::from_contract_class(&caller_class).unwrap())
+            .unwrap(),
+    )
+    .unwrap();
+    let fork = ForkedState::new(ForkConfig {
+        url: "http://127.0.0.1:0".into(),
+        block_number: 100,
+        cache_dir: Some(cache_dir.clone()),
+    });
+
+    let sierra_program = db.get_sierra_program(vec![crate_id]).unwrap();
+    let replacer = DebugReplacer { db };
+    let contracts_info = get_contracts_info(db, vec![crate_id], &replacer).unwrap();
+    assert!(!contracts_info.contains_key(&caller_class_hash));
+    let runner = SierraCasmRunner::new(
+        replacer.apply(&sierra_program),
+        Some(Default::default()),
+        contracts_info,
+    )
+    .unwrap();
+    let result = runner
+        .run_function(
+            runner.find_function("::run").unwrap(),
+            &[Arg::Value(caller_address)],
+            Some(u32::MAX as usize),
+            StarknetState::default().with_fork(Arc::new(fork)),
+        )
+        .unwrap();
+    assert_eq!(result.value, RunResultValue::Success(vec![Felt252::from(42)]));
+    std::fs::remove_dir_all(cache_dir).unwrap();
+}
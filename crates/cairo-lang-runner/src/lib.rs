@@ -15,7 +15,7 @@ use cairo_lang_sierra::extensions::range_check::RangeCheckType;
 use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
 use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
 use cairo_lang_sierra::extensions::{ConcreteType, NamedType};
-use cairo_lang_sierra::program::{Function, GenericArg};
+use cairo_lang_sierra::program::{Function, GenericArg, StatementIdx};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
 use cairo_lang_sierra_ap_change::{calc_ap_changes, ApChangeError};
 use cairo_lang_sierra_gas::gas_info::GasInfo;
@@ -28,12 +28,29 @@ use cairo_lang_utils::extract_matches;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_vm::serde::deserialize_program::BuiltinName;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
-pub use casm_run::StarknetState;
+use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
+pub use casm_run::{
+    ContractCallGas, ContractEvent, ContractStorageDump, HintProcessorExtension, StarknetState,
+    StorageEntry, SyscallCosts, SyscallInput, SyscallOutput,
+};
+use debugger::DebugContext;
+pub use debugger::{Breakpoint, DebugVariable, Debugger, PausedRun, Repl, Resume};
+pub use fork::{ForkConfig, ForkError, ForkedState};
 use itertools::chain;
 use num_traits::ToPrimitive;
+pub use panic_data::PanicDataDecoder;
+pub use profiling::ProfilingInfo;
+pub use prover_input::{MemorySegmentAddresses, ProverInput, PublicInput, PublicMemoryEntry};
+pub use resource_limits::{ExhaustedResource, ResourceLimits};
 use thiserror::Error;
 
 pub mod casm_run;
+mod debugger;
+mod fork;
+mod panic_data;
+mod profiling;
+mod prover_input;
+mod resource_limits;
 pub mod short_string;
 
 #[derive(Debug, Error)]
@@ -58,6 +75,10 @@ pub enum RunnerError {
     ApChangeError(#[from] ApChangeError),
     #[error(transparent)]
     VirtualMachineError(#[from] Box<VirtualMachineError>),
+    #[error("Resources exhausted: the run {0}.")]
+    ResourcesExhausted(ExhaustedResource),
+    #[error("The run was aborted by the debugger.")]
+    RunAborted,
 }
 
 /// The full result of a run.
@@ -66,6 +87,14 @@ pub struct RunResult {
     pub memory: Vec<Option<Felt252>>,
     pub value: RunResultValue,
     pub starknet_state: StarknetState,
+    /// The steps and builtin instances used by the run, including those of the contract calls
+    /// made during it.
+    pub used_resources: ExecutionResources,
+    /// The profile of the run, if the runner profiles its runs.
+    pub profiling_info: Option<ProfilingInfo>,
+    /// The trace and public input for proving the run, if the runner exports the traces of its
+    /// runs.
+    pub prover_input: Option<ProverInput>,
 }
 
 /// The ran function return value.
@@ -105,6 +134,12 @@ pub struct SierraCasmRunner {
     #[allow(dead_code)]
     // Mapping from class_hash to contract info.
     starknet_contracts_info: OrderedHashMap<Felt252, ContractInfo>,
+    /// Whether to profile the runs.
+    profiling: bool,
+    /// Whether to export the traces of the runs for proving them.
+    export_trace: bool,
+    /// The limits of the resources of each run.
+    resource_limits: ResourceLimits,
 }
 impl SierraCasmRunner {
     pub fn new(
@@ -129,9 +164,33 @@ impl SierraCasmRunner {
             sierra_program_registry,
             casm_program,
             starknet_contracts_info,
+            profiling: false,
+            export_trace: false,
+            resource_limits: ResourceLimits::default(),
         })
     }
 
+    /// Sets whether to profile the runs, attributing their steps to the functions and libfuncs of
+    /// the program (see [ProfilingInfo]).
+    pub fn with_profiling(mut self, profiling: bool) -> Self {
+        self.profiling = profiling;
+        self
+    }
+
+    /// Sets whether to export the traces of the runs, along with their public input, for proving
+    /// them (see [ProverInput]).
+    pub fn with_trace_export(mut self, export_trace: bool) -> Self {
+        self.export_trace = export_trace;
+        self
+    }
+
+    /// Sets the limits of the resources of each run, including each of the calls to the contracts
+    /// of the program made during the runs (see [ResourceLimits]).
+    pub fn with_resource_limits(mut self, resource_limits: ResourceLimits) -> Self {
+        self.resource_limits = resource_limits;
+        self
+    }
+
     /// Runs the vm starting from a function. Function may have implicits, but no other ref params.
     /// The cost of the function is deducted from available_gas before the execution begins.
     pub fn run_function(
@@ -140,34 +199,111 @@ impl SierraCasmRunner {
         args: &[Arg],
         available_gas: Option<usize>,
         starknet_state: StarknetState,
+    ) -> Result<RunResult, RunnerError> {
+        self.run_function_with_debugger(func, args, available_gas, starknet_state, self, None)
+    }
+
+    /// Runs a function of a contract class called during a run of `program_runner`, like
+    /// [Self::run_function]. The contracts of `program_runner` remain callable from the function,
+    /// e.g. when a class fetched from the fork calls back into a contract of the program.
+    pub(crate) fn run_contract_function(
+        &self,
+        func: &Function,
+        args: &[Arg],
+        available_gas: Option<usize>,
+        starknet_state: StarknetState,
+        program_runner: &SierraCasmRunner,
+    ) -> Result<RunResult, RunnerError> {
+        self.run_function_with_debugger(
+            func,
+            args,
+            available_gas,
+            starknet_state,
+            program_runner,
+            None,
+        )
+    }
+
+    /// Runs the vm starting from a function like [Self::run_function], pausing the run at the start
+    /// of its first Sierra statement and then as requested by the given debugger. The resource
+    /// limits are not enforced, and the contract calls made during the run are not debugged.
+    pub fn debug_function(
+        &self,
+        func: &Function,
+        args: &[Arg],
+        available_gas: Option<usize>,
+        starknet_state: StarknetState,
+        debugger: &mut dyn Debugger,
+    ) -> Result<RunResult, RunnerError> {
+        self.run_function_with_debugger(
+            func,
+            args,
+            available_gas,
+            starknet_state,
+            self,
+            Some(debugger),
+        )
+    }
+
+    /// Runs the vm starting from a function, debugged by the given debugger if any. The contracts
+    /// callable from the function are those of `program_runner`, or of the fork.
+    fn run_function_with_debugger(
+        &self,
+        func: &Function,
+        args: &[Arg],
+        available_gas: Option<usize>,
+        starknet_state: StarknetState,
+        program_runner: &SierraCasmRunner,
+        debugger: Option<&mut dyn Debugger>,
     ) -> Result<RunResult, RunnerError> {
         let initial_gas = self.get_initial_available_gas(func, available_gas)?;
         let (entry_code, builtins) = self.create_entry_code(func, args, initial_gas)?;
         let footer = self.create_code_footer();
-        let (cells, ap, starknet_state) = casm_run::run_function(
-            Some(self),
-            chain!(entry_code.iter(), self.casm_program.instructions.iter(), footer.iter()),
-            builtins,
-            |context| {
-                let vm = context.vm;
-                // Create the builtin cost segment, with dummy values.
-                let builtin_cost_segment = vm.add_memory_segment();
-                for token_type in CostTokenType::iter_precost() {
+        let entry_code_size: usize =
+            entry_code.iter().map(|instruction| instruction.body.op_size()).sum();
+        let (cells, ap, mut starknet_state, used_resources, pc_offsets, prover_input) =
+            casm_run::run_function(
+                Some(program_runner),
+                chain!(entry_code.iter(), self.casm_program.instructions.iter(), footer.iter()),
+                builtins,
+                |context| {
+                    let vm = context.vm;
+                    // Create the builtin cost segment, with dummy values.
+                    let builtin_cost_segment = vm.add_memory_segment();
+                    for token_type in CostTokenType::iter_precost() {
+                        vm.insert_value(
+                            (builtin_cost_segment
+                                + (token_type.offset_in_builtin_costs() as usize))
+                                .unwrap(),
+                            Felt252::from(DUMMY_BUILTIN_GAS_COST),
+                        )
+                        .map_err(|e| Box::new(e.into()))?;
+                    }
+                    // Put a pointer to the builtin cost segment at the end of the program (after
+                    // the additional `ret` statement).
                     vm.insert_value(
-                        (builtin_cost_segment + (token_type.offset_in_builtin_costs() as usize))
-                            .unwrap(),
-                        Felt252::from(DUMMY_BUILTIN_GAS_COST),
+                        (vm.get_pc() + context.data_len).unwrap(),
+                        builtin_cost_segment,
                     )
                     .map_err(|e| Box::new(e.into()))?;
-                }
-                // Put a pointer to the builtin cost segment at the end of the program (after the
-                // additional `ret` statement).
-                vm.insert_value((vm.get_pc() + context.data_len).unwrap(), builtin_cost_segment)
-                    .map_err(|e| Box::new(e.into()))?;
-                Ok(())
-            },
-            starknet_state,
-        )?;
+                    Ok(())
+                },
+                starknet_state,
+                self.export_trace,
+                &self.resource_limits,
+                debugger.map(|debugger| DebugContext { runner: self, debugger, entry_code_size }),
+            )?;
+        let program_offsets =
+            || pc_offsets.iter().filter_map(|offset| offset.checked_sub(entry_code_size));
+        starknet_state.add_sierra_statement_counts(self.sierra_statement_counts(program_offsets()));
+        let profiling_info = self.profiling.then(|| {
+            ProfilingInfo::new(
+                &self.sierra_program,
+                &self.sierra_program_registry,
+                &self.casm_program.debug_info.sierra_statement_info,
+                program_offsets(),
+            )
+        });
         let mut results_data = self.get_results_data(func, &cells, ap)?;
         // Handling implicits.
         let mut gas_counter = None;
@@ -196,7 +332,42 @@ impl SierraCasmRunner {
             let [(ty, values)] = <[_; 1]>::try_from(results_data).ok().unwrap();
             self.handle_main_return_value(ty, values, &cells)?
         };
-        Ok(RunResult { gas_counter, memory: cells, value, starknet_state })
+        Ok(RunResult {
+            gas_counter,
+            memory: cells,
+            value,
+            starknet_state,
+            used_resources,
+            profiling_info,
+            prover_input,
+        })
+    }
+
+    /// Returns the number of executions of each executed Sierra statement, given the offsets of
+    /// the executed instructions in the CASM program.
+    ///
+    /// A statement is executed whenever the instruction at its start is executed. Statements that
+    /// compile to no instructions are executed along with the statement that follows them.
+    fn sierra_statement_counts(
+        &self,
+        offsets: impl Iterator<Item = usize>,
+    ) -> HashMap<StatementIdx, usize> {
+        // The last entry holds the end of the program, rather than the start of a statement.
+        let statements_info = &self.casm_program.debug_info.sierra_statement_info;
+        let statements_info = &statements_info[..statements_info.len().saturating_sub(1)];
+        let mut offset_counts = HashMap::<usize, usize>::new();
+        for offset in offsets {
+            *offset_counts.entry(offset).or_default() += 1;
+        }
+        let mut statement_counts = HashMap::<StatementIdx, usize>::new();
+        for (offset, count) in offset_counts {
+            let start = statements_info.partition_point(|info| info.code_offset < offset);
+            let end = statements_info.partition_point(|info| info.code_offset <= offset);
+            for statement_idx in start..end {
+                *statement_counts.entry(StatementIdx(statement_idx)).or_default() += count;
+            }
+        }
+        statement_counts
     }
 
     /// Handling the main return value to create a `RunResultValue`.
@@ -0,0 +1,94 @@
+#[cfg(test)]
+#[path = "panic_data_test.rs"]
+mod test;
+
+use std::collections::HashMap;
+
+use cairo_felt::Felt252;
+use cairo_lang_starknet::abi::{Contract, Item};
+use cairo_lang_starknet::contract::SelectorHash;
+use num_bigint::BigUint;
+use num_traits::{Num, ToPrimitive};
+
+use crate::short_string::as_cairo_short_string;
+
+/// The felt252 prefixing the panic data holding a serialized byte array, as a hex string.
+const BYTE_ARRAY_MAGIC: &str = "46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3";
+/// The number of bytes in a full word of a serialized byte array.
+const BYTES_IN_WORD: usize = 31;
+
+/// Decodes panic data for display: short strings as ASCII, serialized byte arrays as text and the
+/// selectors of known contract errors as the names of the errors.
+#[derive(Clone, Debug, Default)]
+pub struct PanicDataDecoder {
+    /// The names of the known contract errors, by selector.
+    errors: HashMap<Felt252, String>,
+}
+impl PanicDataDecoder {
+    /// Adds the errors in the errors section of the ABI of a contract, whose selectors are derived
+    /// with the given hash.
+    pub fn add_contract_errors(&mut self, abi: &Contract, selector_hash: SelectorHash) {
+        for item in &abi.items {
+            if let Item::Error(error) = item {
+                let selector = Felt252::from(selector_hash.hash(error.name.as_bytes()));
+                self.errors.insert(selector, error.name.clone());
+            }
+        }
+    }
+
+    /// Formats panic data as a list of its values. A contract error selector at the start of the
+    /// data is followed by the name of the error, a short string value by its ASCII text, and a
+    /// serialized byte array is replaced by its quoted text.
+    pub fn format(&self, values: &[Felt252]) -> String {
+        let mut formatted = "[".to_string();
+        let mut rest = values;
+        if let Some(name) = values.first().and_then(|selector| self.errors.get(selector)) {
+            formatted.push_str(&format!("{} ({name}), ", values[0]));
+            rest = &values[1..];
+        }
+        while let Some(value) = rest.first() {
+            if let Some((text, size)) = decode_byte_array(rest) {
+                formatted.push_str(&format!("{text:?}, "));
+                rest = &rest[size..];
+                continue;
+            }
+            match as_cairo_short_string(value) {
+                Some(as_string) => formatted.push_str(&format!("{value} ('{as_string}'), ")),
+                None => formatted.push_str(&format!("{value}, ")),
+            }
+            rest = &rest[1..];
+        }
+        formatted.push(']');
+        formatted
+    }
+}
+
+/// Decodes a serialized byte array, prefixed by [BYTE_ARRAY_MAGIC], at the start of the given
+/// values. Returns its text and the number of values it spans, if it is a valid UTF-8 byte array.
+fn decode_byte_array(values: &[Felt252]) -> Option<(String, usize)> {
+    let (magic, values) = values.split_first()?;
+    if magic.to_biguint() != BigUint::from_str_radix(BYTE_ARRAY_MAGIC, 16).unwrap() {
+        return None;
+    }
+    let (full_words_count, values) = values.split_first()?;
+    let full_words_count = full_words_count.to_usize()?;
+    let full_words = values.get(..full_words_count)?;
+    let [pending_word, pending_word_len, ..] = values.get(full_words_count..)? else {
+        return None;
+    };
+    let pending_word_len = pending_word_len.to_usize().filter(|len| *len < BYTES_IN_WORD)?;
+    let mut bytes = vec![];
+    for word in full_words {
+        bytes.extend(word_bytes(word, BYTES_IN_WORD)?);
+    }
+    bytes.extend(word_bytes(pending_word, pending_word_len)?);
+    Some((String::from_utf8(bytes).ok()?, full_words_count + 4))
+}
+
+/// Returns the big-endian bytes of a word of a byte array, if it fits in the given length.
+fn word_bytes(word: &Felt252, len: usize) -> Option<Vec<u8>> {
+    let bytes = word.to_biguint().to_bytes_be();
+    let bytes = if bytes == [0] { vec![] } else { bytes };
+    let padding = len.checked_sub(bytes.len())?;
+    Some(std::iter::repeat(0).take(padding).chain(bytes).collect())
+}
@@ -0,0 +1,56 @@
+use cairo_felt::Felt252;
+use cairo_lang_starknet::abi::{Contract, ContractError, Item};
+use cairo_lang_starknet::contract::SelectorHash;
+use num_bigint::BigUint;
+use num_traits::Num;
+
+use super::{PanicDataDecoder, BYTE_ARRAY_MAGIC};
+
+#[test]
+fn test_format_short_strings() {
+    let decoder = PanicDataDecoder::default();
+    assert_eq!(
+        decoder.format(&[Felt252::from(0x616263), Felt252::from(1000)]),
+        "[6382179 ('abc'), 1000, ]"
+    );
+}
+
+#[test]
+fn test_format_contract_errors() {
+    let mut decoder = PanicDataDecoder::default();
+    let abi = Contract {
+        items: vec![Item::Error(ContractError { name: "Unauthorized".into(), inputs: vec![] })],
+    };
+    decoder.add_contract_errors(&abi, SelectorHash::StarknetKeccak);
+    let selector = Felt252::from(SelectorHash::StarknetKeccak.hash(b"Unauthorized"));
+    assert_eq!(
+        decoder.format(&[selector.clone(), Felt252::from(1000)]),
+        format!("[{selector} (Unauthorized), 1000, ]")
+    );
+    // Selectors are only decoded at the start of the data.
+    assert_eq!(
+        decoder.format(&[Felt252::from(1000), selector.clone()]),
+        format!("[1000, {selector}, ]")
+    );
+}
+
+#[test]
+fn test_format_byte_arrays() {
+    let decoder = PanicDataDecoder::default();
+    let magic = Felt252::from(BigUint::from_str_radix(BYTE_ARRAY_MAGIC, 16).unwrap());
+    let full_word = Felt252::from(BigUint::from_bytes_be(b"This is a full word of 31 bytes"));
+    let pending_word = Felt252::from(BigUint::from_bytes_be(b"!\"\n"));
+    assert_eq!(
+        decoder.format(&[
+            magic.clone(),
+            Felt252::from(1),
+            full_word,
+            pending_word,
+            Felt252::from(3),
+            Felt252::from(1000),
+        ]),
+        "[\"This is a full word of 31 bytes!\\\"\\n\", 1000, ]"
+    );
+    // Byte arrays with missing words are not decoded.
+    assert_eq!(decoder.format(&[magic.clone(), Felt252::from(1000)]), format!("[{magic}, 1000, ]"));
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::program::{Program, Statement};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+use cairo_lang_sierra_to_casm::compiler::SierraStatementDebugInfo;
+use itertools::Itertools;
+
+/// The profile of a run, attributing the executed CASM steps to the Sierra functions and libfuncs
+/// that executed them.
+///
+/// Only the steps of the program itself are included - the steps of the code calling the function
+/// and of the contract calls made during the run are not.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct ProfilingInfo {
+    /// The number of steps executed by each call stack. A stack is given as the names of the
+    /// functions in it, from the outermost one, followed by the name of the executing libfunc.
+    pub stack_steps: HashMap<Vec<String>, usize>,
+    /// The number of invocations of each libfunc, by libfunc name.
+    pub libfunc_invocations: HashMap<String, usize>,
+}
+impl ProfilingInfo {
+    /// Profiles a run of the program, given the offsets of the executed instructions in the CASM
+    /// program compiled from it.
+    pub(crate) fn new(
+        program: &Program,
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+        statements_info: &[SierraStatementDebugInfo],
+        offsets: impl Iterator<Item = usize>,
+    ) -> Self {
+        // The last entry holds the end of the program, rather than the start of a statement.
+        let program_end = statements_info.last().map_or(0, |info| info.code_offset);
+        let statements_info = &statements_info[..statements_info.len().saturating_sub(1)];
+        let functions =
+            program.funcs.iter().sorted_by_key(|function| function.entry_point.0).collect_vec();
+        let function_at = |statement_idx: usize| {
+            functions.partition_point(|function| function.entry_point.0 <= statement_idx) - 1
+        };
+        let libfunc_name = |statement_idx: usize| match &program.statements[statement_idx] {
+            Statement::Invocation(invocation) => invocation.libfunc_id.to_string(),
+            Statement::Return(_) => "return".into(),
+        };
+
+        // The steps of each stack of function indices, by the executing statement.
+        let mut stack_steps = HashMap::<(Vec<usize>, usize), usize>::new();
+        let mut invocations = HashMap::<usize, usize>::new();
+        let mut stack = Vec::<usize>::new();
+        let mut current: Option<(usize, usize)> = None;
+        for offset in offsets.filter(|offset| *offset < program_end) {
+            let start = statements_info.partition_point(|info| info.code_offset < offset);
+            let end = statements_info.partition_point(|info| info.code_offset <= offset);
+            // Statements that compile to no instructions are invoked along with the statement that
+            // follows them.
+            for statement_idx in start..end {
+                *invocations.entry(statement_idx).or_default() += 1;
+            }
+            let statement_idx = end - 1;
+            match &mut current {
+                Some((current_idx, steps)) if *current_idx == statement_idx => {
+                    *steps += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            if let Some((previous_idx, steps)) = current.replace((statement_idx, 1)) {
+                *stack_steps.entry((stack.clone(), previous_idx)).or_default() += steps;
+                match &program.statements[previous_idx] {
+                    Statement::Invocation(invocation)
+                        if matches!(
+                            registry.get_libfunc(&invocation.libfunc_id),
+                            Ok(CoreConcreteLibfunc::FunctionCall(_))
+                        ) =>
+                    {
+                        stack.push(function_at(statement_idx));
+                    }
+                    Statement::Return(_) => {
+                        stack.pop();
+                    }
+                    _ => {}
+                }
+            }
+            // Jumps may move between functions without a call, e.g. when the stack is unknown at
+            // the start of the run.
+            match stack.last_mut() {
+                Some(function) => *function = function_at(statement_idx),
+                None => stack.push(function_at(statement_idx)),
+            }
+        }
+        if let Some((previous_idx, steps)) = current {
+            *stack_steps.entry((stack, previous_idx)).or_default() += steps;
+        }
+
+        let mut info = Self::default();
+        for ((stack, statement_idx), steps) in stack_steps {
+            let names = stack
+                .into_iter()
+                .map(|function| functions[function].id.to_string())
+                .chain([libfunc_name(statement_idx)])
+                .collect();
+            *info.stack_steps.entry(names).or_default() += steps;
+        }
+        for (statement_idx, count) in invocations {
+            *info.libfunc_invocations.entry(libfunc_name(statement_idx)).or_default() += count;
+        }
+        info
+    }
+
+    /// Returns the number of steps executed by each function itself, excluding the functions it
+    /// called, sorted from the most steps.
+    pub fn function_steps(&self) -> Vec<(&str, usize)> {
+        let mut function_steps = HashMap::<&str, usize>::new();
+        for (stack, steps) in &self.stack_steps {
+            if let [.., function, _libfunc] = &stack[..] {
+                *function_steps.entry(function.as_str()).or_default() += steps;
+            }
+        }
+        sorted_by_steps(function_steps)
+    }
+
+    /// Returns the number of steps executed by each libfunc, sorted from the most steps.
+    pub fn libfunc_steps(&self) -> Vec<(&str, usize)> {
+        let mut libfunc_steps = HashMap::<&str, usize>::new();
+        for (stack, steps) in &self.stack_steps {
+            if let Some(libfunc) = stack.last() {
+                *libfunc_steps.entry(libfunc.as_str()).or_default() += steps;
+            }
+        }
+        sorted_by_steps(libfunc_steps)
+    }
+
+    /// Returns the steps of the stacks in the folded stacks format consumed by flamegraph tools,
+    /// with a `<stack> <steps>` line per stack, where the frames of the stack are separated by `;`.
+    pub fn folded_stacks(&self) -> String {
+        self.stack_steps
+            .iter()
+            .map(|(stack, steps)| format!("{} {steps}\n", stack.join(";")))
+            .sorted()
+            .collect()
+    }
+}
+
+/// Returns the given steps by name, sorted from the most steps and then by name.
+fn sorted_by_steps(steps: HashMap<&str, usize>) -> Vec<(&str, usize)> {
+    steps
+        .into_iter()
+        .sorted_by(|(a_name, a_steps), (b_name, b_steps)| {
+            b_steps.cmp(a_steps).then(a_name.cmp(b_name))
+        })
+        .collect()
+}
@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use cairo_felt::Felt252;
+use cairo_vm::vm::errors::trace_errors::TraceError;
+use cairo_vm::vm::trace::trace_entry::TraceEntry;
+use cairo_vm::vm::vm_core::VirtualMachine;
+use serde::Serialize;
+
+/// The layout the runs are executed with.
+const LAYOUT: &str = "all_cairo";
+
+/// The execution trace and public input of a run, for proving the run along with its memory,
+/// in the formats consumed by the prover of the Cairo VM.
+///
+/// Only the run itself is included - the contract calls made during the run are executed in
+/// separate runs, and are not part of its trace.
+#[derive(Debug)]
+pub struct ProverInput {
+    /// The relocated registers of each executed step.
+    pub trace: Vec<TraceEntry>,
+    pub public_input: PublicInput,
+}
+impl ProverInput {
+    /// Extracts the trace and public input of a finished run, whose trace and memory were
+    /// relocated. `memory` is the relocated memory of the run, and `rc_limits` are the limits of
+    /// the offsets of its instructions, computed before relocating its trace.
+    pub(crate) fn new(
+        vm: &VirtualMachine,
+        memory: &[Option<Felt252>],
+        rc_limits: Option<(isize, isize)>,
+    ) -> Result<Self, TraceError> {
+        let trace = vm
+            .get_relocated_trace()?
+            .iter()
+            .map(|entry| TraceEntry { pc: entry.pc, ap: entry.ap, fp: entry.fp })
+            .collect::<Vec<_>>();
+        // The segments are relocated one after the other, starting at address 1.
+        let mut segment_addresses = vec![1];
+        while let Some(size) = vm.get_segment_size(segment_addresses.len() - 1) {
+            segment_addresses.push(segment_addresses.last().unwrap() + size);
+        }
+        let segment = |index: usize, stop_ptr: usize| MemorySegmentAddresses {
+            begin_addr: segment_addresses[index],
+            stop_ptr,
+        };
+        let mut memory_segments = BTreeMap::<String, _>::new();
+        memory_segments.insert("program".into(), segment(0, segment_addresses[1]));
+        memory_segments
+            .insert("execution".into(), segment(1, trace.last().map_or(0, |entry| entry.ap)));
+        for builtin in vm.get_builtin_runners() {
+            let index = builtin.base();
+            let used_size = vm.get_segment_used_size(index).unwrap_or_default();
+            memory_segments.insert(
+                builtin.name().into(),
+                segment(index, segment_addresses[index] + used_size),
+            );
+        }
+        // The program is the public memory of the run.
+        let public_memory = (segment_addresses[0]..segment_addresses[1])
+            .filter_map(|address| {
+                let value = memory.get(address)?.as_ref()?;
+                Some(PublicMemoryEntry {
+                    address,
+                    value: format!("{:#x}", value.to_biguint()),
+                    page: 0,
+                })
+            })
+            .collect();
+        let (rc_min, rc_max) = rc_limits.unwrap_or_default();
+        Ok(Self {
+            public_input: PublicInput {
+                layout: LAYOUT.into(),
+                rc_min,
+                rc_max,
+                n_steps: trace.len(),
+                memory_segments,
+                public_memory,
+            },
+            trace,
+        })
+    }
+
+    /// Writes the trace, the given relocated memory of the run and the public input to the given
+    /// paths. The trace and memory files are in the binary formats of the Cairo VM, and the public
+    /// input is JSON.
+    pub fn write_files(
+        &self,
+        memory: &[Option<Felt252>],
+        trace_path: &Path,
+        memory_path: &Path,
+        public_input_path: &Path,
+    ) -> std::io::Result<()> {
+        // Each trace entry is encoded as its `ap`, `fp` and `pc` registers, as 8 little-endian
+        // bytes each.
+        let mut trace_file = BufWriter::new(File::create(trace_path)?);
+        for entry in &self.trace {
+            for register in [entry.ap, entry.fp, entry.pc] {
+                trace_file.write_all(&(register as u64).to_le_bytes())?;
+            }
+        }
+        trace_file.flush()?;
+        // Each memory cell is encoded as its address, as 8 little-endian bytes, followed by its
+        // value, as 32 little-endian bytes.
+        let mut memory_file = BufWriter::new(File::create(memory_path)?);
+        for (address, value) in memory.iter().enumerate() {
+            if let Some(value) = value {
+                memory_file.write_all(&(address as u64).to_le_bytes())?;
+                memory_file.write_all(&value.to_le_bytes())?;
+            }
+        }
+        memory_file.flush()?;
+        std::fs::write(public_input_path, serde_json::to_string_pretty(&self.public_input)?)
+    }
+}
+
+/// The public input of a run, as consumed by the prover.
+#[derive(Debug, Serialize)]
+pub struct PublicInput {
+    pub layout: String,
+    /// The minimal and maximal offsets of the instructions of the run, for the range check of the
+    /// offsets.
+    pub rc_min: isize,
+    pub rc_max: isize,
+    pub n_steps: usize,
+    /// The addresses of the program, execution and builtin segments, by segment name.
+    pub memory_segments: BTreeMap<String, MemorySegmentAddresses>,
+    pub public_memory: Vec<PublicMemoryEntry>,
+}
+
+/// The addresses of a relocated memory segment.
+#[derive(Debug, Serialize)]
+pub struct MemorySegmentAddresses {
+    pub begin_addr: usize,
+    pub stop_ptr: usize,
+}
+
+/// A public memory cell.
+#[derive(Debug, Serialize)]
+pub struct PublicMemoryEntry {
+    pub address: usize,
+    /// The value of the cell, as a hex string.
+    pub value: String,
+    pub page: usize,
+}
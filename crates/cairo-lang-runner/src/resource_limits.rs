@@ -0,0 +1,107 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use cairo_vm::types::relocatable::Relocatable;
+use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
+use cairo_vm::vm::runners::cairo_runner::CairoRunner;
+use cairo_vm::vm::vm_core::VirtualMachine;
+
+use crate::casm_run::CairoHintProcessor;
+use crate::RunnerError;
+
+/// The number of steps executed between the checks of the limits of a run.
+const STEPS_PER_LIMITS_CHECK: usize = 10000;
+
+/// Limits of the resources of a single run. A run exceeding one of them fails with
+/// [RunnerError::ResourcesExhausted].
+///
+/// Each call to a contract of the program made during a run is a separate run, limited separately,
+/// and a contract call exhausting its resources fails the run that made it. The calls to classes
+/// fetched from a fork are not limited.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ResourceLimits {
+    /// The maximal number of steps of the run.
+    pub max_steps: Option<usize>,
+    /// The maximal number of memory cells of the execution segment of the run, which holds its
+    /// stack.
+    pub max_memory: Option<usize>,
+    /// The maximal wall time of the run.
+    pub timeout: Option<Duration>,
+}
+
+/// A resource whose limit was exceeded by a run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExhaustedResource {
+    /// The run exceeded the given number of steps.
+    Steps(usize),
+    /// The run exceeded the given number of memory cells.
+    Memory(usize),
+    /// The run exceeded the given wall time.
+    Time(Duration),
+}
+impl fmt::Display for ExhaustedResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Steps(max_steps) => write!(f, "exceeded the limit of {max_steps} steps"),
+            Self::Memory(max_memory) => {
+                write!(f, "exceeded the limit of {max_memory} memory cells")
+            }
+            Self::Time(timeout) => write!(f, "exceeded the timeout of {timeout:?}"),
+        }
+    }
+}
+
+/// Runs the vm until it reaches `end`, failing once the run exceeds the given limits.
+///
+/// The limits are checked every [STEPS_PER_LIMITS_CHECK] steps, except for the steps limit, which
+/// is exact.
+pub(crate) fn run_until_pc(
+    runner: &mut CairoRunner,
+    end: Relocatable,
+    vm: &mut VirtualMachine,
+    hint_processor: &mut CairoHintProcessor<'_>,
+    limits: &ResourceLimits,
+) -> Result<(), RunnerError> {
+    if *limits == ResourceLimits::default() {
+        return Ok(runner.run_until_pc(end, vm, hint_processor).map_err(Box::new)?);
+    }
+    let deadline = limits.timeout.map(|timeout| Instant::now() + timeout);
+    let mut steps = 0;
+    loop {
+        if vm.get_pc() == end {
+            return Ok(());
+        }
+        if let Some(resource) = exhausted_resource(limits, vm, steps, deadline) {
+            return Err(RunnerError::ResourcesExhausted(resource));
+        }
+        let chunk = limits.max_steps.map_or(STEPS_PER_LIMITS_CHECK, |max_steps| {
+            (max_steps - steps).min(STEPS_PER_LIMITS_CHECK)
+        });
+        match runner.run_for_steps(chunk, vm, hint_processor) {
+            Ok(()) => steps += chunk,
+            // The run reached its end before executing all the steps.
+            Err(VirtualMachineError::EndOfProgram(_)) => return Ok(()),
+            Err(err) => return Err(Box::new(err).into()),
+        }
+    }
+}
+
+/// Returns the resource whose limit the run exceeded after executing the given number of steps,
+/// if any.
+fn exhausted_resource(
+    limits: &ResourceLimits,
+    vm: &VirtualMachine,
+    steps: usize,
+    deadline: Option<Instant>,
+) -> Option<ExhaustedResource> {
+    match (limits.max_steps, limits.max_memory, limits.timeout.zip(deadline)) {
+        (Some(max_steps), _, _) if steps >= max_steps => Some(ExhaustedResource::Steps(max_steps)),
+        (_, Some(max_memory), _) if vm.get_ap().offset > max_memory => {
+            Some(ExhaustedResource::Memory(max_memory))
+        }
+        (_, _, Some((timeout, deadline))) if Instant::now() >= deadline => {
+            Some(ExhaustedResource::Time(timeout))
+        }
+        _ => None,
+    }
+}
@@ -5,11 +5,11 @@ use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
     ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, FunctionTitleId,
     FunctionWithBodyId, GenericParamId, GenericTypeId, ImplAliasId, ImplDefId, ImplFunctionId,
-    LanguageElementId, LookupItemId, ModuleId, ModuleItemId, StructId, TraitFunctionId, TraitId,
-    TypeAliasId, UseId, VariantId,
+    LanguageElementId, LookupItemId, ModuleFileId, ModuleId, ModuleItemId, StructId,
+    TraitFunctionId, TraitId, TypeAliasId, UseId, VariantId,
 };
 use cairo_lang_defs::plugin::MacroPlugin;
-use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
+use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, LintLevel, Maybe};
 use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup};
 use cairo_lang_filesystem::ids::{CrateId, FileId, FileLongId};
 use cairo_lang_parser::db::ParserGroup;
@@ -32,7 +32,7 @@ use crate::plugin::{DynPluginAuxData, SemanticPlugin};
 use crate::resolve::scope::Scope;
 use crate::resolve::{ResolvedConcreteItem, ResolvedGenericItem, ResolverData};
 use crate::{
-    corelib, items, literals, lsp_helpers, semantic, types, FunctionId, Parameter,
+    corelib, items, lints, literals, lsp_helpers, semantic, types, FunctionId, Parameter,
     SemanticDiagnostic, TypeId,
 };
 
@@ -808,6 +808,21 @@ pub trait SemanticGroup:
     /// Aggregates file level semantic diagnostics.
     fn file_semantic_diagnostics(&self, file_id: FileId) -> Maybe<Diagnostics<SemanticDiagnostic>>;
 
+    // Lints.
+    // ======
+    /// Returns the lint warnings of a module. See [lints].
+    #[salsa::invoke(lints::module_lint_diagnostics)]
+    fn module_lint_diagnostics(
+        &self,
+        module_id: ModuleId,
+    ) -> Maybe<Diagnostics<SemanticDiagnostic>>;
+    /// Aggregates file level lint warnings.
+    #[salsa::invoke(lints::file_lint_diagnostics)]
+    fn file_lint_diagnostics(&self, file_id: FileId) -> Maybe<Diagnostics<SemanticDiagnostic>>;
+    /// The levels of the warnings of specific codes set by the project, unless set otherwise.
+    #[salsa::input]
+    fn lint_levels(&self) -> Arc<OrderedHashMap<SmolStr, LintLevel>>;
+
     // Corelib.
     // ========
     #[salsa::invoke(corelib::core_crate)]
@@ -850,6 +865,7 @@ impl<T: Upcast<dyn SemanticGroup + 'static>> Elongate for T {
 pub fn init_semantic_group(db: &mut (dyn SemanticGroup + 'static)) {
     // Initialize inputs.
     db.set_semantic_plugins(Vec::new());
+    db.set_lint_levels(Default::default());
 }
 
 pub trait SemanticGroupEx<'a>: Upcast<dyn SemanticGroup + 'a> {
@@ -953,7 +969,7 @@ fn module_semantic_diagnostics(
 }
 
 /// Transforms diagnostics that originate from plugin generated files. Uses the plugin's diagnostic
-/// mapper.
+/// mapper, or else reports them at the item the file was generated from - see [map_to_origin].
 fn map_diagnostics(
     db: &(dyn SemanticGroup + 'static),
     module_id: ModuleId,
@@ -974,28 +990,9 @@ fn map_diagnostics(
         assert_eq!(diag.stable_location.module_file_id.0, module_id, "Unexpected module id.");
         let file_index = diag.stable_location.module_file_id.1;
         if let Some(file_info) = &generated_file_info[file_index.0] {
-            let opt_diag = file_info
-                .aux_data
-                .0
-                .as_any()
-                .downcast_ref::<DynPluginAuxData>()
-                .and_then(|mapper| mapper.map_diag(db.upcast(), diag));
-            if let Some(plugin_diag) = opt_diag {
-                // We don't have a real location, so we give a dummy location in the correct file.
-                // SemanticDiagnostic struct knowns to give the proper span for
-                // WrappedPluginDiagnostic.
-                let stable_location = StableLocation::new(
-                    file_info.origin,
-                    db.intern_stable_ptr(SyntaxStablePtr::Root),
-                );
-                let kind = SemanticDiagnosticKind::WrappedPluginDiagnostic {
-                    diagnostic: plugin_diag,
-                    original_diag: Box::new(diag.clone()),
-                };
-                diagnostics.add(SemanticDiagnostic::new(stable_location, kind));
-                has_change = true;
-                continue;
-            }
+            diagnostics.add(map_to_origin(db, file_info, diag));
+            has_change = true;
+            continue;
         }
         diagnostics.add(diag.clone());
     }
@@ -1007,6 +1004,62 @@ fn map_diagnostics(
     (has_change, diagnostics.build())
 }
 
+/// Maps a diagnostic in a plugin generated file to the code the file was generated from, using the
+/// plugin's diagnostic mapper. Returns `None` if the plugin does not map the diagnostic.
+pub(crate) fn map_generated_diagnostic(
+    db: &(dyn SemanticGroup + 'static),
+    file_info: &GeneratedFileInfo,
+    diag: &SemanticDiagnostic,
+) -> Option<SemanticDiagnostic> {
+    let plugin_diag = file_info
+        .aux_data
+        .0
+        .as_any()
+        .downcast_ref::<DynPluginAuxData>()
+        .and_then(|mapper| mapper.map_diag(db.upcast(), diag))?;
+    // We don't have a real location, so we give a dummy location in the correct file.
+    // SemanticDiagnostic struct knowns to give the proper span for WrappedPluginDiagnostic.
+    let stable_location =
+        StableLocation::new(file_info.origin, db.intern_stable_ptr(SyntaxStablePtr::Root));
+    let kind = SemanticDiagnosticKind::WrappedPluginDiagnostic {
+        diagnostic: plugin_diag,
+        original_diag: Box::new(diag.clone()),
+    };
+    Some(SemanticDiagnostic::new(stable_location, kind))
+}
+
+/// Maps a diagnostic in a plugin generated file to the code the file was generated from. A
+/// diagnostic the plugin does not map is reported at the item the file was generated from, as a
+/// [SemanticDiagnosticKind::GeneratedCodeDiagnostic], keeping the location it originates in for its
+/// expansion backtrace. As plugins may expand generated code as well, the diagnostic is mapped
+/// again until it is in a file that was not generated.
+fn map_to_origin(
+    db: &(dyn SemanticGroup + 'static),
+    file_info: &GeneratedFileInfo,
+    diag: &SemanticDiagnostic,
+) -> SemanticDiagnostic {
+    let mut file_info = file_info.clone();
+    let mut diag = diag.clone();
+    loop {
+        diag = match map_generated_diagnostic(db, &file_info, &diag) {
+            Some(mapped_diag) => mapped_diag,
+            None => SemanticDiagnostic::new(
+                StableLocation::new(file_info.origin, file_info.origin_item),
+                SemanticDiagnosticKind::GeneratedCodeDiagnostic { original_diag: Box::new(diag) },
+            ),
+        };
+        let ModuleFileId(module_id, file_index) = file_info.origin;
+        let origin_file_info = db
+            .module_generated_file_infos(module_id)
+            .ok()
+            .and_then(|file_infos| file_infos.get(file_index.0).cloned().flatten());
+        match origin_file_info {
+            Some(origin_file_info) => file_info = origin_file_info,
+            None => return diag,
+        }
+    }
+}
+
 fn file_semantic_diagnostics(
     db: &dyn SemanticGroup,
     file_id: FileId,
@@ -8,7 +8,8 @@ use cairo_lang_defs::ids::{
 };
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder,
+    DiagnosticAdded, DiagnosticEntry, DiagnosticFix, DiagnosticLocation, DiagnosticSpan,
+    Diagnostics, DiagnosticsBuilder, ErrorCode, Severity, SpanPosition,
 };
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::TypedSyntaxNode;
@@ -502,6 +503,9 @@ impl DiagnosticEntry for SemanticDiagnostic {
                 // TODO(spapini): Support nested diagnostics.
                 format!("Plugin diagnostic: {}", diagnostic.message)
             }
+            SemanticDiagnosticKind::GeneratedCodeDiagnostic { original_diag } => {
+                original_diag.format(db)
+            }
             SemanticDiagnosticKind::NameDefinedMultipleTimes { name } => {
                 format!("The name `{name}` is defined multiple times.")
             }
@@ -594,6 +598,210 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::UnsupportedImplicitPrecedenceArguments => {
                 "Unsupported `implicit_precedence` arguments.".into()
             }
+            SemanticDiagnosticKind::UnusedVariable { name } => {
+                format!("Unused variable `{name}`. Consider ignoring it by prefixing it with `_`.")
+            }
+            SemanticDiagnosticKind::UnusedImport { name } => format!("Unused import `{name}`."),
+            SemanticDiagnosticKind::NeedlessSpanClone => {
+                "Needless clone of a span. Spans are copyable, so the span can be used directly."
+                    .into()
+            }
+            SemanticDiagnosticKind::DeprecatedFunctionCall { name, note } => {
+                if note.is_empty() {
+                    format!("Call to deprecated function `{name}`.")
+                } else {
+                    format!("Call to deprecated function `{name}`: {note}")
+                }
+            }
+        }
+    }
+
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(ErrorCode::new(match &self.kind {
+            SemanticDiagnosticKind::ModuleFileNotFound { .. } => "E0101",
+            SemanticDiagnosticKind::Unsupported => "E0102",
+            SemanticDiagnosticKind::UnknownLiteral => "E0103",
+            SemanticDiagnosticKind::UnsupportedUnaryOperator { .. } => "E0104",
+            SemanticDiagnosticKind::UnknownBinaryOperator => "E0105",
+            SemanticDiagnosticKind::UnsupportedBinaryOperator { .. } => "E0106",
+            SemanticDiagnosticKind::UnknownTrait => "E0107",
+            SemanticDiagnosticKind::UnknownImpl => "E0108",
+            SemanticDiagnosticKind::UnexpectedElement { .. } => "E0109",
+            SemanticDiagnosticKind::UnknownType => "E0110",
+            SemanticDiagnosticKind::UnknownStruct => "E0111",
+            SemanticDiagnosticKind::UnknownEnum => "E0112",
+            SemanticDiagnosticKind::NoLiteralFunctionFound => "E0113",
+            SemanticDiagnosticKind::LiteralOutOfRange { .. } => "E0114",
+            SemanticDiagnosticKind::NotAVariant => "E0115",
+            SemanticDiagnosticKind::NotAStruct => "E0116",
+            SemanticDiagnosticKind::NotAType => "E0117",
+            SemanticDiagnosticKind::NotATrait => "E0118",
+            SemanticDiagnosticKind::FunctionNotMemberOfTrait { .. } => "E0119",
+            SemanticDiagnosticKind::UnexpectedGenericArgs => "E0120",
+            SemanticDiagnosticKind::UnknownMember => "E0121",
+            SemanticDiagnosticKind::MemberSpecifiedMoreThanOnce => "E0122",
+            SemanticDiagnosticKind::UseCycle => "E0123",
+            SemanticDiagnosticKind::TypeAliasCycle => "E0124",
+            SemanticDiagnosticKind::ImplAliasCycle => "E0125",
+            SemanticDiagnosticKind::ExpectedConcreteVariant => "E0126",
+            SemanticDiagnosticKind::MissingMember { .. } => "E0127",
+            SemanticDiagnosticKind::WrongNumberOfParameters { .. } => "E0128",
+            SemanticDiagnosticKind::WrongNumberOfArguments { .. } => "E0129",
+            SemanticDiagnosticKind::WrongNumberOfGenericArguments { .. } => "E0130",
+            SemanticDiagnosticKind::WrongParameterType { .. } => "E0131",
+            SemanticDiagnosticKind::VariantCtorNotImmutable => "E0132",
+            SemanticDiagnosticKind::TraitParamMutable { .. } => "E0133",
+            SemanticDiagnosticKind::TraitFunctionWithBody { .. } => "E0134",
+            SemanticDiagnosticKind::ParameterShouldBeReference { .. } => "E0135",
+            SemanticDiagnosticKind::ParameterShouldNotBeReference { .. } => "E0136",
+            SemanticDiagnosticKind::WrongParameterName { .. } => "E0137",
+            SemanticDiagnosticKind::WrongType { .. } => "E0138",
+            SemanticDiagnosticKind::WrongArgumentType { .. } => "E0139",
+            SemanticDiagnosticKind::WrongReturnType { .. } => "E0140",
+            SemanticDiagnosticKind::WrongReturnTypeForImpl { .. } => "E0141",
+            SemanticDiagnosticKind::NoImplementationOfTrait { .. } => "E0142",
+            SemanticDiagnosticKind::AmbiguousTrait { .. } => "E0143",
+            SemanticDiagnosticKind::MultipleImplementationOfTrait { .. } => "E0144",
+            SemanticDiagnosticKind::VariableNotFound { .. } => "E0145",
+            SemanticDiagnosticKind::StructMemberRedefinition { .. } => "E0146",
+            SemanticDiagnosticKind::EnumVariantRedefinition { .. } => "E0147",
+            SemanticDiagnosticKind::ParamNameRedefinition { .. } => "E0148",
+            SemanticDiagnosticKind::IfConditionNotBool { .. } => "E0149",
+            SemanticDiagnosticKind::IncompatibleMatchArms { .. } => "E0150",
+            SemanticDiagnosticKind::IncompatibleIfBlockTypes { .. } => "E0151",
+            SemanticDiagnosticKind::IncompatibleLoopBreakTypes { .. } => "E0152",
+            SemanticDiagnosticKind::TypeHasNoMembers { .. } => "E0153",
+            SemanticDiagnosticKind::NoSuchMethod { .. } => "E0154",
+            SemanticDiagnosticKind::NoSuchMember { .. } => "E0155",
+            SemanticDiagnosticKind::NoSuchVariant { .. } => "E0156",
+            SemanticDiagnosticKind::IncompatibleErrorPropagateType { .. } => "E0157",
+            SemanticDiagnosticKind::ErrorPropagateOnNonErrorType { .. } => "E0158",
+            SemanticDiagnosticKind::ConstGenericParamSupported => "E0159",
+            SemanticDiagnosticKind::RefArgNotAVariable => "E0160",
+            SemanticDiagnosticKind::RefArgNotMutable => "E0161",
+            SemanticDiagnosticKind::RefArgNotExplicit => "E0162",
+            SemanticDiagnosticKind::ImmutableArgWithModifiers => "E0163",
+            SemanticDiagnosticKind::AssignmentToImmutableVar => "E0164",
+            SemanticDiagnosticKind::InvalidLhsForAssignment => "E0165",
+            SemanticDiagnosticKind::InvalidMemberExpression => "E0166",
+            SemanticDiagnosticKind::InvalidPath => "E0167",
+            SemanticDiagnosticKind::PathNotFound(..) => "E0168",
+            SemanticDiagnosticKind::SuperUsedInRootModule => "E0169",
+            SemanticDiagnosticKind::RedundantModifier { .. } => "E0170",
+            SemanticDiagnosticKind::ReferenceLocalVariable => "E0171",
+            SemanticDiagnosticKind::UnexpectedEnumPattern { .. } => "E0172",
+            SemanticDiagnosticKind::UnexpectedStructPattern { .. } => "E0173",
+            SemanticDiagnosticKind::UnexpectedTuplePattern { .. } => "E0174",
+            SemanticDiagnosticKind::WrongEnum { .. } => "E0175",
+            SemanticDiagnosticKind::InvalidCopyTraitImpl { .. } => "E0176",
+            SemanticDiagnosticKind::InvalidDropTraitImpl { .. } => "E0177",
+            SemanticDiagnosticKind::InvalidImplItem { .. } => "E0178",
+            SemanticDiagnosticKind::MissingItemsInImpl { .. } => "E0179",
+            SemanticDiagnosticKind::PassPanicAsNopanic { .. } => "E0180",
+            SemanticDiagnosticKind::PanicableFromNonPanicable => "E0181",
+            SemanticDiagnosticKind::PanicableExternFunction => "E0182",
+            SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => return diagnostic.code,
+            SemanticDiagnosticKind::WrappedPluginDiagnostic { original_diag, .. }
+            | SemanticDiagnosticKind::GeneratedCodeDiagnostic { original_diag } => {
+                return original_diag.error_code();
+            }
+            SemanticDiagnosticKind::NameDefinedMultipleTimes { .. } => "E0183",
+            SemanticDiagnosticKind::NamedArgumentsAreNotSupported => "E0184",
+            SemanticDiagnosticKind::UnnamedArgumentFollowsNamed => "E0185",
+            SemanticDiagnosticKind::NamedArgumentMismatch { .. } => "E0186",
+            SemanticDiagnosticKind::UnsupportedOutsideOfFunction { .. } => "E0187",
+            SemanticDiagnosticKind::OnlyLiteralConstants => "E0188",
+            SemanticDiagnosticKind::ExternItemWithImplGenericsNotSupported => "E0189",
+            SemanticDiagnosticKind::MissingSemicolon => "E0190",
+            SemanticDiagnosticKind::TraitMismatch => "E0191",
+            SemanticDiagnosticKind::DesnapNonSnapshot => "E0192",
+            SemanticDiagnosticKind::InternalInferenceError(..) => "E0193",
+            SemanticDiagnosticKind::NoImplementationOfIndexOperator(..) => "E0194",
+            SemanticDiagnosticKind::MultipleImplementationOfIndexOperator(..) => "E0195",
+            SemanticDiagnosticKind::UnsupportedInlineArguments => "E0196",
+            SemanticDiagnosticKind::RedundantInlineAttribute => "E0197",
+            SemanticDiagnosticKind::InlineWithoutArgumentNotSupported => "E0198",
+            SemanticDiagnosticKind::InlineAttrForExternFunctionNotAllowed => "E0199",
+            SemanticDiagnosticKind::InlineAlwaysWithImplGenericArgNotAllowed => "E0200",
+            SemanticDiagnosticKind::TailExpressionNotAllowedInLoop => "E0201",
+            SemanticDiagnosticKind::ContinueOnlyAllowedInsideALoop => "E0202",
+            SemanticDiagnosticKind::BreakOnlyAllowedInsideALoop => "E0203",
+            SemanticDiagnosticKind::ReturnNotAllowedInsideALoop => "E0204",
+            SemanticDiagnosticKind::ImplicitPrecedenceAttrForExternFunctionNotAllowed => "E0205",
+            SemanticDiagnosticKind::RedundantImplicitPrecedenceAttribute => "E0206",
+            SemanticDiagnosticKind::UnsupportedImplicitPrecedenceArguments => "E0207",
+            SemanticDiagnosticKind::UnusedVariable { .. } => "W0101",
+            SemanticDiagnosticKind::UnusedImport { .. } => "W0102",
+            SemanticDiagnosticKind::NeedlessSpanClone => "W0103",
+            SemanticDiagnosticKind::DeprecatedFunctionCall { .. } => "W0104",
+        }))
+    }
+
+    fn severity(&self) -> Severity {
+        match &self.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => diagnostic.severity,
+            SemanticDiagnosticKind::WrappedPluginDiagnostic { original_diag, .. }
+            | SemanticDiagnosticKind::GeneratedCodeDiagnostic { original_diag } => {
+                original_diag.severity()
+            }
+            SemanticDiagnosticKind::UnusedVariable { .. }
+            | SemanticDiagnosticKind::UnusedImport { .. }
+            | SemanticDiagnosticKind::NeedlessSpanClone
+            | SemanticDiagnosticKind::DeprecatedFunctionCall { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    fn notes(&self, db: &Self::DbType) -> Vec<String> {
+        match &self.kind {
+            SemanticDiagnosticKind::WrappedPluginDiagnostic { original_diag, .. } => {
+                let file_name = original_diag.location(db).file_id.file_name(db.upcast());
+                let message = original_diag.format(db);
+                vec![format!("Mapped from `{message}`, in the plugin generated `{file_name}`.")]
+            }
+            SemanticDiagnosticKind::GeneratedCodeDiagnostic { original_diag } => {
+                let mut notes = vec![];
+                let location = original_diag.location(db);
+                if let Some(span) = DiagnosticSpan::from_location(db.upcast(), location) {
+                    let SpanPosition { line, col } = span.start;
+                    notes.push(format!(
+                        "Originates in the code generated by a plugin, at `{}:{line}:{col}`.",
+                        span.file
+                    ));
+                }
+                notes.extend(original_diag.notes(db));
+                notes
+            }
+            _ => vec![],
+        }
+    }
+
+    fn fixes(&self, db: &Self::DbType) -> Vec<DiagnosticFix> {
+        match &self.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => diagnostic
+                .fixes
+                .iter()
+                .map(|fix| DiagnosticFix {
+                    location: StableLocation::new(
+                        self.stable_location.module_file_id,
+                        fix.stable_ptr,
+                    )
+                    .diagnostic_location(db.upcast()),
+                    replacement: fix.replacement.clone(),
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn expansion(&self, db: &Self::DbType) -> Vec<DiagnosticLocation> {
+        match &self.kind {
+            SemanticDiagnosticKind::GeneratedCodeDiagnostic { original_diag } => {
+                let mut expansion = vec![original_diag.location(db)];
+                expansion.extend(original_diag.expansion(db));
+                expansion
+            }
+            _ => vec![],
         }
     }
 
@@ -843,6 +1051,11 @@ pub enum SemanticDiagnosticKind {
         diagnostic: PluginMappedDiagnostic,
         original_diag: Box<SemanticDiagnostic>,
     },
+    /// A diagnostic in code generated by a plugin that the plugin does not map to the code it was
+    /// given, reported at the item the code was generated from.
+    GeneratedCodeDiagnostic {
+        original_diag: Box<SemanticDiagnostic>,
+    },
     NameDefinedMultipleTimes {
         name: SmolStr,
     },
@@ -875,6 +1088,18 @@ pub enum SemanticDiagnosticKind {
     ImplicitPrecedenceAttrForExternFunctionNotAllowed,
     RedundantImplicitPrecedenceAttribute,
     UnsupportedImplicitPrecedenceArguments,
+    // Lints - see [crate::lints].
+    UnusedVariable {
+        name: SmolStr,
+    },
+    UnusedImport {
+        name: SmolStr,
+    },
+    NeedlessSpanClone,
+    DeprecatedFunctionCall {
+        name: SmolStr,
+        note: String,
+    },
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -5,7 +5,7 @@ use cairo_lang_defs::ids::ModuleId;
 use cairo_lang_defs::plugin::{
     DynGeneratedFileAuxData, GeneratedFileAuxData, MacroPlugin, PluginGeneratedFile, PluginResult,
 };
-use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_diagnostics::{format_expansion, DiagnosticEntry};
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
@@ -246,3 +246,91 @@ fn test_inline_inline_module_diagnostics() {
     "#},
     );
 }
+
+// A dummy plugin that adds a function with a semantic error, whose code is not copied from the
+// original function.
+// Used to test the location of errors in code that a plugin does not map.
+#[derive(Debug)]
+struct AddBadFunctionDummyPlugin;
+
+impl MacroPlugin for AddBadFunctionDummyPlugin {
+    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        match item_ast {
+            ast::Item::FreeFunction(func) if func.has_attr(db, "test_add_bad_function") => {
+                let builder = PatchBuilder::new(db);
+                PluginResult {
+                    code: Some(PluginGeneratedFile {
+                        name: "virt3".into(),
+                        content: indoc! {"
+                            fn bad() -> u128 {
+                                return 5_felt252;
+                            }
+                        "}
+                        .into(),
+                        aux_data: DynGeneratedFileAuxData::new(DynPluginAuxData::new(
+                            PatchMapper { patches: builder.patches },
+                        )),
+                    }),
+                    diagnostics: vec![],
+                    remove_original_item: false,
+                }
+            }
+            _ => PluginResult::default(),
+        }
+    }
+}
+impl AsDynMacroPlugin for AddBadFunctionDummyPlugin {
+    fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
+    where
+        Self: 'a,
+    {
+        self
+    }
+}
+impl SemanticPlugin for AddBadFunctionDummyPlugin {}
+
+#[test]
+fn test_generated_code_diagnostics() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    db.set_semantic_plugins(vec![Arc::new(AddBadFunctionDummyPlugin)]);
+    let crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[test_add_bad_function]
+            fn foo() {}
+       "},
+    );
+
+    // The error is reported at the item the code was generated from.
+    let diagnostics = get_crate_semantic_diagnostics(db, crate_id);
+    assert_eq!(
+        diagnostics.format(db),
+        indoc! {r#"
+            error: Unexpected return type. Expected: "core::integer::u128", found: "core::felt252".
+             --> lib.cairo:1:1
+            #[test_add_bad_function]
+            ^**********************^
+
+            "#},
+    );
+    let [diagnostic] = &diagnostics.get_all()[..] else { panic!("Expected a single diagnostic.") };
+    assert_eq!(
+        diagnostic.notes(db),
+        vec!["Originates in the code generated by a plugin, at `virt3:2:12`.".to_string()]
+    );
+    let expansion = diagnostic
+        .expansion(db)
+        .into_iter()
+        .map(|location| format_expansion(&*db, location))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        expansion,
+        vec![indoc! {"
+            note: In the code generated by a plugin:
+             --> virt3:2:12
+                return 5_felt252;
+                       ^*******^
+        "}]
+    );
+}
@@ -0,0 +1,313 @@
+//! Lints: warnings on code that compiles, but is likely a mistake or can be simplified.
+//!
+//! The lints of a module are computed separately from its semantic diagnostics, on the semantic
+//! model of the function bodies and on the syntax of the items of the module:
+//! * `W0101` - a variable that is never read.
+//! * `W0102` - an imported item that is never used.
+//! * `W0103` - a clone of a `Span`, which is copyable.
+//! * `W0104` - a call to a trait function marked `#[deprecated]`.
+
+use cairo_lang_defs::ids::{
+    FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId, TopLevelLanguageElementId,
+    TraitId,
+};
+use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_syntax::attribute::structured::AttributeArgVariant;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+use smol_str::SmolStr;
+
+use crate::db::{map_generated_diagnostic, SemanticGroup};
+use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
+use crate::items::function_with_body::FunctionBody;
+use crate::items::functions::{GenericFunctionId, ImplGenericFunctionId};
+use crate::items::us::SemanticUseEx;
+use crate::resolve::ResolvedGenericItem;
+use crate::{
+    ConcreteTypeId, Expr, ExprFunctionCall, ExprFunctionCallArg, SemanticDiagnostic, Statement,
+    TypeId, TypeLongId, VarId,
+};
+
+/// Query implementation of [crate::db::SemanticGroup::module_lint_diagnostics].
+pub fn module_lint_diagnostics(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+) -> Maybe<Diagnostics<SemanticDiagnostic>> {
+    let mut diagnostics = DiagnosticsBuilder::default();
+    // The corelib is not linted, as its warnings cannot be addressed by its users.
+    if module_id.owning_crate(db.upcast()) == db.core_crate() {
+        return Ok(diagnostics.build());
+    }
+
+    let mut used_traits = UnorderedHashSet::default();
+    for function_id in module_functions(db, module_id)? {
+        let Ok(body) = db.function_body(function_id) else { continue };
+        let mut function_diagnostics =
+            SemanticDiagnostics::new(function_id.module_file_id(db.upcast()));
+        lint_unused_variables(&body, &mut function_diagnostics);
+        for (_, expr) in body.exprs.iter() {
+            let Expr::FunctionCall(expr) = expr else { continue };
+            if let GenericFunctionId::Impl(ImplGenericFunctionId { function, .. }) =
+                expr.function.get_concrete(db).generic_function
+            {
+                used_traits.insert(function.trait_id(db.upcast()));
+            }
+            lint_needless_span_clone(db, expr, &mut function_diagnostics);
+            lint_deprecated_call(db, expr, &mut function_diagnostics);
+        }
+        diagnostics.extend(function_diagnostics.build());
+    }
+    lint_unused_imports(db, module_id, &used_traits, &mut diagnostics)?;
+
+    // Warnings in code generated by plugins are only reported if they can be mapped to the code
+    // the plugin was given.
+    let generated_file_infos = db.module_generated_file_infos(module_id)?;
+    let mut mapped_diagnostics = DiagnosticsBuilder::default();
+    for diag in diagnostics.build().get_all() {
+        let file_index = diag.stable_location.module_file_id.1;
+        match &generated_file_infos[file_index.0] {
+            Some(file_info) => {
+                if let Some(mapped_diag) = map_generated_diagnostic(db.elongate(), file_info, &diag)
+                {
+                    mapped_diagnostics.add(mapped_diag);
+                }
+            }
+            None => {
+                mapped_diagnostics.add(diag);
+            }
+        }
+    }
+    Ok(mapped_diagnostics.build())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::file_lint_diagnostics].
+pub fn file_lint_diagnostics(
+    db: &dyn SemanticGroup,
+    file_id: FileId,
+) -> Maybe<Diagnostics<SemanticDiagnostic>> {
+    let mut diagnostics = DiagnosticsBuilder::default();
+    for module_id in db.file_modules(file_id)? {
+        if let Ok(module_diagnostics) = db.module_lint_diagnostics(module_id) {
+            diagnostics.extend(module_diagnostics)
+        }
+    }
+    Ok(diagnostics.build())
+}
+
+/// Returns the functions with a body defined in a module, including the functions of its impls.
+fn module_functions(db: &dyn SemanticGroup, module_id: ModuleId) -> Maybe<Vec<FunctionWithBodyId>> {
+    let mut functions = vec![];
+    for item in db.module_items(module_id)?.iter() {
+        match item {
+            ModuleItemId::FreeFunction(free_function_id) => {
+                functions.push(FunctionWithBodyId::Free(*free_function_id));
+            }
+            ModuleItemId::Impl(impl_def_id) => {
+                let Ok(impl_functions) = db.impl_functions(*impl_def_id) else { continue };
+                functions.extend(impl_functions.values().copied().map(FunctionWithBodyId::Impl));
+            }
+            _ => {}
+        }
+    }
+    Ok(functions)
+}
+
+/// Reports the variables defined in a function body that are never read. Variables whose name
+/// starts with `_` are not reported.
+fn lint_unused_variables(body: &FunctionBody, diagnostics: &mut SemanticDiagnostics) {
+    let mut used_vars = UnorderedHashSet::<VarId>::default();
+    let mut defined_vars = vec![];
+    for (_, expr) in body.exprs.iter() {
+        match expr {
+            Expr::Var(expr) => {
+                used_vars.insert(expr.var);
+            }
+            Expr::FunctionCall(expr) => {
+                for arg in &expr.args {
+                    if let ExprFunctionCallArg::Reference(ref_arg) = arg {
+                        used_vars.insert(ref_arg.base_var());
+                    }
+                }
+            }
+            Expr::Match(expr) => {
+                defined_vars.extend(expr.arms.iter().flat_map(|arm| arm.pattern.variables()));
+            }
+            _ => {}
+        }
+    }
+    for (_, statement) in body.statements.iter() {
+        if let Statement::Let(statement) = statement {
+            defined_vars.extend(statement.pattern.variables());
+        }
+    }
+    for var in defined_vars {
+        if var.name.starts_with('_') || used_vars.contains(&VarId::Local(var.var.id)) {
+            continue;
+        }
+        diagnostics.report_by_ptr(
+            var.stable_ptr.untyped(),
+            SemanticDiagnosticKind::UnusedVariable { name: var.name.clone() },
+        );
+    }
+}
+
+/// Reports a call to `Clone::clone` on a `Span`.
+fn lint_needless_span_clone(
+    db: &dyn SemanticGroup,
+    expr: &ExprFunctionCall,
+    diagnostics: &mut SemanticDiagnostics,
+) {
+    let GenericFunctionId::Impl(ImplGenericFunctionId { function, .. }) =
+        expr.function.get_concrete(db).generic_function
+    else {
+        return;
+    };
+    if function.name(db.upcast()) == "clone"
+        && function.trait_id(db.upcast()).full_path(db.upcast()) == "core::clone::Clone"
+        && is_span(db, expr.ty)
+    {
+        diagnostics
+            .report_by_ptr(expr.stable_ptr.untyped(), SemanticDiagnosticKind::NeedlessSpanClone);
+    }
+}
+
+/// Reports a call to a trait function marked `#[deprecated]`, such as a dispatcher method of a
+/// deprecated entry point.
+fn lint_deprecated_call(
+    db: &dyn SemanticGroup,
+    expr: &ExprFunctionCall,
+    diagnostics: &mut SemanticDiagnostics,
+) {
+    let GenericFunctionId::Impl(ImplGenericFunctionId { function, .. }) =
+        expr.function.get_concrete(db).generic_function
+    else {
+        return;
+    };
+    let Ok(attributes) = db.trait_function_attributes(function) else { return };
+    let Some(attr) = attributes.iter().find(|attr| attr.id == "deprecated") else { return };
+    let note = attr.args.iter().find_map(|arg| match &arg.variant {
+        AttributeArgVariant::Named { name, value: ast::Expr::ShortString(note), .. }
+            if name.as_str() == "note" =>
+        {
+            note.string_value(db.upcast())
+        }
+        _ => None,
+    });
+    diagnostics.report_by_ptr(
+        expr.stable_ptr.untyped(),
+        SemanticDiagnosticKind::DeprecatedFunctionCall {
+            name: function.name(db.upcast()),
+            note: note.unwrap_or_default(),
+        },
+    );
+}
+
+/// Returns whether the given type is a concrete `core::array::Span`.
+fn is_span(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+            concrete_struct_id.struct_id(db).full_path(db.upcast()) == "core::array::Span"
+        }
+        _ => false,
+    }
+}
+
+/// Reports the uses of a module whose name is not referred to by any other item of the module.
+///
+/// An imported trait is also used if a function of the trait is called in the module, as methods
+/// are called without naming their trait. Imported impls are never reported.
+fn lint_unused_imports(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    used_traits: &UnorderedHashSet<TraitId>,
+    diagnostics: &mut DiagnosticsBuilder<SemanticDiagnostic>,
+) -> Maybe<()> {
+    let mut used_names = UnorderedHashSet::default();
+    collect_used_names(db, module_id, &mut used_names)?;
+    for item in db.module_items(module_id)?.iter() {
+        let ModuleItemId::Use(use_id) = item else { continue };
+        let name = use_id.name(db.upcast());
+        if used_names.contains(&name) {
+            continue;
+        }
+        match db.use_resolved_item(*use_id) {
+            Ok(ResolvedGenericItem::Trait(trait_id)) if used_traits.contains(&trait_id) => continue,
+            // Imported impls are used implicitly, by inferring the impls of the calls in the
+            // module, so they are never reported.
+            Ok(ResolvedGenericItem::Impl(_)) => continue,
+            _ => {}
+        }
+        let mut use_diagnostics = SemanticDiagnostics::new(use_id.module_file_id(db.upcast()));
+        use_diagnostics.report_by_ptr(
+            use_id.untyped_stable_ptr(db.upcast()),
+            SemanticDiagnosticKind::UnusedImport { name },
+        );
+        diagnostics.extend(use_diagnostics.build());
+    }
+    Ok(())
+}
+
+/// Collects the identifiers referred to by the items of a module and of its submodules, other
+/// than the names the uses of the module import.
+fn collect_used_names(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    used_names: &mut UnorderedHashSet<SmolStr>,
+) -> Maybe<()> {
+    let syntax_db = db.upcast();
+    for item in db.module_items(module_id)?.iter() {
+        let file_syntax = db.file_syntax(db.module_file(item.module_file_id(db.upcast()))?)?;
+        let node = file_syntax
+            .as_syntax_node()
+            .lookup_ptr(syntax_db, item.untyped_stable_ptr(db.upcast()));
+        match item {
+            // The segments of the path of a use, other than the imported name, refer to other
+            // items.
+            ModuleItemId::Use(_) => collect_use_path_prefix(syntax_db, &node, used_names),
+            ModuleItemId::Submodule(submodule_id) => {
+                collect_used_names(db, ModuleId::Submodule(*submodule_id), used_names)?;
+            }
+            _ => collect_identifiers(syntax_db, &node, used_names),
+        }
+    }
+    Ok(())
+}
+
+/// Collects the identifiers of the path segments preceding the leaf of a use path.
+fn collect_use_path_prefix(
+    db: &dyn SyntaxGroup,
+    leaf: &SyntaxNode,
+    used_names: &mut UnorderedHashSet<SmolStr>,
+) {
+    let mut current = leaf.parent();
+    while let Some(node) = current {
+        match node.kind(db) {
+            SyntaxKind::UsePathSingle => {
+                let ident = ast::UsePathSingle::from_syntax_node(db, node.clone()).ident(db);
+                used_names.insert(ident.identifier(db));
+            }
+            SyntaxKind::ItemUse => break,
+            _ => {}
+        }
+        current = node.parent();
+    }
+}
+
+/// Collects the identifiers in a syntax node.
+fn collect_identifiers(
+    db: &dyn SyntaxGroup,
+    node: &SyntaxNode,
+    used_names: &mut UnorderedHashSet<SmolStr>,
+) {
+    for descendant in node.descendants(db) {
+        if descendant.kind(db) == SyntaxKind::TokenIdentifier {
+            if let Some(text) = descendant.text(db) {
+                used_names.insert(text);
+            }
+        }
+    }
+}
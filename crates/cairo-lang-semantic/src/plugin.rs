@@ -15,6 +15,19 @@ pub trait AsDynMacroPlugin {
         Self: 'a;
 }
 
+// `SemanticPlugin` wrapper for a `MacroPlugin`, for plugins that only generate code.
+#[derive(Clone, Debug)]
+pub struct DynMacroPlugin(pub Arc<dyn MacroPlugin>);
+impl AsDynMacroPlugin for DynMacroPlugin {
+    fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
+    where
+        Self: 'a,
+    {
+        self.0.clone()
+    }
+}
+impl SemanticPlugin for DynMacroPlugin {}
+
 /// A trait for Plugins auxiliary data.
 ///
 /// The auxiliary data can assist in mapping plugin generated diagnostics to more readable
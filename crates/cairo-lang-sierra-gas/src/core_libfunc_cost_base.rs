@@ -18,6 +18,7 @@ use cairo_lang_sierra::extensions::gas::GasConcreteLibfunc::{
     BuiltinWithdrawGas, GetAvailableGas, GetBuiltinCosts, RedepositGas, WithdrawGas,
 };
 use cairo_lang_sierra::extensions::gas::{BuiltinCostWithdrawGasLibfunc, CostTokenType};
+use cairo_lang_sierra::extensions::int::signed::{SintConcrete, SintTraits};
 use cairo_lang_sierra::extensions::int::unsigned::{UintConcrete, UintMulTraits};
 use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
 use cairo_lang_sierra::extensions::int::unsigned256::Uint256Concrete;
@@ -233,6 +234,11 @@ pub fn core_libfunc_cost(
         Uint128(libfunc) => u128_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
         Uint256(libfunc) => u256_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
         Uint512(libfunc) => u512_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint8(libfunc) => sint_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint16(libfunc) => sint_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint32(libfunc) => sint_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint64(libfunc) => sint_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint128(libfunc) => sint_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
         Felt252(libfunc) => {
             felt252_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect()
         }
@@ -490,6 +496,23 @@ fn uint_libfunc_cost<TUintTraits: IsZeroTraits + UintMulTraits>(
     }
 }
 
+/// Returns costs for i8/i16/i32/i64/i128 libfuncs.
+fn sint_libfunc_cost<TSintTraits: SintTraits>(
+    libfunc: &SintConcrete<TSintTraits>,
+) -> Vec<ConstCost> {
+    let steps = |value| ConstCost { steps: value, ..Default::default() };
+    match libfunc {
+        SintConcrete::Const(_) | SintConcrete::ToFelt252(_) => vec![steps(0)],
+        SintConcrete::Equal(_) => vec![steps(2), steps(3)],
+        SintConcrete::FromFelt252(_) => {
+            vec![
+                ConstCost { steps: 5, holes: 0, range_checks: 2 },
+                ConstCost { steps: 11, holes: 0, range_checks: 3 },
+            ]
+        }
+    }
+}
+
 /// Returns costs for u128 libfuncs.
 fn u128_libfunc_cost(libfunc: &Uint128Concrete) -> Vec<BranchCost> {
     let steps = |value| ConstCost { steps: value, ..Default::default() };
@@ -554,6 +577,7 @@ fn u256_libfunc_cost(libfunc: &Uint256Concrete) -> Vec<ConstCost> {
 fn u512_libfunc_cost(libfunc: &Uint512Concrete) -> Vec<ConstCost> {
     match libfunc {
         Uint512Concrete::DivModU256(_) => vec![ConstCost { steps: 52, holes: 0, range_checks: 14 }],
+        Uint512Concrete::WideMulU256(_) => vec![ConstCost { steps: 19, holes: 0, range_checks: 6 }],
     }
 }
 
@@ -8,7 +8,9 @@ use crate::core_libfunc_cost_base::{
     core_libfunc_postcost, core_libfunc_precost, CostOperations, InvocationCostInfoProvider,
 };
 use crate::cost_expr::{CostExpr, Var};
+use crate::cost_table::CostTable;
 use crate::generate_equations::StatementFutureCost;
+use crate::objects::ConstCost;
 
 pub type CostExprMap = OrderedHashMap<CostTokenType, CostExpr>;
 
@@ -16,10 +18,16 @@ pub type CostExprMap = OrderedHashMap<CostTokenType, CostExpr>;
 struct Ops<'a> {
     statement_future_cost: &'a mut dyn StatementFutureCost,
     idx: StatementIdx,
+    /// The prices of the constant costs.
+    cost_table: &'a CostTable,
 }
 impl CostOperations for Ops<'_> {
     type CostType = CostExprMap;
 
+    fn const_cost(&self, value: ConstCost) -> Self::CostType {
+        self.cost_token(self.cost_table.const_cost(&value), CostTokenType::Const)
+    }
+
     fn cost_token(&self, value: i32, token_type: CostTokenType) -> Self::CostType {
         Self::CostType::from_iter([(token_type, CostExpr::from_const(value))])
     }
@@ -57,15 +65,24 @@ pub fn core_libfunc_precost_expr(
     idx: &StatementIdx,
     libfunc: &CoreConcreteLibfunc,
 ) -> Vec<CostExprMap> {
-    core_libfunc_precost(&mut Ops { statement_future_cost, idx: *idx }, libfunc)
+    core_libfunc_precost(
+        &mut Ops { statement_future_cost, idx: *idx, cost_table: &CostTable::default() },
+        libfunc,
+    )
 }
 
-/// Returns an expression for the gas cost for core libfuncs.
+/// Returns an expression for the gas cost for core libfuncs, with the constant costs priced by the
+/// given cost table.
 pub fn core_libfunc_postcost_expr<InfoProvider: InvocationCostInfoProvider>(
     statement_future_cost: &mut dyn StatementFutureCost,
     idx: &StatementIdx,
     libfunc: &CoreConcreteLibfunc,
     info_provider: &InfoProvider,
+    cost_table: &CostTable,
 ) -> Vec<CostExprMap> {
-    core_libfunc_postcost(&mut Ops { statement_future_cost, idx: *idx }, libfunc, info_provider)
+    core_libfunc_postcost(
+        &mut Ops { statement_future_cost, idx: *idx, cost_table },
+        libfunc,
+        info_provider,
+    )
 }
@@ -0,0 +1,90 @@
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::objects::ConstCost;
+
+#[cfg(test)]
+#[path = "cost_table_test.rs"]
+mod test;
+
+/// The price of a single usage of a builtin, for builtins missing from a [CostTable].
+pub const DEFAULT_BUILTIN_COST: i32 = 10000;
+
+/// Error loading a [CostTable].
+#[derive(Error, Debug)]
+pub enum CostTableError {
+    #[error("failed parsing the cost table")]
+    Json(#[from] serde_json::Error),
+    #[error("unknown builtin `{0}` in the cost table")]
+    UnknownBuiltin(String),
+}
+
+/// The prices used for computing the gas costs of a program.
+///
+/// The default table holds the prices used by the compiler. Other tables are meant for evaluating
+/// alternative pricing - the compiled code always uses the default prices.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CostTable {
+    /// The price of a single step.
+    pub step_cost: i32,
+    /// The price of a single memory hole.
+    pub hole_cost: i32,
+    /// The price of a single usage of the range check builtin.
+    pub range_check_cost: i32,
+    /// The price of a single usage of each builtin, by the name of its token type (e.g.
+    /// `pedersen`). Missing builtins are priced at [DEFAULT_BUILTIN_COST].
+    pub builtin_costs: OrderedHashMap<String, i32>,
+    /// The constant costs of libfuncs, per branch, by the id of their generic libfunc (e.g.
+    /// `felt252_is_zero`), replacing the costs the libfuncs are known to have.
+    pub libfunc_costs: OrderedHashMap<String, Vec<ConstCost>>,
+}
+impl Default for CostTable {
+    fn default() -> Self {
+        Self {
+            step_cost: 100,
+            hole_cost: 10,
+            range_check_cost: 70,
+            builtin_costs: Default::default(),
+            libfunc_costs: Default::default(),
+        }
+    }
+}
+impl CostTable {
+    /// Parses a cost table from JSON. Missing fields are set to their default value.
+    pub fn from_json(json: &str) -> Result<Self, CostTableError> {
+        let table: Self = serde_json::from_str(json)?;
+        if let Some(name) = table.builtin_costs.keys().find(|name| {
+            !CostTokenType::iter_precost().any(|token_type| token_type.name() == **name)
+        }) {
+            return Err(CostTableError::UnknownBuiltin(name.clone()));
+        }
+        Ok(table)
+    }
+
+    /// Returns the price of the given constant cost.
+    pub fn const_cost(&self, value: &ConstCost) -> i32 {
+        value.steps * self.step_cost
+            + value.holes * self.hole_cost
+            + value.range_checks * self.range_check_cost
+    }
+
+    /// Returns the price of a single usage of the given builtin.
+    pub fn builtin_cost(&self, token_type: CostTokenType) -> i32 {
+        self.builtin_costs.get(&token_type.name()).copied().unwrap_or(DEFAULT_BUILTIN_COST)
+    }
+
+    /// Returns the total gas of the given costs, such as the costs of a function in a
+    /// [crate::gas_info::GasInfo] computed with this table.
+    pub fn total_gas(&self, costs: &OrderedHashMap<CostTokenType, i64>) -> i64 {
+        costs
+            .iter()
+            .map(|(token_type, value)| match token_type {
+                CostTokenType::Const => *value,
+                _ => *value * self.builtin_cost(*token_type) as i64,
+            })
+            .sum()
+    }
+}
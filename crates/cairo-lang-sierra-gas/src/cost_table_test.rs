@@ -0,0 +1,69 @@
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{CostTable, CostTableError, DEFAULT_BUILTIN_COST};
+use crate::objects::ConstCost;
+
+#[test]
+fn test_default_cost_table() {
+    let cost_table = CostTable::from_json("{}").unwrap();
+    assert_eq!(cost_table, CostTable::default());
+    let const_cost = ConstCost { steps: 3, holes: 2, range_checks: 1 };
+    assert_eq!(cost_table.const_cost(&const_cost), const_cost.cost());
+    assert_eq!(cost_table.builtin_cost(CostTokenType::Pedersen), DEFAULT_BUILTIN_COST);
+}
+
+#[test]
+fn test_cost_table_from_json() {
+    let cost_table = CostTable::from_json(indoc! {r#"
+        {
+            "step_cost": 50,
+            "builtin_costs": {"pedersen": 300},
+            "libfunc_costs": {"felt252_is_zero": [{"steps": 2}, {"steps": 2, "holes": 1}]}
+        }
+    "#})
+    .unwrap();
+    assert_eq!(
+        cost_table,
+        CostTable {
+            step_cost: 50,
+            builtin_costs: [("pedersen".into(), 300)].into_iter().collect(),
+            libfunc_costs: [(
+                "felt252_is_zero".into(),
+                vec![
+                    ConstCost { steps: 2, ..ConstCost::default() },
+                    ConstCost { steps: 2, holes: 1, ..ConstCost::default() },
+                ],
+            )]
+            .into_iter()
+            .collect(),
+            ..CostTable::default()
+        }
+    );
+    assert_eq!(
+        cost_table.const_cost(&ConstCost { steps: 3, holes: 2, range_checks: 1 }),
+        3 * 50 + 2 * 10 + 70
+    );
+    assert_eq!(
+        cost_table.total_gas(&OrderedHashMap::from_iter([
+            (CostTokenType::Const, 1000),
+            (CostTokenType::Pedersen, 2),
+            (CostTokenType::Bitwise, 1),
+        ])),
+        1000 + 2 * 300 + DEFAULT_BUILTIN_COST as i64
+    );
+}
+
+#[test]
+fn test_invalid_cost_table() {
+    assert!(matches!(
+        CostTable::from_json(r#"{"builtin_costs": {"range_check": 70}}"#),
+        Err(CostTableError::UnknownBuiltin(name)) if name == "range_check"
+    ));
+    assert!(matches!(
+        CostTable::from_json(r#"{"memory_hole_cost": 10}"#),
+        Err(CostTableError::Json(_))
+    ));
+}
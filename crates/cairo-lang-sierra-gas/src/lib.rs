@@ -6,19 +6,21 @@ use cairo_lang_eq_solver::Expr;
 use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
 use cairo_lang_sierra::extensions::gas::{CostTokenType, GasConcreteLibfunc};
 use cairo_lang_sierra::extensions::ConcreteType;
-use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId};
+use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId, GenericLibfuncId};
 use cairo_lang_sierra::program::{Program, Statement, StatementIdx};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
 use cairo_lang_utils::casts::IntoOrPanic;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use core_libfunc_cost_base::InvocationCostInfoProvider;
 use core_libfunc_cost_expr::CostExprMap;
-use cost_expr::Var;
+use cost_expr::{CostExpr, Var};
+use cost_table::CostTable;
 use gas_info::GasInfo;
 use generate_equations::StatementFutureCost;
 use itertools::Itertools;
-use objects::CostInfoProvider;
+use objects::{BranchCost, ConstCost, CostInfoProvider};
 use thiserror::Error;
 
 pub mod compute_costs;
@@ -26,6 +28,7 @@ pub mod core_libfunc_cost;
 mod core_libfunc_cost_base;
 mod core_libfunc_cost_expr;
 mod cost_expr;
+pub mod cost_table;
 pub mod gas_info;
 mod generate_equations;
 pub mod objects;
@@ -43,6 +46,8 @@ pub enum CostError {
     StatementOutOfBounds(StatementIdx),
     #[error("failed solving the symbol tables")]
     SolvingGasEquationFailed,
+    #[error("the cost table overrides the costs of `{0}`, which are not constant per branch")]
+    InvalidLibfuncCosts(GenericLibfuncId),
 }
 
 /// Helper to implement the `InvocationCostInfoProvider` for the equation generation.
@@ -134,11 +139,44 @@ pub fn calc_gas_postcost_info<ApChangeVarValue: Fn(StatementIdx) -> usize>(
     function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
     precost_gas_info: &GasInfo,
     ap_change_var_value: ApChangeVarValue,
+) -> Result<GasInfo, CostError> {
+    calc_gas_postcost_info_with_cost_table(
+        program,
+        function_set_costs,
+        precost_gas_info,
+        ap_change_var_value,
+        &CostTable::default(),
+    )
+}
+
+/// Calculates gas postcost information for a given program - the gas costs of step token, with the
+/// constant costs priced by the given cost table.
+///
+/// The compiled code relies on the default prices, so the result is only meant for evaluating the
+/// prices of the table.
+pub fn calc_gas_postcost_info_with_cost_table<ApChangeVarValue: Fn(StatementIdx) -> usize>(
+    program: &Program,
+    function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
+    precost_gas_info: &GasInfo,
+    ap_change_var_value: ApChangeVarValue,
+    cost_table: &CostTable,
 ) -> Result<GasInfo, CostError> {
     let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
+    let overridden_costs = libfunc_cost_overrides(program, &registry, cost_table)?;
     calc_gas_info_inner(
         program,
         |statement_future_cost, idx, libfunc_id| {
+            if let Some(branch_costs) = overridden_costs.get(libfunc_id) {
+                return branch_costs
+                    .iter()
+                    .map(|const_cost| {
+                        CostExprMap::from_iter([(
+                            CostTokenType::Const,
+                            CostExpr::from_const(cost_table.const_cost(const_cost)),
+                        )])
+                    })
+                    .collect();
+            }
             let libfunc = registry
                 .get_libfunc(libfunc_id)
                 .expect("Program registry creation would have already failed.");
@@ -153,6 +191,7 @@ pub fn calc_gas_postcost_info<ApChangeVarValue: Fn(StatementIdx) -> usize>(
                     },
                     ap_change_var_value: || ap_change_var_value(*idx),
                 },
+                cost_table,
             )
         },
         function_set_costs,
@@ -160,6 +199,35 @@ pub fn calc_gas_postcost_info<ApChangeVarValue: Fn(StatementIdx) -> usize>(
     )
 }
 
+/// Returns the libfunc costs of the cost table overriding the costs of the libfuncs of the program,
+/// by the ids of the overridden libfuncs.
+/// Only the costs of libfuncs whose branches have constant costs can be overridden.
+fn libfunc_cost_overrides<'a>(
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    cost_table: &'a CostTable,
+) -> Result<UnorderedHashMap<ConcreteLibfuncId, &'a Vec<ConstCost>>, CostError> {
+    let info_provider = ComputeCostInfoProviderImpl::new(registry);
+    let mut overridden_costs = UnorderedHashMap::default();
+    for declaration in &program.libfunc_declarations {
+        let generic_id = &declaration.long_id.generic_id;
+        let Some(branch_costs) = cost_table.libfunc_costs.get(generic_id.0.as_str()) else {
+            continue;
+        };
+        let known_costs = core_libfunc_cost_base::core_libfunc_cost(
+            registry.get_libfunc(&declaration.id)?,
+            &info_provider,
+        );
+        if known_costs.len() != branch_costs.len()
+            || !known_costs.iter().all(|cost| matches!(cost, BranchCost::Regular { .. }))
+        {
+            return Err(CostError::InvalidLibfuncCosts(generic_id.clone()));
+        }
+        overridden_costs.insert(declaration.id.clone(), branch_costs);
+    }
+    Ok(overridden_costs)
+}
+
 /// Calculates gas information. Used for both precost and postcost.
 fn calc_gas_info_inner<
     GetCost: Fn(&mut dyn StatementFutureCost, &StatementIdx, &ConcreteLibfuncId) -> Vec<CostExprMap>,
@@ -1,6 +1,7 @@
 use std::vec;
 
 use cairo_lang_sierra::extensions::starknet::secp256k1::Secp256K1EcConcreteLibfunc;
+use cairo_lang_sierra::extensions::starknet::secp256r1::Secp256R1EcConcreteLibfunc;
 use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 
 use crate::objects::ConstCost;
@@ -53,6 +54,14 @@ pub fn starknet_libfunc_cost_base(libfunc: &StarkNetConcreteLibfunc) -> Vec<Cons
             Secp256K1EcConcreteLibfunc::New(_) => syscall_cost(4),
             Secp256K1EcConcreteLibfunc::GetCoordinates(_) => syscall_cost(1),
         },
+        StarkNetConcreteLibfunc::Secp256R1(libfunc) => match libfunc {
+            Secp256R1EcConcreteLibfunc::Add(_) => syscall_cost(2),
+            Secp256R1EcConcreteLibfunc::Mul(_) | Secp256R1EcConcreteLibfunc::GetPointFromX(_) => {
+                syscall_cost(3)
+            }
+            Secp256R1EcConcreteLibfunc::New(_) => syscall_cost(4),
+            Secp256R1EcConcreteLibfunc::GetCoordinates(_) => syscall_cost(1),
+        },
     }
 }
 
@@ -1,10 +1,18 @@
 use std::fs;
 use std::path::PathBuf;
 
+use cairo_lang_sierra::extensions::gas::CostTokenType;
 use cairo_lang_sierra::program::Program;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
 
-use crate::{calc_gas_postcost_info, calc_gas_precost_info};
+use crate::cost_table::CostTable;
+use crate::objects::ConstCost;
+use crate::{
+    calc_gas_postcost_info, calc_gas_postcost_info_with_cost_table, calc_gas_precost_info,
+    CostError,
+};
 
 cairo_lang_test_utils::test_file_test!(
     test_solve_gas,
@@ -34,3 +42,59 @@ fn test_solve_gas(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<Str
 
     OrderedHashMap::from([("gas_solution".into(), format!("{gas_info}"))])
 }
+
+#[test]
+fn test_solve_gas_with_cost_table() {
+    let program = get_example_program("fib_jumps");
+    let precost_gas_info = calc_gas_precost_info(&program, Default::default()).unwrap();
+    let calc_with_cost_table = |cost_table: &CostTable| {
+        calc_gas_postcost_info_with_cost_table(
+            &program,
+            Default::default(),
+            &precost_gas_info,
+            |_| 0,
+            cost_table,
+        )
+        .unwrap()
+    };
+
+    let gas_info = calc_with_cost_table(&CostTable::default());
+    assert_eq!(
+        gas_info,
+        calc_gas_postcost_info(&program, Default::default(), &precost_gas_info, |_| 0).unwrap()
+    );
+    // Doubling all the prices doubles the cost of every function.
+    let doubled_cost_table =
+        CostTable::from_json(r#"{"step_cost": 200, "hole_cost": 20, "range_check_cost": 140}"#)
+            .unwrap();
+    let doubled_gas_info = calc_with_cost_table(&doubled_cost_table);
+    for (function_id, costs) in gas_info.function_costs {
+        assert_eq!(
+            doubled_gas_info.function_costs[function_id].get(&CostTokenType::Const),
+            costs.get(&CostTokenType::Const).map(|cost| cost * 2).as_ref()
+        );
+    }
+}
+
+#[test_case("felt252_is_zero", 1; "wrong number of branches")]
+#[test_case("branch_align", 1; "non constant costs")]
+fn test_invalid_libfunc_costs(generic_libfunc_id: &str, n_branches: usize) {
+    let program = get_example_program("fib_jumps");
+    let precost_gas_info = calc_gas_precost_info(&program, Default::default()).unwrap();
+    let cost_table = CostTable {
+        libfunc_costs: [(generic_libfunc_id.into(), vec![ConstCost::default(); n_branches])]
+            .into_iter()
+            .collect(),
+        ..CostTable::default()
+    };
+    assert_eq!(
+        calc_gas_postcost_info_with_cost_table(
+            &program,
+            Default::default(),
+            &precost_gas_info,
+            |_| 0,
+            &cost_table,
+        ),
+        Err(CostError::InvalidLibfuncCosts(generic_libfunc_id.into()))
+    );
+}
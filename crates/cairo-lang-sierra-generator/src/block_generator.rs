@@ -2,6 +2,7 @@
 #[path = "block_generator_test.rs"]
 mod test;
 
+use cairo_lang_defs::diagnostic_utils::StableLocationOption;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{chain, enumerate, zip_eq};
@@ -54,6 +55,9 @@ pub fn generate_block_body_code(
     // Process the statements.
     for (i, statement) in block.statements.iter().enumerate() {
         let statement_location = (block_id, i);
+        if let lowering::Statement::Call(statement_call) = statement {
+            add_location_statement(statement_call.location, &mut statements);
+        }
         statements.extend(generate_statement_code(context, statement, &statement_location)?);
         let drop_location = &DropLocation::PostStatement(statement_location);
         add_drop_statements(context, drops, drop_location, &mut statements)?;
@@ -69,6 +73,16 @@ pub fn generate_block_body_code(
     Ok(statements)
 }
 
+/// Adds a [pre_sierra::Statement::Location] for the given location, if it is known.
+fn add_location_statement(
+    location: StableLocationOption,
+    statements: &mut Vec<pre_sierra::Statement>,
+) {
+    if let StableLocationOption::Some(location) = location {
+        statements.push(pre_sierra::Statement::Location(location));
+    }
+}
+
 /// Adds calls to the `drop` libfunc for the given [DropLocation], according to the `drops`
 /// argument (computed by [find_variable_lifetime](crate::lifetime::find_variable_lifetime)).
 fn add_drop_statements(
@@ -151,6 +165,9 @@ pub fn generate_block_code(
                 ));
             }
 
+            if let lowering::MatchInfo::Extern(s) = info {
+                add_location_statement(s.location, &mut statements);
+            }
             statements.extend(match info {
                 lowering::MatchInfo::Extern(s) => {
                     generate_match_extern_code(context, s, &statement_location)?
@@ -0,0 +1,475 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_sierra::ids::{
+    ConcreteLibfuncId, ConcreteTypeId, GenericLibfuncId, GenericTypeId, VarId,
+};
+use cairo_lang_sierra::program::{
+    BranchInfo, BranchTarget, ConcreteLibfuncLongId, ConcreteTypeLongId, Function, GenericArg,
+    Invocation, LibfuncDeclaration, Program, Statement, StatementIdx,
+};
+use cairo_lang_utils::try_extract_matches;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::db::SierraGenGroup;
+use crate::utils::{drop_libfunc_id, jump_libfunc_id};
+
+#[cfg(test)]
+#[path = "constant_folding_test.rs"]
+mod test;
+
+/// The libfuncs creating a constant without arguments. Their outputs take no instructions, so
+/// they may be recreated instead of duplicated, and removed along with their drops.
+const CONST_LIBFUNCS: &[&str] = &[
+    "felt252_const",
+    "u8_const",
+    "u16_const",
+    "u32_const",
+    "u64_const",
+    "u128_const",
+    "i8_const",
+    "i16_const",
+    "i32_const",
+    "i64_const",
+    "i128_const",
+    "struct_construct",
+];
+
+/// A value of a variable known at compile time.
+#[derive(Clone, Debug)]
+enum Value {
+    /// The constant created by the given libfunc of [CONST_LIBFUNCS].
+    Const(ConcreteLibfuncId),
+    /// An enum of the given variant, holding the constant created by the given libfunc.
+    Enum { variant: usize, payload: ConcreteLibfuncId },
+}
+
+/// Folds the felt252 arithmetic and integer conversions of constants into constants, and replaces
+/// the matches on constants - `felt252_is_zero` of zero and `enum_match` of a constant enum - with
+/// jumps to the branches they take.
+///
+/// Only the instructions-free libfuncs are replaced, so the ap changes of the program are
+/// preserved. The branches no longer taken are left in the program, to be removed by
+/// [crate::dead_code_elimination::eliminate_dead_code].
+pub fn fold_constants(db: &dyn SierraGenGroup, program: &Program) -> Program {
+    fold_constants_with_origins(db, program).0
+}
+
+/// Like [fold_constants], also returning the index of the statement of `program` each statement
+/// of the resulting program originates from.
+pub fn fold_constants_with_origins(
+    db: &dyn SierraGenGroup,
+    program: &Program,
+) -> (Program, Vec<StatementIdx>) {
+    let block_starts = block_starts(program);
+    let mut folder = ConstantFolder {
+        db,
+        known: HashMap::new(),
+        definers: HashMap::new(),
+        replacements: vec![],
+    };
+    for (statement_idx, statement) in program.statements.iter().enumerate() {
+        // The values are only tracked within blocks, as variables may hold different values when
+        // reaching a statement from different statements.
+        if block_starts[statement_idx] {
+            folder.known.clear();
+            folder.definers.clear();
+        }
+        folder.replacements.push(vec![]);
+        match statement {
+            Statement::Return(_) => {
+                folder.push(statement.clone());
+            }
+            Statement::Invocation(invocation) => folder.fold_invocation(invocation),
+        }
+    }
+
+    // The index of the first statement replacing each statement, or of the statement following
+    // it if it was removed.
+    let mut new_indices = vec![];
+    let mut statements = vec![];
+    let mut origins = vec![];
+    for (statement_idx, replacement) in folder.replacements.into_iter().enumerate() {
+        new_indices.push(StatementIdx(statements.len()));
+        statements.extend(replacement.into_iter().flatten());
+        origins.resize(statements.len(), StatementIdx(statement_idx));
+    }
+    let statements = statements
+        .into_iter()
+        .map(|statement| match statement {
+            Statement::Return(_) => statement,
+            Statement::Invocation(invocation) => Statement::Invocation(Invocation {
+                branches: invocation
+                    .branches
+                    .into_iter()
+                    .map(|branch| BranchInfo {
+                        target: match branch.target {
+                            BranchTarget::Fallthrough => BranchTarget::Fallthrough,
+                            BranchTarget::Statement(target) => {
+                                BranchTarget::Statement(new_indices[target.0])
+                            }
+                        },
+                        results: branch.results,
+                    })
+                    .collect(),
+                ..invocation
+            }),
+        })
+        .collect_vec();
+
+    // Declares the libfuncs introduced by the folding. The libfuncs no longer used are kept.
+    let mut libfunc_declarations = program.libfunc_declarations.clone();
+    let mut declared_libfuncs: HashSet<_> =
+        libfunc_declarations.iter().map(|declaration| declaration.id.clone()).collect();
+    let invocations = statements
+        .iter()
+        .filter_map(|statement| try_extract_matches!(statement, Statement::Invocation));
+    for invocation in invocations {
+        if declared_libfuncs.insert(invocation.libfunc_id.clone()) {
+            libfunc_declarations.push(LibfuncDeclaration {
+                id: invocation.libfunc_id.clone(),
+                long_id: db.lookup_intern_concrete_lib_func(invocation.libfunc_id.clone()),
+            });
+        }
+    }
+
+    let program = Program {
+        type_declarations: program.type_declarations.clone(),
+        libfunc_declarations,
+        statements,
+        funcs: program
+            .funcs
+            .iter()
+            .map(|function| Function {
+                entry_point: new_indices[function.entry_point.0],
+                ..function.clone()
+            })
+            .collect(),
+    };
+    (program, origins)
+}
+
+/// Returns whether each statement of the program starts a block, i.e. is reachable other than by
+/// falling through from the statement preceding it.
+fn block_starts(program: &Program) -> Vec<bool> {
+    let mut block_starts = vec![false; program.statements.len()];
+    for function in &program.funcs {
+        block_starts[function.entry_point.0] = true;
+    }
+    for (statement_idx, statement) in program.statements.iter().enumerate() {
+        let Statement::Invocation(invocation) = statement else {
+            continue;
+        };
+        for branch in &invocation.branches {
+            let target = StatementIdx(statement_idx).next(&branch.target);
+            if target.0 != statement_idx + 1 {
+                block_starts[target.0] = true;
+            }
+        }
+    }
+    block_starts
+}
+
+/// The state of the folding of the current block of a program.
+struct ConstantFolder<'a> {
+    db: &'a dyn SierraGenGroup,
+    /// The known values of the variables.
+    known: HashMap<VarId, Value>,
+    /// The location of the statement of a [CONST_LIBFUNCS] libfunc defining each variable, as an
+    /// index into [Self::replacements] and an index into the replacement.
+    definers: HashMap<VarId, (usize, usize)>,
+    /// The statements replacing each statement of the program, with `None` for the removed ones.
+    replacements: Vec<Vec<Option<Statement>>>,
+}
+impl ConstantFolder<'_> {
+    /// Folds an invocation, adding its replacement to the replacement of the current statement.
+    fn fold_invocation(&mut self, invocation: &Invocation) {
+        let long_id = self.db.lookup_intern_concrete_lib_func(invocation.libfunc_id.clone());
+        let name = long_id.generic_id.0.as_str();
+        let results = match &invocation.branches[..] {
+            [branch] if branch.target == BranchTarget::Fallthrough => Some(&branch.results[..]),
+            _ => None,
+        };
+        match (name, &invocation.args[..], results) {
+            (_, [], Some([result])) if CONST_LIBFUNCS.contains(&name) => {
+                self.define_const(result, &invocation.libfunc_id);
+            }
+            ("store_temp" | "rename", [arg], Some([result])) => {
+                let value = self.known.get(arg).cloned();
+                self.keep(invocation);
+                if let Some(value) = value {
+                    self.known.insert(result.clone(), value);
+                }
+            }
+            ("dup", [arg], Some([original, copy])) => match self.known.get(arg).cloned() {
+                // Recreates the constant instead of duplicating it, so that each of the two can
+                // be removed along with its drop.
+                Some(Value::Const(libfunc_id)) if arg == original => {
+                    self.define_const(copy, &libfunc_id);
+                }
+                value => {
+                    self.keep(invocation);
+                    if let Some(value) = value {
+                        self.known.insert(original.clone(), value.clone());
+                        self.known.insert(copy.clone(), value);
+                    }
+                }
+            },
+            ("drop", [arg], Some([])) => match self.definers.remove(arg) {
+                Some((statement_idx, idx)) => {
+                    self.known.remove(arg);
+                    self.replacements[statement_idx][idx] = None;
+                }
+                None => self.keep(invocation),
+            },
+            ("felt252_add" | "felt252_sub" | "felt252_mul", [lhs, rhs], Some([result])) => {
+                match (self.felt252_value(lhs), self.felt252_value(rhs)) {
+                    (Some(lhs_value), Some(rhs_value)) => {
+                        let value = felt252_operation(name, lhs_value, rhs_value);
+                        self.fold_into_felt252(&[lhs, rhs], felt252_type(self.db), result, value);
+                    }
+                    _ => self.keep(invocation),
+                }
+            }
+            (
+                "felt252_add_const" | "felt252_sub_const" | "felt252_mul_const",
+                [lhs],
+                Some([result]),
+            ) => match (self.felt252_value(lhs), &long_id.generic_args[..]) {
+                (Some(lhs_value), [GenericArg::Value(rhs_value)]) => {
+                    let value = felt252_operation(
+                        name.trim_end_matches("_const"),
+                        lhs_value,
+                        rhs_value.clone(),
+                    );
+                    self.fold_into_felt252(&[lhs], felt252_type(self.db), result, value);
+                }
+                _ => self.keep(invocation),
+            },
+            (_, [arg], Some([result])) if name.ends_with("_to_felt252") => {
+                match self.const_value(arg) {
+                    Some(value) => {
+                        let ty = self.db.intern_concrete_type(ConcreteTypeLongId {
+                            generic_id: GenericTypeId::from_string(
+                                name.trim_end_matches("_to_felt252"),
+                            ),
+                            generic_args: vec![],
+                        });
+                        self.fold_into_felt252(&[arg], ty, result, felt252_normalize(value));
+                    }
+                    None => self.keep(invocation),
+                }
+            }
+            ("upcast", [arg], Some([result])) => {
+                let from_ty = generic_arg_type(&long_id, 0);
+                let to_ty = generic_arg_type(&long_id, 1);
+                let to_const_libfunc =
+                    format!("{}_const", self.db.lookup_intern_concrete_type(to_ty).generic_id.0);
+                match self.const_value(arg) {
+                    Some(value) if CONST_LIBFUNCS.contains(&to_const_libfunc.as_str()) => {
+                        self.drop_var(arg, from_ty);
+                        let libfunc_id = self.db.intern_concrete_lib_func(ConcreteLibfuncLongId {
+                            generic_id: GenericLibfuncId::from_string(to_const_libfunc),
+                            generic_args: vec![GenericArg::Value(value)],
+                        });
+                        self.define_const(result, &libfunc_id);
+                    }
+                    _ => self.keep(invocation),
+                }
+            }
+            ("felt252_is_zero", [arg], None) => match self.felt252_value(arg) {
+                Some(value) if value.is_zero() && invocation.branches[0].results.is_empty() => {
+                    self.drop_var(arg, felt252_type(self.db));
+                    self.jump(&invocation.branches[0].target);
+                }
+                _ => self.keep(invocation),
+            },
+            ("enum_init", [arg], Some([result])) => {
+                let value = self.known.get(arg).cloned();
+                self.keep(invocation);
+                let variant = match &long_id.generic_args[..] {
+                    [_, GenericArg::Value(variant)] => variant.to_usize(),
+                    _ => None,
+                };
+                if let (Some(Value::Const(payload)), Some(variant)) = (value, variant) {
+                    self.known.insert(result.clone(), Value::Enum { variant, payload });
+                }
+            }
+            ("enum_match", [arg], None) => {
+                let enum_ty = generic_arg_type(&long_id, 0);
+                let known_variant = match self.known.get(arg) {
+                    Some(Value::Enum { variant, payload }) => invocation
+                        .branches
+                        .get(*variant)
+                        .filter(|branch| branch.results.len() == 1)
+                        .map(|branch| (branch, payload.clone())),
+                    _ => None,
+                };
+                match known_variant {
+                    Some((branch, payload)) if self.is_droppable(&enum_ty) => {
+                        self.drop_var(arg, enum_ty);
+                        self.define_const(&branch.results[0], &payload);
+                        self.jump(&branch.target);
+                    }
+                    _ => self.keep(invocation),
+                }
+            }
+            _ => self.keep(invocation),
+        }
+        // The constants defined so far may be used by any of the branches, so their definitions
+        // are no longer removed along with their drops, which may be in only some of the branches.
+        // Their values are still known.
+        if invocation.branches.len() > 1 {
+            self.definers.clear();
+        }
+    }
+
+    /// Adds a statement to the replacement of the current statement, returning its index in the
+    /// replacement.
+    fn push(&mut self, statement: Statement) -> usize {
+        let replacement = self.replacements.last_mut().unwrap();
+        replacement.push(Some(statement));
+        replacement.len() - 1
+    }
+
+    /// Keeps an invocation as is, forgetting the values of its arguments and results.
+    fn keep(&mut self, invocation: &Invocation) {
+        let results = invocation.branches.iter().flat_map(|branch| &branch.results);
+        for var in invocation.args.iter().chain(results) {
+            self.known.remove(var);
+            self.definers.remove(var);
+        }
+        self.push(Statement::Invocation(invocation.clone()));
+    }
+
+    /// Defines a variable as the constant created by the given libfunc of [CONST_LIBFUNCS].
+    fn define_const(&mut self, var: &VarId, libfunc_id: &ConcreteLibfuncId) {
+        let idx = self.push(simple_statement(libfunc_id.clone(), vec![], vec![var.clone()]));
+        self.known.insert(var.clone(), Value::Const(libfunc_id.clone()));
+        self.definers.insert(var.clone(), (self.replacements.len() - 1, idx));
+    }
+
+    /// Drops a variable of the given type, or removes its definition if it is a constant defined
+    /// in the current block.
+    fn drop_var(&mut self, var: &VarId, ty: ConcreteTypeId) {
+        self.known.remove(var);
+        match self.definers.remove(var) {
+            Some((statement_idx, idx)) => self.replacements[statement_idx][idx] = None,
+            None => {
+                let drop_libfunc_id = drop_libfunc_id(self.db, ty);
+                self.push(simple_statement(drop_libfunc_id, vec![var.clone()], vec![]));
+            }
+        }
+    }
+
+    /// Replaces an operation on constants of the given type with the felt252 constant it results
+    /// in.
+    fn fold_into_felt252(
+        &mut self,
+        args: &[&VarId],
+        ty: ConcreteTypeId,
+        result: &VarId,
+        value: BigInt,
+    ) {
+        for arg in args {
+            self.drop_var(arg, ty.clone());
+        }
+        let libfunc_id = self.db.intern_concrete_lib_func(ConcreteLibfuncLongId {
+            generic_id: GenericLibfuncId::from_string("felt252_const"),
+            generic_args: vec![GenericArg::Value(value)],
+        });
+        self.define_const(result, &libfunc_id);
+    }
+
+    /// Jumps to the given target, unless it is the next statement.
+    fn jump(&mut self, target: &BranchTarget) {
+        if let BranchTarget::Statement(_) = target {
+            self.push(Statement::Invocation(Invocation {
+                libfunc_id: jump_libfunc_id(self.db),
+                args: vec![],
+                branches: vec![BranchInfo { target: target.clone(), results: vec![] }],
+            }));
+        }
+    }
+
+    /// Returns the value of a variable holding a known numeric constant.
+    fn const_value(&self, var: &VarId) -> Option<BigInt> {
+        let Some(Value::Const(libfunc_id)) = self.known.get(var) else {
+            return None;
+        };
+        match &self.db.lookup_intern_concrete_lib_func(libfunc_id.clone()).generic_args[..] {
+            [GenericArg::Value(value)] => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a variable holding a known felt252 constant, in the range [0, PRIME).
+    fn felt252_value(&self, var: &VarId) -> Option<BigInt> {
+        let Some(Value::Const(libfunc_id)) = self.known.get(var) else {
+            return None;
+        };
+        let long_id = self.db.lookup_intern_concrete_lib_func(libfunc_id.clone());
+        if long_id.generic_id.0 != "felt252_const" {
+            return None;
+        }
+        self.const_value(var).map(felt252_normalize)
+    }
+
+    /// Returns whether values of the given type may be dropped.
+    fn is_droppable(&self, ty: &ConcreteTypeId) -> bool {
+        self.db.get_type_info(ty.clone()).map_or(false, |info| info.droppable)
+    }
+}
+
+/// Returns the type given as the generic argument of the given index of a libfunc.
+fn generic_arg_type(long_id: &ConcreteLibfuncLongId, idx: usize) -> ConcreteTypeId {
+    match &long_id.generic_args[idx] {
+        GenericArg::Type(ty) => ty.clone(),
+        _ => panic!("Expected a type generic argument."),
+    }
+}
+
+/// Returns a non-branching invocation statement.
+fn simple_statement(
+    libfunc_id: ConcreteLibfuncId,
+    args: Vec<VarId>,
+    results: Vec<VarId>,
+) -> Statement {
+    Statement::Invocation(Invocation {
+        libfunc_id,
+        args,
+        branches: vec![BranchInfo { target: BranchTarget::Fallthrough, results }],
+    })
+}
+
+/// Returns the result of a felt252 binary operation, given by its libfunc name, in the range
+/// [0, PRIME).
+fn felt252_operation(name: &str, lhs: BigInt, rhs: BigInt) -> BigInt {
+    let value = match name {
+        "felt252_add" => lhs + rhs,
+        "felt252_sub" => lhs - rhs,
+        "felt252_mul" => lhs * rhs,
+        _ => unreachable!("Unexpected felt252 operation `{name}`."),
+    };
+    felt252_normalize(value)
+}
+
+/// Returns the felt252 value of an integer, in the range [0, PRIME).
+fn felt252_normalize(value: BigInt) -> BigInt {
+    let prime = felt252_prime();
+    ((value % &prime) + &prime) % &prime
+}
+
+/// Returns the prime of the felt252 field.
+fn felt252_prime() -> BigInt {
+    (BigInt::from(1) << 251) + (BigInt::from(17) << 192) + 1
+}
+
+/// Returns the id of the felt252 type.
+fn felt252_type(db: &dyn SierraGenGroup) -> ConcreteTypeId {
+    db.intern_concrete_type(ConcreteTypeLongId {
+        generic_id: GenericTypeId::from_string("felt252"),
+        generic_args: vec![],
+    })
+}
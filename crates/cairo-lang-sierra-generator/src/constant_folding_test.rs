@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use cairo_lang_sierra::program::Statement;
+use cairo_lang_sierra::validate::validate;
+use cairo_lang_sierra::ProgramParser;
+use indoc::indoc;
+use itertools::Itertools;
+use test_case::test_case;
+
+use super::fold_constants;
+use crate::db::SierraGenGroup;
+use crate::dead_code_elimination::eliminate_dead_code;
+use crate::replace_ids::replace_sierra_ids_in_program;
+use crate::test_utils::setup_db_and_get_crate_id;
+
+#[test_case(
+    "fn foo() -> felt252 { 3 * 4 + 5 - 2 }",
+    &["felt252_const<15>"],
+    &["felt252_add", "felt252_sub", "felt252_mul"];
+    "felt252 arithmetic"
+)]
+#[test_case(
+    "fn foo() -> felt252 { integer::u8_to_felt252(200_u8) + 1 }",
+    &["felt252_const<201>"],
+    &["u8_to_felt252", "felt252_add"];
+    "integer conversion"
+)]
+#[test_case(
+    "fn foo(a: felt252) -> felt252 { match 5 - 5 { 0 => a, _ => a * 2 } }",
+    &[],
+    &["felt252_sub", "felt252_is_zero", "felt252_mul"];
+    "zero check"
+)]
+fn test_fold_constants(content: &str, expected_libfuncs: &[&str], eliminated_libfuncs: &[&str]) {
+    let (db, crate_id) = setup_db_and_get_crate_id(content);
+    let program = db.get_sierra_program(vec![crate_id]).unwrap();
+    let program = fold_constants(&db, &program);
+    let roots = program.funcs.iter().map(|function| function.id.clone()).collect_vec();
+    let program = replace_sierra_ids_in_program(&db, &eliminate_dead_code(&db, &program, &roots));
+    let libfuncs = program
+        .libfunc_declarations
+        .iter()
+        .map(|declaration| declaration.id.to_string())
+        .collect_vec();
+    for libfunc in expected_libfuncs {
+        assert!(libfuncs.contains(&libfunc.to_string()), "`{libfunc}` not in {libfuncs:?}.");
+    }
+    for libfunc in eliminated_libfuncs {
+        assert!(!libfuncs.contains(&libfunc.to_string()), "`{libfunc}` in {libfuncs:?}.");
+    }
+}
+
+#[test]
+fn test_fold_constants_live_across_branches() {
+    let (db, _) = setup_db_and_get_crate_id("");
+    // The constant is used by the branch jumped to, after being dropped by the fallthrough one.
+    let mut program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+            type NonZero<felt252> = NonZero<felt252>;
+            libfunc felt252_const<5> = felt252_const<5>;
+            libfunc felt252_const<0> = felt252_const<0>;
+            libfunc felt252_is_zero = felt252_is_zero;
+            libfunc branch_align = branch_align;
+            libfunc drop<felt252> = drop<felt252>;
+            libfunc drop<NonZero<felt252>> = drop<NonZero<felt252>>;
+            felt252_const<5>() -> ([1]);
+            felt252_is_zero([0]) { fallthrough() 6([2]) };
+            branch_align() -> ();
+            drop<felt252>([1]) -> ();
+            felt252_const<0>() -> ([3]);
+            return([3]);
+            branch_align() -> ();
+            drop<NonZero<felt252>>([2]) -> ();
+            return([1]);
+            foo@0([0]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    // The folding looks the libfuncs up in the database.
+    let mut libfunc_ids = HashMap::new();
+    for declaration in &mut program.libfunc_declarations {
+        let libfunc_id = db.intern_concrete_lib_func(declaration.long_id.clone());
+        libfunc_ids.insert(declaration.id.clone(), libfunc_id.clone());
+        declaration.id = libfunc_id;
+    }
+    for statement in &mut program.statements {
+        if let Statement::Invocation(invocation) = statement {
+            invocation.libfunc_id = libfunc_ids[&invocation.libfunc_id].clone();
+        }
+    }
+    let folded = fold_constants(&db, &program);
+    assert_eq!(validate(&folded), Ok(()));
+    assert_eq!(folded.statements, program.statements);
+}
@@ -0,0 +1,172 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra::program::{
+    BranchInfo, BranchTarget, Function, GenericArg, Invocation, Program, Statement, StatementIdx,
+};
+use cairo_lang_utils::try_extract_matches;
+use itertools::Itertools;
+
+use crate::db::SierraGenGroup;
+use crate::program_generator::collect_used_types;
+
+#[cfg(test)]
+#[path = "dead_code_elimination_test.rs"]
+mod test;
+
+/// Removes the dead code of a Sierra program: the functions unreachable from the `roots`
+/// functions, the statements unreachable from the entry points of the remaining functions, and the
+/// libfunc and type declarations no longer used by the remaining statements.
+///
+/// The order of the remaining statements and declarations is preserved, so a program without dead
+/// code is left unchanged.
+pub fn eliminate_dead_code(
+    db: &dyn SierraGenGroup,
+    program: &Program,
+    roots: &[FunctionId],
+) -> Program {
+    eliminate_dead_code_with_origins(db, program, roots).0
+}
+
+/// Like [eliminate_dead_code], also returning the index of the statement of `program` each
+/// statement of the resulting program originates from.
+pub fn eliminate_dead_code_with_origins(
+    db: &dyn SierraGenGroup,
+    program: &Program,
+    roots: &[FunctionId],
+) -> (Program, Vec<StatementIdx>) {
+    let libfuncs: HashMap<_, _> = program
+        .libfunc_declarations
+        .iter()
+        .map(|declaration| (&declaration.id, &declaration.long_id))
+        .collect();
+    let functions: HashMap<_, _> =
+        program.funcs.iter().map(|function| (&function.id, function)).collect();
+
+    let mut reachable_functions = HashSet::new();
+    let mut reachable_statements = vec![false; program.statements.len()];
+    let mut function_queue = roots.iter().collect_vec();
+    while let Some(function_id) = function_queue.pop() {
+        if !reachable_functions.insert(function_id) {
+            continue;
+        }
+        let mut statement_queue = vec![functions[function_id].entry_point];
+        while let Some(statement_idx) = statement_queue.pop() {
+            if std::mem::replace(&mut reachable_statements[statement_idx.0], true) {
+                continue;
+            }
+            let Statement::Invocation(invocation) = &program.statements[statement_idx.0] else {
+                continue;
+            };
+            statement_queue.extend(
+                invocation.branches.iter().map(|branch| statement_idx.next(&branch.target)),
+            );
+            // Functions are only referenced by the generic arguments of the libfuncs calling them.
+            function_queue.extend(
+                libfuncs[&invocation.libfunc_id]
+                    .generic_args
+                    .iter()
+                    .filter_map(|arg| try_extract_matches!(arg, GenericArg::UserFunc)),
+            );
+        }
+    }
+
+    // The index of each statement in the program without the unreachable statements.
+    let new_indices = reachable_statements
+        .iter()
+        .scan(0, |next_index, reachable| {
+            let index = *next_index;
+            *next_index += usize::from(*reachable);
+            Some(StatementIdx(index))
+        })
+        .collect_vec();
+    let origins = reachable_statements
+        .iter()
+        .positions(|reachable| *reachable)
+        .map(StatementIdx)
+        .collect_vec();
+    let statements = program
+        .statements
+        .iter()
+        .zip(&reachable_statements)
+        .filter(|(_, reachable)| **reachable)
+        .map(|(statement, _)| match statement {
+            Statement::Return(_) => statement.clone(),
+            Statement::Invocation(invocation) => Statement::Invocation(Invocation {
+                branches: invocation
+                    .branches
+                    .iter()
+                    .map(|branch| BranchInfo {
+                        target: match branch.target {
+                            // The statement following a reachable invocation is reachable, so it
+                            // still follows it.
+                            BranchTarget::Fallthrough => BranchTarget::Fallthrough,
+                            BranchTarget::Statement(target) => {
+                                BranchTarget::Statement(new_indices[target.0])
+                            }
+                        },
+                        results: branch.results.clone(),
+                    })
+                    .collect(),
+                ..invocation.clone()
+            }),
+        })
+        .collect_vec();
+
+    let used_libfuncs: HashSet<_> = statements
+        .iter()
+        .filter_map(|statement| try_extract_matches!(statement, Statement::Invocation))
+        .map(|invocation| &invocation.libfunc_id)
+        .collect();
+    let libfunc_declarations = program
+        .libfunc_declarations
+        .iter()
+        .filter(|declaration| used_libfuncs.contains(&declaration.id))
+        .cloned()
+        .collect_vec();
+
+    // The types used by the libfuncs, along with the types they depend on.
+    let type_long_ids: HashMap<_, _> = program
+        .type_declarations
+        .iter()
+        .map(|declaration| (&declaration.id, &declaration.long_id))
+        .collect();
+    let mut used_types = HashSet::new();
+    let mut type_queue =
+        collect_used_types(db, &libfunc_declarations).iter().cloned().collect_vec();
+    while let Some(ty) = type_queue.pop() {
+        if let Some(long_id) = type_long_ids.get(&ty) {
+            type_queue.extend(
+                long_id
+                    .generic_args
+                    .iter()
+                    .filter_map(|arg| try_extract_matches!(arg, GenericArg::Type))
+                    .filter(|inner_ty| !used_types.contains(*inner_ty))
+                    .cloned(),
+            );
+        }
+        used_types.insert(ty);
+    }
+    let type_declarations = program
+        .type_declarations
+        .iter()
+        .filter(|declaration| used_types.contains(&declaration.id))
+        .cloned()
+        .collect_vec();
+
+    let program = Program {
+        type_declarations,
+        libfunc_declarations,
+        statements,
+        funcs: program
+            .funcs
+            .iter()
+            .filter(|function| reachable_functions.contains(&function.id))
+            .map(|function| Function {
+                entry_point: new_indices[function.entry_point.0],
+                ..function.clone()
+            })
+            .collect(),
+    };
+    (program, origins)
+}
@@ -0,0 +1,83 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra::program::{Program, StatementIdx};
+use cairo_lang_utils::try_extract_matches;
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{eliminate_dead_code, eliminate_dead_code_with_origins};
+use crate::db::SierraGenGroup;
+use crate::replace_ids::replace_sierra_ids_in_program;
+use crate::test_utils::setup_db_and_get_crate_id;
+
+#[test_case(&["f1"], &["test::f1", "test::f3", "test::f5"]; "f1 -> (f3 -> f5, f5)")]
+#[test_case(&["f2"], &["test::f2", "test::f4"]; "f2 -> f4")]
+#[test_case(&["f4"], &["test::f4"]; "self loop")]
+#[test_case(&["f3", "f4"], &["test::f3", "test::f4", "test::f5"]; "multiple roots")]
+fn test_eliminate_unreachable_functions(roots: &[&str], expected_funcs: &[&str]) {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn f1(a: felt252) -> felt252 { f3(a) + f5(a) }
+        fn f2(a: felt252) -> bool { f4(a) }
+        fn f3(a: felt252) -> felt252 { f5(a) * 2 }
+        fn f4(a: felt252) -> bool { f4(a) }
+        fn f5(a: felt252) -> felt252 { a + 1 }
+    "});
+    let roots = roots
+        .iter()
+        .map(|name| {
+            let free_function_id = db
+                .crate_modules(crate_id)
+                .iter()
+                .find_map(|module_id| {
+                    try_extract_matches!(
+                        db.module_item_by_name(*module_id, (*name).into()).unwrap().unwrap(),
+                        ModuleItemId::FreeFunction
+                    )
+                })
+                .unwrap();
+            ConcreteFunctionWithBodyId::from_no_generics_free(&db, free_function_id).unwrap()
+        })
+        .collect_vec();
+    let program = eliminate_dead_code(
+        &db,
+        &db.get_sierra_program(vec![crate_id]).unwrap(),
+        &roots
+            .iter()
+            .map(|root| db.intern_sierra_function(root.function_id(&db).unwrap()))
+            .collect_vec(),
+    );
+    let program = replace_sierra_ids_in_program(&db, &program);
+    assert_eq!(
+        program.funcs.iter().map(|function| function.id.to_string()).collect_vec(),
+        expected_funcs
+    );
+
+    // The remaining code is the code generated for the roots alone, up to the order of the
+    // declarations.
+    let expected_program =
+        replace_sierra_ids_in_program(&db, &db.get_sierra_program_for_functions(roots).unwrap());
+    let sorted_lines =
+        |program: &Program| program.to_string().lines().map(str::to_string).sorted().collect_vec();
+    assert_eq!(sorted_lines(&program), sorted_lines(&expected_program));
+}
+
+#[test]
+fn test_eliminate_dead_code_origins() {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn f1(a: felt252) -> felt252 { a + 1 }
+        fn f2(a: felt252) -> felt252 { a * 2 }
+    "});
+    let program = db.get_sierra_program(vec![crate_id]).unwrap();
+    let root = program.funcs.iter().max_by_key(|function| function.entry_point.0).unwrap();
+    let (new_program, origins) =
+        eliminate_dead_code_with_origins(&db, &program, &[root.id.clone()]);
+
+    // Only the statements of the root remain, each originating from its own statement.
+    let start = root.entry_point.0;
+    assert_eq!(origins, (start..program.statements.len()).map(StatementIdx).collect_vec());
+    assert_eq!(new_program.statements, program.statements[start..]);
+}
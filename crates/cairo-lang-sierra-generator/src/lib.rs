@@ -5,7 +5,9 @@ mod ap_change;
 mod ap_tracking;
 mod block_generator;
 pub mod canonical_id_replacer;
+pub mod constant_folding;
 pub mod db;
+pub mod dead_code_elimination;
 mod expr_generator_context;
 mod function_generator;
 #[cfg(any(feature = "testing", test))]
@@ -15,10 +17,11 @@ mod lifetime;
 mod local_variables;
 mod next_statement_index_fetch;
 pub mod pre_sierra;
-mod program_generator;
+pub mod program_generator;
 pub mod replace_ids;
 mod resolve_labels;
 mod specialization_context;
+pub mod statements_locations;
 mod store_variables;
 #[cfg(any(feature = "testing", test))]
 pub mod test_utils;
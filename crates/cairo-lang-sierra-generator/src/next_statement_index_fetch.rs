@@ -28,6 +28,7 @@ impl NextStatementIndexFetch {
                         index += 1;
                     }
                 }
+                Statement::Location(_) => {}
                 Statement::PushValues(_) => panic!(
                     "Unexpected pre_sierra::Statement::PushValues in \
                      NextStatementIndexFetch::new()."
@@ -49,7 +49,8 @@ fn collect_used_libfuncs(
                 Some(invocation.libfunc_id.clone())
             }
             pre_sierra::Statement::Sierra(program::GenStatement::Return(_))
-            | pre_sierra::Statement::Label(_) => None,
+            | pre_sierra::Statement::Label(_)
+            | pre_sierra::Statement::Location(_) => None,
             pre_sierra::Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in collect_used_libfuncs().")
             }
@@ -98,7 +99,7 @@ fn generate_type_declarations_helper(
 
 /// Collects the set of all [ConcreteTypeId] that are used in the given list of
 /// [program::LibfuncDeclaration].
-fn collect_used_types(
+pub(crate) fn collect_used_types(
     db: &dyn SierraGenGroup,
     libfunc_declarations: &[program::LibfuncDeclaration],
 ) -> OrderedHashSet<ConcreteTypeId> {
@@ -179,7 +180,7 @@ pub fn get_sierra_program_for_functions(
 }
 
 /// Tries extracting a ConcreteFunctionWithBodyId from a pre-Sierra statement.
-fn try_get_function_with_body_id(
+pub fn try_get_function_with_body_id(
     db: &dyn SierraGenGroup,
     statement: &pre_sierra::Statement,
 ) -> Option<ConcreteFunctionWithBodyId> {
@@ -206,18 +207,27 @@ pub fn get_sierra_program(
     db: &dyn SierraGenGroup,
     requested_crate_ids: Vec<CrateId>,
 ) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
-    let mut requested_function_ids = vec![];
-    for crate_id in requested_crate_ids {
-        for module_id in db.crate_modules(crate_id).iter() {
+    db.get_sierra_program_for_functions(crates_function_ids(db, &requested_crate_ids)?)
+}
+
+/// Returns the functions of the given crates that their Sierra program starts from: their free
+/// functions without generic parameters.
+pub fn crates_function_ids(
+    db: &dyn SierraGenGroup,
+    crate_ids: &[CrateId],
+) -> Maybe<Vec<ConcreteFunctionWithBodyId>> {
+    let mut function_ids = vec![];
+    for crate_id in crate_ids {
+        for module_id in db.crate_modules(*crate_id).iter() {
             for (free_func_id, _) in db.module_free_functions(*module_id)? {
                 // TODO(spapini): Search Impl functions.
                 if let Some(function) =
                     ConcreteFunctionWithBodyId::from_no_generics_free(db.upcast(), free_func_id)
                 {
-                    requested_function_ids.push(function)
+                    function_ids.push(function)
                 }
             }
         }
     }
-    db.get_sierra_program_for_functions(requested_function_ids)
+    Ok(function_ids)
 }
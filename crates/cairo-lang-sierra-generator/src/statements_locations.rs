@@ -0,0 +1,45 @@
+#[cfg(test)]
+#[path = "statements_locations_test.rs"]
+mod test;
+
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_sierra::program;
+
+use crate::db::SierraGenGroup;
+use crate::pre_sierra;
+
+/// Returns the source location of each of the statements of the given program, or `None` for the
+/// statements whose location is unknown.
+///
+/// The program is expected to be the result of
+/// [SierraGenGroup::get_sierra_program_for_functions], before any of its ids were replaced.
+pub fn get_statements_locations(
+    db: &dyn SierraGenGroup,
+    program: &program::Program,
+) -> Maybe<Vec<Option<StableLocation>>> {
+    let mut locations = Vec::with_capacity(program.statements.len());
+    // The statements of the program are the bodies of its functions, in the order of the
+    // functions.
+    for function in &program.funcs {
+        let function_id = db
+            .lookup_intern_sierra_function(function.id.clone())
+            .body(db.upcast())?
+            .expect("Functions of the program must have a body.");
+        let mut location = None;
+        for statement in &db.function_with_body_sierra(function_id)?.body {
+            match statement {
+                pre_sierra::Statement::Sierra(_) => locations.push(location),
+                pre_sierra::Statement::Label(_) => location = None,
+                pre_sierra::Statement::Location(statement_location) => {
+                    location = Some(*statement_location)
+                }
+                pre_sierra::Statement::PushValues(_) => {
+                    panic!("Unexpected pre_sierra::Statement::PushValues in a generated function.")
+                }
+            }
+        }
+    }
+    assert_eq!(locations.len(), program.statements.len(), "Program functions do not match.");
+    Ok(locations)
+}
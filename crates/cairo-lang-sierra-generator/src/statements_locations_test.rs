@@ -0,0 +1,36 @@
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::get_statements_locations;
+use crate::db::SierraGenGroup;
+use crate::test_utils::setup_db_and_get_crate_id;
+
+#[test]
+fn test_statements_locations() {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn foo(a: felt252) -> felt252 {
+            bar(a)
+        }
+
+        #[inline(never)]
+        fn bar(a: felt252) -> felt252 {
+            felt252_add(a, a)
+        }
+    "});
+    let program = db.get_sierra_program(vec![crate_id]).unwrap();
+    let locations = get_statements_locations(&db, &program).unwrap();
+    assert_eq!(locations.len(), program.statements.len());
+    let lines = locations
+        .into_iter()
+        .flatten()
+        .map(|location| {
+            let location = location.diagnostic_location(&db);
+            location.span.start.position_in_file(&db, location.file_id).unwrap().line
+        })
+        .unique()
+        .sorted()
+        .collect_vec();
+    assert_eq!(lines, vec![1, 6]);
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::iter;
+use std::ops::Range;
 
 use cairo_lang_casm::ap_change::{ApChangeError, ApplyApChange};
 use cairo_lang_sierra::edit_state::{put_results, take_args};
@@ -104,12 +105,15 @@ pub struct StatementAnnotations {
 /// Annotations of the program statements.
 /// See StatementAnnotations.
 pub struct ProgramAnnotations {
+    /// The index of the first statement the annotations are of.
+    first_statement_idx: usize,
     /// Optional per statement annotation.
     per_statement_annotations: Vec<Option<StatementAnnotations>>,
 }
 impl ProgramAnnotations {
     fn new(n_statements: usize) -> Self {
         ProgramAnnotations {
+            first_statement_idx: 0,
             per_statement_annotations: iter::repeat_with(|| None).take(n_statements).collect(),
         }
     }
@@ -145,6 +149,28 @@ impl ProgramAnnotations {
         Ok(annotations)
     }
 
+    /// Splits the annotations into the annotations of each of the given ranges of statements,
+    /// which must be consecutive and cover all the annotated statements.
+    /// The annotations of a range can only be accessed for the statements in the range.
+    pub fn split(self, ranges: &[Range<usize>]) -> Vec<ProgramAnnotations> {
+        let mut per_statement_annotations = self.per_statement_annotations.into_iter();
+        ranges
+            .iter()
+            .map(|range| ProgramAnnotations {
+                first_statement_idx: range.start,
+                per_statement_annotations: per_statement_annotations
+                    .by_ref()
+                    .take(range.len())
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Returns the index of the annotations of the given statement in `per_statement_annotations`.
+    fn annotations_idx(&self, statement_idx: StatementIdx) -> usize {
+        statement_idx.0 - self.first_statement_idx
+    }
+
     /// Sets the annotations at 'statement_idx' to 'annotations'
     /// If the annotations for this statement were set previously asserts that the previous
     /// assignment is consistent with the new assignment and verifies that convergence_allowed
@@ -154,7 +180,10 @@ impl ProgramAnnotations {
         statement_idx: StatementIdx,
         annotations: StatementAnnotations,
     ) -> Result<(), AnnotationError> {
-        let idx = statement_idx.0;
+        let idx = statement_idx
+            .0
+            .checked_sub(self.first_statement_idx)
+            .ok_or(AnnotationError::InvalidStatementIdx)?;
         match self.per_statement_annotations.get(idx).ok_or(AnnotationError::InvalidStatementIdx)? {
             None => self.per_statement_annotations[idx] = Some(annotations),
             Some(expected_annotations) => {
@@ -220,7 +249,8 @@ impl ProgramAnnotations {
         statement_idx: StatementIdx,
         ref_ids: impl Iterator<Item = &'a VarId>,
     ) -> Result<(StatementAnnotations, Vec<ReferenceValue>), AnnotationError> {
-        let statement_annotations = self.per_statement_annotations[statement_idx.0]
+        let idx = self.annotations_idx(statement_idx);
+        let statement_annotations = self.per_statement_annotations[idx]
             .as_ref()
             .ok_or(AnnotationError::MissingAnnotationsForStatement(statement_idx))?
             .clone();
@@ -246,7 +276,8 @@ impl ProgramAnnotations {
         branch_changes: BranchChanges,
         must_set: bool,
     ) -> Result<(), AnnotationError> {
-        if must_set && self.per_statement_annotations[destination_statement_idx.0].is_some() {
+        let destination_idx = self.annotations_idx(destination_statement_idx);
+        if must_set && self.per_statement_annotations[destination_idx].is_some() {
             return Err(AnnotationError::AnnotationAlreadySet {
                 source_statement_idx,
                 destination_statement_idx,
@@ -1,13 +1,15 @@
 use std::fmt::Display;
+use std::ops::Range;
 
 use cairo_lang_casm::instructions::{Instruction, InstructionBody, RetInstruction};
 use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::ConcreteLibfunc;
-use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::ids::{ConcreteTypeId, FunctionId, VarId};
 use cairo_lang_sierra::program::{BranchTarget, Invocation, Program, Statement, StatementIdx};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
-use itertools::zip_eq;
+use itertools::{izip, zip_eq};
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 use thiserror::Error;
 
 use crate::annotations::{AnnotationError, ProgramAnnotations, StatementAnnotations};
@@ -15,9 +17,12 @@ use crate::invocations::{
     check_references_on_stack, compile_invocation, InvocationError, ProgramInfo,
 };
 use crate::metadata::Metadata;
-use crate::references::{check_types_match, ReferencesError};
-use crate::relocations::{relocate_instructions, RelocationEntry};
-use crate::type_sizes::get_type_size_map;
+use crate::peephole::optimize_instructions;
+use crate::references::{
+    check_types_match, ReferenceExpression, ReferenceValue, ReferencesError, StatementRefs,
+};
+use crate::relocations::{relocate_instructions, Relocation, RelocationEntry};
+use crate::type_sizes::{get_type_size_map, TypeSizeMap};
 
 #[cfg(test)]
 #[path = "compiler_test.rs"]
@@ -46,6 +51,18 @@ pub enum CompilationError {
         source_statement_idx: StatementIdx,
         destination_statement_idx: StatementIdx,
     },
+    #[error("Failed creating the compilation threads: {0}")]
+    ThreadPoolError(String),
+}
+
+/// The optimizations applied to the casm emitted for a program.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub enum OptimizationLevel {
+    /// The casm of each statement is emitted as is.
+    O0,
+    /// Peephole optimizations are applied to the emitted casm - see [optimize_instructions].
+    #[default]
+    O1,
 }
 
 /// The casm program representation.
@@ -68,6 +85,18 @@ impl Display for CairoProgram {
 pub struct SierraStatementDebugInfo {
     /// The offset of the sierra statement within the bytecode.
     pub code_offset: usize,
+    /// The variables available at the start of the statement, sorted by id.
+    pub variables: Vec<VariableDebugInfo>,
+}
+
+/// The debug information of a Sierra variable available at the start of a statement.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VariableDebugInfo {
+    pub id: VarId,
+    pub ty: ConcreteTypeId,
+    /// The cells holding the value of the variable, relative to the registers at the start of the
+    /// code of the statement.
+    pub expression: ReferenceExpression,
 }
 
 /// The debug information of a compilation from Sierra to casm.
@@ -101,18 +130,47 @@ pub fn check_basic_structure(
     }
 }
 
+/// The configuration of a compilation from Sierra to casm.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CompilationConfig {
+    /// Whether to check that the gas usage of the program is covered by the gas it withdraws.
+    pub gas_usage_check: bool,
+    /// The optimizations applied to the emitted casm.
+    pub optimization_level: OptimizationLevel,
+    /// The number of threads the functions of the program are compiled on. With a single thread,
+    /// the program is compiled on the calling thread.
+    pub jobs: usize,
+}
+impl Default for CompilationConfig {
+    fn default() -> Self {
+        Self { gas_usage_check: true, optimization_level: Default::default(), jobs: 1 }
+    }
+}
+
+/// Compiles a Sierra program to casm, applying the default optimizations.
 pub fn compile(
     program: &Program,
     metadata: &Metadata,
     gas_usage_check: bool,
 ) -> Result<CairoProgram, Box<CompilationError>> {
-    let mut instructions = Vec::new();
-    let mut relocations: Vec<RelocationEntry> = Vec::new();
-
-    // Maps statement_idx to program_offset. The last value (for statement_idx=number-of-statements)
-    // contains the final offset (the size of the program code segment).
-    let mut statement_offsets = Vec::with_capacity(program.statements.len());
+    compile_with_config(
+        program,
+        metadata,
+        CompilationConfig { gas_usage_check, ..CompilationConfig::default() },
+    )
+}
 
+/// Compiles a Sierra program to casm, with the given configuration.
+///
+/// The statements of the program are split into ranges that can be compiled independently -
+/// consecutive functions with no branches between them. The ranges are compiled on `config.jobs`
+/// threads, and their casm is merged and relocated in the order of the statements, so the result
+/// doesn't depend on the number of threads.
+pub fn compile_with_config(
+    program: &Program,
+    metadata: &Metadata,
+    config: CompilationConfig,
+) -> Result<CairoProgram, Box<CompilationError>> {
     let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new_with_ap_change(
         program,
         metadata.ap_change_info.function_ap_change.clone(),
@@ -120,18 +178,300 @@ pub fn compile(
     .map_err(CompilationError::ProgramRegistryError)?;
     let type_sizes = get_type_size_map(program, &registry)
         .ok_or(CompilationError::FailedBuildingTypeInformation)?;
-    let mut program_annotations = ProgramAnnotations::create(
+    let program_annotations = ProgramAnnotations::create(
         program.statements.len(),
         &program.funcs,
         metadata,
-        gas_usage_check,
+        config.gas_usage_check,
         &type_sizes,
     )
     .map_err(|err| Box::new(err.into()))?;
 
+    let ranges = independent_statement_ranges(program);
+    let ranges_annotations = zip_eq(ranges.iter().cloned(), program_annotations.split(&ranges));
+    let compile_range = |(statements, annotations): (Range<usize>, ProgramAnnotations)| {
+        compile_statements(program, statements, annotations, &registry, metadata, &type_sizes)
+    };
+    let compiled_ranges: Vec<_> = if config.jobs > 1 {
+        let thread_pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(config.jobs)
+            .build()
+            .map_err(|err| CompilationError::ThreadPoolError(err.to_string()))?;
+        let ranges_annotations: Vec<_> = ranges_annotations.collect();
+        thread_pool.install(|| ranges_annotations.into_par_iter().map(compile_range).collect())
+    } else {
+        ranges_annotations.map(compile_range).collect()
+    };
+    // Errors are returned by the order of the statements, as if compiled on a single thread.
+    let compiled_ranges = compiled_ranges.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+    Ok(link_ranges(
+        program.statements.len(),
+        zip_eq(ranges, compiled_ranges),
+        config.optimization_level,
+    ))
+}
+
+/// Merges the casm compiled from the given ranges of statements of a program, in the given order,
+/// into a single program. The statements outside the ranges have no code - their offset is the end
+/// of the code.
+fn link_ranges(
+    n_statements: usize,
+    compiled_ranges: impl IntoIterator<Item = (Range<usize>, CompiledStatements)>,
+    optimization_level: OptimizationLevel,
+) -> CairoProgram {
+    let mut instructions = Vec::new();
+    let mut relocations: Vec<RelocationEntry> = Vec::new();
+
+    // Maps statement_idx to program_offset, for the statements in the ranges.
+    let mut statement_offsets = vec![None; n_statements];
+    let mut statement_variables = vec![vec![]; n_statements];
+
     let mut program_offset: usize = 0;
 
+    for (statements, compiled_range) in compiled_ranges {
+        relocations.extend(compiled_range.relocations.into_iter().map(|entry| RelocationEntry {
+            instruction_idx: instructions.len() + entry.instruction_idx,
+            relocation: entry.relocation,
+        }));
+        instructions.extend(compiled_range.instructions);
+        for (statement_id, offset, variables) in
+            izip!(statements, compiled_range.statement_offsets, compiled_range.statement_variables)
+        {
+            statement_offsets[statement_id] = Some(program_offset + offset);
+            statement_variables[statement_id] = variables;
+        }
+        program_offset += compiled_range.code_size;
+    }
+
+    // Push the final offset at the end of `statement_offsets`, with no available variables.
+    let mut statement_offsets: Vec<usize> = statement_offsets
+        .into_iter()
+        .map(|offset| offset.unwrap_or(program_offset))
+        .chain([program_offset])
+        .collect();
+    statement_variables.push(vec![]);
+
+    if optimization_level >= OptimizationLevel::O1 {
+        optimize_instructions(&mut instructions, &mut relocations, &mut statement_offsets);
+    }
+    relocate_instructions(&relocations, &statement_offsets, &mut instructions);
+
+    CairoProgram {
+        instructions,
+        debug_info: CairoProgramDebugInfo {
+            sierra_statement_info: zip_eq(statement_offsets, statement_variables)
+                .map(|(code_offset, variables)| SierraStatementDebugInfo { code_offset, variables })
+                .collect(),
+        },
+    }
+}
+
+/// A compiler of the functions of a Sierra program to casm on demand, for compiling only the
+/// functions reachable from some of the functions of the program.
+///
+/// The casm of each function is compiled once, and is reused by the later compilations that
+/// include it. The functions are compiled on the calling thread.
+pub struct FunctionsCompiler {
+    program: Program,
+    metadata: Metadata,
+    registry: ProgramRegistry<CoreType, CoreLibfunc>,
+    type_sizes: TypeSizeMap,
+    gas_usage_check: bool,
+    optimization_level: OptimizationLevel,
+    /// The ranges of statements that can be compiled independently.
+    ranges: Vec<Range<usize>>,
+    /// The casm compiled from each of the ranges, if already compiled.
+    compiled_ranges: Vec<Option<CompiledStatements>>,
+}
+impl FunctionsCompiler {
+    pub fn new(
+        program: Program,
+        metadata: Metadata,
+        gas_usage_check: bool,
+        optimization_level: OptimizationLevel,
+    ) -> Result<Self, Box<CompilationError>> {
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new_with_ap_change(
+            &program,
+            metadata.ap_change_info.function_ap_change.clone(),
+        )
+        .map_err(CompilationError::ProgramRegistryError)?;
+        let type_sizes = get_type_size_map(&program, &registry)
+            .ok_or(CompilationError::FailedBuildingTypeInformation)?;
+        let ranges = independent_statement_ranges(&program);
+        let compiled_ranges = ranges.iter().map(|_| None).collect();
+        let compiler = Self {
+            program,
+            metadata,
+            registry,
+            type_sizes,
+            gas_usage_check,
+            optimization_level,
+            ranges,
+            compiled_ranges,
+        };
+        // Validates the entry points of the functions before any of them is compiled.
+        compiler.annotations()?;
+        Ok(compiler)
+    }
+
+    /// Compiles the functions with the given ids, and the functions they call, directly or
+    /// indirectly, in the order of the program. The statements of the other functions have no
+    /// code - see [link_ranges].
+    pub fn compile(
+        &mut self,
+        function_ids: &[FunctionId],
+    ) -> Result<CairoProgram, Box<CompilationError>> {
+        let mut range_idxs_stack: Vec<usize> = function_ids
+            .iter()
+            .map(|id| {
+                let function = self
+                    .registry
+                    .get_function(id)
+                    .map_err(CompilationError::ProgramRegistryError)?;
+                Ok(self.range_idx(function.entry_point))
+            })
+            .collect::<Result<_, Box<CompilationError>>>()?;
+        // The annotations of the ranges, created on the first range that is not compiled yet.
+        let mut ranges_annotations: Option<Vec<Option<ProgramAnnotations>>> = None;
+        let mut included = vec![false; self.ranges.len()];
+        while let Some(range_idx) = range_idxs_stack.pop() {
+            if std::mem::replace(&mut included[range_idx], true) {
+                continue;
+            }
+            if self.compiled_ranges[range_idx].is_none() {
+                let ranges_annotations = match &mut ranges_annotations {
+                    Some(ranges_annotations) => ranges_annotations,
+                    None => ranges_annotations.insert(self.annotations()?),
+                };
+                let compiled_range = compile_statements(
+                    &self.program,
+                    self.ranges[range_idx].clone(),
+                    ranges_annotations[range_idx].take().unwrap(),
+                    &self.registry,
+                    &self.metadata,
+                    &self.type_sizes,
+                )?;
+                self.compiled_ranges[range_idx] = Some(compiled_range);
+            }
+            let range = &self.ranges[range_idx];
+            // The only relocations to other ranges are of the calls to the functions starting them.
+            for entry in &self.compiled_ranges[range_idx].as_ref().unwrap().relocations {
+                if let Relocation::RelativeStatementId(target) = entry.relocation {
+                    if !range.contains(&target.0) {
+                        range_idxs_stack.push(self.range_idx(target));
+                    }
+                }
+            }
+        }
+
+        let compiled_ranges = included.into_iter().enumerate().filter(|(_, included)| *included);
+        Ok(link_ranges(
+            self.program.statements.len(),
+            compiled_ranges.map(|(range_idx, _)| {
+                let range = self.ranges[range_idx].clone();
+                (range, self.compiled_ranges[range_idx].clone().unwrap())
+            }),
+            self.optimization_level,
+        ))
+    }
+
+    /// Returns the program whose functions are compiled.
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Returns the metadata the functions are compiled with.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Returns the annotations of the entry points of the functions, split by the ranges.
+    fn annotations(&self) -> Result<Vec<Option<ProgramAnnotations>>, Box<CompilationError>> {
+        let program_annotations = ProgramAnnotations::create(
+            self.program.statements.len(),
+            &self.program.funcs,
+            &self.metadata,
+            self.gas_usage_check,
+            &self.type_sizes,
+        )
+        .map_err(|err| Box::new(err.into()))?;
+        Ok(program_annotations.split(&self.ranges).into_iter().map(Some).collect())
+    }
+
+    /// Returns the index of the range containing the given statement.
+    fn range_idx(&self, statement_idx: StatementIdx) -> usize {
+        self.ranges.partition_point(|range| range.end <= statement_idx.0)
+    }
+}
+
+/// The casm compiled from a range of statements of a program.
+#[derive(Clone)]
+struct CompiledStatements {
+    instructions: Vec<Instruction>,
+    /// The relocations of the instructions, by their index in `instructions`.
+    relocations: Vec<RelocationEntry>,
+    /// The offset of the code of each statement, relative to the code of the range.
+    statement_offsets: Vec<usize>,
+    /// The variables available at the start of each statement.
+    statement_variables: Vec<Vec<VariableDebugInfo>>,
+    /// The size of the code of the range.
+    code_size: usize,
+}
+
+/// Splits the statements of a program into consecutive ranges that can be compiled independently:
+/// each range starts at the entry point of a function (or at the first statement), and no
+/// statement branches to a statement of another range.
+fn independent_statement_ranges(program: &Program) -> Vec<Range<usize>> {
+    let n_statements = program.statements.len();
+    let mut is_entry_point = vec![false; n_statements];
+    for func in &program.funcs {
+        if let Some(is_entry_point) = is_entry_point.get_mut(func.entry_point.0) {
+            *is_entry_point = true;
+        }
+    }
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut range_start = 0;
+    // The end of the statements the current range must include, for its branches to stay in it.
+    let mut range_min_end = 0;
     for (statement_id, statement) in program.statements.iter().enumerate() {
+        if is_entry_point[statement_id] && statement_id >= range_min_end && statement_id > 0 {
+            ranges.push(range_start..statement_id);
+            range_start = statement_id;
+        }
+        range_min_end = range_min_end.max(statement_id + 1);
+        let Statement::Invocation(invocation) = statement else { continue };
+        for branch in &invocation.branches {
+            let target = StatementIdx(statement_id).next(&branch.target).0;
+            // A branch to a previous range merges the ranges from it to the current range.
+            while target < range_start {
+                range_start = ranges.pop().unwrap().start;
+            }
+            range_min_end = range_min_end.max((target + 1).min(n_statements));
+        }
+    }
+    ranges.push(range_start..n_statements);
+    ranges
+}
+
+/// Compiles the statements in the given range, given their annotations.
+fn compile_statements(
+    program: &Program,
+    statements: Range<usize>,
+    mut program_annotations: ProgramAnnotations,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    metadata: &Metadata,
+    type_sizes: &TypeSizeMap,
+) -> Result<CompiledStatements, Box<CompilationError>> {
+    let mut instructions = Vec::new();
+    let mut relocations: Vec<RelocationEntry> = Vec::new();
+    let mut statement_offsets = Vec::with_capacity(statements.len());
+    let mut statement_variables = Vec::with_capacity(statements.len());
+
+    let mut program_offset: usize = 0;
+
+    for statement_id in statements {
+        let statement = &program.statements[statement_id];
         let statement_idx = StatementIdx(statement_id);
         statement_offsets.push(program_offset);
         match statement {
@@ -139,7 +479,12 @@ pub fn compile(
                 let (annotations, return_refs) = program_annotations
                     .get_annotations_after_take_args(statement_idx, ref_ids.iter())
                     .map_err(|err| Box::new(err.into()))?;
-                return_refs.iter().for_each(|r| r.validate(&type_sizes));
+                return_refs.iter().for_each(|r| r.validate(type_sizes));
+                statement_variables.push(variables_debug_info(
+                    ref_ids,
+                    &return_refs,
+                    &annotations.refs,
+                ));
 
                 if let Some(var_id) = annotations.refs.keys().next() {
                     return Err(Box::new(CompilationError::DanglingReferences {
@@ -186,9 +531,14 @@ pub fn compile(
                 check_types_match(&invoke_refs, &param_types).map_err(|error| {
                     Box::new(AnnotationError::ReferencesError { statement_idx, error }.into())
                 })?;
-                invoke_refs.iter().for_each(|r| r.validate(&type_sizes));
+                invoke_refs.iter().for_each(|r| r.validate(type_sizes));
+                statement_variables.push(variables_debug_info(
+                    &invocation.args,
+                    &invoke_refs,
+                    &annotations.refs,
+                ));
                 let compiled_invocation = compile_invocation(
-                    ProgramInfo { metadata, type_sizes: &type_sizes },
+                    ProgramInfo { metadata, type_sizes },
                     invocation,
                     libfunc,
                     statement_idx,
@@ -222,7 +572,7 @@ pub fn compile(
                     let destination_statement_idx = statement_idx.next(&branch_info.target);
                     if branching_libfunc
                         && !is_branch_align(
-                            &registry,
+                            registry,
                             &program.statements[destination_statement_idx.0],
                         )?
                     {
@@ -247,22 +597,34 @@ pub fn compile(
         }
     }
 
-    // Push the final offset at the end of `statement_offsets`.
-    statement_offsets.push(program_offset);
-
-    relocate_instructions(&relocations, &statement_offsets, &mut instructions);
-
-    Ok(CairoProgram {
+    Ok(CompiledStatements {
         instructions,
-        debug_info: CairoProgramDebugInfo {
-            sierra_statement_info: statement_offsets
-                .into_iter()
-                .map(|code_offset| SierraStatementDebugInfo { code_offset })
-                .collect(),
-        },
+        relocations,
+        statement_offsets,
+        statement_variables,
+        code_size: program_offset,
     })
 }
 
+/// Returns the debug information of the variables available at the start of a statement: its
+/// arguments, taken as the given references, and the other variables, remaining in `refs`.
+fn variables_debug_info(
+    arg_ids: &[VarId],
+    arg_refs: &[ReferenceValue],
+    refs: &StatementRefs,
+) -> Vec<VariableDebugInfo> {
+    let mut variables = zip_eq(arg_ids, arg_refs)
+        .chain(refs.iter())
+        .map(|(id, reference)| VariableDebugInfo {
+            id: id.clone(),
+            ty: reference.ty.clone(),
+            expression: reference.expression.clone(),
+        })
+        .collect::<Vec<_>>();
+    variables.sort_by_key(|variable| variable.id.id);
+    variables
+}
+
 /// Returns true if `statement` is an invocation of the branch_align libfunc.
 fn is_branch_align(
     registry: &ProgramRegistry<CoreType, CoreLibfunc>,
@@ -3,7 +3,9 @@ use indoc::indoc;
 use pretty_assertions;
 use test_case::test_case;
 
-use crate::compiler::compile;
+use crate::compiler::{
+    compile, compile_with_config, independent_statement_ranges, CompilationConfig,
+};
 use crate::test_utils::{build_metadata, read_sierra_example_file, strip_comments_and_linebreaks};
 
 #[test_case(indoc! {"
@@ -90,16 +92,14 @@ use crate::test_utils::{build_metadata, read_sierra_example_file, strip_comments
                 ret;
 
                 // foo:
-                ap += 0;
                 jmp rel 5 if [fp + -4] != 0;
                 [ap + 0] = [fp + -3], ap++;
                 [ap + 0] = [fp + -3], ap++;
                 ret;
-                jmp rel 2;
                 [fp + -4] = [ap + 0] + [fp + -3], ap++;
                 [ap + 0] = [ap + -1] * 2, ap++;
                 [ap + 0] = [fp + -3], ap++;
-                call rel -13;
+                call rel -9;
                 ret;
 
                 // box_and_back:
@@ -807,3 +807,70 @@ fn compiler_errors(sierra_code: &str, expected_result: &str) {
         expected_result
     );
 }
+
+#[test]
+fn test_independent_statement_ranges() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+
+            libfunc jump = jump;
+
+            return([1]);            // #0
+            jump() { 3() };         // #1
+            return([1]);            // #2
+            return([1]);            // #3
+            return([1]);            // #4
+            return([1]);            // #5
+            jump() { 5() };         // #6
+            return([1]);            // #7
+
+            foo@0([1]: felt252) -> (felt252);
+            bar@1([1]: felt252) -> (felt252);
+            jumped_to_from_bar@3([1]: felt252) -> (felt252);
+            baz@4([1]: felt252) -> (felt252);
+            jumped_to_from_qux@5([1]: felt252) -> (felt252);
+            qux@6([1]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    pretty_assertions::assert_eq!(
+        independent_statement_ranges(&program),
+        vec![0..1, 1..4, 4..5, 5..8]
+    );
+}
+
+#[test]
+fn test_compile_on_multiple_threads() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+
+            libfunc felt252_dup = dup<felt252>;
+            libfunc felt252_add = felt252_add;
+            libfunc store_temp_felt252 = store_temp<felt252>;
+            libfunc call_double = function_call<user@double>;
+
+            felt252_dup([1]) -> ([1], [2]);
+            felt252_add([1], [2]) -> ([3]);
+            store_temp_felt252([3]) -> ([3]);
+            return([3]);
+            store_temp_felt252([1]) -> ([1]);
+            call_double([1]) -> ([2]);
+            call_double([2]) -> ([3]);
+            return([3]);
+
+            double@0([1]: felt252) -> (felt252);
+            quadruple@4([1]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    let metadata = build_metadata(&program, false);
+    let compile_on_threads = |jobs| {
+        compile_with_config(
+            &program,
+            &metadata,
+            CompilationConfig { gas_usage_check: false, jobs, ..CompilationConfig::default() },
+        )
+        .expect("Compilation failed.")
+    };
+    pretty_assertions::assert_eq!(compile_on_threads(4), compile_on_threads(1));
+}
@@ -0,0 +1,88 @@
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra::program::{Program, Statement, StatementIdx};
+use itertools::Itertools;
+
+use crate::compiler::CairoProgram;
+
+#[cfg(test)]
+#[path = "function_sizes_test.rs"]
+mod test;
+
+/// The size of the code of a Sierra function, and of the CASM code compiled from it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FunctionSize {
+    pub id: FunctionId,
+    /// The number of Sierra statements of the function.
+    pub sierra_statements: usize,
+    /// The number of CASM instructions compiled from the function.
+    pub casm_instructions: usize,
+    /// The size of the bytecode compiled from the function, in felts.
+    pub bytecode_size: usize,
+    /// An estimate of the steps of a call to the function - the number of instructions along its
+    /// longest branch, excluding the steps of the functions it calls.
+    pub estimated_steps: usize,
+}
+
+/// Returns the sizes of the functions of a Sierra program, given the CASM program compiled from it,
+/// sorted from the most CASM instructions and then by name.
+pub fn function_sizes(program: &Program, cairo_program: &CairoProgram) -> Vec<FunctionSize> {
+    // The last entry holds the end of the program, rather than the start of a statement.
+    let statements_info = &cairo_program.debug_info.sierra_statement_info;
+    let mut statement_instructions = vec![0; program.statements.len()];
+    let mut offset = 0;
+    for instruction in &cairo_program.instructions {
+        // Statements that compile to no instructions share their offset with the statement that
+        // follows them.
+        let statement_idx = statements_info.partition_point(|info| info.code_offset <= offset) - 1;
+        statement_instructions[statement_idx] += 1;
+        offset += instruction.body.op_size();
+    }
+
+    // The code of each function spans from its entry point up to the entry point of the next one.
+    let functions =
+        program.funcs.iter().sorted_by_key(|function| function.entry_point.0).collect_vec();
+    let ends = functions
+        .iter()
+        .skip(1)
+        .map(|function| function.entry_point.0)
+        .chain([program.statements.len()])
+        .collect_vec();
+    functions
+        .into_iter()
+        .zip(ends)
+        .map(|(function, end)| {
+            let start = function.entry_point.0;
+            // The steps from each statement of the function to its end. Branches only jump
+            // forward, so the steps are computed from the last statement backwards.
+            let mut steps = vec![0; end - start];
+            for statement_idx in (start..end).rev() {
+                let branches_steps = match &program.statements[statement_idx] {
+                    Statement::Return(_) => 0,
+                    Statement::Invocation(invocation) => invocation
+                        .branches
+                        .iter()
+                        .map(|branch| StatementIdx(statement_idx).next(&branch.target).0)
+                        .filter(|target| *target > statement_idx && *target < end)
+                        .map(|target| steps[target - start])
+                        .max()
+                        .unwrap_or_default(),
+                };
+                steps[statement_idx - start] =
+                    statement_instructions[statement_idx] + branches_steps;
+            }
+            FunctionSize {
+                id: function.id.clone(),
+                sierra_statements: end - start,
+                casm_instructions: statement_instructions[start..end].iter().sum(),
+                bytecode_size: statements_info[end].code_offset
+                    - statements_info[start].code_offset,
+                estimated_steps: steps.first().copied().unwrap_or_default(),
+            }
+        })
+        .sorted_by(|a, b| {
+            b.casm_instructions
+                .cmp(&a.casm_instructions)
+                .then_with(|| a.id.to_string().cmp(&b.id.to_string()))
+        })
+        .collect()
+}
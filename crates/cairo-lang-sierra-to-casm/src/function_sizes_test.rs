@@ -0,0 +1,77 @@
+use cairo_lang_sierra::ProgramParser;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{function_sizes, FunctionSize};
+use crate::compiler::compile;
+use crate::test_utils::build_metadata;
+
+#[test]
+fn test_function_sizes() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+            type NonZeroFelt252 = NonZero<felt252>;
+
+            libfunc branch_align = branch_align;
+            libfunc felt252_add = felt252_add;
+            libfunc felt252_const_0 = felt252_const<0>;
+            libfunc felt252_dup = dup<felt252>;
+            libfunc felt252_is_zero = felt252_is_zero;
+            libfunc felt252_unwrap_non_zero = unwrap_non_zero<felt252>;
+            libfunc store_temp_felt252 = store_temp<felt252>;
+            libfunc call_foo = function_call<user@foo>;
+
+            felt252_add([1], [2]) -> ([3]);                    // #0
+            store_temp_felt252([3]) -> ([3]);                  // #1
+            return([3]);                                       // #2
+
+            felt252_dup([1]) -> ([1], [2]);                    // #3
+            store_temp_felt252([1]) -> ([1]);                  // #4
+            store_temp_felt252([2]) -> ([2]);                  // #5
+            call_foo([1], [2]) -> ([3]);                       // #6
+            return([3]);                                       // #7
+
+            felt252_is_zero([1]) { fallthrough() 13([2]) };    // #8
+            branch_align() -> ();                              // #9
+            felt252_const_0() -> ([3]);                        // #10
+            store_temp_felt252([3]) -> ([3]);                  // #11
+            return([3]);                                       // #12
+            branch_align() -> ();                              // #13
+            felt252_unwrap_non_zero([2]) -> ([2]);             // #14
+            store_temp_felt252([2]) -> ([2]);                  // #15
+            return([2]);                                       // #16
+
+            foo@0([1]: felt252, [2]: felt252) -> (felt252);
+            bar@3([1]: felt252) -> (felt252);
+            baz@8([1]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    let cairo_program = compile(&program, &build_metadata(&program, false), false).unwrap();
+    assert_eq!(
+        function_sizes(&program, &cairo_program),
+        vec![
+            FunctionSize {
+                id: "baz".into(),
+                sierra_statements: 9,
+                casm_instructions: 5,
+                bytecode_size: 7,
+                estimated_steps: 3,
+            },
+            FunctionSize {
+                id: "bar".into(),
+                sierra_statements: 5,
+                casm_instructions: 4,
+                bytecode_size: 5,
+                estimated_steps: 4,
+            },
+            FunctionSize {
+                id: "foo".into(),
+                sierra_statements: 3,
+                casm_instructions: 2,
+                bytecode_size: 2,
+                estimated_steps: 2,
+            },
+        ]
+    );
+}
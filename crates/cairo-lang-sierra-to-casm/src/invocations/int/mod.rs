@@ -1,3 +1,4 @@
+pub mod signed;
 pub mod unsigned;
 pub mod unsigned128;
 pub mod unsigned256;
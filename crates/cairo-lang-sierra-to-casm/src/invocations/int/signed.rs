@@ -0,0 +1,92 @@
+use cairo_felt::Felt252;
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_sierra::extensions::int::signed::{
+    SintConcrete, SintConstConcreteLibfunc, SintTraits,
+};
+use num_bigint::{BigInt, ToBigInt};
+
+use crate::invocations::misc::validate_under_limit;
+use crate::invocations::{
+    add_input_variables, get_non_fallthrough_statement_id, misc, CompiledInvocation,
+    CompiledInvocationBuilder, CostValidationInfo, InvocationError,
+};
+use crate::references::ReferenceExpression;
+
+/// Builds invocations for sint const values.
+pub fn build_const<TSintTraits: SintTraits>(
+    libfunc: &SintConstConcreteLibfunc<TSintTraits>,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    Ok(builder.build_only_reference_changes(
+        [ReferenceExpression::from_cell(CellExpression::Immediate(libfunc.c.into()))].into_iter(),
+    ))
+}
+
+/// Handles a sint conversion from felt252, where the sint has `BITS` bits.
+///
+/// The value is in range if, shifted by `2**(BITS - 1)`, it is smaller than `2**BITS`, which is
+/// validated as for the small uints.
+fn build_sint_from_felt252<const BITS: usize>(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value] = builder.try_get_single_cells()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let limit_value = BigInt::from(1) << BITS;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref value;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const offset = (BigInt::from(1) << (BITS - 1)) as BigInt;
+        const limit = limit_value.clone();
+        tempvar shifted_value = value + offset;
+        tempvar is_in_range;
+        hint TestLessThan {lhs: shifted_value, rhs: limit} into {dst: is_in_range};
+        jump InRange if is_in_range != 0;
+        tempvar shifted_value_minus_limit = shifted_value - limit;
+    }
+    let auxiliary_vars: [_; 5] = std::array::from_fn(|_| casm_builder.alloc_var(false));
+    validate_under_limit::<2>(
+        &mut casm_builder,
+        &(-Felt252::from(limit_value.clone())).to_biguint().to_bigint().unwrap(),
+        shifted_value_minus_limit,
+        range_check,
+        &auxiliary_vars,
+    );
+    casm_build_extend! {casm_builder,
+        InRange:
+        assert shifted_value = *(range_check++);
+        // shifted_value + 2**128 - limit < 2**128 ==> shifted_value < limit
+        const fixer_limit = ((BigInt::from(1) << 128) - limit_value) as BigInt;
+        tempvar shifted_value_upper_limit = shifted_value + fixer_limit;
+        assert shifted_value_upper_limit = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("Done", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Builds instructions for Sierra i8/i16/i32/i64/i128 operations.
+pub fn build_sint<TSintTraits: SintTraits, const BITS: usize>(
+    libfunc: &SintConcrete<TSintTraits>,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        SintConcrete::Const(libfunc) => build_const(libfunc, builder),
+        SintConcrete::Equal(_) => misc::build_cell_eq(builder),
+        SintConcrete::ToFelt252(_) => misc::build_identity(builder),
+        SintConcrete::FromFelt252(_) => build_sint_from_felt252::<BITS>(builder),
+    }
+}
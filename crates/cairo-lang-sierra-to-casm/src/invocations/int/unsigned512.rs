@@ -15,6 +15,7 @@ pub fn build(
 ) -> Result<CompiledInvocation, InvocationError> {
     match libfunc {
         Uint512Concrete::DivModU256(_) => build_u512_safe_divmod_by_u256(builder),
+        Uint512Concrete::WideMulU256(_) => build_u256_guarantee_wide_mul(builder),
     }
 }
 
@@ -206,3 +207,107 @@ fn build_u512_safe_divmod_by_u256(
         },
     ))
 }
+
+/// Generates casm instructions for `u256_guarantee_wide_mul()`.
+fn build_u256_guarantee_wide_mul(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, lhs, rhs] = builder.try_get_refs()?;
+    let [range_check] = range_check.try_unpack()?;
+    let [lhs0, lhs1] = lhs.try_unpack()?;
+    let [rhs0, rhs1] = rhs.try_unpack()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(6) range_check;
+        deref lhs0;
+        deref lhs1;
+        deref rhs0;
+        deref rhs1;
+    };
+    casm_build_extend! {casm_builder,
+        const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+        // Fixes a carry in [0, 3) to be in [0, 2**128).
+        const carry_range_fixer = u128::MAX - 2;
+        let orig_range_check = range_check;
+
+        // Multiply the limbs in hints. The products are validated by the returned guarantees.
+        ap += 8;
+        tempvar l0r0_low;
+        tempvar l0r0_high;
+        hint WideMul128 { lhs: lhs0, rhs: rhs0 } into { low: l0r0_low, high: l0r0_high };
+        tempvar l0r1_low;
+        tempvar l0r1_high;
+        hint WideMul128 { lhs: lhs0, rhs: rhs1 } into { low: l0r1_low, high: l0r1_high };
+        tempvar l1r0_low;
+        tempvar l1r0_high;
+        hint WideMul128 { lhs: lhs1, rhs: rhs0 } into { low: l1r0_low, high: l1r0_high };
+        tempvar l1r1_low;
+        tempvar l1r1_high;
+        hint WideMul128 { lhs: lhs1, rhs: rhs1 } into { low: l1r1_low, high: l1r1_high };
+    }
+    casm_build_extend! {casm_builder,
+        // limb0 is `l0r0_low`.
+        // Calculate limb1, and the carry to limb2.
+        tempvar part0 = l0r0_high + l0r1_low;
+        tempvar limb1_with_carry = part0 + l1r0_low;
+        tempvar carry1;
+        tempvar limb1;
+        hint DivMod {
+            lhs: limb1_with_carry,
+            rhs: u128_limit
+        } into { quotient: carry1, remainder: limb1 };
+        // Verify that `carry1` is in [0, 3) and `limb1` is in [0, 2**128). Since
+        // `limb1_with_carry` is the sum of 3 u128s, this validates the division without
+        // wraparound.
+        tempvar fixed_carry1 = carry1 + carry_range_fixer;
+        assert fixed_carry1 = *(range_check++);
+        assert carry1 = *(range_check++);
+        assert limb1 = *(range_check++);
+        tempvar shifted_carry1 = carry1 * u128_limit;
+        assert limb1_with_carry = shifted_carry1 + limb1;
+
+        // Calculate limb2, and the carry to limb3.
+        tempvar part0 = carry1 + l0r1_high;
+        tempvar part1 = part0 + l1r0_high;
+        tempvar limb2_with_carry = part1 + l1r1_low;
+        tempvar carry2;
+        tempvar limb2;
+        hint DivMod {
+            lhs: limb2_with_carry,
+            rhs: u128_limit
+        } into { quotient: carry2, remainder: limb2 };
+        // Verify that `carry2` is in [0, 3) and `limb2` is in [0, 2**128). Since
+        // `limb2_with_carry` is at most `2 + 3 * (2**128 - 1)`, this validates the division
+        // without wraparound.
+        tempvar fixed_carry2 = carry2 + carry_range_fixer;
+        assert fixed_carry2 = *(range_check++);
+        assert carry2 = *(range_check++);
+        assert limb2 = *(range_check++);
+        tempvar shifted_carry2 = carry2 * u128_limit;
+        assert limb2_with_carry = shifted_carry2 + limb2;
+
+        // Calculate limb3. As the product is smaller than 2**512, no further carry is possible.
+        tempvar limb3 = carry2 + l1r1_high;
+    };
+
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [(
+            "Fallthrough",
+            &[
+                &[range_check],
+                &[l0r0_low, limb1, limb2, limb3],
+                &[lhs0, rhs0, l0r0_high, l0r0_low],
+                &[lhs0, rhs1, l0r1_high, l0r1_low],
+                &[lhs1, rhs0, l1r0_high, l1r0_low],
+                &[lhs1, rhs1, l1r1_high, l1r1_low],
+            ],
+            None,
+        )],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
@@ -592,6 +592,13 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Uint128(libfunc) => int::unsigned128::build(libfunc, builder),
         CoreConcreteLibfunc::Uint256(libfunc) => int::unsigned256::build(libfunc, builder),
         CoreConcreteLibfunc::Uint512(libfunc) => int::unsigned512::build(libfunc, builder),
+        CoreConcreteLibfunc::Sint8(libfunc) => int::signed::build_sint::<_, 8>(libfunc, builder),
+        CoreConcreteLibfunc::Sint16(libfunc) => int::signed::build_sint::<_, 16>(libfunc, builder),
+        CoreConcreteLibfunc::Sint32(libfunc) => int::signed::build_sint::<_, 32>(libfunc, builder),
+        CoreConcreteLibfunc::Sint64(libfunc) => int::signed::build_sint::<_, 64>(libfunc, builder),
+        CoreConcreteLibfunc::Sint128(libfunc) => {
+            int::signed::build_sint::<_, 128>(libfunc, builder)
+        }
         CoreConcreteLibfunc::Gas(libfunc) => gas::build(libfunc, builder),
         CoreConcreteLibfunc::BranchAlign(_) => misc::build_branch_align(builder),
         CoreConcreteLibfunc::Array(libfunc) => array::build(libfunc, builder),
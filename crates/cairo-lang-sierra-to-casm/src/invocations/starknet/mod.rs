@@ -20,6 +20,7 @@ use crate::invocations::{
 mod testing;
 
 mod secp256k1;
+mod secp256r1;
 mod storage;
 
 /// Builds instructions for Sierra starknet operations.
@@ -79,6 +80,7 @@ pub fn build(
         }
         StarkNetConcreteLibfunc::Testing(libfunc) => testing::build(libfunc, builder),
         StarkNetConcreteLibfunc::Secp256K1(libfunc) => secp256k1::build(libfunc, builder),
+        StarkNetConcreteLibfunc::Secp256R1(libfunc) => secp256r1::build(libfunc, builder),
     }
 }
 
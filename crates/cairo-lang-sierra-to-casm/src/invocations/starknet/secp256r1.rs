@@ -0,0 +1,27 @@
+use cairo_lang_sierra::extensions::starknet::secp256r1::Secp256R1EcConcreteLibfunc;
+
+use super::{build_syscalls, CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+
+/// Builds instructions for Sierra secp256r1 operations.
+pub fn build(
+    libfunc: &Secp256R1EcConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Secp256R1EcConcreteLibfunc::New(_) => {
+            build_syscalls(builder, "Secp256r1EcNew", [2, 2], [2])
+        }
+        Secp256R1EcConcreteLibfunc::Add(_) => {
+            build_syscalls(builder, "Secp256r1EcAdd", [1, 1], [1])
+        }
+        Secp256R1EcConcreteLibfunc::Mul(_) => {
+            build_syscalls(builder, "Secp256r1EcMul", [1, 2], [1])
+        }
+        Secp256R1EcConcreteLibfunc::GetPointFromX(_) => {
+            build_syscalls(builder, "Secp256r1EcGetPointFromX", [2, 1], [2])
+        }
+        Secp256R1EcConcreteLibfunc::GetCoordinates(_) => {
+            build_syscalls(builder, "Secp256r1EcGetCoordinates", [1], [2, 2])
+        }
+    }
+}
@@ -72,6 +72,31 @@ pub fn build(
                 hint StarknetHint::SetSignature { start: start, end: end };
             };
         }
+        TestingConcreteLibfunc::Cheatcode(_) => {
+            let [selector, input] = builder.try_get_refs()?;
+            let [selector] = selector.try_unpack()?;
+            let [input_start, input_end] = input.try_unpack()?;
+            add_input_variables! {casm_builder,
+                deref selector;
+                deref input_start;
+                deref input_end;
+            };
+            casm_build_extend! {casm_builder,
+                tempvar output_start;
+                tempvar output_end;
+                hint StarknetHint::Cheatcode {
+                    selector: selector, input_start: input_start, input_end: input_end
+                } into {
+                    output_start: output_start, output_end: output_end
+                };
+                ap += 2;
+            };
+            return Ok(builder.build_from_casm_builder(
+                casm_builder,
+                [("Fallthrough", &[&[output_start, output_end]], None)],
+                CostValidationInfo::default(),
+            ));
+        }
     }
     casm_build_extend! {casm_builder, ap += 0; };
     Ok(builder.build_from_casm_builder(
@@ -5,10 +5,13 @@ pub mod annotations;
 #[allow(clippy::result_large_err)]
 pub mod compiler;
 pub mod environment;
+pub mod function_sizes;
 pub mod invocations;
 pub mod metadata;
+pub mod peephole;
 pub mod references;
 pub mod relocations;
+pub mod stack_usage;
 #[cfg(any(feature = "testing", test))]
 pub mod test_utils;
 pub mod type_sizes;
@@ -3,9 +3,10 @@ use cairo_lang_sierra::ids::FunctionId;
 use cairo_lang_sierra::program::Program;
 use cairo_lang_sierra_ap_change::ap_change_info::ApChangeInfo;
 use cairo_lang_sierra_ap_change::{calc_ap_changes, ApChangeError};
+use cairo_lang_sierra_gas::cost_table::CostTable;
 use cairo_lang_sierra_gas::gas_info::GasInfo;
 use cairo_lang_sierra_gas::{
-    calc_gas_postcost_info, calc_gas_precost_info, compute_precost_info, CostError,
+    calc_gas_postcost_info_with_cost_table, calc_gas_precost_info, compute_precost_info, CostError,
 };
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use thiserror::Error;
@@ -37,6 +38,29 @@ pub struct MetadataComputationConfig {
 pub fn calc_metadata(
     program: &Program,
     config: MetadataComputationConfig,
+) -> Result<Metadata, MetadataError> {
+    calc_metadata_with_cost_table(program, config, &CostTable::default())
+}
+
+/// Calculates the gas information for a Sierra program, with the constant costs priced by the given
+/// cost table.
+///
+/// The compilation relies on the default prices, so the result is only meant for evaluating the
+/// prices of the table.
+pub fn calc_gas_info_with_cost_table(
+    program: &Program,
+    config: MetadataComputationConfig,
+    cost_table: &CostTable,
+) -> Result<GasInfo, MetadataError> {
+    Ok(calc_metadata_with_cost_table(program, config, cost_table)?.gas_info)
+}
+
+/// Calculates the metadata for a Sierra program, with the constant costs priced by the given cost
+/// table.
+fn calc_metadata_with_cost_table(
+    program: &Program,
+    config: MetadataComputationConfig,
+    cost_table: &CostTable,
 ) -> Result<Metadata, MetadataError> {
     let pre_function_set_costs = config
         .function_set_costs
@@ -71,10 +95,13 @@ pub fn calc_metadata(
             )
         })
         .collect();
-    let post_gas_info =
-        calc_gas_postcost_info(program, post_function_set_costs, &pre_gas_info, |idx| {
-            ap_change_info.variable_values.get(&idx).copied().unwrap_or_default()
-        })?;
+    let post_gas_info = calc_gas_postcost_info_with_cost_table(
+        program,
+        post_function_set_costs,
+        &pre_gas_info,
+        |idx| ap_change_info.variable_values.get(&idx).copied().unwrap_or_default(),
+        cost_table,
+    )?;
 
     Ok(Metadata { ap_change_info, gas_info: pre_gas_info.combine(post_gas_info) })
 }
@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_casm::instructions::{
+    AddApInstruction, CallInstruction, Instruction, InstructionBody, JnzInstruction,
+    JumpInstruction,
+};
+use cairo_lang_casm::operand::{DerefOrImmediate, ResOperand};
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+use crate::relocations::{Relocation, RelocationEntry};
+
+#[cfg(test)]
+#[path = "peephole_test.rs"]
+mod test;
+
+/// Applies peephole optimizations to the instructions of a program, before they are relocated:
+/// * Removes `ap += 0` instructions.
+/// * Removes relative jumps to the instruction following them.
+/// * Merges an `ap += b` instruction into a directly preceding `ap += a` instruction.
+/// * Removes an assertion directly following an identical assertion.
+///
+/// Instructions with hints or `ap++` are never removed, and an instruction is never merged into
+/// the preceding one if it may be reached other than by falling through - that is, if it is the
+/// target of a jump or the beginning of a statement.
+///
+/// `relocations` and `statement_offsets` (where the last offset is the end of the program) are
+/// updated to match the optimized instructions.
+pub fn optimize_instructions(
+    instructions: &mut Vec<Instruction>,
+    relocations: &mut Vec<RelocationEntry>,
+    statement_offsets: &mut [usize],
+) {
+    while optimize_once(instructions, relocations, statement_offsets) {}
+}
+
+/// Runs a single pass of the peephole optimizations. Returns whether the instructions changed.
+fn optimize_once(
+    instructions: &mut Vec<Instruction>,
+    relocations: &mut Vec<RelocationEntry>,
+    statement_offsets: &mut [usize],
+) -> bool {
+    let offsets = instruction_offsets(instructions);
+    let relocated: HashMap<usize, &Relocation> =
+        relocations.iter().map(|entry| (entry.instruction_idx, &entry.relocation)).collect();
+
+    // The offsets the jumps lead to, by the index of the jump instruction.
+    let mut jump_targets = HashMap::new();
+    for (idx, instruction) in instructions.iter().enumerate() {
+        let value = match &instruction.body {
+            InstructionBody::AddAp(_) | InstructionBody::AssertEq(_) | InstructionBody::Ret(_) => {
+                continue;
+            }
+            InstructionBody::Call(CallInstruction {
+                target: DerefOrImmediate::Immediate(value),
+                relative: true,
+            })
+            | InstructionBody::Jump(JumpInstruction {
+                target: DerefOrImmediate::Immediate(value),
+                relative: true,
+            })
+            | InstructionBody::Jnz(JnzInstruction {
+                jump_offset: DerefOrImmediate::Immediate(value),
+                ..
+            }) => &value.value,
+            // A jump to a target unknown at compile time - the instructions can't be safely moved.
+            _ => return false,
+        };
+        // The offset the value is relative to, as the relocation adds the offset of its target
+        // relative to the instruction.
+        let base = match relocated.get(&idx) {
+            Some(Relocation::RelativeStatementId(statement_idx)) => {
+                statement_offsets[statement_idx.0]
+            }
+            Some(Relocation::EndOfProgram) => *offsets.last().unwrap(),
+            None => offsets[idx],
+        };
+        let Ok(target) = usize::try_from(BigInt::from(base) + value) else { return false };
+        if offsets.binary_search(&target).is_err() {
+            return false;
+        }
+        jump_targets.insert(idx, target);
+    }
+    let reachable_by_jump: HashSet<usize> =
+        statement_offsets.iter().chain(jump_targets.values()).copied().collect();
+
+    let mut changed = false;
+    let mut optimized: Vec<Instruction> = Vec::with_capacity(instructions.len());
+    // For each original instruction, whether it was kept, and its index in the optimized
+    // instructions - or the index of the instruction following it if it was removed.
+    let mut kept = Vec::with_capacity(instructions.len());
+    let mut new_indices = Vec::with_capacity(instructions.len() + 1);
+    let mut prev_relocated = false;
+    // Whether an instruction that may be jumped to was removed since the last kept instruction,
+    // so that jumping to it reaches the current instruction.
+    let mut removed_jump_target = false;
+    for (idx, instruction) in std::mem::take(instructions).into_iter().enumerate() {
+        new_indices.push(optimized.len());
+        let is_jump_target = removed_jump_target || reachable_by_jump.contains(&offsets[idx]);
+        let is_relocated = relocated.contains_key(&idx);
+        let prev = optimized.last_mut();
+        let remove = instruction.hints.is_empty()
+            && !instruction.inc_ap
+            && match (&instruction.body, prev) {
+                (
+                    InstructionBody::AddAp(AddApInstruction {
+                        operand: ResOperand::Immediate(imm),
+                    }),
+                    _,
+                ) if imm.value.is_zero() => true,
+                (
+                    InstructionBody::AddAp(AddApInstruction {
+                        operand: ResOperand::Immediate(imm),
+                    }),
+                    Some(Instruction {
+                        body:
+                            InstructionBody::AddAp(AddApInstruction {
+                                operand: ResOperand::Immediate(prev_imm),
+                            }),
+                        ..
+                    }),
+                ) if !is_jump_target => {
+                    prev_imm.value += &imm.value;
+                    true
+                }
+                (InstructionBody::Jump(_), _) => jump_targets[&idx] == offsets[idx + 1],
+                (InstructionBody::AssertEq(_), Some(prev))
+                    if !is_jump_target && !is_relocated && !prev_relocated =>
+                {
+                    prev.body == instruction.body && !prev.inc_ap
+                }
+                _ => false,
+            };
+        kept.push(!remove);
+        if remove {
+            changed = true;
+            removed_jump_target = is_jump_target;
+        } else {
+            optimized.push(instruction);
+            prev_relocated = is_relocated;
+            removed_jump_target = false;
+        }
+    }
+    new_indices.push(optimized.len());
+    *instructions = optimized;
+    if !changed {
+        return false;
+    }
+
+    let new_offsets = instruction_offsets(instructions);
+    let offset_mapping: HashMap<usize, usize> =
+        offsets.iter().zip(&new_indices).map(|(old, idx)| (*old, new_offsets[*idx])).collect();
+    for (idx, target) in jump_targets {
+        if !kept[idx] || relocated.contains_key(&idx) {
+            continue;
+        }
+        let new_idx = new_indices[idx];
+        let new_value = BigInt::from(offset_mapping[&target]) - new_offsets[new_idx];
+        match &mut instructions[new_idx].body {
+            InstructionBody::Call(CallInstruction {
+                target: DerefOrImmediate::Immediate(value),
+                ..
+            })
+            | InstructionBody::Jump(JumpInstruction {
+                target: DerefOrImmediate::Immediate(value),
+                ..
+            })
+            | InstructionBody::Jnz(JnzInstruction {
+                jump_offset: DerefOrImmediate::Immediate(value),
+                ..
+            }) => value.value = new_value,
+            _ => unreachable!("Only jumps have targets."),
+        }
+    }
+    for offset in statement_offsets.iter_mut() {
+        *offset = offset_mapping[offset];
+    }
+    relocations.retain(|entry| kept[entry.instruction_idx]);
+    for entry in relocations.iter_mut() {
+        entry.instruction_idx = new_indices[entry.instruction_idx];
+    }
+    true
+}
+
+/// Returns the offsets of the instructions, followed by the offset of the end of the instructions.
+fn instruction_offsets(instructions: &[Instruction]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(instructions.len() + 1);
+    let mut offset = 0;
+    offsets.push(offset);
+    for instruction in instructions {
+        offset += instruction.body.op_size();
+        offsets.push(offset);
+    }
+    offsets
+}
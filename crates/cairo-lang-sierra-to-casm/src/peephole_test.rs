@@ -0,0 +1,203 @@
+use cairo_lang_casm::casm;
+use cairo_lang_casm::inline::CasmContext;
+use cairo_lang_casm::instructions::Instruction;
+use cairo_lang_sierra::program::StatementIdx;
+use indoc::indoc;
+use itertools::join;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::optimize_instructions;
+use crate::relocations::{Relocation, RelocationEntry};
+
+/// Optimizes the instructions of `ctx`, and returns the optimized code and statement offsets.
+fn optimize(
+    ctx: CasmContext,
+    relocations: &mut Vec<RelocationEntry>,
+    statement_offsets: &[usize],
+) -> (String, Vec<usize>) {
+    let mut instructions = ctx.instructions;
+    let mut statement_offsets = statement_offsets.to_vec();
+    optimize_instructions(&mut instructions, relocations, &mut statement_offsets);
+    (join(instructions.iter().map(Instruction::to_string), "\n"), statement_offsets)
+}
+
+#[test_case(
+    casm! {
+        [ap + 0] = 1, ap++;
+        ap += 0;
+        ret;
+    },
+    &[0, 2, 4, 5],
+    indoc! {"
+        [ap + 0] = 1, ap++
+        ret"},
+    &[0, 2, 2, 3];
+    "ap += 0"
+)]
+#[test_case(
+    casm! {
+        jmp rel 2;
+        ret;
+    },
+    &[0, 3],
+    "ret",
+    &[0, 1];
+    "jump to next instruction"
+)]
+#[test_case(
+    casm! {
+        jmp rel 4 if [fp + -3] != 0;
+        ap += 0;
+        ret;
+    },
+    &[0, 5],
+    indoc! {"
+        jmp rel 2 if [fp + -3] != 0
+        ret"},
+    &[0, 3];
+    "jump over a removed instruction"
+)]
+#[test_case(
+    casm! {
+        jmp rel 4;
+        ap += 0;
+        ret;
+    },
+    &[0, 5],
+    "ret",
+    &[0, 1];
+    "jump to next instruction after removal"
+)]
+#[test_case(
+    casm! {
+        ap += 1;
+        ap += 2;
+        ret;
+    },
+    &[0, 5],
+    indoc! {"
+        ap += 3
+        ret"},
+    &[0, 3];
+    "merged ap changes"
+)]
+#[test_case(
+    casm! {
+        ap += 1;
+        ap += 2;
+        ret;
+    },
+    &[0, 2, 5],
+    indoc! {"
+        ap += 1
+        ap += 2
+        ret"},
+    &[0, 2, 5];
+    "ap change at statement start"
+)]
+#[test_case(
+    casm! {
+        [ap + 0] = [fp + -3];
+        [ap + 0] = [fp + -3];
+        ret;
+    },
+    &[0, 3],
+    indoc! {"
+        [ap + 0] = [fp + -3]
+        ret"},
+    &[0, 2];
+    "duplicate assert"
+)]
+#[test_case(
+    casm! {
+        [ap + 0] = [fp + -3], ap++;
+        [ap + 0] = [fp + -3];
+        ret;
+    },
+    &[0, 3],
+    indoc! {"
+        [ap + 0] = [fp + -3], ap++
+        [ap + 0] = [fp + -3]
+        ret"},
+    &[0, 3];
+    "assert after ap change"
+)]
+#[test_case(
+    casm! {
+        jmp rel 3 if [fp + -3] != 0;
+        [ap + 0] = [fp + -3];
+        [ap + 0] = [fp + -3];
+        ret;
+    },
+    &[0, 5],
+    indoc! {"
+        jmp rel 3 if [fp + -3] != 0
+        [ap + 0] = [fp + -3]
+        [ap + 0] = [fp + -3]
+        ret"},
+    &[0, 5];
+    "assert jumped to"
+)]
+#[test_case(
+    casm! {
+        %{ memory[ap + 0] = segments.add() %}
+        ap += 0;
+        ret;
+    },
+    &[0, 3],
+    indoc! {"
+        %{ memory[ap + 0] = segments.add() %}
+        ap += 0
+        ret"},
+    &[0, 3];
+    "instruction with hints"
+)]
+fn test_optimize(
+    ctx: CasmContext,
+    statement_offsets: &[usize],
+    expected_code: &str,
+    expected_statement_offsets: &[usize],
+) {
+    assert_eq!(
+        optimize(ctx, &mut vec![], statement_offsets),
+        (expected_code.to_string(), expected_statement_offsets.to_vec())
+    );
+}
+
+#[test]
+fn test_optimize_relocations() {
+    let ctx = casm! {
+        ap += 0;
+        call rel 0;
+        jmp rel 0;
+        ret;
+    };
+    let mut relocations = vec![
+        RelocationEntry {
+            instruction_idx: 1,
+            relocation: Relocation::RelativeStatementId(StatementIdx(0)),
+        },
+        RelocationEntry {
+            instruction_idx: 2,
+            relocation: Relocation::RelativeStatementId(StatementIdx(2)),
+        },
+    ];
+    assert_eq!(
+        optimize(ctx, &mut relocations, &[0, 4, 6, 7]),
+        (
+            indoc! {"
+                call rel 0
+                ret"}
+            .to_string(),
+            vec![0, 2, 2, 3]
+        )
+    );
+    assert_eq!(
+        relocations,
+        vec![RelocationEntry {
+            instruction_idx: 0,
+            relocation: Relocation::RelativeStatementId(StatementIdx(0)),
+        }]
+    );
+}
@@ -8,7 +8,7 @@ use cairo_lang_sierra_gas::objects::ConstCost;
 
 type CodeOffset = usize;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Relocation {
     /// Adds program_offset(StatementIdx) and subtracts the program offset of the casm instruction
     /// that is being relocated.
@@ -76,7 +76,7 @@ impl Relocation {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RelocationEntry {
     /// The index of the casm instruction that needs to be relocated.
     pub instruction_idx: CodeOffset,
@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra::program::{Program, Statement};
+use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use itertools::Itertools;
+
+use crate::metadata::Metadata;
+
+#[cfg(test)]
+#[path = "stack_usage_test.rs"]
+mod test;
+
+/// The depth of the deepest chain of calls a Sierra function makes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CallDepth {
+    /// The function makes at most this number of nested calls - 0 if it calls no function.
+    Bounded(usize),
+    /// The function may recurse, so the depth of its calls is not statically bounded.
+    Unbounded {
+        /// A cycle of calls reachable from the function, starting and ending at the same function.
+        recursion: Vec<FunctionId>,
+    },
+}
+impl Display for CallDepth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallDepth::Bounded(depth) => write!(f, "{depth}"),
+            CallDepth::Unbounded { recursion } => {
+                write!(f, "unbounded (recursion: {})", recursion.iter().join(" -> "))
+            }
+        }
+    }
+}
+
+/// The stack usage of a call to a Sierra function, including the functions it calls.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StackUsage {
+    pub id: FunctionId,
+    /// The worst-case ap change of a call to the function, or `None` if it is not statically
+    /// known - e.g. for a function that disables ap tracking.
+    pub ap_change: Option<usize>,
+    /// The depth of the deepest chain of calls the function makes.
+    pub call_depth: CallDepth,
+}
+impl Display for StackUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.ap_change {
+            Some(ap_change) => write!(f, "{}: ap change {ap_change}", self.id)?,
+            None => write!(f, "{}: ap change unknown", self.id)?,
+        }
+        write!(f, ", call depth {}", self.call_depth)
+    }
+}
+
+/// Returns the stack usage of each function of a Sierra program, in the order of its declaration.
+pub fn stack_usage(
+    program: &Program,
+    metadata: &Metadata,
+) -> Result<Vec<StackUsage>, Box<ProgramRegistryError>> {
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new_with_ap_change(
+        program,
+        metadata.ap_change_info.function_ap_change.clone(),
+    )?;
+    let function_indices: UnorderedHashMap<_, _> = program
+        .funcs
+        .iter()
+        .enumerate()
+        .map(|(idx, function)| (function.id.clone(), idx))
+        .collect();
+
+    // The code of each function spans from its entry point up to the entry point of the next one.
+    let functions = program
+        .funcs
+        .iter()
+        .enumerate()
+        .sorted_by_key(|(_, function)| function.entry_point.0)
+        .collect_vec();
+    let ends = functions
+        .iter()
+        .skip(1)
+        .map(|(_, function)| function.entry_point.0)
+        .chain([program.statements.len()])
+        .collect_vec();
+    let mut callees = vec![OrderedHashSet::<usize>::default(); program.funcs.len()];
+    for ((idx, function), end) in functions.into_iter().zip(ends) {
+        for statement in &program.statements[function.entry_point.0..end] {
+            let Statement::Invocation(invocation) = statement else { continue };
+            if let CoreConcreteLibfunc::FunctionCall(libfunc) =
+                registry.get_libfunc(&invocation.libfunc_id)?
+            {
+                callees[idx].insert(function_indices[libfunc.function.id.clone()]);
+            }
+        }
+    }
+
+    let mut helper = CallDepthHelper { callees, depths: vec![None; program.funcs.len()] };
+    Ok(program
+        .funcs
+        .iter()
+        .enumerate()
+        .map(|(idx, function)| StackUsage {
+            id: function.id.clone(),
+            ap_change: metadata.ap_change_info.function_ap_change.get(&function.id).copied(),
+            call_depth: match helper.call_depth(idx, &mut vec![]) {
+                Ok(depth) => CallDepth::Bounded(depth),
+                Err(cycle) => CallDepth::Unbounded {
+                    recursion: cycle.into_iter().map(|idx| program.funcs[idx].id.clone()).collect(),
+                },
+            },
+        })
+        .collect())
+}
+
+/// Helper for computing the call depths of the functions of a program, by their indices.
+struct CallDepthHelper {
+    /// The functions called by each function.
+    callees: Vec<OrderedHashSet<usize>>,
+    /// The computed call depth of each function, or the cycle of calls reachable from it.
+    depths: Vec<Option<Result<usize, Vec<usize>>>>,
+}
+impl CallDepthHelper {
+    /// Returns the call depth of a function, or a cycle of calls reachable from it. `stack` holds
+    /// the chain of calls currently being visited.
+    fn call_depth(&mut self, idx: usize, stack: &mut Vec<usize>) -> Result<usize, Vec<usize>> {
+        if let Some(depth) = &self.depths[idx] {
+            return depth.clone();
+        }
+        if let Some(position) = stack.iter().position(|caller| *caller == idx) {
+            return Err(stack[position..].iter().copied().chain([idx]).collect());
+        }
+        stack.push(idx);
+        let depth: Result<usize, Vec<usize>> = self.callees[idx]
+            .clone()
+            .into_iter()
+            .try_fold(0, |depth, callee| Ok(depth.max(self.call_depth(callee, stack)? + 1)));
+        stack.pop();
+        self.depths[idx] = Some(depth.clone());
+        depth
+    }
+}
@@ -0,0 +1,68 @@
+use cairo_lang_sierra::ProgramParser;
+use cairo_lang_sierra_ap_change::ap_change_info::ApChangeInfo;
+use cairo_lang_sierra_gas::gas_info::GasInfo;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{stack_usage, CallDepth, StackUsage};
+use crate::metadata::Metadata;
+
+#[test]
+fn test_stack_usage() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+
+            libfunc store_temp_felt252 = store_temp<felt252>;
+            libfunc call_foo = function_call<user@foo>;
+            libfunc call_bar = function_call<user@bar>;
+            libfunc call_rec = function_call<user@rec>;
+
+            store_temp_felt252([1]) -> ([1]);   // #0
+            return([1]);                        // #1
+
+            call_foo([1]) -> ([1]);             // #2
+            return([1]);                        // #3
+
+            call_bar([1]) -> ([1]);             // #4
+            call_foo([1]) -> ([1]);             // #5
+            return([1]);                        // #6
+
+            call_rec([1]) -> ([1]);             // #7
+            return([1]);                        // #8
+
+            call_rec([1]) -> ([1]);             // #9
+            return([1]);                        // #10
+
+            foo@0([1]: felt252) -> (felt252);
+            bar@2([1]: felt252) -> (felt252);
+            baz@4([1]: felt252) -> (felt252);
+            rec@7([1]: felt252) -> (felt252);
+            qux@9([1]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    let metadata = Metadata {
+        ap_change_info: ApChangeInfo {
+            variable_values: Default::default(),
+            function_ap_change: [("foo".into(), 1), ("bar".into(), 3), ("baz".into(), 6)]
+                .into_iter()
+                .collect(),
+        },
+        gas_info: GasInfo {
+            variable_values: Default::default(),
+            function_costs: Default::default(),
+        },
+    };
+    let unbounded = || CallDepth::Unbounded { recursion: vec!["rec".into(), "rec".into()] };
+    assert_eq!(
+        stack_usage(&program, &metadata).unwrap(),
+        vec![
+            StackUsage { id: "foo".into(), ap_change: Some(1), call_depth: CallDepth::Bounded(0) },
+            StackUsage { id: "bar".into(), ap_change: Some(3), call_depth: CallDepth::Bounded(1) },
+            StackUsage { id: "baz".into(), ap_change: Some(6), call_depth: CallDepth::Bounded(2) },
+            StackUsage { id: "rec".into(), ap_change: None, call_depth: unbounded() },
+            StackUsage { id: "qux".into(), ap_change: None, call_depth: unbounded() },
+        ]
+    );
+    assert_eq!(unbounded().to_string(), "unbounded (recursion: rec -> rec)");
+}
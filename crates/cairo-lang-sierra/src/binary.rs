@@ -0,0 +1,509 @@
+//! A compact binary encoding of Sierra programs.
+//!
+//! The encoding starts with [BINARY_MAGIC] and [BINARY_FORMAT_VERSION], followed by a table of the
+//! strings of the program - the generic ids and the debug names - each of which is stored once, and
+//! by the program itself, in which the strings are referred to by their index in the table. All the
+//! integers are encoded as LEB128 varints, so the small ids assigned by the compiler take a single
+//! byte, and the ids that are the hash of their debug name are not stored at all.
+//! The debug names the decoder can recover are not stored either: the debug name of a declaration
+//! that is the display of its long id, and the debug name of a used type or libfunc that is the
+//! debug name of its declaration.
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use num_bigint::{BigInt, BigUint};
+use smol_str::SmolStr;
+use thiserror::Error;
+
+use crate::ids::{
+    ConcreteLibfuncId, ConcreteTypeId, FunctionId, GenericLibfuncId, GenericTypeId, UserTypeId,
+    VarId,
+};
+use crate::program::{
+    BranchInfo, BranchTarget, ConcreteLibfuncLongId, ConcreteTypeLongId, DeclaredTypeInfo,
+    Function, FunctionSignature, GenericArg, Invocation, LibfuncDeclaration, Param, Program,
+    Statement, StatementIdx, TypeDeclaration,
+};
+
+#[cfg(test)]
+#[path = "binary_test.rs"]
+mod test;
+
+/// The bytes every binary encoded program starts with.
+pub const BINARY_MAGIC: &[u8; 4] = b"SRBN";
+/// The version of the binary encoding, following [BINARY_MAGIC].
+pub const BINARY_FORMAT_VERSION: u8 = 2;
+
+/// Errors encountered while decoding a binary encoded program.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum BinaryDecodeError {
+    #[error("The input is not a binary encoded Sierra program.")]
+    InvalidMagic,
+    #[error("Unsupported binary format version {0}.")]
+    UnsupportedVersion(u8),
+    #[error("Unexpected end of input.")]
+    UnexpectedEnd,
+    #[error("A varint does not fit in 64 bits.")]
+    VarintOverflow,
+    #[error("Invalid UTF-8 string.")]
+    InvalidString,
+    #[error("String index {0} is out of the string table.")]
+    InvalidStringIndex(u64),
+    #[error("Id {0} has no declaration to take its debug name from.")]
+    UndeclaredId(u64),
+    #[error("Invalid {kind} tag {tag}.")]
+    InvalidTag { kind: &'static str, tag: u64 },
+    #[error("{0} unexpected bytes after the end of the program.")]
+    TrailingBytes(usize),
+}
+
+/// Encodes a program in the binary encoding, keeping the debug names of its ids.
+pub fn program_to_bytes(program: &Program) -> Vec<u8> {
+    let mut encoder = Encoder::default();
+    encoder.program(program);
+    let mut bytes = BINARY_MAGIC.to_vec();
+    bytes.push(BINARY_FORMAT_VERSION);
+    write_varint(&mut bytes, encoder.strings.len() as u64);
+    for s in encoder.strings.keys() {
+        write_varint(&mut bytes, s.len() as u64);
+        bytes.extend_from_slice(s.as_bytes());
+    }
+    bytes.extend(encoder.body);
+    bytes
+}
+
+/// Decodes a program encoded by [program_to_bytes].
+pub fn program_from_bytes(bytes: &[u8]) -> Result<Program, BinaryDecodeError> {
+    let rest = bytes.strip_prefix(BINARY_MAGIC).ok_or(BinaryDecodeError::InvalidMagic)?;
+    let mut decoder = Decoder {
+        input: rest,
+        strings: vec![],
+        type_names: UnorderedHashMap::default(),
+        libfunc_names: UnorderedHashMap::default(),
+    };
+    let version = decoder.byte()?;
+    if version != BINARY_FORMAT_VERSION {
+        return Err(BinaryDecodeError::UnsupportedVersion(version));
+    }
+    decoder.strings = decoder.vec(|decoder| {
+        let len = decoder.usize()?;
+        let s = std::str::from_utf8(decoder.bytes(len)?)
+            .map_err(|_| BinaryDecodeError::InvalidString)?;
+        Ok(SmolStr::new(s))
+    })?;
+    let program = decoder.program()?;
+    if !decoder.input.is_empty() {
+        return Err(BinaryDecodeError::TrailingBytes(decoder.input.len()));
+    }
+    Ok(program)
+}
+
+/// Writes `value` as a LEB128 varint.
+fn write_varint(output: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        output.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+/// Encodes the body of a program, interning its strings.
+#[derive(Default)]
+struct Encoder {
+    /// The strings of the program, by their index in the string table.
+    strings: OrderedHashMap<SmolStr, u64>,
+    /// The debug names of the declared types, by their ids.
+    type_names: UnorderedHashMap<u64, SmolStr>,
+    /// The debug names of the declared libfuncs, by their ids.
+    libfunc_names: UnorderedHashMap<u64, SmolStr>,
+    body: Vec<u8>,
+}
+impl Encoder {
+    fn varint(&mut self, value: u64) {
+        write_varint(&mut self.body, value);
+    }
+
+    fn usize(&mut self, value: usize) {
+        self.varint(value as u64);
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.body.push(value as u8);
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.usize(bytes.len());
+        self.body.extend_from_slice(bytes);
+    }
+
+    /// Returns the index of the string in the string table, adding it if it is not there yet.
+    fn intern(&mut self, s: &SmolStr) -> u64 {
+        let next_index = self.strings.len() as u64;
+        *self.strings.entry(s.clone()).or_insert(next_index)
+    }
+
+    fn string(&mut self, s: &SmolStr) {
+        let index = self.intern(s);
+        self.varint(index);
+    }
+
+    /// Encodes the id of a debug name, as its string index plus 1 (or as 0 for no debug name),
+    /// shifted left by a bit set if the id differs from the hash of the debug name.
+    /// The id itself follows unless it is the hash of the debug name, as for the ids parsed from
+    /// their names.
+    /// A debug name that is `known_name`, which the decoder recovers by itself, is encoded as 0,
+    /// followed by the id.
+    fn id(&mut self, id: u64, debug_name: &Option<SmolStr>, known_name: Option<&SmolStr>) {
+        match debug_name {
+            Some(name) if const_fnv1a_hash::fnv1a_hash_str_64(name) == id => {
+                let index = self.intern(name);
+                self.varint((index + 1) << 1);
+            }
+            Some(name) if Some(name) == known_name => {
+                self.varint(0);
+                self.varint(id);
+            }
+            _ => {
+                let name_ref = debug_name.as_ref().map_or(0, |name| self.intern(name) + 1);
+                self.varint(name_ref << 1 | 1);
+                self.varint(id);
+            }
+        }
+    }
+
+    /// Encodes an optional debug name as 0, or as its string index plus 1.
+    fn debug_name(&mut self, debug_name: &Option<SmolStr>) {
+        let name_ref = debug_name.as_ref().map_or(0, |name| self.intern(name) + 1);
+        self.varint(name_ref);
+    }
+
+    fn vec<T>(&mut self, items: &[T], mut encode: impl FnMut(&mut Self, &T)) {
+        self.usize(items.len());
+        for item in items {
+            encode(self, item);
+        }
+    }
+
+    fn type_id(&mut self, id: &ConcreteTypeId) {
+        let declared_name = self.type_names.get(&id.id).cloned();
+        self.id(id.id, &id.debug_name, declared_name.as_ref());
+    }
+
+    fn libfunc_id(&mut self, id: &ConcreteLibfuncId) {
+        let declared_name = self.libfunc_names.get(&id.id).cloned();
+        self.id(id.id, &id.debug_name, declared_name.as_ref());
+    }
+
+    fn function_id(&mut self, id: &FunctionId) {
+        self.id(id.id, &id.debug_name, None);
+    }
+
+    fn var_id(&mut self, id: &VarId) {
+        self.id(id.id, &id.debug_name, None);
+    }
+
+    fn generic_arg(&mut self, arg: &GenericArg) {
+        match arg {
+            GenericArg::UserType(id) => {
+                self.varint(0);
+                self.bytes(&id.id.to_bytes_le());
+                self.debug_name(&id.debug_name);
+            }
+            GenericArg::Type(id) => {
+                self.varint(1);
+                self.type_id(id);
+            }
+            GenericArg::Value(value) => {
+                self.varint(2);
+                self.bytes(&value.to_signed_bytes_le());
+            }
+            GenericArg::UserFunc(id) => {
+                self.varint(3);
+                self.function_id(id);
+            }
+            GenericArg::Libfunc(id) => {
+                self.varint(4);
+                self.libfunc_id(id);
+            }
+        }
+    }
+
+    fn program(&mut self, program: &Program) {
+        self.vec(&program.type_declarations, |encoder, declaration| {
+            let TypeDeclaration { id, long_id, declared_type_info } = declaration;
+            encoder.string(&long_id.generic_id.0);
+            encoder.vec(&long_id.generic_args, Self::generic_arg);
+            encoder.id(id.id, &id.debug_name, Some(&long_id.to_string().into()));
+            match declared_type_info {
+                Some(DeclaredTypeInfo { storable, droppable, duplicatable, size }) => {
+                    encoder.bool(true);
+                    encoder.bool(*storable);
+                    encoder.bool(*droppable);
+                    encoder.bool(*duplicatable);
+                    // Zigzag encoding, so that small negative sizes are encoded as small varints.
+                    encoder.varint(((*size << 1) ^ (*size >> 15)) as u16 as u64);
+                }
+                None => encoder.bool(false),
+            }
+            if let Some(name) = &id.debug_name {
+                encoder.type_names.insert(id.id, name.clone());
+            }
+        });
+        self.vec(&program.libfunc_declarations, |encoder, declaration| {
+            let LibfuncDeclaration { id, long_id } = declaration;
+            encoder.string(&long_id.generic_id.0);
+            encoder.vec(&long_id.generic_args, Self::generic_arg);
+            encoder.id(id.id, &id.debug_name, Some(&long_id.to_string().into()));
+            if let Some(name) = &id.debug_name {
+                encoder.libfunc_names.insert(id.id, name.clone());
+            }
+        });
+        self.vec(&program.statements, |encoder, statement| match statement {
+            Statement::Invocation(Invocation { libfunc_id, args, branches }) => {
+                encoder.varint(0);
+                encoder.libfunc_id(libfunc_id);
+                encoder.vec(args, Self::var_id);
+                encoder.vec(branches, |encoder, BranchInfo { target, results }| {
+                    // A fallthrough is encoded as 0, and a jump as its target plus 1.
+                    match target {
+                        BranchTarget::Fallthrough => encoder.varint(0),
+                        BranchTarget::Statement(idx) => encoder.usize(idx.0 + 1),
+                    }
+                    encoder.vec(results, Self::var_id);
+                });
+            }
+            Statement::Return(vars) => {
+                encoder.varint(1);
+                encoder.vec(vars, Self::var_id);
+            }
+        });
+        self.vec(&program.funcs, |encoder, func| {
+            encoder.function_id(&func.id);
+            encoder.vec(&func.signature.param_types, Self::type_id);
+            encoder.vec(&func.signature.ret_types, Self::type_id);
+            encoder.vec(&func.params, |encoder, Param { id, ty }| {
+                encoder.var_id(id);
+                encoder.type_id(ty);
+            });
+            encoder.usize(func.entry_point.0);
+        });
+    }
+}
+
+/// Decodes the body of a program, given its string table.
+struct Decoder<'a> {
+    input: &'a [u8],
+    strings: Vec<SmolStr>,
+    /// The debug names of the declared types, by their ids.
+    type_names: UnorderedHashMap<u64, SmolStr>,
+    /// The debug names of the declared libfuncs, by their ids.
+    libfunc_names: UnorderedHashMap<u64, SmolStr>,
+}
+impl<'a> Decoder<'a> {
+    fn byte(&mut self) -> Result<u8, BinaryDecodeError> {
+        let (first, rest) = self.input.split_first().ok_or(BinaryDecodeError::UnexpectedEnd)?;
+        self.input = rest;
+        Ok(*first)
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], BinaryDecodeError> {
+        if self.input.len() < len {
+            return Err(BinaryDecodeError::UnexpectedEnd);
+        }
+        let (bytes, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(bytes)
+    }
+
+    fn varint(&mut self) -> Result<u64, BinaryDecodeError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            let bits = u64::from(byte & 0x7f);
+            if bits << shift >> shift != bits {
+                return Err(BinaryDecodeError::VarintOverflow);
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(BinaryDecodeError::VarintOverflow)
+    }
+
+    fn usize(&mut self) -> Result<usize, BinaryDecodeError> {
+        self.varint()?.try_into().map_err(|_| BinaryDecodeError::VarintOverflow)
+    }
+
+    fn bool(&mut self) -> Result<bool, BinaryDecodeError> {
+        match self.byte()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(BinaryDecodeError::InvalidTag { kind: "bool", tag: tag.into() }),
+        }
+    }
+
+    fn length_prefixed_bytes(&mut self) -> Result<&'a [u8], BinaryDecodeError> {
+        let len = self.usize()?;
+        self.bytes(len)
+    }
+
+    fn string_at(&self, index: u64) -> Result<SmolStr, BinaryDecodeError> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.strings.get(index))
+            .cloned()
+            .ok_or(BinaryDecodeError::InvalidStringIndex(index))
+    }
+
+    fn string(&mut self) -> Result<SmolStr, BinaryDecodeError> {
+        let index = self.varint()?;
+        self.string_at(index)
+    }
+
+    fn debug_name(&mut self) -> Result<Option<SmolStr>, BinaryDecodeError> {
+        match self.varint()? {
+            0 => Ok(None),
+            name_ref => self.string_at(name_ref - 1).map(Some),
+        }
+    }
+
+    /// Decodes an id encoded by [Encoder::id], returning it along with its debug name.
+    /// The known debug name of the id, if any, is given by `known_name`.
+    fn id(
+        &mut self,
+        known_name: impl FnOnce(&Self, u64) -> Option<SmolStr>,
+    ) -> Result<(u64, Option<SmolStr>), BinaryDecodeError> {
+        let tag = self.varint()?;
+        if tag == 0 {
+            let id = self.varint()?;
+            let name = known_name(self, id).ok_or(BinaryDecodeError::UndeclaredId(id))?;
+            return Ok((id, Some(name)));
+        }
+        let debug_name = match tag >> 1 {
+            0 => None,
+            name_ref => Some(self.string_at(name_ref - 1)?),
+        };
+        let id = match (&debug_name, tag & 1) {
+            (Some(name), 0) => const_fnv1a_hash::fnv1a_hash_str_64(name),
+            _ => self.varint()?,
+        };
+        Ok((id, debug_name))
+    }
+
+    fn vec<T>(
+        &mut self,
+        mut decode: impl FnMut(&mut Self) -> Result<T, BinaryDecodeError>,
+    ) -> Result<Vec<T>, BinaryDecodeError> {
+        let len = self.usize()?;
+        // Every item takes at least a byte, which bounds the allocation for corrupted lengths.
+        let mut items = Vec::with_capacity(len.min(self.input.len()));
+        for _ in 0..len {
+            items.push(decode(self)?);
+        }
+        Ok(items)
+    }
+
+    fn type_id(&mut self) -> Result<ConcreteTypeId, BinaryDecodeError> {
+        let (id, debug_name) = self.id(|decoder, id| decoder.type_names.get(&id).cloned())?;
+        Ok(ConcreteTypeId { id, debug_name })
+    }
+
+    fn libfunc_id(&mut self) -> Result<ConcreteLibfuncId, BinaryDecodeError> {
+        let (id, debug_name) = self.id(|decoder, id| decoder.libfunc_names.get(&id).cloned())?;
+        Ok(ConcreteLibfuncId { id, debug_name })
+    }
+
+    fn function_id(&mut self) -> Result<FunctionId, BinaryDecodeError> {
+        let (id, debug_name) = self.id(|_, _| None)?;
+        Ok(FunctionId { id, debug_name })
+    }
+
+    fn var_id(&mut self) -> Result<VarId, BinaryDecodeError> {
+        let (id, debug_name) = self.id(|_, _| None)?;
+        Ok(VarId { id, debug_name })
+    }
+
+    fn generic_arg(&mut self) -> Result<GenericArg, BinaryDecodeError> {
+        Ok(match self.varint()? {
+            0 => {
+                let id = BigUint::from_bytes_le(self.length_prefixed_bytes()?);
+                GenericArg::UserType(UserTypeId { id, debug_name: self.debug_name()? })
+            }
+            1 => GenericArg::Type(self.type_id()?),
+            2 => GenericArg::Value(BigInt::from_signed_bytes_le(self.length_prefixed_bytes()?)),
+            3 => GenericArg::UserFunc(self.function_id()?),
+            4 => GenericArg::Libfunc(self.libfunc_id()?),
+            tag => return Err(BinaryDecodeError::InvalidTag { kind: "generic arg", tag }),
+        })
+    }
+
+    fn program(&mut self) -> Result<Program, BinaryDecodeError> {
+        let type_declarations = self.vec(|decoder| {
+            let long_id = ConcreteTypeLongId {
+                generic_id: GenericTypeId(decoder.string()?),
+                generic_args: decoder.vec(Self::generic_arg)?,
+            };
+            let (id, debug_name) = decoder.id(|_, _| Some(long_id.to_string().into()))?;
+            if let Some(name) = &debug_name {
+                decoder.type_names.insert(id, name.clone());
+            }
+            let id = ConcreteTypeId { id, debug_name };
+            let declared_type_info = if decoder.bool()? {
+                let storable = decoder.bool()?;
+                let droppable = decoder.bool()?;
+                let duplicatable = decoder.bool()?;
+                let zigzag = u16::try_from(decoder.varint()?)
+                    .map_err(|_| BinaryDecodeError::VarintOverflow)?;
+                let size = ((zigzag >> 1) as i16) ^ -((zigzag & 1) as i16);
+                Some(DeclaredTypeInfo { storable, droppable, duplicatable, size })
+            } else {
+                None
+            };
+            Ok(TypeDeclaration { id, long_id, declared_type_info })
+        })?;
+        let libfunc_declarations = self.vec(|decoder| {
+            let long_id = ConcreteLibfuncLongId {
+                generic_id: GenericLibfuncId(decoder.string()?),
+                generic_args: decoder.vec(Self::generic_arg)?,
+            };
+            let (id, debug_name) = decoder.id(|_, _| Some(long_id.to_string().into()))?;
+            if let Some(name) = &debug_name {
+                decoder.libfunc_names.insert(id, name.clone());
+            }
+            Ok(LibfuncDeclaration { id: ConcreteLibfuncId { id, debug_name }, long_id })
+        })?;
+        let statements = self.vec(|decoder| {
+            Ok(match decoder.varint()? {
+                0 => {
+                    let libfunc_id = decoder.libfunc_id()?;
+                    let args = decoder.vec(Self::var_id)?;
+                    let branches = decoder.vec(|decoder| {
+                        let target = match decoder.usize()? {
+                            0 => BranchTarget::Fallthrough,
+                            target => BranchTarget::Statement(StatementIdx(target - 1)),
+                        };
+                        Ok(BranchInfo { target, results: decoder.vec(Self::var_id)? })
+                    })?;
+                    Statement::Invocation(Invocation { libfunc_id, args, branches })
+                }
+                1 => Statement::Return(decoder.vec(Self::var_id)?),
+                tag => return Err(BinaryDecodeError::InvalidTag { kind: "statement", tag }),
+            })
+        })?;
+        let funcs = self.vec(|decoder| {
+            let id = decoder.function_id()?;
+            let param_types = decoder.vec(Self::type_id)?;
+            let ret_types = decoder.vec(Self::type_id)?;
+            let params = decoder
+                .vec(|decoder| Ok(Param { id: decoder.var_id()?, ty: decoder.type_id()? }))?;
+            Ok(Function {
+                id,
+                signature: FunctionSignature { param_types, ret_types },
+                params,
+                entry_point: StatementIdx(decoder.usize()?),
+            })
+        })?;
+        Ok(Program { type_declarations, libfunc_declarations, statements, funcs })
+    }
+}
@@ -0,0 +1,107 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{program_from_bytes, program_to_bytes, BinaryDecodeError, BINARY_FORMAT_VERSION};
+use crate::debug_info::DebugInfo;
+use crate::ids::{ConcreteLibfuncId, ConcreteTypeId};
+use crate::program::Program;
+use crate::ProgramParser;
+
+fn parse(code: &str) -> Program {
+    ProgramParser::new().parse(code).unwrap()
+}
+
+#[test]
+fn test_roundtrip() {
+    let program = parse(indoc! {"
+        type felt252 = felt252;
+        type [1] = NonZero<felt252>;
+        type Unit = Struct<ut@Unit> with_info {
+            storable: true,
+            droppable: true,
+            duplicatable: false,
+            size: -3,
+        };
+
+        libfunc felt252_const_minus_1 = felt252_const<-1>;
+        libfunc [1] = u256_const<340282366920938463463374607431768211456>;
+        libfunc call_foo = function_call<user@foo>;
+        libfunc apply = apply<lib@call_foo>;
+
+        felt252_const_minus_1() -> (a);
+        felt252_is_zero(a) { fallthrough() 3([5]) };
+        return (a);
+        return ([5]);
+
+        foo@0(a: felt252, [2]: [1]) -> (felt252);
+        [7]@3() -> ();
+    "});
+    let decoded = program_from_bytes(&program_to_bytes(&program)).unwrap();
+    assert_eq!(decoded, program);
+    // The debug names, ignored by the comparison of ids, are kept as well.
+    assert_eq!(decoded.to_string(), program.to_string());
+}
+
+#[test]
+fn test_strings_are_interned() {
+    let program = parse(indoc! {"
+        libfunc felt252_const_0 = felt252_const<0>;
+        libfunc felt252_const_1 = felt252_const<1>;
+        libfunc felt252_const_2 = felt252_const<2>;
+    "});
+    let bytes = program_to_bytes(&program);
+    let occurrences = bytes.windows("felt252_const".len()).filter(|w| w == b"felt252_const");
+    // Once as the generic libfunc id, and once within each of the debug names.
+    assert_eq!(occurrences.count(), 4);
+}
+
+#[test]
+fn test_recoverable_debug_names_are_not_stored() {
+    let mut program = parse(indoc! {"
+        type [0] = felt252;
+        type [1] = Array<[0]>;
+        libfunc [0] = array_new<[0]>;
+        libfunc [1] = store_temp<[1]>;
+
+        [0]() -> ([0]);
+        [1]([0]) -> ([0]);
+        return ([0]);
+    "});
+    // Named as by the compiler, which assigns small ids to the named declarations.
+    DebugInfo {
+        type_names: [(0, "felt252"), (1, "Array<felt252>")]
+            .map(|(id, name)| (ConcreteTypeId::new(id), name.into()))
+            .into(),
+        libfunc_names: [(0, "array_new<felt252>"), (1, "store_temp<Array<felt252>>")]
+            .map(|(id, name)| (ConcreteLibfuncId::new(id), name.into()))
+            .into(),
+        user_func_names: Default::default(),
+    }
+    .populate(&mut program);
+    let bytes = program_to_bytes(&program);
+    assert_eq!(program_from_bytes(&bytes).unwrap().to_string(), program.to_string());
+    // Only as the generic ids, as the debug names of the declarations are their long ids, and the
+    // debug names of the used ids are the debug names of their declarations.
+    for name in ["felt252", "Array", "store_temp"] {
+        assert_eq!(bytes.windows(name.len()).filter(|w| *w == name.as_bytes()).count(), 1);
+    }
+}
+
+#[test]
+fn test_decode_errors() {
+    let bytes = program_to_bytes(&parse("return ();"));
+    assert_eq!(program_from_bytes(b"not sierra"), Err(BinaryDecodeError::InvalidMagic));
+    let mut bad_version = bytes.clone();
+    bad_version[4] = BINARY_FORMAT_VERSION + 1;
+    assert_eq!(
+        program_from_bytes(&bad_version),
+        Err(BinaryDecodeError::UnsupportedVersion(BINARY_FORMAT_VERSION + 1))
+    );
+    assert_eq!(
+        program_from_bytes(&bytes[..bytes.len() - 1]),
+        Err(BinaryDecodeError::UnexpectedEnd)
+    );
+    let mut trailing = bytes;
+    trailing.push(0);
+    assert_eq!(program_from_bytes(&trailing), Err(BinaryDecodeError::TrailingBytes(1)));
+}
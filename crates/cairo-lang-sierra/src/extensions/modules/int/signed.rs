@@ -0,0 +1,261 @@
+use std::marker::PhantomData;
+
+use num_bigint::BigInt;
+
+use crate::define_libfunc_hierarchy;
+use crate::extensions::felt252::Felt252Type;
+use crate::extensions::lib_func::{
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::try_from_felt252::{TryFromFelt252, TryFromFelt252Libfunc};
+use crate::extensions::{
+    GenericLibfunc, NamedLibfunc, NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType,
+    OutputVarReferenceInfo, SignatureBasedConcreteLibfunc, SpecializationError,
+};
+use crate::ids::GenericTypeId;
+use crate::program::GenericArg;
+
+/// Trait for implementing signed integers.
+pub trait SintTraits: Default {
+    /// The rust matching type to this type.
+    type SintType: TryFrom<BigInt> + Into<BigInt> + Copy;
+    /// The generic type id for this type.
+    const GENERIC_TYPE_ID: GenericTypeId;
+    /// The generic libfunc id for getting a const of this type.
+    const CONST: &'static str;
+    /// The generic libfunc id for comparing equality.
+    const EQUAL: &'static str;
+    /// The generic libfunc id for conversion to felt252.
+    const TO_FELT252: &'static str;
+    /// The generic libfunc id for conversion from felt252.
+    const TRY_FROM_FELT252: &'static str;
+}
+
+#[derive(Default)]
+pub struct SintType<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericType for SintType<TSintTraits> {
+    const ID: GenericTypeId = TSintTraits::GENERIC_TYPE_ID;
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = true;
+    const DROPPABLE: bool = true;
+    const SIZE: i16 = 1;
+}
+
+/// Libfunc for creating a constant signed integer.
+#[derive(Default)]
+pub struct SintConstLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NamedLibfunc for SintConstLibfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::CONST;
+    type Concrete = SintConstConcreteLibfunc<TSintTraits>;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        _args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Const),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        match args {
+            [GenericArg::Value(c)] => Ok(Self::Concrete {
+                c: TSintTraits::SintType::try_from(c.clone())
+                    .map_err(|_| SpecializationError::UnsupportedGenericArg)?,
+                signature: <Self as NamedLibfunc>::specialize_signature(
+                    self,
+                    context.upcast(),
+                    args,
+                )?,
+            }),
+            _ => Err(SpecializationError::UnsupportedGenericArg),
+        }
+    }
+}
+
+pub struct SintConstConcreteLibfunc<TSintTraits: SintTraits> {
+    pub c: TSintTraits::SintType,
+    pub signature: LibfuncSignature,
+}
+impl<TSintTraits: SintTraits> SignatureBasedConcreteLibfunc
+    for SintConstConcreteLibfunc<TSintTraits>
+{
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for comparing sints` equality.
+#[derive(Default)]
+pub struct SintEqualLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintEqualLibfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::EQUAL;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+        let param_signatures =
+            vec![ParamSignature::new(ty.clone()), ParamSignature::new(ty).with_allow_const()];
+        let branch_signatures = (0..2)
+            .map(|_| BranchSignature {
+                vars: vec![],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            })
+            .collect();
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+
+/// Libfunc for converting a sint into a felt252.
+#[derive(Default)]
+pub struct SintToFelt252Libfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintToFelt252Libfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::TO_FELT252;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature {
+                ty: context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?,
+                allow_deferred: true,
+                allow_add_const: true,
+                allow_const: true,
+            }],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(Felt252Type::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for attempting to convert a felt252 into a sint, succeeding if the felt252 is in the
+/// range of the sint, where negative values are represented by their field element.
+#[derive(Default)]
+pub struct SintFromFelt252Trait<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> TryFromFelt252 for SintFromFelt252Trait<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::TRY_FROM_FELT252;
+    const GENERIC_TYPE_ID: GenericTypeId = TSintTraits::GENERIC_TYPE_ID;
+}
+
+pub type SintFromFelt252Libfunc<T> = TryFromFelt252Libfunc<SintFromFelt252Trait<T>>;
+
+define_libfunc_hierarchy! {
+    pub enum SintLibfunc<TSintTraits: SintTraits> {
+        Const(SintConstLibfunc<TSintTraits>),
+        Equal(SintEqualLibfunc<TSintTraits>),
+        ToFelt252(SintToFelt252Libfunc<TSintTraits>),
+        FromFelt252(SintFromFelt252Libfunc<TSintTraits>),
+    }, SintConcrete
+}
+
+#[derive(Default)]
+pub struct Sint8Traits;
+
+impl SintTraits for Sint8Traits {
+    type SintType = i8;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i8");
+    const CONST: &'static str = "i8_const";
+    const EQUAL: &'static str = "i8_eq";
+    const TO_FELT252: &'static str = "i8_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i8_try_from_felt252";
+}
+
+/// Type for i8.
+pub type Sint8Type = SintType<Sint8Traits>;
+pub type Sint8Libfunc = SintLibfunc<Sint8Traits>;
+pub type Sint8Concrete = <Sint8Libfunc as GenericLibfunc>::Concrete;
+
+#[derive(Default)]
+pub struct Sint16Traits;
+
+impl SintTraits for Sint16Traits {
+    type SintType = i16;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i16");
+    const CONST: &'static str = "i16_const";
+    const EQUAL: &'static str = "i16_eq";
+    const TO_FELT252: &'static str = "i16_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i16_try_from_felt252";
+}
+
+/// Type for i16.
+pub type Sint16Type = SintType<Sint16Traits>;
+pub type Sint16Libfunc = SintLibfunc<Sint16Traits>;
+pub type Sint16Concrete = <Sint16Libfunc as GenericLibfunc>::Concrete;
+
+#[derive(Default)]
+pub struct Sint32Traits;
+
+impl SintTraits for Sint32Traits {
+    type SintType = i32;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i32");
+    const CONST: &'static str = "i32_const";
+    const EQUAL: &'static str = "i32_eq";
+    const TO_FELT252: &'static str = "i32_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i32_try_from_felt252";
+}
+
+/// Type for i32.
+pub type Sint32Type = SintType<Sint32Traits>;
+pub type Sint32Libfunc = SintLibfunc<Sint32Traits>;
+pub type Sint32Concrete = <Sint32Libfunc as GenericLibfunc>::Concrete;
+
+#[derive(Default)]
+pub struct Sint64Traits;
+
+impl SintTraits for Sint64Traits {
+    type SintType = i64;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i64");
+    const CONST: &'static str = "i64_const";
+    const EQUAL: &'static str = "i64_eq";
+    const TO_FELT252: &'static str = "i64_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i64_try_from_felt252";
+}
+
+/// Type for i64.
+pub type Sint64Type = SintType<Sint64Traits>;
+pub type Sint64Libfunc = SintLibfunc<Sint64Traits>;
+pub type Sint64Concrete = <Sint64Libfunc as GenericLibfunc>::Concrete;
+
+#[derive(Default)]
+pub struct Sint128Traits;
+
+impl SintTraits for Sint128Traits {
+    type SintType = i128;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i128");
+    const CONST: &'static str = "i128_const";
+    const EQUAL: &'static str = "i128_eq";
+    const TO_FELT252: &'static str = "i128_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i128_try_from_felt252";
+}
+
+/// Type for i128.
+pub type Sint128Type = SintType<Sint128Traits>;
+pub type Sint128Libfunc = SintLibfunc<Sint128Traits>;
+pub type Sint128Concrete = <Sint128Libfunc as GenericLibfunc>::Concrete;
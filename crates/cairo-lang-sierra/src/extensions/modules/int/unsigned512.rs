@@ -17,6 +17,7 @@ use crate::program::GenericArg;
 define_libfunc_hierarchy! {
     pub enum Uint512Libfunc {
         DivModU256(Uint512DivmodU256Libfunc),
+        WideMulU256(Uint512WideMulU256Libfunc),
     }, Uint512Concrete
 }
 
@@ -72,6 +73,55 @@ impl NoGenericArgsGenericLibfunc for Uint512DivmodU256Libfunc {
     }
 }
 
+// Wide multiplication.
+#[derive(Default)]
+pub struct Uint512WideMulU256Libfunc;
+impl NoGenericArgsGenericLibfunc for Uint512WideMulU256Libfunc {
+    const STR_ID: &'static str = "u256_guarantee_wide_mul";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let u256_ty = get_u256_type(context)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let guarantee_ty = context.get_concrete_type(U128MulGuaranteeType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature::new(range_check_type.clone()).with_allow_add_const(),
+                ParamSignature::new(u256_ty.clone()),
+                ParamSignature::new(u256_ty),
+            ],
+            vec![
+                OutputVarInfo {
+                    ty: range_check_type,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                        param_idx: 0,
+                    }),
+                },
+                OutputVarInfo {
+                    ty: get_u512_type(context)?,
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+                OutputVarInfo {
+                    ty: guarantee_ty.clone(),
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+                OutputVarInfo {
+                    ty: guarantee_ty.clone(),
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+                OutputVarInfo {
+                    ty: guarantee_ty.clone(),
+                    ref_info: OutputVarReferenceInfo::SimpleDerefs,
+                },
+                OutputVarInfo { ty: guarantee_ty, ref_info: OutputVarReferenceInfo::SimpleDerefs },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+
 /// Helper for u512 type def.
 fn get_u512_type(
     context: &dyn SignatureSpecializationContext,
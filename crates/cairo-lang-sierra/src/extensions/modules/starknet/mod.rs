@@ -21,6 +21,9 @@ pub mod getter;
 pub mod secp256k1;
 use secp256k1::{Secp256K1EcLibfunc, Secp256K1EcPointType};
 
+pub mod secp256r1;
+use secp256r1::{Secp256R1EcLibfunc, Secp256R1EcPointType};
+
 pub mod testing;
 
 pub mod interoperability;
@@ -53,6 +56,7 @@ define_type_hierarchy! {
         StorageAddress(StorageAddressType),
         System(SystemType),
         Secp256K1EcPoint(Secp256K1EcPointType),
+        Secp256R1EcPoint(Secp256R1EcPointType),
     }, StarkNetTypeConcrete
 }
 
@@ -83,6 +87,7 @@ define_libfunc_hierarchy! {
          SendMessageToL1(SendMessageToL1Libfunc),
          Testing(TestingLibfunc),
          Secp256K1(Secp256K1EcLibfunc),
+         Secp256R1(Secp256R1EcLibfunc),
     }, StarkNetConcreteLibfunc
 }
 
@@ -7,10 +7,11 @@ use crate::extensions::felt252::Felt252Type;
 use crate::extensions::int::unsigned::Uint64Type;
 use crate::extensions::int::unsigned128::Uint128Type;
 use crate::extensions::lib_func::{
-    LibfuncSignature, SierraApChange, SignatureSpecializationContext,
+    LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange, SignatureSpecializationContext,
 };
 use crate::extensions::{
-    NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, SpecializationError,
+    NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
+    SpecializationError,
 };
 use crate::ids::ConcreteTypeId;
 /// Trait for implementing test setters.
@@ -151,6 +152,29 @@ impl TestSetterTraits for SetSignatureTrait {
     }
 }
 
+/// Libfunc for calling a cheatcode of the test runner, given by its selector, with a span of
+/// felt252s as input and returning a span of felt252s as output.
+#[derive(Default)]
+pub struct CheatcodeLibfunc {}
+impl NoGenericArgsGenericLibfunc for CheatcodeLibfunc {
+    const STR_ID: &'static str = "cheatcode";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let span_ty = felt252_span_ty(context)?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature::new(context.get_concrete_type(Felt252Type::id(), &[])?),
+                ParamSignature::new(span_ty.clone()),
+            ],
+            vec![OutputVarInfo { ty: span_ty, ref_info: OutputVarReferenceInfo::SimpleDerefs }],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+
 define_libfunc_hierarchy! {
     pub enum TestingLibfunc {
          SetBlockNumber(TestSetterLibfunc<SetBlockNumberTrait>),
@@ -165,5 +189,6 @@ define_libfunc_hierarchy! {
          SetChainId(TestSetterLibfunc<SetChainIdTrait>),
          SetNonce(TestSetterLibfunc<SetNonceTrait>),
          SetSignature(TestSetterLibfunc<SetSignatureTrait>),
+         Cheatcode(CheatcodeLibfunc),
     }, TestingConcreteLibfunc
 }
@@ -0,0 +1,314 @@
+use lalrpop_util::lexer::Token;
+use lalrpop_util::ParseError;
+use thiserror::Error;
+
+use crate::parser::{
+    FunctionParser, LibfuncDeclarationParser, StatementParser, TypeDeclarationParser,
+};
+use crate::program::{Function, LibfuncDeclaration, Program, Statement, TypeDeclaration};
+
+#[cfg(test)]
+#[path = "incremental_parser_test.rs"]
+mod test;
+
+/// Human readable position inside a Sierra text, in lines and characters.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TextPosition {
+    /// Line index, 0 based.
+    pub line: usize,
+    /// Character index inside the line, 0 based.
+    pub col: usize,
+}
+impl TextPosition {
+    /// Returns the position at the end of the given text, if it starts at this position.
+    fn after(mut self, text: &str) -> Self {
+        for c in text.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.col = 0;
+            } else {
+                self.col += 1;
+            }
+        }
+        self
+    }
+}
+
+/// A span inside a Sierra text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TextSpan {
+    pub start: TextPosition,
+    pub end: TextPosition,
+}
+
+/// An error found while parsing a Sierra text.
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
+#[error("{}:{}: {message}", .span.start.line + 1, .span.start.col + 1)]
+pub struct SierraParseError {
+    pub span: TextSpan,
+    pub message: String,
+}
+
+/// A Sierra program parsed from a text that may be malformed, along with the errors found in it.
+/// The program holds all the items of the text that were parsed successfully.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartialProgram {
+    pub program: Program,
+    pub errors: Vec<SierraParseError>,
+}
+
+/// Parses a Sierra text that may be malformed, recovering from the errors in it.
+pub fn parse_partial_program(text: &str) -> PartialProgram {
+    let mut parser = IncrementalProgramParser::new();
+    parser.feed(text);
+    parser.finish()
+}
+
+/// The sections of a Sierra program, in their order in the text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+enum Section {
+    TypeDeclarations,
+    LibfuncDeclarations,
+    Statements,
+    Functions,
+}
+impl Section {
+    /// Returns the name of the items of the section.
+    fn item_name(self) -> &'static str {
+        match self {
+            Section::TypeDeclarations => "type declaration",
+            Section::LibfuncDeclarations => "libfunc declaration",
+            Section::Statements => "statement",
+            Section::Functions => "function",
+        }
+    }
+}
+
+/// An item of a Sierra program.
+enum Item {
+    TypeDeclaration(TypeDeclaration),
+    LibfuncDeclaration(LibfuncDeclaration),
+    Statement(Statement),
+    Function(Function),
+}
+
+/// Parser of a Sierra text fed to it in chunks, e.g. as it is read or received.
+///
+/// The text is parsed item by item - each declaration, statement and function ending with a `;` -
+/// as soon as the item is complete. An item that fails to parse is reported as an error and
+/// skipped, so the items following it are still parsed.
+pub struct IncrementalProgramParser {
+    /// The items parsed so far.
+    program: Program,
+    /// The errors found so far.
+    errors: Vec<SierraParseError>,
+    /// The text of the items that are not complete yet.
+    pending: String,
+    /// The position of the start of the pending text.
+    pending_start: TextPosition,
+    /// The length of the prefix of the pending text searched for the end of an item.
+    scanned: usize,
+    /// Whether the scanned prefix of the pending text ends inside a comment.
+    in_comment: bool,
+    /// The section of the last item that was parsed successfully.
+    section: Section,
+}
+impl Default for IncrementalProgramParser {
+    fn default() -> Self {
+        Self {
+            program: Program {
+                type_declarations: vec![],
+                libfunc_declarations: vec![],
+                statements: vec![],
+                funcs: vec![],
+            },
+            errors: vec![],
+            pending: String::new(),
+            pending_start: TextPosition::default(),
+            scanned: 0,
+            in_comment: false,
+            section: Section::TypeDeclarations,
+        }
+    }
+}
+impl IncrementalProgramParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the items parsed so far.
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Returns the errors found so far.
+    pub fn errors(&self) -> &[SierraParseError] {
+        &self.errors
+    }
+
+    /// Feeds the next chunk of the text, parsing the items it completes.
+    pub fn feed(&mut self, chunk: &str) {
+        self.pending.push_str(chunk);
+        while let Some(end) = self.find_item_end() {
+            let rest = self.pending.split_off(end);
+            let item = std::mem::replace(&mut self.pending, rest);
+            self.parse_item(&item);
+            self.pending_start = self.pending_start.after(&item);
+            self.scanned = 0;
+        }
+    }
+
+    /// Finishes the text, parsing its last item even if it does not end with a `;`.
+    pub fn finish(mut self) -> PartialProgram {
+        let item = std::mem::take(&mut self.pending);
+        self.parse_item(&item);
+        PartialProgram { program: self.program, errors: self.errors }
+    }
+
+    /// Returns the end of the first item of the pending text - just after its `;` - if the item is
+    /// complete.
+    fn find_item_end(&mut self) -> Option<usize> {
+        let bytes = self.pending.as_bytes();
+        while self.scanned < bytes.len() {
+            match bytes[self.scanned] {
+                b'\n' | b'\r' => self.in_comment = false,
+                _ if self.in_comment => {}
+                b';' => {
+                    self.scanned += 1;
+                    return Some(self.scanned);
+                }
+                b'/' => match bytes.get(self.scanned + 1) {
+                    Some(b'/') => self.in_comment = true,
+                    Some(_) => {}
+                    // The next chunk determines whether a comment starts here.
+                    None => return None,
+                },
+                _ => {}
+            }
+            self.scanned += 1;
+        }
+        None
+    }
+
+    /// Parses an item at the start of the pending text, adding it to the program, or its error to
+    /// the errors. Items consisting only of whitespace and comments are ignored.
+    fn parse_item(&mut self, item: &str) {
+        let Some((start, first_word)) = first_word(item) else { return };
+        let (section, result) = match first_word {
+            "type" => (
+                Section::TypeDeclarations,
+                TypeDeclarationParser::new().parse(item).map(Item::TypeDeclaration),
+            ),
+            "libfunc" => (
+                Section::LibfuncDeclarations,
+                LibfuncDeclarationParser::new().parse(item).map(Item::LibfuncDeclaration),
+            ),
+            _ => match (StatementParser::new().parse(item), FunctionParser::new().parse(item)) {
+                (Ok(statement), _) => (Section::Statements, Ok(Item::Statement(statement))),
+                (Err(_), Ok(function)) => (Section::Functions, Ok(Item::Function(function))),
+                // The error is reported by the parser that read further into the item, or by the
+                // parser of the section the item is expected in.
+                (Err(statement_error), Err(function_error)) => {
+                    let statement_location = error_location(&statement_error);
+                    let function_location = error_location(&function_error);
+                    if function_location > statement_location
+                        || (function_location == statement_location
+                            && self.section == Section::Functions)
+                    {
+                        (Section::Functions, Err(function_error))
+                    } else {
+                        (Section::Statements, Err(statement_error))
+                    }
+                }
+            },
+        };
+        let parsed = match result {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                let ((error_start, error_end), message) = describe_error(item, start, error);
+                self.add_error(item, error_start, error_end, message);
+                return;
+            }
+        };
+        if section < self.section {
+            let message = format!(
+                "A {} must precede all {}s.",
+                section.item_name(),
+                self.section.item_name()
+            );
+            self.add_error(item, start, item.len(), message);
+        } else {
+            self.section = section;
+        }
+        match parsed {
+            Item::TypeDeclaration(declaration) => self.program.type_declarations.push(declaration),
+            Item::LibfuncDeclaration(declaration) => {
+                self.program.libfunc_declarations.push(declaration)
+            }
+            Item::Statement(statement) => self.program.statements.push(statement),
+            Item::Function(function) => self.program.funcs.push(function),
+        }
+    }
+
+    /// Adds an error spanning the given byte range of an item at the start of the pending text.
+    fn add_error(&mut self, item: &str, start: usize, end: usize, message: String) {
+        let start_position = self.pending_start.after(&item[..start]);
+        let span = TextSpan { start: start_position, end: start_position.after(&item[start..end]) };
+        self.errors.push(SierraParseError { span, message });
+    }
+}
+
+/// Returns the first word of a text and its offset, skipping whitespace and comments. The word is
+/// empty if the text does not start with a label. Returns `None` if the text only consists of
+/// whitespace and comments.
+fn first_word(text: &str) -> Option<(usize, &str)> {
+    let mut rest = text.trim_start();
+    while let Some(comment) = rest.strip_prefix("//") {
+        rest = comment.find(['\n', '\r']).map_or("", |end| &comment[end..]).trim_start();
+    }
+    if rest.is_empty() {
+        return None;
+    }
+    let start = text.len() - rest.len();
+    let end = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(rest.len());
+    Some((start, &rest[..end]))
+}
+
+/// Returns the offset in an item where parsing it failed, if known.
+fn error_location(error: &ParseError<usize, Token<'_>, &'static str>) -> usize {
+    match error {
+        ParseError::InvalidToken { location } | ParseError::UnrecognizedEOF { location, .. } => {
+            *location
+        }
+        ParseError::UnrecognizedToken { token: (start, ..), .. }
+        | ParseError::ExtraToken { token: (start, ..) } => *start,
+        ParseError::User { .. } => 0,
+    }
+}
+
+/// Returns the byte range of a parse error in an item, and its description. `item_start` is the
+/// offset of the first word of the item.
+fn describe_error(
+    item: &str,
+    item_start: usize,
+    error: ParseError<usize, Token<'_>, &'static str>,
+) -> ((usize, usize), String) {
+    match error {
+        ParseError::InvalidToken { location } => {
+            let end = location + item[location..].chars().next().map_or(0, char::len_utf8);
+            ((location, end), "Invalid token.".into())
+        }
+        ParseError::UnrecognizedEOF { location, expected } => (
+            (location, location),
+            format!("Unexpected end of text, expected one of: {}.", expected.join(", ")),
+        ),
+        ParseError::UnrecognizedToken { token: (start, token, end), expected } => (
+            (start, end),
+            format!("Unexpected token `{token}`, expected one of: {}.", expected.join(", ")),
+        ),
+        ParseError::ExtraToken { token: (start, token, end) } => {
+            ((start, end), format!("Unexpected token `{token}`."))
+        }
+        ParseError::User { error } => ((item_start, item.len()), error.into()),
+    }
+}
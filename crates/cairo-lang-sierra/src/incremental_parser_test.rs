@@ -0,0 +1,104 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{parse_partial_program, IncrementalProgramParser, TextPosition, TextSpan};
+use crate::ProgramParser;
+
+const PROGRAM: &str = indoc! {"
+    type felt252 = felt252;
+    // A comment; with a semicolon.
+    libfunc felt252_add = felt252_add;
+    libfunc store_temp_felt252 = store_temp<felt252>;
+
+    felt252_add([1], [2]) -> ([3]);
+    store_temp_felt252([3]) -> ([3]);
+    return([3]);
+
+    add@0([1]: felt252, [2]: felt252) -> (felt252);
+"};
+
+#[test_case(1; "whole text")]
+#[test_case(7; "small chunks")]
+#[test_case(1000; "large chunks")]
+fn test_parse_in_chunks(n_chunks: usize) {
+    let mut parser = IncrementalProgramParser::new();
+    let chunk_size = PROGRAM.len().div_ceil(n_chunks);
+    for chunk in PROGRAM.as_bytes().chunks(chunk_size) {
+        parser.feed(std::str::from_utf8(chunk).unwrap());
+    }
+    let partial_program = parser.finish();
+    assert_eq!(partial_program.errors, vec![]);
+    assert_eq!(partial_program.program, ProgramParser::new().parse(PROGRAM).unwrap());
+}
+
+#[test]
+fn test_items_are_parsed_when_complete() {
+    let mut parser = IncrementalProgramParser::new();
+    parser.feed("type felt252 = felt252;\nlibfunc felt252_add = felt");
+    assert_eq!(parser.program().type_declarations.len(), 1);
+    assert_eq!(parser.program().libfunc_declarations.len(), 0);
+    parser.feed("252_add;\n");
+    assert_eq!(parser.program().libfunc_declarations.len(), 1);
+}
+
+#[test]
+fn test_recover_from_errors() {
+    let partial_program = parse_partial_program(indoc! {"
+        type felt252 = felt252;
+        libfunc felt252_add = felt252_add<;
+        libfunc store_temp_felt252 = store_temp<felt252>;
+        felt252_add([1], [2]) -> ([3]);
+        type u128 = u128;
+        return([3]);
+        add@0([1]: felt252, [2]: felt252) -> (felt252;
+        store_temp_felt252([3]) -> ([3])
+    "});
+    assert_eq!(
+        partial_program
+            .errors
+            .iter()
+            .map(|error| (error.span, error.to_string()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                TextSpan {
+                    start: TextPosition { line: 1, col: 34 },
+                    end: TextPosition { line: 1, col: 35 },
+                },
+                "2:35: Unexpected token `;`, expected one of: \"(\", \">\", \"@\", \"[\", \
+                 \"lib@\", \"user@\", \"ut@\", r#\"-?[1-9][0-9]*|0\"#, \
+                 r#\"[a-zA-Z_][a-zA-Z_0-9]*\"#."
+                    .into()
+            ),
+            (
+                TextSpan {
+                    start: TextPosition { line: 4, col: 0 },
+                    end: TextPosition { line: 4, col: 17 },
+                },
+                "5:1: A type declaration must precede all statements.".into()
+            ),
+            (
+                TextSpan {
+                    start: TextPosition { line: 6, col: 45 },
+                    end: TextPosition { line: 6, col: 46 },
+                },
+                "7:46: Unexpected token `;`, expected one of: \")\", \",\", \":\", \"::\", \"<\", \
+                 \">\", \"@\"."
+                    .into()
+            ),
+            (
+                TextSpan {
+                    start: TextPosition { line: 7, col: 32 },
+                    end: TextPosition { line: 7, col: 32 },
+                },
+                "8:33: Unexpected end of text, expected one of: \";\".".into()
+            ),
+        ]
+    );
+    let program = partial_program.program;
+    assert_eq!(program.type_declarations.len(), 2);
+    assert_eq!(program.libfunc_declarations.len(), 1);
+    assert_eq!(program.statements.len(), 2);
+    assert_eq!(program.funcs.len(), 0);
+}
@@ -11,16 +11,21 @@
 
 use lalrpop_util::lalrpop_mod;
 
+pub mod binary;
 pub mod debug_info;
 pub mod edit_state;
 pub mod extensions;
 pub mod fmt;
 pub mod ids;
+pub mod incremental_parser;
+pub mod link;
 pub mod program;
 pub mod program_registry;
 pub mod simulation;
 #[cfg(test)]
 mod test_utils;
+pub mod type_registry;
+pub mod validate;
 
 lalrpop_mod!(
     #[allow(clippy::all, unused_extern_crates)]
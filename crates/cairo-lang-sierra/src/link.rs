@@ -0,0 +1,277 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use smol_str::SmolStr;
+use thiserror::Error;
+
+use crate::ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId};
+use crate::program::{
+    BranchInfo, BranchTarget, ConcreteLibfuncLongId, ConcreteTypeLongId, DeclaredTypeInfo,
+    Function, FunctionSignature, GenericArg, Invocation, LibfuncDeclaration, Param, Program,
+    Statement, StatementIdx, TypeDeclaration,
+};
+
+#[cfg(test)]
+#[path = "link_test.rs"]
+mod test;
+
+/// Error found while linking Sierra programs.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum LinkError {
+    #[error("Program #{program_idx}: `{type_id}` is used before it is declared.")]
+    UndeclaredType { program_idx: usize, type_id: ConcreteTypeId },
+    #[error("Program #{program_idx}: `{libfunc_id}` is used before it is declared.")]
+    UndeclaredLibfunc { program_idx: usize, libfunc_id: ConcreteLibfuncId },
+    #[error("Program #{program_idx}: `{function_id}` is used but not declared.")]
+    UndeclaredFunction { program_idx: usize, function_id: FunctionId },
+    #[error("`{0}` is declared with different signatures in the linked programs.")]
+    FunctionSignatureMismatch(FunctionId),
+    #[error("Program #{program_idx}: #{statement_idx} is out of the program.")]
+    StatementOutOfBounds { program_idx: usize, statement_idx: StatementIdx },
+}
+
+/// Links Sierra programs, such as a contract and the precompiled libraries it uses, into a single
+/// program.
+///
+/// Identical type and libfunc declarations are declared once, and functions with the same name
+/// are assumed to be compiled from the same code - so only the first of them is kept, along with
+/// its statements. The ids of the linked program are reassigned, keeping their debug names, and its
+/// statements are reindexed accordingly.
+pub fn link_programs(programs: &[Program]) -> Result<Program, LinkError> {
+    let mut linker = Linker {
+        program: Program {
+            type_declarations: vec![],
+            libfunc_declarations: vec![],
+            statements: vec![],
+            funcs: vec![],
+        },
+        type_ids: HashMap::new(),
+        libfunc_ids: HashMap::new(),
+        named_functions: HashMap::new(),
+    };
+    for (program_idx, program) in programs.iter().enumerate() {
+        linker.link(program_idx, program)?;
+    }
+    Ok(linker.program)
+}
+
+struct Linker {
+    /// The linked program.
+    program: Program,
+    /// The ids of the types of the linked program, by their declaration.
+    type_ids: HashMap<(ConcreteTypeLongId, Option<DeclaredTypeInfo>), ConcreteTypeId>,
+    /// The ids of the libfuncs of the linked program, by their declaration.
+    libfunc_ids: HashMap<ConcreteLibfuncLongId, ConcreteLibfuncId>,
+    /// The indices of the functions of the linked program that have a debug name, by their name.
+    named_functions: HashMap<SmolStr, usize>,
+}
+impl Linker {
+    /// Links a program into the linked program.
+    fn link(&mut self, program_idx: usize, program: &Program) -> Result<(), LinkError> {
+        let mut ids = IdsMapping {
+            program_idx,
+            types: HashMap::new(),
+            libfuncs: HashMap::new(),
+            functions: HashMap::new(),
+        };
+        for declaration in &program.type_declarations {
+            let long_id = ConcreteTypeLongId {
+                generic_id: declaration.long_id.generic_id.clone(),
+                generic_args: ids.map_generic_args(&declaration.long_id.generic_args)?,
+            };
+            let id = match self.type_ids.entry((long_id, declaration.declared_type_info.clone())) {
+                Entry::Occupied(entry) => entry.get().clone(),
+                Entry::Vacant(entry) => {
+                    let id = ConcreteTypeId {
+                        id: self.program.type_declarations.len() as u64,
+                        debug_name: declaration.id.debug_name.clone(),
+                    };
+                    let (long_id, declared_type_info) = entry.key().clone();
+                    self.program.type_declarations.push(TypeDeclaration {
+                        id: id.clone(),
+                        long_id,
+                        declared_type_info,
+                    });
+                    entry.insert(id).clone()
+                }
+            };
+            ids.types.insert(declaration.id.clone(), id);
+        }
+
+        // The indices of the functions of the program added to the linked program.
+        let mut added_functions = vec![];
+        for function in &program.funcs {
+            let signature = FunctionSignature {
+                param_types: ids.map_types(&function.signature.param_types)?,
+                ret_types: ids.map_types(&function.signature.ret_types)?,
+            };
+            let existing_idx =
+                function.id.debug_name.as_ref().and_then(|name| self.named_functions.get(name));
+            if let Some(existing) = existing_idx.map(|idx| &self.program.funcs[*idx]) {
+                if existing.signature != signature {
+                    return Err(LinkError::FunctionSignatureMismatch(function.id.clone()));
+                }
+                ids.functions.insert(function.id.clone(), existing.id.clone());
+                continue;
+            }
+            let function_idx = self.program.funcs.len();
+            let id =
+                FunctionId { id: function_idx as u64, debug_name: function.id.debug_name.clone() };
+            if let Some(name) = &function.id.debug_name {
+                self.named_functions.insert(name.clone(), function_idx);
+            }
+            ids.functions.insert(function.id.clone(), id.clone());
+            let params = function
+                .params
+                .iter()
+                .map(|param| Ok(Param { id: param.id.clone(), ty: ids.map_type(&param.ty)? }))
+                .collect::<Result<_, _>>()?;
+            // The entry point is updated once the statements are linked.
+            self.program.funcs.push(Function {
+                id,
+                signature,
+                params,
+                entry_point: function.entry_point,
+            });
+            added_functions.push(function_idx);
+        }
+
+        for declaration in &program.libfunc_declarations {
+            let long_id = ConcreteLibfuncLongId {
+                generic_id: declaration.long_id.generic_id.clone(),
+                generic_args: ids.map_generic_args(&declaration.long_id.generic_args)?,
+            };
+            let id = match self.libfunc_ids.entry(long_id) {
+                Entry::Occupied(entry) => entry.get().clone(),
+                Entry::Vacant(entry) => {
+                    let id = ConcreteLibfuncId {
+                        id: self.program.libfunc_declarations.len() as u64,
+                        debug_name: declaration.id.debug_name.clone(),
+                    };
+                    self.program
+                        .libfunc_declarations
+                        .push(LibfuncDeclaration { id: id.clone(), long_id: entry.key().clone() });
+                    entry.insert(id).clone()
+                }
+            };
+            ids.libfuncs.insert(declaration.id.clone(), id);
+        }
+
+        // Only the statements of the added functions are linked.
+        let entry_points = added_functions.iter().map(|idx| self.program.funcs[*idx].entry_point);
+        let reachable = reachable_statements(program_idx, program, entry_points)?;
+        let mut new_indices = vec![None; program.statements.len()];
+        let mut next_idx = self.program.statements.len();
+        for (statement_idx, is_reachable) in reachable.iter().enumerate() {
+            if *is_reachable {
+                new_indices[statement_idx] = Some(StatementIdx(next_idx));
+                next_idx += 1;
+            }
+        }
+        for (statement, _) in program.statements.iter().zip(reachable).filter(|(_, r)| *r) {
+            self.program.statements.push(match statement {
+                Statement::Invocation(invocation) => Statement::Invocation(Invocation {
+                    libfunc_id: ids.map_libfunc(&invocation.libfunc_id)?,
+                    args: invocation.args.clone(),
+                    branches: invocation
+                        .branches
+                        .iter()
+                        .map(|branch| BranchInfo {
+                            target: match branch.target {
+                                BranchTarget::Fallthrough => BranchTarget::Fallthrough,
+                                BranchTarget::Statement(target) => {
+                                    BranchTarget::Statement(new_indices[target.0].unwrap())
+                                }
+                            },
+                            results: branch.results.clone(),
+                        })
+                        .collect(),
+                }),
+                Statement::Return(ref_ids) => Statement::Return(ref_ids.clone()),
+            });
+        }
+        for function_idx in added_functions {
+            let function = &mut self.program.funcs[function_idx];
+            function.entry_point = new_indices[function.entry_point.0].unwrap();
+        }
+        Ok(())
+    }
+}
+
+/// The mapping from the ids of a linked program to the ids of the linked program.
+struct IdsMapping {
+    /// The index of the program, for error reporting.
+    program_idx: usize,
+    types: HashMap<ConcreteTypeId, ConcreteTypeId>,
+    libfuncs: HashMap<ConcreteLibfuncId, ConcreteLibfuncId>,
+    functions: HashMap<FunctionId, FunctionId>,
+}
+impl IdsMapping {
+    fn map_type(&self, type_id: &ConcreteTypeId) -> Result<ConcreteTypeId, LinkError> {
+        self.types.get(type_id).cloned().ok_or_else(|| LinkError::UndeclaredType {
+            program_idx: self.program_idx,
+            type_id: type_id.clone(),
+        })
+    }
+
+    fn map_types(&self, type_ids: &[ConcreteTypeId]) -> Result<Vec<ConcreteTypeId>, LinkError> {
+        type_ids.iter().map(|type_id| self.map_type(type_id)).collect()
+    }
+
+    fn map_libfunc(&self, libfunc_id: &ConcreteLibfuncId) -> Result<ConcreteLibfuncId, LinkError> {
+        self.libfuncs.get(libfunc_id).cloned().ok_or_else(|| LinkError::UndeclaredLibfunc {
+            program_idx: self.program_idx,
+            libfunc_id: libfunc_id.clone(),
+        })
+    }
+
+    fn map_function(&self, function_id: &FunctionId) -> Result<FunctionId, LinkError> {
+        self.functions.get(function_id).cloned().ok_or_else(|| LinkError::UndeclaredFunction {
+            program_idx: self.program_idx,
+            function_id: function_id.clone(),
+        })
+    }
+
+    fn map_generic_args(&self, args: &[GenericArg]) -> Result<Vec<GenericArg>, LinkError> {
+        args.iter()
+            .map(|arg| {
+                Ok(match arg {
+                    GenericArg::Type(type_id) => GenericArg::Type(self.map_type(type_id)?),
+                    GenericArg::Libfunc(libfunc_id) => {
+                        GenericArg::Libfunc(self.map_libfunc(libfunc_id)?)
+                    }
+                    GenericArg::UserFunc(function_id) => {
+                        GenericArg::UserFunc(self.map_function(function_id)?)
+                    }
+                    GenericArg::UserType(_) | GenericArg::Value(_) => arg.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns whether each statement of the program is reachable from the given entry points, without
+/// following function calls.
+fn reachable_statements(
+    program_idx: usize,
+    program: &Program,
+    entry_points: impl Iterator<Item = StatementIdx>,
+) -> Result<Vec<bool>, LinkError> {
+    let mut reachable = vec![false; program.statements.len()];
+    let mut stack: Vec<_> = entry_points.collect();
+    while let Some(statement_idx) = stack.pop() {
+        let Some(statement) = program.get_statement(&statement_idx) else {
+            return Err(LinkError::StatementOutOfBounds { program_idx, statement_idx });
+        };
+        if reachable[statement_idx.0] {
+            continue;
+        }
+        reachable[statement_idx.0] = true;
+        if let Statement::Invocation(invocation) = statement {
+            stack.extend(
+                invocation.branches.iter().map(|branch| statement_idx.next(&branch.target)),
+            );
+        }
+    }
+    Ok(reachable)
+}
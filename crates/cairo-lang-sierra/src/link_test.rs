@@ -0,0 +1,108 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{link_programs, LinkError};
+use crate::program::{Program, StatementIdx};
+use crate::ProgramParser;
+
+fn parse(code: &str) -> Program {
+    ProgramParser::new().parse(code).unwrap()
+}
+
+#[test]
+fn test_link_programs() {
+    let contract = parse(indoc! {"
+        type felt252 = felt252;
+
+        libfunc felt252_dup = dup<felt252>;
+        libfunc felt252_add = felt252_add;
+        libfunc call_double = function_call<user@lib::double>;
+
+        call_double([0]) -> ([1]);
+        return([1]);
+        felt252_dup([0]) -> ([0], [1]);
+        felt252_add([0], [1]) -> ([2]);
+        return([2]);
+
+        contract::main@0([0]: felt252) -> (felt252);
+        lib::double@2([0]: felt252) -> (felt252);
+    "});
+    let library = parse(indoc! {"
+        type felt = felt252;
+
+        libfunc dup_felt = dup<felt>;
+        libfunc add_felt = felt252_add;
+        libfunc call_double = function_call<user@lib::double>;
+        libfunc jump = jump;
+
+        dup_felt([0]) -> ([0], [1]);
+        add_felt([0], [1]) -> ([2]);
+        return([2]);
+        call_double([0]) -> ([1]);
+        jump() { 5() };
+        call_double([1]) -> ([2]);
+        return([2]);
+
+        lib::double@0([0]: felt) -> (felt);
+        lib::quadruple@3([0]: felt) -> (felt);
+    "});
+    assert_eq!(
+        link_programs(&[contract, library]).unwrap().to_string(),
+        indoc! {"
+            type felt252 = felt252;
+
+            libfunc felt252_dup = dup<felt252>;
+            libfunc felt252_add = felt252_add;
+            libfunc call_double = function_call<user@lib::double>;
+            libfunc jump = jump;
+
+            call_double([0]) -> ([1]);
+            return([1]);
+            felt252_dup([0]) -> ([0], [1]);
+            felt252_add([0], [1]) -> ([2]);
+            return([2]);
+            call_double([0]) -> ([1]);
+            jump() { 7() };
+            call_double([1]) -> ([2]);
+            return([2]);
+
+            contract::main@0([0]: felt252) -> (felt252);
+            lib::double@2([0]: felt252) -> (felt252);
+            lib::quadruple@5([0]: felt252) -> (felt252);
+        "}
+    );
+}
+
+#[test_case(
+    &["foo@0([0]: felt252) -> ();"],
+    LinkError::UndeclaredType { program_idx: 0, type_id: "felt252".into() };
+    "undeclared type"
+)]
+#[test_case(
+    &["return();", "bar() -> (); return(); foo@0() -> ();"],
+    LinkError::UndeclaredLibfunc { program_idx: 1, libfunc_id: "bar".into() };
+    "undeclared libfunc"
+)]
+#[test_case(
+    &["libfunc call_foo = function_call<user@foo>;"],
+    LinkError::UndeclaredFunction { program_idx: 0, function_id: "foo".into() };
+    "undeclared function"
+)]
+#[test_case(
+    &[
+        "return(); lib::foo@0() -> ();",
+        "type felt252 = felt252; return([0]); lib::foo@0([0]: felt252) -> (felt252);",
+    ],
+    LinkError::FunctionSignatureMismatch("lib::foo".into());
+    "function signature mismatch"
+)]
+#[test_case(
+    &["return(); foo@1() -> ();"],
+    LinkError::StatementOutOfBounds { program_idx: 0, statement_idx: StatementIdx(1) };
+    "entry point out of bounds"
+)]
+fn test_link_errors(codes: &[&str], error: LinkError) {
+    let programs: Vec<_> = codes.iter().map(|code| parse(code)).collect();
+    assert_eq!(link_programs(&programs), Err(error));
+}
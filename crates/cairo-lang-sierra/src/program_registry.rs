@@ -169,7 +169,7 @@ impl<TType: GenericType> TypeSpecializationContext
 
 /// Creates the type-id to concrete type map, and the reverse map from generic-id and arguments to
 /// concrete-id.
-fn get_concrete_types_maps<TType: GenericType>(
+pub(crate) fn get_concrete_types_maps<TType: GenericType>(
     program: &Program,
 ) -> Result<(TypeMap<TType::Concrete>, ConcreteTypeIdMap<'_>), Box<ProgramRegistryError>> {
     let mut concrete_types = HashMap::new();
@@ -352,6 +352,11 @@ pub fn simulate<
         CoreConcreteLibfunc::Felt252DictEntry(_) => unimplemented!(),
         CoreConcreteLibfunc::Uint256(_) => unimplemented!(),
         CoreConcreteLibfunc::Uint512(_) => unimplemented!(),
+        CoreConcreteLibfunc::Sint8(_)
+        | CoreConcreteLibfunc::Sint16(_)
+        | CoreConcreteLibfunc::Sint32(_)
+        | CoreConcreteLibfunc::Sint64(_)
+        | CoreConcreteLibfunc::Sint128(_) => unimplemented!(),
     }
 }
 
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use crate::extensions::types::TypeInfo;
+use crate::extensions::{ConcreteType, GenericType};
+use crate::ids::ConcreteTypeId;
+use crate::program::{ConcreteTypeLongId, Program};
+use crate::program_registry::{get_concrete_types_maps, ProgramRegistryError};
+
+#[cfg(test)]
+#[path = "type_registry_test.rs"]
+mod test;
+
+/// Registry of the concrete types of a program, for querying their properties - e.g. by tools
+/// encoding calldata or laying out storage, which need the sizes and capabilities of types.
+///
+/// Unlike [crate::program_registry::ProgramRegistry], only the types of the program are
+/// specialized, so the registry is available for any program whose types are valid.
+pub struct TypeRegistry<TType: GenericType> {
+    /// Mapping ids to the concrete types represented by them.
+    concrete_types: HashMap<ConcreteTypeId, TType::Concrete>,
+    /// Mapping the long ids of the declared types to their ids.
+    type_ids: HashMap<ConcreteTypeLongId, ConcreteTypeId>,
+}
+impl<TType: GenericType> TypeRegistry<TType> {
+    /// Creates a registry of the types of the program.
+    pub fn new(program: &Program) -> Result<Self, Box<ProgramRegistryError>> {
+        let (concrete_types, _) = get_concrete_types_maps::<TType>(program)?;
+        let type_ids = program
+            .type_declarations
+            .iter()
+            .map(|declaration| (declaration.long_id.clone(), declaration.id.clone()))
+            .collect();
+        Ok(Self { concrete_types, type_ids })
+    }
+
+    /// Returns the id of the type declared with the given long id, e.g. as parsed by
+    /// [crate::ConcreteTypeLongIdParser] from `Array<felt252>`, where the generic arguments are
+    /// types of the program.
+    pub fn type_id(&self, long_id: &ConcreteTypeLongId) -> Option<&ConcreteTypeId> {
+        self.type_ids.get(long_id)
+    }
+
+    /// Gets a type of the program.
+    pub fn get_type(
+        &self,
+        id: &ConcreteTypeId,
+    ) -> Result<&TType::Concrete, Box<ProgramRegistryError>> {
+        self.concrete_types
+            .get(id)
+            .ok_or_else(|| Box::new(ProgramRegistryError::MissingType(id.clone())))
+    }
+
+    /// Returns all the properties of a type of the program.
+    pub fn info(&self, id: &ConcreteTypeId) -> Result<&TypeInfo, Box<ProgramRegistryError>> {
+        Ok(self.get_type(id)?.info())
+    }
+
+    /// Returns the number of memory cells a value of the type takes.
+    pub fn size(&self, id: &ConcreteTypeId) -> Result<i16, Box<ProgramRegistryError>> {
+        Ok(self.info(id)?.size)
+    }
+
+    /// Returns whether values of the type can be duplicated.
+    pub fn is_duplicatable(&self, id: &ConcreteTypeId) -> Result<bool, Box<ProgramRegistryError>> {
+        Ok(self.info(id)?.duplicatable)
+    }
+
+    /// Returns whether values of the type can be dropped.
+    pub fn is_droppable(&self, id: &ConcreteTypeId) -> Result<bool, Box<ProgramRegistryError>> {
+        Ok(self.info(id)?.droppable)
+    }
+
+    /// Returns whether values of the type can be stored, and so passed to and returned from
+    /// functions.
+    pub fn is_storable(&self, id: &ConcreteTypeId) -> Result<bool, Box<ProgramRegistryError>> {
+        Ok(self.info(id)?.storable)
+    }
+}
@@ -0,0 +1,59 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::TypeRegistry;
+use crate::extensions::core::CoreType;
+use crate::program_registry::ProgramRegistryError;
+use crate::{ConcreteTypeLongIdParser, ProgramParser};
+
+fn type_registry() -> TypeRegistry<CoreType> {
+    TypeRegistry::new(
+        &ProgramParser::new()
+            .parse(indoc! {"
+                type felt252 = felt252;
+                type u128 = u128;
+                type GasBuiltin = GasBuiltin;
+                type ArrayFelt252 = Array<felt252>;
+                type NonZeroU128 = NonZero<u128>;
+                type UninitializedFelt252 = Uninitialized<felt252>;
+                type Pair = Struct<ut@Pair, felt252, u128>;
+            "})
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test_case("felt252", 1, true, true, true; "felt252")]
+#[test_case("GasBuiltin", 1, false, false, true; "builtin")]
+#[test_case("Array<felt252>", 2, false, true, true; "array")]
+#[test_case("NonZero<u128>", 1, true, true, true; "non zero")]
+#[test_case("Uninitialized<felt252>", 0, false, true, false; "uninitialized")]
+#[test_case("Struct<ut@Pair, felt252, u128>", 2, true, true, true; "struct of pair")]
+fn test_type_properties(
+    long_id: &str,
+    size: i16,
+    duplicatable: bool,
+    droppable: bool,
+    storable: bool,
+) {
+    let registry = type_registry();
+    let long_id = ConcreteTypeLongIdParser::new().parse(long_id).unwrap();
+    let id = registry.type_id(&long_id).unwrap();
+    assert_eq!(registry.info(id).unwrap().long_id, long_id);
+    assert_eq!(registry.size(id), Ok(size));
+    assert_eq!(registry.is_duplicatable(id), Ok(duplicatable));
+    assert_eq!(registry.is_droppable(id), Ok(droppable));
+    assert_eq!(registry.is_storable(id), Ok(storable));
+}
+
+#[test]
+fn test_missing_type() {
+    let registry = type_registry();
+    let long_id = ConcreteTypeLongIdParser::new().parse("Array<u128>").unwrap();
+    assert_eq!(registry.type_id(&long_id), None);
+    assert_eq!(
+        registry.size(&"ArrayU128".into()),
+        Err(Box::new(ProgramRegistryError::MissingType("ArrayU128".into())))
+    );
+}
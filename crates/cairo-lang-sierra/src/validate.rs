@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+
+use itertools::izip;
+use thiserror::Error;
+
+use crate::edit_state::{put_results, take_args, EditStateError};
+use crate::extensions::ap_tracking::ApTrackingConcreteLibfunc;
+use crate::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use crate::extensions::lib_func::SierraApChange;
+use crate::extensions::ConcreteLibfunc;
+use crate::ids::{ConcreteTypeId, FunctionId, VarId};
+use crate::program::{BranchTarget, Invocation, Program, Statement, StatementIdx};
+use crate::program_registry::{ProgramRegistry, ProgramRegistryError};
+
+#[cfg(test)]
+#[path = "validate_test.rs"]
+mod test;
+
+/// Error found while validating a Sierra program.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    #[error("error from the program registry")]
+    ProgramRegistryError(#[from] Box<ProgramRegistryError>),
+    #[error("The entry point of `{0}` is out of the program.")]
+    EntryPointOutOfBounds(FunctionId),
+    #[error("#{source_statement_idx}->#{destination_statement_idx}: Jumped out of the program.")]
+    BranchTargetOutOfBounds {
+        source_statement_idx: StatementIdx,
+        destination_statement_idx: StatementIdx,
+    },
+    #[error("#{statement_idx}: Belongs to both `{function_id}` and `{other_function_id}`.")]
+    InconsistentFunction {
+        statement_idx: StatementIdx,
+        function_id: FunctionId,
+        other_function_id: FunctionId,
+    },
+    #[error("#{statement_idx}: Invocation mismatched to libfunc.")]
+    LibfuncInvocationMismatch { statement_idx: StatementIdx },
+    #[error("#{statement_idx}: {var_id} is undefined.")]
+    MissingVariable { statement_idx: StatementIdx, var_id: VarId },
+    #[error("#{statement_idx}: {var_id} was overridden.")]
+    VariableOverride { statement_idx: StatementIdx, var_id: VarId },
+    #[error("#{statement_idx}: Expected {var_id} to be of type `{expected}`, got `{actual}`.")]
+    ArgumentTypeMismatch {
+        statement_idx: StatementIdx,
+        var_id: VarId,
+        expected: ConcreteTypeId,
+        actual: ConcreteTypeId,
+    },
+    #[error("#{statement_idx}: The returned types do not match the signature of `{function_id}`.")]
+    ReturnTypesMismatch { statement_idx: StatementIdx, function_id: FunctionId },
+    #[error("#{statement_idx}: {var_id} is dangling.")]
+    DanglingVariable { statement_idx: StatementIdx, var_id: VarId },
+    #[error("#{statement_idx}: Reached with different variables from different statements.")]
+    InconsistentVariables { statement_idx: StatementIdx },
+    #[error("#{source_statement_idx}->#{destination_statement_idx}: Expected branch align.")]
+    ExpectedBranchAlign {
+        source_statement_idx: StatementIdx,
+        destination_statement_idx: StatementIdx,
+    },
+    #[error("#{statement_idx}: Attempting to enable ap tracking when already enabled.")]
+    ApTrackingAlreadyEnabled { statement_idx: StatementIdx },
+    #[error("#{statement_idx}: Reached with and without ap tracking from different statements.")]
+    InconsistentApTracking { statement_idx: StatementIdx },
+}
+
+/// Validates a Sierra program without compiling it: that its declarations are valid, that the
+/// branches of each function jump within the function, that each invocation and return matches
+/// the types of the variables it uses, that no variable is left dangling, and that the variables
+/// and ap tracking are consistent wherever branches converge.
+///
+/// The ap changes of the functions are not computed, so the validation of the ap tracking is
+/// conservative - the ap tracking after a call to a function is assumed to be consistent with
+/// both the enabled and disabled ap tracking.
+pub fn validate(program: &Program) -> Result<(), ValidationError> {
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
+    let mut validator =
+        Validator { program, states: vec![None; program.statements.len()], queue: vec![] };
+    for (function_idx, function) in program.funcs.iter().enumerate() {
+        if function.entry_point.0 >= program.statements.len() {
+            return Err(ValidationError::EntryPointOutOfBounds(function.id.clone()));
+        }
+        let vars = put_results(
+            HashMap::new(),
+            function.params.iter().map(|param| (&param.id, param.ty.clone())),
+        )
+        .map_err(|error| edit_state_error(function.entry_point, error))?;
+        validator.merge(
+            function.entry_point,
+            StatementState { function_idx, vars, ap_tracking: ApTracking::Enabled },
+        )?;
+    }
+    while let Some(statement_idx) = validator.queue.pop() {
+        let state = validator.states[statement_idx.0].clone().unwrap();
+        match &program.statements[statement_idx.0] {
+            Statement::Return(ref_ids) => {
+                let function = &program.funcs[state.function_idx];
+                let (remaining, types) = take_args(state.vars, ref_ids.iter())
+                    .map_err(|error| edit_state_error(statement_idx, error))?;
+                if types != function.signature.ret_types {
+                    return Err(ValidationError::ReturnTypesMismatch {
+                        statement_idx,
+                        function_id: function.id.clone(),
+                    });
+                }
+                if let Some(var_id) = remaining.into_keys().min_by_key(|var_id| var_id.id) {
+                    return Err(ValidationError::DanglingVariable { statement_idx, var_id });
+                }
+            }
+            Statement::Invocation(invocation) => {
+                validator.validate_invocation(&registry, statement_idx, invocation, state)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The ap tracking state at a statement.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ApTracking {
+    Enabled,
+    Disabled,
+    /// Depends on whether the ap change of a called function is known, which is not computed by
+    /// the validation.
+    Unknown,
+}
+
+/// The state of the program when reaching a statement.
+#[derive(Clone, Debug)]
+struct StatementState {
+    /// The index of the function the statement belongs to.
+    function_idx: usize,
+    /// The types of the variables available at the statement.
+    vars: HashMap<VarId, ConcreteTypeId>,
+    ap_tracking: ApTracking,
+}
+
+struct Validator<'a> {
+    program: &'a Program,
+    /// The state when reaching each statement, for the statements reached so far.
+    states: Vec<Option<StatementState>>,
+    /// The statements whose state was set or changed, and are yet to be validated.
+    queue: Vec<StatementIdx>,
+}
+impl Validator<'_> {
+    /// Validates an invocation, and merges the states following it into the states of the
+    /// statements its branches lead to.
+    fn validate_invocation(
+        &mut self,
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+        statement_idx: StatementIdx,
+        invocation: &Invocation,
+        state: StatementState,
+    ) -> Result<(), ValidationError> {
+        let libfunc = registry.get_libfunc(&invocation.libfunc_id)?;
+        if !matches_libfunc_structure(invocation, libfunc) {
+            return Err(ValidationError::LibfuncInvocationMismatch { statement_idx });
+        }
+        let (remaining, arg_types) = take_args(state.vars, invocation.args.iter())
+            .map_err(|error| edit_state_error(statement_idx, error))?;
+        let params = libfunc.param_signatures();
+        for (var_id, actual, param) in izip!(&invocation.args, arg_types, params) {
+            if actual != param.ty {
+                return Err(ValidationError::ArgumentTypeMismatch {
+                    statement_idx,
+                    var_id: var_id.clone(),
+                    expected: param.ty.clone(),
+                    actual,
+                });
+            }
+        }
+
+        for (branch, signature) in izip!(&invocation.branches, libfunc.branch_signatures()) {
+            let destination_statement_idx = statement_idx.next(&branch.target);
+            let Some(destination) = self.program.get_statement(&destination_statement_idx) else {
+                return Err(ValidationError::BranchTargetOutOfBounds {
+                    source_statement_idx: statement_idx,
+                    destination_statement_idx,
+                });
+            };
+            if invocation.branches.len() > 1 && !is_branch_align(registry, destination)? {
+                return Err(ValidationError::ExpectedBranchAlign {
+                    source_statement_idx: statement_idx,
+                    destination_statement_idx,
+                });
+            }
+            let ap_tracking = match (libfunc, &signature.ap_change) {
+                (CoreConcreteLibfunc::ApTracking(ApTrackingConcreteLibfunc::Enable(_)), _) => {
+                    if state.ap_tracking == ApTracking::Enabled {
+                        return Err(ValidationError::ApTrackingAlreadyEnabled { statement_idx });
+                    }
+                    ApTracking::Enabled
+                }
+                (CoreConcreteLibfunc::ApTracking(ApTrackingConcreteLibfunc::Disable(_)), _) => {
+                    ApTracking::Disabled
+                }
+                (CoreConcreteLibfunc::FunctionCall(_), SierraApChange::Unknown)
+                    if state.ap_tracking != ApTracking::Disabled =>
+                {
+                    ApTracking::Unknown
+                }
+                (_, SierraApChange::Unknown) => ApTracking::Disabled,
+                (_, SierraApChange::Known { .. } | SierraApChange::BranchAlign) => {
+                    state.ap_tracking
+                }
+            };
+            let vars = put_results(
+                remaining.clone(),
+                izip!(&branch.results, signature.vars.iter().map(|var| var.ty.clone())),
+            )
+            .map_err(|error| edit_state_error(statement_idx, error))?;
+            self.merge(
+                destination_statement_idx,
+                StatementState { function_idx: state.function_idx, vars, ap_tracking },
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Merges a state reaching the given statement into its state, queueing the statement for
+    /// validation if its state was set or changed.
+    fn merge(
+        &mut self,
+        statement_idx: StatementIdx,
+        state: StatementState,
+    ) -> Result<(), ValidationError> {
+        if self.states[statement_idx.0].is_none() {
+            self.states[statement_idx.0] = Some(state);
+            self.queue.push(statement_idx);
+            return Ok(());
+        }
+        let existing = self.states[statement_idx.0].as_mut().unwrap();
+        if existing.function_idx != state.function_idx {
+            return Err(ValidationError::InconsistentFunction {
+                statement_idx,
+                function_id: self.program.funcs[existing.function_idx].id.clone(),
+                other_function_id: self.program.funcs[state.function_idx].id.clone(),
+            });
+        }
+        if existing.vars != state.vars {
+            return Err(ValidationError::InconsistentVariables { statement_idx });
+        }
+        match (existing.ap_tracking, state.ap_tracking) {
+            (existing_ap_tracking, ap_tracking) if existing_ap_tracking == ap_tracking => {}
+            (ApTracking::Unknown, _) => {}
+            (_, ApTracking::Unknown) => {
+                // The statements following the statement are revalidated with the weaker state.
+                existing.ap_tracking = ApTracking::Unknown;
+                self.queue.push(statement_idx);
+            }
+            _ => return Err(ValidationError::InconsistentApTracking { statement_idx }),
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether the invocation has the number of arguments, branches and results of the
+/// libfunc, and falls through in the fallthrough branch of the libfunc.
+fn matches_libfunc_structure(invocation: &Invocation, libfunc: &CoreConcreteLibfunc) -> bool {
+    invocation.args.len() == libfunc.param_signatures().len()
+        && itertools::equal(
+            invocation.branches.iter().map(|branch| branch.results.len()),
+            libfunc.branch_signatures().iter().map(|signature| signature.vars.len()),
+        )
+        && libfunc.fallthrough().map_or(true, |fallthrough| {
+            invocation.branches[fallthrough].target == BranchTarget::Fallthrough
+        })
+}
+
+/// Returns whether the statement is an invocation of `branch_align`.
+fn is_branch_align(
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    statement: &Statement,
+) -> Result<bool, ValidationError> {
+    Ok(match statement {
+        Statement::Invocation(invocation) => matches!(
+            registry.get_libfunc(&invocation.libfunc_id)?,
+            CoreConcreteLibfunc::BranchAlign(_)
+        ),
+        Statement::Return(_) => false,
+    })
+}
+
+fn edit_state_error(statement_idx: StatementIdx, error: EditStateError) -> ValidationError {
+    match error {
+        EditStateError::MissingReference(var_id) => {
+            ValidationError::MissingVariable { statement_idx, var_id }
+        }
+        EditStateError::VariableOverride(var_id) => {
+            ValidationError::VariableOverride { statement_idx, var_id }
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{validate, ValidationError};
+use crate::program::StatementIdx;
+use crate::ProgramParser;
+
+/// The declarations shared by the validated programs.
+const DECLARATIONS: &str = indoc! {"
+    type felt252 = felt252;
+    type NonZeroFelt252 = NonZero<felt252>;
+
+    libfunc branch_align = branch_align;
+    libfunc disable_ap_tracking = disable_ap_tracking;
+    libfunc enable_ap_tracking = enable_ap_tracking;
+    libfunc felt252_drop = drop<felt252>;
+    libfunc felt252_dup = dup<felt252>;
+    libfunc felt252_is_zero = felt252_is_zero;
+    libfunc felt252_unwrap_non_zero = unwrap_non_zero<felt252>;
+    libfunc jump = jump;
+"};
+
+/// Validates the program with the given code, following [DECLARATIONS].
+fn validate_code(code: &str) -> Result<(), ValidationError> {
+    validate(&ProgramParser::new().parse(&format!("{DECLARATIONS}{code}")).unwrap())
+}
+
+#[test_case(
+    indoc! {"
+        felt252_is_zero([1]) { fallthrough() 3([2]) };
+        branch_align() -> ();
+        return();
+        branch_align() -> ();
+        felt252_unwrap_non_zero([2]) -> ([2]);
+        felt252_drop([2]) -> ();
+        return();
+
+        foo@0([1]: felt252) -> ();
+    "};
+    "branches"
+)]
+#[test_case(
+    indoc! {"
+        libfunc call_bar = function_call<user@bar>;
+
+        felt252_is_zero([1]) { fallthrough() 3([2]) };
+        branch_align() -> ();
+        jump() { 7() };
+        branch_align() -> ();
+        felt252_unwrap_non_zero([2]) -> ([2]);
+        felt252_drop([2]) -> ();
+        call_bar() -> ();
+        return();
+        return();
+
+        foo@0([1]: felt252) -> ();
+        bar@8() -> ();
+    "};
+    "convergence after a function call"
+)]
+fn test_valid_program(code: &str) {
+    assert_eq!(validate_code(code), Ok(()));
+}
+
+#[test_case(
+    indoc! {"
+        return();
+
+        foo@1() -> ();
+    "},
+    ValidationError::EntryPointOutOfBounds("foo".into());
+    "entry point out of bounds"
+)]
+#[test_case(
+    indoc! {"
+        return();
+
+        foo@0() -> ();
+        bar@0() -> ();
+    "},
+    ValidationError::InconsistentFunction {
+        statement_idx: StatementIdx(0),
+        function_id: "foo".into(),
+        other_function_id: "bar".into(),
+    };
+    "shared statement"
+)]
+#[test_case(
+    indoc! {"
+        felt252_drop([1]) -> ();
+
+        foo@0([1]: felt252) -> ();
+    "},
+    ValidationError::BranchTargetOutOfBounds {
+        source_statement_idx: StatementIdx(0),
+        destination_statement_idx: StatementIdx(1),
+    };
+    "falling through the end of the program"
+)]
+#[test_case(
+    indoc! {"
+        felt252_drop([1]) -> ([2]);
+        return();
+
+        foo@0([1]: felt252) -> ();
+    "},
+    ValidationError::LibfuncInvocationMismatch { statement_idx: StatementIdx(0) };
+    "wrong number of results"
+)]
+#[test_case(
+    indoc! {"
+        felt252_drop([2]) -> ();
+        return();
+
+        foo@0([1]: felt252) -> ();
+    "},
+    ValidationError::MissingVariable { statement_idx: StatementIdx(0), var_id: 2.into() };
+    "missing variable"
+)]
+#[test_case(
+    indoc! {"
+        felt252_dup([1]) -> ([1], [1]);
+        return([1]);
+
+        foo@0([1]: felt252) -> (felt252);
+    "},
+    ValidationError::VariableOverride { statement_idx: StatementIdx(0), var_id: 1.into() };
+    "overridden variable"
+)]
+#[test_case(
+    indoc! {"
+        felt252_unwrap_non_zero([1]) -> ([1]);
+        return([1]);
+
+        foo@0([1]: felt252) -> (felt252);
+    "},
+    ValidationError::ArgumentTypeMismatch {
+        statement_idx: StatementIdx(0),
+        var_id: 1.into(),
+        expected: "NonZeroFelt252".into(),
+        actual: "felt252".into(),
+    };
+    "argument type mismatch"
+)]
+#[test_case(
+    indoc! {"
+        return([1]);
+
+        foo@0([1]: felt252) -> ();
+    "},
+    ValidationError::ReturnTypesMismatch {
+        statement_idx: StatementIdx(0),
+        function_id: "foo".into(),
+    };
+    "return types mismatch"
+)]
+#[test_case(
+    indoc! {"
+        return();
+
+        foo@0([1]: felt252) -> ();
+    "},
+    ValidationError::DanglingVariable { statement_idx: StatementIdx(0), var_id: 1.into() };
+    "dangling variable"
+)]
+#[test_case(
+    indoc! {"
+        felt252_is_zero([1]) { fallthrough() 2([2]) };
+        return();
+        branch_align() -> ();
+        felt252_unwrap_non_zero([2]) -> ([2]);
+        felt252_drop([2]) -> ();
+        return();
+
+        foo@0([1]: felt252) -> ();
+    "},
+    ValidationError::ExpectedBranchAlign {
+        source_statement_idx: StatementIdx(0),
+        destination_statement_idx: StatementIdx(1),
+    };
+    "missing branch align"
+)]
+#[test_case(
+    indoc! {"
+        felt252_is_zero([1]) { fallthrough() 3([2]) };
+        branch_align() -> ();
+        jump() { 5() };
+        branch_align() -> ();
+        felt252_unwrap_non_zero([2]) -> ([2]);
+        return([2]);
+
+        foo@0([1]: felt252) -> (felt252);
+    "},
+    ValidationError::InconsistentVariables { statement_idx: StatementIdx(5) };
+    "inconsistent variables"
+)]
+#[test_case(
+    indoc! {"
+        enable_ap_tracking() -> ();
+        return();
+
+        foo@0() -> ();
+    "},
+    ValidationError::ApTrackingAlreadyEnabled { statement_idx: StatementIdx(0) };
+    "ap tracking already enabled"
+)]
+#[test_case(
+    indoc! {"
+        felt252_is_zero([1]) { fallthrough() 3([2]) };
+        branch_align() -> ();
+        jump() { 7() };
+        branch_align() -> ();
+        felt252_unwrap_non_zero([2]) -> ([2]);
+        felt252_drop([2]) -> ();
+        disable_ap_tracking() -> ();
+        return();
+
+        foo@0([1]: felt252) -> ();
+    "},
+    ValidationError::InconsistentApTracking { statement_idx: StatementIdx(7) };
+    "inconsistent ap tracking"
+)]
+fn test_invalid_program(code: &str, error: ValidationError) {
+    assert_eq!(validate_code(code), Err(error));
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+use cairo_lang_sierra::binary;
 use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
 use cairo_lang_sierra::program::{Program, StatementIdx};
 use cairo_lang_sierra::program_registry::ProgramRegistry;
@@ -24,6 +25,15 @@ fn parse(name: &str) {
     get_example_program(name);
 }
 
+#[test_case("fib_jumps")]
+#[test_case("fib_no_gas")]
+fn binary_roundtrip(name: &str) {
+    let program = get_example_program(name);
+    let bytes = binary::program_to_bytes(&program);
+    assert!(bytes.len() < program.to_string().len());
+    assert_eq!(binary::program_from_bytes(&bytes).unwrap().to_string(), program.to_string());
+}
+
 #[test_case("fib_jumps")]
 #[test_case("fib_no_gas")]
 fn create_registry(name: &str) {
@@ -0,0 +1,60 @@
+use array::ArrayTrait;
+use option::OptionTrait;
+use result::ResultTrait;
+use traits::TryInto;
+use starknet::class_hash::Felt252TryIntoClassHash;
+use starknet::syscalls::deploy_syscall;
+use test::test_utils::assert_eq;
+
+#[starknet::interface]
+trait IOwnedContract<T> {
+    fn set_value(ref self: T, value: felt252);
+    fn get_value(self: @T) -> felt252;
+}
+
+#[contract]
+mod OwnedContract {
+    #[starknet::storage]
+    struct Storage {
+        value: felt252
+    }
+
+    #[external]
+    #[only_owner]
+    fn set_value(ref self: Storage, value: felt252) {
+        self.value.write(value);
+    }
+
+    #[external]
+    fn get_value(self: @Storage) -> felt252 {
+        self.value.read()
+    }
+}
+
+fn deploy_owned_contract() -> IOwnedContractDispatcher {
+    let calldata: Array<felt252> = Default::default();
+    let (contract_address, _) = deploy_syscall(
+        OwnedContract::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    IOwnedContractDispatcher { contract_address }
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_only_owner_called_by_deployer() {
+    let contract = deploy_owned_contract();
+    contract.set_value(5);
+    assert_eq(contract.get_value(), 5, 'contract.get_value() == 5');
+}
+
+#[test]
+#[available_gas(30000000)]
+#[should_panic(expected: ('Caller is not the owner', 'ENTRYPOINT_FAILED', ))]
+fn test_only_owner_called_by_other() {
+    let contract = deploy_owned_contract();
+    starknet::testing::start_cheat_caller_address(
+        contract.contract_address, starknet::contract_address_const::<2>()
+    );
+    contract.set_value(5);
+}
@@ -2,12 +2,14 @@ use array::ArrayTrait;
 use array::SpanTrait;
 use box::BoxTrait;
 use option::OptionTrait;
+use result::ResultTrait;
 use traits::{TryInto, Into};
 use zeroable::Zeroable;
 use clone::Clone;
 use starknet::Event;
 use starknet::class_hash::Felt252TryIntoClassHash;
 use starknet::StorageAddress;
+use starknet::SyscallResultTrait;
 use test::test_utils::{assert_eq, assert_ne};
 
 use super::utils::serialized_element;
@@ -28,6 +30,9 @@ mod TestContract {
         value: felt252,
         mapping: LegacyMap::<u128, bool>,
         large_mapping: LegacyMap::<u256, u256>,
+        wide_mapping: LegacyMap::<u256, integer::u512>,
+        signed_mapping: LegacyMap::<i32, i64>,
+        price: UFixed64x64,
     }
 
     #[external]
@@ -82,6 +87,38 @@ mod TestContract {
         self.large_mapping.read(key)
     }
 
+    #[external]
+    fn set_wide(ref self: Storage, key: u256, value: integer::u512) {
+        self.wide_mapping.write(key, value)
+    }
+
+    #[external]
+    fn get_wide(self: @Storage, key: u256) -> integer::u512 {
+        self.wide_mapping.read(key)
+    }
+
+    #[external]
+    fn set_signed(ref self: Storage, key: i32, value: i64) {
+        self.signed_mapping.write(key, value)
+    }
+
+    #[external]
+    fn get_signed(self: @Storage, key: i32) -> i64 {
+        self.signed_mapping.read(key)
+    }
+
+    #[external]
+    fn set_price(ref self: Storage, price: UFixed64x64) {
+        self.price.write(price)
+    }
+
+    #[external]
+    fn scale_price(ref self: Storage, factor: UFixed64x64) -> UFixed64x64 {
+        let price = self.price.read() * factor;
+        self.price.write(price);
+        price
+    }
+
     #[external]
     fn test_storage_address(self: @Storage, storage_address: StorageAddress) -> StorageAddress {
         storage_address
@@ -203,6 +240,57 @@ fn write_read_large_value() {
     assert(retdata.is_empty(), 'Array not empty');
 }
 
+#[test]
+#[available_gas(600000)]
+fn write_read_wide_value() {
+    let value = integer::u512 { limb0: 3_u128, limb1: 4_u128, limb2: 5_u128, limb3: 6_u128 };
+    let mut args = Default::default();
+    serde::Serde::serialize(@u256 { low: 1_u128, high: 2_u128 }, ref args);
+    serde::Serde::serialize(@value, ref args);
+    let mut retdata = TestContract::__external::set_wide(args.span());
+    assert(retdata.is_empty(), 'Array not empty');
+    let mut retdata = TestContract::__external::get_wide(
+        serialized_element(u256 { low: 1_u128, high: 2_u128 })
+    );
+    assert_eq(single_deserialize(ref retdata), value, 'Wrong result');
+    assert(retdata.is_empty(), 'Array not empty');
+}
+
+#[test]
+#[available_gas(300000)]
+fn write_read_signed_value() {
+    let mut args = Default::default();
+    serde::Serde::serialize(@-5_i32, ref args);
+    serde::Serde::serialize(@-7_i64, ref args);
+    let mut retdata = TestContract::__external::set_signed(args.span());
+    assert(retdata.is_empty(), 'Array not empty');
+    let mut retdata = TestContract::__external::get_signed(serialized_element(-5_i32));
+    assert_eq(single_deserialize(ref retdata), -7_i64, 'Wrong result');
+    assert(retdata.is_empty(), 'Array not empty');
+}
+
+#[test]
+#[should_panic]
+fn test_wrapper_signed_arg_out_of_range() {
+    let mut calldata = Default::default();
+    calldata.append(0x80000000);
+    TestContract::__external::get_signed(calldata.span());
+}
+
+#[test]
+#[available_gas(600000)]
+fn write_read_fixed_point_value() {
+    let mut args = Default::default();
+    serde::Serde::serialize(@fixed::ufixed64x64_from_int(2), ref args);
+    let mut retdata = TestContract::__external::set_price(args.span());
+    assert(retdata.is_empty(), 'Array not empty');
+    let mut retdata = TestContract::__external::scale_price(
+        serialized_element(fixed::ufixed64x64_from_raw(0x8000000000000000))
+    );
+    assert_eq(single_deserialize(ref retdata), fixed::ufixed64x64_from_int(1), 'Wrong result');
+    assert(retdata.is_empty(), 'Array not empty');
+}
+
 #[test]
 #[available_gas(300000)]
 fn test_get_block_info() {
@@ -297,6 +385,105 @@ fn test_get_signature() {
     assert_eq(*read_signature.at(1), 'signature', 'unexpected element 1');
 }
 
+#[test]
+#[available_gas(300000)]
+fn test_cheat_caller_address() {
+    let target = starknet::contract_address_const::<1>();
+    starknet::testing::set_contract_address(target);
+    starknet::testing::start_cheat_caller_address(target, starknet::contract_address_const::<2>());
+    assert_eq(starknet::get_caller_address().into(), 2, 'caller_address not cheated');
+    starknet::testing::stop_cheat_caller_address(target);
+    assert(starknet::get_caller_address().is_zero(), 'caller_address still cheated');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_cheat_block_info() {
+    let target = starknet::contract_address_const::<1>();
+    starknet::testing::set_contract_address(target);
+    starknet::testing::start_cheat_block_number(target, 1_u64);
+    starknet::testing::start_cheat_block_timestamp(target, 2_u64);
+    starknet::testing::start_cheat_sequencer_address(
+        target, starknet::contract_address_const::<3>()
+    );
+    let info = starknet::get_block_info().unbox();
+    assert_eq(info.block_number, 1_u64, 'block_number not cheated');
+    assert_eq(info.block_timestamp, 2_u64, 'block_timestamp not cheated');
+    assert_eq(info.sequencer_address.into(), 3, 'sequencer_address not cheated');
+    starknet::testing::stop_cheat_block_number(target);
+    starknet::testing::stop_cheat_block_timestamp(target);
+    starknet::testing::stop_cheat_sequencer_address(target);
+    let info = starknet::get_block_info().unbox();
+    assert_eq(info.block_number, 0_u64, 'block_number still cheated');
+    assert_eq(info.block_timestamp, 0_u64, 'block_timestamp still cheated');
+    assert(info.sequencer_address.is_zero(), 'sequencer_address still cheated');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_cheat_other_target() {
+    starknet::testing::set_contract_address(starknet::contract_address_const::<1>());
+    starknet::testing::start_cheat_chain_id(starknet::contract_address_const::<2>(), 5);
+    assert(starknet::get_tx_info().unbox().chain_id.is_zero(), 'chain_id cheated for other');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_pop_log() {
+    let emitter = starknet::contract_address_const::<1>();
+    starknet::testing::set_contract_address(emitter);
+    let mut keys = Default::default();
+    keys.append('key');
+    let mut data = Default::default();
+    data.append(1);
+    data.append(2);
+    starknet::emit_event_syscall(keys.span(), data.span()).unwrap_syscall();
+    assert(
+        starknet::testing::pop_log(starknet::contract_address_const::<2>()).is_none(),
+        'popped log of other contract'
+    );
+    let (keys, data) = starknet::testing::pop_log(emitter).unwrap();
+    assert_eq(keys.len(), 1, 'unexpected keys size');
+    assert_eq(*keys.at(0), 'key', 'unexpected key');
+    assert_eq(data.len(), 2, 'unexpected data size');
+    assert_eq(*data.at(0), 1, 'unexpected element 0');
+    assert_eq(*data.at(1), 2, 'unexpected element 1');
+    assert(starknet::testing::pop_log(emitter).is_none(), 'log popped twice');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_mock_call() {
+    let target = starknet::contract_address_const::<1>();
+    let mut ret_data = Default::default();
+    ret_data.append(5);
+    starknet::testing::start_mock_call(target, 'foo', ret_data.span());
+    let ret_data = starknet::call_contract_syscall(target, 'foo', Default::default().span())
+        .unwrap_syscall();
+    assert_eq(ret_data.len(), 1, 'unexpected ret_data size');
+    assert_eq(*ret_data.at(0), 5, 'unexpected ret_data');
+    let mut err = starknet::call_contract_syscall(target, 'bar', Default::default().span())
+        .unwrap_err();
+    assert_eq(err.pop_front().unwrap(), 'CONTRACT_NOT_DEPLOYED', 'other selector mocked');
+    starknet::testing::stop_mock_call(target, 'foo');
+    let mut err = starknet::call_contract_syscall(target, 'foo', Default::default().span())
+        .unwrap_err();
+    assert_eq(err.pop_front().unwrap(), 'CONTRACT_NOT_DEPLOYED', 'call still mocked');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_mock_call_panic() {
+    let target = starknet::contract_address_const::<1>();
+    let mut panic_data = Default::default();
+    panic_data.append('Failure');
+    starknet::testing::start_mock_call_panic(target, 'foo', panic_data.span());
+    let mut err = starknet::call_contract_syscall(target, 'foo', Default::default().span())
+        .unwrap_err();
+    assert_eq(err.pop_front().unwrap(), 'Failure', 'err == "Failure"');
+    assert_eq(err.pop_front().unwrap(), 'ENTRYPOINT_FAILED', 'err == "ENTRYPOINT_FAILED"');
+}
+
 #[test]
 #[should_panic]
 fn test_out_of_range_storage_address_from_felt252() -> starknet::StorageAddress {
@@ -320,6 +507,13 @@ struct MyEventStruct {
     data: usize,
 }
 
+#[derive(starknet::Event, PartialEq, Drop, Clone)]
+struct MyWideEvent {
+    #[key]
+    from: u256,
+    amount: integer::u512,
+}
+
 #[derive(starknet::Event, PartialEq, Drop, Clone)]
 enum MyEventEnum {
     #[event]
@@ -352,6 +546,10 @@ fn test_event_serde() {
     event_serde_tester(event.clone());
     let event = MyEventEnum::A(event);
     event_serde_tester(event.clone());
+    let from = u256 { low: 1_u128, high: 2_u128 };
+    let amount = integer::u512 { limb0: 3_u128, limb1: 4_u128, limb2: 5_u128, limb3: 6_u128 };
+    let event = MyWideEvent { from, amount };
+    event_serde_tester(event);
 }
 
 #[test]
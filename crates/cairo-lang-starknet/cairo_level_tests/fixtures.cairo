@@ -0,0 +1,38 @@
+use core::result::ResultTrait;
+use test::test_utils::assert_eq;
+use starknet::syscalls::deploy_syscall;
+use array::ArrayTrait;
+use traits::TryInto;
+use option::OptionTrait;
+use starknet::class_hash::Felt252TryIntoClassHash;
+use super::interoperability::{ContractA, IContractDispatcher, IContractDispatcherTrait};
+
+#[setup]
+#[available_gas(30000000)]
+fn setup() {
+    let mut calldata = Default::default();
+    calldata.append(100);
+    deploy_syscall(ContractA::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false)
+        .unwrap();
+}
+
+/// The contract deployed by the setup, which is the first deployed contract.
+fn deployed_contract() -> IContractDispatcher {
+    IContractDispatcher { contract_address: 1.try_into().unwrap() }
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_setup_deployment() {
+    let mut contract = deployed_contract();
+    assert_eq(contract.foo(300), 100, 'contract.foo(300) == 100');
+    assert_eq(contract.foo(400), 300, 'contract.foo(400) == 300');
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_setup_state_is_copied() {
+    // The write of the other test is not visible.
+    let mut contract = deployed_contract();
+    assert_eq(contract.foo(500), 100, 'contract.foo(500) == 100');
+}
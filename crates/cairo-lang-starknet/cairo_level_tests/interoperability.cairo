@@ -1,7 +1,7 @@
 use core::traits::Into;
 use core::result::ResultTrait;
 use test::test_utils::{assert_eq, assert_ne};
-use starknet::syscalls::{deploy_syscall, get_block_hash_syscall};
+use starknet::syscalls::{call_contract_syscall, deploy_syscall, get_block_hash_syscall};
 use array::ArrayTrait;
 use traits::TryInto;
 use option::OptionTrait;
@@ -167,6 +167,89 @@ fn test_entrypoint_failed() {
     contract.foo(300);
 }
 
+#[starknet::interface]
+trait IProxy<T> {
+    fn call_foo(ref self: T, contract_address: starknet::ContractAddress, a: u128) -> u128;
+}
+
+#[contract]
+mod Proxy {
+    use starknet::ContractAddress;
+    use super::{IContractDispatcher, IContractDispatcherTrait};
+
+    #[starknet::storage]
+    struct Storage {}
+
+    #[external]
+    fn call_foo(ref self: Storage, contract_address: ContractAddress, a: u128) -> u128 {
+        IContractDispatcher { contract_address }.foo(a)
+    }
+
+    #[external]
+    fn call_foo_and_fail(ref self: Storage, contract_address: ContractAddress, a: u128) {
+        IContractDispatcher { contract_address }.foo(a);
+        panic_with_felt252('Failure');
+    }
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_nested_calls() {
+    let mut calldata = Default::default();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractA::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let (proxy_address, _) = deploy_syscall(
+        Proxy::TEST_CLASS_HASH.try_into().unwrap(), 0, Default::default().span(), false
+    )
+        .unwrap();
+    let mut proxy = IProxyDispatcher { contract_address: proxy_address };
+    assert_eq(proxy.call_foo(address0, 300), 100, 'proxy.call_foo(300) == 100');
+    assert_eq(proxy.call_foo(address0, 400), 300, 'proxy.call_foo(400) == 300');
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_failed_call_reverts_nested_calls() {
+    let mut calldata = Default::default();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractA::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let (proxy_address, _) = deploy_syscall(
+        Proxy::TEST_CLASS_HASH.try_into().unwrap(), 0, Default::default().span(), false
+    )
+        .unwrap();
+
+    let mut calldata = Default::default();
+    calldata.append(address0.into());
+    calldata.append(300);
+    // The selector of `call_foo_and_fail`.
+    let selector = 0xd08d239651127e94f7923e9eb1542996e4bbc43a1c7bf89f52ae32bdeeb9e0;
+    let mut err = call_contract_syscall(proxy_address, selector, calldata.span()).unwrap_err();
+    assert_eq(err.pop_front().unwrap(), 'Failure', 'err == "Failure"');
+    assert_eq(err.pop_front().unwrap(), 'ENTRYPOINT_FAILED', 'err == "ENTRYPOINT_FAILED"');
+
+    // The write of the nested call was reverted.
+    let mut contract0 = IContractDispatcher { contract_address: address0 };
+    assert_eq(contract0.foo(400), 100, 'contract0.foo(400) == 100');
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_library_call_class_hash_not_found() {
+    let mut calldata = Default::default();
+    calldata.append(100);
+    let mut err = starknet::syscalls::library_call_syscall(
+        5.try_into().unwrap(), 0, calldata.span()
+    )
+        .unwrap_err();
+    assert_eq(err.pop_front().unwrap(), 'CLASS_HASH_NOT_FOUND', 'err == "CLASS_HASH_NOT_FOUND"');
+}
+
 #[test]
 #[available_gas(30000000)]
 #[should_panic(expected: ('GET_BLOCK_HASH_UNIMPLEMENTED', ))]
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod contract_tests;
 mod abi_dispatchers_tests;
+mod access_control;
+mod fixtures;
 mod interoperability;
 mod storage_access;
 mod utils;
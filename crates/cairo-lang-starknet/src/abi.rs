@@ -1,17 +1,35 @@
 use std::collections::HashSet;
+use std::fmt;
 
-use cairo_lang_defs::ids::{LanguageElementId, TraitFunctionId, TraitId};
+use cairo_lang_defs::ids::{
+    LanguageElementId, TopLevelLanguageElementId, TraitFunctionId, TraitId,
+};
 use cairo_lang_diagnostics::{DiagnosticAdded, Maybe};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::enm::SemanticEnumEx;
 use cairo_lang_semantic::items::structure::SemanticStructEx;
+use cairo_lang_semantic::items::trt::ConcreteTraitGenericFunctionId;
 use cairo_lang_semantic::{
     ConcreteTypeId, GenericArgumentId, GenericParam, Mutability, TypeId, TypeLongId,
 };
+use cairo_lang_syntax::attribute::structured::AttributeArgVariant;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{ast, Terminal};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::Itertools;
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::plugin::consts::EVENT_ATTR;
+use crate::contract::{entry_point_selector, event_selector, DEFAULT_ENTRY_POINT_SELECTOR};
+use crate::plugin::consts::{
+    CONSTRUCTOR_ATTR, DEFAULT_ATTR, DEPRECATED_ATTR, ERROR_ATTR, EVENTS_ATTR, EVENT_ATTR,
+    EVENT_NAMESPACE_ARG, EXTERNAL_ATTR, KEY_ATTR, L1_HANDLER_ATTR, L1_MESSAGE_ATTR,
+};
+
+pub mod codec;
+pub mod diff;
+pub mod validation;
 
 #[cfg(test)]
 #[path = "abi_test.rs"]
@@ -28,6 +46,73 @@ impl Contract {
     pub fn json(&self) -> String {
         serde_json::to_string_pretty(&self).unwrap()
     }
+
+    /// Returns a one-line signature of each entry point and event of the contract, followed by its
+    /// selector, e.g. `transfer(recipient: ContractAddress, amount: u256) -> bool  [0x83af...]`.
+    /// The types are given by their names, without their paths, and the keys of events are marked
+    /// with `#[key]`.
+    pub fn signatures(&self) -> Vec<String> {
+        let param = |input: &Input| format!("{}: {}", input.name, short_type_name(&input.ty));
+        let params = |inputs: &[Input]| inputs.iter().map(param).collect_vec();
+        self.items
+            .iter()
+            .filter_map(|item| {
+                let (prefix, name, inputs, outputs, selector) = match item {
+                    Item::Function(function) => (
+                        "",
+                        &function.name,
+                        params(&function.inputs),
+                        function.outputs.as_slice(),
+                        entry_point_selector(&function.name),
+                    ),
+                    Item::Default(function) => (
+                        "default ",
+                        &function.name,
+                        params(&function.inputs),
+                        function.outputs.as_slice(),
+                        BigUint::from(DEFAULT_ENTRY_POINT_SELECTOR),
+                    ),
+                    Item::Constructor(constructor) => (
+                        "constructor ",
+                        &constructor.name,
+                        params(&constructor.inputs),
+                        [].as_slice(),
+                        entry_point_selector(&constructor.name),
+                    ),
+                    Item::L1Handler(function) => (
+                        "l1_handler ",
+                        &function.name,
+                        params(&function.inputs),
+                        function.outputs.as_slice(),
+                        entry_point_selector(&function.name),
+                    ),
+                    Item::Event(event) => (
+                        "event ",
+                        &event.name,
+                        event
+                            .keys
+                            .iter()
+                            .map(|key| format!("#[key] {}", param(key)))
+                            .chain(params(&event.data))
+                            .collect(),
+                        [].as_slice(),
+                        event_selector(&event.name, event.namespace.as_deref()),
+                    ),
+                    _ => return None,
+                };
+                let inputs = inputs.join(", ");
+                let outputs = match outputs {
+                    [] => "".to_string(),
+                    [output] => format!(" -> {}", short_type_name(&output.ty)),
+                    outputs => format!(
+                        " -> ({})",
+                        outputs.iter().map(|output| short_type_name(&output.ty)).join(", ")
+                    ),
+                };
+                Some(format!("{prefix}{name}({inputs}){outputs}  [0x{selector:x}]"))
+            })
+            .collect()
+    }
 }
 
 pub struct AbiBuilder {
@@ -50,24 +135,48 @@ impl AbiBuilder {
         };
 
         let mut builder = Self { abi: Contract::default(), types: HashSet::new() };
+        let interface_functions = external_impl_functions(db, trait_id)?;
 
         for trait_function_id in db.trait_functions(trait_id).unwrap_or_default().values() {
             if trait_function_has_attr(db, *trait_function_id, EVENT_ATTR)? {
                 builder.add_event(db, *trait_function_id)?;
+            } else if trait_function_has_attr(db, *trait_function_id, EVENTS_ATTR)? {
+                builder.add_contract_events(db, *trait_function_id)?;
+            } else if trait_function_has_attr(db, *trait_function_id, L1_MESSAGE_ATTR)? {
+                builder.add_l1_message(db, *trait_function_id)?;
+            } else if trait_function_has_attr(db, *trait_function_id, ERROR_ATTR)? {
+                builder.add_errors(db, *trait_function_id)?;
+            } else if trait_function_has_attr(db, *trait_function_id, DEFAULT_ATTR)? {
+                let function = builder.function_abi(db, *trait_function_id)?;
+                builder.abi.items.push(Item::Default(function));
+            } else if trait_function_has_attr(db, *trait_function_id, CONSTRUCTOR_ATTR)? {
+                let Function { name, inputs, .. } = builder.function_abi(db, *trait_function_id)?;
+                builder.abi.items.push(Item::Constructor(Constructor { name, inputs }));
+            } else if trait_function_has_attr(db, *trait_function_id, L1_HANDLER_ATTR)? {
+                let function = builder.function_abi(db, *trait_function_id)?;
+                builder.abi.items.push(Item::L1Handler(function));
             } else {
-                builder.add_function(db, *trait_function_id)?;
+                let mut function = builder.function_abi(db, *trait_function_id)?;
+                if let Some((interface, interface_function)) =
+                    interface_functions.get(function.name.as_str())
+                {
+                    verify_interface_function(db, &function, *interface_function)?;
+                    function.interface = Some(interface.clone());
+                }
+                builder.abi.items.push(Item::Function(function));
             }
         }
 
         Ok(builder.abi)
     }
 
-    /// Adds a function to the ABI from a TraitFunctionId.
-    fn add_function(
+    /// Returns the ABI of a function given its TraitFunctionId, adding the types it uses to the
+    /// ABI.
+    fn function_abi(
         &mut self,
         db: &dyn SemanticGroup,
         trait_function_id: TraitFunctionId,
-    ) -> Result<(), ABIError> {
+    ) -> Result<Function, ABIError> {
         // Get generic params.
         let trait_id = trait_function_id.trait_id(db.upcast());
         let generic_params =
@@ -116,12 +225,13 @@ impl AbiBuilder {
             vec![Output { ty: signature.return_type.format(db) }]
         };
 
-        self.abi.items.push(Item::Function(Function { name, inputs, outputs, state_mutability }));
+        let deprecated = deprecation_note(db, trait_function_id)?;
 
-        Ok(())
+        Ok(Function { name, inputs, outputs, state_mutability, deprecated, interface: None })
     }
 
-    /// Adds an event to the ABI from a TraitFunctionId.
+    /// Adds an event to the ABI from a TraitFunctionId, adding the types of its parameters to the
+    /// ABI. The parameters are emitted as the data of the event.
     fn add_event(
         &mut self,
         db: &dyn SemanticGroup,
@@ -132,17 +242,175 @@ impl AbiBuilder {
         let signature = db
             .trait_function_signature(trait_function_id)
             .map_err(|_| ABIError::CompilationError)?;
-        self.abi.items.push(Item::Event(Event {
-            name,
-            inputs: signature
-                .params
-                .into_iter()
-                .map(|param| Input {
-                    name: param.id.name(db.upcast()).into(),
-                    ty: param.ty.format(db),
-                })
-                .collect(),
-        }));
+        let namespace = event_namespace(db, trait_function_id)?;
+        let mut data = vec![];
+        for param in signature.params {
+            self.add_type(db, param.ty)?;
+            data.push(Input { name: param.id.name(db.upcast()).into(), ty: param.ty.format(db) });
+        }
+        self.abi.items.push(Item::Event(Event { name, namespace, keys: vec![], data }));
+
+        Ok(())
+    }
+
+    /// Adds the events of a contract to the ABI from a TraitFunctionId, whose single parameter is
+    /// the `Event` enum of the contract. Each variant of the enum is an event, whose selector is
+    /// the selector of the name of the variant.
+    /// The members of an `#[event]` variant are split into keys and data as they are emitted, with
+    /// the members of its nested `#[event]` structs flattened into them.
+    fn add_contract_events(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_function_id: TraitFunctionId,
+    ) -> Result<(), ABIError> {
+        let signature = db
+            .trait_function_signature(trait_function_id)
+            .map_err(|_| ABIError::CompilationError)?;
+        let [param] = signature.params.as_slice() else {
+            return Err(ABIError::UnexpectedType);
+        };
+        let TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) =
+            db.lookup_intern_type(param.ty)
+        else {
+            return Err(ABIError::UnexpectedType);
+        };
+        let enum_id = concrete_enum_id.enum_id(db);
+        let defs_db = db.upcast();
+        let enum_ast = db
+            .module_enums(enum_id.parent_module(defs_db))
+            .map_err(|_| ABIError::CompilationError)?
+            .get(&enum_id)
+            .cloned()
+            .ok_or(ABIError::CompilationError)?;
+        let variant_ids = db.enum_variants(enum_id).map_err(|_| ABIError::CompilationError)?;
+        for variant_ast in enum_ast.variants(defs_db).elements(defs_db) {
+            let name = variant_ast.name(defs_db).text(defs_db);
+            let variant_id = *variant_ids.get(&name).ok_or(ABIError::CompilationError)?;
+            let variant = db
+                .variant_semantic(enum_id, variant_id)
+                .and_then(|variant| db.concrete_enum_variant(concrete_enum_id, &variant))
+                .map_err(|_| ABIError::CompilationError)?;
+            let mut keys = vec![];
+            let mut data = vec![];
+            if variant_ast.has_attr(defs_db, EVENT_ATTR) {
+                self.add_event_members(db, variant.ty, "", &mut keys, &mut data)?;
+            } else {
+                self.add_type(db, variant.ty)?;
+                data.push(Input { name: "value".to_string(), ty: variant.ty.format(db) });
+            }
+            self.abi.items.push(Item::Event(Event {
+                name: name.into(),
+                namespace: None,
+                keys,
+                data,
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Adds the members of an event struct to the keys and data of an event, by the names given
+    /// by the path of their nested events, and adds their types to the ABI.
+    fn add_event_members(
+        &mut self,
+        db: &dyn SemanticGroup,
+        type_id: TypeId,
+        prefix: &str,
+        keys: &mut Vec<Input>,
+        data: &mut Vec<Input>,
+    ) -> Result<(), ABIError> {
+        let TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) =
+            db.lookup_intern_type(type_id)
+        else {
+            return Err(ABIError::UnsupportedNestedEvent(type_id.format(db)));
+        };
+        let struct_id = concrete_struct_id.struct_id(db);
+        let defs_db = db.upcast();
+        let struct_ast = db
+            .module_structs(struct_id.parent_module(defs_db))
+            .map_err(|_| ABIError::CompilationError)?
+            .get(&struct_id)
+            .cloned()
+            .ok_or(ABIError::CompilationError)?;
+        let members =
+            db.concrete_struct_members(concrete_struct_id).map_err(|_| ABIError::UnexpectedType)?;
+        for member_ast in struct_ast.members(defs_db).elements(defs_db) {
+            let member_name = member_ast.name(defs_db).text(defs_db);
+            let member = members.get(&member_name).ok_or(ABIError::CompilationError)?;
+            let name = format!("{prefix}{member_name}");
+            if member_ast.has_attr(defs_db, EVENT_ATTR) {
+                self.add_event_members(db, member.ty, &format!("{name}."), keys, data)?;
+                continue;
+            }
+            self.add_type(db, member.ty)?;
+            let input = Input { name, ty: member.ty.format(db) };
+            if member_ast.has_attr(defs_db, KEY_ATTR) {
+                keys.push(input);
+            } else {
+                data.push(input);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds an L2 to L1 message to the ABI from a TraitFunctionId, adding its payload type to the
+    /// ABI.
+    fn add_l1_message(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_function_id: TraitFunctionId,
+    ) -> Result<(), ABIError> {
+        let defs_db = db.upcast();
+        let name = trait_function_id.name(defs_db).into();
+        let signature = db
+            .trait_function_signature(trait_function_id)
+            .map_err(|_| ABIError::CompilationError)?;
+        let mut inputs = vec![];
+        for param in signature.params {
+            self.add_type(db, param.ty)?;
+            inputs.push(Input { name: param.id.name(db.upcast()).into(), ty: param.ty.format(db) });
+        }
+        self.abi.items.push(Item::L1Message(L1Message { name, inputs }));
+
+        Ok(())
+    }
+
+    /// Adds the errors of a contract to the ABI from a TraitFunctionId, whose single parameter is
+    /// the enum of the errors. Each variant of the enum is an error, and the types of their data
+    /// are added to the ABI.
+    fn add_errors(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_function_id: TraitFunctionId,
+    ) -> Result<(), ABIError> {
+        let signature = db
+            .trait_function_signature(trait_function_id)
+            .map_err(|_| ABIError::CompilationError)?;
+        let [param] = signature.params.as_slice() else {
+            return Err(ABIError::UnexpectedType);
+        };
+        let TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) =
+            db.lookup_intern_type(param.ty)
+        else {
+            return Err(ABIError::UnexpectedType);
+        };
+        let enum_id = concrete_enum_id.enum_id(db);
+        for (name, variant_id) in
+            db.enum_variants(enum_id).map_err(|_| ABIError::CompilationError)?.iter()
+        {
+            let variant = db
+                .variant_semantic(enum_id, *variant_id)
+                .and_then(|variant| db.concrete_enum_variant(concrete_enum_id, &variant))
+                .map_err(|_| ABIError::CompilationError)?;
+            let inputs = if variant.ty.is_unit(db) {
+                vec![]
+            } else {
+                self.add_type(db, variant.ty)?;
+                vec![Input { name: "data".to_string(), ty: variant.ty.format(db) }]
+            };
+            self.abi.items.push(Item::Error(ContractError { name: name.to_string(), inputs }));
+        }
 
         Ok(())
     }
@@ -241,6 +509,72 @@ fn is_native_type(db: &dyn SemanticGroup, concrete: &ConcreteTypeId) -> bool {
     concrete.generic_type(db).parent_module(def_db).owning_crate(def_db) == db.core_crate()
 }
 
+/// Returns the functions of the `#[external]` impls in the module of the ABI trait, by name, along
+/// with the full path of the interface trait they implement and the function of that interface.
+/// The interface is resolved semantically, so it may be defined in another crate.
+fn external_impl_functions(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+) -> Result<OrderedHashMap<String, (String, ConcreteTraitGenericFunctionId)>, ABIError> {
+    let defs_db = db.upcast();
+    let module_id = trait_id.parent_module(defs_db);
+    let mut functions = OrderedHashMap::default();
+    for (impl_def_id, impl_ast) in
+        db.module_impls(module_id).map_err(|_| ABIError::CompilationError)?.iter()
+    {
+        if !impl_ast.has_attr(db.upcast(), EXTERNAL_ATTR) {
+            continue;
+        }
+        let concrete_trait_id =
+            db.impl_def_concrete_trait(*impl_def_id).map_err(|_| ABIError::CompilationError)?;
+        let interface_id = concrete_trait_id.trait_id(db);
+        let interface = interface_id.full_path(defs_db);
+        for name in db.impl_functions(*impl_def_id).map_err(|_| ABIError::CompilationError)?.keys()
+        {
+            let Some(interface_function_id) = db
+                .trait_function_by_name(interface_id, name.clone())
+                .map_err(|_| ABIError::CompilationError)?
+            else {
+                continue;
+            };
+            let interface_function =
+                ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, interface_function_id);
+            functions.insert(name.to_string(), (interface.clone(), interface_function));
+        }
+    }
+    Ok(functions)
+}
+
+/// Verifies that the signature of the function in the ABI matches the signature of the interface
+/// function it implements.
+fn verify_interface_function(
+    db: &dyn SemanticGroup,
+    function: &Function,
+    interface_function: ConcreteTraitGenericFunctionId,
+) -> Result<(), ABIError> {
+    let signature = db
+        .concrete_trait_function_signature(interface_function)
+        .map_err(|_| ABIError::CompilationError)?;
+    let inputs_match = signature
+        .params
+        .iter()
+        .skip(1)
+        .map(|param| (param.id.name(db.upcast()).to_string(), param.ty.format(db)))
+        .eq(function.inputs.iter().map(|input| (input.name.clone(), input.ty.clone())));
+    let outputs_match = if signature.return_type.is_unit(db) {
+        function.outputs.is_empty()
+    } else {
+        matches!(
+            function.outputs.as_slice(),
+            [Output { ty }] if *ty == signature.return_type.format(db)
+        )
+    };
+    if !inputs_match || !outputs_match {
+        return Err(ABIError::InterfaceSignatureMismatch(function.name.clone()));
+    }
+    Ok(())
+}
+
 /// Checks whether the trait function has the given attribute.
 fn trait_function_has_attr(
     db: &dyn SemanticGroup,
@@ -254,6 +588,100 @@ fn trait_function_has_attr(
         .any(|a| a.id.to_string() == attr))
 }
 
+/// Returns the note of the `#[deprecated]` attribute of the trait function, if it has one.
+/// The note is empty if the attribute has no `note` argument.
+fn deprecation_note(
+    db: &dyn SemanticGroup,
+    trait_function_id: TraitFunctionId,
+) -> Result<Option<String>, ABIError> {
+    let attributes =
+        db.trait_function_attributes(trait_function_id).map_err(|_| ABIError::CompilationError)?;
+    let Some(attr) = attributes.iter().find(|a| a.id == DEPRECATED_ATTR) else {
+        return Ok(None);
+    };
+    let note = attr.args.iter().find_map(|arg| match &arg.variant {
+        AttributeArgVariant::Named { name, value: ast::Expr::ShortString(note), .. }
+            if name.as_str() == "note" =>
+        {
+            note.string_value(db.upcast())
+        }
+        _ => None,
+    });
+    Ok(Some(note.unwrap_or_default()))
+}
+
+/// Returns the namespace of the selector of the event declared by the trait function, given by the
+/// `namespace` argument of its `#[event]` attribute, if it has one.
+fn event_namespace(
+    db: &dyn SemanticGroup,
+    trait_function_id: TraitFunctionId,
+) -> Result<Option<String>, ABIError> {
+    let attributes =
+        db.trait_function_attributes(trait_function_id).map_err(|_| ABIError::CompilationError)?;
+    let Some(attr) = attributes.iter().find(|a| a.id == EVENT_ATTR) else {
+        return Ok(None);
+    };
+    Ok(attr.args.iter().find_map(|arg| match &arg.variant {
+        AttributeArgVariant::Named { name, value: ast::Expr::ShortString(namespace), .. }
+            if name.as_str() == EVENT_NAMESPACE_ARG =>
+        {
+            namespace.string_value(db.upcast())
+        }
+        _ => None,
+    }))
+}
+
+/// Returns the name of a type without its path, and the paths of its generic arguments and members.
+fn short_type_name(ty: &str) -> String {
+    let ty = ty.trim();
+    if let Some(inner) = ty.strip_prefix('@') {
+        return format!("@{}", short_type_name(inner));
+    }
+    if let Some(inner) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+        let members = split_type_list(inner).into_iter().map(short_type_name).collect_vec();
+        return match members.as_slice() {
+            [member] => format!("({member},)"),
+            _ => format!("({})", members.join(", ")),
+        };
+    }
+    let (base, generic_args) = match ty.split_once("::<") {
+        Some((base, rest)) => (base, rest.strip_suffix('>')),
+        None => (ty, None),
+    };
+    let name = base.rsplit("::").next().unwrap_or(base);
+    match generic_args {
+        Some(generic_args) => format!(
+            "{name}<{}>",
+            split_type_list(generic_args).into_iter().map(short_type_name).join(", ")
+        ),
+        None => name.to_string(),
+    }
+}
+
+/// Splits a comma separated list of types, ignoring the commas nested in generic arguments and
+/// tuples.
+pub(crate) fn split_type_list(types: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in types.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(types[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = types[start..].trim();
+    if !last.is_empty() {
+        result.push(last);
+    }
+    result
+}
+
 #[derive(Error, Debug)]
 pub enum ABIError {
     #[error("ABIs must have exactly one generic parameter.")]
@@ -266,6 +694,10 @@ pub enum ABIError {
     EntrypointMustHaveSelf,
     #[error("Entrypoint attribute must match the mutability of the self parameter")]
     AttributeMismatch,
+    #[error("The signature of `{0}` does not match the interface it implements.")]
+    InterfaceSignatureMismatch(String),
+    #[error("Nested event `{0}` is not a struct. Only event structs can be nested in the ABI.")]
+    UnsupportedNestedEvent(String),
 }
 
 /// Enum of contract item ABIs.
@@ -274,14 +706,82 @@ pub enum ABIError {
 pub enum Item {
     #[serde(rename = "function")]
     Function(Function),
+    #[serde(rename = "default")]
+    Default(Function),
+    #[serde(rename = "constructor")]
+    Constructor(Constructor),
+    #[serde(rename = "l1_handler")]
+    L1Handler(Function),
     #[serde(rename = "event")]
     Event(Event),
+    #[serde(rename = "l1_message")]
+    L1Message(L1Message),
+    #[serde(rename = "error")]
+    Error(ContractError),
     #[serde(rename = "struct")]
     Struct(Struct),
     #[serde(rename = "enum")]
     Enum(Enum),
 }
 
+impl Item {
+    pub fn kind(&self) -> AbiItemKind {
+        match self {
+            Item::Function(_) => AbiItemKind::Function,
+            Item::Default(_) => AbiItemKind::DefaultEntryPoint,
+            Item::Constructor(_) => AbiItemKind::Constructor,
+            Item::L1Handler(_) => AbiItemKind::L1Handler,
+            Item::Event(_) => AbiItemKind::Event,
+            Item::L1Message(_) => AbiItemKind::L1Message,
+            Item::Error(_) => AbiItemKind::Error,
+            Item::Struct(_) => AbiItemKind::Struct,
+            Item::Enum(_) => AbiItemKind::Enum,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Item::Function(item) | Item::Default(item) | Item::L1Handler(item) => &item.name,
+            Item::Constructor(item) => &item.name,
+            Item::Event(item) => &item.name,
+            Item::L1Message(item) => &item.name,
+            Item::Error(item) => &item.name,
+            Item::Struct(item) => &item.name,
+            Item::Enum(item) => &item.name,
+        }
+    }
+}
+
+/// The kind of an item of a contract ABI.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AbiItemKind {
+    Function,
+    DefaultEntryPoint,
+    Constructor,
+    L1Handler,
+    Event,
+    L1Message,
+    Error,
+    Struct,
+    Enum,
+}
+
+impl fmt::Display for AbiItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AbiItemKind::Function => "function",
+            AbiItemKind::DefaultEntryPoint => "default entry point",
+            AbiItemKind::Constructor => "constructor",
+            AbiItemKind::L1Handler => "L1 handler",
+            AbiItemKind::Event => "event",
+            AbiItemKind::L1Message => "L1 message",
+            AbiItemKind::Error => "error",
+            AbiItemKind::Struct => "struct",
+            AbiItemKind::Enum => "enum",
+        })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum StateMutability {
     #[serde(rename = "external")]
@@ -299,11 +799,47 @@ pub struct Function {
     // TODO(ilya): Should the output be a vector or a single type?
     pub outputs: Vec<Output>,
     pub state_mutability: StateMutability,
+    /// The note of a deprecated function. Empty if the function was deprecated without a note.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+    /// The full path of the interface trait the function implements, if it is defined in an
+    /// `#[external]` impl.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
 }
 
-/// Contract event.
+/// Contract constructor ABI.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Constructor {
+    pub name: String,
+    pub inputs: Vec<Input>,
+}
+
+/// Contract event, emitted with its selector as its first key.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Event {
+    pub name: String,
+    /// The namespace of the selector of the event, if it is namespaced. The selector of a
+    /// namespaced event is `starknet_keccak("<namespace>::<name>")`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// The members serialized into the keys of the event, following its selector.
+    pub keys: Vec<Input>,
+    /// The members serialized into the data of the event.
+    pub data: Vec<Input>,
+}
+
+/// Contract L2 to L1 message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct L1Message {
+    pub name: String,
+    pub inputs: Vec<Input>,
+}
+
+/// Contract error, which a contract reverts with by panicking with the selector of its name,
+/// followed by its serialized data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractError {
     pub name: String,
     pub inputs: Vec<Input>,
 }
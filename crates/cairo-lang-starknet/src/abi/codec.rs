@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+
+use cairo_felt::Felt252;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Signed, Zero};
+use thiserror::Error;
+
+use super::{split_type_list, Contract, Function, Input, Item, Struct};
+
+#[cfg(test)]
+#[path = "codec_test.rs"]
+mod test;
+
+/// A value of a type of a contract ABI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// A value encoded as a single felt: a `felt252`, or a type wrapping it such as a
+    /// `ContractAddress`.
+    Felt252(BigUint),
+    Bool(bool),
+    /// An unsigned integer, from `u8` to `u512`, or the raw value of a `UFixed64x64`.
+    Uint(BigUint),
+    /// A signed integer, from `i8` to `i128`.
+    Int(BigInt),
+    /// An `Array` or a `Span`.
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
+    /// A struct, with the values of its members by name, in the order of their declaration.
+    Struct(Vec<(String, Value)>),
+    /// An enum, with the name of its variant and the value of the variant.
+    Enum(String, Box<Value>),
+}
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum CodecError {
+    #[error("Function not found: `{0}`.")]
+    FunctionNotFound(String),
+    #[error("Unsupported type: `{0}`.")]
+    UnsupportedType(String),
+    #[error("Expected {expected} values, got {actual}.")]
+    WrongNumberOfValues { expected: usize, actual: usize },
+    #[error("The value does not match the type `{0}`.")]
+    TypeMismatch(String),
+    #[error("The value is out of the range of the type `{0}`.")]
+    OutOfRange(String),
+    #[error("Invalid variant of the enum `{0}`.")]
+    InvalidVariant(String),
+    #[error("Missing data.")]
+    MissingData,
+    #[error("Unexpected data after the decoded values.")]
+    TrailingData,
+}
+
+/// The types whose values are encoded as a single felt.
+const FELT252_TYPES: [&str; 5] = [
+    "core::felt252",
+    "core::starknet::contract_address::ContractAddress",
+    "core::starknet::class_hash::ClassHash",
+    "core::starknet::eth_address::EthAddress",
+    "core::starknet::storage_access::StorageAddress",
+];
+
+/// The layout of the encoding of an ABI type, as serialized by its `Serde` implementation.
+enum TypeLayout<'a> {
+    Felt252,
+    Bool,
+    /// An unsigned integer of the given number of bits.
+    Uint(u32),
+    /// A signed integer of the given number of bits, encoded as a single felt, where negative
+    /// values are represented by their field element.
+    Int(u32),
+    /// An array of values of the given type, encoded as its length followed by its values.
+    Array(&'a str),
+    /// The values of the given types, one after the other.
+    Tuple(Vec<&'a str>),
+    Struct(&'a Struct),
+    /// An enum with the given variants, by name and type, encoded as the index of the variant
+    /// followed by the value of the variant.
+    Enum(Vec<(&'a str, &'a str)>),
+}
+
+/// Encodes values into calldata, and decodes values from return data, according to the types of
+/// a contract ABI, for tools calling the functions of a contract.
+pub struct AbiCodec<'a> {
+    functions: HashMap<&'a str, &'a Function>,
+    structs: HashMap<&'a str, &'a Struct>,
+    enums: HashMap<&'a str, Vec<(&'a str, &'a str)>>,
+}
+
+impl<'a> AbiCodec<'a> {
+    pub fn new(abi: &'a Contract) -> Self {
+        let mut codec =
+            Self { functions: HashMap::new(), structs: HashMap::new(), enums: HashMap::new() };
+        for item in &abi.items {
+            match item {
+                Item::Function(function) => {
+                    codec.functions.insert(&function.name, function);
+                }
+                Item::Struct(item) => {
+                    codec.structs.insert(&item.name, item);
+                }
+                Item::Enum(item) => {
+                    let variants = item
+                        .variants
+                        .iter()
+                        .map(|variant| (variant.name.as_str(), variant.ty.as_str()))
+                        .collect();
+                    codec.enums.insert(&item.name, variants);
+                }
+                _ => {}
+            }
+        }
+        codec
+    }
+
+    /// Encodes the arguments of a call to the function with the given name into calldata.
+    pub fn encode_calldata(
+        &self,
+        function_name: &str,
+        args: &[Value],
+    ) -> Result<Vec<BigUint>, CodecError> {
+        self.encode_inputs(&self.function(function_name)?.inputs, args)
+    }
+
+    /// Decodes the return data of a call to the function with the given name into the values of
+    /// its outputs.
+    pub fn decode_return_data(
+        &self,
+        function_name: &str,
+        mut data: &[BigUint],
+    ) -> Result<Vec<Value>, CodecError> {
+        let function = self.function(function_name)?;
+        let values = function
+            .outputs
+            .iter()
+            .map(|output| self.decode(&output.ty, &mut data))
+            .collect::<Result<Vec<_>, _>>()?;
+        if !data.is_empty() {
+            return Err(CodecError::TrailingData);
+        }
+        Ok(values)
+    }
+
+    /// Encodes a value of the given type, appending its felts to `output`.
+    pub fn encode(
+        &self,
+        ty: &str,
+        value: &Value,
+        output: &mut Vec<BigUint>,
+    ) -> Result<(), CodecError> {
+        let mismatch = || CodecError::TypeMismatch(ty.to_string());
+        match (self.layout(ty)?, value) {
+            (TypeLayout::Felt252, Value::Felt252(value)) => {
+                if *value >= Felt252::prime() {
+                    return Err(CodecError::OutOfRange(ty.to_string()));
+                }
+                output.push(value.clone());
+            }
+            (TypeLayout::Bool, Value::Bool(value)) => output.push(BigUint::from(*value as u8)),
+            (TypeLayout::Uint(bits), Value::Uint(value)) => {
+                if value.bits() > bits as u64 {
+                    return Err(CodecError::OutOfRange(ty.to_string()));
+                }
+                if bits > 128 {
+                    // Encoded as its limbs of 128 bits, from the least significant one, e.g. the
+                    // `low` and `high` members of a `u256`.
+                    let mask = (BigUint::one() << 128) - 1_u32;
+                    for limb in 0..bits / 128 {
+                        output.push((value >> (limb * 128)) & &mask);
+                    }
+                } else {
+                    output.push(value.clone());
+                }
+            }
+            (TypeLayout::Int(bits), Value::Int(value)) => {
+                if !int_in_range(value, bits) {
+                    return Err(CodecError::OutOfRange(ty.to_string()));
+                }
+                output.push(if value.is_negative() {
+                    Felt252::prime() - value.magnitude()
+                } else {
+                    value.magnitude().clone()
+                });
+            }
+            (TypeLayout::Array(element_ty), Value::Array(values)) => {
+                output.push(BigUint::from(values.len()));
+                for value in values {
+                    self.encode(element_ty, value, output)?;
+                }
+            }
+            (TypeLayout::Tuple(member_tys), Value::Tuple(values)) => {
+                if member_tys.len() != values.len() {
+                    return Err(CodecError::WrongNumberOfValues {
+                        expected: member_tys.len(),
+                        actual: values.len(),
+                    });
+                }
+                for (member_ty, value) in member_tys.into_iter().zip(values) {
+                    self.encode(member_ty, value, output)?;
+                }
+            }
+            (TypeLayout::Struct(item), Value::Struct(values)) => {
+                if item.members.len() != values.len() {
+                    return Err(CodecError::WrongNumberOfValues {
+                        expected: item.members.len(),
+                        actual: values.len(),
+                    });
+                }
+                for (member, (name, value)) in item.members.iter().zip(values) {
+                    if member.name != *name {
+                        return Err(mismatch());
+                    }
+                    self.encode(&member.ty, value, output)?;
+                }
+            }
+            (TypeLayout::Enum(variants), Value::Enum(name, value)) => {
+                let Some(idx) = variants.iter().position(|(variant, _)| variant == name) else {
+                    return Err(CodecError::InvalidVariant(ty.to_string()));
+                };
+                output.push(BigUint::from(idx));
+                self.encode(variants[idx].1, value, output)?;
+            }
+            _ => return Err(mismatch()),
+        }
+        Ok(())
+    }
+
+    /// Decodes a value of the given type from the start of `data`, advancing it past the decoded
+    /// felts.
+    pub fn decode(&self, ty: &str, data: &mut &[BigUint]) -> Result<Value, CodecError> {
+        Ok(match self.layout(ty)? {
+            TypeLayout::Felt252 => Value::Felt252(next_felt(data)?),
+            TypeLayout::Bool => Value::Bool(!next_felt(data)?.is_zero()),
+            TypeLayout::Uint(bits) => {
+                let value = if bits > 128 {
+                    let mut value = BigUint::zero();
+                    for limb in 0..bits / 128 {
+                        value += next_uint(data, 128, ty)? << (limb * 128);
+                    }
+                    value
+                } else {
+                    next_uint(data, bits, ty)?
+                };
+                Value::Uint(value)
+            }
+            TypeLayout::Int(bits) => {
+                let felt = next_felt(data)?;
+                let value = if felt > Felt252::prime() >> 1 {
+                    -BigInt::from(Felt252::prime() - felt)
+                } else {
+                    BigInt::from(felt)
+                };
+                if !int_in_range(&value, bits) {
+                    return Err(CodecError::OutOfRange(ty.to_string()));
+                }
+                Value::Int(value)
+            }
+            TypeLayout::Array(element_ty) => {
+                let len = usize::try_from(next_felt(data)?).map_err(|_| CodecError::MissingData)?;
+                Value::Array(
+                    (0..len)
+                        .map(|_| self.decode(element_ty, data))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )
+            }
+            TypeLayout::Tuple(member_tys) => Value::Tuple(
+                member_tys
+                    .into_iter()
+                    .map(|member_ty| self.decode(member_ty, data))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            TypeLayout::Struct(item) => Value::Struct(
+                item.members
+                    .iter()
+                    .map(|member| Ok((member.name.clone(), self.decode(&member.ty, data)?)))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            TypeLayout::Enum(variants) => {
+                let Some((name, variant_ty)) = usize::try_from(next_felt(data)?)
+                    .ok()
+                    .and_then(|idx| variants.get(idx))
+                else {
+                    return Err(CodecError::InvalidVariant(ty.to_string()));
+                };
+                Value::Enum(name.to_string(), Box::new(self.decode(variant_ty, data)?))
+            }
+        })
+    }
+
+    /// Encodes the arguments of the given inputs, one after the other.
+    fn encode_inputs(&self, inputs: &[Input], args: &[Value]) -> Result<Vec<BigUint>, CodecError> {
+        if inputs.len() != args.len() {
+            return Err(CodecError::WrongNumberOfValues {
+                expected: inputs.len(),
+                actual: args.len(),
+            });
+        }
+        let mut calldata = vec![];
+        for (input, arg) in inputs.iter().zip(args) {
+            self.encode(&input.ty, arg, &mut calldata)?;
+        }
+        Ok(calldata)
+    }
+
+    /// Returns the function with the given name.
+    fn function(&self, function_name: &str) -> Result<&'a Function, CodecError> {
+        self.functions
+            .get(function_name)
+            .copied()
+            .ok_or_else(|| CodecError::FunctionNotFound(function_name.to_string()))
+    }
+
+    /// Returns the layout of the encoding of the given type.
+    fn layout<'b>(&self, ty: &'b str) -> Result<TypeLayout<'b>, CodecError>
+    where
+        'a: 'b,
+    {
+        let ty = ty.trim();
+        // Snapshots are encoded as the values they are snapshots of.
+        if let Some(inner) = ty.strip_prefix('@') {
+            return self.layout(inner);
+        }
+        if let Some(inner) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+            return Ok(TypeLayout::Tuple(split_type_list(inner)));
+        }
+        if FELT252_TYPES.contains(&ty) {
+            return Ok(TypeLayout::Felt252);
+        }
+        match ty {
+            "core::bool" => return Ok(TypeLayout::Bool),
+            "core::integer::u8" => return Ok(TypeLayout::Uint(8)),
+            "core::integer::u16" => return Ok(TypeLayout::Uint(16)),
+            "core::integer::u32" => return Ok(TypeLayout::Uint(32)),
+            "core::integer::u64" => return Ok(TypeLayout::Uint(64)),
+            "core::integer::u128" => return Ok(TypeLayout::Uint(128)),
+            "core::integer::u256" => return Ok(TypeLayout::Uint(256)),
+            "core::integer::u512" => return Ok(TypeLayout::Uint(512)),
+            // Encoded as its raw value, i.e. the number multiplied by `2**64`.
+            "core::fixed::UFixed64x64" => return Ok(TypeLayout::Uint(128)),
+            "core::integer::i8" => return Ok(TypeLayout::Int(8)),
+            "core::integer::i16" => return Ok(TypeLayout::Int(16)),
+            "core::integer::i32" => return Ok(TypeLayout::Int(32)),
+            "core::integer::i64" => return Ok(TypeLayout::Int(64)),
+            "core::integer::i128" => return Ok(TypeLayout::Int(128)),
+            _ => {}
+        }
+        if let Some(item) = self.structs.get(ty) {
+            return Ok(TypeLayout::Struct(item));
+        }
+        if let Some(variants) = self.enums.get(ty) {
+            return Ok(TypeLayout::Enum(variants.clone()));
+        }
+        let generic_arg = |base: &str| {
+            ty.strip_prefix(base).and_then(|rest| rest.strip_prefix("::<")?.strip_suffix('>'))
+        };
+        if let Some(element_ty) =
+            generic_arg("core::array::Array").or_else(|| generic_arg("core::array::Span"))
+        {
+            return Ok(TypeLayout::Array(element_ty));
+        }
+        if let Some(value_ty) = generic_arg("core::option::Option") {
+            return Ok(TypeLayout::Enum(vec![("Some", value_ty), ("None", "()")]));
+        }
+        Err(CodecError::UnsupportedType(ty.to_string()))
+    }
+}
+
+/// Pops the next felt of the data.
+fn next_felt(data: &mut &[BigUint]) -> Result<BigUint, CodecError> {
+    let Some((felt, rest)) = data.split_first() else {
+        return Err(CodecError::MissingData);
+    };
+    *data = rest;
+    Ok(felt.clone())
+}
+
+/// Pops the next felt of the data, as an unsigned integer of the given number of bits.
+fn next_uint(data: &mut &[BigUint], bits: u32, ty: &str) -> Result<BigUint, CodecError> {
+    let value = next_felt(data)?;
+    if value.bits() > bits as u64 {
+        return Err(CodecError::OutOfRange(ty.to_string()));
+    }
+    Ok(value)
+}
+
+/// Returns whether the value is in the range of a signed integer of the given number of bits.
+fn int_in_range(value: &BigInt, bits: u32) -> bool {
+    let bound = BigInt::one() << (bits - 1);
+    -&bound <= *value && *value < bound
+}
@@ -0,0 +1,223 @@
+use cairo_felt::Felt252;
+use indoc::indoc;
+use num_bigint::BigUint;
+use pretty_assertions::assert_eq;
+
+use super::{AbiCodec, CodecError, Value};
+use crate::abi::Contract;
+
+const TEST_ABI: &str = indoc! {r#"
+    [
+      {
+        "type": "struct",
+        "name": "test::Order",
+        "members": [
+          {
+            "name": "amount",
+            "type": "core::integer::u256"
+          },
+          {
+            "name": "memo",
+            "type": "core::option::Option::<core::felt252>"
+          }
+        ]
+      },
+      {
+        "type": "enum",
+        "name": "test::Side",
+        "variants": [
+          {
+            "name": "Buy",
+            "type": "()"
+          },
+          {
+            "name": "Sell",
+            "type": "core::integer::u8"
+          }
+        ]
+      },
+      {
+        "type": "function",
+        "name": "place",
+        "inputs": [
+          {
+            "name": "trader",
+            "type": "core::starknet::contract_address::ContractAddress"
+          },
+          {
+            "name": "order",
+            "type": "test::Order"
+          },
+          {
+            "name": "sides",
+            "type": "core::array::Span::<test::Side>"
+          }
+        ],
+        "outputs": [
+          {
+            "type": "(core::bool, core::integer::u128)"
+          }
+        ],
+        "state_mutability": "external"
+      }
+    ]"#};
+
+fn felts(values: &[u128]) -> Vec<BigUint> {
+    values.iter().map(|value| BigUint::from(*value)).collect()
+}
+
+fn order(amount: BigUint) -> Value {
+    Value::Struct(vec![
+        ("amount".into(), Value::Uint(amount)),
+        ("memo".into(), Value::Enum("Some".into(), Box::new(Value::Felt252(5_u32.into())))),
+    ])
+}
+
+#[test]
+fn test_encode_calldata() {
+    let abi: Contract = serde_json::from_str(TEST_ABI).unwrap();
+    let codec = AbiCodec::new(&abi);
+    let amount = (BigUint::from(2_u32) << 128_usize) + 3_u32;
+    let args = [
+        Value::Felt252(7_u32.into()),
+        order(amount.clone()),
+        Value::Array(vec![
+            Value::Enum("Buy".into(), Box::new(Value::Tuple(vec![]))),
+            Value::Enum("Sell".into(), Box::new(Value::Uint(9_u32.into()))),
+        ]),
+    ];
+    let calldata = codec.encode_calldata("place", &args).unwrap();
+    // The trader, the low and high parts of the amount, `Option::Some` of the memo, and the length
+    // and variants of the sides.
+    assert_eq!(calldata, felts(&[7, 3, 2, 0, 5, 2, 0, 1, 9]));
+
+    // Values are decoded as they were encoded.
+    let mut data = calldata.as_slice();
+    assert_eq!(
+        codec.decode("core::starknet::contract_address::ContractAddress", &mut data),
+        Ok(args[0].clone())
+    );
+    assert_eq!(codec.decode("@test::Order", &mut data), Ok(order(amount)));
+    assert_eq!(codec.decode("core::array::Array::<test::Side>", &mut data), Ok(args[2].clone()));
+    assert!(data.is_empty());
+}
+
+#[test]
+fn test_decode_return_data() {
+    let abi: Contract = serde_json::from_str(TEST_ABI).unwrap();
+    let codec = AbiCodec::new(&abi);
+    assert_eq!(
+        codec.decode_return_data("place", &felts(&[1, 100])),
+        Ok(vec![Value::Tuple(vec![Value::Bool(true), Value::Uint(100_u32.into())])])
+    );
+    assert_eq!(codec.decode_return_data("place", &felts(&[1])), Err(CodecError::MissingData));
+    assert_eq!(
+        codec.decode_return_data("place", &felts(&[1, 100, 0])),
+        Err(CodecError::TrailingData)
+    );
+    assert_eq!(
+        codec.decode_return_data("cancel", &[]),
+        Err(CodecError::FunctionNotFound("cancel".into()))
+    );
+}
+
+#[test]
+fn test_encode_wide_integers() {
+    let abi: Contract = serde_json::from_str(TEST_ABI).unwrap();
+    let codec = AbiCodec::new(&abi);
+    let value = (BigUint::from(4_u32) << 384_usize)
+        + (BigUint::from(3_u32) << 256_usize)
+        + (BigUint::from(2_u32) << 128_usize)
+        + 1_u32;
+    let mut output = vec![];
+    codec.encode("core::integer::u512", &Value::Uint(value.clone()), &mut output).unwrap();
+    // The limbs of the value, from the least significant one.
+    assert_eq!(output, felts(&[1, 2, 3, 4]));
+    let mut data = output.as_slice();
+    assert_eq!(codec.decode("core::integer::u512", &mut data), Ok(Value::Uint(value.clone())));
+    assert!(data.is_empty());
+
+    assert_eq!(
+        codec.encode("core::integer::u256", &Value::Uint(value), &mut output),
+        Err(CodecError::OutOfRange("core::integer::u256".into()))
+    );
+    let limb_out_of_range = [BigUint::from(1_u32) << 128_usize, 0_u32.into()];
+    assert_eq!(
+        codec.decode("core::integer::u256", &mut limb_out_of_range.as_slice()),
+        Err(CodecError::OutOfRange("core::integer::u256".into()))
+    );
+}
+
+#[test]
+fn test_encode_fixed_point() {
+    let abi: Contract = serde_json::from_str(TEST_ABI).unwrap();
+    let codec = AbiCodec::new(&abi);
+    // 1.5, as its raw value.
+    let value = Value::Uint(BigUint::from(3_u32) << 63_usize);
+    let mut output = vec![];
+    codec.encode("core::fixed::UFixed64x64", &value, &mut output).unwrap();
+    assert_eq!(output, vec![BigUint::from(3_u32) << 63_usize]);
+    assert_eq!(codec.decode("core::fixed::UFixed64x64", &mut output.as_slice()), Ok(value));
+    assert_eq!(
+        codec.encode(
+            "core::fixed::UFixed64x64",
+            &Value::Uint(BigUint::from(1_u32) << 128_usize),
+            &mut output
+        ),
+        Err(CodecError::OutOfRange("core::fixed::UFixed64x64".into()))
+    );
+}
+
+#[test]
+fn test_encode_signed_integers() {
+    let abi: Contract = serde_json::from_str(TEST_ABI).unwrap();
+    let codec = AbiCodec::new(&abi);
+    let mut output = vec![];
+    codec.encode("core::integer::i8", &Value::Int((-128).into()), &mut output).unwrap();
+    codec.encode("core::integer::i64", &Value::Int(5.into()), &mut output).unwrap();
+    // Negative values are encoded as their field element.
+    assert_eq!(output, vec![Felt252::prime() - 128_u32, 5_u32.into()]);
+    let mut data = output.as_slice();
+    assert_eq!(codec.decode("core::integer::i8", &mut data), Ok(Value::Int((-128).into())));
+    assert_eq!(codec.decode("core::integer::i64", &mut data), Ok(Value::Int(5.into())));
+    assert!(data.is_empty());
+
+    assert_eq!(
+        codec.encode("core::integer::i8", &Value::Int(128.into()), &mut output),
+        Err(CodecError::OutOfRange("core::integer::i8".into()))
+    );
+    let below_range = [Felt252::prime() - 129_u32];
+    assert_eq!(
+        codec.decode("core::integer::i8", &mut below_range.as_slice()),
+        Err(CodecError::OutOfRange("core::integer::i8".into()))
+    );
+}
+
+#[test]
+fn test_encode_failures() {
+    let abi: Contract = serde_json::from_str(TEST_ABI).unwrap();
+    let codec = AbiCodec::new(&abi);
+    let mut output = vec![];
+    assert_eq!(
+        codec.encode("core::integer::u8", &Value::Uint(256_u32.into()), &mut output),
+        Err(CodecError::OutOfRange("core::integer::u8".into()))
+    );
+    assert_eq!(
+        codec.encode("core::felt252", &Value::Uint(1_u32.into()), &mut output),
+        Err(CodecError::TypeMismatch("core::felt252".into()))
+    );
+    let hold = Value::Enum("Hold".into(), Box::new(Value::Tuple(vec![])));
+    assert_eq!(
+        codec.encode("test::Side", &hold, &mut output),
+        Err(CodecError::InvalidVariant("test::Side".into()))
+    );
+    assert_eq!(
+        codec.encode("test::Unknown", &Value::Tuple(vec![]), &mut output),
+        Err(CodecError::UnsupportedType("test::Unknown".into()))
+    );
+    assert_eq!(
+        codec.encode_calldata("place", &[]),
+        Err(CodecError::WrongNumberOfValues { expected: 3, actual: 0 })
+    );
+    assert!(output.is_empty());
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+use super::{AbiItemKind, Contract, Item, StateMutability};
+
+#[cfg(test)]
+#[path = "diff_test.rs"]
+mod test;
+
+/// A change between two versions of a contract ABI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiChange {
+    Added {
+        kind: AbiItemKind,
+        name: String,
+    },
+    Removed {
+        kind: AbiItemKind,
+        name: String,
+    },
+    /// The inputs or outputs of a function, the inputs of a constructor, the keys, the data or the
+    /// namespace of an event, the inputs of an L1 message or an error, or the members or variants
+    /// of a struct or an enum changed. As they are serialized by their position, only their types
+    /// and their order are compared, so renaming them is not a change.
+    SignatureChanged {
+        kind: AbiItemKind,
+        name: String,
+    },
+    /// The state mutability of a function changed.
+    StateMutabilityChanged {
+        name: String,
+        old: StateMutability,
+        new: StateMutability,
+    },
+    /// A function was deprecated.
+    Deprecated {
+        name: String,
+    },
+}
+
+impl AbiChange {
+    /// Returns whether the change breaks the users of the previous version of the ABI: whether an
+    /// item they use was removed or changed, or a function they only read with may now modify the
+    /// state of the contract.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            AbiChange::Added { .. } | AbiChange::Deprecated { .. } => false,
+            AbiChange::Removed { .. } | AbiChange::SignatureChanged { .. } => true,
+            AbiChange::StateMutabilityChanged { new, .. } => *new == StateMutability::External,
+        }
+    }
+}
+
+impl fmt::Display for AbiChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let compatibility = if self.is_breaking() { "breaking" } else { "compatible" };
+        match self {
+            AbiChange::Added { kind, name } => write!(f, "{compatibility}: added {kind} `{name}`"),
+            AbiChange::Removed { kind, name } => {
+                write!(f, "{compatibility}: removed {kind} `{name}`")
+            }
+            AbiChange::SignatureChanged { kind, name } => {
+                write!(f, "{compatibility}: changed the signature of {kind} `{name}`")
+            }
+            AbiChange::StateMutabilityChanged { name, old, new } => write!(
+                f,
+                "{compatibility}: changed the state mutability of function `{name}` from {old:?} \
+                 to {new:?}"
+            ),
+            AbiChange::Deprecated { name } => {
+                write!(f, "{compatibility}: deprecated function `{name}`")
+            }
+        }
+    }
+}
+
+/// Returns the changes from the old version of a contract ABI to the new one: the items that were
+/// removed or changed, in the order of the old ABI, followed by the items that were added, in the
+/// order of the new ABI.
+pub fn abi_diff(old: &Contract, new: &Contract) -> Vec<AbiChange> {
+    let old_items = items_by_key(old);
+    let new_items = items_by_key(new);
+    let mut changes = vec![];
+    for ((kind, name), old_item) in old_items.iter() {
+        let name = name.to_string();
+        let Some(new_item) = new_items.get(&(*kind, name.as_str())) else {
+            changes.push(AbiChange::Removed { kind: *kind, name });
+            continue;
+        };
+        match (old_item, new_item) {
+            (
+                Item::Function(old) | Item::Default(old) | Item::L1Handler(old),
+                Item::Function(new) | Item::Default(new) | Item::L1Handler(new),
+            ) => {
+                if !same_types(&old.inputs, &new.inputs, |input| &input.ty)
+                    || old.outputs != new.outputs
+                {
+                    changes.push(AbiChange::SignatureChanged { kind: *kind, name: name.clone() });
+                }
+                if old.state_mutability != new.state_mutability {
+                    changes.push(AbiChange::StateMutabilityChanged {
+                        name: name.clone(),
+                        old: old.state_mutability.clone(),
+                        new: new.state_mutability.clone(),
+                    });
+                }
+                if old.deprecated.is_none() && new.deprecated.is_some() {
+                    changes.push(AbiChange::Deprecated { name });
+                }
+            }
+            _ => {
+                if !same_signature(old_item, new_item) {
+                    changes.push(AbiChange::SignatureChanged { kind: *kind, name });
+                }
+            }
+        }
+    }
+    for (kind, name) in new_items.keys() {
+        if !old_items.contains_key(&(*kind, *name)) {
+            changes.push(AbiChange::Added { kind: *kind, name: name.to_string() });
+        }
+    }
+    changes
+}
+
+/// Returns the items of the ABI by their kind and name.
+fn items_by_key(abi: &Contract) -> OrderedHashMap<(AbiItemKind, &str), &Item> {
+    abi.items.iter().map(|item| ((item.kind(), item.name()), item)).collect()
+}
+
+/// Returns whether two items of the same kind and name, other than functions, default entry points
+/// and L1 handlers, have the same signature.
+fn same_signature(old: &Item, new: &Item) -> bool {
+    match (old, new) {
+        (Item::Constructor(old), Item::Constructor(new)) => {
+            same_types(&old.inputs, &new.inputs, |input| &input.ty)
+        }
+        (Item::Event(old), Item::Event(new)) => {
+            same_types(&old.keys, &new.keys, |key| &key.ty)
+                && same_types(&old.data, &new.data, |data| &data.ty)
+                && old.namespace == new.namespace
+        }
+        (Item::L1Message(old), Item::L1Message(new)) => {
+            same_types(&old.inputs, &new.inputs, |input| &input.ty)
+        }
+        (Item::Error(old), Item::Error(new)) => {
+            same_types(&old.inputs, &new.inputs, |input| &input.ty)
+        }
+        (Item::Struct(old), Item::Struct(new)) => {
+            same_types(&old.members, &new.members, |member| &member.ty)
+        }
+        (Item::Enum(old), Item::Enum(new)) => {
+            same_types(&old.variants, &new.variants, |variant| &variant.ty)
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether two lists of named values have the same types in the same order, regardless of
+/// the names of the values.
+fn same_types<T>(old: &[T], new: &[T], ty: impl Fn(&T) -> &str) -> bool {
+    old.iter().map(&ty).eq(new.iter().map(&ty))
+}
@@ -0,0 +1,215 @@
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+
+use super::{abi_diff, AbiChange};
+use crate::abi::{AbiItemKind, Contract, Item, StateMutability};
+
+const OLD_ABI: &str = indoc! {r#"
+    [
+      {
+        "type": "function",
+        "name": "balance_of",
+        "inputs": [
+          {
+            "name": "account",
+            "type": "core::felt252"
+          }
+        ],
+        "outputs": [
+          {
+            "type": "core::integer::u256"
+          }
+        ],
+        "state_mutability": "view"
+      },
+      {
+        "type": "function",
+        "name": "transfer",
+        "inputs": [
+          {
+            "name": "amount",
+            "type": "core::integer::u128"
+          }
+        ],
+        "outputs": [],
+        "state_mutability": "external"
+      },
+      {
+        "type": "function",
+        "name": "get_owner",
+        "inputs": [],
+        "outputs": [
+          {
+            "type": "core::felt252"
+          }
+        ],
+        "state_mutability": "view"
+      },
+      {
+        "type": "event",
+        "name": "Transfer",
+        "keys": [],
+        "data": [
+          {
+            "name": "amount",
+            "type": "core::integer::u128"
+          }
+        ]
+      }
+    ]"#};
+
+const NEW_ABI: &str = indoc! {r#"
+    [
+      {
+        "type": "function",
+        "name": "balance_of",
+        "inputs": [
+          {
+            "name": "account",
+            "type": "core::felt252"
+          }
+        ],
+        "outputs": [
+          {
+            "type": "core::integer::u256"
+          }
+        ],
+        "state_mutability": "view",
+        "deprecated": "Use balance"
+      },
+      {
+        "type": "function",
+        "name": "transfer",
+        "inputs": [
+          {
+            "name": "amount",
+            "type": "core::integer::u256"
+          }
+        ],
+        "outputs": [],
+        "state_mutability": "external"
+      },
+      {
+        "type": "function",
+        "name": "get_owner",
+        "inputs": [],
+        "outputs": [
+          {
+            "type": "core::felt252"
+          }
+        ],
+        "state_mutability": "external"
+      },
+      {
+        "type": "function",
+        "name": "balance",
+        "inputs": [],
+        "outputs": [
+          {
+            "type": "core::integer::u256"
+          }
+        ],
+        "state_mutability": "view"
+      }
+    ]"#};
+
+#[test]
+fn test_abi_diff() {
+    let old: Contract = serde_json::from_str(OLD_ABI).unwrap();
+    let new: Contract = serde_json::from_str(NEW_ABI).unwrap();
+    let changes = abi_diff(&old, &new);
+    assert_eq!(
+        changes,
+        vec![
+            AbiChange::Deprecated { name: "balance_of".into() },
+            AbiChange::SignatureChanged { kind: AbiItemKind::Function, name: "transfer".into() },
+            AbiChange::StateMutabilityChanged {
+                name: "get_owner".into(),
+                old: StateMutability::View,
+                new: StateMutability::External,
+            },
+            AbiChange::Removed { kind: AbiItemKind::Event, name: "Transfer".into() },
+            AbiChange::Added { kind: AbiItemKind::Function, name: "balance".into() },
+        ]
+    );
+    assert_eq!(
+        changes.iter().map(|change| change.to_string()).join("\n"),
+        indoc! {"
+            compatible: deprecated function `balance_of`
+            breaking: changed the signature of function `transfer`
+            breaking: changed the state mutability of function `get_owner` from View to External
+            breaking: removed event `Transfer`
+            compatible: added function `balance`"}
+    );
+
+    // Going back removes the added function, and changes the signature of `transfer` again.
+    let changes = abi_diff(&new, &old);
+    assert_eq!(
+        changes.iter().filter(|change| change.is_breaking()).cloned().collect_vec(),
+        vec![
+            AbiChange::SignatureChanged { kind: AbiItemKind::Function, name: "transfer".into() },
+            AbiChange::Removed { kind: AbiItemKind::Function, name: "balance".into() },
+        ]
+    );
+    assert!(abi_diff(&old, &old).is_empty());
+}
+
+/// Tests that renaming the inputs of a function, the members of an event, a struct or an enum, or
+/// the variants of an enum is compatible, while reordering them is not.
+#[test]
+fn test_abi_diff_renames() {
+    let abi = |first: &str, second: &str| -> Contract {
+        serde_json::from_str(&format!(
+            r#"[
+              {{
+                "type": "function",
+                "name": "transfer",
+                "inputs": [
+                  {{ "name": "{first}", "type": "core::felt252" }},
+                  {{ "name": "{second}", "type": "core::integer::u128" }}
+                ],
+                "outputs": [],
+                "state_mutability": "external"
+              }},
+              {{
+                "type": "event",
+                "name": "Transfer",
+                "keys": [{{ "name": "{first}", "type": "core::felt252" }}],
+                "data": [{{ "name": "{second}", "type": "core::integer::u128" }}]
+              }},
+              {{
+                "type": "struct",
+                "name": "Pair",
+                "members": [
+                  {{ "name": "{first}", "type": "core::felt252" }},
+                  {{ "name": "{second}", "type": "core::integer::u128" }}
+                ]
+              }},
+              {{
+                "type": "enum",
+                "name": "Either",
+                "variants": [
+                  {{ "name": "{first}", "type": "core::felt252" }},
+                  {{ "name": "{second}", "type": "core::integer::u128" }}
+                ]
+              }}
+            ]"#
+        ))
+        .unwrap()
+    };
+    assert_eq!(abi_diff(&abi("to", "amount"), &abi("recipient", "value")), vec![]);
+
+    let mut reordered = abi("to", "amount");
+    let Item::Function(transfer) = &mut reordered.items[0] else { panic!("Expected a function.") };
+    transfer.inputs.reverse();
+    let Item::Struct(pair) = &mut reordered.items[2] else { panic!("Expected a struct.") };
+    pair.members.reverse();
+    assert_eq!(
+        abi_diff(&abi("to", "amount"), &reordered),
+        vec![
+            AbiChange::SignatureChanged { kind: AbiItemKind::Function, name: "transfer".into() },
+            AbiChange::SignatureChanged { kind: AbiItemKind::Struct, name: "Pair".into() },
+        ]
+    );
+}
@@ -0,0 +1,223 @@
+use std::collections::HashSet;
+
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use super::{AbiItemKind, Contract, Input, Item};
+
+#[cfg(test)]
+#[path = "validation_test.rs"]
+mod test;
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum AbiValidationError {
+    #[error("Invalid JSON: {0}")]
+    InvalidJson(String),
+    #[error("An ABI must be a JSON array of entries.")]
+    NotAnArray,
+    #[error("Entry {index}: an entry must be a JSON object.")]
+    NotAnObject { index: usize },
+    #[error("Entry {index}: missing entry type.")]
+    MissingEntryType { index: usize },
+    #[error("Entry {index}: unknown entry type `{entry_type}`.")]
+    UnknownEntryType { index: usize, entry_type: String },
+    #[error("Entry {index}: missing field `{field}`.")]
+    MissingField { index: usize, field: String },
+    #[error("Entry {index}: unknown field `{field}`.")]
+    UnknownField { index: usize, field: String },
+    #[error("Entry {index}: {message}")]
+    InvalidEntry { index: usize, message: String },
+    #[error("Duplicate {kind} `{name}`.")]
+    DuplicateName { kind: AbiItemKind, name: String },
+    #[error("Duplicate member `{member}` of {kind} `{name}`.")]
+    DuplicateMember { kind: AbiItemKind, name: String, member: String },
+}
+
+/// The required and optional fields of the entries of each type, and of their members.
+struct EntrySchema {
+    entry_type: &'static str,
+    required: &'static [&'static str],
+    optional: &'static [&'static str],
+    /// The fields listing members, along with the fields of the members.
+    member_lists: &'static [(&'static str, &'static [&'static str])],
+}
+
+const NAMED_TYPE_FIELDS: &[&str] = &["name", "type"];
+
+const ENTRY_SCHEMAS: &[EntrySchema] = &[
+    EntrySchema {
+        entry_type: "function",
+        required: &["name", "inputs", "outputs", "state_mutability"],
+        optional: &["deprecated", "interface"],
+        member_lists: &[("inputs", NAMED_TYPE_FIELDS), ("outputs", &["type"])],
+    },
+    EntrySchema {
+        entry_type: "default",
+        required: &["name", "inputs", "outputs", "state_mutability"],
+        optional: &["deprecated", "interface"],
+        member_lists: &[("inputs", NAMED_TYPE_FIELDS), ("outputs", &["type"])],
+    },
+    EntrySchema {
+        entry_type: "constructor",
+        required: &["name", "inputs"],
+        optional: &[],
+        member_lists: &[("inputs", NAMED_TYPE_FIELDS)],
+    },
+    EntrySchema {
+        entry_type: "l1_handler",
+        required: &["name", "inputs", "outputs", "state_mutability"],
+        optional: &["deprecated", "interface"],
+        member_lists: &[("inputs", NAMED_TYPE_FIELDS), ("outputs", &["type"])],
+    },
+    EntrySchema {
+        entry_type: "event",
+        required: &["name", "keys", "data"],
+        optional: &["namespace"],
+        member_lists: &[("keys", NAMED_TYPE_FIELDS), ("data", NAMED_TYPE_FIELDS)],
+    },
+    EntrySchema {
+        entry_type: "l1_message",
+        required: &["name", "inputs"],
+        optional: &[],
+        member_lists: &[("inputs", NAMED_TYPE_FIELDS)],
+    },
+    EntrySchema {
+        entry_type: "error",
+        required: &["name", "inputs"],
+        optional: &[],
+        member_lists: &[("inputs", NAMED_TYPE_FIELDS)],
+    },
+    EntrySchema {
+        entry_type: "struct",
+        required: &["name", "members"],
+        optional: &[],
+        member_lists: &[("members", NAMED_TYPE_FIELDS)],
+    },
+    EntrySchema {
+        entry_type: "enum",
+        required: &["name", "variants"],
+        optional: &[],
+        member_lists: &[("variants", NAMED_TYPE_FIELDS)],
+    },
+];
+
+/// Deserializes a contract ABI from JSON, rejecting malformed ABIs: entries of unknown types,
+/// entries with missing or unknown fields, and duplicate names of entries or of their members.
+/// The names of the entry points must be unique across functions, default entry points,
+/// constructors and L1 handlers, and the names of the types across structs and enums.
+pub fn parse_abi(json: &str) -> Result<Contract, AbiValidationError> {
+    let value: Value =
+        serde_json::from_str(json).map_err(|e| AbiValidationError::InvalidJson(e.to_string()))?;
+    let Value::Array(entries) = value else {
+        return Err(AbiValidationError::NotAnArray);
+    };
+    let items = entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| parse_entry(index, entry))
+        .collect::<Result<Vec<_>, _>>()?;
+    validate_names(&items)?;
+    Ok(Contract { items })
+}
+
+/// Deserializes an entry of an ABI, after validating its fields against the schema of its type.
+fn parse_entry(index: usize, entry: Value) -> Result<Item, AbiValidationError> {
+    let Value::Object(fields) = &entry else {
+        return Err(AbiValidationError::NotAnObject { index });
+    };
+    let Some(Value::String(entry_type)) = fields.get("type") else {
+        return Err(AbiValidationError::MissingEntryType { index });
+    };
+    let Some(schema) = ENTRY_SCHEMAS.iter().find(|schema| schema.entry_type == entry_type) else {
+        return Err(AbiValidationError::UnknownEntryType {
+            index,
+            entry_type: entry_type.clone(),
+        });
+    };
+    let mut allowed = vec!["type"];
+    allowed.extend(schema.required);
+    allowed.extend(schema.optional);
+    validate_fields(index, fields, schema.required, &allowed)?;
+    for (list_field, member_fields) in schema.member_lists {
+        let Value::Array(members) = &fields[*list_field] else {
+            return Err(AbiValidationError::InvalidEntry {
+                index,
+                message: format!("`{list_field}` must be an array."),
+            });
+        };
+        for member in members {
+            let Value::Object(member) = member else {
+                return Err(AbiValidationError::InvalidEntry {
+                    index,
+                    message: format!("The members of `{list_field}` must be objects."),
+                });
+            };
+            validate_fields(index, member, member_fields, member_fields)?;
+        }
+    }
+    serde_json::from_value(entry)
+        .map_err(|e| AbiValidationError::InvalidEntry { index, message: e.to_string() })
+}
+
+/// Checks that the object has all the required fields, and only allowed fields.
+fn validate_fields(
+    index: usize,
+    fields: &Map<String, Value>,
+    required: &[&str],
+    allowed: &[&str],
+) -> Result<(), AbiValidationError> {
+    if let Some(field) = required.iter().find(|field| !fields.contains_key(**field)) {
+        return Err(AbiValidationError::MissingField { index, field: field.to_string() });
+    }
+    if let Some(field) = fields.keys().find(|field| !allowed.contains(&field.as_str())) {
+        return Err(AbiValidationError::UnknownField { index, field: field.clone() });
+    }
+    Ok(())
+}
+
+/// Checks that the names of the items, and of the members of each item, are unique.
+fn validate_names(items: &[Item]) -> Result<(), AbiValidationError> {
+    let mut names = HashSet::new();
+    for item in items {
+        let namespace = match item.kind() {
+            AbiItemKind::Function
+            | AbiItemKind::DefaultEntryPoint
+            | AbiItemKind::Constructor
+            | AbiItemKind::L1Handler => AbiItemKind::Function,
+            AbiItemKind::Struct | AbiItemKind::Enum => AbiItemKind::Struct,
+            kind => kind,
+        };
+        if !names.insert((namespace, item.name())) {
+            return Err(AbiValidationError::DuplicateName {
+                kind: item.kind(),
+                name: item.name().to_string(),
+            });
+        }
+        let member_names: Vec<&str> = match item {
+            Item::Function(item) | Item::Default(item) | Item::L1Handler(item) => {
+                input_names(&item.inputs)
+            }
+            Item::Constructor(item) => input_names(&item.inputs),
+            Item::Event(item) => {
+                item.keys.iter().chain(&item.data).map(|input| input.name.as_str()).collect()
+            }
+            Item::L1Message(item) => input_names(&item.inputs),
+            Item::Error(item) => input_names(&item.inputs),
+            Item::Struct(item) => item.members.iter().map(|member| member.name.as_str()).collect(),
+            Item::Enum(item) => item.variants.iter().map(|variant| variant.name.as_str()).collect(),
+        };
+        let mut seen = HashSet::new();
+        if let Some(member) = member_names.into_iter().find(|member| !seen.insert(*member)) {
+            return Err(AbiValidationError::DuplicateMember {
+                kind: item.kind(),
+                name: item.name().to_string(),
+                member: member.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn input_names(inputs: &[Input]) -> Vec<&str> {
+    inputs.iter().map(|input| input.name.as_str()).collect()
+}
@@ -0,0 +1,99 @@
+use std::fs::read_to_string;
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{parse_abi, AbiValidationError};
+use crate::abi::AbiItemKind;
+use crate::contract_class::ContractClass;
+use crate::test_utils::get_example_file_path;
+
+#[test_case("test_contract")]
+#[test_case("erc20")]
+#[test_case("token_bridge")]
+fn test_parse_abi(example_file_name: &str) {
+    let contract_class: ContractClass = serde_json::from_str(
+        &read_to_string(get_example_file_path(&format!("{example_file_name}.sierra.json")))
+            .unwrap(),
+    )
+    .unwrap();
+    let abi = contract_class.abi.unwrap();
+    assert_eq!(parse_abi(&abi.json()), Ok(abi));
+}
+
+#[test_case(
+    "{}",
+    AbiValidationError::NotAnArray;
+    "not an array"
+)]
+#[test_case(
+    r#"[{"name": "foo"}]"#,
+    AbiValidationError::MissingEntryType { index: 0 };
+    "missing entry type"
+)]
+#[test_case(
+    r#"[{"type": "constant", "name": "foo"}]"#,
+    AbiValidationError::UnknownEntryType { index: 0, entry_type: "constant".into() };
+    "unknown entry type"
+)]
+#[test_case(
+    r#"[{"type": "struct", "name": "Foo", "members": []}, {"type": "event", "name": "Foo"}]"#,
+    AbiValidationError::MissingField { index: 1, field: "keys".into() };
+    "missing field"
+)]
+#[test_case(
+    r#"[{"type": "event", "name": "Foo", "keys": [], "data": [], "inputs": []}]"#,
+    AbiValidationError::UnknownField { index: 0, field: "inputs".into() };
+    "unknown field"
+)]
+#[test_case(
+    r#"[{"type": "event", "name": "Foo", "keys": [{"name": "a"}], "data": []}]"#,
+    AbiValidationError::MissingField { index: 0, field: "type".into() };
+    "missing member field"
+)]
+#[test_case(
+    indoc! {r#"
+        [
+          {
+            "type": "function",
+            "name": "foo",
+            "inputs": [],
+            "outputs": [],
+            "state_mutability": "pure"
+          }
+        ]"#},
+    AbiValidationError::InvalidEntry {
+        index: 0,
+        message: "unknown variant `pure`, expected `external` or `view`".into(),
+    };
+    "invalid state mutability"
+)]
+#[test_case(
+    indoc! {r#"
+        [
+          {"type": "struct", "name": "Foo", "members": []},
+          {"type": "enum", "name": "Foo", "variants": []}
+        ]"#},
+    AbiValidationError::DuplicateName { kind: AbiItemKind::Enum, name: "Foo".into() };
+    "duplicate type name"
+)]
+#[test_case(
+    indoc! {r#"
+        [
+          {
+            "type": "struct",
+            "name": "Foo",
+            "members": [{"name": "a", "type": "core::felt252"}, {"name": "a", "type": "core::bool"}]
+          }
+        ]"#},
+    AbiValidationError::DuplicateMember {
+        kind: AbiItemKind::Struct,
+        name: "Foo".into(),
+        member: "a".into(),
+    };
+    "duplicate member"
+)]
+fn test_parse_abi_failures(json: &str, expected_error: AbiValidationError) {
+    assert_eq!(parse_abi(json), Err(expected_error));
+}
@@ -1,4 +1,9 @@
-use cairo_lang_defs::ids::ModuleItemId;
+use std::sync::Arc;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId};
+use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::test_utils::{setup_test_module, SemanticDatabaseForTesting};
 use cairo_lang_utils::extract_matches;
@@ -18,11 +23,21 @@ fn test_abi() {
               b: felt252
             }
 
+            struct MyMessage {
+              recipient: felt252,
+              amount: u256
+            }
+
             enum MyEnum<S> {
               a: u256,
               b: MyStruct::<S>
             }
 
+            enum MyError {
+              InsufficientBalance: u256,
+              Unauthorized: ()
+            }
+
             trait MyAbi<T> {
                 fn foo(ref self: T, a: felt252, b: u128) -> Option::<()>;
 
@@ -35,8 +50,24 @@ fn test_abi() {
                 #[external]
                 fn empty(ref self: T);
 
+                #[deprecated(note: 'Use foo_external')]
+                #[external]
+                fn old_foo(ref self: T);
+
+                #[default]
+                fn fallback(ref self: T, selector: felt252, calldata: Span<felt252>) -> Span<felt252>;
+
                 #[event]
                 fn foo_event(a: felt252, b: u128);
+
+                #[event(namespace: 'MyContract')]
+                fn namespaced_event(a: felt252);
+
+                #[l1_message]
+                fn MyMessage(message: MyMessage);
+
+                #[error]
+                fn MyError(error: MyError);
             }
         "},
     )
@@ -149,10 +180,39 @@ fn test_abi() {
               "outputs": [],
               "state_mutability": "external"
             },
+            {
+              "type": "function",
+              "name": "old_foo",
+              "inputs": [],
+              "outputs": [],
+              "state_mutability": "external",
+              "deprecated": "Use foo_external"
+            },
+            {
+              "type": "default",
+              "name": "fallback",
+              "inputs": [
+                {
+                  "name": "selector",
+                  "type": "core::felt252"
+                },
+                {
+                  "name": "calldata",
+                  "type": "core::array::Span::<core::felt252>"
+                }
+              ],
+              "outputs": [
+                {
+                  "type": "core::array::Span::<core::felt252>"
+                }
+              ],
+              "state_mutability": "external"
+            },
             {
               "type": "event",
               "name": "foo_event",
-              "inputs": [
+              "keys": [],
+              "data": [
                 {
                   "name": "a",
                   "type": "core::felt252"
@@ -162,7 +222,479 @@ fn test_abi() {
                   "type": "core::integer::u128"
                 }
               ]
+            },
+            {
+              "type": "event",
+              "name": "namespaced_event",
+              "namespace": "MyContract",
+              "keys": [],
+              "data": [
+                {
+                  "name": "a",
+                  "type": "core::felt252"
+                }
+              ]
+            },
+            {
+              "type": "struct",
+              "name": "test::MyMessage",
+              "members": [
+                {
+                  "name": "recipient",
+                  "type": "core::felt252"
+                },
+                {
+                  "name": "amount",
+                  "type": "core::integer::u256"
+                }
+              ]
+            },
+            {
+              "type": "l1_message",
+              "name": "MyMessage",
+              "inputs": [
+                {
+                  "name": "message",
+                  "type": "test::MyMessage"
+                }
+              ]
+            },
+            {
+              "type": "error",
+              "name": "InsufficientBalance",
+              "inputs": [
+                {
+                  "name": "data",
+                  "type": "core::integer::u256"
+                }
+              ]
+            },
+            {
+              "type": "error",
+              "name": "Unauthorized",
+              "inputs": []
+            }
+          ]"#}
+    );
+}
+
+#[test]
+fn test_abi_interface() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            mod interfaces {
+                trait IToken<T> {
+                    fn balance_of(self: @T, account: felt252) -> u128;
+                }
+            }
+
+            struct Storage {}
+
+            #[external]
+            impl TokenImpl of interfaces::IToken::<Storage> {
+                fn balance_of(self: @Storage, account: felt252) -> u128 {
+                    0_u128
+                }
+            }
+
+            trait MyAbi<T> {
+                #[external]
+                fn balance_of(self: @T, account: felt252) -> u128;
+                #[external]
+                fn mint(ref self: T, amount: u128);
+            }
+
+            trait MismatchingAbi<T> {
+                #[external]
+                fn balance_of(self: @T, owner: felt252) -> u128;
+            }
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    let abi = AbiBuilder::from_trait(db, trait_id).unwrap();
+    let actual_serialization = serde_json::to_string_pretty(&abi).unwrap();
+    assert_eq!(
+        actual_serialization,
+        indoc! {
+        r#"[
+            {
+              "type": "function",
+              "name": "balance_of",
+              "inputs": [
+                {
+                  "name": "account",
+                  "type": "core::felt252"
+                }
+              ],
+              "outputs": [
+                {
+                  "type": "core::integer::u128"
+                }
+              ],
+              "state_mutability": "view",
+              "interface": "test::interfaces::IToken"
+            },
+            {
+              "type": "function",
+              "name": "mint",
+              "inputs": [
+                {
+                  "name": "amount",
+                  "type": "core::integer::u128"
+                }
+              ],
+              "outputs": [],
+              "state_mutability": "external"
+            }
+          ]"#}
+    );
+
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MismatchingAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    assert_eq!(
+        AbiBuilder::from_trait(db, trait_id).unwrap_err().to_string(),
+        "The signature of `balance_of` does not match the interface it implements."
+    );
+}
+
+#[test]
+fn test_abi_interface_from_dependency() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    let dependency_id = db.intern_crate(CrateLongId("dependency".into()));
+    db.set_crate_root(dependency_id, Some(Directory("dependency".into())));
+    let file_id = db.module_main_file(ModuleId::CrateRoot(dependency_id)).unwrap();
+    db.as_files_group_mut().override_file_content(
+        file_id,
+        Some(Arc::new(
+            indoc! {"
+                trait IToken<T> {
+                    fn balance_of(self: @T, account: felt252) -> u128;
+                }
+            "}
+            .to_string(),
+        )),
+    );
+    let module_id = setup_test_module(
+        db,
+        indoc! {"
+            struct Storage {}
+
+            #[external]
+            impl TokenImpl of dependency::IToken::<Storage> {
+                fn balance_of(self: @Storage, account: felt252) -> u128 {
+                    0_u128
+                }
+            }
+
+            trait MyAbi<T> {
+                #[external]
+                fn balance_of(self: @T, account: felt252) -> u128;
+            }
+
+            trait MismatchingAbi<T> {
+                #[external]
+                fn balance_of(self: @T, account: felt252) -> felt252;
+            }
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    let abi = AbiBuilder::from_trait(db, trait_id).unwrap();
+    let actual_serialization = serde_json::to_string_pretty(&abi).unwrap();
+    assert_eq!(
+        actual_serialization,
+        indoc! {
+        r#"[
+            {
+              "type": "function",
+              "name": "balance_of",
+              "inputs": [
+                {
+                  "name": "account",
+                  "type": "core::felt252"
+                }
+              ],
+              "outputs": [
+                {
+                  "type": "core::integer::u128"
+                }
+              ],
+              "state_mutability": "view",
+              "interface": "dependency::IToken"
             }
           ]"#}
     );
+
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MismatchingAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    assert_eq!(
+        AbiBuilder::from_trait(db, trait_id).unwrap_err().to_string(),
+        "The signature of `balance_of` does not match the interface it implements."
+    );
+}
+
+#[test]
+fn test_abi_signatures() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            struct Pair {
+              a: felt252,
+              b: felt252
+            }
+
+            trait MyAbi<T> {
+                #[external]
+                fn transfer(ref self: T, recipient: starknet::ContractAddress, amount: u256) -> bool;
+
+                #[external]
+                fn pairs(self: @T, pairs: Span<Pair>) -> (Pair, Option::<u8>);
+
+                #[default]
+                fn fallback(ref self: T, selector: felt252, calldata: Span<felt252>) -> Span<felt252>;
+
+                #[event]
+                fn Transfer(recipient: starknet::ContractAddress, amount: u256);
+
+                #[event(namespace: 'MyContract')]
+                fn Approval(amount: u256);
+            }
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    let abi = AbiBuilder::from_trait(db, trait_id).unwrap();
+    assert_eq!(
+        abi.signatures().join("\n"),
+        indoc! {"
+            transfer(recipient: ContractAddress, amount: u256) -> bool  [0x83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e]
+            pairs(pairs: Span<Pair>) -> (Pair, Option<u8>)  [0x26a6843931e99852362ca0dabb728b39e089c8c1788cda36477a012fa9967b9]
+            default fallback(selector: felt252, calldata: Span<felt252>) -> Span<felt252>  [0x0]
+            event Transfer(recipient: ContractAddress, amount: u256)  [0x99cd8bde557814842a3121e8ddfd433a539b8c9f14bf31ebf108d12e6196e9]
+            event Approval(amount: u256)  [0x18f920b65904ce4c37cb4d265dbb0caa9fb097c8f7cea1e85ee886a076addbf]"}
+    );
+}
+
+#[test]
+fn test_abi_constructor_and_l1_handler() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            struct DepositPayload {
+              account: felt252,
+              amount: u256
+            }
+
+            trait MyAbi<T> {
+                #[constructor]
+                fn constructor(ref self: T, owner: felt252, supply: u256);
+
+                #[l1_handler]
+                fn deposit(ref self: T, from_address: felt252, payload: DepositPayload);
+            }
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    let abi = AbiBuilder::from_trait(db, trait_id).unwrap();
+    assert_eq!(
+        abi.json(),
+        indoc! {r#"
+            [
+              {
+                "type": "constructor",
+                "name": "constructor",
+                "inputs": [
+                  {
+                    "name": "owner",
+                    "type": "core::felt252"
+                  },
+                  {
+                    "name": "supply",
+                    "type": "core::integer::u256"
+                  }
+                ]
+              },
+              {
+                "type": "struct",
+                "name": "test::DepositPayload",
+                "members": [
+                  {
+                    "name": "account",
+                    "type": "core::felt252"
+                  },
+                  {
+                    "name": "amount",
+                    "type": "core::integer::u256"
+                  }
+                ]
+              },
+              {
+                "type": "l1_handler",
+                "name": "deposit",
+                "inputs": [
+                  {
+                    "name": "from_address",
+                    "type": "core::felt252"
+                  },
+                  {
+                    "name": "payload",
+                    "type": "test::DepositPayload"
+                  }
+                ],
+                "outputs": [],
+                "state_mutability": "external"
+              }
+            ]"#}
+    );
+    assert_eq!(
+        abi.signatures().join("\n"),
+        indoc! {"
+            constructor constructor(owner: felt252, supply: u256)  [0x28ffe4ff0f226a9107253e17a904099aa4f63a02a5621de0576e5aa71bc5194]
+            l1_handler deposit(from_address: felt252, payload: DepositPayload)  [0xc73f681176fc7b3f9693986fd7b14581e8d540519e27400e88b8713932be01]"}
+    );
+}
+
+#[test]
+fn test_abi_contract_events() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            struct Memo {
+              text: felt252
+            }
+
+            struct Origin {
+              #[key]
+              chain: felt252,
+              memo: Memo
+            }
+
+            struct Transfer {
+              #[key]
+              from: felt252,
+              #[key]
+              to: felt252,
+              value: u256,
+              #[event]
+              origin: Origin
+            }
+
+            enum Event {
+              #[event]
+              Transfer: Transfer,
+              Paused: felt252
+            }
+
+            trait MyAbi<T> {
+                #[events]
+                fn Event(event: Event);
+            }
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    let abi = AbiBuilder::from_trait(db, trait_id).unwrap();
+    assert_eq!(
+        abi.json(),
+        indoc! {r#"
+            [
+              {
+                "type": "struct",
+                "name": "test::Memo",
+                "members": [
+                  {
+                    "name": "text",
+                    "type": "core::felt252"
+                  }
+                ]
+              },
+              {
+                "type": "event",
+                "name": "Transfer",
+                "keys": [
+                  {
+                    "name": "from",
+                    "type": "core::felt252"
+                  },
+                  {
+                    "name": "to",
+                    "type": "core::felt252"
+                  },
+                  {
+                    "name": "origin.chain",
+                    "type": "core::felt252"
+                  }
+                ],
+                "data": [
+                  {
+                    "name": "value",
+                    "type": "core::integer::u256"
+                  },
+                  {
+                    "name": "origin.memo",
+                    "type": "test::Memo"
+                  }
+                ]
+              },
+              {
+                "type": "event",
+                "name": "Paused",
+                "keys": [],
+                "data": [
+                  {
+                    "name": "value",
+                    "type": "core::felt252"
+                  }
+                ]
+              }
+            ]"#}
+    );
+    assert_eq!(
+        abi.signatures().join("\n"),
+        indoc! {"
+            event Transfer(#[key] from: felt252, #[key] to: felt252, #[key] origin.chain: felt252, value: u256, origin.memo: Memo)  [0x99cd8bde557814842a3121e8ddfd433a539b8c9f14bf31ebf108d12e6196e9]
+            event Paused(value: felt252)  [0x2eb5248cf3d8cd81a5ba6d3cc6e1997df7b174eb894aac081867c1a2bc43c90]"}
+    );
 }
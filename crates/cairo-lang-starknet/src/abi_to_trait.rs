@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::abi::{split_type_list, Contract, Function, Item, StateMutability};
+use crate::plugin::consts::ABI_ATTR;
+
+#[cfg(test)]
+#[path = "abi_to_trait_test.rs"]
+mod test;
+
+/// The name of the generic parameter of the generated interface trait.
+const CONTRACT_STATE_PARAM: &str = "TContractState";
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum AbiToTraitError {
+    #[error("Unsupported type: `{0}`.")]
+    UnsupportedType(String),
+    #[error("The types `{0}` and `{1}` have the same name.")]
+    ConflictingTypeNames(String, String),
+    #[error("The function `{0}` has more than one output.")]
+    MultipleOutputs(String),
+}
+
+/// Generates the Cairo code of an interface trait for the functions of a contract ABI, from which
+/// the StarkNet plugin generates the dispatchers of the contract, along with the structs and enums
+/// the functions use.
+/// The structs and enums are declared by their name without their path, and the core types are
+/// referred to by their full path. Events, L1 messages, errors and the default entry point are not
+/// part of the trait, as they cannot be called through a dispatcher.
+pub fn abi_to_trait(abi: &Contract, trait_name: &str) -> Result<String, AbiToTraitError> {
+    // The local name of each struct and enum, by its full name in the ABI.
+    let mut type_names = HashMap::<String, String>::new();
+    for item in &abi.items {
+        let full_name = match item {
+            Item::Struct(item) => &item.name,
+            Item::Enum(item) => &item.name,
+            _ => continue,
+        };
+        let name = local_type_name(full_name);
+        if let Some((other, _)) = type_names.iter().find(|(_, other_name)| **other_name == name) {
+            return Err(AbiToTraitError::ConflictingTypeNames(other.clone(), full_name.clone()));
+        }
+        type_names.insert(full_name.clone(), name);
+    }
+
+    let mut code = String::new();
+    for item in &abi.items {
+        let (keyword, name, fields) = match item {
+            Item::Struct(item) => (
+                "struct",
+                &item.name,
+                item.members.iter().map(|member| (&member.name, &member.ty)).collect_vec(),
+            ),
+            Item::Enum(item) => (
+                "enum",
+                &item.name,
+                item.variants.iter().map(|variant| (&variant.name, &variant.ty)).collect_vec(),
+            ),
+            _ => continue,
+        };
+        code.push_str(&format!("#[derive(Drop, Serde)]\n{keyword} {} {{\n", type_names[name]));
+        for (field_name, ty) in fields {
+            code.push_str(&format!("    {field_name}: {},\n", cairo_type(ty, &type_names)?));
+        }
+        code.push_str("}\n\n");
+    }
+
+    code.push_str(&format!("#[{ABI_ATTR}]\ntrait {trait_name}<{CONTRACT_STATE_PARAM}> {{\n"));
+    for item in &abi.items {
+        if let Item::Function(function) = item {
+            code.push_str(&function_declaration(function, &type_names)?);
+        }
+    }
+    code.push_str("}\n");
+    Ok(code)
+}
+
+/// Returns the declaration of the interface trait function of an ABI function.
+fn function_declaration(
+    function: &Function,
+    type_names: &HashMap<String, String>,
+) -> Result<String, AbiToTraitError> {
+    let mut declaration = match &function.deprecated {
+        Some(note) if note.is_empty() => "    #[deprecated]\n".to_string(),
+        Some(note) => format!("    #[deprecated(note: '{note}')]\n"),
+        None => "".to_string(),
+    };
+    let self_param = match function.state_mutability {
+        StateMutability::External => format!("ref self: {CONTRACT_STATE_PARAM}"),
+        StateMutability::View => format!("self: @{CONTRACT_STATE_PARAM}"),
+    };
+    let mut params = vec![self_param];
+    for input in &function.inputs {
+        params.push(format!("{}: {}", input.name, cairo_type(&input.ty, type_names)?));
+    }
+    let return_type = match function.outputs.as_slice() {
+        [] => "".to_string(),
+        [output] => format!(" -> {}", cairo_type(&output.ty, type_names)?),
+        _ => return Err(AbiToTraitError::MultipleOutputs(function.name.clone())),
+    };
+    let (name, params) = (&function.name, params.join(", "));
+    declaration.push_str(&format!("    fn {name}({params}){return_type};\n"));
+    Ok(declaration)
+}
+
+/// Returns the name of a struct or enum, given its full name in the ABI, without its path and
+/// generic arguments.
+fn local_type_name(full_name: &str) -> String {
+    let base = full_name.split_once("::<").map_or(full_name, |(base, _)| base);
+    base.rsplit("::").next().unwrap_or(base).to_string()
+}
+
+/// Converts a type of the ABI to Cairo code, replacing the full names of the structs and enums of
+/// the ABI by their local names.
+fn cairo_type(ty: &str, type_names: &HashMap<String, String>) -> Result<String, AbiToTraitError> {
+    let ty = ty.trim();
+    if let Some(name) = type_names.get(ty) {
+        return Ok(name.clone());
+    }
+    if let Some(inner) = ty.strip_prefix('@') {
+        return Ok(format!("@{}", cairo_type(inner, type_names)?));
+    }
+    if let Some(inner) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+        let members = split_type_list(inner)
+            .into_iter()
+            .map(|member| cairo_type(member, type_names))
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(match members.as_slice() {
+            [member] => format!("({member},)"),
+            _ => format!("({})", members.join(", ")),
+        });
+    }
+    // Only the core types may be referred to by their full path.
+    if !ty.starts_with("core::") {
+        return Err(AbiToTraitError::UnsupportedType(ty.to_string()));
+    }
+    let Some((base, generic_args)) = ty.split_once("::<") else {
+        return Ok(ty.to_string());
+    };
+    let generic_args = generic_args
+        .strip_suffix('>')
+        .ok_or_else(|| AbiToTraitError::UnsupportedType(ty.to_string()))?;
+    let generic_args = split_type_list(generic_args)
+        .into_iter()
+        .map(|arg| cairo_type(arg, type_names))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(format!("{base}::<{}>", generic_args.join(", ")))
+}
@@ -0,0 +1,232 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::test_utils::{setup_test_module, SemanticDatabaseForTesting};
+use cairo_lang_utils::extract_matches;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{abi_to_trait, AbiToTraitError};
+use crate::abi::{AbiBuilder, Contract, Item};
+
+/// An ABI of the test module, using only non-generic structs and enums so it can be compared with
+/// the ABI of the generated trait.
+const TEST_ABI: &str = indoc! {r#"
+    [
+      {
+        "type": "struct",
+        "name": "test::Account",
+        "members": [
+          {
+            "name": "address",
+            "type": "core::starknet::contract_address::ContractAddress"
+          },
+          {
+            "name": "balance",
+            "type": "core::integer::u256"
+          }
+        ]
+      },
+      {
+        "type": "function",
+        "name": "get_account",
+        "inputs": [
+          {
+            "name": "id",
+            "type": "core::felt252"
+          }
+        ],
+        "outputs": [
+          {
+            "type": "test::Account"
+          }
+        ],
+        "state_mutability": "view"
+      },
+      {
+        "type": "enum",
+        "name": "test::Action",
+        "variants": [
+          {
+            "name": "Deposit",
+            "type": "core::integer::u128"
+          },
+          {
+            "name": "Withdraw",
+            "type": "(core::integer::u128, core::felt252)"
+          }
+        ]
+      },
+      {
+        "type": "function",
+        "name": "apply",
+        "inputs": [
+          {
+            "name": "actions",
+            "type": "core::array::Span::<test::Action>"
+          }
+        ],
+        "outputs": [],
+        "state_mutability": "external"
+      },
+      {
+        "type": "function",
+        "name": "old_apply",
+        "inputs": [],
+        "outputs": [
+          {
+            "type": "core::option::Option::<core::felt252>"
+          }
+        ],
+        "state_mutability": "external",
+        "deprecated": "Use apply"
+      },
+      {
+        "type": "event",
+        "name": "Applied",
+        "keys": [],
+        "data": [
+          {
+            "name": "action",
+            "type": "test::Action"
+          }
+        ]
+      }
+    ]"#};
+
+#[test]
+fn test_abi_to_trait() {
+    let abi: Contract = serde_json::from_str(TEST_ABI).unwrap();
+    let code = abi_to_trait(&abi, "IBank").unwrap();
+    assert_eq!(
+        code,
+        indoc! {"
+            #[derive(Drop, Serde)]
+            struct Account {
+                address: core::starknet::contract_address::ContractAddress,
+                balance: core::integer::u256,
+            }
+
+            #[derive(Drop, Serde)]
+            enum Action {
+                Deposit: core::integer::u128,
+                Withdraw: (core::integer::u128, core::felt252),
+            }
+
+            #[starknet::interface]
+            trait IBank<TContractState> {
+                fn get_account(self: @TContractState, id: core::felt252) -> Account;
+                fn apply(ref self: TContractState, actions: core::array::Span::<Action>);
+                #[deprecated(note: 'Use apply')]
+                fn old_apply(ref self: TContractState) -> core::option::Option::<core::felt252>;
+            }
+        "}
+    );
+
+    // The ABI of the generated trait is the ABI of the functions of the original contract.
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(&mut db_val, &code).unwrap().module_id;
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "IBank".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    let mut expected_abi = abi;
+    expected_abi.items.retain(|item| !matches!(item, Item::Event(_)));
+    assert_eq!(AbiBuilder::from_trait(db, trait_id).unwrap(), expected_abi);
+}
+
+#[test]
+fn test_abi_to_trait_generic_types() {
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          {
+            "type": "struct",
+            "name": "my_crate::utils::Pair::<core::integer::u8>",
+            "members": [
+              {
+                "name": "first",
+                "type": "core::integer::u8"
+              },
+              {
+                "name": "second",
+                "type": "core::integer::u8"
+              }
+            ]
+          },
+          {
+            "type": "function",
+            "name": "swap",
+            "inputs": [
+              {
+                "name": "pairs",
+                "type": "core::array::Array::<my_crate::utils::Pair::<core::integer::u8>>"
+              }
+            ],
+            "outputs": [
+              {
+                "type": "(my_crate::utils::Pair::<core::integer::u8>,)"
+              }
+            ],
+            "state_mutability": "view"
+          }
+        ]"#})
+    .unwrap();
+    assert_eq!(
+        abi_to_trait(&abi, "ISwap").unwrap(),
+        indoc! {"
+            #[derive(Drop, Serde)]
+            struct Pair {
+                first: core::integer::u8,
+                second: core::integer::u8,
+            }
+
+            #[starknet::interface]
+            trait ISwap<TContractState> {
+                fn swap(self: @TContractState, pairs: core::array::Array::<Pair>) -> (Pair,);
+            }
+        "}
+    );
+}
+
+#[test]
+fn test_abi_to_trait_failures() {
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          {
+            "type": "function",
+            "name": "foo",
+            "inputs": [
+              {
+                "name": "a",
+                "type": "other::Missing"
+              }
+            ],
+            "outputs": [],
+            "state_mutability": "external"
+          }
+        ]"#})
+    .unwrap();
+    assert_eq!(
+        abi_to_trait(&abi, "IFoo").unwrap_err(),
+        AbiToTraitError::UnsupportedType("other::Missing".into())
+    );
+
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          {
+            "type": "struct",
+            "name": "a::Point",
+            "members": []
+          },
+          {
+            "type": "enum",
+            "name": "b::Point",
+            "variants": []
+          }
+        ]"#})
+    .unwrap();
+    assert_eq!(
+        abi_to_trait(&abi, "IFoo").unwrap_err(),
+        AbiToTraitError::ConflictingTypeNames("a::Point".into(), "b::Point".into())
+    );
+}
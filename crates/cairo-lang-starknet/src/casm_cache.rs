@@ -0,0 +1,88 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use num_bigint::BigUint;
+
+use crate::casm_contract_class::CasmContractClass;
+use crate::class_hash::compute_sierra_class_hash;
+use crate::compiler_version::current_compiler_version_id;
+use crate::contract_class::ContractClass;
+
+#[cfg(test)]
+#[path = "casm_cache_test.rs"]
+mod test;
+
+/// A cache of the CASM contract classes compiled from Sierra contract classes, keyed by the class
+/// hash of the Sierra contract class.
+///
+/// The compiled classes are stored as JSON files in a directory per compiler version, so classes
+/// compiled by another version of the compiler are not reused. Failing to read or write the cache
+/// is not an error - the class is compiled as if it was not cached.
+#[derive(Clone, Debug)]
+pub struct CasmCache {
+    /// The directory the compiled classes are stored in.
+    dir: PathBuf,
+}
+impl CasmCache {
+    /// Creates a cache stored in the given directory.
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Creates a cache stored in the cache directory of the user: `$XDG_CACHE_HOME/cairo-lang`,
+    /// falling back to `$HOME/.cache/cairo-lang`, or `%LOCALAPPDATA%\cairo-lang` on Windows.
+    /// Returns `None` if the cache directory of the user is unknown.
+    pub fn in_user_cache_dir() -> Option<Self> {
+        let user_cache_dir = if cfg!(windows) {
+            std::env::var_os("LOCALAPPDATA").map(PathBuf::from)
+        } else {
+            std::env::var_os("XDG_CACHE_HOME")
+                .map(PathBuf::from)
+                .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        }?;
+        Some(Self::new(user_cache_dir.join("cairo-lang")))
+    }
+
+    /// Compiles a Sierra contract class into a CASM contract class, as
+    /// [CasmContractClass::from_contract_class] does. The compiled class is read from the cache if
+    /// the contract class was compiled before, and is stored in the cache otherwise.
+    pub fn compile(
+        &self,
+        contract_class: ContractClass,
+        add_pythonic_hints: bool,
+    ) -> Result<CasmContractClass> {
+        let path = self.path(&compute_sierra_class_hash(&contract_class)?, add_pythonic_hints);
+        if path.exists() {
+            match CasmContractClass::from_file(&path) {
+                Ok(casm_contract_class) => return Ok(casm_contract_class),
+                Err(err) => log::warn!("Ignoring invalid cached class {}: {err:#}", path.display()),
+            }
+        }
+        let casm_contract_class =
+            CasmContractClass::from_contract_class(contract_class, add_pythonic_hints)?;
+        if let Err(err) = store(&path, &casm_contract_class) {
+            log::warn!("Failed caching the compiled class in {}: {err:#}", path.display());
+        }
+        Ok(casm_contract_class)
+    }
+
+    /// Returns the path of the cached class compiled from the Sierra class with the given hash.
+    fn path(&self, class_hash: &BigUint, add_pythonic_hints: bool) -> PathBuf {
+        let suffix = if add_pythonic_hints { "_pythonic" } else { "" };
+        self.dir
+            .join("casm")
+            .join(current_compiler_version_id().to_string())
+            .join(format!("{class_hash:#066x}{suffix}.json"))
+    }
+}
+
+/// Stores the compiled class in the given path. The class is written to a temporary file that is
+/// then renamed, so that concurrent compilations never read a partially written class.
+fn store(path: &Path, casm_contract_class: &CasmContractClass) -> Result<()> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+    casm_contract_class.to_file(&temp_path)?;
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::fs::{self, read_to_string};
+
+use pretty_assertions::assert_eq;
+
+use super::CasmCache;
+use crate::casm_contract_class::CasmContractClass;
+use crate::class_hash::compute_sierra_class_hash;
+use crate::contract_class::ContractClass;
+use crate::test_utils::get_example_file_path;
+
+/// Returns a cache in a new temporary directory, unique to the test.
+fn temp_cache(test_name: &str) -> CasmCache {
+    let dir = std::env::temp_dir()
+        .join(format!("cairo-lang-casm-cache-{test_name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    CasmCache::new(dir)
+}
+
+fn test_contract_class() -> ContractClass {
+    serde_json::from_str(
+        &read_to_string(get_example_file_path("test_contract.sierra.json")).unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_cache_miss_and_hit() {
+    let cache = temp_cache("hit");
+    let contract_class = test_contract_class();
+    let path = cache.path(&compute_sierra_class_hash(&contract_class).unwrap(), false);
+    let compiled = CasmContractClass::from_contract_class(contract_class.clone(), false).unwrap();
+
+    assert_eq!(cache.compile(contract_class.clone(), false).unwrap(), compiled);
+    assert_eq!(CasmContractClass::from_file(&path).unwrap(), compiled);
+
+    // A cached class is returned without compiling the contract class again.
+    let mut cached = compiled.clone();
+    cached.compiler_version = "cached".into();
+    cached.to_file(&path).unwrap();
+    assert_eq!(cache.compile(contract_class.clone(), false).unwrap(), cached);
+
+    // Classes with pythonic hints are cached separately.
+    assert_eq!(
+        cache.compile(contract_class.clone(), true).unwrap(),
+        CasmContractClass::from_contract_class(contract_class, true).unwrap()
+    );
+    fs::remove_dir_all(&cache.dir).unwrap();
+}
+
+#[test]
+fn test_invalid_cached_class() {
+    let cache = temp_cache("invalid");
+    let contract_class = test_contract_class();
+    let path = cache.path(&compute_sierra_class_hash(&contract_class).unwrap(), false);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "not a class").unwrap();
+
+    let compiled = CasmContractClass::from_contract_class(contract_class.clone(), false).unwrap();
+    assert_eq!(cache.compile(contract_class, false).unwrap(), compiled);
+    // The invalid cached class is replaced by the compiled class.
+    assert_eq!(CasmContractClass::from_file(&path).unwrap(), compiled);
+    fs::remove_dir_all(&cache.dir).unwrap();
+}
@@ -2,6 +2,11 @@
 #[path = "casm_contract_class_test.rs"]
 mod test;
 
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use cairo_lang_casm::decoder::{disassemble, DecodingError};
 use cairo_lang_casm::hints::Hint;
 use cairo_lang_sierra::extensions::array::ArrayType;
 use cairo_lang_sierra::extensions::bitwise::BitwiseType;
@@ -18,17 +23,22 @@ use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
 use cairo_lang_sierra::extensions::structure::StructType;
 use cairo_lang_sierra::extensions::NamedType;
 use cairo_lang_sierra::ids::{ConcreteTypeId, GenericTypeId};
-use cairo_lang_sierra::program::{ConcreteTypeLongId, GenericArg, TypeDeclaration};
-use cairo_lang_sierra_to_casm::compiler::CompilationError;
+use cairo_lang_sierra::program::{
+    ConcreteTypeLongId, GenericArg, Program, StatementIdx, TypeDeclaration,
+};
+use cairo_lang_sierra_to_casm::compiler::{
+    CairoProgram, CompilationError, FunctionsCompiler, OptimizationLevel,
+};
 use cairo_lang_sierra_to_casm::metadata::{
-    calc_metadata, MetadataComputationConfig, MetadataError,
+    calc_metadata, Metadata, MetadataComputationConfig, MetadataError,
 };
 use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint, BigUintAsHex};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use convert_case::{Case, Casing};
 use itertools::{chain, Itertools};
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use num_integer::Integer;
 use num_traits::{Num, Signed};
 use serde::{Deserialize, Serialize};
@@ -36,7 +46,7 @@ use thiserror::Error;
 
 use crate::allowed_libfuncs::AllowedLibfuncsError;
 use crate::compiler_version::current_compiler_version_id;
-use crate::contract_class::{ContractClass, ContractEntryPoint};
+use crate::contract_class::{ContractClass, ContractEntryPoint, ContractEntryPoints};
 use crate::felt252_serde::{sierra_from_felt252s, Felt252SerdeError};
 
 /// The expected gas cost of an entrypoint.
@@ -66,6 +76,12 @@ pub enum StarknetSierraCompilationError {
     EntryPointsOutOfOrder,
     #[error("Out of range value in serialization.")]
     ValueOutOfRange,
+    #[error("Entry point offset out of the bytecode.")]
+    EntryPointOffsetOutOfRange,
+    #[error("Hints not sorted by offset, or out of the bytecode.")]
+    InvalidHintOffsets,
+    #[error("No entry point with selector 0x{0:x}.")]
+    EntryPointNotFound(BigUint),
 }
 
 fn skip_if_none<T>(opt_field: &Option<T>) -> bool {
@@ -87,6 +103,28 @@ pub struct CasmContractClass {
     pub entry_points_by_type: CasmContractEntryPoints,
 }
 
+/// The representation in which the hints of a [`CasmContractClass`] are serialized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HintsFormat {
+    /// Structured hints, executable by the Rust VM.
+    #[default]
+    Structured,
+    /// Legacy pythonic hints, in place of the structured hints, as expected by older sequencers
+    /// and tooling that only execute hints with the python VM.
+    Pythonic,
+}
+
+/// A [`CasmContractClass`] with its hints in the legacy pythonic representation.
+#[derive(Serialize)]
+struct PythonicCasmContractClass<'a> {
+    #[serde(serialize_with = "serialize_big_uint")]
+    prime: &'a BigUint,
+    compiler_version: &'a str,
+    bytecode: &'a [BigUintAsHex],
+    hints: Vec<(usize, Vec<String>)>,
+    entry_points_by_type: &'a CasmContractEntryPoints,
+}
+
 /// Context for resolving types.
 pub struct TypeResolver<'a> {
     type_decl: &'a [TypeDeclaration],
@@ -168,48 +206,51 @@ impl CasmContractClass {
         contract_class: ContractClass,
         add_pythonic_hints: bool,
     ) -> Result<Self, StarknetSierraCompilationError> {
-        let prime = BigUint::from_str_radix(
-            "800000000000011000000000000000000000000000000000000000000000001",
-            16,
-        )
-        .unwrap();
-
-        for felt252 in &contract_class.sierra_program {
-            if felt252.value >= prime {
-                return Err(StarknetSierraCompilationError::ValueOutOfRange);
-            }
-        }
+        let program = decode_sierra_program(&contract_class)?;
+        Self::compile(program, contract_class.entry_points_by_type, add_pythonic_hints)
+    }
 
-        let (_, _, program) = sierra_from_felt252s(&contract_class.sierra_program)?;
-        for entry_points in [
-            &contract_class.entry_points_by_type.constructor,
-            &contract_class.entry_points_by_type.external,
-            &contract_class.entry_points_by_type.l1_handler,
-        ] {
-            // TODO(orizi): Use `is_sorted` when it becomes stable.
-            if (1..entry_points.len())
-                .any(|i| entry_points[i - 1].selector > entry_points[i].selector)
-            {
-                return Err(StarknetSierraCompilationError::EntryPointsOutOfOrder);
-            }
-        }
+    /// Compiles only the entry points of the contract class with the given selectors, for
+    /// compiling a class lazily, per entry point, instead of all at once.
+    /// The resulting class contains only the requested entry points, and the code of the
+    /// functions reachable from them - see [CasmContractClassCompiler].
+    #[allow(clippy::result_large_err)]
+    pub fn from_contract_class_entry_points(
+        contract_class: ContractClass,
+        selectors: &[BigUint],
+        add_pythonic_hints: bool,
+    ) -> Result<Self, StarknetSierraCompilationError> {
+        CasmContractClassCompiler::new(contract_class, add_pythonic_hints)?.compile(selectors)
+    }
 
-        let entrypoint_ids = chain!(
-            &contract_class.entry_points_by_type.constructor,
-            &contract_class.entry_points_by_type.external,
-            &contract_class.entry_points_by_type.l1_handler,
+    /// Compiles the Sierra program of a contract class, with the given entry points.
+    #[allow(clippy::result_large_err)]
+    fn compile(
+        program: Program,
+        entry_points_by_type: ContractEntryPoints,
+        add_pythonic_hints: bool,
+    ) -> Result<Self, StarknetSierraCompilationError> {
+        let (metadata, cairo_program) = compile_to_casm(&program, &entry_points_by_type)?;
+        Self::from_cairo_program(
+            &program,
+            &metadata,
+            cairo_program,
+            entry_points_by_type,
+            add_pythonic_hints,
         )
-        .map(|entrypoint| program.funcs[entrypoint.function_idx].id.clone());
-        let metadata_computation_config = MetadataComputationConfig {
-            function_set_costs: entrypoint_ids
-                .map(|id| (id, [(CostTokenType::Const, ENTRY_POINT_COST)].into()))
-                .collect(),
-        };
-        let metadata = calc_metadata(&program, metadata_computation_config)?;
+    }
 
-        let gas_usage_check = true;
-        let cairo_program =
-            cairo_lang_sierra_to_casm::compiler::compile(&program, &metadata, gas_usage_check)?;
+    /// Creates the contract class of the CASM compiled from the Sierra program of a contract class,
+    /// with the given entry points.
+    #[allow(clippy::result_large_err)]
+    fn from_cairo_program(
+        program: &Program,
+        metadata: &Metadata,
+        cairo_program: CairoProgram,
+        entry_points_by_type: ContractEntryPoints,
+        add_pythonic_hints: bool,
+    ) -> Result<Self, StarknetSierraCompilationError> {
+        let prime = prime();
 
         let mut bytecode = vec![];
         let mut hints = vec![];
@@ -321,18 +362,8 @@ impl CasmContractClass {
             Ok::<Vec<CasmContractEntryPoint>, StarknetSierraCompilationError>(entry_points)
         };
 
-        let pythonic_hints = if add_pythonic_hints {
-            Some(
-                hints
-                    .iter()
-                    .map(|(pc, hints)| {
-                        (*pc, hints.iter().map(|hint| hint.to_string()).collect_vec())
-                    })
-                    .collect_vec(),
-            )
-        } else {
-            None
-        };
+        let pythonic_hints =
+            if add_pythonic_hints { Some(to_pythonic_hints(&hints)) } else { None };
 
         let compiler_version = current_compiler_version_id().to_string();
         Ok(Self {
@@ -342,12 +373,300 @@ impl CasmContractClass {
             hints,
             pythonic_hints,
             entry_points_by_type: CasmContractEntryPoints {
-                external: as_casm_entry_points(contract_class.entry_points_by_type.external)?,
-                l1_handler: as_casm_entry_points(contract_class.entry_points_by_type.l1_handler)?,
-                constructor: as_casm_entry_points(contract_class.entry_points_by_type.constructor)?,
+                external: as_casm_entry_points(entry_points_by_type.external)?,
+                l1_handler: as_casm_entry_points(entry_points_by_type.l1_handler)?,
+                constructor: as_casm_entry_points(entry_points_by_type.constructor)?,
             },
         })
     }
+
+    /// Reads the contract class in the given JSON file and validates it.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let casm_contract: Self = serde_json::from_str(
+            &fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}.", path.display()))?,
+        )
+        .with_context(|| "Failed to deserialize the casm contract class.")?;
+        casm_contract.validate()?;
+        Ok(casm_contract)
+    }
+
+    /// Writes the contract class into the given file as pretty JSON with structured hints, so that
+    /// a class read with [`CasmContractClass::from_file`] is written back byte-identically.
+    pub fn to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let serialized =
+            self.to_json(HintsFormat::Structured).with_context(|| "Serialization failed.")?;
+        fs::write(path, serialized + "\n")
+            .with_context(|| format!("Failed to write {}.", path.display()))
+    }
+
+    /// Validates that the bytecode of the contract class consists of field elements, that its
+    /// hints are sorted by offset and that its hints and entry points are within the bytecode.
+    pub fn validate(&self) -> Result<(), StarknetSierraCompilationError> {
+        if self.bytecode.iter().any(|felt252| felt252.value >= self.prime) {
+            return Err(StarknetSierraCompilationError::ValueOutOfRange);
+        }
+        let bytecode_len = self.bytecode.len();
+        if self.hints.iter().any(|(pc, _)| *pc >= bytecode_len)
+            || self.hints.iter().tuple_windows().any(|((a, _), (b, _))| a >= b)
+        {
+            return Err(StarknetSierraCompilationError::InvalidHintOffsets);
+        }
+        for entry_points in [
+            &self.entry_points_by_type.external,
+            &self.entry_points_by_type.l1_handler,
+            &self.entry_points_by_type.constructor,
+        ] {
+            if entry_points.iter().tuple_windows().any(|(a, b)| a.selector > b.selector) {
+                return Err(StarknetSierraCompilationError::EntryPointsOutOfOrder);
+            }
+            if entry_points.iter().any(|entry_point| entry_point.offset >= bytecode_len) {
+                return Err(StarknetSierraCompilationError::EntryPointOffsetOutOfRange);
+            }
+        }
+        Ok(())
+    }
+
+    /// Disassembles the bytecode of the contract class into readable CASM, listing the hints of
+    /// each instruction. The instructions are annotated by the entry points starting at them, and
+    /// by the given annotations, e.g. from [`bytecode_annotations`].
+    pub fn disassemble(
+        &self,
+        annotations: &OrderedHashMap<usize, Vec<String>>,
+    ) -> Result<String, DecodingError> {
+        let mut all_annotations = OrderedHashMap::<usize, Vec<String>>::default();
+        for (entry_point_type, entry_points) in [
+            ("constructor", &self.entry_points_by_type.constructor),
+            ("external", &self.entry_points_by_type.external),
+            ("l1_handler", &self.entry_points_by_type.l1_handler),
+        ] {
+            for entry_point in entry_points {
+                all_annotations
+                    .entry(entry_point.offset)
+                    .or_default()
+                    .push(format!("{entry_point_type} entry point {:#x}", entry_point.selector));
+            }
+        }
+        for (offset, offset_annotations) in annotations.iter() {
+            all_annotations.entry(*offset).or_default().extend(offset_annotations.iter().cloned());
+        }
+        // Immediate values above half the prime are shown as the negative numbers they encode.
+        let half_prime = &self.prime / 2u32;
+        let bytecode = self
+            .bytecode
+            .iter()
+            .map(|felt252| {
+                if felt252.value > half_prime {
+                    -BigInt::from(&self.prime - &felt252.value)
+                } else {
+                    BigInt::from(felt252.value.clone())
+                }
+            })
+            .collect_vec();
+        disassemble(&bytecode, &self.hints, &all_annotations)
+    }
+
+    /// Serializes the contract class into pretty JSON, with its hints in the given format.
+    pub fn to_json(&self, hints_format: HintsFormat) -> serde_json::Result<String> {
+        match hints_format {
+            HintsFormat::Structured => serde_json::to_string_pretty(self),
+            HintsFormat::Pythonic => serde_json::to_string_pretty(&PythonicCasmContractClass {
+                prime: &self.prime,
+                compiler_version: &self.compiler_version,
+                bytecode: &self.bytecode,
+                hints: to_pythonic_hints(&self.hints),
+                entry_points_by_type: &self.entry_points_by_type,
+            }),
+        }
+    }
+}
+
+/// A compiler of the entry points of a contract class to CASM on demand, for compiling a class
+/// lazily, per entry point, instead of all at once.
+///
+/// Each compilation results in a class with only the requested entry points, and the code of the
+/// functions reachable from them. The code of each function is compiled once, and is reused by the
+/// later compilations that include it, e.g. of other entry points calling it.
+pub struct CasmContractClassCompiler {
+    entry_points_by_type: ContractEntryPoints,
+    functions_compiler: FunctionsCompiler,
+    add_pythonic_hints: bool,
+}
+impl CasmContractClassCompiler {
+    #[allow(clippy::result_large_err)]
+    pub fn new(
+        contract_class: ContractClass,
+        add_pythonic_hints: bool,
+    ) -> Result<Self, StarknetSierraCompilationError> {
+        let program = decode_sierra_program(&contract_class)?;
+        let entry_points_by_type = contract_class.entry_points_by_type;
+        // The metadata of the whole class, so that the code of the functions doesn't depend on the
+        // entry points compiled with them.
+        let metadata = calc_contract_metadata(&program, &entry_points_by_type)?;
+        let gas_usage_check = true;
+        let functions_compiler = FunctionsCompiler::new(
+            program,
+            metadata,
+            gas_usage_check,
+            OptimizationLevel::default(),
+        )?;
+        Ok(Self { entry_points_by_type, functions_compiler, add_pythonic_hints })
+    }
+
+    /// Compiles the entry points of the contract class with the given selectors.
+    #[allow(clippy::result_large_err)]
+    pub fn compile(
+        &mut self,
+        selectors: &[BigUint],
+    ) -> Result<CasmContractClass, StarknetSierraCompilationError> {
+        let mut entry_points_by_type = self.entry_points_by_type.clone();
+        if let Some(selector) = selectors.iter().find(|selector| {
+            !chain!(
+                &entry_points_by_type.constructor,
+                &entry_points_by_type.external,
+                &entry_points_by_type.l1_handler,
+            )
+            .any(|entry_point| entry_point.selector == **selector)
+        }) {
+            return Err(StarknetSierraCompilationError::EntryPointNotFound(selector.clone()));
+        }
+        for entry_points in [
+            &mut entry_points_by_type.constructor,
+            &mut entry_points_by_type.external,
+            &mut entry_points_by_type.l1_handler,
+        ] {
+            entry_points.retain(|entry_point| selectors.contains(&entry_point.selector));
+        }
+        // The entry points are validated by the calculation of the metadata.
+        let function_ids = chain!(
+            &entry_points_by_type.constructor,
+            &entry_points_by_type.external,
+            &entry_points_by_type.l1_handler,
+        )
+        .map(|entry_point| {
+            self.functions_compiler.program().funcs[entry_point.function_idx].id.clone()
+        })
+        .collect_vec();
+        let cairo_program = self.functions_compiler.compile(&function_ids)?;
+        CasmContractClass::from_cairo_program(
+            self.functions_compiler.program(),
+            self.functions_compiler.metadata(),
+            cairo_program,
+            entry_points_by_type,
+            self.add_pythonic_hints,
+        )
+    }
+}
+
+/// Decodes the Sierra program of the contract class, validating that it consists of field elements
+/// and that the entry points of the class are sorted by selector.
+#[allow(clippy::result_large_err)]
+pub(crate) fn decode_sierra_program(
+    contract_class: &ContractClass,
+) -> Result<Program, StarknetSierraCompilationError> {
+    let prime = prime();
+    for felt252 in &contract_class.sierra_program {
+        if felt252.value >= prime {
+            return Err(StarknetSierraCompilationError::ValueOutOfRange);
+        }
+    }
+
+    let (_, _, program) = sierra_from_felt252s(&contract_class.sierra_program)?;
+    for entry_points in [
+        &contract_class.entry_points_by_type.constructor,
+        &contract_class.entry_points_by_type.external,
+        &contract_class.entry_points_by_type.l1_handler,
+    ] {
+        // TODO(orizi): Use `is_sorted` when it becomes stable.
+        if (1..entry_points.len()).any(|i| entry_points[i - 1].selector > entry_points[i].selector)
+        {
+            return Err(StarknetSierraCompilationError::EntryPointsOutOfOrder);
+        }
+    }
+    Ok(program)
+}
+
+/// Compiles the Sierra program of a contract class, with the given entry points, to CASM.
+#[allow(clippy::result_large_err)]
+pub(crate) fn compile_to_casm(
+    program: &Program,
+    entry_points_by_type: &ContractEntryPoints,
+) -> Result<(Metadata, CairoProgram), StarknetSierraCompilationError> {
+    let metadata = calc_contract_metadata(program, entry_points_by_type)?;
+    let gas_usage_check = true;
+    let cairo_program =
+        cairo_lang_sierra_to_casm::compiler::compile(program, &metadata, gas_usage_check)?;
+    Ok((metadata, cairo_program))
+}
+
+/// Calculates the metadata of the Sierra program of a contract class, where each of the given entry
+/// points costs [ENTRY_POINT_COST].
+#[allow(clippy::result_large_err)]
+fn calc_contract_metadata(
+    program: &Program,
+    entry_points_by_type: &ContractEntryPoints,
+) -> Result<Metadata, StarknetSierraCompilationError> {
+    let entrypoint_ids = chain!(
+        &entry_points_by_type.constructor,
+        &entry_points_by_type.external,
+        &entry_points_by_type.l1_handler,
+    )
+    .map(|entrypoint| {
+        program
+            .funcs
+            .get(entrypoint.function_idx)
+            .map(|function| function.id.clone())
+            .ok_or(StarknetSierraCompilationError::EntryPointError)
+    });
+    let metadata_computation_config = MetadataComputationConfig {
+        function_set_costs: entrypoint_ids
+            .map_ok(|id| (id, [(CostTokenType::Const, ENTRY_POINT_COST)].into()))
+            .try_collect()?,
+    };
+    Ok(calc_metadata(program, metadata_computation_config)?)
+}
+
+/// Returns the annotations of the bytecode compiled from a contract class, by offset: the function
+/// starting at each offset, and the Sierra statements compiled into the code starting at it.
+/// The functions and statements are named by the debug information of the contract class, if it
+/// has any.
+#[allow(clippy::result_large_err)]
+pub fn bytecode_annotations(
+    contract_class: &ContractClass,
+) -> Result<OrderedHashMap<usize, Vec<String>>, StarknetSierraCompilationError> {
+    let mut program = decode_sierra_program(contract_class)?;
+    if let Some(debug_info) = &contract_class.sierra_program_debug_info {
+        debug_info.populate(&mut program);
+    }
+    let (_, cairo_program) = compile_to_casm(&program, &contract_class.entry_points_by_type)?;
+    let statements_info = &cairo_program.debug_info.sierra_statement_info;
+    let functions: UnorderedHashMap<_, _> =
+        program.funcs.iter().map(|function| (function.entry_point, function)).collect();
+
+    let mut annotations = OrderedHashMap::<usize, Vec<String>>::default();
+    for (statement_idx, statement) in program.statements.iter().enumerate() {
+        let offset_annotations =
+            annotations.entry(statements_info[statement_idx].code_offset).or_default();
+        if let Some(function) = functions.get(&StatementIdx(statement_idx)) {
+            offset_annotations.push(format!("function {}", function.id));
+        }
+        offset_annotations.push(format!("#{statement_idx}: {statement}"));
+    }
+    Ok(annotations)
+}
+
+/// Returns the prime of the field of the Starknet field elements.
+fn prime() -> BigUint {
+    BigUint::from_str_radix("800000000000011000000000000000000000000000000000000000000000001", 16)
+        .unwrap()
+}
+
+/// Converts hints into their pythonic representation, which can be executed by the python VM.
+fn to_pythonic_hints(hints: &[(usize, Vec<Hint>)]) -> Vec<(usize, Vec<String>)> {
+    hints
+        .iter()
+        .map(|(pc, hints)| (*pc, hints.iter().map(|hint| hint.to_string()).collect_vec()))
+        .collect_vec()
 }
 
 #[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
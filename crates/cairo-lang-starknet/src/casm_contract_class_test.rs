@@ -1,11 +1,15 @@
 use std::io::BufReader;
 
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
+use itertools::{chain, Itertools};
 use num_bigint::BigUint;
 use num_traits::Num;
 use test_case::test_case;
 
-use crate::casm_contract_class::{BigUintAsHex, CasmContractClass, StarknetSierraCompilationError};
+use crate::casm_contract_class::{
+    bytecode_annotations, BigUintAsHex, CasmContractClass, CasmContractClassCompiler, HintsFormat,
+    StarknetSierraCompilationError,
+};
 use crate::contract_class::ContractClass;
 use crate::test_utils::{get_example_file_path, get_test_contract};
 
@@ -28,6 +32,32 @@ fn test_casm_contract_from_contract_class(example_file_name: &str) {
     );
 }
 
+/// Tests that the disassembly of the casm compiled from <test_case>.cairo is annotated by its entry
+/// points, functions and Sierra statements.
+#[test_case("minimal_contract")]
+#[test_case("test_contract")]
+fn test_casm_contract_disassemble(example_file_name: &str) {
+    let contract_class = get_test_contract(format!("{example_file_name}.cairo").as_str());
+    let annotations = bytecode_annotations(&contract_class).unwrap();
+    let add_pythonic_hints = false;
+    let casm_contract =
+        CasmContractClass::from_contract_class(contract_class, add_pythonic_hints).unwrap();
+
+    let disassembly = casm_contract.disassemble(&annotations).unwrap();
+    let lines = disassembly.lines().collect_vec();
+    for annotation in annotations.values().flatten() {
+        assert!(lines.contains(&format!("// {annotation}").as_str()), "Missing `{annotation}`.");
+    }
+    for entry_point in &casm_contract.entry_points_by_type.external {
+        let annotation = format!("// external entry point {:#x}", entry_point.selector);
+        assert!(lines.contains(&annotation.as_str()), "Missing `{annotation}`.");
+    }
+    assert_eq!(
+        lines.iter().filter(|line| line.starts_with("%{")).count(),
+        casm_contract.hints.iter().map(|(_, hints)| hints.len()).sum::<usize>()
+    );
+}
+
 #[test_case("test_contract")]
 fn test_casm_contract_from_contract_class_failure(example_file_name: &str) {
     let f = std::fs::File::open(get_example_file_path(&format!("{example_file_name}.sierra.json")))
@@ -48,3 +78,120 @@ fn test_casm_contract_from_contract_class_failure(example_file_name: &str) {
         Err(StarknetSierraCompilationError::ValueOutOfRange)
     );
 }
+
+/// Tests that in the pythonic hints format, the hints are serialized as python code in place of
+/// the structured hints.
+#[test]
+fn test_casm_contract_pythonic_hints_format() {
+    let f = std::fs::File::open(get_example_file_path("test_contract.casm.json")).unwrap();
+    let casm_contract: CasmContractClass = serde_json::from_reader(BufReader::new(f)).unwrap();
+    let expected_hints = casm_contract.pythonic_hints.clone().unwrap();
+
+    let serialized: serde_json::Value =
+        serde_json::from_str(&casm_contract.to_json(HintsFormat::Pythonic).unwrap()).unwrap();
+    assert_eq!(serialized["hints"], serde_json::to_value(expected_hints).unwrap());
+    assert_eq!(serialized.get("pythonic_hints"), None);
+    assert_eq!(serialized["bytecode"], serde_json::to_value(&casm_contract.bytecode).unwrap());
+
+    assert_eq!(
+        casm_contract.to_json(HintsFormat::Structured).unwrap(),
+        serde_json::to_string_pretty(&casm_contract).unwrap()
+    );
+}
+
+/// Tests that reading the casm contract class in <test_case>.casm.json and writing it back results
+/// in the same file.
+#[test_case("account")]
+#[test_case("test_contract")]
+#[test_case("token_bridge")]
+fn test_casm_contract_file_round_trip(example_file_name: &str) {
+    let path = get_example_file_path(&format!("{example_file_name}.casm.json"));
+    let casm_contract = CasmContractClass::from_file(&path).unwrap();
+    let output_path =
+        std::env::temp_dir().join(format!("{example_file_name}.round_trip.casm.json"));
+    casm_contract.to_file(&output_path).unwrap();
+    assert_eq!(std::fs::read(&output_path).unwrap(), std::fs::read(&path).unwrap());
+    std::fs::remove_file(output_path).unwrap();
+}
+
+#[test]
+fn test_casm_contract_validation() {
+    let casm_contract =
+        CasmContractClass::from_file(&get_example_file_path("test_contract.casm.json")).unwrap();
+
+    let mut out_of_range = casm_contract.clone();
+    out_of_range.bytecode[0] = BigUintAsHex { value: out_of_range.prime.clone() };
+    assert_eq!(out_of_range.validate(), Err(StarknetSierraCompilationError::ValueOutOfRange));
+
+    let mut unsorted_hints = casm_contract.clone();
+    unsorted_hints.hints.reverse();
+    assert_eq!(unsorted_hints.validate(), Err(StarknetSierraCompilationError::InvalidHintOffsets));
+
+    let mut bad_offset = casm_contract;
+    bad_offset.entry_points_by_type.external[0].offset = bad_offset.bytecode.len();
+    assert_eq!(
+        bad_offset.validate(),
+        Err(StarknetSierraCompilationError::EntryPointOffsetOutOfRange)
+    );
+}
+
+/// Tests that compiling the entry points of <test_case>.cairo one by one results in classes with
+/// only the requested entry point, and that compiling all of them results in the full class, both
+/// at once and after the code of their functions was compiled for the previous entry points.
+#[test_case("test_contract")]
+#[test_case("erc20")]
+fn test_casm_contract_from_contract_class_entry_points(example_file_name: &str) {
+    let contract_class = get_test_contract(format!("{example_file_name}.cairo").as_str());
+    let add_pythonic_hints = false;
+    let casm_contract =
+        CasmContractClass::from_contract_class(contract_class.clone(), add_pythonic_hints).unwrap();
+
+    let entry_points = &contract_class.entry_points_by_type;
+    let selectors =
+        chain!(&entry_points.external, &entry_points.l1_handler, &entry_points.constructor)
+            .map(|entry_point| entry_point.selector.clone())
+            .collect_vec();
+    let mut compiler =
+        CasmContractClassCompiler::new(contract_class.clone(), add_pythonic_hints).unwrap();
+    for selector in &selectors {
+        let partial_casm_contract = compiler.compile(std::slice::from_ref(selector)).unwrap();
+        partial_casm_contract.validate().unwrap();
+        let partial_entry_points = &partial_casm_contract.entry_points_by_type;
+        assert_eq!(
+            chain!(
+                &partial_entry_points.external,
+                &partial_entry_points.l1_handler,
+                &partial_entry_points.constructor
+            )
+            .map(|entry_point| &entry_point.selector)
+            .collect_vec(),
+            vec![selector]
+        );
+        assert!(partial_casm_contract.bytecode.len() <= casm_contract.bytecode.len());
+    }
+    assert_eq!(compiler.compile(&selectors).unwrap(), casm_contract);
+
+    assert_eq!(
+        CasmContractClass::from_contract_class_entry_points(
+            contract_class,
+            &selectors,
+            add_pythonic_hints
+        )
+        .unwrap(),
+        casm_contract
+    );
+}
+
+#[test]
+fn test_casm_contract_from_contract_class_missing_entry_point() {
+    let contract_class = get_test_contract("minimal_contract.cairo");
+    let add_pythonic_hints = false;
+    assert_eq!(
+        CasmContractClass::from_contract_class_entry_points(
+            contract_class,
+            &[BigUint::from(1_u32)],
+            add_pythonic_hints
+        ),
+        Err(StarknetSierraCompilationError::EntryPointNotFound(BigUint::from(1_u32)))
+    );
+}
@@ -0,0 +1,118 @@
+use anyhow::{Context, Result};
+use cairo_lang_utils::bigint::BigUintAsHex;
+use num_bigint::BigUint;
+use starknet_crypto::{poseidon_hash_many, FieldElement};
+
+use crate::casm_contract_class::{CasmContractClass, CasmContractEntryPoint};
+use crate::contract::starknet_keccak;
+use crate::contract_class::{ContractClass, ContractEntryPoint};
+
+#[cfg(test)]
+#[path = "class_hash_test.rs"]
+mod test;
+
+/// The prefix of the version of a contract class, hashed as a short string along with the version.
+const CONTRACT_CLASS_VERSION_PREFIX: &str = "CONTRACT_CLASS_V";
+/// The version of a compiled contract class, hashed as a short string.
+const COMPILED_CLASS_VERSION: &str = "COMPILED_CLASS_V1";
+
+/// Computes the class hash of a Sierra contract class, as declared on the network:
+/// `poseidon(version, external, l1_handler, constructor, abi, program)`, where each list of entry
+/// points is hashed as the poseidon of its flattened `(selector, function_idx)` pairs, the ABI is
+/// hashed as the keccak of its compact JSON serialization, and the program as the poseidon of its
+/// felts.
+pub fn compute_sierra_class_hash(contract_class: &ContractClass) -> Result<BigUint> {
+    let version =
+        format!("{CONTRACT_CLASS_VERSION_PREFIX}{}", contract_class.contract_class_version);
+    let abi = match &contract_class.abi {
+        Some(abi) => serde_json::to_string(abi).with_context(|| "ABI serialization failed.")?,
+        None => "".to_string(),
+    };
+    let entry_points = &contract_class.entry_points_by_type;
+    let program = contract_class
+        .sierra_program
+        .iter()
+        .map(|felt| to_field_element(&felt.value))
+        .collect::<Result<Vec<_>>>()?;
+    let hash = poseidon_hash_many(&[
+        short_string_to_field_element(&version)?,
+        entry_points_hash(&entry_points.external)?,
+        entry_points_hash(&entry_points.l1_handler)?,
+        entry_points_hash(&entry_points.constructor)?,
+        to_field_element(&starknet_keccak(abi.as_bytes()))?,
+        poseidon_hash_many(&program),
+    ]);
+    Ok(BigUint::from_bytes_be(&hash.to_bytes_be()))
+}
+
+/// Computes the class hash of the contract class and sets it in the class.
+pub fn set_sierra_class_hash(contract_class: &mut ContractClass) -> Result<()> {
+    contract_class.class_hash =
+        Some(BigUintAsHex { value: compute_sierra_class_hash(contract_class)? });
+    Ok(())
+}
+
+/// Computes the compiled class hash of a CASM contract class, as declared on the network:
+/// `poseidon(version, external, l1_handler, constructor, bytecode)`, where each list of entry
+/// points is hashed as the poseidon of its flattened `(selector, offset, builtins)` triplets, with
+/// the builtins hashed as the poseidon of their names, and the bytecode as the poseidon of its
+/// felts.
+pub fn compute_compiled_class_hash(casm_contract_class: &CasmContractClass) -> Result<BigUint> {
+    let entry_points = &casm_contract_class.entry_points_by_type;
+    let bytecode = casm_contract_class
+        .bytecode
+        .iter()
+        .map(|felt| to_field_element(&felt.value))
+        .collect::<Result<Vec<_>>>()?;
+    let hash = poseidon_hash_many(&[
+        short_string_to_field_element(COMPILED_CLASS_VERSION)?,
+        casm_entry_points_hash(&entry_points.external)?,
+        casm_entry_points_hash(&entry_points.l1_handler)?,
+        casm_entry_points_hash(&entry_points.constructor)?,
+        poseidon_hash_many(&bytecode),
+    ]);
+    Ok(BigUint::from_bytes_be(&hash.to_bytes_be()))
+}
+
+/// Returns the poseidon hash of the flattened `(selector, function_idx)` pairs of the entry
+/// points.
+fn entry_points_hash(entry_points: &[ContractEntryPoint]) -> Result<FieldElement> {
+    let mut felts = vec![];
+    for entry_point in entry_points {
+        felts.push(to_field_element(&entry_point.selector)?);
+        felts.push(FieldElement::from(entry_point.function_idx));
+    }
+    Ok(poseidon_hash_many(&felts))
+}
+
+/// Returns the poseidon hash of the flattened `(selector, offset, builtins)` triplets of the CASM
+/// entry points.
+fn casm_entry_points_hash(entry_points: &[CasmContractEntryPoint]) -> Result<FieldElement> {
+    let mut felts = vec![];
+    for entry_point in entry_points {
+        felts.push(to_field_element(&entry_point.selector)?);
+        felts.push(FieldElement::from(entry_point.offset));
+        let builtins = entry_point
+            .builtins
+            .iter()
+            .map(|builtin| short_string_to_field_element(builtin))
+            .collect::<Result<Vec<_>>>()?;
+        felts.push(poseidon_hash_many(&builtins));
+    }
+    Ok(poseidon_hash_many(&felts))
+}
+
+/// Converts a value to a field element, failing if it is out of range.
+fn to_field_element(value: &BigUint) -> Result<FieldElement> {
+    FieldElement::from_byte_slice_be(&value.to_bytes_be())
+        .ok()
+        .with_context(|| format!("Value out of the field range: 0x{value:x}."))
+}
+
+/// Converts a short string to a field element.
+fn short_string_to_field_element(value: &str) -> Result<FieldElement> {
+    if value.len() > 31 || !value.is_ascii() {
+        anyhow::bail!("Invalid short string: `{value}`.");
+    }
+    to_field_element(&BigUint::from_bytes_be(value.as_bytes()))
+}
@@ -0,0 +1,109 @@
+use std::fs::read_to_string;
+
+use cairo_lang_compiler::CompilerConfig;
+use num_bigint::BigUint;
+use num_traits::Num;
+use pretty_assertions::assert_eq;
+use sha3::{Digest, Keccak256};
+use starknet_crypto::{poseidon_hash_many, FieldElement};
+use test_case::test_case;
+
+use super::{compute_compiled_class_hash, compute_sierra_class_hash};
+use crate::casm_contract_class::CasmContractClass;
+use crate::contract_class::{
+    compile_path, compute_sierra_class_hashes, ContractClass, DeclaredContractClass,
+};
+use crate::plugin::StarkNetPlugin;
+use crate::test_utils::get_example_file_path;
+
+/// Computes the class hash of a contract class from its JSON serialization in the format in which
+/// it is declared on the network, following the definition of the class hash in the protocol:
+/// `poseidon("CONTRACT_CLASS_V" + version, poseidon(external), poseidon(l1_handler),
+/// poseidon(constructor), starknet_keccak(abi), poseidon(sierra_program))`.
+/// Serves as a reference independent of [`ContractClass`] and of [`compute_sierra_class_hash`].
+fn reference_sierra_class_hash(declared_class: &serde_json::Value) -> BigUint {
+    let felt =
+        |value: &serde_json::Value| FieldElement::from_hex_be(value.as_str().unwrap()).unwrap();
+    let entry_points_hash = |entry_point_type: &str| {
+        let entry_points = declared_class["entry_points_by_type"][entry_point_type].as_array();
+        poseidon_hash_many(
+            &entry_points
+                .unwrap()
+                .iter()
+                .flat_map(|entry_point| {
+                    [
+                        felt(&entry_point["selector"]),
+                        FieldElement::from(entry_point["function_idx"].as_u64().unwrap()),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        )
+    };
+    let version =
+        format!("CONTRACT_CLASS_V{}", declared_class["contract_class_version"].as_str().unwrap());
+    // The keccak256 of the ABI string, truncated to 250 bits.
+    let mut abi_hash: [u8; 32] =
+        Keccak256::digest(declared_class["abi"].as_str().unwrap().as_bytes()).into();
+    abi_hash[0] &= 3;
+    let program =
+        declared_class["sierra_program"].as_array().unwrap().iter().map(felt).collect::<Vec<_>>();
+    let hash = poseidon_hash_many(&[
+        FieldElement::from_byte_slice_be(version.as_bytes()).unwrap(),
+        entry_points_hash("EXTERNAL"),
+        entry_points_hash("L1_HANDLER"),
+        entry_points_hash("CONSTRUCTOR"),
+        FieldElement::from_bytes_be(&abi_hash).unwrap(),
+        poseidon_hash_many(&program),
+    ]);
+    BigUint::from_bytes_be(&hash.to_bytes_be())
+}
+
+#[test]
+fn test_sierra_class_hash() {
+    let contract_class: ContractClass = serde_json::from_str(
+        &read_to_string(get_example_file_path("test_contract.sierra.json")).unwrap(),
+    )
+    .unwrap();
+    let class_hash = compute_sierra_class_hash(&contract_class).unwrap();
+    assert_eq!(
+        class_hash,
+        BigUint::from_str_radix(
+            "31347d12669e1eab3b86916faaa574786bef1a8d05da29cf02d1231eb28b411",
+            16
+        )
+        .unwrap()
+    );
+    // The class hash is that of the class as declared on the network.
+    let declared_class = DeclaredContractClass::from_contract_class(&contract_class).unwrap();
+    assert_eq!(
+        class_hash,
+        reference_sierra_class_hash(&serde_json::to_value(declared_class).unwrap())
+    );
+    // The class hash is computed when compiling the contract.
+    assert_eq!(contract_class.class_hash.map(|class_hash| class_hash.value), Some(class_hash));
+}
+
+#[test_case("test_contract", "636e18eaa5730715fdf7a618c00ca03f6f18f324e22482fa97406f8a2336e0f")]
+#[test_case("account", "248ef271e7673b6b56d90be2706118ab20b63a5ea1079909a4a90ce1c1bbc9f")]
+fn test_compiled_class_hash(example_file_name: &str, expected_hash: &str) {
+    let casm_contract_class: CasmContractClass = serde_json::from_str(
+        &read_to_string(get_example_file_path(&format!("{example_file_name}.casm.json"))).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        compute_compiled_class_hash(&casm_contract_class).unwrap(),
+        BigUint::from_str_radix(expected_hash, 16).unwrap()
+    );
+}
+
+#[test]
+fn test_compute_sierra_class_hashes() {
+    let path = get_example_file_path("minimal_contract.cairo");
+    let class_hashes = compute_sierra_class_hashes(&path).unwrap();
+    let contract_class =
+        compile_path(&path, None, CompilerConfig::default(), StarkNetPlugin::default()).unwrap();
+    assert_eq!(
+        class_hashes.into_iter().collect::<Vec<_>>(),
+        vec![("MinimalContract".into(), contract_class.class_hash.unwrap().value)]
+    );
+}
@@ -25,3 +25,13 @@ pub fn current_compiler_version_id() -> VersionId {
 pub fn current_sierra_version_id() -> VersionId {
     VersionId { major: 1, minor: 1, patch: 0 }
 }
+
+/// The version of the Starknet plugin that generated the code of the contract: the version of this
+/// crate.
+pub fn current_plugin_version_id() -> VersionId {
+    VersionId {
+        major: env!("CARGO_PKG_VERSION_MAJOR").parse().unwrap(),
+        minor: env!("CARGO_PKG_VERSION_MINOR").parse().unwrap(),
+        patch: env!("CARGO_PKG_VERSION_PATCH").parse().unwrap(),
+    }
+}
@@ -4,7 +4,7 @@ use cairo_lang_defs::ids::{
     FreeFunctionId, LanguageElementId, ModuleId, ModuleItemId, SubmoduleId, TraitId,
 };
 use cairo_lang_diagnostics::ToOption;
-use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_filesystem::ids::{CrateId, FileLongId};
 use cairo_lang_lowering::ids::{ConcreteFunctionWithBodyId, FunctionWithBodyLongId};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::plugin::DynPluginAuxData;
@@ -13,13 +13,16 @@ use cairo_lang_sierra::ids::FunctionId;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::SierraIdReplacer;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::{extract_matches, try_extract_matches};
 use num_bigint::BigUint;
 use sha3::{Digest, Keccak256};
+use smol_str::SmolStr;
+use starknet_crypto::{poseidon_hash_many, FieldElement};
 
 use crate::contract_class::{extract_semantic_entrypoints, SemanticEntryPoints};
 use crate::plugin::aux_data::StarkNetContractAuxData;
-use crate::plugin::consts::ABI_TRAIT;
+use crate::plugin::consts::{ABI_TRAIT, DEFAULT_ENTRY_POINT_NAME};
 
 #[cfg(test)]
 #[path = "contract_test.rs"]
@@ -29,6 +32,10 @@ mod test;
 pub struct ContractDeclaration {
     /// The id of the module that defines the contract.
     pub submodule_id: SubmoduleId,
+    /// The hash from which the plugin derived the selectors of the contract.
+    pub selector_hash: SelectorHash,
+    /// The name of the artifacts of the contract, if given by its `#[contract]` attribute.
+    pub artifact_name: Option<SmolStr>,
 }
 
 impl ContractDeclaration {
@@ -37,7 +44,12 @@ impl ContractDeclaration {
     }
 }
 
-/// A variant of eth-keccak that computes a value that fits in a Starknet field element.
+/// The selector of the default entry point, called when no other selector matches.
+pub const DEFAULT_ENTRY_POINT_SELECTOR: u64 = 0;
+
+/// A variant of eth-keccak that computes a value that fits in a Starknet field element: the
+/// keccak256 of the data, truncated to its 250 lower bits.
+/// Used for the selectors of entry points and events, and for the addresses of storage variables.
 pub fn starknet_keccak(data: &[u8]) -> BigUint {
     let mut hasher = Keccak256::new();
     hasher.update(data);
@@ -48,6 +60,72 @@ pub fn starknet_keccak(data: &[u8]) -> BigUint {
     BigUint::from_bytes_be(&result)
 }
 
+/// The poseidon hash of the data, packed into field elements of 31 bytes each (the last one
+/// possibly shorter) in big endian, truncated to its 250 lower bits like [`starknet_keccak`], so
+/// it is a valid storage address.
+pub fn starknet_poseidon(data: &[u8]) -> BigUint {
+    let felts = data
+        .chunks(31)
+        .map(|chunk| FieldElement::from_byte_slice_be(chunk).unwrap())
+        .collect::<Vec<_>>();
+    let mut result = poseidon_hash_many(&felts).to_bytes_be();
+
+    // Truncate result to 250 bits.
+    *result.first_mut().unwrap() &= 3;
+    BigUint::from_bytes_be(&result)
+}
+
+/// The hash function from which the selectors of entry points, events and errors, and the
+/// addresses of storage variables, are derived.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SelectorHash {
+    /// [`starknet_keccak`], as in the current version of the protocol.
+    #[default]
+    StarknetKeccak,
+    /// [`starknet_poseidon`], for targeting protocol versions deriving selectors with poseidon.
+    Poseidon,
+}
+impl SelectorHash {
+    /// Hashes the given data.
+    pub fn hash(self, data: &[u8]) -> BigUint {
+        match self {
+            SelectorHash::StarknetKeccak => starknet_keccak(data),
+            SelectorHash::Poseidon => starknet_poseidon(data),
+        }
+    }
+
+    /// Returns the selector of the entry point with the given name, see [`entry_point_selector`].
+    pub fn entry_point_selector(self, name: &str) -> BigUint {
+        if name == DEFAULT_ENTRY_POINT_NAME {
+            BigUint::from(DEFAULT_ENTRY_POINT_SELECTOR)
+        } else {
+            self.hash(name.as_bytes())
+        }
+    }
+
+    /// Returns the selector of the event with the given name, see [`event_selector`].
+    pub fn event_selector(self, name: &str, namespace: Option<&str>) -> BigUint {
+        match namespace {
+            Some(namespace) => self.hash(format!("{namespace}::{name}").as_bytes()),
+            None => self.hash(name.as_bytes()),
+        }
+    }
+}
+
+/// Returns the selector of the entry point with the given name, by which it is called: the
+/// [`starknet_keccak`] of its name, or [`DEFAULT_ENTRY_POINT_SELECTOR`] for the default entry point
+/// of a contract.
+pub fn entry_point_selector(name: &str) -> BigUint {
+    SelectorHash::StarknetKeccak.entry_point_selector(name)
+}
+
+/// Returns the selector of the event with the given name, emitted as its first key: the
+/// [`starknet_keccak`] of its name, or of `<namespace>::<name>` if the selectors of the events of
+/// the contract are namespaced.
+pub fn event_selector(name: &str, namespace: Option<&str>) -> BigUint {
+    SelectorHash::StarknetKeccak.event_selector(name, namespace)
+}
+
 /// Finds the inline modules annotated as contracts in the given crate_ids and
 /// returns the corresponding ContractDeclarations.
 pub fn find_contracts(db: &dyn SemanticGroup, crate_ids: &[CrateId]) -> Vec<ContractDeclaration> {
@@ -81,7 +159,11 @@ pub fn find_contracts(db: &dyn SemanticGroup, crate_ids: &[CrateId]) -> Vec<Cont
 
                 for contract_name in &aux_data.contracts {
                     if let ModuleId::Submodule(submodule_id) = *module_id {
-                        contracts.push(ContractDeclaration { submodule_id });
+                        contracts.push(ContractDeclaration {
+                            submodule_id,
+                            selector_hash: aux_data.selector_hash,
+                            artifact_name: aux_data.artifact_name.clone(),
+                        });
                     } else {
                         panic!("Contract `{contract_name}` was not found.");
                     }
@@ -128,6 +210,46 @@ pub fn get_abi(
     }
 }
 
+/// Returns the hash of the source of the given contract: the [`starknet_keccak`] of the source
+/// files of its crate on disk, as seen by the compiler, in the order of their modules. Each file is
+/// hashed as its path relative to the crate root followed by its content, each prefixed by its
+/// length, so that moving code between files changes the hash. The virtual files, such as the
+/// files generated by plugins, are not part of the source.
+pub fn contract_source_hash(
+    db: &dyn SemanticGroup,
+    contract: &ContractDeclaration,
+) -> anyhow::Result<BigUint> {
+    let crate_id = contract.module_id().owning_crate(db.upcast());
+    let root = db.crate_root_dir(crate_id).with_context(|| "Failed to get the crate root.")?;
+    let mut paths = OrderedHashSet::default();
+    for module_id in db.crate_modules(crate_id).iter() {
+        let module_files = db
+            .module_files(*module_id)
+            .to_option()
+            .with_context(|| "Failed to get module files.")?;
+        for file_id in module_files {
+            if let FileLongId::OnDisk(path) = db.lookup_intern_file(file_id) {
+                paths.insert((file_id, path));
+            }
+        }
+    }
+    let mut source = vec![];
+    let mut add = |bytes: &[u8]| {
+        source.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        source.extend_from_slice(bytes);
+    };
+    for (file_id, path) in paths {
+        // The components are joined by `/`, so the hash is the same on all platforms.
+        let relative_path = path.strip_prefix(&root.0).unwrap_or(&path);
+        let relative_path =
+            relative_path.components().map(|component| component.as_os_str().to_string_lossy());
+        add(relative_path.collect::<Vec<_>>().join("/").as_bytes());
+        let content = db.file_content(file_id).with_context(|| "Failed to read a source file.")?;
+        add(content.as_bytes());
+    }
+    Ok(starknet_keccak(&source))
+}
+
 /// Returns the generated contract module.
 fn get_generated_contract_module(
     db: &dyn SemanticGroup,
@@ -167,21 +289,28 @@ pub fn get_contracts_info<T: SierraIdReplacer>(
     let contracts = find_contracts(db.upcast(), &main_crate_ids);
     let mut contracts_info = OrderedHashMap::default();
     for contract in contracts {
-        let (class_hash, contract_info) = analyze_contract(db, &contract, replacer)?;
+        // Contracts without a `TEST_CLASS_HASH` cannot be deployed, so they are skipped.
+        let Some((class_hash, contract_info)) = analyze_contract(db, &contract, replacer)? else {
+            continue;
+        };
         contracts_info.insert(class_hash, contract_info);
     }
     Ok(contracts_info)
 }
 
-/// Analyzes a contract and returns its class hash and a list of its functions.
+/// Analyzes a contract and returns its class hash and a list of its functions, or None if the
+/// contract has no `TEST_CLASS_HASH`.
 fn analyze_contract<T: SierraIdReplacer>(
     db: &dyn SierraGenGroup,
     contract: &ContractDeclaration,
     replacer: &T,
-) -> anyhow::Result<(cairo_felt::Felt252, ContractInfo)> {
+) -> anyhow::Result<Option<(cairo_felt::Felt252, ContractInfo)>> {
     // Extract class hash.
-    let item =
-        db.module_item_by_name(contract.module_id(), "TEST_CLASS_HASH".into()).unwrap().unwrap();
+    let Some(item) =
+        db.module_item_by_name(contract.module_id(), "TEST_CLASS_HASH".into()).unwrap()
+    else {
+        return Ok(None);
+    };
     let constant_id = extract_matches!(item, ModuleItemId::Constant);
     let value =
         extract_matches!(db.constant_semantic_data(constant_id).unwrap().value, Expr::Literal)
@@ -191,13 +320,17 @@ fn analyze_contract<T: SierraIdReplacer>(
     // Extract functions.
     let SemanticEntryPoints { external, l1_handler, constructor } =
         extract_semantic_entrypoints(db, contract)?;
-    let externals =
-        external.into_iter().map(|f| get_selector_and_sierra_function(db, f, replacer)).collect();
-    let l1_handlers =
-        l1_handler.into_iter().map(|f| get_selector_and_sierra_function(db, f, replacer)).collect();
+    let externals = external
+        .into_iter()
+        .map(|f| get_selector_and_sierra_function(db, f, replacer, contract.selector_hash))
+        .collect();
+    let l1_handlers = l1_handler
+        .into_iter()
+        .map(|f| get_selector_and_sierra_function(db, f, replacer, contract.selector_hash))
+        .collect();
     let constructors: Vec<_> = constructor
         .into_iter()
-        .map(|f| get_selector_and_sierra_function(db, f, replacer))
+        .map(|f| get_selector_and_sierra_function(db, f, replacer, contract.selector_hash))
         .collect();
     assert!(constructors.len() <= 1, "Expected at most one constructor.");
 
@@ -206,7 +339,7 @@ fn analyze_contract<T: SierraIdReplacer>(
         l1_handlers,
         constructor: constructors.into_iter().next().map(|x| x.1),
     };
-    Ok((class_hash, contract_info))
+    Ok(Some((class_hash, contract_info)))
 }
 
 /// Converts a function to a Sierra function.
@@ -215,6 +348,7 @@ pub fn get_selector_and_sierra_function<T: SierraIdReplacer>(
     db: &dyn SierraGenGroup,
     function_with_body: ConcreteFunctionWithBodyId,
     replacer: &T,
+    selector_hash: SelectorHash,
 ) -> (Felt252, FunctionId) {
     let function_id = function_with_body.function_id(db.upcast()).expect("Function error.");
     let sierra_id = replacer.replace_function_id(&db.intern_sierra_function(function_id));
@@ -225,7 +359,6 @@ pub fn get_selector_and_sierra_function<T: SierraIdReplacer>(
         FunctionWithBodyLongId::Semantic
     )
     .expect("Entrypoint cannot be a generated function.");
-    let selector =
-        Felt252::try_from(starknet_keccak(semantic.name(db.upcast()).as_bytes())).unwrap();
+    let selector = Felt252::from(selector_hash.entry_point_selector(&semantic.name(db.upcast())));
     (selector, sierra_id)
 }
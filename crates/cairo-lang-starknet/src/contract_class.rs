@@ -1,35 +1,54 @@
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::fixes::apply_fixes;
+use cairo_lang_compiler::parallel::{
+    check_modules_in_parallel, generate_functions_sierra_in_parallel,
+};
 use cairo_lang_compiler::project::setup_project;
+use cairo_lang_compiler::timings::Phase;
 use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::cfg::CfgSet;
+use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_sierra::binary::program_to_bytes;
+use cairo_lang_sierra::program::StatementIdx;
 use cairo_lang_sierra_generator::canonical_id_replacer::CanonicalReplacer;
+use cairo_lang_sierra_generator::constant_folding::fold_constants_with_origins;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::dead_code_elimination::eliminate_dead_code_with_origins;
 use cairo_lang_sierra_generator::replace_ids::{replace_sierra_ids_in_program, SierraIdReplacer};
 use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint, BigUintAsHex};
-use itertools::{chain, Itertools};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use cairo_lang_utils::Upcast;
+use itertools::{chain, izip, Itertools};
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
 use thiserror::Error;
 
 use crate::abi::{AbiBuilder, Contract};
 use crate::allowed_libfuncs::{
     validate_compatible_sierra_version, AllowedLibfuncsError, ListSelector,
 };
+use crate::class_hash::{compute_sierra_class_hash, set_sierra_class_hash};
 use crate::compiler_version::{self};
 use crate::contract::{
-    find_contracts, get_abi, get_module_functions, get_selector_and_sierra_function,
-    ContractDeclaration,
+    contract_source_hash, find_contracts, get_abi, get_module_functions,
+    get_selector_and_sierra_function, ContractDeclaration, SelectorHash,
 };
-use crate::felt252_serde::sierra_to_felt252s;
+use crate::docs::{extract_contract_docs, ContractDocs};
+use crate::felt252_serde::{sierra_from_felt252s, sierra_to_felt252s};
 use crate::plugin::consts::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L1_HANDLER_MODULE};
-use crate::plugin::StarkNetPlugin;
+use crate::plugin::{StarkNetPlugin, TestClassHash};
+use crate::source_map::{statements_source_spans, SourceSpan};
 
 #[cfg(test)]
 #[path = "contract_class_test.rs"]
@@ -51,10 +70,115 @@ pub struct ContractClass {
     pub contract_class_version: String,
     pub entry_points_by_type: ContractEntryPoints,
     pub abi: Option<Contract>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs: Option<ContractDocs>,
+    /// The class hash of the contract class. Not part of the class itself, but computed from it
+    /// when compiling the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class_hash: Option<BigUintAsHex>,
+    /// The provenance of the contract class. Not part of the class itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ContractMetadata>,
+    /// The span of the Cairo code each statement of the Sierra program was generated from, if
+    /// requested when compiling the contract. Not part of the class itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sierra_program_source_map: Option<Vec<Option<SourceSpan>>>,
+}
+
+/// The provenance of a contract class, for reproducing its compilation: the versions of the
+/// compiler that compiled it, and the hash of its source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractMetadata {
+    /// The version of the high level compiler.
+    pub compiler_version: String,
+    /// The version of the Starknet plugin that generated the code of the contract.
+    pub plugin_version: String,
+    /// The version of the Sierra of the program.
+    pub sierra_version: String,
+    /// The hash of the source of the contract, see [`contract_source_hash`].
+    pub source_hash: BigUintAsHex,
+}
+
+impl ContractMetadata {
+    /// Returns the metadata of a contract class compiled from the source with the given hash by
+    /// the current compiler.
+    pub fn current(source_hash: BigUint) -> Self {
+        Self {
+            compiler_version: compiler_version::current_compiler_version_id().to_string(),
+            plugin_version: compiler_version::current_plugin_version_id().to_string(),
+            sierra_version: compiler_version::current_sierra_version_id().to_string(),
+            source_hash: BigUintAsHex { value: source_hash },
+        }
+    }
 }
 
-const DEFAULT_CONTRACT_CLASS_VERSION: &str = "0.1.0";
+impl ContractClass {
+    /// Reads the contract class in the given JSON file and validates it.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contract_class: Self = serde_json::from_str(
+            &fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}.", path.display()))?,
+        )
+        .with_context(|| "Failed to deserialize the contract class.")?;
+        contract_class.validate()?;
+        Ok(contract_class)
+    }
 
+    /// Writes the contract class into the given file as pretty JSON, in the same format as the
+    /// compiler, so that a class read with [`ContractClass::from_file`] is written back
+    /// byte-identically.
+    pub fn to_file(&self, path: &Path) -> Result<()> {
+        let serialized =
+            serde_json::to_string_pretty(self).with_context(|| "Serialization failed.")?;
+        fs::write(path, serialized + "\n")
+            .with_context(|| format!("Failed to write {}.", path.display()))
+    }
+
+    /// Validates that the Sierra program of the contract class can be decoded, and that its entry
+    /// points are functions of the program.
+    pub fn validate(&self) -> Result<()> {
+        let (_, _, program) = sierra_from_felt252s(&self.sierra_program)
+            .with_context(|| "Failed to decode the Sierra program.")?;
+        for entry_point in chain!(
+            &self.entry_points_by_type.external,
+            &self.entry_points_by_type.l1_handler,
+            &self.entry_points_by_type.constructor,
+        ) {
+            if entry_point.function_idx >= program.funcs.len() {
+                anyhow::bail!(
+                    "Entry point 0x{:x} refers to function #{}, but the program has {} functions.",
+                    entry_point.selector,
+                    entry_point.function_idx,
+                    program.funcs.len()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the Sierra program of the contract class in the compact binary encoding of
+    /// [cairo_lang_sierra::binary], named by the debug info of the class if it has any.
+    pub fn sierra_program_binary(&self) -> Result<Vec<u8>> {
+        let (_, _, mut program) = sierra_from_felt252s(&self.sierra_program)
+            .with_context(|| "Failed to decode the Sierra program.")?;
+        if let Some(debug_info) = &self.sierra_program_debug_info {
+            debug_info.populate(&mut program);
+        }
+        Ok(program_to_bytes(&program))
+    }
+}
+
+/// Reads the metadata of the contract class in the given file, as written by the compiler.
+/// Fails if the class has no metadata.
+pub fn read_contract_metadata(path: &Path) -> Result<ContractMetadata> {
+    ContractClass::from_file(path)?.metadata.with_context(|| "The contract class has no metadata.")
+}
+
+pub(crate) const DEFAULT_CONTRACT_CLASS_VERSION: &str = "0.1.0";
+
+/// The entry points of a contract class, by type.
+/// Each list is sorted by selector, so the class does not depend on the order in which the entry
+/// points are declared in the contract.
 #[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ContractEntryPoints {
     #[serde(rename = "EXTERNAL")]
@@ -74,16 +198,112 @@ pub struct ContractEntryPoint {
     pub function_idx: usize,
 }
 
-/// Compile the contract given by path.
+/// The suffix of the file name of a contract class artifact, as written by
+/// [`write_contract_class_artifact`].
+pub const CONTRACT_CLASS_ARTIFACT_SUFFIX: &str = ".contract_class.json";
+
+/// The file name of the manifest of the contract class artifacts written into a directory by
+/// [`starknet_compile_contracts`].
+pub const ARTIFACTS_MANIFEST_FILE_NAME: &str = "starknet_artifacts.json";
+
+/// The manifest of the contract class artifacts written into a directory, for deployment tools to
+/// find the artifact of each contract.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactsManifest {
+    pub contracts: Vec<ContractArtifact>,
+}
+
+/// A contract class artifact, as listed in an [`ArtifactsManifest`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractArtifact {
+    /// The fully qualified path of the contract, e.g. `my_crate::my_contract`.
+    pub contract_path: String,
+    /// The file name of the artifact, in the directory of the manifest.
+    pub artifact: String,
+    pub class_hash: Option<BigUintAsHex>,
+    pub abi: Option<Contract>,
+}
+
+/// A contract compiled by [`compile_contracts_in_path`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledContract {
+    /// The fully qualified path of the contract, e.g. `my_crate::my_contract`.
+    pub contract_path: String,
+    /// The name of the artifacts of the contract: the one given by its `#[contract]` attribute, or
+    /// else derived from its path (see [`contract_artifact_name`]).
+    pub artifact_name: String,
+    pub contract_class: ContractClass,
+}
+
+/// Returns the directory into which the artifacts of a build profile are written, in a target
+/// directory: `<target_dir>/<profile>`.
+pub fn profile_artifacts_dir(target_dir: &Path, profile: &str) -> PathBuf {
+    target_dir.join(profile)
+}
+
+/// A contract class in the format in which it is declared on the network: the Sierra program, the
+/// version of the class, its entry points sorted by selector, and its ABI serialized as a compact
+/// JSON string (empty if the class has no ABI).
+/// The debug info, docs, class hash and metadata of the class are not part of it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeclaredContractClass {
+    pub sierra_program: Vec<BigUintAsHex>,
+    pub contract_class_version: String,
+    pub entry_points_by_type: ContractEntryPoints,
+    pub abi: String,
+}
+
+impl DeclaredContractClass {
+    pub fn from_contract_class(contract_class: &ContractClass) -> Result<Self> {
+        let abi = match &contract_class.abi {
+            Some(abi) => serde_json::to_string(abi).with_context(|| "ABI serialization failed.")?,
+            None => "".to_string(),
+        };
+        let mut entry_points_by_type = contract_class.entry_points_by_type.clone();
+        for entry_points in [
+            &mut entry_points_by_type.external,
+            &mut entry_points_by_type.l1_handler,
+            &mut entry_points_by_type.constructor,
+        ] {
+            entry_points.sort_by(|a, b| a.selector.cmp(&b.selector));
+        }
+        Ok(Self {
+            sierra_program: contract_class.sierra_program.clone(),
+            contract_class_version: contract_class.contract_class_version.clone(),
+            entry_points_by_type,
+            abi,
+        })
+    }
+}
+
+/// Writes the contract class, in the format in which it is declared on the network, into a single
+/// `<artifact_name>.contract_class.json` file in the given directory.
+/// Returns the path of the written file.
+pub fn write_contract_class_artifact(
+    contract_class: &ContractClass,
+    output_dir: &Path,
+    artifact_name: &str,
+) -> Result<PathBuf> {
+    let declared_class = DeclaredContractClass::from_contract_class(contract_class)?;
+    let serialized =
+        serde_json::to_string_pretty(&declared_class).with_context(|| "Serialization failed.")?;
+    let path = output_dir.join(format!("{artifact_name}{CONTRACT_CLASS_ARTIFACT_SUFFIX}"));
+    fs::write(&path, serialized).with_context(|| format!("Failed to write {}.", path.display()))?;
+    Ok(path)
+}
+
+/// Compile the contract given by path, with the given configuration of the Starknet plugin.
 /// Errors if there is ambiguity.
 pub fn compile_path(
     path: &Path,
     contract_path: Option<&str>,
     compiler_config: CompilerConfig<'_>,
+    plugin: StarkNetPlugin,
 ) -> Result<ContractClass> {
     let mut db = RootDatabase::builder()
         .detect_corelib()
-        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .with_cfg(compiler_config.cfg_set.clone())
+        .with_semantic_plugin(Arc::new(plugin))
         .build()?;
 
     let main_crate_ids = setup_project(&mut db, Path::new(&path))?;
@@ -91,10 +311,128 @@ pub fn compile_path(
     compile_contract_in_prepared_db(&db, contract_path, main_crate_ids, compiler_config)
 }
 
+/// Applies the suggested fixes of the diagnostics of the crate given by path, including those of
+/// the Starknet plugin, to its files. Returns the number of fixes applied.
+pub fn apply_fixes_in_path(path: &Path, cfg_set: CfgSet) -> Result<usize> {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_cfg(cfg_set)
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()?;
+    setup_project(&mut db, path)?;
+    apply_fixes(&db)
+}
+
+/// Compiles the contracts of the crate given by path, each into its own contract class.
+/// If `contract_paths` is not empty, only the contracts with the given fully qualified paths are
+/// compiled, and each of them must exist. They are compiled with the given configuration of the
+/// Starknet plugin.
+/// Returns the compiled classes along with the paths and the artifact names of their contracts.
+pub fn compile_contracts_in_path(
+    path: &Path,
+    contract_paths: &[String],
+    compiler_config: CompilerConfig<'_>,
+    plugin: StarkNetPlugin,
+) -> Result<Vec<CompiledContract>> {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_cfg(compiler_config.cfg_set.clone())
+        .with_semantic_plugin(Arc::new(plugin))
+        .build()?;
+
+    let main_crate_ids = setup_project(&mut db, Path::new(&path))?;
+
+    compile_contracts_in_prepared_db(&db, contract_paths, main_crate_ids, compiler_config)
+}
+
+/// Computes the class hashes of the contracts of the crate given by path, by contract name, to be
+/// generated as their `TEST_CLASS_HASH` with [`TestClassHash::SierraClassHash`].
+/// As a contract may use the `TEST_CLASS_HASH` of other contracts, which is part of its class, the
+/// contracts are recompiled with the computed class hashes until they are stable. Fails if the
+/// class hashes depend on each other cyclically, e.g. if a contract uses its own class hash.
+pub fn compute_sierra_class_hashes(path: &Path) -> Result<OrderedHashMap<SmolStr, BigUint>> {
+    let mut test_class_hash = TestClassHash::ModuleText;
+    let mut iterations = 0;
+    loop {
+        let mut db = RootDatabase::builder()
+            .detect_corelib()
+            .with_semantic_plugin(Arc::new(
+                StarkNetPlugin::default().with_test_class_hash(test_class_hash.clone()),
+            ))
+            .build()?;
+        let main_crate_ids = setup_project(&mut db, path)?;
+        let classes =
+            compile_contracts_in_prepared_db(&db, &[], main_crate_ids, CompilerConfig::default())?;
+
+        let mut class_hashes = OrderedHashMap::default();
+        for CompiledContract { contract_path, contract_class, .. } in &classes {
+            let name = SmolStr::from(contract_path.rsplit("::").next().unwrap());
+            let class_hash = compute_sierra_class_hash(contract_class)?;
+            if class_hashes.insert(name.clone(), class_hash).is_some() {
+                anyhow::bail!("More than one contract is named `{name}`.");
+            }
+        }
+        if test_class_hash == TestClassHash::SierraClassHash(class_hashes.clone()) {
+            return Ok(class_hashes);
+        }
+        // Each iteration fixes the class hash of at least one more contract, unless they are
+        // cyclically dependent.
+        iterations += 1;
+        if iterations > classes.len() {
+            anyhow::bail!("The class hashes of the contracts depend on each other cyclically.");
+        }
+        test_class_hash = TestClassHash::SierraClassHash(class_hashes);
+    }
+}
+
+/// Runs StarkNet contract compiler on all the contracts in the main crates, or on the specified
+/// ones if `contract_paths` is not empty.
+pub(crate) fn compile_contracts_in_prepared_db(
+    db: &RootDatabase,
+    contract_paths: &[String],
+    main_crate_ids: Vec<CrateId>,
+    mut compiler_config: CompilerConfig<'_>,
+) -> Result<Vec<CompiledContract>> {
+    let mut contracts = find_contracts(db, &main_crate_ids);
+    if !contract_paths.is_empty() {
+        contracts.retain(|contract| contract_paths.contains(&contract.submodule_id.full_path(db)));
+    }
+    let found_paths =
+        contracts.iter().map(|contract| contract.submodule_id.full_path(db)).collect_vec();
+    if let Some(missing_path) = contract_paths.iter().find(|path| !found_paths.contains(path)) {
+        // Report diagnostics as they might reveal the reason why the contract was not found.
+        compiler_config.diagnostics_reporter.ensure(db)?;
+        anyhow::bail!("Contract not found: {missing_path}.");
+    }
+    if contracts.is_empty() {
+        compiler_config.diagnostics_reporter.ensure(db)?;
+        anyhow::bail!("Contract not found.");
+    }
+
+    let classes = compile_prepared_db(db, &contracts.iter().collect_vec(), compiler_config)?;
+    Ok(izip!(contracts, found_paths, classes)
+        .map(|(contract, contract_path, contract_class)| CompiledContract {
+            artifact_name: match contract.artifact_name {
+                Some(artifact_name) => artifact_name.into(),
+                None => contract_artifact_name(&contract_path),
+            },
+            contract_path,
+            contract_class,
+        })
+        .collect())
+}
+
+/// Returns the default name of the artifact of the contract with the given fully qualified path.
+/// As different paths may have the same name (e.g. `a::b_c` and `a_b::c`), the names of the
+/// artifacts written together are checked to be distinct.
+pub fn contract_artifact_name(contract_path: &str) -> String {
+    contract_path.replace("::", "_")
+}
+
 /// Runs StarkNet contract compiler on the specified contract.
 /// If no contract was specified, verify that there is only one.
 /// Otherwise, return an error.
-pub(crate) fn compile_contract_in_prepared_db(
+pub fn compile_contract_in_prepared_db(
     db: &RootDatabase,
     contract_path: Option<&str>,
     main_crate_ids: Vec<CrateId>,
@@ -118,7 +456,7 @@ pub(crate) fn compile_contract_in_prepared_db(
                 contracts.iter().map(|contract| contract.submodule_id.full_path(db)).join("\n  ");
             anyhow::bail!(
                 "More than one contract found in the main crate: \n  {}\nUse --contract-path to \
-                 specify which to compile.",
+                 specify which to compile, or --output-dir to compile all of them.",
                 contract_names
             );
         }
@@ -145,12 +483,38 @@ pub fn compile_prepared_db(
     contracts: &[&ContractDeclaration],
     mut compiler_config: CompilerConfig<'_>,
 ) -> Result<Vec<ContractClass>> {
+    if let Some(timings) = compiler_config.timings.as_deref_mut() {
+        timings.measure_crate_phases(db, &db.crates());
+    }
+    if compiler_config.jobs > 1 {
+        check_modules_in_parallel(db, compiler_config.jobs);
+    }
     compiler_config.diagnostics_reporter.ensure(db)?;
+    if compiler_config.jobs > 1 {
+        let mut function_ids = vec![];
+        for contract in contracts {
+            let SemanticEntryPoints { external, l1_handler, constructor } =
+                extract_semantic_entrypoints(db, contract)?;
+            function_ids.extend(chain!(external, l1_handler, constructor));
+        }
+        generate_functions_sierra_in_parallel(db, function_ids, compiler_config.jobs);
+    }
 
+    // The Sierra generation of each contract is measured under the path of the contract.
+    let mut timings = compiler_config.timings.take();
     contracts
         .iter()
         .map(|contract| {
-            compile_contract_with_prepared_and_checked_db(db, contract, &compiler_config)
+            let compile =
+                || compile_contract_with_prepared_and_checked_db(db, contract, &compiler_config);
+            match timings.as_deref_mut() {
+                Some(timings) => timings.measure(
+                    Phase::SierraGen,
+                    Some(&contract.submodule_id.full_path(db)),
+                    compile,
+                ),
+                None => compile(),
+            }
         })
         .try_collect()
 }
@@ -167,13 +531,41 @@ fn compile_contract_with_prepared_and_checked_db(
 ) -> Result<ContractClass> {
     let SemanticEntryPoints { external, l1_handler, constructor } =
         extract_semantic_entrypoints(db, contract)?;
-    let mut sierra_program = db
+    let generated_program = db
         .get_sierra_program_for_functions(
             chain!(&external, &l1_handler, &constructor).cloned().collect(),
         )
         .to_option()
         .with_context(|| "Compilation failed without any diagnostics.")?;
 
+    let mut sierra_program = generated_program.clone();
+    // The index of the generated statement each statement of the program originates from.
+    let mut origins = (0..sierra_program.statements.len()).map(StatementIdx).collect_vec();
+    if compiler_config.fold_constants {
+        let (program, new_origins) = fold_constants_with_origins(db, &sierra_program);
+        sierra_program = Arc::new(program);
+        origins = new_origins.into_iter().map(|origin| origins[origin.0]).collect();
+    }
+    if compiler_config.eliminate_dead_code {
+        let roots = chain!(&external, &l1_handler, &constructor)
+            .map(|function| {
+                let function_id = function.function_id(db.upcast()).expect("Function error.");
+                db.intern_sierra_function(function_id)
+            })
+            .collect_vec();
+        let (program, new_origins) = eliminate_dead_code_with_origins(db, &sierra_program, &roots);
+        sierra_program = Arc::new(program);
+        origins = new_origins.into_iter().map(|origin| origins[origin.0]).collect();
+    }
+    let sierra_program_source_map = if compiler_config.add_source_map {
+        Some(
+            statements_source_spans(db, &generated_program, &origins)
+                .to_option()
+                .with_context(|| "Failed to get the locations of the statements.")?,
+        )
+    } else {
+        None
+    };
     if compiler_config.replace_ids {
         sierra_program = Arc::new(replace_sierra_ids_in_program(db, &sierra_program));
     }
@@ -181,12 +573,12 @@ fn compile_contract_with_prepared_and_checked_db(
     let sierra_program = replacer.apply(&sierra_program);
 
     let entry_points_by_type = ContractEntryPoints {
-        external: get_entry_points(db, &external, &replacer)?,
-        l1_handler: get_entry_points(db, &l1_handler, &replacer)?,
+        external: get_entry_points(db, &external, &replacer, contract.selector_hash)?,
+        l1_handler: get_entry_points(db, &l1_handler, &replacer, contract.selector_hash)?,
         /// TODO(orizi): Validate there is at most one constructor.
-        constructor: get_entry_points(db, &constructor, &replacer)?,
+        constructor: get_entry_points(db, &constructor, &replacer, contract.selector_hash)?,
     };
-    let contract_class = ContractClass {
+    let mut contract_class = ContractClass {
         sierra_program: sierra_to_felt252s(
             compiler_version::current_sierra_version_id(),
             compiler_version::current_compiler_version_id(),
@@ -198,7 +590,17 @@ fn compile_contract_with_prepared_and_checked_db(
         contract_class_version: DEFAULT_CONTRACT_CLASS_VERSION.to_string(),
         entry_points_by_type,
         abi: Some(AbiBuilder::from_trait(db, get_abi(db, contract)?).with_context(|| "ABI error")?),
+        docs: Some(
+            extract_contract_docs(db, contract)
+                .to_option()
+                .with_context(|| "Failed to extract contract docs.")?,
+        )
+        .filter(|docs| !docs.is_empty()),
+        class_hash: None,
+        metadata: Some(ContractMetadata::current(contract_source_hash(db, contract)?)),
+        sierra_program_source_map,
     };
+    set_sierra_class_hash(&mut contract_class)?;
     Ok(contract_class)
 }
 
@@ -236,11 +638,12 @@ fn get_entry_points(
     db: &RootDatabase,
     entry_point_functions: &[ConcreteFunctionWithBodyId],
     replacer: &CanonicalReplacer,
+    selector_hash: SelectorHash,
 ) -> Result<Vec<ContractEntryPoint>> {
     let mut entry_points = vec![];
     for function_with_body_id in entry_point_functions {
         let (selector, sierra_id) =
-            get_selector_and_sierra_function(db, *function_with_body_id, replacer);
+            get_selector_and_sierra_function(db, *function_with_body_id, replacer, selector_hash);
 
         entry_points.push(ContractEntryPoint {
             selector: selector.to_biguint(),
@@ -248,6 +651,11 @@ fn get_entry_points(
         });
     }
     entry_points.sort_by(|a, b| a.selector.cmp(&b.selector));
+    if let Some((entry_point, _)) =
+        entry_points.iter().tuple_windows().find(|(a, b)| a.selector == b.selector)
+    {
+        anyhow::bail!("Duplicate entry point selector: 0x{:x}.", entry_point.selector);
+    }
     Ok(entry_points)
 }
 
@@ -262,6 +670,7 @@ pub fn starknet_compile(
         &crate_path,
         contract_path.as_deref(),
         if let Some(config) = config { config } else { CompilerConfig::default() },
+        StarkNetPlugin::default(),
     )?;
     validate_compatible_sierra_version(
         &contract,
@@ -273,3 +682,55 @@ pub fn starknet_compile(
     )?;
     serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")
 }
+
+/// Compile all the contracts in a Starknet crate (or the specified contracts in the crate), and
+/// write each of them as a single contract class artifact into the given directory (see
+/// [`write_contract_class_artifact`]), along with an [`ArtifactsManifest`] listing them.
+/// The contracts are compiled with the given configuration of the Starknet plugin.
+/// Returns the paths of the written artifacts.
+pub fn starknet_compile_contracts(
+    crate_path: PathBuf,
+    contract_paths: &[String],
+    config: Option<CompilerConfig<'_>>,
+    allowed_libfuncs_list: Option<ListSelector>,
+    output_dir: &Path,
+    plugin: StarkNetPlugin,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let contracts = compile_contracts_in_path(
+        &crate_path,
+        contract_paths,
+        if let Some(config) = config { config } else { CompilerConfig::default() },
+        plugin,
+    )?;
+    let allowed_libfuncs_list = allowed_libfuncs_list.unwrap_or_default();
+    fs::create_dir_all(output_dir).with_context(|| "Failed to create output directory.")?;
+    let mut artifact_paths = UnorderedHashMap::<&str, &str>::default();
+    for CompiledContract { contract_path, artifact_name, .. } in &contracts {
+        if let Some(other_path) = artifact_paths.insert(artifact_name, contract_path) {
+            anyhow::bail!(
+                "Contracts `{other_path}` and `{contract_path}` have the same artifact name \
+                 `{artifact_name}`. Give one of them another name with `#[contract(name: \
+                 '<name>')]`."
+            );
+        }
+    }
+    let mut paths = vec![];
+    let mut manifest = ArtifactsManifest::default();
+    for CompiledContract { contract_path, artifact_name, contract_class: contract } in contracts {
+        validate_compatible_sierra_version(&contract, allowed_libfuncs_list.clone())?;
+        let path = write_contract_class_artifact(&contract, output_dir, &artifact_name)?;
+        manifest.contracts.push(ContractArtifact {
+            contract_path,
+            artifact: path.file_name().unwrap().to_string_lossy().into_owned(),
+            class_hash: contract.class_hash,
+            abi: contract.abi,
+        });
+        paths.push(path);
+    }
+    let manifest_path = output_dir.join(ARTIFACTS_MANIFEST_FILE_NAME);
+    let serialized =
+        serde_json::to_string_pretty(&manifest).with_context(|| "Serialization failed.")?;
+    fs::write(&manifest_path, serialized)
+        .with_context(|| format!("Failed to write {}.", manifest_path.display()))?;
+    Ok(paths)
+}
@@ -1,15 +1,28 @@
+use cairo_lang_compiler::CompilerConfig;
+use cairo_lang_sierra::binary::program_from_bytes;
+use cairo_lang_sierra::validate::validate;
+use cairo_lang_sierra::ProgramParser;
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
+use cairo_lang_utils::bigint::BigUintAsHex;
 use indoc::indoc;
+use itertools::Itertools;
 use num_bigint::BigUint;
 use pretty_assertions::assert_eq;
 use test_case::test_case;
 
-use crate::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
+use crate::allowed_libfuncs::{
+    validate_compatible_sierra_version, ListSelector, BUILTIN_ALL_LIBFUNCS_LIST,
+};
 use crate::compiler_version;
+use crate::contract::starknet_keccak;
 use crate::contract_class::{
-    ContractClass, ContractEntryPoint, ContractEntryPoints, DEFAULT_CONTRACT_CLASS_VERSION,
+    compile_contracts_in_path, compile_path, profile_artifacts_dir, read_contract_metadata,
+    starknet_compile_contracts, ArtifactsManifest, ContractArtifact, ContractClass,
+    ContractEntryPoint, ContractEntryPoints, ContractMetadata, DeclaredContractClass,
+    ARTIFACTS_MANIFEST_FILE_NAME, DEFAULT_CONTRACT_CLASS_VERSION,
 };
 use crate::felt252_serde::sierra_from_felt252s;
+use crate::plugin::StarkNetPlugin;
 use crate::test_utils::{get_example_file_path, get_test_contract};
 
 #[test]
@@ -26,6 +39,10 @@ fn test_serialization() {
             constructor: vec![],
         },
         abi: None,
+        docs: None,
+        class_hash: None,
+        metadata: None,
+        sierra_program_source_map: None,
     };
 
     let serialized = serde_json::to_string_pretty(&contract).unwrap();
@@ -55,6 +72,187 @@ fn test_serialization() {
     assert_eq!(contract, serde_json::from_str(&serialized).unwrap())
 }
 
+#[test]
+fn test_declared_contract_class_serialization() {
+    let contract = ContractClass {
+        sierra_program: vec![],
+        sierra_program_debug_info: None,
+        contract_class_version: DEFAULT_CONTRACT_CLASS_VERSION.to_string(),
+        entry_points_by_type: ContractEntryPoints {
+            external: vec![
+                ContractEntryPoint { selector: BigUint::from(2_u32), function_idx: 0 },
+                ContractEntryPoint { selector: BigUint::from(1_u32), function_idx: 1 },
+            ],
+            l1_handler: vec![],
+            constructor: vec![],
+        },
+        abi: None,
+        docs: None,
+        class_hash: Some(BigUintAsHex { value: BigUint::from(3_u32) }),
+        metadata: None,
+        sierra_program_source_map: None,
+    };
+
+    let declared_class = DeclaredContractClass::from_contract_class(&contract).unwrap();
+    let serialized = serde_json::to_string_pretty(&declared_class).unwrap();
+
+    // The entry points are sorted by selector, and the class hash is not part of the class.
+    assert_eq!(
+        &serialized,
+        indoc! {
+            r#"
+        {
+          "sierra_program": [],
+          "contract_class_version": "0.1.0",
+          "entry_points_by_type": {
+            "EXTERNAL": [
+              {
+                "selector": "0x1",
+                "function_idx": 1
+              },
+              {
+                "selector": "0x2",
+                "function_idx": 0
+              }
+            ],
+            "L1_HANDLER": [],
+            "CONSTRUCTOR": []
+          },
+          "abi": ""
+        }"#}
+    );
+
+    assert_eq!(declared_class, serde_json::from_str(&serialized).unwrap())
+}
+
+#[test]
+fn test_declared_contract_class() {
+    let contract = get_test_contract("minimal_contract.cairo");
+    let declared_class = DeclaredContractClass::from_contract_class(&contract).unwrap();
+    assert_eq!(declared_class.sierra_program, contract.sierra_program);
+    assert_eq!(declared_class.contract_class_version, contract.contract_class_version);
+    assert_eq!(declared_class.entry_points_by_type, contract.entry_points_by_type);
+    // The ABI is declared as its compact JSON serialization.
+    assert_eq!(declared_class.abi, serde_json::to_string(&contract.abi.unwrap()).unwrap());
+}
+
+#[test]
+fn test_artifacts_manifest() {
+    let target_dir = std::env::temp_dir().join("starknet_artifacts_manifest_test");
+    let output_dir = profile_artifacts_dir(&target_dir, "dev");
+    let paths = starknet_compile_contracts(
+        get_example_file_path("minimal_contract.cairo"),
+        &[],
+        Some(CompilerConfig { replace_ids: true, ..CompilerConfig::default() }),
+        Some(ListSelector::ListName(BUILTIN_ALL_LIBFUNCS_LIST.to_string())),
+        &output_dir,
+        StarkNetPlugin::default(),
+    )
+    .unwrap();
+    let manifest: ArtifactsManifest = serde_json::from_str(
+        &std::fs::read_to_string(output_dir.join(ARTIFACTS_MANIFEST_FILE_NAME)).unwrap(),
+    )
+    .unwrap();
+    std::fs::remove_dir_all(target_dir).unwrap();
+
+    let contract = get_test_contract("minimal_contract.cairo");
+    let artifact = "minimal_contract_minimal_contract_MinimalContract.contract_class.json";
+    assert_eq!(paths, [output_dir.join(artifact)]);
+    assert_eq!(
+        manifest,
+        ArtifactsManifest {
+            contracts: vec![ContractArtifact {
+                contract_path: "minimal_contract::minimal_contract::MinimalContract".into(),
+                artifact: artifact.into(),
+                class_hash: contract.class_hash,
+                abi: contract.abi,
+            }],
+        }
+    );
+}
+
+#[test]
+fn test_contract_metadata() {
+    let contract = get_test_contract("minimal_contract.cairo");
+    let content = std::fs::read_to_string(get_example_file_path("minimal_contract.cairo")).unwrap();
+    // The crate root of a single file project is the file declaring its module. Each file is hashed
+    // as its length-prefixed path and content.
+    let mut source = vec![];
+    for part in ["lib.cairo", "mod minimal_contract;", "minimal_contract.cairo", &content] {
+        source.extend_from_slice(&(part.len() as u64).to_le_bytes());
+        source.extend_from_slice(part.as_bytes());
+    }
+    let metadata = contract.metadata.unwrap();
+    assert_eq!(
+        metadata,
+        ContractMetadata {
+            compiler_version: compiler_version::current_compiler_version_id().to_string(),
+            plugin_version: env!("CARGO_PKG_VERSION").to_string(),
+            sierra_version: compiler_version::current_sierra_version_id().to_string(),
+            source_hash: BigUintAsHex { value: starknet_keccak(&source) },
+        }
+    );
+    // The metadata is written into the contract class artifact.
+    assert_eq!(
+        read_contract_metadata(&get_example_file_path("minimal_contract.sierra.json")).unwrap(),
+        metadata
+    );
+}
+
+/// Tests that the Sierra program of the contract in <test_case>.sierra is valid.
+#[test_case("account")]
+#[test_case("erc20")]
+#[test_case("hello_starknet")]
+#[test_case("minimal_contract")]
+#[test_case("test_contract")]
+#[test_case("token_bridge")]
+fn test_contract_sierra_is_valid(example_file_name: &str) {
+    let path = get_example_file_path(&format!("{example_file_name}.sierra"));
+    let program = ProgramParser::new().parse(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(validate(&program), Ok(()));
+}
+
+/// Tests that reading the contract class in <test_case>.sierra.json and writing it back results in
+/// the same file.
+#[test_case("account")]
+#[test_case("test_contract")]
+#[test_case("token_bridge")]
+fn test_contract_class_file_round_trip(example_file_name: &str) {
+    let path = get_example_file_path(&format!("{example_file_name}.sierra.json"));
+    let contract = ContractClass::from_file(&path).unwrap();
+    let output_path = std::env::temp_dir().join(format!("{example_file_name}.round_trip.json"));
+    contract.to_file(&output_path).unwrap();
+    assert_eq!(std::fs::read(&output_path).unwrap(), std::fs::read(&path).unwrap());
+    std::fs::remove_file(output_path).unwrap();
+}
+
+#[test]
+fn test_contract_class_validation() {
+    let mut contract = get_test_contract("minimal_contract.cairo");
+    contract.validate().unwrap();
+    let (_, _, program) = sierra_from_felt252s(&contract.sierra_program).unwrap();
+    let entry_point = &mut contract.entry_points_by_type.external[0];
+    entry_point.function_idx = program.funcs.len();
+    let expected_error = format!(
+        "Entry point 0x{:x} refers to function #{}, but the program has {} functions.",
+        entry_point.selector,
+        program.funcs.len(),
+        program.funcs.len()
+    );
+    assert_eq!(contract.validate().unwrap_err().to_string(), expected_error);
+}
+
+#[test]
+fn test_sierra_program_binary() {
+    let contract = get_test_contract("test_contract.cairo");
+    let binary = contract.sierra_program_binary().unwrap();
+    let (_, _, mut program) = sierra_from_felt252s(&contract.sierra_program).unwrap();
+    contract.sierra_program_debug_info.as_ref().unwrap().populate(&mut program);
+    assert_eq!(program_from_bytes(&binary).unwrap().to_string(), program.to_string());
+    // Smaller than the felt252s of the program, even with the debug names.
+    assert!(binary.len() < serde_json::to_string(&contract.sierra_program).unwrap().len());
+}
+
 #[test_case("test_contract")]
 #[test_case("hello_starknet")]
 #[test_case("erc20")]
@@ -103,3 +301,106 @@ fn test_compile_path(example_file_name: &str) {
         sierra_program.to_string(),
     );
 }
+
+#[test]
+fn test_compile_selected_contracts() {
+    let crate_path = get_example_file_path("");
+    let contract_paths = vec![
+        "contracts::minimal_contract::MinimalContract".to_string(),
+        "contracts::hello_starknet::HelloStarknet".to_string(),
+    ];
+    let contracts = compile_contracts_in_path(
+        &crate_path,
+        &contract_paths,
+        CompilerConfig::default(),
+        StarkNetPlugin::default(),
+    )
+    .unwrap();
+    // Contracts are returned in the order they are found in the crate.
+    assert_eq!(
+        contracts.iter().map(|contract| contract.artifact_name.as_str()).collect_vec(),
+        vec![
+            "contracts_hello_starknet_HelloStarknet",
+            "contracts_minimal_contract_MinimalContract"
+        ]
+    );
+
+    let missing_contract_paths = vec!["contracts::minimal_contract::Missing".to_string()];
+    let err = compile_contracts_in_path(
+        &crate_path,
+        &missing_contract_paths,
+        CompilerConfig::default(),
+        StarkNetPlugin::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Contract not found: contracts::minimal_contract::Missing.");
+}
+
+/// Tests that the artifacts of a contract are named by its `#[contract]` attribute, and that
+/// contracts whose artifacts would have the same name are rejected.
+#[test]
+fn test_contract_artifact_names() {
+    let dir = std::env::temp_dir().join("starknet_contract_artifact_names_test");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("contracts.cairo");
+    let contract = |attr: &str, name: &str| {
+        format!(
+            "#[{attr}]\nmod {name} {{\n    #[starknet::storage]\n    struct Storage {{}}\n    \
+             #[external]\n    fn empty(ref self: Storage) {{}}\n}}\n"
+        )
+    };
+    std::fs::write(
+        &path,
+        contract("contract(name: 'token')", "Token") + &contract("contract", "token"),
+    )
+    .unwrap();
+    let contracts =
+        compile_contracts_in_path(&path, &[], CompilerConfig::default(), StarkNetPlugin::default())
+            .unwrap();
+    assert_eq!(
+        contracts.iter().map(|contract| contract.artifact_name.as_str()).collect_vec(),
+        vec!["token", "contracts_contracts_token"]
+    );
+
+    std::fs::write(
+        &path,
+        contract("contract(name: 'token')", "Token")
+            + &contract("contract(name: 'token')", "Other"),
+    )
+    .unwrap();
+    let err = starknet_compile_contracts(
+        path,
+        &[],
+        None,
+        None,
+        &dir.join("artifacts"),
+        StarkNetPlugin::default(),
+    )
+    .unwrap_err();
+    std::fs::remove_dir_all(dir).unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Contracts `contracts::contracts::Token` and `contracts::contracts::Other` have the same \
+         artifact name `token`. Give one of them another name with `#[contract(name: '<name>')]`."
+    );
+}
+
+/// Tests that compiling a contract is reproducible, and that its entry points are sorted by
+/// selector.
+#[test]
+fn test_compile_is_reproducible() {
+    let path = get_example_file_path("erc20.cairo");
+    let compile = || {
+        let contract =
+            compile_path(&path, None, CompilerConfig::default(), StarkNetPlugin::default())
+                .unwrap();
+        serde_json::to_string_pretty(&contract).unwrap()
+    };
+    assert_eq!(compile(), compile());
+
+    let ContractEntryPoints { external, l1_handler, constructor } =
+        get_test_contract("erc20.cairo").entry_points_by_type;
+    for entry_points in [external, l1_handler, constructor] {
+        assert!(entry_points.iter().tuple_windows().all(|(a, b)| a.selector < b.selector));
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt::Display;
+use std::hash::Hash;
+
+use cairo_lang_sierra::program::Statement;
+use cairo_lang_sierra_to_casm::function_sizes::{function_sizes, FunctionSize};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::casm_contract_class::{
+    compile_to_casm, decode_sierra_program, StarknetSierraCompilationError,
+};
+use crate::contract_class::ContractClass;
+use crate::plugin::consts::{ABI_TRAIT, CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L1_HANDLER_MODULE};
+
+#[cfg(test)]
+#[path = "contract_size_test.rs"]
+mod test;
+
+/// The number of the largest functions listed when a contract class exceeds its size budget.
+const LARGEST_FUNCTIONS_TO_REPORT: usize = 5;
+
+/// The origin of the code of a function, determined by its debug name.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CodeOrigin {
+    /// Code written in the contract, or in the other crates it uses.
+    User,
+    /// Code generated by the Starknet plugin: the entry point wrappers, the ABI, the storage
+    /// variable accessors and the dispatchers.
+    Generated,
+    /// Code of the core library.
+    Corelib,
+}
+impl CodeOrigin {
+    /// Returns the origin of a function with the given debug name. Functions without a debug name
+    /// are considered user code.
+    pub fn of_function(debug_name: Option<&str>) -> Self {
+        let Some(debug_name) = debug_name else { return CodeOrigin::User };
+        if debug_name.starts_with("core::") {
+            return CodeOrigin::Corelib;
+        }
+        let is_generated = debug_name.split("::").any(|segment| {
+            [ABI_TRAIT, EXTERNAL_MODULE, L1_HANDLER_MODULE, CONSTRUCTOR_MODULE].contains(&segment)
+                || segment == "InternalStorageImpl"
+                || segment.ends_with("DispatcherImpl")
+        });
+        if is_generated { CodeOrigin::Generated } else { CodeOrigin::User }
+    }
+}
+impl Display for CodeOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodeOrigin::User => write!(f, "user"),
+            CodeOrigin::Generated => write!(f, "generated"),
+            CodeOrigin::Corelib => write!(f, "corelib"),
+        }
+    }
+}
+
+/// The breakdown of the size of a contract class, and of the bytecode compiled from it.
+/// Sizes are in felts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractSizeReport {
+    /// The size of the Sierra program of the contract class.
+    pub sierra_program_size: usize,
+    /// The size of the bytecode compiled from the contract class.
+    pub bytecode_size: usize,
+    /// The sizes of the functions of the contract class, from the largest bytecode.
+    pub functions: Vec<FunctionSize>,
+    /// The size of the bytecode compiled from the invocations of each libfunc category - the
+    /// generic libfunc, e.g. `store_temp` - and from the `return` statements, from the largest.
+    pub libfunc_categories: Vec<(String, usize)>,
+    /// The size of the bytecode compiled from the functions of each origin, from the largest.
+    pub origins: Vec<(CodeOrigin, usize)>,
+}
+impl ContractSizeReport {
+    /// Analyzes the size of a contract class, compiling it to find the size of its bytecode. The
+    /// functions are named by the debug information of the contract class, if it has any.
+    #[allow(clippy::result_large_err)]
+    pub fn new(contract_class: &ContractClass) -> Result<Self, StarknetSierraCompilationError> {
+        let mut program = decode_sierra_program(contract_class)?;
+        if let Some(debug_info) = &contract_class.sierra_program_debug_info {
+            debug_info.populate(&mut program);
+        }
+        let (_, cairo_program) = compile_to_casm(&program, &contract_class.entry_points_by_type)?;
+        let statements_info = &cairo_program.debug_info.sierra_statement_info;
+
+        let generic_libfuncs: UnorderedHashMap<_, _> = program
+            .libfunc_declarations
+            .iter()
+            .map(|declaration| (declaration.id.clone(), declaration.long_id.generic_id.to_string()))
+            .collect();
+        let mut libfunc_categories = OrderedHashMap::<String, usize>::default();
+        for (statement, (info, next_info)) in
+            program.statements.iter().zip(statements_info.iter().tuple_windows())
+        {
+            let category = match statement {
+                Statement::Invocation(invocation) => {
+                    generic_libfuncs[invocation.libfunc_id.clone()].clone()
+                }
+                Statement::Return(_) => "return".into(),
+            };
+            *libfunc_categories.entry(category).or_default() +=
+                next_info.code_offset - info.code_offset;
+        }
+
+        let functions = function_sizes(&program, &cairo_program)
+            .into_iter()
+            .sorted_by(|a, b| {
+                b.bytecode_size
+                    .cmp(&a.bytecode_size)
+                    .then_with(|| a.id.to_string().cmp(&b.id.to_string()))
+            })
+            .collect_vec();
+        let mut origins = OrderedHashMap::<CodeOrigin, usize>::default();
+        for function in &functions {
+            let origin = CodeOrigin::of_function(function.id.debug_name.as_deref());
+            *origins.entry(origin).or_default() += function.bytecode_size;
+        }
+
+        Ok(Self {
+            sierra_program_size: contract_class.sierra_program.len(),
+            bytecode_size: statements_info.last().map(|info| info.code_offset).unwrap_or_default(),
+            functions,
+            libfunc_categories: sorted_by_size(libfunc_categories),
+            origins: sorted_by_size(origins),
+        })
+    }
+
+    /// Checks that the bytecode of the contract class is at most `max_bytecode_size` felts.
+    pub fn check_bytecode_size(&self, max_bytecode_size: usize) -> Result<(), BytecodeSizeError> {
+        if self.bytecode_size <= max_bytecode_size {
+            return Ok(());
+        }
+        Err(BytecodeSizeError {
+            bytecode_size: self.bytecode_size,
+            max_bytecode_size,
+            largest_functions: self
+                .functions
+                .iter()
+                .take(LARGEST_FUNCTIONS_TO_REPORT)
+                .map(|function| (function.id.to_string(), function.bytecode_size))
+                .collect(),
+        })
+    }
+}
+impl Display for ContractSizeReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Sierra program: {} felts", self.sierra_program_size)?;
+        writeln!(f, "Bytecode: {} felts", self.bytecode_size)?;
+        writeln!(f)?;
+        writeln!(f, "By origin:")?;
+        for (origin, size) in &self.origins {
+            writeln!(f, "  {origin}: {size} felts")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "By libfunc category:")?;
+        for (category, size) in &self.libfunc_categories {
+            writeln!(f, "  {category}: {size} felts")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "By function:")?;
+        for function in &self.functions {
+            writeln!(
+                f,
+                "  {}: {} felts, {} Sierra statements",
+                function.id, function.bytecode_size, function.sierra_statements
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Error for a contract class whose bytecode exceeds its size budget.
+#[derive(Error, Debug, Eq, PartialEq)]
+#[error(
+    "The bytecode size of {bytecode_size} felts exceeds the budget of {max_bytecode_size} felts. \
+     The largest functions are: {}.",
+    .largest_functions.iter().map(|(name, size)| format!("{name} ({size} felts)")).join(", ")
+)]
+pub struct BytecodeSizeError {
+    pub bytecode_size: usize,
+    pub max_bytecode_size: usize,
+    /// The names and bytecode sizes of the largest functions of the contract class.
+    pub largest_functions: Vec<(String, usize)>,
+}
+
+/// Returns the entries of the sizes map, from the largest size, and then by their order in the map.
+fn sorted_by_size<K: Hash + Eq>(sizes: OrderedHashMap<K, usize>) -> Vec<(K, usize)> {
+    sizes.into_iter().sorted_by(|(_, a), (_, b)| b.cmp(a)).collect()
+}
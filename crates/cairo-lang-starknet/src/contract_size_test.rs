@@ -0,0 +1,62 @@
+use std::fs::read_to_string;
+
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{CodeOrigin, ContractSizeReport};
+use crate::casm_contract_class::CasmContractClass;
+use crate::contract_class::ContractClass;
+use crate::test_utils::get_example_file_path;
+
+#[test_case(None, CodeOrigin::User; "unnamed")]
+#[test_case(Some("test_contract::TestContract::test"), CodeOrigin::User; "user")]
+#[test_case(Some("core::serde::Felt252Serde::serialize"), CodeOrigin::Corelib; "corelib")]
+#[test_case(
+    Some("test_contract::TestContract::__external::test"),
+    CodeOrigin::Generated;
+    "entry point wrapper"
+)]
+#[test_case(
+    Some("test_contract::TestContract::my_storage_var::InternalStorageImpl::read"),
+    CodeOrigin::Generated;
+    "storage variable accessor"
+)]
+#[test_case(
+    Some("test_contract::IAnotherContractLibraryDispatcherImpl::foo"),
+    CodeOrigin::Generated;
+    "dispatcher"
+)]
+fn test_code_origin(debug_name: Option<&str>, expected: CodeOrigin) {
+    assert_eq!(CodeOrigin::of_function(debug_name), expected);
+}
+
+#[test]
+fn test_contract_size_report() {
+    let contract_class: ContractClass = serde_json::from_str(
+        &read_to_string(get_example_file_path("test_contract.sierra.json")).unwrap(),
+    )
+    .unwrap();
+    let report = ContractSizeReport::new(&contract_class).unwrap();
+
+    let casm_contract_class =
+        CasmContractClass::from_contract_class(contract_class, false).unwrap();
+    assert_eq!(report.bytecode_size, casm_contract_class.bytecode.len());
+    // Each breakdown covers the whole bytecode.
+    let functions_size: usize =
+        report.functions.iter().map(|function| function.bytecode_size).sum();
+    assert_eq!(functions_size, report.bytecode_size);
+    let libfunc_categories_size: usize =
+        report.libfunc_categories.iter().map(|(_, size)| size).sum();
+    assert_eq!(libfunc_categories_size, report.bytecode_size);
+    let origins_size: usize = report.origins.iter().map(|(_, size)| size).sum();
+    assert_eq!(origins_size, report.bytecode_size);
+    assert!(report.origins.iter().any(|(origin, _)| *origin == CodeOrigin::Generated));
+
+    assert_eq!(report.check_bytecode_size(report.bytecode_size), Ok(()));
+    let error = report.check_bytecode_size(report.bytecode_size - 1).unwrap_err();
+    assert_eq!(error.largest_functions.len(), 5);
+    assert_eq!(
+        error.largest_functions[0],
+        (report.functions[0].id.to_string(), report.functions[0].bytecode_size)
+    );
+}
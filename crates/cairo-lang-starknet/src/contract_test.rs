@@ -1,13 +1,19 @@
 use std::sync::Arc;
 
 use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_semantic::test_utils::setup_test_crate;
 use indoc::indoc;
 use itertools::Itertools;
+use num_bigint::BigUint;
 use pretty_assertions::assert_eq;
 
-use crate::contract::{find_contracts, get_module_functions, starknet_keccak};
+use crate::contract::{
+    entry_point_selector, event_selector, find_contracts, get_module_functions, starknet_keccak,
+    starknet_poseidon, SelectorHash,
+};
+use crate::contract_class::compile_prepared_db;
 use crate::plugin::consts::EXTERNAL_MODULE;
 use crate::plugin::StarkNetPlugin;
 
@@ -60,3 +66,83 @@ fn test_starknet_keccak() {
         "0x15d40a3d6ca2ac30f4031e42be28da9b056fef9bb7357ac5e85627ee876e5ad",
     )
 }
+
+#[test]
+fn test_selectors() {
+    assert_eq!(
+        format!("0x{:x}", entry_point_selector("transfer")),
+        "0x83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e",
+    );
+    assert_eq!(entry_point_selector("__default__"), 0_u32.into());
+    assert_eq!(event_selector("Transfer", None), starknet_keccak("Transfer".as_bytes()));
+    assert_eq!(
+        event_selector("Transfer", Some("MyToken")),
+        starknet_keccak("MyToken::Transfer".as_bytes())
+    );
+}
+
+#[test]
+fn test_selector_hash() {
+    assert_eq!(SelectorHash::default(), SelectorHash::StarknetKeccak);
+    assert_eq!(
+        SelectorHash::StarknetKeccak.entry_point_selector("transfer"),
+        entry_point_selector("transfer")
+    );
+    let selector = SelectorHash::Poseidon.entry_point_selector("transfer");
+    assert_eq!(selector, starknet_poseidon("transfer".as_bytes()));
+    assert_ne!(selector, entry_point_selector("transfer"));
+    assert!(selector.bits() <= 250);
+    assert_eq!(SelectorHash::Poseidon.entry_point_selector("__default__"), 0_u32.into());
+    assert_eq!(
+        SelectorHash::Poseidon.event_selector("Transfer", Some("MyToken")),
+        starknet_poseidon("MyToken::Transfer".as_bytes())
+    );
+    // Data longer than a single field element is hashed as multiple field elements.
+    let long_name = "a_name_longer_than_thirty_one_bytes";
+    assert_ne!(
+        starknet_poseidon(long_name.as_bytes()),
+        starknet_poseidon(&long_name.as_bytes()[..31])
+    );
+}
+
+/// Tests that the entry points of a contract class get the selectors derived by the hash the
+/// plugin is configured with.
+#[test]
+fn test_contract_class_selector_hash() {
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(
+            StarkNetPlugin::default().with_selector_hash(SelectorHash::Poseidon),
+        ))
+        .build()
+        .unwrap();
+    let crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[contract]
+            mod MinimalContract {
+                #[starknet::storage]
+                struct Storage {}
+
+                #[external]
+                fn empty(ref self: Storage) {}
+            }
+        "},
+    );
+
+    let contracts = find_contracts(db, &[crate_id]);
+    assert_eq!(contracts[0].selector_hash, SelectorHash::Poseidon);
+    let contract_classes =
+        compile_prepared_db(db, &contracts.iter().collect_vec(), CompilerConfig::default())
+            .unwrap();
+    assert_eq!(
+        contract_classes[0]
+            .entry_points_by_type
+            .external
+            .iter()
+            .map(|entry_point| entry_point.selector.clone())
+            .collect_vec(),
+        vec![starknet_poseidon("empty".as_bytes())]
+    );
+    assert_ne!(starknet_poseidon("empty".as_bytes()), BigUint::from(0_u32));
+}
@@ -0,0 +1,164 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::LanguageElementId;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_plugins::plugins::is_enabled_by_cfg;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use serde::{Deserialize, Serialize};
+
+use crate::contract::ContractDeclaration;
+use crate::plugin::consts::{
+    CONSTRUCTOR_ATTR, DEFAULT_ATTR, EVENT_ATTR, EXTERNAL_ATTR, L1_HANDLER_ATTR, STORAGE_STRUCT_NAME,
+};
+
+#[cfg(test)]
+#[path = "docs_test.rs"]
+mod test;
+
+/// The documentation of a contract, collected from its `///` doc comments.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractDocs {
+    /// The doc comment of the contract module.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entry_points: Vec<ItemDocs>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<ItemDocs>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<ItemDocs>,
+}
+impl ContractDocs {
+    pub fn is_empty(&self) -> bool {
+        self.contract.is_none()
+            && self.entry_points.is_empty()
+            && self.events.is_empty()
+            && self.storage.is_empty()
+    }
+}
+
+/// The doc comment of a named contract item.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemDocs {
+    pub name: String,
+    pub docs: String,
+}
+
+/// Collects the doc comments of the contract module, and of its entry points, events and storage
+/// variables.
+pub fn extract_contract_docs(
+    db: &dyn DefsGroup,
+    contract: &ContractDeclaration,
+) -> Maybe<ContractDocs> {
+    let syntax_db = db.upcast();
+    let parent_module_id = contract.submodule_id.parent_module(db);
+    let module_ast = db.module_submodules(parent_module_id)?[contract.submodule_id].clone();
+    let mut contract_docs = ContractDocs {
+        contract: extract_docs(syntax_db, &module_ast.as_syntax_node()),
+        ..ContractDocs::default()
+    };
+    let ast::MaybeModuleBody::Some(body) = module_ast.body(syntax_db) else {
+        return Ok(contract_docs);
+    };
+
+    for item in body.items(syntax_db).elements(syntax_db) {
+        if !is_enabled_by_cfg(syntax_db, &item) {
+            continue;
+        }
+        match &item {
+            ast::Item::FreeFunction(item_function)
+                if item_function.has_attr(syntax_db, EVENT_ATTR) =>
+            {
+                push_item_docs(
+                    syntax_db,
+                    &mut contract_docs.events,
+                    item_function.declaration(syntax_db).name(syntax_db),
+                    &item.as_syntax_node(),
+                );
+            }
+            ast::Item::FreeFunction(item_function)
+                if [EXTERNAL_ATTR, CONSTRUCTOR_ATTR, L1_HANDLER_ATTR, DEFAULT_ATTR]
+                    .into_iter()
+                    .any(|attr| item_function.has_attr(syntax_db, attr)) =>
+            {
+                push_item_docs(
+                    syntax_db,
+                    &mut contract_docs.entry_points,
+                    item_function.declaration(syntax_db).name(syntax_db),
+                    &item.as_syntax_node(),
+                );
+            }
+            ast::Item::Impl(item_impl) if item_impl.has_attr(syntax_db, EXTERNAL_ATTR) => {
+                let ast::MaybeImplBody::Some(impl_body) = item_impl.body(syntax_db) else {
+                    continue;
+                };
+                for impl_item in impl_body.items(syntax_db).elements(syntax_db) {
+                    let ast::ImplItem::Function(item_function) = &impl_item else { continue; };
+                    push_item_docs(
+                        syntax_db,
+                        &mut contract_docs.entry_points,
+                        item_function.declaration(syntax_db).name(syntax_db),
+                        &impl_item.as_syntax_node(),
+                    );
+                }
+            }
+            ast::Item::Enum(item_enum) if item_enum.name(syntax_db).text(syntax_db) == "Event" => {
+                for variant in item_enum.variants(syntax_db).elements(syntax_db) {
+                    push_item_docs(
+                        syntax_db,
+                        &mut contract_docs.events,
+                        variant.name(syntax_db),
+                        &variant.as_syntax_node(),
+                    );
+                }
+            }
+            ast::Item::Struct(item_struct)
+                if item_struct.name(syntax_db).text(syntax_db) == STORAGE_STRUCT_NAME =>
+            {
+                for member in item_struct.members(syntax_db).elements(syntax_db) {
+                    push_item_docs(
+                        syntax_db,
+                        &mut contract_docs.storage,
+                        member.name(syntax_db),
+                        &member.as_syntax_node(),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(contract_docs)
+}
+
+/// Adds the doc comment of the item with the given name, if it has one.
+fn push_item_docs(
+    db: &dyn SyntaxGroup,
+    items_docs: &mut Vec<ItemDocs>,
+    name: ast::TerminalIdentifier,
+    node: &SyntaxNode,
+) {
+    if let Some(docs) = extract_docs(db, node) {
+        items_docs.push(ItemDocs { name: name.text(db).into(), docs });
+    }
+}
+
+/// Returns the `///` doc comment directly preceding the given node, if any.
+/// The lines of the comment are joined by newlines, without their `///` prefix.
+fn extract_docs(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Option<String> {
+    let leading_trivia = node.clone().get_text_of_span(
+        db,
+        TextSpan { start: node.offset(), end: node.span_start_without_trivia(db) },
+    );
+    let mut doc_lines = vec![];
+    for line in leading_trivia.lines().map(str::trim) {
+        if let Some(doc_line) = line.strip_prefix("///") {
+            doc_lines.push(doc_line.strip_prefix(' ').unwrap_or(doc_line));
+        } else if !line.is_empty() {
+            // Only the doc comment block directly preceding the node is relevant.
+            doc_lines.clear();
+        }
+    }
+    if doc_lines.is_empty() { None } else { Some(doc_lines.join("\n")) }
+}
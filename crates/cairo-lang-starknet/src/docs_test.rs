@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_semantic::test_utils::setup_test_crate;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use crate::contract::find_contracts;
+use crate::docs::{extract_contract_docs, ContractDocs, ItemDocs};
+use crate::plugin::StarkNetPlugin;
+
+#[test]
+fn test_extract_contract_docs() {
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let _crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            /// A simple counter.
+            /// Counts things.
+            #[contract]
+            mod Counter {
+                #[starknet::storage]
+                struct Storage {
+                    /// The current value.
+                    value: u128,
+                    undocumented: u128,
+                }
+
+                /// Emitted when the counter is increased.
+                #[event]
+                fn Increased(amount: u128) {}
+
+                // A regular comment.
+                #[external]
+                fn undocumented(ref self: Storage) {}
+
+                /// Increases the counter.
+                #[external]
+                fn increase(ref self: Storage, amount: u128) {}
+
+                #[external]
+                impl CounterImpl {
+                    ///Resets the counter.
+                    fn reset(ref self: Storage) {}
+                }
+            }
+        "},
+    );
+
+    let contracts = find_contracts(db, &db.crates());
+    assert_eq!(
+        extract_contract_docs(db, &contracts[0]).unwrap(),
+        ContractDocs {
+            contract: Some("A simple counter.\nCounts things.".into()),
+            entry_points: vec![
+                ItemDocs { name: "increase".into(), docs: "Increases the counter.".into() },
+                ItemDocs { name: "reset".into(), docs: "Resets the counter.".into() },
+            ],
+            events: vec![ItemDocs {
+                name: "Increased".into(),
+                docs: "Emitted when the counter is increased.".into(),
+            }],
+            storage: vec![ItemDocs { name: "value".into(), docs: "The current value.".into() }],
+        }
+    );
+}
@@ -2,6 +2,9 @@ use cairo_lang_sierra::extensions::starknet::interoperability::ContractAddressTr
 use cairo_lang_sierra::extensions::starknet::secp256k1::{
     Secp256K1EcGetCoordinatesLibfunc, Secp256K1EcGetPointFromXLibfunc,
 };
+use cairo_lang_sierra::extensions::starknet::secp256r1::{
+    Secp256R1EcGetCoordinatesLibfunc, Secp256R1EcGetPointFromXLibfunc,
+};
 use cairo_lang_sierra::extensions::starknet::storage::{
     StorageAddressFromBaseAndOffsetLibfunc, StorageAddressTryFromFelt252Trait,
     StorageBaseAddressFromFelt252Libfunc,
@@ -186,6 +189,8 @@ static SERDE_SUPPORTED_LONG_IDS: Lazy<OrderedHashSet<&'static str>> = Lazy::new(
             StorageAddressTryFromFelt252Trait::STR_ID,
             Secp256K1EcGetPointFromXLibfunc::STR_ID,
             Secp256K1EcGetCoordinatesLibfunc::STR_ID,
+            Secp256R1EcGetPointFromXLibfunc::STR_ID,
+            Secp256R1EcGetCoordinatesLibfunc::STR_ID,
         ]
         .into_iter(),
     )
@@ -5,14 +5,23 @@
 //!
 //! Learn more at [starkware.io](http://starknet.io/).
 pub mod abi;
+pub mod abi_to_trait;
 pub mod allowed_libfuncs;
+pub mod casm_cache;
 pub mod casm_contract_class;
+pub mod class_hash;
 mod compiler_version;
 pub mod contract;
 pub mod contract_class;
-mod felt252_serde;
+pub mod contract_size;
+pub mod docs;
+pub mod felt252_serde;
 mod felt252_vec_compression;
 pub mod plugin;
+pub mod reproducibility;
+pub mod sierra_text;
+pub mod source_map;
+pub mod storage_layout;
 
 #[cfg(test)]
 mod test_utils;
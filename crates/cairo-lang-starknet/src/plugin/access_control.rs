@@ -0,0 +1,185 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::{ErrorCode, Severity};
+use cairo_lang_plugins::plugins::is_enabled_by_cfg;
+use cairo_lang_semantic::patcher::RewriteNode;
+use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeStructurize};
+use cairo_lang_syntax::node::ast::{self, FunctionWithBody};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+
+use super::consts::{
+    ACCESS_CONTROL_MODULE, CONSTRUCTOR_ATTR, CONSTRUCTOR_ENTRY_POINT_NAME, EXTERNAL_ATTR,
+    ONLY_OWNER_ATTR, OWNER_VAR_NAME, REQUIRE_ATTR, ROLES_VAR_NAME, WHEN_NOT_PAUSED_ATTR,
+};
+use super::entry_point::{entry_point_wrapper_code, EntryPointKind};
+use crate::contract::SelectorHash;
+
+/// Returns true if the entry point is access controlled, i.e. has an `#[only_owner]` or a
+/// `#[require(...)]` attribute.
+pub fn has_access_control(db: &dyn SyntaxGroup, function: &FunctionWithBody) -> bool {
+    function.has_attr(db, ONLY_OWNER_ATTR) || function.has_attr(db, REQUIRE_ATTR)
+}
+
+/// Returns true if the contract has an owner, i.e. has access controlled entry points or is
+/// pausable, as pausing and unpausing it is restricted to its owner.
+pub fn contract_has_access_control(db: &dyn SyntaxGroup, items: &[ast::Item]) -> bool {
+    let is_owned = |function: &FunctionWithBody| {
+        has_access_control(db, function) || function.has_attr(db, WHEN_NOT_PAUSED_ATTR)
+    };
+    items.iter().any(|item| match item {
+        ast::Item::FreeFunction(function) => {
+            EntryPointKind::try_from_function_with_body(db, function).is_some()
+                && is_owned(function)
+        }
+        ast::Item::Impl(item_impl) if item_impl.has_attr(db, EXTERNAL_ATTR) => {
+            let ast::MaybeImplBody::Some(body) = item_impl.body(db) else { return false };
+            body.items(db).elements(db).into_iter().any(|item| {
+                matches!(item, ast::ImplItem::Function(function)
+                    if is_enabled_by_cfg(db, &function) && is_owned(&function))
+            })
+        }
+        _ => false,
+    })
+}
+
+/// Returns the check of the `#[only_owner]` attribute, to be generated in the wrapper of the entry
+/// point.
+pub fn only_owner_check() -> String {
+    format!("\n            super::{ACCESS_CONTROL_MODULE}::assert_only_owner();")
+}
+
+/// Returns the code setting the caller of the constructor, i.e. the deployer of the contract, as
+/// its owner, to be generated in the wrapper of the constructor.
+pub fn owner_initialization() -> String {
+    format!(
+        "\n            super::{ACCESS_CONTROL_MODULE}::set_owner(starknet::get_caller_address());"
+    )
+}
+
+/// Generates the wrapper of the constructor of a contract with an owner that does not define a
+/// constructor, which only sets the owner.
+pub fn owner_constructor_wrapper() -> RewriteNode {
+    entry_point_wrapper_code(
+        RewriteNode::Text(CONSTRUCTOR_ENTRY_POINT_NAME.to_string()),
+        RewriteNode::Text("".to_string()),
+        owner_initialization(),
+        RewriteNode::Text(
+            "let mut arr = array::array_new();
+            array::ArrayTrait::span(@arr)"
+                .to_string(),
+        ),
+    )
+}
+
+/// Returns the ABI declaration of the constructor generated by [owner_constructor_wrapper].
+pub fn owner_constructor_abi() -> RewriteNode {
+    let name = CONSTRUCTOR_ENTRY_POINT_NAME;
+    RewriteNode::Text(format!(
+        "#[{CONSTRUCTOR_ATTR}]\n        fn {name}(ref self: Storage);\n        "
+    ))
+}
+
+/// Returns the checks of the `#[only_owner]` and `#[require(...)]` attributes of the entry point,
+/// to be generated in its wrapper.
+/// The roles of `#[require(...)]` are given as literals or as constants of the contract.
+pub fn access_control_checks(
+    db: &dyn SyntaxGroup,
+    function: &FunctionWithBody,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> String {
+    let mut checks = String::new();
+    if function.has_attr(db, ONLY_OWNER_ATTR) {
+        checks += &only_owner_check();
+    }
+    for attr in function.query_attr(db, REQUIRE_ATTR) {
+        let attr = attr.structurize(db);
+        if attr.args.is_empty() {
+            diagnostics.push(PluginDiagnostic {
+                message: format!("`{REQUIRE_ATTR}` must be given at least one role."),
+                stable_ptr: attr.stable_ptr.untyped(),
+                code: Some(ErrorCode::new("SN001")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        }
+        for arg in attr.args {
+            let role = match arg.variant {
+                AttributeArgVariant::Unnamed {
+                    value: value @ (ast::Expr::ShortString(_) | ast::Expr::Literal(_)),
+                    ..
+                } => value.as_syntax_node().get_text_without_trivia(db),
+                AttributeArgVariant::Unnamed { value: ast::Expr::Path(path), .. } => {
+                    format!("super::{}", path.as_syntax_node().get_text_without_trivia(db))
+                }
+                _ => {
+                    diagnostics.push(PluginDiagnostic {
+                        message: "Expected a role, given as a literal or a constant.".to_string(),
+                        stable_ptr: arg.arg_stable_ptr.untyped(),
+                        code: Some(ErrorCode::new("SN002")),
+                        severity: Severity::Error,
+                        fixes: vec![],
+                    });
+                    continue;
+                }
+            };
+            checks +=
+                &format!("\n            super::{ACCESS_CONTROL_MODULE}::assert_has_role({role});");
+        }
+    }
+    checks
+}
+
+/// Generates the module managing the owner and the roles of an access controlled contract.
+pub fn access_control_module(selector_hash: SelectorHash) -> RewriteNode {
+    let owner_address = format!("0x{:x}", selector_hash.hash(OWNER_VAR_NAME.as_bytes()));
+    let roles_address = format!("0x{:x}", selector_hash.hash(ROLES_VAR_NAME.as_bytes()));
+    RewriteNode::Text(format!(
+        "
+    mod {ACCESS_CONTROL_MODULE} {{
+        use starknet::SyscallResultTrait;
+        use starknet::SyscallResultTraitImpl;
+        use starknet::ContractAddress;
+        use starknet::contract_address::ContractAddressPartialEq;
+
+        fn owner_address() -> starknet::StorageBaseAddress {{
+            starknet::storage_base_address_const::<{owner_address}>()
+        }}
+        fn role_address(role: felt252, account: ContractAddress) -> starknet::StorageBaseAddress {{
+            starknet::storage_base_address_from_felt252(
+                hash::LegacyHash::<(felt252, ContractAddress)>::hash(
+                    {roles_address}, (role, account)
+                )
+            )
+        }}
+
+        fn owner() -> ContractAddress {{
+            starknet::StorageAccess::<ContractAddress>::read(0_u32, owner_address())
+                .unwrap_syscall()
+        }}
+        fn set_owner(owner: ContractAddress) {{
+            starknet::StorageAccess::<ContractAddress>::write(0_u32, owner_address(), owner)
+                .unwrap_syscall()
+        }}
+        fn assert_only_owner() {{
+            assert(starknet::get_caller_address() == owner(), 'Caller is not the owner');
+        }}
+
+        fn has_role(role: felt252, account: ContractAddress) -> bool {{
+            starknet::StorageAccess::<bool>::read(0_u32, role_address(role, account))
+                .unwrap_syscall()
+        }}
+        fn grant_role(role: felt252, account: ContractAddress) {{
+            starknet::StorageAccess::<bool>::write(0_u32, role_address(role, account), true)
+                .unwrap_syscall()
+        }}
+        fn revoke_role(role: felt252, account: ContractAddress) {{
+            starknet::StorageAccess::<bool>::write(0_u32, role_address(role, account), false)
+                .unwrap_syscall()
+        }}
+        fn assert_has_role(role: felt252) {{
+            assert(has_role(role, starknet::get_caller_address()), 'Caller is missing role');
+        }}
+    }}"
+    ))
+}
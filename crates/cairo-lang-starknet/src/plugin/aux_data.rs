@@ -7,6 +7,8 @@ use cairo_lang_semantic::plugin::{
 };
 use cairo_lang_semantic::SemanticDiagnostic;
 
+use crate::contract::SelectorHash;
+
 /// Contract related auxiliary data of the Starknet plugin.
 #[derive(Debug, PartialEq, Eq)]
 pub struct StarkNetContractAuxData {
@@ -15,6 +17,13 @@ pub struct StarkNetContractAuxData {
 
     /// A list of contracts that were processed by the plugin.
     pub contracts: Vec<smol_str::SmolStr>,
+
+    /// The name of the artifacts of the contracts, given by the `name` argument of their
+    /// `#[contract]` attribute, if they have one.
+    pub artifact_name: Option<smol_str::SmolStr>,
+
+    /// The hash from which the plugin derived the selectors of the contracts.
+    pub selector_hash: SelectorHash,
 }
 impl GeneratedFileAuxData for StarkNetContractAuxData {
     fn as_any(&self) -> &dyn std::any::Any {
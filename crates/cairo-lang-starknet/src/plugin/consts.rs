@@ -3,18 +3,80 @@ pub const EXTERNAL_MODULE: &str = "__external";
 pub const L1_HANDLER_MODULE: &str = "__l1_handler";
 pub const CONSTRUCTOR_MODULE: &str = "__constructor";
 pub const STORAGE_STRUCT_NAME: &str = "Storage";
+pub(super) const ACCESS_CONTROL_MODULE: &str = "__access_control__";
+pub(super) const PAUSABLE_MODULE: &str = "__pausable__";
+pub(super) const ERROR_ENUM_NAME: &str = "Error";
+pub(super) const EVENT_TYPE_NAME: &str = "Event";
+pub(super) const CONTRACT_ERRORS_TRAIT: &str = "ContractErrors";
 
 pub const EVENT_ATTR: &str = "event";
-pub(super) const ABI_ATTR: &str = "starknet::interface";
+/// The attribute of the members of an event struct emitted as keys of the event, rather than as
+/// its data.
+pub const KEY_ATTR: &str = "key";
+/// The attribute of the function of the ABI trait declaring the events of a contract by the type
+/// of its `Event` enum.
+pub const EVENTS_ATTR: &str = "events";
+pub const DEFAULT_ATTR: &str = "default";
+pub const L1_MESSAGE_ATTR: &str = "l1_message";
+pub const EXTERNAL_ATTR: &str = "external";
+pub const L1_HANDLER_ATTR: &str = "l1_handler";
+pub const CONSTRUCTOR_ATTR: &str = "constructor";
+pub const DEPRECATED_ATTR: &str = "deprecated";
+pub const ERROR_ATTR: &str = "error";
+/// The argument of the `#[event]` attribute in the ABI trait recording the namespace of the event
+/// selector.
+pub const EVENT_NAMESPACE_ARG: &str = "namespace";
+pub const ABI_ATTR: &str = "starknet::interface";
 pub(super) const CONTRACT_ATTR: &str = "contract";
-pub(super) const EXTERNAL_ATTR: &str = "external";
-pub(super) const L1_HANDLER_ATTR: &str = "l1_handler";
-pub(super) const CONSTRUCTOR_ATTR: &str = "constructor";
+/// The argument of the `#[contract]` attribute giving the name of the artifacts of the contract.
+pub(super) const CONTRACT_NAME_ARG: &str = "name";
 pub(super) const RAW_OUTPUT_ATTR: &str = "raw_output";
+pub(super) const NON_REENTRANT_ATTR: &str = "non_reentrant";
+pub(super) const ONLY_OWNER_ATTR: &str = "only_owner";
+pub(super) const REQUIRE_ATTR: &str = "require";
+pub(super) const WHEN_NOT_PAUSED_ATTR: &str = "when_not_paused";
 
 pub(super) const L1_HANDLER_FIRST_PARAM_NAME: &str = "from_address";
+pub(super) const L1_HANDLER_PAYLOAD_PARAM_NAME: &str = "payload";
 pub(super) const CALLDATA_PARAM_NAME: &str = "__calldata__";
 
+/// The name of the storage variable of the re-entrancy guard, shared by all the `non_reentrant`
+/// entry points of a contract.
+pub(super) const REENTRANCY_GUARD_VAR_NAME: &str = "__reentrancy_guard__";
+
+/// The names of the storage variables of the owner and the roles of an access controlled contract.
+pub(super) const OWNER_VAR_NAME: &str = "__owner__";
+pub(super) const ROLES_VAR_NAME: &str = "__roles__";
+/// The name of the constructor generated for contracts with an owner that do not define one.
+pub(super) const CONSTRUCTOR_ENTRY_POINT_NAME: &str = "constructor";
+
+/// The name of the storage variable of the paused flag of a pausable contract, and the names of the
+/// entry points and the events generated for pausing and unpausing it.
+pub(super) const PAUSED_VAR_NAME: &str = "__paused__";
+pub(super) const PAUSE_ENTRY_POINT_NAME: &str = "pause";
+pub(super) const UNPAUSE_ENTRY_POINT_NAME: &str = "unpause";
+pub(super) const PAUSED_EVENT_NAME: &str = "Paused";
+pub(super) const UNPAUSED_EVENT_NAME: &str = "Unpaused";
+
+/// The name of the generated wrapper of the default entry point.
+pub const DEFAULT_ENTRY_POINT_NAME: &str = "__default__";
+
+/// Names of items generated by the plugin in a contract module, which user items must not use.
+pub(super) const RESERVED_CONTRACT_ITEM_NAMES: &[&str] = &[
+    ABI_TRAIT,
+    EXTERNAL_MODULE,
+    L1_HANDLER_MODULE,
+    CONSTRUCTOR_MODULE,
+    ACCESS_CONTROL_MODULE,
+    PAUSABLE_MODULE,
+    CONTRACT_ERRORS_TRAIT,
+    "ContractErrorsImpl",
+    "TEST_CLASS_HASH",
+    "unsafe_new_storage",
+    "StorageDrop",
+    "StorageEventEmitter",
+];
+
 /// Starknet OS required implicit precedence.
 pub(super) const IMPLICIT_PRECEDENCE: &[&str] = &[
     "Pedersen",
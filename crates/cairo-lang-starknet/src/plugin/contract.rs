@@ -2,26 +2,48 @@ use std::vec;
 
 use cairo_lang_defs::db::get_all_path_leafs;
 use cairo_lang_defs::plugin::{
-    DynGeneratedFileAuxData, PluginDiagnostic, PluginGeneratedFile, PluginResult,
+    DynGeneratedFileAuxData, PluginDiagnostic, PluginFix, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::{ErrorCode, Severity};
+use cairo_lang_plugins::plugins::is_enabled_by_cfg;
 use cairo_lang_semantic::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_semantic::plugin::DynPluginAuxData;
+use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeStructurize};
 use cairo_lang_syntax::node::ast::{MaybeModuleBody, OptionWrappedGenericParamList};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{GetIdentifier, QueryAttrs};
 use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use indoc::formatdoc;
+use smol_str::SmolStr;
 
+use super::access_control::{
+    access_control_module, contract_has_access_control, owner_constructor_abi,
+    owner_constructor_wrapper,
+};
 use super::consts::{
-    ABI_TRAIT, CONSTRUCTOR_MODULE, CONTRACT_ATTR, EVENT_ATTR, EXTERNAL_ATTR, EXTERNAL_MODULE,
-    L1_HANDLER_FIRST_PARAM_NAME, L1_HANDLER_MODULE, STORAGE_STRUCT_NAME,
+    ABI_TRAIT, CONSTRUCTOR_MODULE, CONTRACT_ATTR, CONTRACT_NAME_ARG, DEPRECATED_ATTR,
+    ERROR_ENUM_NAME, EVENT_ATTR, EVENT_TYPE_NAME, EXTERNAL_ATTR, EXTERNAL_MODULE,
+    L1_HANDLER_FIRST_PARAM_NAME, L1_HANDLER_MODULE, L1_MESSAGE_ATTR, PAUSE_ENTRY_POINT_NAME,
+    RESERVED_CONTRACT_ITEM_NAMES, STORAGE_STRUCT_NAME, UNPAUSE_ENTRY_POINT_NAME,
+    WHEN_NOT_PAUSED_ATTR,
+};
+use super::entry_point::{
+    generate_default_entry_point_wrapper, generate_entry_point_wrapper,
+    is_l1_handler_payload_param, EntryPointKind,
 };
-use super::entry_point::{generate_entry_point_wrapper, EntryPointKind};
-use super::events::handle_event;
+use super::errors::handle_error_enum;
+use super::events::{abi_contract_events, handle_event};
+use super::l1_message::handle_l1_message_abi;
+use super::lints::{shadowed_storage_variables_lint, storage_mutability_lint};
+use super::pausable::{pausable_abi, pausable_entry_point_wrappers, pausable_module};
 use super::storage::handle_storage_struct;
-use super::utils::{is_felt252, is_mut_param, maybe_strip_underscore};
+use super::utils::{
+    is_felt252, is_mut_param, is_ref_param, maybe_strip_underscore, with_attribute_before,
+};
+use super::{StarkNetPlugin, TestClassHash};
 use crate::contract::starknet_keccak;
 use crate::plugin::aux_data::StarkNetContractAuxData;
 
@@ -36,30 +58,48 @@ pub fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> Plugi
             diagnostics: vec![PluginDiagnostic {
                 message: "Contracts without body are not supported.".to_string(),
                 stable_ptr: module_ast.stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN003")),
+                severity: Severity::Error,
+                fixes: vec![],
             }],
             remove_original_item: false,
         };
     };
-    let Some(storage_struct_ast) = body.items(db).elements(db).into_iter().find(|item| {
-        matches!(item, ast::Item::Struct(struct_ast) if struct_ast.name(db).text(db) == "Storage")
-    }) else {
+    let Some(storage_struct_ast) =
+        body.items(db).elements(db).into_iter().find_map(|item| match item {
+            ast::Item::Struct(struct_ast) if struct_ast.name(db).text(db) == "Storage" => {
+                Some(struct_ast)
+            }
+            _ => None,
+        })
+    else {
         return PluginResult {
             code: None,
             diagnostics: vec![PluginDiagnostic {
                 message: "Contracts must define a 'Storage' struct.".to_string(),
                 stable_ptr: module_ast.stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN004")),
+                severity: Severity::Error,
+                fixes: vec![],
             }],
             remove_original_item: false,
         };
     };
 
     if !storage_struct_ast.has_attr(db, "starknet::storage") {
+        let struct_kw = storage_struct_ast.struct_kw(db);
         return PluginResult {
             code: None,
             diagnostics: vec![PluginDiagnostic {
                 message: "'Storage' struct must be annotated with #[starknet::storage]."
                     .to_string(),
                 stable_ptr: module_ast.stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN005")),
+                severity: Severity::Error,
+                fixes: vec![PluginFix {
+                    stable_ptr: struct_kw.stable_ptr().untyped(),
+                    replacement: with_attribute_before(db, &struct_kw, "starknet::storage"),
+                }],
             }],
             remove_original_item: false,
         };
@@ -77,12 +117,21 @@ struct ContractGenerationData {
     abi_functions: Vec<RewriteNode>,
     event_functions: Vec<RewriteNode>,
     abi_events: Vec<RewriteNode>,
+    abi_l1_messages: Vec<RewriteNode>,
+    errors_code: Vec<RewriteNode>,
+    abi_errors: Vec<RewriteNode>,
+    has_default_entry_point: bool,
+    has_constructor: bool,
+    /// Whether the contract has an owner, which is set by its constructor.
+    has_access_control: bool,
+    is_pausable: bool,
 }
 
 /// If the module is annotated with CONTRACT_ATTR, generate the relevant contract logic.
 pub fn handle_contract_by_storage(
     db: &dyn SyntaxGroup,
     struct_ast: ast::ItemStruct,
+    config: &StarkNetPlugin,
 ) -> Option<PluginResult> {
     let module_node = struct_ast.as_syntax_node().parent()?.parent()?.parent()?;
     if module_node.kind(db) != SyntaxKind::ItemModule {
@@ -102,26 +151,52 @@ pub fn handle_contract_by_storage(
                 diagnostics: vec![PluginDiagnostic {
                     message: "Contracts without body are not supported.".to_string(),
                     stable_ptr: empty_body.stable_ptr().untyped(),
+                    code: Some(ErrorCode::new("SN003")),
+                    severity: Severity::Error,
+                    fixes: vec![],
                 }],
                 remove_original_item: false,
             });
         }
     };
+    // Items disabled by `#[cfg(...)]` are removed from the contract module, so no code should be
+    // generated for them.
+    let items = body
+        .items(db)
+        .elements(db)
+        .into_iter()
+        .filter(|item| is_enabled_by_cfg(db, item))
+        .collect::<Vec<_>>();
     let mut diagnostics = vec![];
     let mut kept_original_items = Vec::new();
 
     // A mapping from a 'use' item to its path.
     let mut extra_uses = OrderedHashMap::default();
     let mut has_event = false;
-    for item in body.items(db).elements(db) {
+    // The names of the items defined in the contract module.
+    let mut contract_item_names = UnorderedHashSet::default();
+    for item in items.iter().cloned() {
+        if let Some(name) = item_name(db, &item) {
+            let name_text = name.text(db);
+            if RESERVED_CONTRACT_ITEM_NAMES.contains(&name_text.as_str()) {
+                diagnostics.push(PluginDiagnostic {
+                    message: format!("`{name_text}` is a reserved name in contracts."),
+                    stable_ptr: name.stable_ptr().untyped(),
+                    code: Some(ErrorCode::new("SN006")),
+                    severity: Severity::Error,
+                    fixes: vec![],
+                });
+            }
+            contract_item_names.insert(name_text);
+        }
         // Skipping elements that only generate other code, but their code itself is ignored.
         if matches!(&item, ast::Item::FreeFunction(item) if item.has_attr(db, EVENT_ATTR))
             || matches!(&item, ast::Item::Struct(item) if item.name(db).text(db) == STORAGE_STRUCT_NAME)
         {
             continue;
         }
-        if matches!(&item, ast::Item::Struct(item) if item.name(db).text(db) == "Event")
-            || matches!(&item, ast::Item::Enum(item) if item.name(db).text(db) == "Event")
+        if matches!(&item, ast::Item::Struct(item) if item.name(db).text(db) == EVENT_TYPE_NAME)
+            || matches!(&item, ast::Item::Enum(item) if item.name(db).text(db) == EVENT_TYPE_NAME)
         {
             has_event = true;
         }
@@ -179,13 +254,49 @@ pub fn handle_contract_by_storage(
             .collect(),
     );
 
-    let mut data = ContractGenerationData::default();
+    let mut data = ContractGenerationData {
+        has_access_control: contract_has_access_control(db, &items),
+        ..Default::default()
+    };
+
+    let module_name_ast = module_ast.name(db);
+    let contract_name = module_name_ast.text(db);
+    // The namespace is recorded in the ABI as a short string.
+    if config.namespaced_event_selectors && contract_name.len() > 31 {
+        diagnostics.push(PluginDiagnostic {
+            message: "Contract names must be at most 31 characters long to namespace event \
+                      selectors."
+                .to_string(),
+            stable_ptr: module_name_ast.stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN007")),
+            severity: Severity::Error,
+            fixes: vec![],
+        });
+    }
+    let event_namespace = config.namespaced_event_selectors.then_some(contract_name.as_str());
+    let artifact_name = contract_artifact_name_arg(db, &module_ast, &mut diagnostics);
 
     let mut storage_code = RewriteNode::Text("".to_string());
-    for item in body.items(db).elements(db) {
-        match &item {
+    let mut event_names = UnorderedHashSet::default();
+    for item in &items {
+        match item {
             ast::Item::FreeFunction(item_function) if item_function.has_attr(db, EVENT_ATTR) => {
-                let (rewrite_nodes, event_diagnostics) = handle_event(db, item_function.clone());
+                let event_name = item_function.declaration(db).name(db);
+                if !event_names.insert(event_name.text(db)) {
+                    diagnostics.push(PluginDiagnostic {
+                        message: format!(
+                            "The event `{}` is defined multiple times.",
+                            event_name.text(db)
+                        ),
+                        stable_ptr: event_name.stable_ptr().untyped(),
+                        code: Some(ErrorCode::new("SN008")),
+                        severity: Severity::Error,
+                        fixes: vec![],
+                    });
+                    continue;
+                }
+                let (rewrite_nodes, event_diagnostics) =
+                    handle_event(db, item_function.clone(), event_namespace, config.selector_hash);
                 if let Some((event_function_rewrite, abi_event_rewrite)) = rewrite_nodes {
                     data.event_functions.push(event_function_rewrite);
                     data.abi_events.push(abi_event_rewrite);
@@ -204,6 +315,7 @@ pub fn handle_contract_by_storage(
                     item_function,
                     function_name,
                     db,
+                    config,
                     &mut diagnostics,
                     &mut data,
                 );
@@ -216,6 +328,9 @@ pub fn handle_contract_by_storage(
                 let impl_name = RewriteNode::new_trimmed(item_impl.name(db).as_syntax_node());
                 for item in body.items(db).elements(db) {
                     let ast::ImplItem::Function(item_function) = item else { continue; };
+                    if !is_enabled_by_cfg(db, &item_function) {
+                        continue;
+                    }
                     let function_name = RewriteNode::new_trimmed(
                         item_function.declaration(db).name(db).as_syntax_node(),
                     );
@@ -232,6 +347,7 @@ pub fn handle_contract_by_storage(
                         &item_function,
                         function_name,
                         db,
+                        config,
                         &mut diagnostics,
                         &mut data,
                     );
@@ -240,33 +356,104 @@ pub fn handle_contract_by_storage(
             ast::Item::Struct(item_struct)
                 if item_struct.name(db).text(db) == STORAGE_STRUCT_NAME =>
             {
-                let (storage_rewrite_node, storage_diagnostics) =
-                    handle_storage_struct(db, item_struct.clone(), &extra_uses_node, has_event);
+                let (storage_rewrite_node, storage_diagnostics) = handle_storage_struct(
+                    db,
+                    item_struct.clone(),
+                    &extra_uses_node,
+                    has_event,
+                    &contract_item_names,
+                    config.selector_hash,
+                );
                 storage_code = storage_rewrite_node;
                 diagnostics.extend(storage_diagnostics);
             }
+            ast::Item::Enum(item_enum) if item_enum.name(db).text(db) == EVENT_TYPE_NAME => {
+                data.abi_events.push(abi_contract_events());
+            }
+            ast::Item::Enum(item_enum) if item_enum.name(db).text(db) == ERROR_ENUM_NAME => {
+                let (rewrite_nodes, error_diagnostics) =
+                    handle_error_enum(db, item_enum, config.selector_hash);
+                if let Some((errors_code, abi_errors)) = rewrite_nodes {
+                    data.errors_code.push(errors_code);
+                    data.abi_errors.push(abi_errors);
+                }
+                diagnostics.extend(error_diagnostics);
+            }
+            ast::Item::Struct(item_struct) if item_struct.has_attr(db, L1_MESSAGE_ATTR) => {
+                data.abi_l1_messages.push(handle_l1_message_abi(db, item_struct));
+            }
             _ => {}
         }
     }
 
-    let module_name_ast = module_ast.name(db);
-    let test_class_hash = starknet_keccak(
-        module_ast.as_syntax_node().get_text_without_trivia(db).as_str().as_bytes(),
-    );
+    if config.lint_shadowed_storage_variables {
+        diagnostics.extend(shadowed_storage_variables_lint(db, &items));
+    }
+
+    let pausable_code = if data.is_pausable {
+        for name in [PAUSE_ENTRY_POINT_NAME, UNPAUSE_ENTRY_POINT_NAME] {
+            if contract_item_names.contains(name) {
+                diagnostics.push(PluginDiagnostic {
+                    message: format!(
+                        "`{name}` is generated in pausable contracts, and cannot be defined."
+                    ),
+                    stable_ptr: module_name_ast.stable_ptr().untyped(),
+                    code: Some(ErrorCode::new("SN009")),
+                    severity: Severity::Error,
+                    fixes: vec![],
+                });
+            }
+        }
+        for wrapper in pausable_entry_point_wrappers() {
+            data.generated_external_functions.push(wrapper);
+            data.generated_external_functions.push(RewriteNode::Text("\n        ".to_string()));
+        }
+        let (abi_functions, abi_events) = pausable_abi(event_namespace);
+        data.abi_functions.push(abi_functions);
+        data.abi_events.push(abi_events);
+        pausable_module(event_namespace, config.selector_hash)
+    } else {
+        RewriteNode::Text("".to_string())
+    };
+    let access_control_code = if data.has_access_control {
+        // The owner is set by the constructor, which is generated if the contract has none.
+        if !data.has_constructor {
+            data.generated_constructor_functions.push(owner_constructor_wrapper());
+            data.abi_functions.push(owner_constructor_abi());
+        }
+        access_control_module(config.selector_hash)
+    } else {
+        RewriteNode::Text("".to_string())
+    };
+    let test_class_hash = match &config.test_class_hash {
+        TestClassHash::ModuleText => Some(starknet_keccak(
+            module_ast.as_syntax_node().get_text_without_trivia(db).as_str().as_bytes(),
+        )),
+        TestClassHash::Canonical => {
+            Some(starknet_keccak(canonical_text(db, &module_ast.as_syntax_node()).as_bytes()))
+        }
+        TestClassHash::Pinned(value) => Some(value.clone()),
+        TestClassHash::SierraClassHash(class_hashes) => class_hashes.get(&contract_name).cloned(),
+        TestClassHash::Omitted => None,
+    };
+    let test_class_hash_const = match test_class_hash {
+        Some(test_class_hash) => format!("const TEST_CLASS_HASH: felt252 = {test_class_hash};"),
+        None => "".to_string(),
+    };
     let generated_contract_mod = RewriteNode::interpolate_patched(
         formatdoc!(
             "
             use starknet::SyscallResultTrait;
             use starknet::SyscallResultTraitImpl;
 
-            const TEST_CLASS_HASH: felt252 = {test_class_hash};
-            $storage_code$
+            {test_class_hash_const}
+            $storage_code$$access_control_code$$pausable_code$$errors_code$
 
             $event_functions$
 
             trait {ABI_TRAIT}<Storage> {{
                 $abi_functions$
-                $abi_events$
+                $abi_events$$abi_l1_messages$$abi_errors$
             }}
 
             mod {EXTERNAL_MODULE} {{$extra_uses$
@@ -294,8 +481,13 @@ pub fn handle_contract_by_storage(
             ("original_items".to_string(), RewriteNode::new_modified(kept_original_items)),
             ("storage_code".to_string(), storage_code),
             ("event_functions".to_string(), RewriteNode::new_modified(data.event_functions)),
+            ("access_control_code".to_string(), access_control_code),
+            ("pausable_code".to_string(), pausable_code),
             ("abi_functions".to_string(), RewriteNode::new_modified(data.abi_functions)),
             ("abi_events".to_string(), RewriteNode::new_modified(data.abi_events)),
+            ("abi_l1_messages".to_string(), RewriteNode::new_modified(data.abi_l1_messages)),
+            ("errors_code".to_string(), RewriteNode::new_modified(data.errors_code)),
+            ("abi_errors".to_string(), RewriteNode::new_modified(data.abi_errors)),
             ("extra_uses".to_string(), extra_uses_node),
             (
                 "generated_external_functions".to_string(),
@@ -323,6 +515,8 @@ pub fn handle_contract_by_storage(
                 StarkNetContractAuxData {
                     patches: builder.patches,
                     contracts: vec![module_name_ast.text(db)],
+                    artifact_name,
+                    selector_hash: config.selector_hash,
                 },
             )),
         }),
@@ -331,16 +525,86 @@ pub fn handle_contract_by_storage(
     })
 }
 
+/// Returns the name of the given module item, if it has one.
+fn item_name(db: &dyn SyntaxGroup, item: &ast::Item) -> Option<ast::TerminalIdentifier> {
+    match item {
+        ast::Item::Constant(item) => Some(item.name(db)),
+        ast::Item::Module(item) => Some(item.name(db)),
+        ast::Item::FreeFunction(item) => Some(item.declaration(db).name(db)),
+        ast::Item::ExternFunction(item) => Some(item.declaration(db).name(db)),
+        ast::Item::ExternType(item) => Some(item.name(db)),
+        ast::Item::Trait(item) => Some(item.name(db)),
+        ast::Item::Impl(item) => Some(item.name(db)),
+        ast::Item::ImplAlias(item) => Some(item.name(db)),
+        ast::Item::Struct(item) => Some(item.name(db)),
+        ast::Item::Enum(item) => Some(item.name(db)),
+        ast::Item::TypeAlias(item) => Some(item.name(db)),
+        ast::Item::Use(_) | ast::Item::Missing(_) => None,
+    }
+}
+
+/// Returns the name of the artifacts of the contract, given by the `name` argument of its
+/// `#[contract]` attribute, if it has one. As it is used as a file name, it may only consist of
+/// ASCII letters, digits, `_` and `-`.
+fn contract_artifact_name_arg(
+    db: &dyn SyntaxGroup,
+    module_ast: &ast::ItemModule,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> Option<SmolStr> {
+    let attr = module_ast.find_attr(db, CONTRACT_ATTR)?.structurize(db);
+    let mut artifact_name = None;
+    for arg in attr.args {
+        if let AttributeArgVariant::Named { name, value: ast::Expr::ShortString(value), .. } =
+            &arg.variant
+        {
+            let value = value.string_value(db).filter(|value| {
+                !value.is_empty()
+                    && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            });
+            if let (CONTRACT_NAME_ARG, Some(value)) = (name.as_str(), value) {
+                artifact_name = Some(value.into());
+                continue;
+            }
+        }
+        diagnostics.push(PluginDiagnostic {
+            message: format!(
+                "Expected `{CONTRACT_NAME_ARG}: '<name>'`, where the name of the artifacts of the \
+                 contract consists of ASCII letters, digits, `_` and `-`."
+            ),
+            stable_ptr: arg.arg_stable_ptr.untyped(),
+            code: Some(ErrorCode::new("SN046")),
+            severity: Severity::Error,
+            fixes: vec![],
+        });
+    }
+    artifact_name
+}
+
+/// Returns the tokens of the node separated by single spaces, ignoring whitespace and comments.
+fn canonical_text(db: &dyn SyntaxGroup, node: &SyntaxNode) -> String {
+    if node.kind(db).is_terminal() {
+        return node.clone().get_text_without_trivia(db);
+    }
+    node.children(db)
+        .map(|child| canonical_text(db, &child))
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Handles a contract entrypoint function.
 fn handle_entry_point(
     entry_point_kind: EntryPointKind,
     item_function: &ast::FunctionWithBody,
     function_name: RewriteNode,
     db: &dyn SyntaxGroup,
+    config: &StarkNetPlugin,
     diagnostics: &mut Vec<PluginDiagnostic>,
     data: &mut ContractGenerationData,
 ) {
     let attr = entry_point_kind.get_attr();
+    data.has_constructor |= entry_point_kind == EntryPointKind::Constructor;
+    data.is_pausable |= item_function.has_attr(db, WHEN_NOT_PAUSED_ATTR);
 
     let declaration = item_function.declaration(db);
     if let OptionWrappedGenericParamList::WrappedGenericParamList(generic_params) =
@@ -349,6 +613,9 @@ fn handle_entry_point(
         diagnostics.push(PluginDiagnostic {
             message: "Contract entry points cannot have generic arguments".to_string(),
             stable_ptr: generic_params.stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN011")),
+            severity: Severity::Error,
+            fixes: vec![],
         })
     }
 
@@ -368,13 +635,33 @@ fn handle_entry_point(
                 .set_str("".to_string());
         }
     }
-    data.abi_functions.push(RewriteNode::new_modified(vec![
+    let mut abi_function = vec![];
+    // Deprecated entry points are marked as such in the ABI.
+    if let Some(deprecated_attr) = item_function.find_attr(db, DEPRECATED_ATTR) {
+        abi_function.push(RewriteNode::new_trimmed(deprecated_attr.as_syntax_node()));
+        abi_function.push(RewriteNode::Text("\n        ".to_string()));
+    }
+    abi_function.extend([
         RewriteNode::Text(format!("#[{attr}]\n        ")),
         declaration_node,
         RewriteNode::Text(";\n        ".to_string()),
-    ]));
+    ]);
+    data.abi_functions.push(RewriteNode::new_modified(abi_function));
 
-    match generate_entry_point_wrapper(db, item_function, function_name) {
+    let wrapper = match entry_point_kind {
+        EntryPointKind::Default => {
+            generate_default_entry_point_wrapper(db, item_function, function_name)
+        }
+        _ => generate_entry_point_wrapper(
+            db,
+            entry_point_kind,
+            item_function,
+            function_name,
+            config.selector_hash,
+            data.has_access_control,
+        ),
+    };
+    match wrapper {
         Ok(generated_function) => {
             let generated = match entry_point_kind {
                 EntryPointKind::Constructor => &mut data.generated_constructor_functions,
@@ -382,7 +669,27 @@ fn handle_entry_point(
                     validate_l1_handler_first_parameter(db, &params, diagnostics);
                     &mut data.generated_l1_handler_functions
                 }
-                EntryPointKind::External => &mut data.generated_external_functions,
+                EntryPointKind::External => {
+                    if config.lint_storage_mutability {
+                        diagnostics.extend(storage_mutability_lint(db, item_function));
+                    }
+                    &mut data.generated_external_functions
+                }
+                EntryPointKind::Default => {
+                    if data.has_default_entry_point {
+                        diagnostics.push(PluginDiagnostic {
+                            message: "A contract can have at most one default entry point."
+                                .to_string(),
+                            stable_ptr: declaration.name(db).stable_ptr().untyped(),
+                            code: Some(ErrorCode::new("SN012")),
+                            severity: Severity::Error,
+                            fixes: vec![],
+                        });
+                        return;
+                    }
+                    data.has_default_entry_point = true;
+                    &mut data.generated_external_functions
+                }
             };
             generated.push(generated_function);
             generated.push(RewriteNode::Text("\n        ".to_string()));
@@ -395,6 +702,8 @@ fn handle_entry_point(
 
 /// Validates the first parameter of an L1 handler is `from_address: felt252` or `_from_address:
 /// felt252`.
+/// If the handler takes a typed L1 message payload (a parameter named `payload` or `_payload`),
+/// also validates that it directly follows `from_address` and is the last parameter.
 fn validate_l1_handler_first_parameter(
     db: &dyn SyntaxGroup,
     params: &ast::ParamList,
@@ -407,6 +716,9 @@ fn validate_l1_handler_first_parameter(
                 message: "The second parameter of an L1 handler must be of type `felt252`."
                     .to_string(),
                 stable_ptr: first_param.stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN013")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
         }
 
@@ -418,6 +730,9 @@ fn validate_l1_handler_first_parameter(
                 message: "The second parameter of an L1 handler must be named 'from_address'."
                     .to_string(),
                 stable_ptr: first_param.stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN014")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
         }
     } else {
@@ -425,6 +740,36 @@ fn validate_l1_handler_first_parameter(
             message: "An L1 handler must have the 'from_address' as its second parameter."
                 .to_string(),
             stable_ptr: params.stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN015")),
+            severity: Severity::Error,
+            fixes: vec![],
         });
     };
+
+    let params = params.elements(db);
+    for (param_idx, param) in params.iter().enumerate().skip(2) {
+        if !is_l1_handler_payload_param(db, param) {
+            continue;
+        }
+        if param_idx != 2 || params.len() != 3 {
+            diagnostics.push(PluginDiagnostic {
+                message: "The L1 message payload must directly follow 'from_address' and be the \
+                          last parameter of an L1 handler."
+                    .to_string(),
+                stable_ptr: param.stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN016")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        }
+        if is_ref_param(db, param) {
+            diagnostics.push(PluginDiagnostic {
+                message: "The L1 message payload cannot be a `ref` parameter.".to_string(),
+                stable_ptr: param.modifiers(db).stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN017")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use cairo_lang_defs::plugin::{
-    DynGeneratedFileAuxData, PluginDiagnostic, PluginGeneratedFile, PluginResult,
+    DynGeneratedFileAuxData, PluginDiagnostic, PluginFix, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_semantic::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_semantic::plugin::DynPluginAuxData;
 use cairo_lang_syntax::node::ast::{self, MaybeTraitBody, OptionReturnTypeClause};
@@ -10,13 +11,17 @@ use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
 use indoc::formatdoc;
 
 use super::aux_data::StarkNetABIAuxData;
-use super::consts::{CALLDATA_PARAM_NAME, EVENT_ATTR};
+use super::consts::{CALLDATA_PARAM_NAME, DEPRECATED_ATTR, EVENT_ATTR};
 use super::utils::is_ref_param;
 use super::ABI_ATTR;
-use crate::contract::starknet_keccak;
+use crate::contract::SelectorHash;
 
 /// If the trait is annotated with ABI_ATTR, generate the relevant dispatcher logic.
-pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginResult {
+pub fn handle_trait(
+    db: &dyn SyntaxGroup,
+    trait_ast: ast::ItemTrait,
+    selector_hash: SelectorHash,
+) -> PluginResult {
     if !trait_ast.has_attr(db, ABI_ATTR) {
         return PluginResult::default();
     }
@@ -28,6 +33,9 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                 diagnostics: vec![PluginDiagnostic {
                     message: "ABIs without body are not supported.".to_string(),
                     stable_ptr: empty_body.stable_ptr().untyped(),
+                    code: Some(ErrorCode::new("SN018")),
+                    severity: Severity::Error,
+                    fixes: vec![],
                 }],
                 remove_original_item: false,
             };
@@ -61,6 +69,9 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     diagnostics.push(PluginDiagnostic {
                         message: "ABI functions must have a `self` parameter.".to_string(),
                         stable_ptr: declaration.stable_ptr().untyped(),
+                        code: Some(ErrorCode::new("SN019")),
+                        severity: Severity::Error,
+                        fixes: vec![],
                     });
                     continue;
                 };
@@ -68,6 +79,12 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     diagnostics.push(PluginDiagnostic {
                         message: "The `self` parameter must be named `self`.".to_string(),
                         stable_ptr: self_param.stable_ptr().untyped(),
+                        code: Some(ErrorCode::new("SN020")),
+                        severity: Severity::Error,
+                        fixes: vec![PluginFix {
+                            stable_ptr: self_param.name(db).stable_ptr().untyped(),
+                            replacement: "self".to_string(),
+                        }],
                     });
                     skip_generation = true;
                 }
@@ -80,6 +97,9 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                             message: "`ref` parameters are not supported in the ABI of a contract."
                                 .to_string(),
                             stable_ptr: param.modifiers(db).stable_ptr().untyped(),
+                            code: Some(ErrorCode::new("SN021")),
+                            severity: Severity::Error,
+                            fixes: vec![],
                         })
                     }
 
@@ -89,6 +109,9 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                         diagnostics.push(PluginDiagnostic {
                             message: "Parameter name `__calldata__` cannot be used.".to_string(),
                             stable_ptr: param.name(db).stable_ptr().untyped(),
+                            code: Some(ErrorCode::new("SN022")),
+                            severity: Severity::Error,
+                            fixes: vec![],
                         })
                     }
 
@@ -127,15 +150,28 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                         )
                     }
                 };
+                // Keep the deprecation of the function on the dispatcher methods.
+                let deprecated_attr = match func.find_attr(db, DEPRECATED_ATTR) {
+                    Some(attr) => RewriteNode::new_modified(vec![
+                        RewriteNode::new_trimmed(attr.as_syntax_node()),
+                        RewriteNode::Text("\n".to_string()),
+                    ]),
+                    None => RewriteNode::Text("".to_string()),
+                };
                 dispatcher_signatures.push(RewriteNode::interpolate_patched(
-                    "$func_decl$;",
-                    [("func_decl".to_string(), dispatcher_signature(db, &declaration, "T"))].into(),
+                    "$deprecated_attr$$func_decl$;",
+                    [
+                        ("deprecated_attr".to_string(), deprecated_attr.clone()),
+                        ("func_decl".to_string(), dispatcher_signature(db, &declaration, "T")),
+                    ]
+                    .into(),
                 ));
                 let entry_point_selector = RewriteNode::Text(format!(
                     "0x{:x}",
-                    starknet_keccak(declaration.name(db).text(db).as_bytes())
+                    selector_hash.hash(declaration.name(db).text(db).as_bytes())
                 ));
                 contract_caller_method_impls.push(declaration_method_impl(
+                    deprecated_attr.clone(),
                     dispatcher_signature(db, &declaration, &contract_caller_name),
                     entry_point_selector.clone(),
                     "contract_address",
@@ -144,6 +180,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     ret_decode.clone(),
                 ));
                 library_caller_method_impls.push(declaration_method_impl(
+                    deprecated_attr,
                     dispatcher_signature(db, &declaration, &library_caller_name),
                     entry_point_selector,
                     "class_hash",
@@ -212,6 +249,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
 
 /// Returns the method implementation rewrite node for a declaration.
 fn declaration_method_impl(
+    deprecated_attr: RewriteNode,
     func_declaration: RewriteNode,
     entry_point_selector: RewriteNode,
     member: &str,
@@ -221,7 +259,7 @@ fn declaration_method_impl(
 ) -> RewriteNode {
     RewriteNode::interpolate_patched(
         &formatdoc!(
-            "$func_decl$ {{
+            "$deprecated_attr$$func_decl$ {{
                 let mut {CALLDATA_PARAM_NAME} = traits::Default::default();
         $serialization_code$
                 let mut ret_data = starknet::SyscallResultTrait::unwrap_syscall(
@@ -236,6 +274,7 @@ fn declaration_method_impl(
         "
         ),
         [
+            ("deprecated_attr".to_string(), deprecated_attr),
             ("func_decl".to_string(), func_declaration),
             ("entry_point_selector".to_string(), entry_point_selector),
             ("syscall".to_string(), RewriteNode::Text(syscall.to_string())),
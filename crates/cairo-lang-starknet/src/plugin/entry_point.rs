@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_semantic::patcher::RewriteNode;
 use cairo_lang_syntax::node::ast::{self, FunctionWithBody, OptionReturnTypeClause};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -6,10 +7,15 @@ use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
 use itertools::Itertools;
 
+use super::access_control::{access_control_checks, has_access_control, owner_initialization};
 use super::consts::{
-    CONSTRUCTOR_ATTR, EXTERNAL_ATTR, IMPLICIT_PRECEDENCE, L1_HANDLER_ATTR, RAW_OUTPUT_ATTR,
+    CONSTRUCTOR_ATTR, DEFAULT_ATTR, DEFAULT_ENTRY_POINT_NAME, EXTERNAL_ATTR, IMPLICIT_PRECEDENCE,
+    L1_HANDLER_ATTR, L1_HANDLER_PAYLOAD_PARAM_NAME, NON_REENTRANT_ATTR, ONLY_OWNER_ATTR,
+    RAW_OUTPUT_ATTR, REENTRANCY_GUARD_VAR_NAME, REQUIRE_ATTR, WHEN_NOT_PAUSED_ATTR,
 };
-use super::utils::{is_felt252_span, is_ref_param};
+use super::pausable::when_not_paused_check;
+use super::utils::{is_felt252, is_felt252_span, is_ref_param, maybe_strip_underscore};
+use crate::contract::SelectorHash;
 
 /// Kind of an entry point. Determined by the entry point's attributes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,6 +23,7 @@ pub enum EntryPointKind {
     External,
     Constructor,
     L1Handler,
+    Default,
 }
 impl EntryPointKind {
     /// Returns the entry point kind if the given function is indeed an entry point.
@@ -30,6 +37,8 @@ impl EntryPointKind {
             Some(EntryPointKind::Constructor)
         } else if item_function.has_attr(db, L1_HANDLER_ATTR) {
             Some(EntryPointKind::L1Handler)
+        } else if item_function.has_attr(db, DEFAULT_ATTR) {
+            Some(EntryPointKind::Default)
         } else {
             None
         }
@@ -41,15 +50,26 @@ impl EntryPointKind {
             EntryPointKind::External => EXTERNAL_ATTR,
             EntryPointKind::Constructor => CONSTRUCTOR_ATTR,
             EntryPointKind::L1Handler => L1_HANDLER_ATTR,
+            EntryPointKind::Default => DEFAULT_ATTR,
         }
     }
 }
 
+/// Returns true if the parameter is the typed L1 message payload of an L1 handler.
+pub fn is_l1_handler_payload_param(db: &dyn SyntaxGroup, param: &ast::Param) -> bool {
+    maybe_strip_underscore(param.name(db).text(db).as_str()) == L1_HANDLER_PAYLOAD_PARAM_NAME
+}
+
 /// Generates Cairo code for an entry point wrapper.
+/// The constructor of a contract with access controlled entry points sets the owner of the
+/// contract.
 pub fn generate_entry_point_wrapper(
     db: &dyn SyntaxGroup,
+    entry_point_kind: EntryPointKind,
     function: &FunctionWithBody,
     wrapped_function_name: RewriteNode,
+    selector_hash: SelectorHash,
+    contract_has_access_control: bool,
 ) -> Result<RewriteNode, Vec<PluginDiagnostic>> {
     let declaration = function.declaration(db);
     let sig = declaration.signature(db);
@@ -65,18 +85,85 @@ pub fn generate_entry_point_wrapper(
         return Err(vec![PluginDiagnostic{
             message: format!("`{RAW_OUTPUT_ATTR}` functions must get a 'self' param."),
             stable_ptr: sig.stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN023")),
+            severity: Severity::Error,
+            fixes: vec![],
         }]);
     };
     if first_param.name(db).text(db) != "self" {
         return Err(vec![PluginDiagnostic {
             message: format!("`{RAW_OUTPUT_ATTR}` functions must get a 'self' param."),
             stable_ptr: sig.stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN023")),
+            severity: Severity::Error,
+            fixes: vec![],
         }]);
     };
     let is_snapshot = matches!(first_param.type_clause(db).ty(db), ast::Expr::Unary(_));
     // TODO(spapini): Check modifiers and type.
 
+    let access_control_checks = access_control_checks(db, function, &mut diagnostics);
+    if has_access_control(db, function) && entry_point_kind != EntryPointKind::External {
+        diagnostics.push(PluginDiagnostic {
+            message: format!(
+                "`{ONLY_OWNER_ATTR}` and `{REQUIRE_ATTR}` are only supported on external \
+                 functions."
+            ),
+            stable_ptr: declaration.name(db).stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN024")),
+            severity: Severity::Error,
+            fixes: vec![],
+        });
+    }
+    let owner_initialization =
+        if entry_point_kind == EntryPointKind::Constructor && contract_has_access_control {
+            owner_initialization()
+        } else {
+            "".into()
+        };
+
+    let when_not_paused = function.has_attr(db, WHEN_NOT_PAUSED_ATTR);
+    if when_not_paused
+        && !matches!(entry_point_kind, EntryPointKind::External | EntryPointKind::L1Handler)
+    {
+        diagnostics.push(PluginDiagnostic {
+            message: format!(
+                "`{WHEN_NOT_PAUSED_ATTR}` is only supported on external functions and L1 handlers."
+            ),
+            stable_ptr: declaration.name(db).stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN025")),
+            severity: Severity::Error,
+            fixes: vec![],
+        });
+    }
+    let when_not_paused_check = if when_not_paused { when_not_paused_check() } else { "".into() };
+
+    let non_reentrant = function.has_attr(db, NON_REENTRANT_ATTR);
+    if non_reentrant {
+        if !matches!(entry_point_kind, EntryPointKind::External | EntryPointKind::L1Handler) {
+            diagnostics.push(PluginDiagnostic {
+                message: format!(
+                    "`{NON_REENTRANT_ATTR}` is only supported on external functions and L1 \
+                     handlers."
+                ),
+                stable_ptr: declaration.name(db).stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN026")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        } else if is_snapshot {
+            diagnostics.push(PluginDiagnostic {
+                message: format!("`{NON_REENTRANT_ATTR}` functions must get a `ref self` param."),
+                stable_ptr: first_param.stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN027")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        }
+    }
+
     let input_data_short_err = "'Input too short for arguments'";
+    let l1_payload_err = "'Invalid L1 message payload'";
     for param in params {
         let arg_name = format!("__arg_{}", param.name(db).text(db));
         let arg_type_ast = param.type_clause(db).ty(db);
@@ -87,17 +174,28 @@ pub fn generate_entry_point_wrapper(
             diagnostics.push(PluginDiagnostic {
                 message: format!("`{RAW_OUTPUT_ATTR}` functions cannot have `ref` parameters."),
                 stable_ptr: param.modifiers(db).stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN028")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
         }
 
         let ref_modifier = if is_ref { "ref " } else { "" };
         arg_names.push(format!("{ref_modifier}{arg_name}"));
         let mut_modifier = if is_ref { "mut " } else { "" };
+        // The typed payload of an L1 handler consumes the rest of the L1 message.
+        let deserialization_err = if entry_point_kind == EntryPointKind::L1Handler
+            && is_l1_handler_payload_param(db, &param)
+        {
+            l1_payload_err
+        } else {
+            input_data_short_err
+        };
         // TODO(yuval): use panicable version of deserializations when supported.
         let arg_definition = format!(
             "
             let {mut_modifier}{arg_name} =
-                serde::Serde::<{type_name}>::deserialize(ref data).expect({input_data_short_err});"
+                serde::Serde::<{type_name}>::deserialize(ref data).expect({deserialization_err});"
         );
         arg_definitions.push(arg_definition);
 
@@ -138,6 +236,9 @@ pub fn generate_entry_point_wrapper(
         diagnostics.push(PluginDiagnostic {
             message: format!("`{RAW_OUTPUT_ATTR}` functions must return `Span::<felt252>`."),
             stable_ptr: ret_type_ptr,
+            code: Some(ErrorCode::new("SN029")),
+            severity: Severity::Error,
+            fixes: vec![],
         });
     }
 
@@ -145,12 +246,21 @@ pub fn generate_entry_point_wrapper(
         return Err(diagnostics);
     }
 
+    // The wrapper has a single return path, right after the call to the wrapped function, so the
+    // guard is cleared there.
+    let (guard_enter, guard_exit) =
+        if non_reentrant { reentrancy_guard_code(selector_hash) } else { Default::default() };
     let storage_arg = if is_snapshot { "@storage" } else { "ref storage" };
-    let output_handling_string = if raw_output {
+    let output_handling_string = if raw_output && non_reentrant {
+        format!(
+            "let res = $wrapped_name$({storage_arg}, {arg_names_str});{guard_exit}
+            res"
+        )
+    } else if raw_output {
         format!("$wrapped_name$({storage_arg}, {arg_names_str})")
     } else {
         format!(
-            "{let_res}$wrapped_name$({storage_arg}, {arg_names_str});
+            "{let_res}$wrapped_name$({storage_arg}, {arg_names_str});{guard_exit}
             let mut arr = array::array_new();
             // References.$ref_appends$
             // Result.{append_res}
@@ -167,13 +277,23 @@ pub fn generate_entry_point_wrapper(
         .into(),
     );
 
-    let implicit_precedence = RewriteNode::Text(format!("#[implicit_precedence({})]", {
-        IMPLICIT_PRECEDENCE.iter().join(", ")
-    }));
-
     let arg_definitions = RewriteNode::Text(arg_definitions.join("\n"));
+    let checks =
+        [access_control_checks, owner_initialization, when_not_paused_check, guard_enter].concat();
 
-    Ok(RewriteNode::interpolate_patched(
+    Ok(entry_point_wrapper_code(function_name, arg_definitions, checks, output_handling))
+}
+
+/// Generates the Cairo code of the wrapper of an entry point, given the code deserializing its
+/// arguments from `data`, the checks run before the wrapped function is called, and the code
+/// calling it and serializing its output.
+pub(super) fn entry_point_wrapper_code(
+    function_name: RewriteNode,
+    arg_definitions: RewriteNode,
+    checks: String,
+    output_handling: RewriteNode,
+) -> RewriteNode {
+    RewriteNode::interpolate_patched(
         "$implicit_precedence$
         fn $function_name$(mut data: Span::<felt252>) -> Span::<felt252> {
             internal::revoke_ap_tracking();
@@ -187,16 +307,120 @@ pub fn generate_entry_point_wrapper(
                 array::array_append(ref err_data, 'Input too long for arguments');
                 panic(err_data);
             }
-            gas::withdraw_gas_all(get_builtin_costs()).expect('Out of gas');
+            gas::withdraw_gas_all(get_builtin_costs()).expect('Out of gas');$checks$
             let mut storage = super::unsafe_new_storage();
             $output_handling$
         }",
         [
             ("function_name".to_string(), function_name),
+            ("checks".to_string(), RewriteNode::Text(checks)),
             ("output_handling".to_string(), output_handling),
             ("arg_definitions".to_string(), arg_definitions),
-            ("implicit_precedence".to_string(), implicit_precedence),
+            ("implicit_precedence".to_string(), implicit_precedence_attr()),
+        ]
+        .into(),
+    )
+}
+
+/// Generates Cairo code for the wrapper of a default (fallback) entry point.
+///
+/// The wrapped function must have the signature
+/// `fn <name>(self: <Storage>, selector: felt252, calldata: Span<felt252>) -> Span<felt252>`.
+/// The generated wrapper expects the original selector as the first element of its input, and
+/// forwards the rest of the input as the raw calldata.
+pub fn generate_default_entry_point_wrapper(
+    db: &dyn SyntaxGroup,
+    function: &FunctionWithBody,
+    wrapped_function_name: RewriteNode,
+) -> Result<RewriteNode, Vec<PluginDiagnostic>> {
+    let declaration = function.declaration(db);
+    let sig = declaration.signature(db);
+    let params = sig.parameters(db).elements(db);
+    let signature_err = PluginDiagnostic {
+        message: format!(
+            "`{DEFAULT_ATTR}` functions must have the signature `(self, selector: felt252, \
+             calldata: Span<felt252>) -> Span<felt252>`."
+        ),
+        stable_ptr: sig.stable_ptr().untyped(),
+        code: Some(ErrorCode::new("SN030")),
+        severity: Severity::Error,
+        fixes: vec![],
+    };
+    let [self_param, selector_param, calldata_param] = params.as_slice() else {
+        return Err(vec![signature_err]);
+    };
+    if self_param.name(db).text(db) != "self"
+        || is_ref_param(db, selector_param)
+        || is_ref_param(db, calldata_param)
+        || !is_felt252(db, &selector_param.type_clause(db).ty(db))
+        || !is_felt252_span(db, &calldata_param.type_clause(db).ty(db))
+    {
+        return Err(vec![signature_err]);
+    }
+    let OptionReturnTypeClause::ReturnTypeClause(ret_ty) = sig.ret_ty(db) else {
+        return Err(vec![signature_err]);
+    };
+    if !is_felt252_span(db, &ret_ty.ty(db)) {
+        return Err(vec![signature_err]);
+    }
+
+    let is_snapshot = matches!(self_param.type_clause(db).ty(db), ast::Expr::Unary(_));
+    let storage_arg = if is_snapshot { "@storage" } else { "ref storage" };
+    let wrapped_name = RewriteNode::interpolate_patched(
+        "super::$wrapped_function_name$",
+        [("wrapped_function_name".to_string(), wrapped_function_name)].into(),
+    );
+
+    Ok(RewriteNode::interpolate_patched(
+        &format!(
+            "$implicit_precedence$
+        fn {DEFAULT_ENTRY_POINT_NAME}(mut data: Span::<felt252>) -> Span::<felt252> {{
+            internal::revoke_ap_tracking();
+            gas::withdraw_gas().expect('Out of gas');
+            // Force the inclusion of `System` in the list of implicits.
+            starknet::use_system_implicit();
+            let __selector =
+                serde::Serde::<felt252>::deserialize(ref data).expect('Input too short for \
+             arguments');
+            gas::withdraw_gas_all(get_builtin_costs()).expect('Out of gas');
+            let mut storage = super::unsafe_new_storage();
+            $wrapped_name$({storage_arg}, __selector, data)
+        }}"
+        ),
+        [
+            ("wrapped_name".to_string(), wrapped_name),
+            ("implicit_precedence".to_string(), implicit_precedence_attr()),
         ]
         .into(),
     ))
 }
+
+/// Returns the code that checks and sets the re-entrancy guard of the contract on entry to a
+/// `non_reentrant` entry point, and the code that clears it on return.
+fn reentrancy_guard_code(selector_hash: SelectorHash) -> (String, String) {
+    let address = format!("0x{:x}", selector_hash.hash(REENTRANCY_GUARD_VAR_NAME.as_bytes()));
+    let guard_enter = format!(
+        "
+            let __reentrancy_guard = starknet::storage_base_address_const::<{address}>();
+            assert(
+                !starknet::SyscallResultTrait::unwrap_syscall(
+                    starknet::StorageAccess::<bool>::read(0_u32, __reentrancy_guard)
+                ),
+                'Reentrant call'
+            );
+            starknet::SyscallResultTrait::unwrap_syscall(
+                starknet::StorageAccess::<bool>::write(0_u32, __reentrancy_guard, true)
+            );"
+    );
+    let guard_exit = "
+            starknet::SyscallResultTrait::unwrap_syscall(
+                starknet::StorageAccess::<bool>::write(0_u32, __reentrancy_guard, false)
+            );"
+    .to_string();
+    (guard_enter, guard_exit)
+}
+
+/// Returns the `implicit_precedence` attribute required on entry point wrappers.
+fn implicit_precedence_attr() -> RewriteNode {
+    RewriteNode::Text(format!("#[implicit_precedence({})]", IMPLICIT_PRECEDENCE.iter().join(", ")))
+}
@@ -0,0 +1,89 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::{ErrorCode, Severity};
+use cairo_lang_semantic::patcher::RewriteNode;
+use cairo_lang_syntax::node::ast::{self, OptionWrappedGenericParamList};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
+
+use super::consts::{CONTRACT_ERRORS_TRAIT, ERROR_ATTR};
+use crate::contract::SelectorHash;
+
+/// Generates `ContractErrors::revert` for the `Error` enum of a contract, and the ABI declaration
+/// of the errors.
+/// Reverting panics with the selector of the error variant, followed by its serialized data, so
+/// clients can decode the error using the ABI.
+pub fn handle_error_enum(
+    db: &dyn SyntaxGroup,
+    enum_ast: &ast::ItemEnum,
+    selector_hash: SelectorHash,
+) -> (Option<(RewriteNode, RewriteNode)>, Vec<PluginDiagnostic>) {
+    let generic_params = enum_ast.generic_params(db);
+    let OptionWrappedGenericParamList::Empty(_) = generic_params else {
+        return (
+            None,
+            vec![PluginDiagnostic {
+                message: "Contract errors with generic arguments are unsupported".to_string(),
+                stable_ptr: generic_params.stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN031")),
+                severity: Severity::Error,
+                fixes: vec![],
+            }],
+        );
+    };
+
+    let enum_name = RewriteNode::new_trimmed(enum_ast.name(db).as_syntax_node());
+    let mut revert_variants = vec![];
+    for variant in enum_ast.variants(db).elements(db) {
+        let selector = format!("0x{:x}", selector_hash.hash(variant.name(db).text(db).as_bytes()));
+        revert_variants.push(RewriteNode::interpolate_patched(
+            &format!(
+                "
+                $enum_name$::$variant_name$(val) => {{
+                    array::array_append(ref data, {selector});
+                    serde::Serde::<$variant_type$>::serialize(@val, ref data);
+                }},"
+            ),
+            [
+                ("enum_name".to_string(), enum_name.clone()),
+                (
+                    "variant_name".to_string(),
+                    RewriteNode::new_trimmed(variant.name(db).as_syntax_node()),
+                ),
+                (
+                    "variant_type".to_string(),
+                    RewriteNode::new_trimmed(variant.type_clause(db).ty(db).as_syntax_node()),
+                ),
+            ]
+            .into(),
+        ));
+    }
+
+    let errors_code = RewriteNode::interpolate_patched(
+        &format!(
+            "
+    trait {CONTRACT_ERRORS_TRAIT} {{
+        fn revert(error: $enum_name$) -> never;
+    }}
+    impl {CONTRACT_ERRORS_TRAIT}Impl of {CONTRACT_ERRORS_TRAIT} {{
+        // Inlined, as functions returning `never` are only supported when inlined.
+        #[inline(always)]
+        fn revert(error: $enum_name$) -> never {{
+            let mut data = array::array_new();
+            match error {{$revert_variants$
+            }}
+            panic(data)
+        }}
+    }}"
+        ),
+        [
+            ("enum_name".to_string(), enum_name.clone()),
+            ("revert_variants".to_string(), RewriteNode::new_modified(revert_variants)),
+        ]
+        .into(),
+    );
+    let abi_errors = RewriteNode::interpolate_patched(
+        &format!("#[{ERROR_ATTR}]\n        fn $enum_name$(error: $enum_name$);\n        "),
+        [("enum_name".to_string(), enum_name)].into(),
+    );
+    (Some((errors_code, abi_errors)), vec![])
+}
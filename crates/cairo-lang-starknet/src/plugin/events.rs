@@ -1,6 +1,7 @@
 use cairo_lang_defs::plugin::{
     DynGeneratedFileAuxData, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_semantic::patcher::{ModifiedNode, PatchBuilder, RewriteNode};
 use cairo_lang_semantic::plugin::DynPluginAuxData;
 use cairo_lang_syntax::attribute::structured::{
@@ -13,8 +14,9 @@ use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
 use indoc::indoc;
 
 use super::aux_data::StarkNetABIAuxData;
+use super::consts::{EVENTS_ATTR, EVENT_ATTR, EVENT_NAMESPACE_ARG, EVENT_TYPE_NAME, KEY_ATTR};
 use super::utils::is_ref_param;
-use crate::contract::starknet_keccak;
+use crate::contract::SelectorHash;
 
 /// Removes `#[event]` decorated functions from the contract.
 pub fn handle_function(db: &dyn SyntaxGroup, function_ast: ast::FunctionWithBody) -> PluginResult {
@@ -36,6 +38,9 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
         diagnostics.push(PluginDiagnostic {
             message: "Event structs with generic arguments are unsupported".to_string(),
             stable_ptr: generic_params.stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN032")),
+            severity: Severity::Error,
+            fixes: vec![],
         });
         return PluginResult{ code: None, diagnostics, remove_original_item: false };
     };
@@ -46,13 +51,19 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
     let mut ctor = vec![];
     for member in struct_ast.members(db).elements(db) {
         let member_name = RewriteNode::new_trimmed(member.name(db).as_syntax_node());
-        let as_event = member.has_attr(db, "event");
+        let kind = if member.has_attr(db, EVENT_ATTR) {
+            EventFieldKind::Nested
+        } else if member.has_attr(db, KEY_ATTR) {
+            EventFieldKind::Key
+        } else {
+            EventFieldKind::Data
+        };
         let value_for_append = RewriteNode::interpolate_patched(
             "self.$member_name$",
             [(String::from("member_name"), member_name.clone())].into(),
         );
-        let append_member = append_field(as_event, value_for_append);
-        let deserialize_member = deserialize_field(as_event, member_name.clone());
+        let append_member = append_field(kind, value_for_append);
+        let deserialize_member = deserialize_field(kind, member_name.clone());
         append_members.push(append_member);
         deserialize_members.push(deserialize_member);
         ctor.push(RewriteNode::interpolate_patched(
@@ -105,7 +116,11 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
 }
 
 /// Derive the `Event` trait for enums annotated with `derive(starknet::Event)`.
-pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResult {
+pub fn handle_enum(
+    db: &dyn SyntaxGroup,
+    enum_ast: ast::ItemEnum,
+    selector_hash: SelectorHash,
+) -> PluginResult {
     if !derive_event_needed(&enum_ast, db) {
         return PluginResult::default();
     }
@@ -120,6 +135,9 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
         diagnostics.push(PluginDiagnostic {
             message: "Event enums with generic arguments are unsupported".to_string(),
             stable_ptr: generic_params.stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN033")),
+            severity: Severity::Error,
+            fixes: vec![],
         });
         return PluginResult{ code: None, diagnostics, remove_original_item: false };
     };
@@ -129,9 +147,13 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
     for member in enum_ast.variants(db).elements(db) {
         let variant_name = RewriteNode::new_trimmed(member.name(db).as_syntax_node());
         let name = member.name(db).text(db);
-        let variant_selector = format!("0x{:x}", starknet_keccak(name.as_bytes()));
-        let as_event = member.has_attr(db, "event");
-        let append_member = append_field(as_event, RewriteNode::Text("val".into()));
+        let variant_selector = format!("0x{:x}", selector_hash.hash(name.as_bytes()));
+        let kind = if member.has_attr(db, EVENT_ATTR) {
+            EventFieldKind::Nested
+        } else {
+            EventFieldKind::Data
+        };
+        let append_member = append_field(kind, RewriteNode::Text("val".into()));
         let append_variant = RewriteNode::interpolate_patched(
             "
             $enum_name$::$variant_name$(val) => {
@@ -145,7 +167,7 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
             ]
             .into(),
         );
-        let deserialize_member = deserialize_field(as_event, RewriteNode::Text("val".into()));
+        let deserialize_member = deserialize_field(kind, RewriteNode::Text("val".into()));
         let deserialize_variant = RewriteNode::interpolate_patched(
             "
             if selector == $variant_selector$ {$deserialize_member$
@@ -230,51 +252,96 @@ pub fn derive_event_needed<T: QueryAttrs>(with_attrs: &T, db: &dyn SyntaxGroup)
     })
 }
 
+/// How a member of an event is emitted.
+#[derive(Clone, Copy)]
+enum EventFieldKind {
+    /// A nested event, whose keys and data are appended to the keys and data of the event.
+    Nested,
+    /// A value serialized into the keys of the event.
+    Key,
+    /// A value serialized into the data of the event.
+    Data,
+}
+
 /// Generates code to emit an event for a value
-fn append_field(as_event: bool, value: RewriteNode) -> RewriteNode {
-    if as_event {
-        RewriteNode::interpolate_patched(
+fn append_field(kind: EventFieldKind, value: RewriteNode) -> RewriteNode {
+    match kind {
+        EventFieldKind::Nested => RewriteNode::interpolate_patched(
             "
                 starknet::Event::append_keys_and_values(
                     $value$, ref keys, ref values
                 );",
             [(String::from("value"), value)].into(),
-        )
-    } else {
-        RewriteNode::interpolate_patched(
+        ),
+        EventFieldKind::Key => RewriteNode::interpolate_patched(
+            "
+                serde::Serde::serialize($value$, ref keys);",
+            [(String::from("value"), value)].into(),
+        ),
+        EventFieldKind::Data => RewriteNode::interpolate_patched(
             "
                 serde::Serde::serialize($value$, ref values);",
             [(String::from("value"), value)].into(),
-        )
+        ),
     }
 }
 
-fn deserialize_field(as_event: bool, member_name: RewriteNode) -> RewriteNode {
-    if as_event {
-        RewriteNode::interpolate_patched(
+fn deserialize_field(kind: EventFieldKind, member_name: RewriteNode) -> RewriteNode {
+    match kind {
+        EventFieldKind::Nested => RewriteNode::interpolate_patched(
             "
                 let $member_name$ = starknet::Event::deserialize(
                     ref keys, ref values
                 )?;",
             [(String::from("member_name"), member_name)].into(),
-        )
-    } else {
-        RewriteNode::interpolate_patched(
+        ),
+        EventFieldKind::Key => RewriteNode::interpolate_patched(
+            "
+                let $member_name$ = serde::Serde::deserialize(
+                    ref keys
+                )?;",
+            [(String::from("member_name"), member_name)].into(),
+        ),
+        EventFieldKind::Data => RewriteNode::interpolate_patched(
             "
                 let $member_name$ = serde::Serde::deserialize(
                     ref values
                 )?;",
             [(String::from("member_name"), member_name)].into(),
-        )
+        ),
+    }
+}
+
+/// Returns the selector of an event of a contract, used as its first key, as a hex literal.
+pub fn event_key(name: &str, namespace: Option<&str>, selector_hash: SelectorHash) -> String {
+    format!("0x{:x}", selector_hash.event_selector(name, namespace))
+}
+
+/// Returns the attribute declaring an event in the ABI, recording the namespace of its selector,
+/// if any.
+pub fn abi_event_attr(namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) => format!("#[{EVENT_ATTR}({EVENT_NAMESPACE_ARG}: '{namespace}')]"),
+        None => format!("#[{EVENT_ATTR}]"),
     }
 }
 
+/// Returns the declaration of the events of a contract in the ABI, by the type of its `Event` enum.
+pub fn abi_contract_events() -> RewriteNode {
+    RewriteNode::Text(format!(
+        "#[{EVENTS_ATTR}]\n        fn {EVENT_TYPE_NAME}(event: {EVENT_TYPE_NAME});\n        "
+    ))
+}
+
 /// Generates a function to emit an event and the corresponding ABI item.
+/// The selector of the event is namespaced by `namespace`, if given.
 /// On success, returns a RewriteNode for the event function and a RewriteNode for the ABI
 /// declaration. On failure returns None. In addition, returns diagnostics.
 pub fn handle_event(
     db: &dyn SyntaxGroup,
     function_ast: ast::FunctionWithBody,
+    namespace: Option<&str>,
+    selector_hash: SelectorHash,
 ) -> (Option<(RewriteNode, RewriteNode)>, Vec<PluginDiagnostic>) {
     let mut diagnostics = vec![];
     let declaration = function_ast.declaration(db);
@@ -285,6 +352,9 @@ pub fn handle_event(
         diagnostics.push(PluginDiagnostic {
             message: "Event functions cannot have generic arguments".to_string(),
             stable_ptr: generic_params.stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN034")),
+            severity: Severity::Error,
+            fixes: vec![],
         })
     }
 
@@ -294,6 +364,9 @@ pub fn handle_event(
         diagnostics.push(PluginDiagnostic {
             stable_ptr: ret_ty.stable_ptr().untyped(),
             message: "Event functions must not return a value.".to_string(),
+            code: Some(ErrorCode::new("SN035")),
+            severity: Severity::Error,
+            fixes: vec![],
         });
     }
 
@@ -306,6 +379,9 @@ pub fn handle_event(
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: param.modifiers(db).stable_ptr().untyped(),
                 message: "`ref` parameters are not supported in contract events.".to_string(),
+                code: Some(ErrorCode::new("SN036")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
             skip_param_serialization = true;
         }
@@ -332,6 +408,9 @@ pub fn handle_event(
         diagnostics.push(PluginDiagnostic {
             stable_ptr: function_ast.body(db).statements(db).stable_ptr().untyped(),
             message: "Event function body must be empty.".to_string(),
+            code: Some(ErrorCode::new("SN037")),
+            severity: Severity::Error,
+            fixes: vec![],
         });
     }
 
@@ -340,7 +419,7 @@ pub fn handle_event(
     }
 
     let name = declaration.name(db).text(db);
-    let event_key = format!("0x{:x}", starknet_keccak(name.as_bytes()));
+    let event_key = event_key(&name, namespace, selector_hash);
 
     (
         Some((
@@ -374,7 +453,7 @@ pub fn handle_event(
             ),
             // ABI event
             RewriteNode::new_modified(vec![
-                RewriteNode::Text("#[event]\n        ".to_string()),
+                RewriteNode::Text(format!("{}\n        ", abi_event_attr(namespace))),
                 RewriteNode::new_trimmed(function_ast.declaration(db).as_syntax_node()),
                 RewriteNode::Text(";\n        ".to_string()),
             ]),
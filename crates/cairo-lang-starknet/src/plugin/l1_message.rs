@@ -0,0 +1,111 @@
+use cairo_lang_defs::plugin::{
+    DynGeneratedFileAuxData, PluginDiagnostic, PluginGeneratedFile, PluginResult,
+};
+use cairo_lang_diagnostics::{ErrorCode, Severity};
+use cairo_lang_semantic::patcher::{PatchBuilder, RewriteNode};
+use cairo_lang_semantic::plugin::DynPluginAuxData;
+use cairo_lang_syntax::node::ast::{self, OptionWrappedGenericParamList};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use indoc::indoc;
+
+use super::aux_data::StarkNetABIAuxData;
+use super::consts::L1_MESSAGE_ATTR;
+use super::events::derive_event_needed;
+use super::storage_access::derive_storage_access_needed;
+
+/// Generates a `send` method for structs annotated with `#[l1_message]`.
+/// The method serializes the members of the struct into the message payload and sends it to L1.
+pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> PluginResult {
+    let mut builder = PatchBuilder::new(db);
+    let mut diagnostics = vec![];
+
+    let generic_params = struct_ast.generic_params(db);
+    let OptionWrappedGenericParamList::Empty(_) = generic_params else {
+        diagnostics.push(PluginDiagnostic {
+            message: "L1 message structs with generic arguments are unsupported".to_string(),
+            stable_ptr: generic_params.stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN038")),
+            severity: Severity::Error,
+            fixes: vec![],
+        });
+        return PluginResult { code: None, diagnostics, remove_original_item: false };
+    };
+    // A struct gets the code generated for a single attribute, so the derived impls could not be
+    // generated along with the `send` method.
+    if derive_event_needed(&struct_ast, db) || derive_storage_access_needed(&struct_ast, db) {
+        diagnostics.push(PluginDiagnostic {
+            message: "L1 message structs cannot derive `Event` or `StorageAccess`.".to_string(),
+            stable_ptr: struct_ast.name(db).stable_ptr().untyped(),
+            code: Some(ErrorCode::new("SN045")),
+            severity: Severity::Error,
+            fixes: vec![],
+        });
+        return PluginResult { code: None, diagnostics, remove_original_item: false };
+    }
+
+    let mut serialize_members = vec![];
+    for member in struct_ast.members(db).elements(db) {
+        serialize_members.push(RewriteNode::interpolate_patched(
+            "
+        serde::Serde::serialize(self.$member_name$, ref payload);",
+            [(
+                String::from("member_name"),
+                RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
+            )]
+            .into(),
+        ));
+    }
+
+    let struct_name = RewriteNode::new_trimmed(struct_ast.name(db).as_syntax_node());
+    let l1_message_impl = RewriteNode::interpolate_patched(
+        indoc! {"
+            trait $struct_name$L1MessageTrait {
+                fn send(self: @$struct_name$, to_address: felt252);
+            }
+            impl $struct_name$L1MessageImpl of $struct_name$L1MessageTrait {
+                fn send(self: @$struct_name$, to_address: felt252) {
+                    let mut payload = array::array_new();$serialize_members$
+                    starknet::SyscallResultTrait::unwrap_syscall(
+                        starknet::syscalls::send_message_to_l1_syscall(
+                            to_address, array::ArrayTrait::span(@payload)
+                        )
+                    )
+                }
+            }
+        "},
+        [
+            (String::from("struct_name"), struct_name),
+            (String::from("serialize_members"), RewriteNode::new_modified(serialize_members)),
+        ]
+        .into(),
+    );
+
+    builder.add_modified(l1_message_impl);
+
+    PluginResult {
+        code: Some(PluginGeneratedFile {
+            name: "l1_message_impl".into(),
+            content: builder.code,
+            aux_data: DynGeneratedFileAuxData::new(DynPluginAuxData::new(StarkNetABIAuxData {
+                patches: builder.patches,
+            })),
+        }),
+        diagnostics,
+        remove_original_item: false,
+    }
+}
+
+/// Generates the ABI declaration of an `#[l1_message]` struct.
+pub fn handle_l1_message_abi(db: &dyn SyntaxGroup, struct_ast: &ast::ItemStruct) -> RewriteNode {
+    RewriteNode::interpolate_patched(
+        &format!(
+            "#[{L1_MESSAGE_ATTR}]\n        fn $struct_name$(message: $struct_name$);\n        "
+        ),
+        [(
+            String::from("struct_name"),
+            RewriteNode::new_trimmed(struct_ast.name(db).as_syntax_node()),
+        )]
+        .into(),
+    )
+}
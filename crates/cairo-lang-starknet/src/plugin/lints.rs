@@ -0,0 +1,167 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::{ErrorCode, Severity};
+use cairo_lang_plugins::plugins::is_enabled_by_cfg;
+use cairo_lang_syntax::node::ast::FunctionWithBody;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+
+use super::consts::{EVENT_ATTR, STORAGE_STRUCT_NAME};
+use super::utils::is_ref_param;
+
+/// Returns a diagnostic suggesting to take the storage as a snapshot, if the external function
+/// takes it by `ref` but only reads from it, so it could be a view.
+/// The plugin runs before the semantic model is available, so the check only matches the token
+/// sequence of the body: any `self` token that is not followed by `. <var> . read` is
+/// conservatively assumed to modify the storage.
+pub fn storage_mutability_lint(
+    db: &dyn SyntaxGroup,
+    function: &FunctionWithBody,
+) -> Option<PluginDiagnostic> {
+    let declaration = function.declaration(db);
+    let params = declaration.signature(db).parameters(db).elements(db);
+    let self_param = params.first()?;
+    if self_param.name(db).text(db) != "self" || !is_ref_param(db, self_param) {
+        return None;
+    }
+    let mut tokens = vec![];
+    collect_tokens(db, &function.body(db).as_syntax_node(), &mut tokens);
+    let only_reads_storage =
+        tokens.iter().enumerate().filter(|(_, token)| *token == "self").all(|(i, _)| {
+            matches!(
+                tokens.get(i + 1..i + 5),
+                Some([dot, _, read_dot, read]) if dot == "." && read_dot == "." && read == "read"
+            )
+        });
+    if !only_reads_storage {
+        return None;
+    }
+    Some(PluginDiagnostic {
+        message: format!(
+            "`{}` takes the storage by `ref` but never modifies it. Consider taking `self: \
+             @Storage` to make it a view.",
+            declaration.name(db).text(db)
+        ),
+        stable_ptr: self_param.stable_ptr().untyped(),
+        code: Some(ErrorCode::new("SN039")),
+        severity: Severity::Warning,
+        fixes: vec![],
+    })
+}
+
+/// Returns diagnostics for the parameters and the variables of the functions of a contract that
+/// are named like one of its storage variables, as they may be mistaken for the storage variable.
+pub fn shadowed_storage_variables_lint(
+    db: &dyn SyntaxGroup,
+    items: &[ast::Item],
+) -> Vec<PluginDiagnostic> {
+    let mut storage_names = UnorderedHashSet::default();
+    let mut functions = vec![];
+    for item in items {
+        match item {
+            ast::Item::Struct(item_struct)
+                if item_struct.name(db).text(db) == STORAGE_STRUCT_NAME =>
+            {
+                for member in item_struct.members(db).elements(db) {
+                    storage_names.insert(member.name(db).text(db));
+                }
+            }
+            ast::Item::FreeFunction(item_function) if !item_function.has_attr(db, EVENT_ATTR) => {
+                functions.push(item_function.clone());
+            }
+            ast::Item::Impl(item_impl) => {
+                let ast::MaybeImplBody::Some(body) = item_impl.body(db) else { continue; };
+                for item in body.items(db).elements(db) {
+                    let ast::ImplItem::Function(item_function) = item else { continue; };
+                    if is_enabled_by_cfg(db, &item_function) {
+                        functions.push(item_function);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut diagnostics = vec![];
+    for function in functions {
+        let params = function.declaration(db).signature(db).parameters(db).elements(db);
+        let mut names = params.iter().map(|param| param.name(db)).collect::<Vec<_>>();
+        for node in function.body(db).as_syntax_node().descendants(db) {
+            let pattern = match node.kind(db) {
+                SyntaxKind::StatementLet => {
+                    ast::StatementLet::from_syntax_node(db, node).pattern(db)
+                }
+                SyntaxKind::MatchArm => ast::MatchArm::from_syntax_node(db, node).pattern(db),
+                _ => continue,
+            };
+            collect_pattern_names(db, pattern, &mut names);
+        }
+        for name in names {
+            let name_text = name.text(db);
+            if storage_names.contains(&name_text) {
+                diagnostics.push(PluginDiagnostic {
+                    message: format!(
+                        "`{name_text}` shadows the storage variable of the same name. Consider \
+                         renaming it."
+                    ),
+                    stable_ptr: name.stable_ptr().untyped(),
+                    code: Some(ErrorCode::new("SN044")),
+                    severity: Severity::Warning,
+                    fixes: vec![],
+                });
+            }
+        }
+    }
+    diagnostics
+}
+
+/// Collects the names of the variables bound by a pattern.
+fn collect_pattern_names(
+    db: &dyn SyntaxGroup,
+    pattern: ast::Pattern,
+    names: &mut Vec<ast::TerminalIdentifier>,
+) {
+    match pattern {
+        ast::Pattern::Identifier(pattern) => names.push(pattern.name(db)),
+        // A single identifier is parsed as a path.
+        ast::Pattern::Path(path) => {
+            if let [ast::PathSegment::Simple(segment)] = &path.elements(db)[..] {
+                names.push(segment.ident(db));
+            }
+        }
+        ast::Pattern::Struct(pattern) => {
+            for param in pattern.params(db).elements(db) {
+                match param {
+                    ast::PatternStructParam::Single(param) => names.push(param.name(db)),
+                    ast::PatternStructParam::WithExpr(param) => {
+                        collect_pattern_names(db, param.pattern(db), names)
+                    }
+                    ast::PatternStructParam::Tail(_) => {}
+                }
+            }
+        }
+        ast::Pattern::Tuple(pattern) => {
+            for pattern in pattern.patterns(db).elements(db) {
+                collect_pattern_names(db, pattern, names);
+            }
+        }
+        ast::Pattern::Enum(pattern) => collect_pattern_names(db, pattern.pattern(db), names),
+        ast::Pattern::Underscore(_) | ast::Pattern::Literal(_) | ast::Pattern::ShortString(_) => {}
+    }
+}
+
+/// Collects the texts of the tokens of the node, without trivia.
+fn collect_tokens(db: &dyn SyntaxGroup, node: &SyntaxNode, tokens: &mut Vec<String>) {
+    if node.kind(db).is_terminal() {
+        let text = node.clone().get_text_without_trivia(db);
+        if !text.is_empty() {
+            tokens.push(text);
+        }
+        return;
+    }
+    for child in node.children(db) {
+        collect_tokens(db, &child, tokens);
+    }
+}
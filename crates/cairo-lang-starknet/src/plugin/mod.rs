@@ -9,13 +9,21 @@ use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin};
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use consts::*;
+use num_bigint::BigUint;
+use smol_str::SmolStr;
 
+mod access_control;
 pub mod aux_data;
 mod contract;
 mod dispatcher;
 mod entry_point;
+mod errors;
 mod events;
+mod l1_message;
+mod lints;
+mod pausable;
 mod storage;
 mod storage_access;
 mod utils;
@@ -26,16 +34,81 @@ use storage_access::derive_storage_access_needed;
 
 use self::contract::{handle_contract_by_storage, handle_module};
 use self::events::{handle_enum, handle_function};
+use crate::contract::SelectorHash;
 
-#[derive(Debug, Default)]
+/// The way the `TEST_CLASS_HASH` constant of a contract is generated.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TestClassHash {
+    /// The keccak of the text of the contract module, so any change to the module, including
+    /// whitespace, changes it.
+    #[default]
+    ModuleText,
+    /// The keccak of the canonical form of the contract module, which ignores whitespace and
+    /// comments.
+    Canonical,
+    /// The given value. Meant for crates with a single contract, as contracts must have distinct
+    /// class hashes to be deployed in tests.
+    Pinned(BigUint),
+    /// The class hash of the contract, by contract name, as computed by
+    /// [`crate::contract_class::compute_sierra_class_hashes`]. Contracts missing from the map get
+    /// no `TEST_CLASS_HASH`.
+    SierraClassHash(OrderedHashMap<SmolStr, BigUint>),
+    /// `TEST_CLASS_HASH` is not generated, so the contract cannot be deployed in tests.
+    Omitted,
+}
+
+#[derive(Clone, Debug, Default)]
 #[non_exhaustive]
-pub struct StarkNetPlugin;
+pub struct StarkNetPlugin {
+    /// The way the `TEST_CLASS_HASH` constant of contracts is generated.
+    pub test_class_hash: TestClassHash,
+    /// Whether to report external functions that take the storage by `ref` but never modify it.
+    /// As taking the storage by `ref` is common before the contract is complete, this is opt-in.
+    pub lint_storage_mutability: bool,
+    /// Whether to report parameters and variables of the functions of contracts named like a
+    /// storage variable. As e.g. `let balance = self.balance.read();` is idiomatic, this is
+    /// opt-in.
+    pub lint_shadowed_storage_variables: bool,
+    /// Whether the selectors of the events of contracts are namespaced by the contract name, i.e.
+    /// the selector of the event `Transfer` of the contract `MyToken` is
+    /// `starknet_keccak("MyToken::Transfer")` instead of `starknet_keccak("Transfer")`.
+    /// Avoids clashes between the selectors of events of different contracts.
+    pub namespaced_event_selectors: bool,
+    /// The hash from which the selectors of entry points, events and errors, and the addresses of
+    /// storage variables are derived.
+    pub selector_hash: SelectorHash,
+}
+impl StarkNetPlugin {
+    pub fn with_test_class_hash(mut self, test_class_hash: TestClassHash) -> Self {
+        self.test_class_hash = test_class_hash;
+        self
+    }
+    pub fn with_storage_mutability_lint(mut self) -> Self {
+        self.lint_storage_mutability = true;
+        self
+    }
+    pub fn with_shadowed_storage_variables_lint(mut self) -> Self {
+        self.lint_shadowed_storage_variables = true;
+        self
+    }
+    pub fn with_namespaced_event_selectors(mut self) -> Self {
+        self.namespaced_event_selectors = true;
+        self
+    }
+    pub fn with_selector_hash(mut self, selector_hash: SelectorHash) -> Self {
+        self.selector_hash = selector_hash;
+        self
+    }
+}
 
 impl MacroPlugin for StarkNetPlugin {
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
         match item_ast {
             ast::Item::Module(module_ast) => handle_module(db, module_ast),
-            ast::Item::Trait(trait_ast) => handle_trait(db, trait_ast),
+            ast::Item::Trait(trait_ast) => handle_trait(db, trait_ast, self.selector_hash),
+            ast::Item::Struct(struct_ast) if struct_ast.has_attr(db, L1_MESSAGE_ATTR) => {
+                l1_message::handle_struct(db, struct_ast)
+            }
             ast::Item::Struct(struct_ast) if derive_event_needed(&struct_ast, db) => {
                 events::handle_struct(db, struct_ast)
             }
@@ -43,9 +116,9 @@ impl MacroPlugin for StarkNetPlugin {
                 storage_access::handle_struct(db, struct_ast)
             }
             ast::Item::Struct(struct_ast) if struct_ast.has_attr(db, "starknet::storage") => {
-                handle_contract_by_storage(db, struct_ast).unwrap_or_default()
+                handle_contract_by_storage(db, struct_ast, self).unwrap_or_default()
             }
-            ast::Item::Enum(enum_ast) => handle_enum(db, enum_ast),
+            ast::Item::Enum(enum_ast) => handle_enum(db, enum_ast, self.selector_hash),
             ast::Item::FreeFunction(function_ast) => handle_function(db, function_ast),
             // Nothing to do for other items.
             _ => PluginResult::default(),
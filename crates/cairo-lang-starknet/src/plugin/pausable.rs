@@ -0,0 +1,102 @@
+use cairo_lang_semantic::patcher::RewriteNode;
+
+use super::access_control::only_owner_check;
+use super::consts::{
+    PAUSABLE_MODULE, PAUSED_EVENT_NAME, PAUSED_VAR_NAME, PAUSE_ENTRY_POINT_NAME,
+    UNPAUSED_EVENT_NAME, UNPAUSE_ENTRY_POINT_NAME,
+};
+use super::entry_point::entry_point_wrapper_code;
+use super::events::{abi_event_attr, event_key};
+use crate::contract::SelectorHash;
+
+/// Returns the check of the `#[when_not_paused]` attribute, to be generated in the wrapper of the
+/// entry point.
+pub fn when_not_paused_check() -> String {
+    format!("\n            super::{PAUSABLE_MODULE}::assert_not_paused();")
+}
+
+/// Generates the module managing the paused flag of a pausable contract.
+/// The selectors of the emitted events are namespaced by `event_namespace`, if given.
+pub fn pausable_module(event_namespace: Option<&str>, selector_hash: SelectorHash) -> RewriteNode {
+    let paused_address = format!("0x{:x}", selector_hash.hash(PAUSED_VAR_NAME.as_bytes()));
+    let paused_key = event_key(PAUSED_EVENT_NAME, event_namespace, selector_hash);
+    let unpaused_key = event_key(UNPAUSED_EVENT_NAME, event_namespace, selector_hash);
+    RewriteNode::Text(format!(
+        "
+    mod {PAUSABLE_MODULE} {{
+        use starknet::SyscallResultTrait;
+        use starknet::SyscallResultTraitImpl;
+
+        fn paused_address() -> starknet::StorageBaseAddress {{
+            starknet::storage_base_address_const::<{paused_address}>()
+        }}
+        fn set_paused(paused: bool, event_key: felt252) {{
+            starknet::StorageAccess::<bool>::write(0_u32, paused_address(), paused)
+                .unwrap_syscall();
+            let mut keys = array::array_new();
+            array::array_append(ref keys, event_key);
+            let mut data = array::array_new();
+            serde::Serde::<starknet::ContractAddress>::serialize(
+                @starknet::get_caller_address(), ref data
+            );
+            starknet::syscalls::emit_event_syscall(
+                array::ArrayTrait::span(@keys), array::ArrayTrait::span(@data),
+            ).unwrap_syscall()
+        }}
+
+        fn is_paused() -> bool {{
+            starknet::StorageAccess::<bool>::read(0_u32, paused_address()).unwrap_syscall()
+        }}
+        fn assert_not_paused() {{
+            assert(!is_paused(), 'Contract is paused');
+        }}
+        fn pause() {{
+            assert_not_paused();
+            set_paused(true, {paused_key});
+        }}
+        fn unpause() {{
+            assert(is_paused(), 'Contract is not paused');
+            set_paused(false, {unpaused_key});
+        }}
+    }}"
+    ))
+}
+
+/// Generates the wrappers of the `pause` and `unpause` external functions of a pausable contract,
+/// which may only be called by the owner.
+pub fn pausable_entry_point_wrappers() -> Vec<RewriteNode> {
+    [PAUSE_ENTRY_POINT_NAME, UNPAUSE_ENTRY_POINT_NAME]
+        .into_iter()
+        .map(|name| {
+            entry_point_wrapper_code(
+                RewriteNode::Text(name.to_string()),
+                RewriteNode::Text("".to_string()),
+                only_owner_check(),
+                RewriteNode::Text(format!(
+                    "super::{PAUSABLE_MODULE}::{name}();
+            let mut arr = array::array_new();
+            array::ArrayTrait::span(@arr)"
+                )),
+            )
+        })
+        .collect()
+}
+
+/// Returns the ABI declarations of the `pause` and `unpause` external functions of a pausable
+/// contract, and of their events.
+pub fn pausable_abi(event_namespace: Option<&str>) -> (RewriteNode, RewriteNode) {
+    let functions = [PAUSE_ENTRY_POINT_NAME, UNPAUSE_ENTRY_POINT_NAME]
+        .into_iter()
+        .map(|name| format!("#[external]\n        fn {name}(ref self: Storage);\n        "))
+        .collect::<String>();
+    let event_attr = abi_event_attr(event_namespace);
+    let events = [PAUSED_EVENT_NAME, UNPAUSED_EVENT_NAME]
+        .into_iter()
+        .map(|name| {
+            format!(
+                "{event_attr}\n        fn {name}(account: starknet::ContractAddress);\n        "
+            )
+        })
+        .collect::<String>();
+    (RewriteNode::Text(functions), RewriteNode::Text(events))
+}
@@ -1,12 +1,16 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_semantic::patcher::RewriteNode;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::try_extract_matches;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use indoc::formatdoc;
+use smol_str::SmolStr;
 
-use crate::contract::starknet_keccak;
+use super::utils::maybe_strip_underscore;
+use crate::contract::SelectorHash;
 
 /// Generate getters and setters for the variables in the storage struct.
 pub fn handle_storage_struct(
@@ -14,15 +18,41 @@ pub fn handle_storage_struct(
     struct_ast: ast::ItemStruct,
     extra_uses_node: &RewriteNode,
     has_event: bool,
+    contract_item_names: &UnorderedHashSet<SmolStr>,
+    selector_hash: SelectorHash,
 ) -> (RewriteNode, Vec<PluginDiagnostic>) {
     let mut members_code = Vec::new();
     let mut members_init_code = Vec::new();
     let mut vars_code = Vec::new();
     let mut diagnostics = vec![];
+    let mut stripped_names = UnorderedHashSet::default();
 
     for member in struct_ast.members(db).elements(db) {
         let name_node = member.name(db).as_syntax_node();
         let name = member.name(db).text(db);
+        if !stripped_names.insert(maybe_strip_underscore(name.as_str()).to_string()) {
+            diagnostics.push(PluginDiagnostic {
+                message: format!("The storage variable `{name}` is defined multiple times."),
+                stable_ptr: member.name(db).stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN040")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+            continue;
+        }
+        if contract_item_names.contains(&name) {
+            diagnostics.push(PluginDiagnostic {
+                message: format!(
+                    "The storage variable `{name}` collides with an item of the same name in the \
+                     contract."
+                ),
+                stable_ptr: member.name(db).stable_ptr().untyped(),
+                code: Some(ErrorCode::new("SN041")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+            continue;
+        }
         members_code.push(RewriteNode::interpolate_patched(
             "
         $name$: $name$::Storage,",
@@ -36,10 +66,49 @@ pub fn handle_storage_struct(
             $name$: $name$::Storage{},",
             UnorderedHashMap::from([("name".to_string(), RewriteNode::new_trimmed(name_node))]),
         ));
-        let address = format!("0x{:x}", starknet_keccak(name.as_bytes()));
+        let address = format!("0x{:x}", selector_hash.hash(name.as_bytes()));
         let type_ast = member.type_clause(db).ty(db);
         match try_extract_mapping_types(db, &type_ast) {
             Some((key_type_ast, value_type_ast, MappingType::Legacy)) => {
+                if let Some((inner_key_type_ast, inner_value_type_ast)) =
+                    try_extract_nested_legacy_mapping_types(db, &value_type_ast)
+                {
+                    if try_extract_generic_arg_mapping_types(db, &inner_value_type_ast).is_some() {
+                        diagnostics.push(PluginDiagnostic {
+                            message: "Only two levels of nested `LegacyMap` are supported."
+                                .to_string(),
+                            stable_ptr: type_ast.stable_ptr().untyped(),
+                            code: Some(ErrorCode::new("SN042")),
+                            severity: Severity::Error,
+                            fixes: vec![],
+                        });
+                        continue;
+                    }
+                    vars_code.push(RewriteNode::interpolate_patched(
+                        handle_nested_legacy_mapping_storage_var(&address).as_str(),
+                        [
+                            (
+                                "storage_var_name".to_string(),
+                                RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
+                            ),
+                            ("extra_uses".to_string(), extra_uses_node.clone()),
+                            (
+                                "key1_type".to_string(),
+                                RewriteNode::new_trimmed(key_type_ast.as_syntax_node()),
+                            ),
+                            (
+                                "key2_type".to_string(),
+                                RewriteNode::new_trimmed(inner_key_type_ast.as_syntax_node()),
+                            ),
+                            (
+                                "value_type".to_string(),
+                                RewriteNode::new_trimmed(inner_value_type_ast.as_syntax_node()),
+                            ),
+                        ]
+                        .into(),
+                    ));
+                    continue;
+                }
                 vars_code.push(RewriteNode::interpolate_patched(
                     handle_legacy_mapping_storage_var(&address).as_str(),
                     [
@@ -60,13 +129,86 @@ pub fn handle_storage_struct(
                     .into(),
                 ));
             }
+            Some((key_type_ast, value_type_ast, MappingType::Enumerable)) => {
+                let keys_address =
+                    format!("0x{:x}", selector_hash.hash(format!("{name}::keys").as_bytes()));
+                let indices_address =
+                    format!("0x{:x}", selector_hash.hash(format!("{name}::indices").as_bytes()));
+                vars_code.push(RewriteNode::interpolate_patched(
+                    handle_enumerable_mapping_storage_var(
+                        &address,
+                        &keys_address,
+                        &indices_address,
+                    )
+                    .as_str(),
+                    [
+                        (
+                            "storage_var_name".to_string(),
+                            RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
+                        ),
+                        ("extra_uses".to_string(), extra_uses_node.clone()),
+                        (
+                            "key_type".to_string(),
+                            RewriteNode::new_trimmed(key_type_ast.as_syntax_node()),
+                        ),
+                        (
+                            "value_type".to_string(),
+                            RewriteNode::new_trimmed(value_type_ast.as_syntax_node()),
+                        ),
+                    ]
+                    .into(),
+                ));
+            }
             Some((_, _, MappingType::NonLegacy)) => {
                 diagnostics.push(PluginDiagnostic {
                     message: "Non `LegacyMap` mapping is not yet supported.".to_string(),
                     stable_ptr: type_ast.stable_ptr().untyped(),
+                    code: Some(ErrorCode::new("SN043")),
+                    severity: Severity::Error,
+                    fixes: vec![],
                 });
             }
             None => {
+                if let Some(value_type_ast) = try_extract_wrapped_type(db, &type_ast, "List") {
+                    vars_code.push(RewriteNode::interpolate_patched(
+                        handle_list_storage_var(&address).as_str(),
+                        [
+                            (
+                                "storage_var_name".to_string(),
+                                RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
+                            ),
+                            ("extra_uses".to_string(), extra_uses_node.clone()),
+                            (
+                                "value_type".to_string(),
+                                RewriteNode::new_trimmed(value_type_ast.as_syntax_node()),
+                            ),
+                        ]
+                        .into(),
+                    ));
+                    continue;
+                }
+                if let Some(value_type_ast) = try_extract_wrapped_type(db, &type_ast, "Option") {
+                    let presence_address = format!(
+                        "0x{:x}",
+                        selector_hash.hash(format!("{name}::is_some").as_bytes())
+                    );
+                    vars_code.push(RewriteNode::interpolate_patched(
+                        handle_option_storage_var(&address, &presence_address).as_str(),
+                        [
+                            (
+                                "storage_var_name".to_string(),
+                                RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
+                            ),
+                            ("extra_uses".to_string(), extra_uses_node.clone()),
+                            (
+                                "value_type".to_string(),
+                                RewriteNode::new_trimmed(value_type_ast.as_syntax_node()),
+                            ),
+                        ]
+                        .into(),
+                    ));
+                    continue;
+                }
                 vars_code.push(RewriteNode::interpolate_patched(
                     handle_simple_storage_var(&address).as_str(),
                     [
@@ -132,10 +274,12 @@ enum MappingType {
     Legacy,
     /// Poseidon based.
     NonLegacy,
+    /// Pedersen based, keeping track of its keys so they can be iterated.
+    Enumerable,
 }
 
-/// Given a type, if it is of form `Map{Legacy,}::<K, V>`, returns `K` and `V` and the mapping type.
-/// Otherwise, returns None.
+/// Given a type, if it is of form `{Legacy,Enumerable,}Map::<K, V>`, returns `K` and `V` and the
+/// mapping type. Otherwise, returns None.
 fn try_extract_mapping_types(
     db: &dyn SyntaxGroup,
     type_ast: &ast::Expr,
@@ -144,20 +288,57 @@ fn try_extract_mapping_types(
     let [ast::PathSegment::WithGenericArgs(segment)] = &as_path.elements(db)[..] else {
         return None;
     };
-    let ty = segment.ident(db).text(db);
-    if ty == "LegacyMap" || ty == "Map" {
-        let [key_ty, value_ty] = <[ast::GenericArg; 2]>::try_from(
-            segment.generic_args(db).generic_args(db).elements(db),
-        )
-        .ok()?;
-        Some((
-            key_ty,
-            value_ty,
-            if ty == "LegacyMap" { MappingType::Legacy } else { MappingType::NonLegacy },
-        ))
-    } else {
-        None
+    let mapping_type = match segment.ident(db).text(db).as_str() {
+        "LegacyMap" => MappingType::Legacy,
+        "Map" => MappingType::NonLegacy,
+        "EnumerableMap" => MappingType::Enumerable,
+        _ => return None,
+    };
+    let [key_ty, value_ty] =
+        <[ast::GenericArg; 2]>::try_from(segment.generic_args(db).generic_args(db).elements(db))
+            .ok()?;
+    Some((key_ty, value_ty, mapping_type))
+}
+
+/// Given a generic argument, if it is a type of form `Map{Legacy,}::<K, V>`, returns `K` and `V`
+/// and the mapping type. Otherwise, returns None.
+fn try_extract_generic_arg_mapping_types(
+    db: &dyn SyntaxGroup,
+    generic_arg: &ast::GenericArg,
+) -> Option<(ast::GenericArg, ast::GenericArg, MappingType)> {
+    let generic_arg_expr = try_extract_matches!(generic_arg, ast::GenericArg::Expr)?;
+    try_extract_mapping_types(db, &generic_arg_expr.value(db))
+}
+
+/// Given the value type of a `LegacyMap`, if it is itself of form `LegacyMap::<K, V>`, returns `K`
+/// and `V`. Otherwise, returns None.
+fn try_extract_nested_legacy_mapping_types(
+    db: &dyn SyntaxGroup,
+    value_type_ast: &ast::GenericArg,
+) -> Option<(ast::GenericArg, ast::GenericArg)> {
+    match try_extract_generic_arg_mapping_types(db, value_type_ast)? {
+        (key_ty, value_ty, MappingType::Legacy) => Some((key_ty, value_ty)),
+        (_, _, MappingType::NonLegacy | MappingType::Enumerable) => None,
+    }
+}
+
+/// Given a type, if it is of form `{wrapper_name}::<T>`, returns `T`. Otherwise, returns None.
+fn try_extract_wrapped_type(
+    db: &dyn SyntaxGroup,
+    type_ast: &ast::Expr,
+    wrapper_name: &str,
+) -> Option<ast::GenericArg> {
+    let as_path = try_extract_matches!(type_ast, ast::Expr::Path)?;
+    let [ast::PathSegment::WithGenericArgs(segment)] = &as_path.elements(db)[..] else {
+        return None;
+    };
+    if segment.ident(db).text(db) != wrapper_name {
+        return None;
     }
+    let [value_ty] =
+        <[ast::GenericArg; 1]>::try_from(segment.generic_args(db).generic_args(db).elements(db))
+            .ok()?;
+    Some(value_ty)
 }
 
 /// Generate getters and setters skeleton for a non-mapping member in the storage struct.
@@ -248,3 +429,330 @@ fn handle_legacy_mapping_storage_var(address: &str) -> String {
     }}"
     )
 }
+
+/// Generate getters and setters skeleton for an `Option` member in the storage struct.
+/// Whether the value is present is kept in a separate flag slot, so `Option::None` is
+/// distinguishable from any stored value.
+fn handle_option_storage_var(address: &str, presence_address: &str) -> String {
+    format!(
+        "
+    use $storage_var_name$::InternalStorageTrait as $storage_var_name$StorageTrait;
+    mod $storage_var_name$ {{$extra_uses$
+        use starknet::SyscallResultTrait;
+        use starknet::SyscallResultTraitImpl;
+        use super;
+
+        #[derive(Copy, Drop)]
+        struct Storage {{}}
+        trait InternalStorageTrait {{
+            fn address(self: @Storage) -> starknet::StorageBaseAddress;
+            fn presence_address(self: @Storage) -> starknet::StorageBaseAddress;
+            fn read(self: @Storage) -> Option<$value_type$>;
+            fn write(ref self: Storage, value: $value_type$);
+            fn clear(ref self: Storage);
+        }}
+
+        impl InternalStorageImpl of InternalStorageTrait {{
+            fn address(self: @Storage) -> starknet::StorageBaseAddress {{
+                starknet::storage_base_address_const::<{address}>()
+            }}
+            fn presence_address(self: @Storage) -> starknet::StorageBaseAddress {{
+                starknet::storage_base_address_const::<{presence_address}>()
+            }}
+            fn read(self: @Storage) -> Option<$value_type$> {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                let is_some = starknet::StorageAccess::<bool>::read(
+                    address_domain,
+                    self.presence_address(),
+                ).unwrap_syscall();
+                if !is_some {{
+                    return Option::None(());
+                }}
+                Option::Some(
+                    starknet::StorageAccess::<$value_type$>::read(
+                        address_domain,
+                        self.address(),
+                    ).unwrap_syscall()
+                )
+            }}
+            fn write(ref self: Storage, value: $value_type$) {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                starknet::StorageAccess::<$value_type$>::write(
+                    address_domain,
+                    self.address(),
+                    value,
+                ).unwrap_syscall();
+                starknet::StorageAccess::<bool>::write(
+                    address_domain,
+                    self.presence_address(),
+                    true,
+                ).unwrap_syscall()
+            }}
+            fn clear(ref self: Storage) {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                starknet::StorageAccess::<bool>::write(
+                    address_domain,
+                    self.presence_address(),
+                    false,
+                ).unwrap_syscall()
+            }}
+        }}
+    }}"
+    )
+}
+
+/// Generate getters and setters skeleton for a `List` member in the storage struct.
+/// The length of the list is kept at the base address, and its elements are kept in a mapping
+/// from their index.
+fn handle_list_storage_var(address: &str) -> String {
+    format!(
+        "
+    use $storage_var_name$::InternalStorageTrait as $storage_var_name$StorageTrait;
+    mod $storage_var_name$ {{$extra_uses$
+        use starknet::SyscallResultTrait;
+        use starknet::SyscallResultTraitImpl;
+        use super;
+
+        #[derive(Copy, Drop)]
+        struct Storage {{}}
+        trait InternalStorageTrait {{
+            fn address(self: @Storage, index: usize) -> starknet::StorageBaseAddress;
+            fn len(self: @Storage) -> usize;
+            fn get(self: @Storage, index: usize) -> $value_type$;
+            fn set(ref self: Storage, index: usize, value: $value_type$);
+            fn append(ref self: Storage, value: $value_type$);
+        }}
+
+        fn len_address() -> starknet::StorageBaseAddress {{
+            starknet::storage_base_address_const::<{address}>()
+        }}
+
+        impl InternalStorageImpl of InternalStorageTrait {{
+            fn address(self: @Storage, index: usize) -> starknet::StorageBaseAddress {{
+                starknet::storage_base_address_from_felt252(
+                    hash::LegacyHash::<usize>::hash({address}, index))
+            }}
+            fn len(self: @Storage) -> usize {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                starknet::StorageAccess::<usize>::read(
+                    address_domain,
+                    len_address(),
+                ).unwrap_syscall()
+            }}
+            fn get(self: @Storage, index: usize) -> $value_type$ {{
+                assert(index < self.len(), 'Index out of bounds');
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                starknet::StorageAccess::<$value_type$>::read(
+                    address_domain,
+                    self.address(index),
+                ).unwrap_syscall()
+            }}
+            fn set(ref self: Storage, index: usize, value: $value_type$) {{
+                assert(index < self.len(), 'Index out of bounds');
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                starknet::StorageAccess::<$value_type$>::write(
+                    address_domain,
+                    self.address(index),
+                    value,
+                ).unwrap_syscall()
+            }}
+            fn append(ref self: Storage, value: $value_type$) {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                let len = self.len();
+                starknet::StorageAccess::<$value_type$>::write(
+                    address_domain,
+                    self.address(len),
+                    value,
+                ).unwrap_syscall();
+                starknet::StorageAccess::<usize>::write(
+                    address_domain,
+                    len_address(),
+                    len + 1_u32,
+                ).unwrap_syscall()
+            }}
+        }}
+    }}"
+    )
+}
+
+/// Generate getters and setters skeleton for a nested `LegacyMap` member in the storage struct.
+/// The address of a value is computed by hashing the keys one after the other.
+fn handle_nested_legacy_mapping_storage_var(address: &str) -> String {
+    format!(
+        "
+    use $storage_var_name$::InternalStorageTrait as $storage_var_name$StorageTrait;
+    mod $storage_var_name$ {{$extra_uses$
+        use starknet::SyscallResultTrait;
+        use starknet::SyscallResultTraitImpl;
+        use super;
+
+        #[derive(Copy, Drop)]
+        struct Storage {{}}
+        trait InternalStorageTrait {{
+            fn address(
+                self: @Storage, key1: $key1_type$, key2: $key2_type$
+            ) -> starknet::StorageBaseAddress;
+            fn read(self: @Storage, key1: $key1_type$, key2: $key2_type$) -> $value_type$;
+            fn write(ref self: Storage, key1: $key1_type$, key2: $key2_type$, value: $value_type$);
+        }}
+
+        impl InternalStorageImpl of InternalStorageTrait {{
+            fn address(
+                self: @Storage, key1: $key1_type$, key2: $key2_type$
+            ) -> starknet::StorageBaseAddress {{
+                starknet::storage_base_address_from_felt252(
+                    hash::LegacyHash::<$key2_type$>::hash(
+                        hash::LegacyHash::<$key1_type$>::hash({address}, key1),
+                        key2,
+                    ))
+            }}
+            fn read(self: @Storage, key1: $key1_type$, key2: $key2_type$) -> $value_type$ {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                starknet::StorageAccess::<$value_type$>::read(
+                    address_domain,
+                    self.address(key1, key2),
+                ).unwrap_syscall()
+            }}
+            fn write(ref self: Storage, key1: $key1_type$, key2: $key2_type$, value: $value_type$) \
+         {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                starknet::StorageAccess::<$value_type$>::write(
+                    address_domain,
+                    self.address(key1, key2),
+                    value,
+                ).unwrap_syscall()
+            }}
+        }}
+    }}"
+    )
+}
+
+/// Generate getters and setters skeleton for an `EnumerableMap` member in the storage struct.
+/// Besides the values, the number of keys is kept at the base address, the key of each index is
+/// kept in a mapping based on `keys_address`, and the index (plus one) of each key is kept in a
+/// mapping based on `indices_address`.
+fn handle_enumerable_mapping_storage_var(
+    address: &str,
+    keys_address: &str,
+    indices_address: &str,
+) -> String {
+    format!(
+        "
+    use $storage_var_name$::InternalStorageTrait as $storage_var_name$StorageTrait;
+    mod $storage_var_name$ {{$extra_uses$
+        use starknet::SyscallResultTrait;
+        use starknet::SyscallResultTraitImpl;
+        use super;
+
+        #[derive(Copy, Drop)]
+        struct Storage {{}}
+        trait InternalStorageTrait {{
+            fn address(self: @Storage, key: $key_type$) -> starknet::StorageBaseAddress;
+            fn read(self: @Storage, key: $key_type$) -> $value_type$;
+            fn write(ref self: Storage, key: $key_type$, value: $value_type$);
+            fn len(self: @Storage) -> usize;
+            fn at(self: @Storage, index: usize) -> ($key_type$, $value_type$);
+            fn entries(self: @Storage) -> Array<($key_type$, $value_type$)>;
+        }}
+
+        fn len_address() -> starknet::StorageBaseAddress {{
+            starknet::storage_base_address_const::<{address}>()
+        }}
+        fn key_address(index: usize) -> starknet::StorageBaseAddress {{
+            starknet::storage_base_address_from_felt252(
+                hash::LegacyHash::<usize>::hash({keys_address}, index))
+        }}
+        fn index_address(key: $key_type$) -> starknet::StorageBaseAddress {{
+            starknet::storage_base_address_from_felt252(
+                hash::LegacyHash::<$key_type$>::hash({indices_address}, key))
+        }}
+
+        impl InternalStorageImpl of InternalStorageTrait {{
+            fn address(self: @Storage, key: $key_type$) -> starknet::StorageBaseAddress {{
+                starknet::storage_base_address_from_felt252(
+                    hash::LegacyHash::<$key_type$>::hash({address}, key))
+            }}
+            fn read(self: @Storage, key: $key_type$) -> $value_type$ {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                starknet::StorageAccess::<$value_type$>::read(
+                    address_domain,
+                    self.address(key),
+                ).unwrap_syscall()
+            }}
+            fn write(ref self: Storage, key: $key_type$, value: $value_type$) {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                let index_plus_one = starknet::StorageAccess::<usize>::read(
+                    address_domain,
+                    index_address(key),
+                ).unwrap_syscall();
+                if index_plus_one == 0_u32 {{
+                    // A new key - append it to the keys.
+                    let len = self.len();
+                    starknet::StorageAccess::<$key_type$>::write(
+                        address_domain,
+                        key_address(len),
+                        key,
+                    ).unwrap_syscall();
+                    starknet::StorageAccess::<usize>::write(
+                        address_domain,
+                        index_address(key),
+                        len + 1_u32,
+                    ).unwrap_syscall();
+                    starknet::StorageAccess::<usize>::write(
+                        address_domain,
+                        len_address(),
+                        len + 1_u32,
+                    ).unwrap_syscall();
+                }}
+                starknet::StorageAccess::<$value_type$>::write(
+                    address_domain,
+                    self.address(key),
+                    value,
+                ).unwrap_syscall()
+            }}
+            fn len(self: @Storage) -> usize {{
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                starknet::StorageAccess::<usize>::read(
+                    address_domain,
+                    len_address(),
+                ).unwrap_syscall()
+            }}
+            fn at(self: @Storage, index: usize) -> ($key_type$, $value_type$) {{
+                assert(index < self.len(), 'Index out of bounds');
+                // Only address_domain 0 is currently supported.
+                let address_domain = 0_u32;
+                let key = starknet::StorageAccess::<$key_type$>::read(
+                    address_domain,
+                    key_address(index),
+                ).unwrap_syscall();
+                (key, self.read(key))
+            }}
+            fn entries(self: @Storage) -> Array<($key_type$, $value_type$)> {{
+                let mut entries = array::array_new();
+                let len = self.len();
+                let mut index = 0_u32;
+                loop {{
+                    if index == len {{
+                        break ();
+                    }}
+                    array::ArrayTrait::append(ref entries, self.at(index));
+                    index = index + 1_u32;
+                }};
+                entries
+            }}
+        }}
+    }}"
+    )
+}
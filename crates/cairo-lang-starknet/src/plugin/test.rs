@@ -2,14 +2,18 @@ use std::sync::Arc;
 
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::get_diagnostics_as_string;
+use cairo_lang_compiler::fixes::{apply_edits, collect_fixes};
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_filesystem::cfg::CfgSet;
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_semantic::test_utils::setup_test_module;
 use cairo_lang_test_utils::parse_test_file::TestFileRunner;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use indoc::indoc;
 
-use crate::plugin::StarkNetPlugin;
+use crate::contract::{starknet_keccak, starknet_poseidon, SelectorHash};
+use crate::plugin::{StarkNetPlugin, TestClassHash};
 
 struct ExpandContractTestRunner {
     db: RootDatabase,
@@ -29,6 +33,10 @@ impl Default for ExpandContractTestRunner {
 
 impl TestFileRunner for ExpandContractTestRunner {
     fn run(&mut self, inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+        if let Some(cfg_set) = inputs.get("cfg") {
+            let cfg_set: CfgSet = serde_json::from_str(cfg_set.as_str()).unwrap();
+            self.db.set_cfg_set(Arc::new(cfg_set));
+        }
         let (test_module, _semantic_diagnostics) =
             setup_test_module(&mut self.db, inputs["cairo_code"].as_str()).split();
 
@@ -76,6 +84,326 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         dispatcher: "dispatcher",
         user_defined_types: "user_defined_types",
         l1_handler: "l1_handler",
+        default_entry_point: "default_entry_point",
+        l1_message: "l1_message",
+        deprecated: "deprecated",
+        cfg: "cfg",
+        non_reentrant: "non_reentrant",
+        access_control: "access_control",
+        pausable: "pausable",
+        errors: "errors",
     },
     ExpandContractTestRunner
 );
+
+/// Returns the `TEST_CLASS_HASH` generated for the contract in the given code, if any.
+fn generated_test_class_hash(test_class_hash: TestClassHash, cairo_code: &str) -> Option<String> {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(
+            StarkNetPlugin::default().with_test_class_hash(test_class_hash),
+        ))
+        .build()
+        .unwrap();
+    let test_module = setup_test_module(&mut db, cairo_code).unwrap();
+    let submodule_ids = db.module_submodules_ids(test_module.module_id).unwrap();
+    submodule_ids.into_iter().find_map(|submodule_id| {
+        db.module_files(ModuleId::Submodule(submodule_id)).unwrap().iter().find_map(|file| {
+            db.file_content(*file)?
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("const TEST_CLASS_HASH: felt252 = "))
+                .map(|value| value.trim_end_matches(';').to_string())
+        })
+    })
+}
+
+#[test]
+fn test_test_class_hash() {
+    let contract_code = indoc! {"
+        #[contract]
+        mod TestContract {
+            #[starknet::storage]
+            struct Storage {}
+
+            #[external]
+            fn foo(ref self: Storage) {}
+        }
+    "};
+    // The same contract, with different whitespace and comments.
+    let reformatted_contract_code = indoc! {"
+        #[contract]
+        mod TestContract {
+            #[starknet::storage]
+            struct Storage {}
+            // A comment.
+            #[external]
+            fn foo(ref self: Storage) { }
+        }
+    "};
+
+    assert_ne!(
+        generated_test_class_hash(TestClassHash::ModuleText, contract_code),
+        generated_test_class_hash(TestClassHash::ModuleText, reformatted_contract_code),
+    );
+    let canonical_hash = generated_test_class_hash(TestClassHash::Canonical, contract_code);
+    assert!(canonical_hash.is_some());
+    assert_eq!(
+        canonical_hash,
+        generated_test_class_hash(TestClassHash::Canonical, reformatted_contract_code),
+    );
+    assert_eq!(
+        generated_test_class_hash(TestClassHash::Pinned(1234_u32.into()), contract_code),
+        Some("1234".to_string()),
+    );
+    assert_eq!(
+        generated_test_class_hash(
+            TestClassHash::SierraClassHash([("TestContract".into(), 5678_u32.into())].into()),
+            contract_code
+        ),
+        Some("5678".to_string()),
+    );
+    assert_eq!(
+        generated_test_class_hash(
+            TestClassHash::SierraClassHash([("OtherContract".into(), 5678_u32.into())].into()),
+            contract_code
+        ),
+        None,
+    );
+    assert_eq!(generated_test_class_hash(TestClassHash::Omitted, contract_code), None);
+}
+
+#[test]
+fn test_storage_mutability_lint() {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default().with_storage_mutability_lint()))
+        .build()
+        .unwrap();
+    setup_test_module(
+        &mut db,
+        indoc! {"
+            #[contract]
+            mod TestContract {
+                #[starknet::storage]
+                struct Storage {
+                    value: felt252,
+                }
+
+                #[external]
+                fn get_value(ref self: Storage) -> felt252 {
+                    self.value.read()
+                }
+
+                #[external]
+                fn get_value_view(self: @Storage) -> felt252 {
+                    self.value.read()
+                }
+
+                #[external]
+                #[allow(SN039)]
+                fn get_value_allowed(ref self: Storage) -> felt252 {
+                    self.value.read()
+                }
+
+                #[external]
+                fn set_value(ref self: Storage, value: felt252) {
+                    self.value.write(value);
+                }
+
+                #[external]
+                fn increment(ref self: Storage) {
+                    set_value(ref self, self.value.read() + 1);
+                }
+            }
+        "},
+    )
+    .unwrap();
+    // Only `get_value` and `get_value_allowed` could take the storage as a snapshot, and the lint
+    // is allowed for the latter.
+    assert_eq!(
+        get_diagnostics_as_string(&mut db),
+        indoc! {"
+            warning: Plugin diagnostic: `get_value` takes the storage by `ref` but never modifies it. Consider taking `self: @Storage` to make it a view.
+             --> lib.cairo:9:18
+                fn get_value(ref self: Storage) -> felt252 {
+                             ^***************^
+
+        "}
+    );
+}
+
+#[test]
+fn test_shadowed_storage_variables_lint() {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(
+            StarkNetPlugin::default().with_shadowed_storage_variables_lint(),
+        ))
+        .build()
+        .unwrap();
+    setup_test_module(
+        &mut db,
+        indoc! {"
+            #[contract]
+            mod TestContract {
+                #[starknet::storage]
+                struct Storage {
+                    value: felt252,
+                }
+
+                #[external]
+                fn get_value(self: @Storage) -> felt252 {
+                    let value = self.value.read();
+                    value
+                }
+
+                #[external]
+                fn set_value(ref self: Storage, new_value: felt252) {
+                    self.value.write(new_value);
+                }
+
+                #[external]
+                #[allow(SN044)]
+                fn set_value_allowed(ref self: Storage, value: felt252) {
+                    self.value.write(value);
+                }
+            }
+        "},
+    )
+    .unwrap();
+    assert_eq!(
+        get_diagnostics_as_string(&mut db),
+        indoc! {"
+            warning: Plugin diagnostic: `value` shadows the storage variable of the same name. Consider renaming it.
+             --> lib.cairo:10:13
+                    let value = self.value.read();
+                        ^***^
+
+        "}
+    );
+}
+
+#[test]
+fn test_fixes() {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    setup_test_module(
+        &mut db,
+        indoc! {"
+            #[starknet::interface]
+            trait IContract<TContractState> {
+                fn get_value(me: @TContractState) -> felt252;
+            }
+
+            #[contract]
+            mod TestContract {
+                #[derive(Drop)]
+                struct Storage {
+                    value: felt252,
+                }
+            }
+        "},
+    );
+    let fixes = collect_fixes(&db);
+    let [(file_id, edits)] = fixes.iter().collect::<Vec<_>>()[..] else {
+        panic!("Expected fixes in a single file.");
+    };
+    assert_eq!(
+        apply_edits(&db.file_content(*file_id).unwrap(), edits.clone()),
+        (
+            indoc! {"
+                #[starknet::interface]
+                trait IContract<TContractState> {
+                    fn get_value(self: @TContractState) -> felt252;
+                }
+
+                #[contract]
+                mod TestContract {
+                    #[derive(Drop)]
+                    #[starknet::storage]
+                    struct Storage {
+                        value: felt252,
+                    }
+                }
+            "}
+            .into(),
+            2
+        )
+    );
+}
+
+#[test]
+fn test_namespaced_event_selectors() {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default().with_namespaced_event_selectors()))
+        .build()
+        .unwrap();
+    let test_module = setup_test_module(
+        &mut db,
+        indoc! {"
+            #[contract]
+            mod MyToken {
+                #[starknet::storage]
+                struct Storage {}
+
+                #[event]
+                fn Transfer(from: felt252, to: felt252) {}
+            }
+        "},
+    )
+    .unwrap();
+    let submodule_id = db.module_submodules_ids(test_module.module_id).unwrap()[0];
+    let files = db.module_files(ModuleId::Submodule(submodule_id)).unwrap();
+    let contract_code = files
+        .iter()
+        .map(|file| db.file_content(*file).unwrap().as_ref().clone())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let namespaced_key = format!("0x{:x}", starknet_keccak("MyToken::Transfer".as_bytes()));
+    assert!(contract_code.contains(&format!("array::array_append(ref __keys, {namespaced_key});")));
+    assert!(contract_code.contains("#[event(namespace: 'MyToken')]"));
+}
+
+#[test]
+fn test_poseidon_selector_hash() {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(
+            StarkNetPlugin::default().with_selector_hash(SelectorHash::Poseidon),
+        ))
+        .build()
+        .unwrap();
+    let test_module = setup_test_module(
+        &mut db,
+        indoc! {"
+            #[contract]
+            mod MyToken {
+                #[starknet::storage]
+                struct Storage {
+                    balance: felt252,
+                }
+
+                #[event]
+                fn Transfer(from: felt252, to: felt252) {}
+            }
+        "},
+    )
+    .unwrap();
+    let submodule_id = db.module_submodules_ids(test_module.module_id).unwrap()[0];
+    let files = db.module_files(ModuleId::Submodule(submodule_id)).unwrap();
+    let contract_code = files
+        .iter()
+        .map(|file| db.file_content(*file).unwrap().as_ref().clone())
+        .collect::<Vec<_>>()
+        .join("\n");
+    for name in ["balance", "Transfer"] {
+        let poseidon_hash = format!("0x{:x}", starknet_poseidon(name.as_bytes()));
+        let keccak_hash = format!("0x{:x}", starknet_keccak(name.as_bytes()));
+        assert!(contract_code.contains(&poseidon_hash));
+        assert!(!contract_code.contains(&keccak_hash));
+    }
+}
@@ -64,3 +64,27 @@ pub fn maybe_strip_underscore(s: &str) -> &str {
         None => s,
     }
 }
+
+/// Returns the text replacing a terminal of an item to annotate the item with the given attribute
+/// just before the terminal. The attribute is on a line of its own, with the indentation of the
+/// terminal, if the terminal is indented at the start of a line.
+pub fn with_attribute_before(
+    db: &dyn SyntaxGroup,
+    terminal: &impl Terminal,
+    attribute: &str,
+) -> String {
+    let leading_trivia = terminal
+        .as_syntax_node()
+        .children(db)
+        .next()
+        .map(|trivia| trivia.get_text(db))
+        .unwrap_or_default();
+    // The newline ending the previous line is usually in the trailing trivia of the previous token.
+    let indentation = &leading_trivia[leading_trivia.rfind('\n').map_or(0, |end| end + 1)..];
+    let separator = if !leading_trivia.is_empty() && indentation.trim().is_empty() {
+        format!("\n{indentation}")
+    } else {
+        " ".to_string()
+    };
+    format!("#[{attribute}]{separator}{}", terminal.text(db))
+}
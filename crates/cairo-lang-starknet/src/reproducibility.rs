@@ -0,0 +1,172 @@
+//! Auditing the reproducibility of compiled artifacts, on which verifying a deployed class against
+//! its source depends: an artifact compiled twice from the same source should be identical, and
+//! should not depend on the machine or the time of its compilation.
+
+use std::fmt;
+
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "reproducibility_test.rs"]
+mod test;
+
+/// A source of nondeterminism in a compiled artifact. The location of the value in the artifact is
+/// given as a path of object keys and array indices, e.g. `abi[2].name`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Nondeterminism {
+    /// The value differs between two compilations of the artifact, e.g. because it depends on the
+    /// iteration order of a hash map.
+    Mismatch { location: String, first: String, second: String },
+    /// The value is an absolute path, which depends on the location of the compiled project.
+    AbsolutePath { location: String, path: String },
+    /// The value is a timestamp, which depends on the time of the compilation.
+    Timestamp { location: String, timestamp: String },
+}
+impl fmt::Display for Nondeterminism {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Nondeterminism::Mismatch { location, first, second } => {
+                write!(f, "`{location}` differs between compilations: {first} and {second}.")
+            }
+            Nondeterminism::AbsolutePath { location, path } => {
+                write!(f, "`{location}` holds the absolute path `{path}`.")
+            }
+            Nondeterminism::Timestamp { location, timestamp } => {
+                write!(f, "`{location}` holds the timestamp `{timestamp}`.")
+            }
+        }
+    }
+}
+
+/// Returns the sources of nondeterminism in two compilations of an artifact, given in their JSON
+/// form: the values that differ between them, and the absolute paths and the timestamps they hold.
+///
+/// Only the first differing item of an array is reported, as an item inserted into an array, or
+/// reordered, makes all the items after it differ. Each absolute path and timestamp is reported
+/// once, at its first location.
+pub fn find_nondeterminism(first: &Value, second: &Value) -> Vec<Nondeterminism> {
+    let mut found = vec![];
+    find_mismatches("", first, second, &mut found);
+    find_machine_dependent_values("", first, &mut found);
+    found
+}
+
+/// Adds the values that differ between `first` and `second`, at the given location, to `found`.
+fn find_mismatches(location: &str, first: &Value, second: &Value, found: &mut Vec<Nondeterminism>) {
+    match (first, second) {
+        (Value::Object(first_object), Value::Object(second_object)) => {
+            for (key, first_value) in first_object {
+                let key_location = object_key_location(location, key);
+                match second_object.get(key) {
+                    Some(second_value) => {
+                        find_mismatches(&key_location, first_value, second_value, found)
+                    }
+                    None => found.push(mismatch(key_location, first_value, &Value::Null)),
+                }
+            }
+            for (key, second_value) in second_object {
+                if !first_object.contains_key(key) {
+                    let key_location = object_key_location(location, key);
+                    found.push(mismatch(key_location, &Value::Null, second_value));
+                }
+            }
+        }
+        (Value::Array(first_items), Value::Array(second_items)) => {
+            let n_found = found.len();
+            for (index, (first_item, second_item)) in
+                first_items.iter().zip(second_items).enumerate()
+            {
+                find_mismatches(&format!("{location}[{index}]"), first_item, second_item, found);
+                if found.len() > n_found {
+                    return;
+                }
+            }
+            if first_items.len() != second_items.len() {
+                found.push(Nondeterminism::Mismatch {
+                    location: display_location(location),
+                    first: format!("{} items", first_items.len()),
+                    second: format!("{} items", second_items.len()),
+                });
+            }
+        }
+        _ if first != second => found.push(mismatch(location.into(), first, second)),
+        _ => {}
+    }
+}
+
+/// Adds the absolute paths and the timestamps in `value`, at the given location, to `found`.
+fn find_machine_dependent_values(location: &str, value: &Value, found: &mut Vec<Nondeterminism>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                find_machine_dependent_values(&object_key_location(location, key), value, found);
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                find_machine_dependent_values(&format!("{location}[{index}]"), item, found);
+            }
+        }
+        Value::String(string) if is_absolute_path(string) => {
+            let is_new = !found.iter().any(|found| {
+                matches!(found, Nondeterminism::AbsolutePath { path, .. } if path == string)
+            });
+            if is_new {
+                found.push(Nondeterminism::AbsolutePath {
+                    location: display_location(location),
+                    path: string.clone(),
+                });
+            }
+        }
+        Value::String(string) if is_timestamp(string) => {
+            let is_new = !found.iter().any(|found| {
+                matches!(found, Nondeterminism::Timestamp { timestamp, .. } if timestamp == string)
+            });
+            if is_new {
+                found.push(Nondeterminism::Timestamp {
+                    location: display_location(location),
+                    timestamp: string.clone(),
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+fn mismatch(location: String, first: &Value, second: &Value) -> Nondeterminism {
+    Nondeterminism::Mismatch {
+        location: display_location(&location),
+        first: first.to_string(),
+        second: second.to_string(),
+    }
+}
+
+fn object_key_location(location: &str, key: &str) -> String {
+    if location.is_empty() { key.into() } else { format!("{location}.{key}") }
+}
+
+/// Returns the location to display, where the empty location is the root of the artifact.
+fn display_location(location: &str) -> String {
+    if location.is_empty() { "<root>".into() } else { location.into() }
+}
+
+/// Returns whether the string is an absolute path, on Unix or on Windows.
+fn is_absolute_path(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    match bytes {
+        [b'/', second, ..] => *second != b'/' && !string.contains(char::is_whitespace),
+        [drive, b':', b'\\' | b'/', ..] => drive.is_ascii_alphabetic(),
+        _ => false,
+    }
+}
+
+/// Returns whether the string starts with an ISO 8601 date and time, e.g. `2023-05-01T12:30`.
+fn is_timestamp(string: &str) -> bool {
+    let Some(prefix) = string.as_bytes().get(..16) else { return false };
+    prefix.iter().enumerate().all(|(index, byte)| match index {
+        4 | 7 => *byte == b'-',
+        10 => *byte == b'T' || *byte == b' ',
+        13 => *byte == b':',
+        _ => byte.is_ascii_digit(),
+    })
+}
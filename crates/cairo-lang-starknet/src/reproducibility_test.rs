@@ -0,0 +1,62 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use super::{find_nondeterminism, Nondeterminism};
+
+#[test]
+fn test_find_nondeterminism() {
+    let first = json!({
+        "sierra_program": ["0x1", "0x2", "0x3", "0x4"],
+        "abi": [{ "name": "foo" }, { "name": "bar" }],
+        "sierra_program_source_map": [
+            { "file": "/home/user/project/src/lib.cairo" },
+            { "file": "/home/user/project/src/lib.cairo" },
+        ],
+        "built_at": "2023-05-01T12:30:00Z",
+        "debug_name": "core::felt252_add",
+    });
+    let second = json!({
+        "sierra_program": ["0x1", "0x3", "0x2", "0x4"],
+        "abi": [{ "name": "foo" }, { "name": "bar" }],
+        "sierra_program_source_map": [
+            { "file": "/home/user/project/src/lib.cairo" },
+            { "file": "/home/user/project/src/lib.cairo" },
+        ],
+        "built_at": "2023-05-01T12:30:01Z",
+        "debug_name": "core::felt252_add",
+    });
+    assert_eq!(
+        find_nondeterminism(&first, &second),
+        [
+            Nondeterminism::Mismatch {
+                location: "built_at".into(),
+                first: "\"2023-05-01T12:30:00Z\"".into(),
+                second: "\"2023-05-01T12:30:01Z\"".into(),
+            },
+            Nondeterminism::Mismatch {
+                location: "sierra_program[1]".into(),
+                first: "\"0x2\"".into(),
+                second: "\"0x3\"".into(),
+            },
+            Nondeterminism::Timestamp {
+                location: "built_at".into(),
+                timestamp: "2023-05-01T12:30:00Z".into(),
+            },
+            Nondeterminism::AbsolutePath {
+                location: "sierra_program_source_map[0].file".into(),
+                path: "/home/user/project/src/lib.cairo".into(),
+            },
+        ]
+    );
+    assert_eq!(find_nondeterminism(&first["abi"], &second["abi"]), []);
+    assert_eq!(
+        find_nondeterminism(&json!({ "a": [1, 2] }), &json!({ "a": [1, 2, 3], "b": 4 }))
+            .iter()
+            .map(|found| found.to_string())
+            .collect::<Vec<_>>(),
+        [
+            "`a` differs between compilations: 2 items and 3 items.",
+            "`b` differs between compilations: null and 4.",
+        ]
+    );
+}
@@ -0,0 +1,131 @@
+use anyhow::{Context, Result};
+use cairo_lang_sierra::debug_info::DebugInfo;
+use cairo_lang_sierra::ProgramParser;
+use cairo_lang_sierra_generator::canonical_id_replacer::CanonicalReplacer;
+use cairo_lang_sierra_generator::replace_ids::SierraIdReplacer;
+use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+use crate::class_hash::set_sierra_class_hash;
+use crate::compiler_version;
+use crate::contract_class::{
+    ContractClass, ContractEntryPoint, ContractEntryPoints, DEFAULT_CONTRACT_CLASS_VERSION,
+};
+use crate::felt252_serde::{sierra_from_felt252s, sierra_to_felt252s};
+
+#[cfg(test)]
+#[path = "sierra_text_test.rs"]
+mod test;
+
+/// The entry points of a contract class by type, referring to the functions of its Sierra program
+/// by name rather than by index, to accompany the program in its text form.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntryPointsManifest {
+    #[serde(rename = "EXTERNAL")]
+    pub external: Vec<ManifestEntryPoint>,
+    #[serde(rename = "L1_HANDLER")]
+    pub l1_handler: Vec<ManifestEntryPoint>,
+    #[serde(rename = "CONSTRUCTOR")]
+    pub constructor: Vec<ManifestEntryPoint>,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntryPoint {
+    /// A field element that encodes the signature of the called function.
+    #[serde(serialize_with = "serialize_big_uint", deserialize_with = "deserialize_big_uint")]
+    pub selector: BigUint,
+    /// The name of the user function in the Sierra program.
+    pub function: SmolStr,
+}
+
+/// Converts the contract class into its Sierra program in text form, named by the debug info of
+/// the class if it has any, and the manifest of its entry points.
+pub fn contract_class_to_sierra_text(
+    contract_class: &ContractClass,
+) -> Result<(String, EntryPointsManifest)> {
+    let (_, _, mut program) = sierra_from_felt252s(&contract_class.sierra_program)
+        .with_context(|| "Failed to decode the Sierra program.")?;
+    if let Some(debug_info) = &contract_class.sierra_program_debug_info {
+        debug_info.populate(&mut program);
+    }
+    let to_manifest_entry_points = |entry_points: &[ContractEntryPoint]| {
+        entry_points
+            .iter()
+            .map(|entry_point| {
+                let function = program.funcs.get(entry_point.function_idx).with_context(|| {
+                    format!("Invalid entry point 0x{:x}.", entry_point.selector)
+                })?;
+                Ok(ManifestEntryPoint {
+                    selector: entry_point.selector.clone(),
+                    function: function.id.to_string().into(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+    let entry_points = &contract_class.entry_points_by_type;
+    let manifest = EntryPointsManifest {
+        external: to_manifest_entry_points(&entry_points.external)?,
+        l1_handler: to_manifest_entry_points(&entry_points.l1_handler)?,
+        constructor: to_manifest_entry_points(&entry_points.constructor)?,
+    };
+    Ok((program.to_string(), manifest))
+}
+
+/// Converts a Sierra program in text form, and the manifest of its entry points, into a contract
+/// class, with the names in the program, as normalized by the Sierra parser, as its debug info.
+/// The class has no ABI, as it cannot be deduced from the program.
+pub fn contract_class_from_sierra_text(
+    sierra_text: &str,
+    manifest: &EntryPointsManifest,
+) -> Result<ContractClass> {
+    let program = ProgramParser::new()
+        .parse(sierra_text)
+        .map_err(|err| anyhow::anyhow!("Failed to parse the Sierra program: {err}"))?;
+    let to_entry_points = |manifest_entry_points: &[ManifestEntryPoint]| {
+        let mut entry_points = manifest_entry_points
+            .iter()
+            .map(|manifest_entry_point| {
+                let function_idx = program
+                    .funcs
+                    .iter()
+                    .position(|function| function.id.to_string() == manifest_entry_point.function)
+                    .with_context(|| {
+                        format!("Function `{}` not found.", manifest_entry_point.function)
+                    })?;
+                Ok(ContractEntryPoint {
+                    selector: manifest_entry_point.selector.clone(),
+                    function_idx,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        entry_points.sort_by(|a, b| a.selector.cmp(&b.selector));
+        Ok::<_, anyhow::Error>(entry_points)
+    };
+    let entry_points_by_type = ContractEntryPoints {
+        external: to_entry_points(&manifest.external)?,
+        l1_handler: to_entry_points(&manifest.l1_handler)?,
+        constructor: to_entry_points(&manifest.constructor)?,
+    };
+
+    let program = CanonicalReplacer::from_program(&program).apply(&program);
+    let mut contract_class = ContractClass {
+        sierra_program: sierra_to_felt252s(
+            compiler_version::current_sierra_version_id(),
+            compiler_version::current_compiler_version_id(),
+            &program,
+        )?,
+        sierra_program_debug_info: Some(DebugInfo::extract(&program)),
+        contract_class_version: DEFAULT_CONTRACT_CLASS_VERSION.to_string(),
+        entry_points_by_type,
+        abi: None,
+        docs: None,
+        class_hash: None,
+        metadata: None,
+        sierra_program_source_map: None,
+    };
+    contract_class.validate()?;
+    set_sierra_class_hash(&mut contract_class)?;
+    Ok(contract_class)
+}
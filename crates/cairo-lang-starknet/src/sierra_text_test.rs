@@ -0,0 +1,53 @@
+use indoc::indoc;
+use test_case::test_case;
+
+use crate::sierra_text::{
+    contract_class_from_sierra_text, contract_class_to_sierra_text, EntryPointsManifest,
+    ManifestEntryPoint,
+};
+use crate::test_utils::{get_example_file_path, get_test_contract};
+
+/// Tests that converting the contract class of <test_case>.cairo into Sierra text results in the
+/// program in <test_case>.sierra, and that converting it back results in the same program and entry
+/// points.
+#[test_case("test_contract")]
+#[test_case("erc20")]
+fn test_sierra_text_round_trip(example_file_name: &str) {
+    let contract_class = get_test_contract(format!("{example_file_name}.cairo").as_str());
+    let (sierra_text, manifest) = contract_class_to_sierra_text(&contract_class).unwrap();
+    assert_eq!(
+        sierra_text,
+        std::fs::read_to_string(get_example_file_path(&format!("{example_file_name}.sierra")))
+            .unwrap()
+    );
+
+    let converted_class = contract_class_from_sierra_text(&sierra_text, &manifest).unwrap();
+    assert_eq!(converted_class.sierra_program, contract_class.sierra_program);
+    // The names of the types are normalized by the parser, so only the names of the functions are
+    // kept as is.
+    assert_eq!(
+        converted_class.sierra_program_debug_info.unwrap().user_func_names,
+        contract_class.sierra_program_debug_info.unwrap().user_func_names
+    );
+    assert_eq!(converted_class.entry_points_by_type, contract_class.entry_points_by_type);
+    assert_eq!(converted_class.abi, None);
+}
+
+#[test]
+fn test_sierra_text_missing_function() {
+    let sierra_text = indoc! {"
+        type felt252 = felt252;
+
+        return();
+
+        foo@0() -> ();
+    "};
+    let manifest = EntryPointsManifest {
+        external: vec![ManifestEntryPoint { selector: 1_u32.into(), function: "bar".into() }],
+        ..EntryPointsManifest::default()
+    };
+    assert_eq!(
+        contract_class_from_sierra_text(sierra_text, &manifest).unwrap_err().to_string(),
+        "Function `bar` not found."
+    );
+}
@@ -0,0 +1,120 @@
+use anyhow::Context;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_filesystem::ids::FileLongId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_sierra::program::{Program, StatementIdx};
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::statements_locations::get_statements_locations;
+use serde::{Deserialize, Serialize};
+
+use crate::casm_contract_class::{compile_to_casm, decode_sierra_program};
+use crate::contract_class::ContractClass;
+
+#[cfg(test)]
+#[path = "source_map_test.rs"]
+mod test;
+
+/// A position in a source file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourcePosition {
+    /// The line number, starting from 1.
+    pub line: usize,
+    /// The column number, starting from 1.
+    pub col: usize,
+}
+
+/// A span of Cairo code.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceSpan {
+    /// The path of the file, or the name of the virtual file for code generated by plugins.
+    pub file: String,
+    pub start: SourcePosition,
+    pub end: SourcePosition,
+}
+
+/// Returns the span of the Cairo code each statement of a Sierra program was generated from, or
+/// `None` for the statements whose location is unknown.
+///
+/// `program` is expected to be the result of [SierraGenGroup::get_sierra_program_for_functions],
+/// and `origins` to hold the index in `program` of each statement of the program compiled from it,
+/// as returned by the passes transforming it.
+pub fn statements_source_spans(
+    db: &dyn SierraGenGroup,
+    program: &Program,
+    origins: &[StatementIdx],
+) -> Maybe<Vec<Option<SourceSpan>>> {
+    let locations = get_statements_locations(db, program)?;
+    Ok(origins
+        .iter()
+        .map(|origin| {
+            let location = locations[origin.0]?.diagnostic_location(db.upcast());
+            let file = match db.lookup_intern_file(location.file_id) {
+                FileLongId::OnDisk(path) => path.display().to_string(),
+                FileLongId::Virtual(virtual_file) => virtual_file.name.to_string(),
+            };
+            let position = |offset: TextOffset| {
+                let position = offset.position_in_file(db.upcast(), location.file_id)?;
+                Some(SourcePosition { line: position.line + 1, col: position.col + 1 })
+            };
+            Some(SourceSpan {
+                file,
+                start: position(location.span.start)?,
+                end: position(location.span.end)?,
+            })
+        })
+        .collect())
+}
+
+/// The debug info of the CASM code compiled from a contract class: the range of the bytecode
+/// compiled from each Sierra statement of the class, along with the Cairo code the statement was
+/// generated from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CasmSourceMap {
+    pub statements: Vec<StatementSourceMap>,
+}
+
+/// The debug info of a single Sierra statement of a contract class.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatementSourceMap {
+    /// The index of the statement in the Sierra program.
+    pub statement_idx: usize,
+    /// The offset in the bytecode of the code compiled from the statement.
+    pub pc_start: usize,
+    /// The offset in the bytecode following the code compiled from the statement. Equal to
+    /// `pc_start` for statements compiled to no code.
+    pub pc_end: usize,
+    /// The Cairo code the statement was generated from, if known.
+    pub location: Option<SourceSpan>,
+}
+
+impl CasmSourceMap {
+    /// Returns the source map of the CASM code compiled from a contract class, as compiled by
+    /// [crate::casm_contract_class::CasmContractClass::from_contract_class].
+    /// The class must have been compiled along with its source map, see
+    /// [cairo_lang_compiler::CompilerConfig::add_source_map].
+    pub fn from_contract_class(contract_class: &ContractClass) -> anyhow::Result<Self> {
+        let locations = contract_class
+            .sierra_program_source_map
+            .as_ref()
+            .with_context(|| "The contract class has no source map.")?;
+        let program = decode_sierra_program(contract_class)?;
+        if locations.len() != program.statements.len() {
+            anyhow::bail!("The source map of the contract class does not match its program.");
+        }
+        let (_, cairo_program) = compile_to_casm(&program, &contract_class.entry_points_by_type)?;
+        // The statements info has an additional entry, for the end of the program.
+        let statements_info = &cairo_program.debug_info.sierra_statement_info;
+        Ok(Self {
+            statements: locations
+                .iter()
+                .enumerate()
+                .map(|(statement_idx, location)| StatementSourceMap {
+                    statement_idx,
+                    pc_start: statements_info[statement_idx].code_offset,
+                    pc_end: statements_info[statement_idx + 1].code_offset,
+                    location: location.clone(),
+                })
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use cairo_lang_compiler::CompilerConfig;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+
+use super::CasmSourceMap;
+use crate::allowed_libfuncs::BUILTIN_ALL_LIBFUNCS_LIST;
+use crate::casm_contract_class::CasmContractClass;
+use crate::test_utils::{compile_test_contract, get_test_contract};
+
+#[test]
+fn test_casm_source_map() {
+    let contract_class = compile_test_contract(
+        "test_contract.cairo",
+        CompilerConfig {
+            add_source_map: true,
+            fold_constants: true,
+            allowed_libfuncs_list_name: Some(BUILTIN_ALL_LIBFUNCS_LIST.to_string()),
+            ..CompilerConfig::default()
+        },
+    );
+    let source_map = CasmSourceMap::from_contract_class(&contract_class).unwrap();
+    let casm_contract_class =
+        CasmContractClass::from_contract_class(contract_class, false).unwrap();
+
+    // The code of the statements covers the bytecode, in the order of the statements.
+    let statements = &source_map.statements;
+    assert_eq!(statements.first().unwrap().pc_start, 0);
+    for (statement, next_statement) in statements.iter().tuple_windows() {
+        assert_eq!(statement.pc_end, next_statement.pc_start);
+    }
+    assert_eq!(statements.last().unwrap().pc_end, casm_contract_class.bytecode.len());
+
+    let contract_locations = statements
+        .iter()
+        .filter_map(|statement| statement.location.as_ref())
+        .filter(|location| location.file.ends_with("test_contract.cairo"))
+        .collect_vec();
+    assert!(!contract_locations.is_empty());
+    for location in contract_locations {
+        assert!((location.start.line, location.start.col) <= (location.end.line, location.end.col));
+    }
+}
+
+#[test]
+fn test_casm_source_map_without_source_map() {
+    let contract_class = get_test_contract("test_contract.cairo");
+    assert_eq!(
+        CasmSourceMap::from_contract_class(&contract_class).unwrap_err().to_string(),
+        "The contract class has no source map."
+    );
+}
@@ -0,0 +1,124 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::project::setup_project;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LanguageElementId, TopLevelLanguageElementId};
+use cairo_lang_diagnostics::{Maybe, ToOption};
+use cairo_lang_plugins::plugins::is_enabled_by_cfg;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint};
+use num_bigint::BigUint;
+use num_traits::CheckedSub;
+use serde::{Deserialize, Serialize};
+
+use crate::contract::{find_contracts, ContractDeclaration};
+use crate::plugin::consts::STORAGE_STRUCT_NAME;
+use crate::plugin::StarkNetPlugin;
+
+#[cfg(test)]
+#[path = "storage_layout_test.rs"]
+mod test;
+
+/// The maximal offset of a storage address from the base address of its variable, as with the
+/// felts of a value that spans several storage addresses.
+const MAX_STORAGE_OFFSET: u32 = 255;
+
+/// The layout of the storage of a contract: the base address of each of its storage variables.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageLayout {
+    pub variables: Vec<StorageVariable>,
+}
+impl StorageLayout {
+    pub fn json(&self) -> String {
+        serde_json::to_string_pretty(&self).unwrap()
+    }
+
+    /// Returns the storage variable the given address belongs to, along with the offset of the
+    /// address from the base address of the variable.
+    /// The addresses of the values of mappings are hashes of their keys, so they are not matched to
+    /// their variables.
+    pub fn variable_at(&self, address: &BigUint) -> Option<(&StorageVariable, u8)> {
+        self.variables.iter().find_map(|variable| {
+            let offset = address.checked_sub(&variable.address)?;
+            if offset > BigUint::from(MAX_STORAGE_OFFSET) {
+                return None;
+            }
+            Some((variable, u8::try_from(offset).ok()?))
+        })
+    }
+}
+
+/// A storage variable of a contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageVariable {
+    pub name: String,
+    /// The type of the variable, as written in the storage struct.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The base address of the variable, from which the addresses of its values are derived.
+    #[serde(serialize_with = "serialize_big_uint", deserialize_with = "deserialize_big_uint")]
+    pub address: BigUint,
+}
+
+/// Extracts the storage layout of the contract from the members of its storage struct.
+pub fn extract_storage_layout(
+    db: &dyn DefsGroup,
+    contract: &ContractDeclaration,
+) -> Maybe<StorageLayout> {
+    let syntax_db = db.upcast();
+    let parent_module_id = contract.submodule_id.parent_module(db);
+    let module_ast = db.module_submodules(parent_module_id)?[contract.submodule_id].clone();
+    let mut layout = StorageLayout::default();
+    let ast::MaybeModuleBody::Some(body) = module_ast.body(syntax_db) else {
+        return Ok(layout);
+    };
+
+    for item in body.items(syntax_db).elements(syntax_db) {
+        if !is_enabled_by_cfg(syntax_db, &item) {
+            continue;
+        }
+        let ast::Item::Struct(item_struct) = &item else { continue; };
+        if item_struct.name(syntax_db).text(syntax_db) != STORAGE_STRUCT_NAME {
+            continue;
+        }
+        for member in item_struct.members(syntax_db).elements(syntax_db) {
+            let name = member.name(syntax_db).text(syntax_db);
+            layout.variables.push(StorageVariable {
+                address: contract.selector_hash.hash(name.as_bytes()),
+                name: name.into(),
+                ty: member
+                    .type_clause(syntax_db)
+                    .ty(syntax_db)
+                    .as_syntax_node()
+                    .get_text_without_trivia(syntax_db),
+            });
+        }
+    }
+    Ok(layout)
+}
+
+/// Extracts the storage layout of the contract given by path.
+/// Errors if there is ambiguity.
+pub fn storage_layout_in_path(path: &Path, contract_path: Option<&str>) -> Result<StorageLayout> {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()?;
+    let main_crate_ids = setup_project(&mut db, path)?;
+    DiagnosticsReporter::stderr().ensure(&db)?;
+
+    let mut contracts = find_contracts(&db, &main_crate_ids);
+    if let Some(contract_path) = contract_path {
+        contracts.retain(|contract| contract.submodule_id.full_path(&db) == contract_path);
+    }
+    let [contract] = &contracts[..] else {
+        anyhow::bail!("Expected a single contract, found {}.", contracts.len());
+    };
+    extract_storage_layout(&db, contract)
+        .to_option()
+        .with_context(|| "Failed to extract the storage layout.")
+}
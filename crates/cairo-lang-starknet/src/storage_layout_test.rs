@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_semantic::test_utils::setup_test_crate;
+use indoc::indoc;
+use num_bigint::BigUint;
+use pretty_assertions::assert_eq;
+
+use crate::contract::{find_contracts, starknet_keccak};
+use crate::plugin::StarkNetPlugin;
+use crate::storage_layout::{extract_storage_layout, StorageLayout, StorageVariable};
+
+#[test]
+fn test_extract_storage_layout() {
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let _crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[contract]
+            mod Token {
+                #[starknet::storage]
+                struct Storage {
+                    total_supply: u256,
+                    balances: LegacyMap::<ContractAddress, u256>,
+                }
+            }
+        "},
+    );
+
+    let contracts = find_contracts(db, &db.crates());
+    assert_eq!(
+        extract_storage_layout(db, &contracts[0]).unwrap(),
+        StorageLayout {
+            variables: vec![
+                StorageVariable {
+                    name: "total_supply".into(),
+                    ty: "u256".into(),
+                    address: starknet_keccak(b"total_supply"),
+                },
+                StorageVariable {
+                    name: "balances".into(),
+                    ty: "LegacyMap::<ContractAddress, u256>".into(),
+                    address: starknet_keccak(b"balances"),
+                },
+            ],
+        }
+    );
+}
+
+#[test]
+fn test_variable_at() {
+    let layout = StorageLayout {
+        variables: vec![StorageVariable {
+            name: "total_supply".into(),
+            ty: "u256".into(),
+            address: BigUint::from(1000_u32),
+        }],
+    };
+    let total_supply = &layout.variables[0];
+    assert_eq!(layout.variable_at(&BigUint::from(1000_u32)), Some((total_supply, 0)));
+    assert_eq!(layout.variable_at(&BigUint::from(1001_u32)), Some((total_supply, 1)));
+    assert_eq!(layout.variable_at(&BigUint::from(1255_u32)), Some((total_supply, 255)));
+    assert_eq!(layout.variable_at(&BigUint::from(1256_u32)), None);
+    assert_eq!(layout.variable_at(&BigUint::from(999_u32)), None);
+}
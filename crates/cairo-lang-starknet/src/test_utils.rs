@@ -33,21 +33,27 @@ static SHARED_DB: Lazy<Mutex<RootDatabase>> = Lazy::new(|| {
 
 /// Returns the compiled test contract, with replaced ids.
 pub fn get_test_contract(example_file_name: &str) -> crate::contract_class::ContractClass {
-    let path = get_example_file_path(example_file_name);
-    let mut locked_db = test_lock(&SHARED_DB);
-    // Setting up the contract path.
-    let main_crate_ids =
-        setup_project(locked_db.deref_mut(), Path::new(&path)).expect("failed to setup project");
-    let db = locked_db.snapshot();
-    compile_contract_in_prepared_db(
-        &db,
-        None,
-        main_crate_ids,
+    compile_test_contract(
+        example_file_name,
         CompilerConfig {
             replace_ids: true,
             allowed_libfuncs_list_name: Some(BUILTIN_ALL_LIBFUNCS_LIST.to_string()),
             ..CompilerConfig::default()
         },
     )
-    .expect("compile_path failed")
+}
+
+/// Returns the test contract compiled with the given configuration.
+pub fn compile_test_contract(
+    example_file_name: &str,
+    compiler_config: CompilerConfig<'_>,
+) -> crate::contract_class::ContractClass {
+    let path = get_example_file_path(example_file_name);
+    let mut locked_db = test_lock(&SHARED_DB);
+    // Setting up the contract path.
+    let main_crate_ids =
+        setup_project(locked_db.deref_mut(), Path::new(&path)).expect("failed to setup project");
+    let db = locked_db.snapshot();
+    compile_contract_in_prepared_db(&db, None, main_crate_ids, compiler_config)
+        .expect("compile_path failed")
 }
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use cairo_felt::Felt252;
+use cairo_lang_runner::{ResourceLimits, RunResultValue, SierraCasmRunner, StarknetState};
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
+use cairo_lang_starknet::contract::ContractInfo;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use colored::{ColoredString, Colorize};
+use num_traits::ToPrimitive;
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
+
+use crate::format_values;
+use crate::test_config::BenchConfig;
+
+/// The default number of runs of a benchmark.
+pub const DEFAULT_BENCH_RUNS: usize = 10;
+
+/// The statistics of a measurement over the runs of a benchmark.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+}
+impl BenchStats {
+    /// Computes the statistics of the given non-empty measurements.
+    fn new(values: &[usize]) -> Self {
+        Self {
+            min: *values.iter().min().unwrap(),
+            max: *values.iter().max().unwrap(),
+            mean: values.iter().sum::<usize>() as f64 / values.len() as f64,
+        }
+    }
+}
+
+/// The measurements of the runs of a benchmark.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BenchResult {
+    /// The full name of the benchmark.
+    pub name: String,
+    /// The number of runs of the benchmark.
+    pub runs: usize,
+    /// The number of steps of the runs, including the steps of the contract calls.
+    pub steps: BenchStats,
+    /// The gas used by the runs, if the benchmark was run with available gas.
+    pub gas: Option<BenchStats>,
+}
+
+/// Runs each of the benchmarks repeatedly from the given initial Starknet state, and returns their
+/// measurements sorted by name. Fails if a run of a benchmark panics or exceeds the resource
+/// limits.
+pub fn run_benches(
+    named_benches: Vec<(String, BenchConfig)>,
+    sierra_program: cairo_lang_sierra::program::Program,
+    function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
+    contracts_info: OrderedHashMap<Felt252, ContractInfo>,
+    initial_state: StarknetState,
+    resource_limits: ResourceLimits,
+) -> Result<Vec<BenchResult>> {
+    let runner = SierraCasmRunner::new(
+        sierra_program,
+        Some(MetadataComputationConfig { function_set_costs }),
+        contracts_info,
+    )
+    .with_context(|| "Failed setting up runner.")?
+    .with_resource_limits(resource_limits);
+    println!("running {} benchmarks", named_benches.len());
+    let mut results = named_benches
+        .into_par_iter()
+        .map(|(name, bench)| run_bench(&runner, name, &bench, &initial_state))
+        .collect::<Result<Vec<_>>>()?;
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(results)
+}
+
+/// Runs a single benchmark repeatedly and measures its runs.
+fn run_bench(
+    runner: &SierraCasmRunner,
+    name: String,
+    bench: &BenchConfig,
+    initial_state: &StarknetState,
+) -> Result<BenchResult> {
+    let func = runner.find_function(name.as_str())?;
+    let mut steps = vec![];
+    let mut gas = vec![];
+    for _ in 0..bench.runs {
+        let result = runner
+            .run_function(func, &[], bench.available_gas, initial_state.clone())
+            .with_context(|| format!("Failed to run the benchmark `{name}`."))?;
+        if let RunResultValue::Panic(values) = &result.value {
+            bail!("Benchmark `{name}` panicked with {}.", format_values(values));
+        }
+        steps.push(result.used_resources.n_steps);
+        gas.extend(bench.available_gas.zip(result.gas_counter.as_ref()).map(
+            |(available_gas, remaining)| {
+                available_gas - remaining.to_usize().expect("Remaining gas out of range.")
+            },
+        ));
+    }
+    Ok(BenchResult {
+        name,
+        runs: bench.runs,
+        steps: BenchStats::new(&steps),
+        gas: if gas.is_empty() { None } else { Some(BenchStats::new(&gas)) },
+    })
+}
+
+/// Prints the measurements of the benchmarks as a table, along with the change of their mean
+/// steps and gas from the baseline, if given. Returns the names of the benchmarks whose mean steps
+/// or gas increased from the baseline.
+pub fn print_bench_report<'a>(
+    results: &'a [BenchResult],
+    baseline: Option<&HashMap<String, BenchResult>>,
+) -> Vec<&'a str> {
+    let name_width = results.iter().map(|result| result.name.len()).chain([5]).max().unwrap();
+    let mut regressions = vec![];
+    println!("bench report:");
+    print!("   {:<name_width$}  {:>6}  {:>28}", "bench", "runs", "steps (mean [min, max])");
+    if baseline.is_some() {
+        print!("  {:>9}", "delta");
+    }
+    print!("  {:>28}", "gas (mean [min, max])");
+    if baseline.is_some() {
+        print!("  {:>9}", "delta");
+    }
+    println!();
+    for result in results {
+        print!(
+            "   {:<name_width$}  {:>6}  {:>28}",
+            result.name,
+            result.runs,
+            format_stats(Some(result.steps))
+        );
+        let mut regressed = false;
+        let base = baseline.map(|baseline| baseline.get(&result.name));
+        if let Some(base) = base {
+            let (delta, steps_regressed) =
+                format_delta(Some(result.steps), base.map(|base| base.steps));
+            regressed |= steps_regressed;
+            print!("  {delta}");
+        }
+        print!("  {:>28}", format_stats(result.gas));
+        if let Some(base) = base {
+            let (delta, gas_regressed) = format_delta(result.gas, base.and_then(|base| base.gas));
+            regressed |= gas_regressed;
+            print!("  {delta}");
+        }
+        println!();
+        if regressed {
+            regressions.push(result.name.as_str());
+        }
+    }
+    println!();
+    regressions
+}
+
+/// Formats the statistics of a measurement, if measured.
+fn format_stats(stats: Option<BenchStats>) -> String {
+    match stats {
+        Some(BenchStats { min, max, mean }) => format!("{mean:.1} [{min}, {max}]"),
+        None => "-".into(),
+    }
+}
+
+/// Formats the change of the mean of a measurement from its baseline, colored by whether it
+/// improved or regressed, and returns whether it regressed.
+fn format_delta(current: Option<BenchStats>, base: Option<BenchStats>) -> (ColoredString, bool) {
+    let (Some(current), Some(base)) = (current, base) else {
+        let delta = if current.is_some() { "new" } else { "-" };
+        return (format!("{delta:>9}").normal(), false);
+    };
+    if base.mean == 0.0 {
+        return (format!("{:>9}", "-").normal(), false);
+    }
+    let change = (current.mean - base.mean) / base.mean * 100.0;
+    let delta = format!("{change:+.2}%");
+    let delta = format!("{delta:>9}");
+    if change > 0.0 {
+        (delta.bright_red(), true)
+    } else if change < 0.0 {
+        (delta.bright_green(), false)
+    } else {
+        (delta.normal(), false)
+    }
+}
+
+/// Reads the measurements of the benchmarks stored in a baseline file, by benchmark name.
+pub fn read_baseline(path: &Path) -> Result<HashMap<String, BenchResult>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read the bench baseline {}.", path.display()))?;
+    let results: Vec<BenchResult> = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid bench baseline {}.", path.display()))?;
+    Ok(results.into_iter().map(|result| (result.name.clone(), result)).collect())
+}
+
+/// Writes the measurements of the benchmarks as JSON to the given baseline path.
+pub fn write_baseline(results: &[BenchResult], path: &Path) -> Result<()> {
+    let content = serde_json::to_string_pretty(results)?;
+    std::fs::write(path, content)
+        .with_context(|| format!("Failed to write the bench baseline to {}.", path.display()))
+}
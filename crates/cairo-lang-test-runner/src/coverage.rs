@@ -0,0 +1,131 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::{CrateId, FileLongId};
+use cairo_lang_sierra::program::{BranchTarget, Program, Statement, StatementIdx};
+use cairo_lang_sierra_generator::statements_locations::get_statements_locations;
+
+/// A line in a source file.
+struct SourceLine {
+    file: PathBuf,
+    /// The line number, starting from 1.
+    line: usize,
+}
+
+/// Maps the statements of a Sierra program to the source lines they were generated from, for
+/// reporting the code covered by runs of the program.
+pub struct CoverageMap {
+    /// The source line of each statement of the program, if it was generated from a file of the
+    /// covered crates.
+    statement_lines: Vec<Option<SourceLine>>,
+    /// The branching statements that have a source line, along with the statement each of their
+    /// branches leads to.
+    branches: Vec<(StatementIdx, Vec<StatementIdx>)>,
+}
+impl CoverageMap {
+    /// Creates the map of a program generated from the given database, covering only the code of
+    /// the given crates.
+    pub fn new(db: &RootDatabase, program: &Program, crate_ids: &[CrateId]) -> Result<Self> {
+        let statement_lines = get_statements_locations(db, program)
+            .to_option()
+            .with_context(|| "Failed getting the locations of the statements.")?
+            .into_iter()
+            .map(|location| {
+                let location = location?;
+                if !crate_ids.contains(&location.module_file_id.0.owning_crate(db)) {
+                    return None;
+                }
+                let location = location.diagnostic_location(db);
+                // Code generated by plugins has no lines of its own.
+                let FileLongId::OnDisk(file) = db.lookup_intern_file(location.file_id) else {
+                    return None;
+                };
+                let position = location.span.start.position_in_file(db, location.file_id)?;
+                Some(SourceLine { file, line: position.line + 1 })
+            })
+            .collect::<Vec<_>>();
+        let branches = program
+            .statements
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, statement)| {
+                let Statement::Invocation(invocation) = statement else { return None };
+                if invocation.branches.len() < 2 || statement_lines[idx].is_none() {
+                    return None;
+                }
+                let targets = invocation
+                    .branches
+                    .iter()
+                    .map(|branch| match branch.target {
+                        BranchTarget::Fallthrough => StatementIdx(idx + 1),
+                        BranchTarget::Statement(target) => target,
+                    })
+                    .collect();
+                Some((StatementIdx(idx), targets))
+            })
+            .collect();
+        Ok(Self { statement_lines, branches })
+    }
+
+    /// Writes the line and branch coverage of the given executions of the statements to the given
+    /// path, in the lcov tracefile format.
+    ///
+    /// A line is executed as many times as the most executed of its statements. A branch is taken
+    /// whenever the statement it leads to is executed.
+    pub fn write_lcov(
+        &self,
+        statement_counts: &HashMap<StatementIdx, usize>,
+        path: &Path,
+    ) -> Result<()> {
+        let count_of = |idx: &StatementIdx| statement_counts.get(idx).copied().unwrap_or_default();
+        let mut line_counts = BTreeMap::<&Path, BTreeMap<usize, usize>>::new();
+        for (idx, source_line) in self.statement_lines.iter().enumerate() {
+            let Some(SourceLine { file, line }) = source_line else { continue };
+            let line_count =
+                line_counts.entry(file.as_path()).or_default().entry(*line).or_default();
+            *line_count = (*line_count).max(count_of(&StatementIdx(idx)));
+        }
+        // The taken count of each branch, by file, along with the line and the statement of the
+        // branching statement. `None` if the branching statement was never executed.
+        let mut branch_counts = BTreeMap::<&Path, Vec<(usize, usize, Vec<Option<usize>>)>>::new();
+        for (idx, targets) in &self.branches {
+            let SourceLine { file, line } = self.statement_lines[idx.0].as_ref().unwrap();
+            let executed = statement_counts.contains_key(idx);
+            let taken = targets.iter().map(|target| executed.then(|| count_of(target))).collect();
+            branch_counts.entry(file.as_path()).or_default().push((*line, idx.0, taken));
+        }
+
+        let mut content = String::new();
+        for (file, lines) in line_counts {
+            writeln!(content, "TN:")?;
+            writeln!(content, "SF:{}", file.display())?;
+            let branches = branch_counts.remove(file).unwrap_or_default();
+            let (mut found_branches, mut hit_branches) = (0, 0);
+            for (line, block, taken) in branches {
+                for (branch, count) in taken.into_iter().enumerate() {
+                    found_branches += 1;
+                    if count.unwrap_or_default() > 0 {
+                        hit_branches += 1;
+                    }
+                    let count = count.map_or_else(|| "-".into(), |count| count.to_string());
+                    writeln!(content, "BRDA:{line},{block},{branch},{count}")?;
+                }
+            }
+            writeln!(content, "BRF:{found_branches}")?;
+            writeln!(content, "BRH:{hit_branches}")?;
+            for (line, count) in &lines {
+                writeln!(content, "DA:{line},{count}")?;
+            }
+            writeln!(content, "LF:{}", lines.len())?;
+            writeln!(content, "LH:{}", lines.values().filter(|count| **count > 0).count())?;
+            writeln!(content, "end_of_record")?;
+        }
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write the coverage report to {}.", path.display()))
+    }
+}
@@ -0,0 +1,260 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cairo_felt::Felt252;
+use cairo_lang_runner::Arg;
+use itertools::Itertools;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+#[path = "fuzzing_test.rs"]
+mod test;
+
+/// The default number of runs of a fuzzed test.
+pub const DEFAULT_FUZZER_RUNS: usize = 256;
+/// The maximal length of a generated array argument.
+const MAX_ARRAY_LEN: usize = 16;
+/// The maximal number of runs spent on shrinking the arguments of a failing run.
+const MAX_SHRINK_RUNS: usize = 1024;
+
+/// The kind of a scalar parameter of a fuzzed test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FuzzScalarKind {
+    Felt252,
+    /// An unsigned integer with the given number of bits, of up to 128 bits.
+    Uint(u32),
+    U256,
+}
+impl FuzzScalarKind {
+    /// Returns the kind of a scalar by the full path of its type.
+    fn from_type_path(path: &str) -> Option<Self> {
+        Some(match path {
+            "core::felt252" => Self::Felt252,
+            "core::integer::u8" => Self::Uint(8),
+            "core::integer::u16" => Self::Uint(16),
+            "core::integer::u32" => Self::Uint(32),
+            "core::integer::u64" => Self::Uint(64),
+            "core::integer::u128" => Self::Uint(128),
+            "core::integer::u256" => Self::U256,
+            _ => return None,
+        })
+    }
+
+    /// Returns the maximal value of the scalar.
+    fn max_value(&self) -> BigUint {
+        match self {
+            Self::Felt252 => Felt252::prime() - 1_u32,
+            Self::Uint(bits) => (BigUint::one() << *bits) - 1_u32,
+            Self::U256 => (BigUint::one() << 256_u32) - 1_u32,
+        }
+    }
+
+    /// Generates a random value of the scalar, preferring the edge values.
+    fn generate(&self, rng: &mut StdRng) -> BigUint {
+        let max_value = self.max_value();
+        match rng.gen_range(0..8) {
+            0 => BigUint::zero(),
+            1 => BigUint::one(),
+            2 => max_value,
+            _ => BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()) % (max_value + 1_u32),
+        }
+    }
+
+    /// Appends the cells representing a value of the scalar.
+    fn push_cells(&self, value: &BigUint, cells: &mut Vec<Felt252>) {
+        let to_felt252 = |value: &BigUint| Felt252::from_bytes_be(&value.to_bytes_be());
+        match self {
+            Self::Felt252 | Self::Uint(_) => cells.push(to_felt252(value)),
+            Self::U256 => {
+                let mask = (BigUint::one() << 128_u32) - 1_u32;
+                cells.push(to_felt252(&(value & mask)));
+                cells.push(to_felt252(&(value >> 128_u32)));
+            }
+        }
+    }
+}
+
+/// The kind of a parameter of a fuzzed test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FuzzParamKind {
+    Scalar(FuzzScalarKind),
+    /// An array of scalars of the given kind.
+    Array(FuzzScalarKind),
+}
+impl FuzzParamKind {
+    /// Returns the kind of a parameter by the full path of its type, or `None` if parameters of
+    /// this type can't be fuzzed.
+    pub fn from_type_path(path: &str) -> Option<Self> {
+        if let Some(element) =
+            path.strip_prefix("core::array::Array::<").and_then(|path| path.strip_suffix('>'))
+        {
+            return FuzzScalarKind::from_type_path(element).map(Self::Array);
+        }
+        FuzzScalarKind::from_type_path(path).map(Self::Scalar)
+    }
+
+    /// Generates a random value for the parameter.
+    fn generate(&self, rng: &mut StdRng) -> FuzzValue {
+        match self {
+            Self::Scalar(kind) => FuzzValue::Scalar(kind.generate(rng)),
+            Self::Array(kind) => {
+                let len = rng.gen_range(0..=MAX_ARRAY_LEN);
+                FuzzValue::Array((0..len).map(|_| kind.generate(rng)).collect())
+            }
+        }
+    }
+
+    /// Returns the runner argument for a value of the parameter.
+    fn to_arg(self, value: &FuzzValue) -> Vec<Arg> {
+        match (self, value) {
+            (Self::Scalar(kind), FuzzValue::Scalar(value)) => {
+                let mut cells = vec![];
+                kind.push_cells(value, &mut cells);
+                cells.into_iter().map(Arg::Value).collect()
+            }
+            (Self::Array(kind), FuzzValue::Array(values)) => {
+                let mut cells = vec![];
+                for value in values {
+                    kind.push_cells(value, &mut cells);
+                }
+                vec![Arg::Array(cells)]
+            }
+            _ => unreachable!("Value does not match the parameter kind."),
+        }
+    }
+}
+
+/// A value generated for a parameter of a fuzzed test.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FuzzValue {
+    Scalar(BigUint),
+    Array(Vec<BigUint>),
+}
+impl FuzzValue {
+    /// Returns the smaller values to try instead of this value when shrinking a failing run.
+    fn shrink_candidates(&self) -> Vec<FuzzValue> {
+        match self {
+            Self::Scalar(value) => {
+                shrink_scalar(value).into_iter().map(FuzzValue::Scalar).collect()
+            }
+            Self::Array(values) => {
+                let mut candidates = vec![];
+                if !values.is_empty() {
+                    candidates.push(vec![]);
+                    candidates.push(values[..values.len() / 2].to_vec());
+                }
+                for i in 0..values.len() {
+                    let mut removed = values.clone();
+                    removed.remove(i);
+                    candidates.push(removed);
+                }
+                for (i, value) in values.iter().enumerate() {
+                    for shrunk in shrink_scalar(value) {
+                        let mut replaced = values.clone();
+                        replaced[i] = shrunk;
+                        candidates.push(replaced);
+                    }
+                }
+                candidates.into_iter().unique().map(FuzzValue::Array).collect()
+            }
+        }
+    }
+}
+impl fmt::Display for FuzzValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Scalar(value) => write!(f, "{value}"),
+            Self::Array(values) => write!(f, "[{}]", values.iter().join(", ")),
+        }
+    }
+}
+
+/// Returns the smaller values to try instead of a scalar value when shrinking a failing run.
+fn shrink_scalar(value: &BigUint) -> Vec<BigUint> {
+    if value.is_zero() {
+        return vec![];
+    }
+    [BigUint::zero(), value / 2_u32, value - 1_u32].into_iter().dedup().collect()
+}
+
+/// The arguments of a failing run of a fuzzed test.
+pub struct FuzzFailure {
+    /// The seed of the fuzzer.
+    pub seed: u64,
+    /// The index of the first failing run.
+    pub run: usize,
+    /// The arguments of the failing run, after shrinking.
+    pub values: Vec<FuzzValue>,
+}
+impl fmt::Display for FuzzFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "fuzzer seed {}, run {}, arguments ({})",
+            self.seed,
+            self.run,
+            self.values.iter().join(", ")
+        )
+    }
+}
+
+/// Returns a seed for the fuzzer, for tests that don't fix their seed.
+pub fn random_seed() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards.").as_nanos() as u64
+}
+
+/// Fuzzes a test with parameters of the given kinds. `run_test` runs the test with the given
+/// arguments and returns its result along with whether the test passed.
+///
+/// Returns the result of the last run, along with the failing arguments after shrinking them if
+/// any of the runs failed.
+pub fn fuzz<T, E>(
+    params: &[FuzzParamKind],
+    runs: usize,
+    seed: u64,
+    mut run_test: impl FnMut(&[Arg]) -> Result<(T, bool), E>,
+) -> Result<(T, Option<FuzzFailure>), E> {
+    let to_args = |values: &[FuzzValue]| -> Vec<Arg> {
+        params.iter().zip_eq(values).flat_map(|(param, value)| param.to_arg(value)).collect()
+    };
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut run = 0;
+    loop {
+        let values = params.iter().map(|param| param.generate(&mut rng)).collect_vec();
+        let (result, passed) = run_test(&to_args(&values))?;
+        run += 1;
+        if passed {
+            if run >= runs {
+                return Ok((result, None));
+            }
+            continue;
+        }
+        // Shrink the arguments, greedily replacing them with the first smaller failing ones.
+        let (mut result, mut values) = (result, values);
+        let mut shrink_runs = 0;
+        'shrink: while shrink_runs < MAX_SHRINK_RUNS {
+            let candidates = values
+                .iter()
+                .enumerate()
+                .flat_map(|(i, value)| {
+                    value.shrink_candidates().into_iter().map(move |candidate| (i, candidate))
+                })
+                .collect_vec();
+            for (i, candidate) in candidates.into_iter().take(MAX_SHRINK_RUNS - shrink_runs) {
+                shrink_runs += 1;
+                let mut shrunk = values.clone();
+                shrunk[i] = candidate;
+                let (shrunk_result, passed) = run_test(&to_args(&shrunk))?;
+                if !passed {
+                    (result, values) = (shrunk_result, shrunk);
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+        return Ok((result, Some(FuzzFailure { seed, run: run - 1, values })));
+    }
+}
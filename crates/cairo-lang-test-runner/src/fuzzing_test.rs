@@ -0,0 +1,119 @@
+use cairo_lang_runner::Arg;
+use itertools::Itertools;
+use num_bigint::BigUint;
+
+use super::{fuzz, shrink_scalar, FuzzParamKind, FuzzScalarKind, FuzzValue};
+
+/// Returns the values of the cells of the given runner arguments.
+fn arg_values(args: &[Arg]) -> Vec<Vec<BigUint>> {
+    args.iter()
+        .map(|arg| match arg {
+            Arg::Value(value) => vec![value.to_biguint()],
+            Arg::Array(values) => values.iter().map(|value| value.to_biguint()).collect(),
+        })
+        .collect()
+}
+
+fn scalars(values: &[u32]) -> Vec<BigUint> {
+    values.iter().map(|value| BigUint::from(*value)).collect()
+}
+
+#[test]
+fn test_shrink_scalar() {
+    assert_eq!(shrink_scalar(&BigUint::from(0_u32)), scalars(&[]));
+    assert_eq!(shrink_scalar(&BigUint::from(1_u32)), scalars(&[0]));
+    assert_eq!(shrink_scalar(&BigUint::from(2_u32)), scalars(&[0, 1]));
+    assert_eq!(shrink_scalar(&BigUint::from(10_u32)), scalars(&[0, 5, 9]));
+}
+
+#[test]
+fn test_shrink_candidates() {
+    assert_eq!(
+        FuzzValue::Scalar(BigUint::from(10_u32)).shrink_candidates(),
+        scalars(&[0, 5, 9]).into_iter().map(FuzzValue::Scalar).collect_vec()
+    );
+    assert_eq!(FuzzValue::Array(vec![]).shrink_candidates(), vec![]);
+    // Dropping the whole array, its second half and each of its elements, then shrinking each of
+    // its elements, without repeating candidates.
+    assert_eq!(
+        FuzzValue::Array(scalars(&[3, 1])).shrink_candidates(),
+        [&[][..], &[3], &[1], &[0, 1], &[1, 1], &[2, 1], &[3, 0]]
+            .into_iter()
+            .map(|values| FuzzValue::Array(scalars(values)))
+            .collect_vec()
+    );
+}
+
+#[test]
+fn test_fuzz_passes() {
+    let mut runs = 0;
+    let (result, failure) =
+        fuzz(&[FuzzParamKind::Scalar(FuzzScalarKind::Uint(8))], 10, 0, |args| {
+            runs += 1;
+            Ok::<_, ()>((arg_values(args), true))
+        })
+        .unwrap();
+    assert_eq!(runs, 10);
+    assert_eq!(result.len(), 1);
+    assert!(failure.is_none());
+}
+
+/// Tests that the arguments of a failing run are shrunk to the minimal failing ones.
+#[test]
+fn test_fuzz_shrinks_to_minimal_counterexample() {
+    let params = [
+        FuzzParamKind::Scalar(FuzzScalarKind::Uint(32)),
+        FuzzParamKind::Array(FuzzScalarKind::Uint(8)),
+    ];
+    // Fails if the scalar is at least 100, or if the array has an element of at least 10.
+    let (result, failure) = fuzz(&params, 256, 7, |args| {
+        let values = arg_values(args);
+        let passed = values[0][0] < BigUint::from(100_u32)
+            && values[1].iter().all(|value| *value < BigUint::from(10_u32));
+        Ok::<_, ()>((values, passed))
+    })
+    .unwrap();
+    let failure = failure.unwrap();
+    assert_eq!(failure.seed, 7);
+    // Either of the conditions is enough for the run to fail, so the other argument is shrunk to
+    // its minimal value.
+    let minimal_failures = [
+        vec![FuzzValue::Scalar(BigUint::from(100_u32)), FuzzValue::Array(vec![])],
+        vec![FuzzValue::Scalar(BigUint::from(0_u32)), FuzzValue::Array(scalars(&[10]))],
+    ];
+    assert!(minimal_failures.contains(&failure.values), "Not minimal: {}", failure);
+    // The result is of the last failing run, which is of the shrunk arguments.
+    let expected_result = params
+        .iter()
+        .zip_eq(&failure.values)
+        .map(|(param, value)| arg_values(&param.to_arg(value)).concat())
+        .collect_vec();
+    assert_eq!(result, expected_result);
+}
+
+/// Tests that fuzzing with the same seed runs the test with the same arguments, and reports the
+/// same failure.
+#[test]
+fn test_fuzz_is_reproducible() {
+    let params = [
+        FuzzParamKind::Scalar(FuzzScalarKind::Felt252),
+        FuzzParamKind::Scalar(FuzzScalarKind::U256),
+        FuzzParamKind::Array(FuzzScalarKind::Uint(64)),
+    ];
+    let run = |seed| {
+        let mut all_args = vec![];
+        let (_, failure) = fuzz(&params, 64, seed, |args| {
+            let values = arg_values(args);
+            // Fails on a large enough array.
+            let passed = values[3].len() < 10;
+            all_args.push(values);
+            Ok::<_, ()>(((), passed))
+        })
+        .unwrap();
+        (all_args, failure.map(|failure| (failure.run, failure.values)))
+    };
+    let (args, failure) = run(1);
+    assert!(failure.is_some());
+    assert_eq!(run(1), (args.clone(), failure));
+    assert_ne!(run(2).0, args);
+}
@@ -0,0 +1,98 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use cairo_felt::Felt252;
+use cairo_lang_runner::RunResult;
+use itertools::Itertools;
+use num_traits::ToPrimitive;
+use serde::Serialize;
+
+/// The gas and resources used by a test.
+#[derive(Debug, Serialize)]
+pub struct TestGasUsage {
+    /// The full name of the test.
+    pub name: String,
+    /// The gas used by the test, if it was run with available gas.
+    pub gas: Option<usize>,
+    /// The number of steps of the run, including the steps of the contract calls.
+    pub steps: usize,
+    /// The number of used instances of each builtin, by builtin name.
+    pub builtins: BTreeMap<String, usize>,
+    /// The gas used by each of the contract calls made by the test, in call order.
+    pub contract_calls: Vec<ContractCallGasUsage>,
+}
+impl TestGasUsage {
+    /// Extracts the gas usage of a test from the result of its run. `entry_point_names` maps the
+    /// selectors of the entry points of the contracts to their names.
+    pub fn new(
+        name: String,
+        available_gas: Option<usize>,
+        result: &RunResult,
+        entry_point_names: &HashMap<Felt252, String>,
+    ) -> Self {
+        let gas =
+            available_gas.zip(result.gas_counter.as_ref()).map(|(available_gas, remaining)| {
+                available_gas - remaining.to_usize().expect("Remaining gas out of range.")
+            });
+        let builtins = result
+            .used_resources
+            .builtin_instance_counter
+            .iter()
+            .filter(|(_, count)| **count != 0)
+            .map(|(name, count)| (name.clone(), *count))
+            .collect();
+        let contract_calls = result
+            .starknet_state
+            .contract_calls_gas()
+            .iter()
+            .map(|call| ContractCallGasUsage {
+                contract_address: format!("{:#x}", call.contract_address.to_biguint()),
+                entry_point: entry_point_names
+                    .get(&call.selector)
+                    .cloned()
+                    .unwrap_or_else(|| format!("{:#x}", call.selector.to_biguint())),
+                gas: call.gas,
+            })
+            .collect();
+        Self { name, gas, steps: result.used_resources.n_steps, builtins, contract_calls }
+    }
+}
+
+/// The gas used by a call to an entry point of a contract.
+#[derive(Debug, Serialize)]
+pub struct ContractCallGasUsage {
+    /// The address of the called contract, as a hex string.
+    pub contract_address: String,
+    /// The name of the called entry point if known, or its selector as a hex string otherwise.
+    pub entry_point: String,
+    /// The gas used by the call, including the calls nested in it.
+    pub gas: usize,
+}
+
+/// Prints the gas usages of the tests as a table.
+pub fn print_gas_report(usages: &[TestGasUsage]) {
+    let name_width = usages.iter().map(|usage| usage.name.len()).chain([4]).max().unwrap();
+    println!("gas report:");
+    println!("   {:<name_width$}  {:>10}  {:>10}  builtins", "test", "gas", "steps");
+    for usage in usages {
+        let gas = usage.gas.map_or_else(|| "-".into(), |gas| gas.to_string());
+        let builtins =
+            usage.builtins.iter().map(|(name, count)| format!("{name}: {count}")).join(", ");
+        println!("   {:<name_width$}  {gas:>10}  {:>10}  {builtins}", usage.name, usage.steps);
+        for call in &usage.contract_calls {
+            println!(
+                "      call {} on {} - gas: {}",
+                call.entry_point, call.contract_address, call.gas
+            );
+        }
+    }
+    println!();
+}
+
+/// Writes the gas usages of the tests as JSON to the given path.
+pub fn write_gas_report_json(usages: &[TestGasUsage], path: &Path) -> Result<()> {
+    let content = serde_json::to_string_pretty(usages)?;
+    std::fs::write(path, content)
+        .with_context(|| format!("Failed to write the gas report to {}.", path.display()))
+}
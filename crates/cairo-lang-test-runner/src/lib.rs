@@ -1,52 +1,130 @@
-use std::path::Path;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use anyhow::{bail, Context, Result};
+use bench::{print_bench_report, read_baseline, run_benches, write_baseline, BenchResult};
 use cairo_felt::Felt252;
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::setup_project;
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_defs::ids::{FreeFunctionId, FunctionWithBodyId, ModuleItemId};
+use cairo_lang_defs::ids::{
+    FreeFunctionId, FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId,
+};
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
-use cairo_lang_runner::short_string::as_cairo_short_string;
-use cairo_lang_runner::{RunResultValue, SierraCasmRunner};
+use cairo_lang_runner::{
+    Arg, ContractEvent, ForkConfig, ForkedState, PanicDataDecoder, ResourceLimits, RunResult,
+    RunResultValue, RunnerError, SierraCasmRunner, StarknetState,
+};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::functions::GenericFunctionId;
 use cairo_lang_semantic::{ConcreteFunction, FunctionLongId};
 use cairo_lang_sierra::extensions::gas::CostTokenType;
 use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra::program::StatementIdx;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
 use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
+use cairo_lang_starknet::abi::AbiBuilder;
 use cairo_lang_starknet::casm_contract_class::ENTRY_POINT_COST;
 use cairo_lang_starknet::contract::{
-    find_contracts, get_contracts_info, get_module_functions, ContractInfo,
+    find_contracts, get_abi, get_contracts_info, get_module_functions, ContractInfo,
 };
 use cairo_lang_starknet::plugin::consts::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L1_HANDLER_MODULE};
 use cairo_lang_starknet::plugin::StarkNetPlugin;
+use cairo_lang_syntax::attribute::structured::Attribute;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use colored::Colorize;
+use coverage::CoverageMap;
+use fuzzing::{fuzz, random_seed, FuzzFailure, FuzzParamKind};
+use gas_report::{print_gas_report, write_gas_report_json, TestGasUsage};
 use itertools::{chain, Itertools};
 use plugin::TestPlugin;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
-use test_config::{try_extract_test_config, TestConfig};
+use report::{print_test_report, TestReportEntry, TestReportStatus};
+use snapshot::{check_snapshot, serialize_run, SnapshotsConfig, SNAPSHOTS_DIR};
+use test_config::{
+    extract_bench_config, extract_setup_config, try_extract_test_config, BenchConfig, SetupConfig,
+    TestConfig,
+};
 
 use crate::test_config::{PanicExpectation, TestExpectation};
 
+mod bench;
+mod coverage;
+mod fuzzing;
+mod gas_report;
 pub mod plugin;
+mod report;
+mod snapshot;
 mod test_config;
 
-pub struct TestRunner {
-    pub db: RootDatabase,
-    pub main_crate_ids: Vec<CrateId>,
+#[cfg(test)]
+#[path = "lib_test.rs"]
+mod test;
+
+pub use report::TestOutputFormat;
+
+/// The configuration of a [TestRunner].
+#[derive(Clone, Debug, Default)]
+pub struct TestRunnerConfig {
+    /// Run only tests containing the filter string.
     pub filter: String,
+    /// Run only tests whose full name is exactly the filter string.
+    pub exact: bool,
+    /// Include ignored tests as well.
     pub include_ignored: bool,
+    /// Run ignored tests only.
     pub ignored: bool,
+    /// Add the starknet plugin to run the tests.
     pub starknet: bool,
+    /// Report the external functions of the contracts that take the storage by `ref` but never
+    /// modify it.
+    pub lint_storage_mutability: bool,
+    /// The `cfg` options to compile the tests with, in addition to `test`.
+    pub cfg_set: CfgSet,
+    /// Print the gas used by each test.
+    pub gas_report: bool,
+    /// Write the gas used by each test as JSON to this path.
+    pub gas_report_json: Option<String>,
+    /// Write the line and branch coverage of the tests in lcov format to this path.
+    pub coverage: Option<String>,
+    /// Update the snapshots of the tests to their output instead of comparing them.
+    pub update_snapshots: bool,
+    /// Run the tests against the state of a live network at a pinned block.
+    pub fork: Option<ForkConfig>,
+    /// Print the output of the tests as they run, instead of showing the output of the failed
+    /// tests.
+    pub nocapture: bool,
+    /// Run the benchmarks instead of the tests.
+    pub bench: bool,
+    /// Compare the benchmarks with the baseline stored at this path.
+    pub bench_baseline: Option<String>,
+    /// Store the benchmarks as the baseline instead of comparing them.
+    pub save_bench_baseline: bool,
+    /// The format to print the results of the tests in.
+    pub format: TestOutputFormat,
+    /// The limits of the resources of each run, failing the tests exceeding them.
+    pub resource_limits: ResourceLimits,
+}
+impl TestRunnerConfig {
+    /// Returns whether a test or benchmark with the given full name matches the filter.
+    fn matches_filter(&self, name: &str) -> bool {
+        if self.exact { name == self.filter } else { name.contains(&self.filter) }
+    }
+}
+
+pub struct TestRunner {
+    pub db: RootDatabase,
+    pub main_crate_ids: Vec<CrateId>,
+    pub snapshots_dir: PathBuf,
+    pub config: TestRunnerConfig,
 }
 
 impl TestRunner {
@@ -55,65 +133,61 @@ impl TestRunner {
     /// # Arguments
     ///
     /// * `path` - The path to compile and run its tests
-    /// * `filter` - Run only tests containing the filter string
-    /// * `include_ignored` - Include ignored tests as well
-    /// * `ignored` - Run ignored tests only
-    /// * `starknet` - Add the starknet plugin to run the tests
-    pub fn new(
-        path: &str,
-        filter: &str,
-        include_ignored: bool,
-        ignored: bool,
-        starknet: bool,
-    ) -> Result<Self> {
+    /// * `config` - The configuration of the runner
+    pub fn new(path: &str, config: TestRunnerConfig) -> Result<Self> {
         let db = &mut {
             let mut b = RootDatabase::builder();
             b.detect_corelib();
-            b.with_cfg(CfgSet::from_iter([Cfg::name("test")]));
+            b.with_cfg(CfgSet::from_iter([Cfg::name("test")]).union(&config.cfg_set));
             b.with_semantic_plugin(Arc::new(TestPlugin::default()));
 
-            if starknet {
-                b.with_semantic_plugin(Arc::new(StarkNetPlugin::default()));
+            if config.starknet {
+                let plugin = StarkNetPlugin::default();
+                b.with_semantic_plugin(Arc::new(if config.lint_storage_mutability {
+                    plugin.with_storage_mutability_lint()
+                } else {
+                    plugin
+                }));
             }
 
             b.build()?
         };
 
         let main_crate_ids = setup_project(db, Path::new(&path))?;
+        let project_dir = Path::new(path);
+        let project_dir = if project_dir.is_file() { project_dir.parent() } else { None };
+        let snapshots_dir = project_dir.unwrap_or(Path::new(path)).join(SNAPSHOTS_DIR);
 
         if DiagnosticsReporter::stderr().check(db) {
             bail!("failed to compile: {}", path);
         }
 
-        Ok(Self {
-            db: db.snapshot(),
-            main_crate_ids,
-            filter: filter.into(),
-            include_ignored,
-            ignored,
-            starknet,
-        })
+        Ok(Self { db: db.snapshot(), main_crate_ids, snapshots_dir, config })
     }
 
     /// Runs the tests and process the results for a summary.
     pub fn run(&self) -> Result<Option<TestsSummary>> {
         let db = &self.db;
 
-        let all_entry_points = if self.starknet {
-            find_contracts(db, &self.main_crate_ids)
-                .iter()
-                .flat_map(|contract| {
-                    chain!(
-                        get_module_functions(db, contract, EXTERNAL_MODULE).unwrap(),
-                        get_module_functions(db, contract, CONSTRUCTOR_MODULE).unwrap(),
-                        get_module_functions(db, contract, L1_HANDLER_MODULE).unwrap()
-                    )
-                })
-                .flat_map(|func_id| ConcreteFunctionWithBodyId::from_no_generics_free(db, func_id))
-                .collect()
-        } else {
-            vec![]
-        };
+        let contracts =
+            if self.config.starknet { find_contracts(db, &self.main_crate_ids) } else { vec![] };
+        let all_entry_points = contracts
+            .iter()
+            .flat_map(|contract| {
+                chain!(
+                    get_module_functions(db, contract, EXTERNAL_MODULE).unwrap(),
+                    get_module_functions(db, contract, CONSTRUCTOR_MODULE).unwrap(),
+                    get_module_functions(db, contract, L1_HANDLER_MODULE).unwrap()
+                )
+            })
+            .flat_map(|func_id| ConcreteFunctionWithBodyId::from_no_generics_free(db, func_id))
+            .collect_vec();
+        let mut panic_decoder = PanicDataDecoder::default();
+        for contract in &contracts {
+            let abi = AbiBuilder::from_trait(db, get_abi(db, contract)?)
+                .with_context(|| "Failed building the ABI of a contract.")?;
+            panic_decoder.add_contract_errors(&abi, contract.selector_hash);
+        }
         let function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>> =
             all_entry_points
                 .iter()
@@ -124,13 +198,41 @@ impl TestRunner {
                     )
                 })
                 .collect();
-        let all_tests = find_all_tests(db, self.main_crate_ids.clone());
+        let all_setups = find_all_setups(db, self.main_crate_ids.clone());
+        let mut module_setups = HashMap::<ModuleId, String>::new();
+        for (func_id, _) in &all_setups {
+            let signature = db
+                .free_function_signature(*func_id)
+                .to_option()
+                .with_context(|| "Failed getting the signature of a setup function.")?;
+            let name = free_function_name(db, *func_id);
+            if !signature.params.is_empty() {
+                bail!("Setup function `{name}` should not have parameters.");
+            }
+            let module_id = func_id.parent_module(db);
+            if module_setups.insert(module_id, name).is_some() {
+                bail!("Module `{}` has multiple setup functions.", module_id.full_path(db));
+            }
+        }
+        let all_benches = find_all_benches(db, self.main_crate_ids.clone());
+        let mut all_tests = find_all_tests(db, self.main_crate_ids.clone());
+        for (func_id, test) in all_tests.iter_mut() {
+            if let Some(fuzzer) = &mut test.fuzzer {
+                fuzzer.params = get_fuzz_params(db, *func_id)?;
+            }
+            test.setup = module_setups.get(&func_id.parent_module(db)).cloned();
+        }
         let sierra_program = self
             .db
             .get_sierra_program_for_functions(
                 chain!(
                     all_entry_points.into_iter(),
-                    all_tests.iter().flat_map(|(func_id, _cfg)| {
+                    chain!(
+                        all_tests.iter().map(|(func_id, _cfg)| func_id),
+                        all_setups.iter().map(|(func_id, _setup)| func_id),
+                        all_benches.iter().map(|(func_id, _bench)| func_id)
+                    )
+                    .flat_map(|func_id| {
                         ConcreteFunctionWithBodyId::from_no_generics_free(db, *func_id)
                     })
                 )
@@ -138,6 +240,10 @@ impl TestRunner {
             )
             .to_option()
             .with_context(|| "Compilation failed without any diagnostics.")?;
+        let coverage_map = match self.config.coverage {
+            Some(_) => Some(CoverageMap::new(db, &sierra_program, &self.main_crate_ids)?),
+            None => None,
+        };
         let replacer = DebugReplacer { db };
         let sierra_program = replacer.apply(&sierra_program);
         let total_tests_count = all_tests.len();
@@ -145,61 +251,132 @@ impl TestRunner {
           .into_iter()
           .map(|(func_id, mut test)| {
               // Un-ignoring all the tests in `include-ignored` mode.
-              if self.include_ignored {
+              if self.config.include_ignored {
                   test.ignored = false;
               }
-              (
-                  format!(
-                      "{:?}",
-                      FunctionLongId {
-                          function: ConcreteFunction {
-                              generic_function: GenericFunctionId::Free(func_id),
-                              generic_args: vec![]
-                          }
-                      }
-                      .debug(db)
-                  ),
-                  test,
-              )
+              (free_function_name(db, func_id), test)
           })
-          .filter(|(name, _)| name.contains(&self.filter))
+          .filter(|(name, _)| self.config.matches_filter(name))
           // Filtering unignored tests in `ignored` mode.
-          .filter(|(_, test)| !self.ignored || test.ignored)
+          .filter(|(_, test)| !self.config.ignored || test.ignored)
           .collect_vec();
         let filtered_out = total_tests_count - named_tests.len();
+        // Running only the setup functions of the tests that are going to run.
+        let named_setups = all_setups
+            .into_iter()
+            .map(|(func_id, setup)| (free_function_name(db, func_id), setup))
+            .filter(|(name, _)| {
+                named_tests
+                    .iter()
+                    .any(|(_, test)| !test.ignored && test.setup.as_ref() == Some(name))
+            })
+            .collect_vec();
         let contracts_info = get_contracts_info(db, self.main_crate_ids.clone(), &replacer)?;
-        let TestsSummary { passed, failed, ignored, failed_run_results } =
-            run_tests(named_tests, sierra_program, function_set_costs, contracts_info)?;
+        let initial_state = StarknetState::default().with_output_capture(!self.config.nocapture);
+        let initial_state = match &self.config.fork {
+            Some(fork) => initial_state.with_fork(Arc::new(ForkedState::new(fork.clone()))),
+            None => initial_state,
+        };
+        if self.config.bench {
+            let mut named_benches = vec![];
+            for (func_id, bench) in all_benches {
+                let name = free_function_name(db, func_id);
+                if !self.config.matches_filter(&name) {
+                    continue;
+                }
+                let signature = db
+                    .free_function_signature(func_id)
+                    .to_option()
+                    .with_context(|| "Failed getting the signature of a benchmark.")?;
+                if !signature.params.is_empty() {
+                    bail!("Benchmark `{name}` should not have parameters.");
+                }
+                named_benches.push((name, bench));
+            }
+            let results = run_benches(
+                named_benches,
+                sierra_program,
+                function_set_costs,
+                contracts_info,
+                initial_state,
+                self.config.resource_limits,
+            )?;
+            return self.report_benches(&results).map(|()| None);
+        }
+        let TestsSummary {
+            passed,
+            failed,
+            ignored,
+            failures,
+            mut gas_usages,
+            statement_counts,
+            durations,
+        } = run_tests(
+            named_tests,
+            named_setups,
+            sierra_program,
+            function_set_costs,
+            contracts_info,
+            SnapshotsConfig { dir: &self.snapshots_dir, update: self.config.update_snapshots },
+            initial_state,
+            &panic_decoder,
+            self.config.format == TestOutputFormat::Pretty,
+            self.config.resource_limits,
+        )?;
+        gas_usages.sort_by(|a, b| a.name.cmp(&b.name));
+        if self.config.gas_report {
+            print_gas_report(&gas_usages);
+        }
+        if let Some(path) = &self.config.gas_report_json {
+            write_gas_report_json(&gas_usages, Path::new(path))?;
+        }
+        if let (Some(coverage_map), Some(path)) = (coverage_map, &self.config.coverage) {
+            coverage_map.write_lcov(&statement_counts, Path::new(path))?;
+        }
+        let pretty = self.config.format == TestOutputFormat::Pretty;
+        if !pretty {
+            let gas: HashMap<&str, Option<usize>> =
+                gas_usages.iter().map(|usage| (usage.name.as_str(), usage.gas)).collect();
+            let entries = chain!(
+                passed.iter().map(|name| (name, TestReportStatus::Passed, None)),
+                failed.iter().zip_eq(&failures).map(|(name, failure)| (
+                    name,
+                    TestReportStatus::Failed,
+                    Some(failure)
+                )),
+                ignored.iter().map(|name| (name, TestReportStatus::Ignored, None))
+            )
+            .map(|(name, status, failure)| TestReportEntry {
+                name: name.clone(),
+                status,
+                duration: durations.get(name).map_or(0.0, Duration::as_secs_f64),
+                gas: gas.get(name.as_str()).copied().flatten(),
+                failure: failure.map(TestFailure::to_string),
+            })
+            .sorted_by(|a, b| a.name.cmp(&b.name))
+            .collect_vec();
+            print_test_report(self.config.format, &entries, filtered_out);
+        }
         if failed.is_empty() {
-            println!(
-                "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
-                "ok".bright_green(),
-                passed.len(),
-                failed.len(),
-                ignored.len()
-            );
+            if pretty {
+                println!(
+                    "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered \
+                     out;",
+                    "ok".bright_green(),
+                    passed.len(),
+                    failed.len(),
+                    ignored.len()
+                );
+            }
             Ok(None)
         } else {
-            println!("failures:");
-            for (failure, run_result) in failed.iter().zip_eq(failed_run_results) {
-                print!("   {failure} - ");
-                match run_result {
-                    RunResultValue::Success(_) => {
-                        println!("expected panic but finished successfully.");
-                    }
-                    RunResultValue::Panic(values) => {
-                        print!("panicked with [");
-                        for value in &values {
-                            match as_cairo_short_string(value) {
-                                Some(as_string) => print!("{value} ('{as_string}'), "),
-                                None => print!("{value}, "),
-                            }
-                        }
-                        println!("].")
-                    }
+            if pretty {
+                println!("failures:");
+                for (name, failure) in failed.iter().zip_eq(failures) {
+                    println!("   {name} - {failure}");
                 }
+                println!();
             }
-            println!();
             bail!(
                 "test result: {}. {} passed; {} failed; {} ignored",
                 "FAILED".bright_red(),
@@ -209,74 +386,291 @@ impl TestRunner {
             );
         }
     }
+
+    /// Prints the measurements of the benchmarks, compared with the baseline if given, or stores
+    /// them as the baseline if requested. Fails if a benchmark regressed from the baseline.
+    fn report_benches(&self, results: &[BenchResult]) -> Result<()> {
+        let baseline_path = self.config.bench_baseline.as_ref().map(Path::new);
+        if self.config.save_bench_baseline {
+            print_bench_report(results, None);
+            if let Some(path) = baseline_path {
+                write_baseline(results, path)?;
+            }
+            return Ok(());
+        }
+        let baseline = baseline_path.map(read_baseline).transpose()?;
+        let regressions = print_bench_report(results, baseline.as_ref());
+        if regressions.is_empty() {
+            println!("bench result: {}. {} benchmarks;", "ok".bright_green(), results.len());
+            Ok(())
+        } else {
+            println!("regressions:");
+            for name in &regressions {
+                println!("   {name}");
+            }
+            println!();
+            bail!(
+                "bench result: {}. {} benchmarks; {} regressed",
+                "FAILED".bright_red(),
+                results.len(),
+                regressions.len()
+            );
+        }
+    }
+}
+
+/// Formats felt252 values, along with their short string and byte array representations.
+fn format_values(values: &[Felt252]) -> String {
+    PanicDataDecoder::default().format(values)
+}
+
+/// Describes the result of a test run that did not match the expectation of the test, decoding
+/// the panic values with the given decoder.
+fn describe_unexpected_result(
+    result: &RunResultValue,
+    expectation: &TestExpectation,
+    panic_decoder: &PanicDataDecoder,
+) -> String {
+    let expected_panic = match expectation {
+        TestExpectation::Panics(PanicExpectation::Exact(expected)) => Some(expected),
+        _ => None,
+    };
+    match (result, expected_panic) {
+        (RunResultValue::Success(_), None) => "expected panic but finished successfully.".into(),
+        (RunResultValue::Success(_), Some(expected)) => format!(
+            "expected panic with {} but finished successfully.",
+            panic_decoder.format(expected)
+        ),
+        (RunResultValue::Panic(values), None) => {
+            format!("panicked with {}.", panic_decoder.format(values))
+        }
+        (RunResultValue::Panic(values), Some(expected)) => format!(
+            "panicked with {} but expected {}.",
+            panic_decoder.format(values),
+            panic_decoder.format(expected)
+        ),
+    }
 }
 
 /// The status of a ran test.
 enum TestStatus {
     Success,
-    Fail(RunResultValue),
+    Fail(TestFailure),
     Ignore,
 }
 
+/// The details of a failed test.
+struct TestFailure {
+    /// The description of the failure: the unexpected result of the run, or the mismatch between
+    /// the output of the test and its snapshot if the test otherwise passed.
+    reason: String,
+    /// The events emitted during the run.
+    events: Vec<ContractEvent>,
+    /// The arguments of the failing run, if the test was fuzzed.
+    fuzz_failure: Option<FuzzFailure>,
+    /// The captured output of the `print` hints of the run.
+    output: String,
+}
+
+impl fmt::Display for TestFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)?;
+        if let Some(fuzz_failure) = &self.fuzz_failure {
+            write!(f, "\n   failed with {fuzz_failure}.")?;
+        }
+        if !self.events.is_empty() {
+            write!(f, "\n   emitted events:")?;
+            for event in &self.events {
+                write!(f, "\n      {event}")?;
+            }
+        }
+        if !self.output.is_empty() {
+            write!(f, "\n   output:")?;
+            for line in self.output.lines() {
+                write!(f, "\n      {line}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Summary data of the ran tests.
 pub struct TestsSummary {
     passed: Vec<String>,
     failed: Vec<String>,
     ignored: Vec<String>,
-    failed_run_results: Vec<RunResultValue>,
+    failures: Vec<TestFailure>,
+    gas_usages: Vec<TestGasUsage>,
+    /// The number of executions of each Sierra statement, in all the runs of all the tests.
+    statement_counts: HashMap<StatementIdx, usize>,
+    /// The wall time of the runs of each test, by test name.
+    durations: HashMap<String, Duration>,
 }
 
+/// The name of a ran test, along with its status, the gas it used, the number of executions of
+/// each Sierra statement in its runs and the wall time of its runs.
+type TestRunResult =
+    (String, TestStatus, Option<TestGasUsage>, HashMap<StatementIdx, usize>, Duration);
+
 /// Runs the tests and process the results for a summary.
+///
+/// The setup functions are run first, from the given initial Starknet state, and each test starts
+/// from a copy of the state resulting from the setup function of its module, or of the initial
+/// state if its module has none. The progress of the tests is printed if `print_progress` is set.
+/// A test whose run exceeds the resource limits fails, while a setup function exceeding them fails
+/// the whole run.
+#[allow(clippy::too_many_arguments)]
 pub fn run_tests(
     named_tests: Vec<(String, TestConfig)>,
+    named_setups: Vec<(String, SetupConfig)>,
     sierra_program: cairo_lang_sierra::program::Program,
     function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
     contracts_info: OrderedHashMap<Felt252, ContractInfo>,
+    snapshots: SnapshotsConfig<'_>,
+    initial_state: StarknetState,
+    panic_decoder: &PanicDataDecoder,
+    print_progress: bool,
+    resource_limits: ResourceLimits,
 ) -> anyhow::Result<TestsSummary> {
+    let entry_point_names: HashMap<Felt252, String> = contracts_info
+        .values()
+        .flat_map(|contract_info| contract_info.externals.iter())
+        .filter_map(|(selector, function_id)| {
+            Some((selector.clone(), function_id.debug_name.as_ref()?.to_string()))
+        })
+        .collect();
     let runner = SierraCasmRunner::new(
         sierra_program,
         Some(MetadataComputationConfig { function_set_costs }),
         contracts_info,
     )
-    .with_context(|| "Failed setting up runner.")?;
-    println!("running {} tests", named_tests.len());
+    .with_context(|| "Failed setting up runner.")?
+    .with_resource_limits(resource_limits);
+    let mut setups_statement_counts = HashMap::<StatementIdx, usize>::new();
+    let mut setup_states = HashMap::<String, StarknetState>::new();
+    for (name, setup) in named_setups {
+        let func = runner.find_function(name.as_str())?;
+        let result = runner
+            .run_function(func, &[], setup.available_gas, initial_state.clone())
+            .with_context(|| format!("Failed to run the setup function `{name}`."))?;
+        if let RunResultValue::Panic(values) = &result.value {
+            bail!("Setup function `{name}` panicked with {}.", panic_decoder.format(values));
+        }
+        for (statement_idx, count) in result.starknet_state.sierra_statement_counts() {
+            *setups_statement_counts.entry(*statement_idx).or_default() += count;
+        }
+        setup_states.insert(name, result.starknet_state.into_fixture());
+    }
+    if print_progress {
+        println!("running {} tests", named_tests.len());
+    }
     let wrapped_summary = Mutex::new(Ok(TestsSummary {
         passed: vec![],
         failed: vec![],
         ignored: vec![],
-        failed_run_results: vec![],
+        failures: vec![],
+        gas_usages: vec![],
+        statement_counts: setups_statement_counts,
+        durations: HashMap::new(),
     }));
     named_tests
         .into_par_iter()
-        .map(|(name, test)| -> anyhow::Result<(String, TestStatus)> {
+        .map(|(name, test)| -> anyhow::Result<TestRunResult> {
+            let mut statement_counts = HashMap::<StatementIdx, usize>::new();
             if test.ignored {
-                return Ok((name, TestStatus::Ignore));
+                return Ok((name, TestStatus::Ignore, None, statement_counts, Duration::ZERO));
+            }
+            let start = Instant::now();
+            let func = runner.find_function(name.as_str())?;
+            let starknet_state = match &test.setup {
+                Some(setup) => setup_states[setup].clone(),
+                None => initial_state.clone(),
+            };
+            let mut run_test = |args: &[Arg]| -> anyhow::Result<(RunResult, bool)> {
+                let result = runner
+                    .run_function(func, args, test.available_gas, starknet_state.clone())
+                    .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
+                for (statement_idx, count) in result.starknet_state.sierra_statement_counts() {
+                    *statement_counts.entry(*statement_idx).or_default() += count;
+                }
+                let passed = match (&result.value, &test.expectation) {
+                    (RunResultValue::Success(_), TestExpectation::Success) => true,
+                    (RunResultValue::Panic(_), TestExpectation::Panics(PanicExpectation::Any)) => {
+                        true
+                    }
+                    (
+                        RunResultValue::Panic(value),
+                        TestExpectation::Panics(PanicExpectation::Exact(expected)),
+                    ) => value == expected,
+                    _ => false,
+                };
+                Ok((result, passed))
+            };
+            let run = match &test.fuzzer {
+                Some(fuzzer) => {
+                    let seed = fuzzer.seed.unwrap_or_else(random_seed);
+                    fuzz(&fuzzer.params, fuzzer.runs, seed, run_test).map(
+                        |(result, fuzz_failure)| (result, fuzz_failure.is_none(), fuzz_failure),
+                    )
+                }
+                None => run_test(&[]).map(|(result, passed)| (result, passed, None)),
+            };
+            let (result, passed, fuzz_failure) = match run {
+                Ok(run) => run,
+                Err(err) => match err.downcast_ref::<RunnerError>() {
+                    Some(RunnerError::ResourcesExhausted(resource)) => {
+                        let failure = TestFailure {
+                            reason: format!("resources exhausted: the run {resource}."),
+                            events: vec![],
+                            fuzz_failure: None,
+                            output: String::new(),
+                        };
+                        return Ok((
+                            name,
+                            TestStatus::Fail(failure),
+                            None,
+                            statement_counts,
+                            start.elapsed(),
+                        ));
+                    }
+                    _ => return Err(err),
+                },
+            };
+            let duration = start.elapsed();
+            let gas_usage =
+                TestGasUsage::new(name.clone(), test.available_gas, &result, &entry_point_names);
+            let snapshot_mismatch = if passed && test.snapshot {
+                check_snapshot(&snapshots, &name, serialize_run(&result, gas_usage.gas))?
+            } else {
+                None
+            };
+            if passed && snapshot_mismatch.is_none() {
+                return Ok((
+                    name,
+                    TestStatus::Success,
+                    Some(gas_usage),
+                    statement_counts,
+                    duration,
+                ));
             }
-            let result = runner
-                .run_function(
-                    runner.find_function(name.as_str())?,
-                    &[],
-                    test.available_gas,
-                    Default::default(),
-                )
-                .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
             Ok((
                 name,
-                match &result.value {
-                    RunResultValue::Success(_) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Success,
-                        TestExpectation::Panics(_) => TestStatus::Fail(result.value),
-                    },
-                    RunResultValue::Panic(value) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Fail(result.value),
-                        TestExpectation::Panics(panic_expectation) => match panic_expectation {
-                            PanicExpectation::Exact(expected) if value != &expected => {
-                                TestStatus::Fail(result.value)
-                            }
-                            _ => TestStatus::Success,
-                        },
+                TestStatus::Fail(TestFailure {
+                    reason: match snapshot_mismatch {
+                        Some(snapshot_mismatch) => snapshot_mismatch.to_string(),
+                        None => describe_unexpected_result(
+                            &result.value,
+                            &test.expectation,
+                            panic_decoder,
+                        ),
                     },
-                },
+                    events: result.starknet_state.events().to_vec(),
+                    fuzz_failure,
+                    output: result.starknet_state.captured_output().unwrap_or_default().into(),
+                }),
+                Some(gas_usage),
+                statement_counts,
+                duration,
             ))
         })
         .for_each(|r| {
@@ -284,48 +678,122 @@ pub fn run_tests(
             if wrapped_summary.is_err() {
                 return;
             }
-            let (name, status) = match r {
-                Ok((name, status)) => (name, status),
+            let (name, status, gas_usage, statement_counts, duration) = match r {
+                Ok(test_run_result) => test_run_result,
                 Err(err) => {
                     *wrapped_summary = Err(err);
                     return;
                 }
             };
             let summary = wrapped_summary.as_mut().unwrap();
+            summary.gas_usages.extend(gas_usage);
+            for (statement_idx, count) in statement_counts {
+                *summary.statement_counts.entry(statement_idx).or_default() += count;
+            }
+            summary.durations.insert(name.clone(), duration);
             let (res_type, status_str) = match status {
                 TestStatus::Success => (&mut summary.passed, "ok".bright_green()),
-                TestStatus::Fail(run_result) => {
-                    summary.failed_run_results.push(run_result);
+                TestStatus::Fail(failure) => {
+                    summary.failures.push(failure);
                     (&mut summary.failed, "fail".bright_red())
                 }
                 TestStatus::Ignore => (&mut summary.ignored, "ignored".bright_yellow()),
             };
-            println!("test {name} ... {status_str}",);
+            if print_progress {
+                println!("test {name} ... {status_str}",);
+            }
             res_type.push(name);
         });
     wrapped_summary.into_inner().unwrap()
 }
 
-/// Finds the tests in the requested crates.
-fn find_all_tests(
+/// Returns the kinds of the parameters of a fuzzed test.
+fn get_fuzz_params(db: &dyn SemanticGroup, func_id: FreeFunctionId) -> Result<Vec<FuzzParamKind>> {
+    let signature = db
+        .free_function_signature(func_id)
+        .to_option()
+        .with_context(|| "Failed getting the signature of a fuzzed test.")?;
+    signature
+        .params
+        .iter()
+        .map(|param| {
+            let ty = param.ty.format(db);
+            FuzzParamKind::from_type_path(&ty).with_context(|| {
+                format!("Parameter `{}` of type `{ty}` can't be fuzzed.", param.name)
+            })
+        })
+        .collect()
+}
+
+/// Returns the full name of a free function without generic arguments.
+fn free_function_name(db: &(dyn SemanticGroup + 'static), func_id: FreeFunctionId) -> String {
+    format!(
+        "{:?}",
+        FunctionLongId {
+            function: ConcreteFunction {
+                generic_function: GenericFunctionId::Free(func_id),
+                generic_args: vec![]
+            }
+        }
+        .debug(db)
+    )
+}
+
+/// Finds the free functions in the requested crates, along with their attributes.
+fn find_all_free_functions(
     db: &dyn SemanticGroup,
     main_crates: Vec<CrateId>,
-) -> Vec<(FreeFunctionId, TestConfig)> {
-    let mut tests = vec![];
+) -> Vec<(FreeFunctionId, Vec<Attribute>)> {
+    let mut functions = vec![];
     for crate_id in main_crates {
         let modules = db.crate_modules(crate_id);
         for module_id in modules.iter() {
             let Ok(module_items) = db.module_items(*module_id) else {
               continue;
           };
-            tests.extend(
+            functions.extend(
               module_items.iter().filter_map(|item| {
                   let ModuleItemId::FreeFunction(func_id) = item else { return None };
                   let Ok(attrs) = db.function_with_body_attributes(FunctionWithBodyId::Free(*func_id)) else { return None };
-                  Some((*func_id, try_extract_test_config(db.upcast(), attrs).unwrap()?))
+                  Some((*func_id, attrs))
               }),
           );
         }
     }
-    tests
+    functions
+}
+
+/// Finds the tests in the requested crates.
+fn find_all_tests(
+    db: &dyn SemanticGroup,
+    main_crates: Vec<CrateId>,
+) -> Vec<(FreeFunctionId, TestConfig)> {
+    find_all_free_functions(db, main_crates)
+        .into_iter()
+        .filter_map(|(func_id, attrs)| {
+            Some((func_id, try_extract_test_config(db.upcast(), attrs).unwrap()?))
+        })
+        .collect()
+}
+
+/// Finds the setup functions in the requested crates.
+fn find_all_setups(
+    db: &dyn SemanticGroup,
+    main_crates: Vec<CrateId>,
+) -> Vec<(FreeFunctionId, SetupConfig)> {
+    find_all_free_functions(db, main_crates)
+        .into_iter()
+        .filter_map(|(func_id, attrs)| Some((func_id, extract_setup_config(db.upcast(), &attrs)?)))
+        .collect()
+}
+
+/// Finds the benchmarks in the requested crates.
+fn find_all_benches(
+    db: &dyn SemanticGroup,
+    main_crates: Vec<CrateId>,
+) -> Vec<(FreeFunctionId, BenchConfig)> {
+    find_all_free_functions(db, main_crates)
+        .into_iter()
+        .filter_map(|(func_id, attrs)| Some((func_id, extract_bench_config(db.upcast(), &attrs)?)))
+        .collect()
 }
@@ -0,0 +1,58 @@
+use crate::{TestRunner, TestRunnerConfig};
+
+/// Tests whose names all contain the filter `filter::test_value`, of which only the first is
+/// exactly the filter. The filter is a prefix of the name of the second, and a suffix of the name
+/// of the third.
+const FILTER_TESTS_CODE: &str = "
+#[test]
+fn test_value() {}
+
+#[test]
+fn test_value_overflow() {
+    panic_with_felt252('Should not run');
+}
+
+mod inner {
+    mod filter {
+        #[test]
+        fn test_value() {
+            panic_with_felt252('Should not run');
+        }
+    }
+}
+";
+
+#[test]
+fn test_matches_filter() {
+    let config = |exact| TestRunnerConfig {
+        filter: "filter::test_value".into(),
+        exact,
+        ..Default::default()
+    };
+    for (name, contains_filter, is_filter) in [
+        ("filter::test_value", true, true),
+        ("filter::test_value_overflow", true, false),
+        ("filter::inner::filter::test_value", true, false),
+        ("filter::test_val", false, false),
+    ] {
+        assert_eq!(config(false).matches_filter(name), contains_filter, "{name}");
+        assert_eq!(config(true).matches_filter(name), is_filter, "{name}");
+    }
+}
+
+#[test]
+fn test_exact_filter() {
+    let dir = std::env::temp_dir().join(format!("cairo-test-filter-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("filter.cairo");
+    std::fs::write(&path, FILTER_TESTS_CODE).unwrap();
+    let run = |exact| {
+        let config =
+            TestRunnerConfig { filter: "filter::test_value".into(), exact, ..Default::default() };
+        TestRunner::new(path.to_str().unwrap(), config).unwrap().run()
+    };
+    // The failing tests only contain the filter, so they are skipped in exact mode.
+    assert!(run(true).is_ok());
+    assert!(run(false).is_err());
+    std::fs::remove_dir_all(dir).unwrap();
+}
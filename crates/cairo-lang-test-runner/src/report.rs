@@ -0,0 +1,138 @@
+use std::str::FromStr;
+
+use itertools::Itertools;
+use serde::Serialize;
+use serde_json::json;
+
+/// The format of the results of the tests.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TestOutputFormat {
+    /// Human readable text, printed as the tests run.
+    #[default]
+    Pretty,
+    /// A JSON object with the results of the tests, printed once the tests ran.
+    Json,
+    /// A JUnit XML report with the results of the tests, printed once the tests ran.
+    Junit,
+}
+impl FromStr for TestOutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            "junit" => Ok(Self::Junit),
+            _ => Err(format!("Unknown format `{s}`, expected `pretty`, `json` or `junit`.")),
+        }
+    }
+}
+
+/// The status of a test in the report.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestReportStatus {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+/// The result of a single test in the report.
+#[derive(Debug, Serialize)]
+pub struct TestReportEntry {
+    /// The full name of the test.
+    pub name: String,
+    pub status: TestReportStatus,
+    /// The wall time of the runs of the test, in seconds.
+    pub duration: f64,
+    /// The gas used by the test, if it was run with available gas.
+    pub gas: Option<usize>,
+    /// The description of the failure of the test, if it failed.
+    pub failure: Option<String>,
+}
+
+/// Prints the results of the tests in the given machine readable format.
+pub fn print_test_report(
+    format: TestOutputFormat,
+    entries: &[TestReportEntry],
+    filtered_out: usize,
+) {
+    match format {
+        TestOutputFormat::Pretty => {}
+        TestOutputFormat::Json => println!("{}", format_json(entries, filtered_out)),
+        TestOutputFormat::Junit => println!("{}", format_junit(entries)),
+    }
+}
+
+/// Formats the results of the tests as a JSON object, with the counts of the tests by status and
+/// the entries of the tests.
+fn format_json(entries: &[TestReportEntry], filtered_out: usize) -> String {
+    let count = |status| entries.iter().filter(|entry| entry.status == status).count();
+    serde_json::to_string_pretty(&json!({
+        "passed": count(TestReportStatus::Passed),
+        "failed": count(TestReportStatus::Failed),
+        "ignored": count(TestReportStatus::Ignored),
+        "filtered_out": filtered_out,
+        "tests": entries,
+    }))
+    .unwrap()
+}
+
+/// Formats the results of the tests as a JUnit XML report, with a single test suite. The module
+/// path of a test is its class name, and its gas is given as a property.
+fn format_junit(entries: &[TestReportEntry]) -> String {
+    let count = |status| entries.iter().filter(|entry| entry.status == status).count();
+    let total_duration: f64 = entries.iter().map(|entry| entry.duration).sum();
+    let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+    report.push_str(&format!(
+        "  <testsuite name=\"cairo-test\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" \
+         time=\"{total_duration:.6}\">\n",
+        entries.len(),
+        count(TestReportStatus::Failed),
+        count(TestReportStatus::Ignored),
+    ));
+    for entry in entries {
+        let (class_name, name) = entry.name.rsplit_once("::").unwrap_or(("", &entry.name));
+        report.push_str(&format!(
+            "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.6}\">\n",
+            escape_xml(name),
+            escape_xml(class_name),
+            entry.duration
+        ));
+        if let Some(gas) = entry.gas {
+            report.push_str(&format!(
+                "      <properties>\n        <property name=\"gas\" value=\"{gas}\"/>\n      \
+                 </properties>\n"
+            ));
+        }
+        match entry.status {
+            TestReportStatus::Passed => {}
+            TestReportStatus::Failed => {
+                let failure = entry.failure.as_deref().unwrap_or_default();
+                report.push_str(&format!(
+                    "      <failure message=\"{}\">{}</failure>\n",
+                    escape_xml(failure.lines().next().unwrap_or_default()),
+                    escape_xml(failure)
+                ));
+            }
+            TestReportStatus::Ignored => report.push_str("      <skipped/>\n"),
+        }
+        report.push_str("    </testcase>\n");
+    }
+    report.push_str("  </testsuite>\n</testsuites>");
+    report
+}
+
+/// Escapes text for use in XML attributes and content.
+fn escape_xml(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' => "&amp;".into(),
+            '<' => "&lt;".into(),
+            '>' => "&gt;".into(),
+            '"' => "&quot;".into(),
+            '\'' => "&apos;".into(),
+            c => c.to_string(),
+        })
+        .join("")
+}
@@ -0,0 +1,93 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use cairo_lang_runner::{RunResult, RunResultValue};
+use itertools::Itertools;
+
+use crate::format_values;
+
+/// The name of the directory of the snapshots, in the directory of the tested project.
+pub const SNAPSHOTS_DIR: &str = "snapshots";
+
+/// Where the snapshots of the tests are stored, and whether to update them.
+pub struct SnapshotsConfig<'a> {
+    pub dir: &'a Path,
+    pub update: bool,
+}
+
+/// A mismatch between the output of a test and its snapshot.
+pub enum SnapshotMismatch {
+    /// The test has no snapshot yet.
+    Missing { path: PathBuf, actual: String },
+    /// The output of the test differs from its snapshot.
+    Differs { path: PathBuf, expected: String, actual: String },
+}
+impl fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { path, actual } => {
+                writeln!(f, "snapshot {} is missing, the output is:", path.display())?;
+                write!(f, "{}", indent(actual))
+            }
+            Self::Differs { path, expected, actual } => {
+                writeln!(f, "output does not match snapshot {}.", path.display())?;
+                writeln!(f, "   expected:")?;
+                writeln!(f, "{}", indent(expected))?;
+                writeln!(f, "   actual:")?;
+                write!(f, "{}", indent(actual))
+            }
+        }
+    }
+}
+
+/// Indents the lines of a snapshot for printing under the name of its test.
+fn indent(content: &str) -> String {
+    content.lines().map(|line| format!("      {line}")).join("\n")
+}
+
+/// Serializes the output of a test run for comparing with its snapshot: its returned or panic
+/// values, the events it emitted and the gas it used, if run with available gas.
+pub fn serialize_run(result: &RunResult, gas: Option<usize>) -> String {
+    let mut content = match &result.value {
+        RunResultValue::Success(values) => format!("success: {}\n", format_values(values)),
+        RunResultValue::Panic(values) => format!("panic: {}\n", format_values(values)),
+    };
+    let events = result.starknet_state.events();
+    if !events.is_empty() {
+        content.push_str("events:\n");
+        for event in events {
+            content.push_str(&format!("   {event}\n"));
+        }
+    }
+    if let Some(gas) = gas {
+        content.push_str(&format!("gas: {gas}\n"));
+    }
+    content
+}
+
+/// Compares the serialized output of a test with its snapshot, or updates the snapshot to the
+/// output if requested.
+pub fn check_snapshot(
+    config: &SnapshotsConfig<'_>,
+    name: &str,
+    actual: String,
+) -> Result<Option<SnapshotMismatch>> {
+    let path = config.dir.join(format!("{}.snap", name.replace("::", "__")));
+    if config.update {
+        std::fs::create_dir_all(config.dir).with_context(|| {
+            format!("Failed to create the snapshots directory {}.", config.dir.display())
+        })?;
+        std::fs::write(&path, actual)
+            .with_context(|| format!("Failed to write the snapshot {}.", path.display()))?;
+        return Ok(None);
+    }
+    let Ok(expected) = std::fs::read_to_string(&path) else {
+        return Ok(Some(SnapshotMismatch::Missing { path, actual }));
+    };
+    Ok(if expected == actual {
+        None
+    } else {
+        Some(SnapshotMismatch::Differs { path, expected, actual })
+    })
+}
@@ -1,11 +1,15 @@
 use cairo_felt::Felt252;
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_syntax::attribute::structured::{Attribute, AttributeArg, AttributeArgVariant};
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_utils::OptionHelper;
 use num_traits::ToPrimitive;
 
+use crate::bench::DEFAULT_BENCH_RUNS;
+use crate::fuzzing::{FuzzParamKind, DEFAULT_FUZZER_RUNS};
+
 /// Expectation for a panic case.
 pub enum PanicExpectation {
     /// Accept any panic value.
@@ -22,6 +26,17 @@ pub enum TestExpectation {
     Panics(PanicExpectation),
 }
 
+/// The configuration of the fuzzing of a test with parameters.
+pub struct FuzzerConfig {
+    /// The number of runs with random arguments.
+    pub runs: usize,
+    /// The seed of the random arguments, if fixed.
+    pub seed: Option<u64>,
+    /// The kinds of the parameters of the test. Resolved from the signature of the test after
+    /// extracting the configuration.
+    pub params: Vec<FuzzParamKind>,
+}
+
 /// The configuration for running a single test.
 pub struct TestConfig {
     /// The amount of gas the test requested.
@@ -30,6 +45,29 @@ pub struct TestConfig {
     pub expectation: TestExpectation,
     /// Should the test be ignored.
     pub ignored: bool,
+    /// The configuration of the fuzzing of the test, if its parameters should be fuzzed.
+    pub fuzzer: Option<FuzzerConfig>,
+    /// Should the output of the test be compared to its snapshot.
+    pub snapshot: bool,
+    /// The full name of the setup function of the module of the test, if it has one. Resolved
+    /// from the module of the test after extracting the configuration.
+    pub setup: Option<String>,
+}
+
+/// The configuration for running a setup function, whose resulting Starknet state is the initial
+/// state of the tests of its module.
+pub struct SetupConfig {
+    /// The amount of gas the setup function requested.
+    pub available_gas: Option<usize>,
+}
+
+/// The configuration for running a benchmark, a function run repeatedly to measure the steps and
+/// gas it uses.
+pub struct BenchConfig {
+    /// The amount of gas the benchmark requested.
+    pub available_gas: Option<usize>,
+    /// The number of runs of the benchmark.
+    pub runs: usize,
 }
 
 /// Extracts the configuration of a tests from attributes, or returns the diagnostics if the
@@ -42,46 +80,99 @@ pub fn try_extract_test_config(
     let ignore_attr = attrs.iter().find(|attr| attr.id.as_str() == "ignore");
     let available_gas_attr = attrs.iter().find(|attr| attr.id.as_str() == "available_gas");
     let should_panic_attr = attrs.iter().find(|attr| attr.id.as_str() == "should_panic");
+    let fuzz_attr = attrs.iter().find(|attr| attr.id.as_str() == "fuzz");
+    let snapshot_attr = attrs.iter().find(|attr| attr.id.as_str() == "snapshot");
+    let setup_attr = attrs.iter().find(|attr| attr.id.as_str() == "setup");
+    let bench_attr = attrs.iter().find(|attr| attr.id.as_str() == "bench");
     let mut diagnostics = vec![];
     if let Some(attr) = test_attr {
         if !attr.args.is_empty() {
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.id_stable_ptr.untyped(),
                 message: "Attribute should not have arguments.".into(),
+                code: Some(ErrorCode::new("E0501")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        }
+        if let Some(attr) = setup_attr {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: attr.id_stable_ptr.untyped(),
+                message: "Setup functions can't be tests.".into(),
+                code: Some(ErrorCode::new("E0502")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        }
+        if let Some(attr) = bench_attr {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: attr.id_stable_ptr.untyped(),
+                message: "Benchmarks can't be tests.".into(),
+                code: Some(ErrorCode::new("E0503")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
         }
     } else {
-        for attr in [ignore_attr, available_gas_attr, should_panic_attr].into_iter().flatten() {
+        // The gas of setup functions and benchmarks may be set as well.
+        let available_gas_attr =
+            if setup_attr.is_some() || bench_attr.is_some() { None } else { available_gas_attr };
+        for attr in [ignore_attr, available_gas_attr, should_panic_attr, fuzz_attr, snapshot_attr]
+            .into_iter()
+            .flatten()
+        {
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.id_stable_ptr.untyped(),
                 message: "Attribute should only appear on tests.".into(),
+                code: Some(ErrorCode::new("E0504")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
         }
     }
-    let ignored = if let Some(attr) = ignore_attr {
+    for attr in [ignore_attr, snapshot_attr, setup_attr].into_iter().flatten() {
         if !attr.args.is_empty() {
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.id_stable_ptr.untyped(),
                 message: "Attribute should not have arguments.".into(),
+                code: Some(ErrorCode::new("E0501")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
         }
-        true
-    } else {
-        false
-    };
+    }
+    if let Some(attr) = bench_attr {
+        if setup_attr.is_some() {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: attr.id_stable_ptr.untyped(),
+                message: "Setup functions can't be benchmarks.".into(),
+                code: Some(ErrorCode::new("E0505")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        }
+        if extract_bench_runs(db, attr).is_none() {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: attr.args_stable_ptr.untyped(),
+                message: "Benchmark arguments must be of the form `runs: <number>`.".into(),
+                code: Some(ErrorCode::new("E0506")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        }
+    }
+    let ignored = ignore_attr.is_some();
+    let snapshot = snapshot_attr.is_some();
     let available_gas = if let Some(attr) = available_gas_attr {
-        if let [
-            AttributeArg {
-                variant: AttributeArgVariant::Unnamed { value: ast::Expr::Literal(literal), .. },
-                ..
-            },
-        ] = &attr.args[..]
-        {
+        if let Some(literal) = available_gas_literal(attr) {
             literal.numeric_value(db).unwrap_or_default().to_usize()
         } else {
             diagnostics.push(PluginDiagnostic {
                 stable_ptr: attr.id_stable_ptr.untyped(),
                 message: "Attribute should have a single value argument.".into(),
+                code: Some(ErrorCode::new("E0507")),
+                severity: Severity::Error,
+                fixes: vec![],
             });
             None
         }
@@ -97,9 +188,12 @@ pub fn try_extract_test_config(
                 extract_panic_values(db, attr).on_none(|| {
                     diagnostics.push(PluginDiagnostic {
                         stable_ptr: attr.args_stable_ptr.untyped(),
-                        message: "Expected panic must be of the form `expected: <tuple of \
-                                  felt252s>`."
+                        message: "Expected panic must be of the form `expected: <felt252 or tuple \
+                                  of felt252s>`."
                             .into(),
+                        code: Some(ErrorCode::new("E0508")),
+                        severity: Severity::Error,
+                        fixes: vec![],
                     });
                 }),
             )
@@ -107,6 +201,18 @@ pub fn try_extract_test_config(
     } else {
         (false, None)
     };
+    let fuzzer = fuzz_attr.map(|attr| {
+        extract_fuzzer_config(db, attr).on_none(|| {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: attr.args_stable_ptr.untyped(),
+                message: "Fuzzer arguments must be of the form `runs: <number>, seed: <number>`."
+                    .into(),
+                code: Some(ErrorCode::new("E0509")),
+                severity: Severity::Error,
+                fixes: vec![],
+            });
+        })
+    });
     if !diagnostics.is_empty() {
         return Err(diagnostics);
     }
@@ -125,11 +231,85 @@ pub fn try_extract_test_config(
                 TestExpectation::Success
             },
             ignored,
+            fuzzer: fuzzer.flatten(),
+            snapshot,
+            setup: None,
         })
     })
 }
 
-/// Tries to extract the relevant expected panic values.
+/// Extracts the configuration of a setup function from attributes, assuming they were validated
+/// by [try_extract_test_config].
+pub fn extract_setup_config(db: &dyn SyntaxGroup, attrs: &[Attribute]) -> Option<SetupConfig> {
+    attrs.iter().find(|attr| attr.id.as_str() == "setup")?;
+    let available_gas = attrs
+        .iter()
+        .find(|attr| attr.id.as_str() == "available_gas")
+        .and_then(available_gas_literal)
+        .and_then(|literal| literal.numeric_value(db).unwrap_or_default().to_usize());
+    Some(SetupConfig { available_gas })
+}
+
+/// Extracts the configuration of a benchmark from attributes, assuming they were validated by
+/// [try_extract_test_config].
+pub fn extract_bench_config(db: &dyn SyntaxGroup, attrs: &[Attribute]) -> Option<BenchConfig> {
+    let bench_attr = attrs.iter().find(|attr| attr.id.as_str() == "bench")?;
+    let available_gas = attrs
+        .iter()
+        .find(|attr| attr.id.as_str() == "available_gas")
+        .and_then(available_gas_literal)
+        .and_then(|literal| literal.numeric_value(db).unwrap_or_default().to_usize());
+    Some(BenchConfig { available_gas, runs: extract_bench_runs(db, bench_attr)? })
+}
+
+/// Tries to extract the number of runs of a benchmark from the optional named `runs` argument.
+fn extract_bench_runs(db: &dyn SyntaxGroup, attr: &Attribute) -> Option<usize> {
+    match &attr.args[..] {
+        [] => Some(DEFAULT_BENCH_RUNS),
+        [
+            AttributeArg {
+                variant: AttributeArgVariant::Named { name, value: ast::Expr::Literal(literal), .. },
+                ..
+            },
+        ] if name == "runs" => literal.numeric_value(db)?.to_usize().filter(|runs| *runs > 0),
+        _ => None,
+    }
+}
+
+/// Returns the literal argument of an `available_gas` attribute, if it is its single argument.
+fn available_gas_literal(attr: &Attribute) -> Option<&ast::TerminalLiteralNumber> {
+    let [
+        AttributeArg {
+            variant: AttributeArgVariant::Unnamed { value: ast::Expr::Literal(literal), .. },
+            ..
+        },
+    ] = &attr.args[..] else {
+        return None;
+    };
+    Some(literal)
+}
+
+/// Tries to extract the fuzzer configuration from the named `runs` and `seed` arguments, both
+/// optional.
+fn extract_fuzzer_config(db: &dyn SyntaxGroup, attr: &Attribute) -> Option<FuzzerConfig> {
+    let mut config = FuzzerConfig { runs: DEFAULT_FUZZER_RUNS, seed: None, params: vec![] };
+    for arg in &attr.args {
+        let AttributeArgVariant::Named { name, value: ast::Expr::Literal(literal), .. } =
+            &arg.variant else {
+            return None;
+        };
+        let value = literal.numeric_value(db)?;
+        match name.as_str() {
+            "runs" => config.runs = value.to_usize().filter(|runs| *runs > 0)?,
+            "seed" => config.seed = Some(value.to_u64()?),
+            _ => return None,
+        }
+    }
+    Some(config)
+}
+
+/// Tries to extract the relevant expected panic values, given either as a single value or as a
+/// tuple of values.
 fn extract_panic_values(db: &dyn SyntaxGroup, attr: &Attribute) -> Option<Vec<Felt252>> {
     let [
         AttributeArg {
@@ -142,10 +322,11 @@ fn extract_panic_values(db: &dyn SyntaxGroup, attr: &Attribute) -> Option<Vec<Fe
     if name != "expected" {
         return None;
     }
-    let ast::Expr::Tuple(panics) = panics else { return None };
+    let panics = match panics {
+        ast::Expr::Tuple(panics) => panics.expressions(db).elements(db),
+        _ => vec![panics.clone()],
+    };
     panics
-        .expressions(db)
-        .elements(db)
         .into_iter()
         .map(|value| match value {
             ast::Expr::Literal(literal) => {
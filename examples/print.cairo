@@ -0,0 +1,7 @@
+use debug::PrintTrait;
+
+// Prints the given value, and returns it.
+fn print_value(a: felt252) -> felt252 {
+    a.print();
+    a
+}
@@ -47,6 +47,11 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         felt252_dict: "felt252_dict",
         felt252: "felt252",
         gas: "gas",
+        i128: "i128",
+        i16: "i16",
+        i32: "i32",
+        i64: "i64",
+        i8: "i8",
         nullable: "nullable",
         poseidon: "poseidon",
         snapshot: "snapshot",
@@ -77,6 +82,7 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         class_hash: "class_hash",
         contract_address: "contract_address",
         secp256k1: "secp256k1",
+        secp256r1: "secp256r1",
         storage_address: "storage_address",
         syscalls: "syscalls",
     },
@@ -11,7 +11,10 @@ use cairo_lang_filesystem::db::FilesGroupEx;
 use cairo_lang_filesystem::flag::Flag;
 use cairo_lang_filesystem::ids::{CrateId, FlagId};
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
-use cairo_lang_runner::{Arg, RunResultValue, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST};
+use cairo_lang_runner::{
+    Arg, Repl, RunResultValue, RunnerError, SierraCasmRunner, StarknetState, DUMMY_BUILTIN_GAS_COST,
+};
+use cairo_lang_sierra::validate::validate;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use cairo_lang_sierra_to_casm::test_utils::build_metadata;
@@ -103,11 +106,9 @@ fn checked_compile_to_sierra(
 #[case::pedersen_test("pedersen_test")]
 #[case::testing("testing")]
 fn cairo_to_sierra(#[case] name: &str, example_dir_data: &ExampleDirData) {
-    compare_contents_or_fix(
-        name,
-        "sierra",
-        checked_compile_to_sierra(name, example_dir_data, false).to_string(),
-    );
+    let program = checked_compile_to_sierra(name, example_dir_data, false);
+    assert_eq!(validate(&program), Ok(()));
+    compare_contents_or_fix(name, "sierra", program.to_string());
 }
 
 /// Tests lowering from Cairo to Sierra, with automatic addition of `withdraw_gas` calls.
@@ -328,3 +329,104 @@ fn run_fib_array_len(#[case] n: usize, #[case] last: usize, example_dir_data: &E
         [_, _, actual_last, actual_len] if actual_last == &Felt252::from(last) && actual_len == &Felt252::from(n)
     );
 }
+
+#[rstest]
+fn run_fib_profiled(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("fib", example_dir_data, false),
+        None,
+        Default::default(),
+    )
+    .expect("Failed setting up runner.")
+    .with_profiling(true);
+    let result = runner
+        .run_function(
+            runner.find_function("").expect("Failed finding the function."),
+            &[1, 1, 7].map(|value| Arg::Value(Felt252::from(value))),
+            None,
+            Default::default(),
+        )
+        .expect("Failed running the function.");
+    let profiling_info = result.profiling_info.expect("The run was not profiled.");
+    // Every recursive call adds a frame to the stack.
+    assert_eq!(profiling_info.stack_steps.keys().map(|stack| stack.len() - 1).max(), Some(8));
+    assert_eq!(profiling_info.libfunc_invocations["function_call<user@examples::fib::fib>"], 7);
+    assert_eq!(profiling_info.function_steps()[0].0, "examples::fib::fib");
+}
+
+#[rstest]
+fn run_fib_with_trace_export(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("fib", example_dir_data, false),
+        None,
+        Default::default(),
+    )
+    .expect("Failed setting up runner.")
+    .with_trace_export(true);
+    let result = runner
+        .run_function(
+            runner.find_function("").expect("Failed finding the function."),
+            &[1, 1, 7].map(|value| Arg::Value(Felt252::from(value))),
+            None,
+            Default::default(),
+        )
+        .expect("Failed running the function.");
+    let prover_input = result.prover_input.expect("The trace of the run was not exported.");
+    let public_input = &prover_input.public_input;
+    assert_eq!(public_input.n_steps, prover_input.trace.len());
+    assert_eq!(public_input.n_steps, result.used_resources.n_steps);
+    let program = &public_input.memory_segments["program"];
+    assert_eq!(program.begin_addr, 1);
+    assert_eq!(public_input.public_memory.len(), program.stop_ptr - program.begin_addr);
+    // The run starts at the start of the program.
+    assert_eq!(prover_input.trace[0].pc, program.begin_addr);
+}
+
+#[rstest]
+fn debug_fib(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("fib", example_dir_data, false),
+        None,
+        Default::default(),
+    )
+    .expect("Failed setting up runner.");
+    let mut output = vec![];
+    let mut repl =
+        Repl::new("variables\nbreak fib\ncontinue\nvariables\nquit\n".as_bytes(), &mut output);
+    let result = runner.debug_function(
+        runner.find_function("").expect("Failed finding the function."),
+        &[1, 1, 7].map(|value| Arg::Value(Felt252::from(value))),
+        None,
+        Default::default(),
+        &mut repl,
+    );
+    assert!(matches!(result, Err(RunnerError::RunAborted)));
+    drop(repl);
+    let output = String::from_utf8(output).unwrap();
+    // The run pauses at the start of the function, and at the start of its recursive call.
+    assert_eq!(output.matches(" in examples::fib::fib: ").count(), 2);
+    assert!(output.contains("felt252 = 7\n"));
+    assert!(output.contains("felt252 = 6\n"));
+}
+
+#[rstest]
+fn run_print_captured(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("print", example_dir_data, false),
+        None,
+        Default::default(),
+    )
+    .expect("Failed setting up runner.");
+    let result = runner
+        .run_function(
+            runner.find_function("::print_value").expect("Failed finding the function."),
+            &[Arg::Value(Felt252::from(448378203247_u64))],
+            None,
+            StarknetState::default().with_output_capture(true),
+        )
+        .expect("Failed running the function.");
+    assert_eq!(
+        result.starknet_state.captured_output(),
+        Some(format!("[DEBUG]\t{: <31}\t(raw: {})\n\n", "hello", 448378203247_u64).as_str())
+    );
+}